@@ -0,0 +1,154 @@
+#ifndef CANFORGE_H
+#define CANFORGE_H
+
+/* Generated by cbindgen from canforge-ffi/src/lib.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Type of a decoded value, mirrors the generated `CanDbcType` variants.
+typedef enum CanforgeValueKind {
+  CANFORGE_VALUE_KIND_BOOL,
+  CANFORGE_VALUE_KIND_U8,
+  CANFORGE_VALUE_KIND_U16,
+  CANFORGE_VALUE_KIND_U32,
+  CANFORGE_VALUE_KIND_U64,
+  CANFORGE_VALUE_KIND_I8,
+  CANFORGE_VALUE_KIND_I16,
+  CANFORGE_VALUE_KIND_I32,
+  CANFORGE_VALUE_KIND_I64,
+  CANFORGE_VALUE_KIND_F64,
+} CanforgeValueKind;
+
+// Opaque database handle.
+typedef struct CanforgeDbc CanforgeDbc;
+
+// Message metadata; strings are owned by the database handle.
+typedef struct CanforgeMessageInfo {
+  // Raw DBC id (bit 31 set for extended frames).
+  uint32_t id;
+  // Identifier used by the generated code.
+  const char *name;
+  // Name as written in the DBC file.
+  const char *dbc_name;
+  uint32_t size;
+  size_t signal_count;
+} CanforgeMessageInfo;
+
+// Signal metadata; strings are owned by the database handle.
+typedef struct CanforgeSignalInfo {
+  // Identifier used by the generated code.
+  const char *name;
+  // Name as written in the DBC file.
+  const char *dbc_name;
+  const char *unit;
+  CanforgeValueKind kind;
+  uint32_t bit_len;
+  bool is_signed;
+  double factor;
+  double offset;
+  double min;
+  double max;
+} CanforgeSignalInfo;
+
+// A decoded signal value; integers are exact in `as_u64`/`as_i64`, `as_f64` is always set.
+typedef struct CanforgeValue {
+  // False for multiplexed signals outside the active page or beyond a short payload.
+  bool valid;
+  CanforgeValueKind kind;
+  double as_f64;
+  int64_t as_i64;
+  uint64_t as_u64;
+} CanforgeValue;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Description of the last error on the calling thread; valid until the next failing call.
+const char *canforge_last_error(void);
+
+// Load a DBC file. Returns NULL on error.
+//
+// # Safety
+// `path` must be a valid NUL-terminated string.
+CanforgeDbc *canforge_dbc_open(const char *path);
+
+// Parse DBC text already in memory. Returns NULL on error.
+//
+// # Safety
+// `source` must be a valid NUL-terminated string.
+CanforgeDbc *canforge_dbc_parse(const char *source);
+
+// Release a database handle; NULL is ignored.
+//
+// # Safety
+// `dbc` must come from `canforge_dbc_open`/`canforge_dbc_parse` and not be used afterwards.
+void canforge_dbc_close(CanforgeDbc *dbc);
+
+// Number of messages, sorted by CAN id.
+//
+// # Safety
+// `dbc` must be a valid handle.
+size_t canforge_message_count(const CanforgeDbc *dbc);
+
+// Index of the message with raw `canid`, or -1.
+//
+// # Safety
+// `dbc` must be a valid handle.
+int canforge_message_find(const CanforgeDbc *dbc, uint32_t canid);
+
+// Fill `info` for message `msg_idx`.
+//
+// # Safety
+// `dbc` must be a valid handle and `info` point to writable memory.
+int canforge_message_info(const CanforgeDbc *dbc, size_t msg_idx, CanforgeMessageInfo *info);
+
+// Index of a signal (generated or DBC name) within message `msg_idx`, or -1.
+//
+// # Safety
+// `dbc` must be a valid handle and `name` a valid NUL-terminated string.
+int canforge_signal_find(const CanforgeDbc *dbc, size_t msg_idx, const char *name);
+
+// Fill `info` for signal `sig_idx` of message `msg_idx`.
+//
+// # Safety
+// `dbc` must be a valid handle and `info` point to writable memory.
+int canforge_signal_info(const CanforgeDbc *dbc,
+                         size_t msg_idx,
+                         size_t sig_idx,
+                         CanforgeSignalInfo *info);
+
+// Decode one signal of a frame into `value`.
+//
+// # Safety
+// `dbc` must be a valid handle, `data` point to `len` readable bytes and `value` to
+// writable memory.
+int canforge_decode_signal(const CanforgeDbc *dbc,
+                           size_t msg_idx,
+                           size_t sig_idx,
+                           const uint8_t *data,
+                           size_t len,
+                           CanforgeValue *value);
+
+// Decode every signal of the frame with raw `canid` into `values` (signal order).
+// Returns the number of entries written, at most `values_len`.
+//
+// # Safety
+// `dbc` must be a valid handle, `data` point to `len` readable bytes and `values` to
+// `values_len` writable entries.
+int canforge_decode_frame(const CanforgeDbc *dbc,
+                          uint32_t canid,
+                          const uint8_t *data,
+                          size_t len,
+                          CanforgeValue *values,
+                          size_t values_len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* CANFORGE_H */
@@ -0,0 +1,41 @@
+#ifndef CANFORGE_PLUGIN_H
+#define CANFORGE_PLUGIN_H
+
+/*
+ * Entry points of a decoder generated with `dbcparser-cli --plugin-abi` and built as a
+ * cdylib. Hosts resolve them with dlsym() and must check canforge_plugin_abi_version()
+ * against CANFORGE_PLUGIN_ABI_VERSION before any other call.
+ */
+
+#include <stddef.h>
+#include <stdint.h>
+
+#define CANFORGE_PLUGIN_ABI_VERSION 1
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// ABI revision implemented by the plugin.
+uint32_t canforge_plugin_abi_version(void);
+
+// Pool uid given to the generator (--uid).
+const char *canforge_plugin_uid(void);
+
+// Create a message pool, release it with canforge_plugin_release().
+void *canforge_plugin_init(void);
+
+void canforge_plugin_release(void *pool);
+
+// Update the pool with one received frame, returns the signal listener count or -1 (also for
+// a payload over the 64 bytes of CAN FD).
+int32_t canforge_plugin_decode(void *pool, uint32_t canid, uint64_t stamp, const uint8_t *data, size_t len);
+
+// Read the last value of a signal (generated name) as double, returns 0 or -1.
+int32_t canforge_plugin_query(void *pool, uint32_t canid, const char *signal, double *value);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* CANFORGE_PLUGIN_H */
@@ -0,0 +1,62 @@
+#ifndef CANFORGE_SHM_H
+#define CANFORGE_SHM_H
+
+/*
+ * Layout of the shared segment written by canforge-runtime ShmExporter. Map the file
+ * read-only (open + mmap with PROT_READ, MAP_SHARED), check the header, then read slots with
+ * canforge_shm_read(), which retries while the writer updates the slot. All fields are
+ * little endian.
+ */
+
+#include <stdint.h>
+
+#define CANFORGE_SHM_MAGIC 0x48534643u  // "CFSH"
+#define CANFORGE_SHM_VERSION 1
+#define CANFORGE_SHM_NAME_LEN 32
+
+#define CANFORGE_SHM_UNSET 0
+#define CANFORGE_SHM_VALID 1
+
+typedef struct {
+    uint32_t magic;
+    uint32_t version;
+    uint32_t slot_count;
+    uint32_t slot_size;    // 64
+    uint32_t header_size;  // 64, first slot offset
+    uint8_t reserved[44];
+} canforge_shm_header_t;
+
+typedef struct {
+    uint32_t sequence;     // odd while the writer updates the slot
+    uint32_t status;       // CANFORGE_SHM_UNSET or CANFORGE_SHM_VALID
+    double value;
+    uint64_t stamp;        // microseconds
+    uint32_t canid;        // raw DBC id, bit 31 flags extended ids
+    uint8_t reserved[4];
+    char name[CANFORGE_SHM_NAME_LEN];  // "Message.Signal", NUL terminated
+} canforge_shm_slot_t;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Consistent copy of one slot value, returns its status.
+static inline uint32_t canforge_shm_read(const canforge_shm_slot_t *slot, double *value, uint64_t *stamp) {
+    uint32_t before, after, status;
+    do {
+        before = __atomic_load_n(&slot->sequence, __ATOMIC_ACQUIRE);
+        if (before & 1) continue;
+        status = slot->status;
+        *value = slot->value;
+        *stamp = slot->stamp;
+        __atomic_thread_fence(__ATOMIC_ACQUIRE);
+        after = __atomic_load_n(&slot->sequence, __ATOMIC_RELAXED);
+    } while ((before & 1) || before != after);
+    return status;
+}
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* CANFORGE_SHM_H */
@@ -0,0 +1,445 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! C API over the runtime DBC decoder.
+//!
+//! Messages and signals are addressed by index (as listed by the `*_info` functions) so C
+//! callers never hold Rust references. Functions returning `int` give `0` (or a count/index)
+//! on success and `-1` on error; `canforge_last_error()` then describes the failure.
+//! The matching header is `include/canforge.h`, generated by cbindgen (see `cbindgen.toml`).
+
+#![doc(
+    html_logo_url = "https://iot.bzh/images/defaults/company/512-479-max-transp.png",
+    html_favicon_url = "https://iot.bzh/images/defaults/favicon.ico"
+)]
+
+use canforge_runtime::prelude::*;
+use std::cell::RefCell;
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::ptr;
+use std::slice;
+
+thread_local! {
+    static LAST_ERROR: RefCell<CString> = RefCell::new(CString::default());
+}
+
+fn set_error(info: impl ToString) -> c_int {
+    let info = CString::new(info.to_string().replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = info);
+    -1
+}
+
+fn c_string(text: &str) -> CString {
+    CString::new(text.replace('\0', " ")).unwrap_or_default()
+}
+
+/// Type of a decoded value, mirrors the generated `CanDbcType` variants.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CanforgeValueKind {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    F64,
+}
+
+impl From<SignalKind> for CanforgeValueKind {
+    fn from(kind: SignalKind) -> Self {
+        match kind {
+            SignalKind::Bool => CanforgeValueKind::Bool,
+            SignalKind::U8 => CanforgeValueKind::U8,
+            SignalKind::U16 => CanforgeValueKind::U16,
+            SignalKind::U32 => CanforgeValueKind::U32,
+            SignalKind::U64 => CanforgeValueKind::U64,
+            SignalKind::I8 => CanforgeValueKind::I8,
+            SignalKind::I16 => CanforgeValueKind::I16,
+            SignalKind::I32 => CanforgeValueKind::I32,
+            SignalKind::I64 => CanforgeValueKind::I64,
+            SignalKind::F64 | SignalKind::F32 | SignalKind::F64Bits => CanforgeValueKind::F64,
+        }
+    }
+}
+
+/// A decoded signal value; integers are exact in `as_u64`/`as_i64`, `as_f64` is always set.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CanforgeValue {
+    /// False for multiplexed signals outside the active page or beyond a short payload.
+    pub valid: bool,
+    pub kind: CanforgeValueKind,
+    pub as_f64: f64,
+    pub as_i64: i64,
+    pub as_u64: u64,
+}
+
+impl CanforgeValue {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+    fn new(kind: SignalKind, value: Option<SignalValue>) -> Self {
+        let (as_i64, as_u64) = match value {
+            Some(SignalValue::Bool(v)) => (i64::from(v), u64::from(v)),
+            Some(SignalValue::U8(v)) => (i64::from(v), u64::from(v)),
+            Some(SignalValue::U16(v)) => (i64::from(v), u64::from(v)),
+            Some(SignalValue::U32(v)) => (i64::from(v), u64::from(v)),
+            Some(SignalValue::U64(v)) => (v as i64, v),
+            Some(SignalValue::I8(v)) => (i64::from(v), v as u64),
+            Some(SignalValue::I16(v)) => (i64::from(v), v as u64),
+            Some(SignalValue::I32(v)) => (i64::from(v), v as u64),
+            Some(SignalValue::I64(v)) => (v, v as u64),
+            Some(SignalValue::F64(v)) => (v as i64, v as u64),
+            None => (0, 0),
+        };
+        CanforgeValue {
+            valid: value.is_some(),
+            kind: kind.into(),
+            as_f64: value.map_or(0.0, SignalValue::as_f64),
+            as_i64,
+            as_u64,
+        }
+    }
+}
+
+/// Message metadata; strings are owned by the database handle.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CanforgeMessageInfo {
+    /// Raw DBC id (bit 31 set for extended frames).
+    pub id: u32,
+    /// Identifier used by the generated code.
+    pub name: *const c_char,
+    /// Name as written in the DBC file.
+    pub dbc_name: *const c_char,
+    pub size: u32,
+    pub signal_count: usize,
+}
+
+/// Signal metadata; strings are owned by the database handle.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CanforgeSignalInfo {
+    /// Identifier used by the generated code.
+    pub name: *const c_char,
+    /// Name as written in the DBC file.
+    pub dbc_name: *const c_char,
+    pub unit: *const c_char,
+    pub kind: CanforgeValueKind,
+    pub bit_len: u32,
+    pub is_signed: bool,
+    pub factor: f64,
+    pub offset: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+struct SignalStrings {
+    name: CString,
+    dbc_name: CString,
+    unit: CString,
+}
+
+struct MessageStrings {
+    name: CString,
+    dbc_name: CString,
+    signals: Vec<SignalStrings>,
+}
+
+/// Opaque database handle.
+pub struct CanforgeDbc {
+    dbc: RuntimeDbc,
+    strings: Vec<MessageStrings>,
+}
+
+impl CanforgeDbc {
+    fn new(dbc: RuntimeDbc) -> Box<Self> {
+        let strings = dbc
+            .get_messages()
+            .iter()
+            .map(|msg| MessageStrings {
+                name: c_string(msg.name),
+                dbc_name: c_string(&msg.dbc_name),
+                signals: msg
+                    .signals
+                    .iter()
+                    .map(|sig| SignalStrings {
+                        name: c_string(sig.name),
+                        dbc_name: c_string(&sig.dbc_name),
+                        unit: c_string(&sig.unit),
+                    })
+                    .collect(),
+            })
+            .collect();
+        Box::new(CanforgeDbc { dbc, strings })
+    }
+
+    fn message(&self, msg_idx: usize) -> Result<&MessageDef, String> {
+        self.dbc
+            .get_messages()
+            .get(msg_idx)
+            .map(AsRef::as_ref)
+            .ok_or_else(|| format!("message index:{msg_idx} out of range"))
+    }
+}
+
+unsafe fn as_str<'a>(text: *const c_char) -> Result<&'a str, String> {
+    if text.is_null() {
+        return Err("null string".to_owned());
+    }
+    CStr::from_ptr(text).to_str().map_err(|error| error.to_string())
+}
+
+unsafe fn as_data<'a>(data: *const u8, len: usize) -> &'a [u8] {
+    if data.is_null() || len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(data, len)
+    }
+}
+
+fn index(idx: usize) -> c_int {
+    c_int::try_from(idx).unwrap_or(c_int::MAX)
+}
+
+/// Description of the last error on the calling thread; valid until the next failing call.
+#[no_mangle]
+pub extern "C" fn canforge_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ptr())
+}
+
+/// Load a DBC file. Returns NULL on error.
+///
+/// # Safety
+/// `path` must be a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn canforge_dbc_open(path: *const c_char) -> *mut CanforgeDbc {
+    match as_str(path).and_then(|path| RuntimeDbc::from_file(path).map_err(|e| e.to_string())) {
+        Ok(dbc) => Box::into_raw(CanforgeDbc::new(dbc)),
+        Err(error) => {
+            set_error(error);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// Parse DBC text already in memory. Returns NULL on error.
+///
+/// # Safety
+/// `source` must be a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn canforge_dbc_parse(source: *const c_char) -> *mut CanforgeDbc {
+    match as_str(source)
+        .and_then(|source| RuntimeDbc::from_source(source).map_err(|e| e.to_string()))
+    {
+        Ok(dbc) => Box::into_raw(CanforgeDbc::new(dbc)),
+        Err(error) => {
+            set_error(error);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// Release a database handle; NULL is ignored.
+///
+/// # Safety
+/// `dbc` must come from `canforge_dbc_open`/`canforge_dbc_parse` and not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn canforge_dbc_close(dbc: *mut CanforgeDbc) {
+    if !dbc.is_null() {
+        drop(Box::from_raw(dbc));
+    }
+}
+
+/// Number of messages, sorted by CAN id.
+///
+/// # Safety
+/// `dbc` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn canforge_message_count(dbc: *const CanforgeDbc) -> usize {
+    dbc.as_ref().map_or(0, |dbc| dbc.dbc.get_messages().len())
+}
+
+/// Index of the message with raw `canid`, or -1.
+///
+/// # Safety
+/// `dbc` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn canforge_message_find(dbc: *const CanforgeDbc, canid: u32) -> c_int {
+    let Some(dbc) = dbc.as_ref() else {
+        return set_error("null dbc handle");
+    };
+    match dbc.dbc.get_messages().binary_search_by_key(&canid, |msg| msg.id) {
+        Ok(idx) => index(idx),
+        Err(_) => set_error(format!("canid:{canid} not found")),
+    }
+}
+
+/// Fill `info` for message `msg_idx`.
+///
+/// # Safety
+/// `dbc` must be a valid handle and `info` point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn canforge_message_info(
+    dbc: *const CanforgeDbc,
+    msg_idx: usize,
+    info: *mut CanforgeMessageInfo,
+) -> c_int {
+    let (Some(dbc), Some(info)) = (dbc.as_ref(), info.as_mut()) else {
+        return set_error("null argument");
+    };
+    match dbc.message(msg_idx) {
+        Ok(msg) => {
+            let strings = &dbc.strings[msg_idx];
+            *info = CanforgeMessageInfo {
+                id: msg.id,
+                name: strings.name.as_ptr(),
+                dbc_name: strings.dbc_name.as_ptr(),
+                size: u32::try_from(msg.size).unwrap_or(u32::MAX),
+                signal_count: msg.signals.len(),
+            };
+            0
+        },
+        Err(error) => set_error(error),
+    }
+}
+
+/// Index of a signal (generated or DBC name) within message `msg_idx`, or -1.
+///
+/// # Safety
+/// `dbc` must be a valid handle and `name` a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn canforge_signal_find(
+    dbc: *const CanforgeDbc,
+    msg_idx: usize,
+    name: *const c_char,
+) -> c_int {
+    let Some(dbc) = dbc.as_ref() else {
+        return set_error("null dbc handle");
+    };
+    let name = match as_str(name) {
+        Ok(name) => name,
+        Err(error) => return set_error(error),
+    };
+    match dbc.message(msg_idx) {
+        Ok(msg) => match msg.signals.iter().position(|s| s.name == name || s.dbc_name == name) {
+            Some(idx) => index(idx),
+            None => set_error(format!("signal:{name} not in message:{}", msg.name)),
+        },
+        Err(error) => set_error(error),
+    }
+}
+
+/// Fill `info` for signal `sig_idx` of message `msg_idx`.
+///
+/// # Safety
+/// `dbc` must be a valid handle and `info` point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn canforge_signal_info(
+    dbc: *const CanforgeDbc,
+    msg_idx: usize,
+    sig_idx: usize,
+    info: *mut CanforgeSignalInfo,
+) -> c_int {
+    let (Some(dbc), Some(info)) = (dbc.as_ref(), info.as_mut()) else {
+        return set_error("null argument");
+    };
+    let msg = match dbc.message(msg_idx) {
+        Ok(msg) => msg,
+        Err(error) => return set_error(error),
+    };
+    let Some(sig) = msg.signals.get(sig_idx) else {
+        return set_error(format!("signal index:{sig_idx} out of range"));
+    };
+    let strings = &dbc.strings[msg_idx].signals[sig_idx];
+    *info = CanforgeSignalInfo {
+        name: strings.name.as_ptr(),
+        dbc_name: strings.dbc_name.as_ptr(),
+        unit: strings.unit.as_ptr(),
+        kind: sig.kind.into(),
+        bit_len: sig.layout.bit_len(),
+        is_signed: sig.layout.signed,
+        factor: sig.factor,
+        offset: sig.offset,
+        min: sig.min,
+        max: sig.max,
+    };
+    0
+}
+
+/// Decode one signal of a frame into `value`.
+///
+/// # Safety
+/// `dbc` must be a valid handle, `data` point to `len` readable bytes and `value` to
+/// writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn canforge_decode_signal(
+    dbc: *const CanforgeDbc,
+    msg_idx: usize,
+    sig_idx: usize,
+    data: *const u8,
+    len: usize,
+    value: *mut CanforgeValue,
+) -> c_int {
+    let (Some(dbc), Some(value)) = (dbc.as_ref(), value.as_mut()) else {
+        return set_error("null argument");
+    };
+    let msg = match dbc.message(msg_idx) {
+        Ok(msg) => msg,
+        Err(error) => return set_error(error),
+    };
+    let Some(sig) = msg.signals.get(sig_idx) else {
+        return set_error(format!("signal index:{sig_idx} out of range"));
+    };
+    let decoded = msg.decode(as_data(data, len));
+    *value = CanforgeValue::new(sig.kind, decoded[sig_idx]);
+    0
+}
+
+/// Decode every signal of the frame with raw `canid` into `values` (signal order).
+/// Returns the number of entries written, at most `values_len`.
+///
+/// # Safety
+/// `dbc` must be a valid handle, `data` point to `len` readable bytes and `values` to
+/// `values_len` writable entries.
+#[no_mangle]
+pub unsafe extern "C" fn canforge_decode_frame(
+    dbc: *const CanforgeDbc,
+    canid: u32,
+    data: *const u8,
+    len: usize,
+    values: *mut CanforgeValue,
+    values_len: usize,
+) -> c_int {
+    let Some(dbc) = dbc.as_ref() else {
+        return set_error("null dbc handle");
+    };
+    if values.is_null() && values_len > 0 {
+        return set_error("null values buffer");
+    }
+    let Some(msg) = dbc.dbc.get_message(canid) else {
+        return set_error(format!("canid:{canid} not found"));
+    };
+    let decoded = msg.decode(as_data(data, len));
+    let count = decoded.len().min(values_len);
+    for (idx, (sig, value)) in msg.signals.iter().zip(decoded).take(count).enumerate() {
+        values.add(idx).write(CanforgeValue::new(sig.kind, value));
+    }
+    index(count)
+}
@@ -0,0 +1,56 @@
+use canforge_ffi::*;
+use std::ffi::{CStr, CString};
+
+const DBC: &str = r#"VERSION "1.0"
+NS_ :
+BU_: ECU
+BO_ 512 Mux_Frame: 8 ECU
+ SG_ page M : 0|4@1+ (1,0) [0|15] "" ECU
+ SG_ speed m0 : 8|8@1+ (0.5,0) [0|127] "km/h" ECU
+ SG_ temp m1 : 8|8@1- (1,0) [-128|127] "degC" ECU
+"#;
+
+#[test]
+fn decode_through_c_api() {
+    let source = CString::new(DBC).unwrap();
+    unsafe {
+        let dbc = canforge_dbc_parse(source.as_ptr());
+        assert!(!dbc.is_null());
+        assert_eq!(canforge_message_count(dbc), 1);
+        assert_eq!(canforge_message_find(dbc, 512), 0);
+        assert_eq!(canforge_message_find(dbc, 513), -1);
+        assert_eq!(CStr::from_ptr(canforge_last_error()).to_str().unwrap(), "canid:513 not found");
+
+        let mut msg = std::mem::zeroed::<CanforgeMessageInfo>();
+        assert_eq!(canforge_message_info(dbc, 0, &mut msg), 0);
+        assert_eq!(CStr::from_ptr(msg.name).to_str().unwrap(), "MuxFrame");
+        assert_eq!(msg.signal_count, 3);
+
+        let name = CString::new("speed").unwrap();
+        let speed = canforge_signal_find(dbc, 0, name.as_ptr());
+        assert_eq!(speed, 1);
+        let mut sig = std::mem::zeroed::<CanforgeSignalInfo>();
+        assert_eq!(canforge_signal_info(dbc, 0, 1, &mut sig), 0);
+        assert_eq!(CStr::from_ptr(sig.unit).to_str().unwrap(), "km/h");
+        assert_eq!(sig.kind, CanforgeValueKind::F64);
+
+        // page 1 active: speed is not valid, temp is
+        let data = [0x01u8, 0xFE, 0, 0, 0, 0, 0, 0];
+        let mut values = [std::mem::zeroed::<CanforgeValue>(); 3];
+        assert_eq!(
+            canforge_decode_frame(dbc, 512, data.as_ptr(), data.len(), values.as_mut_ptr(), 3),
+            3
+        );
+        assert!(values[0].valid && values[0].as_u64 == 1);
+        assert!(!values[1].valid);
+        assert!(values[2].valid && values[2].as_i64 == -2);
+
+        let mut value = std::mem::zeroed::<CanforgeValue>();
+        let data = [0x00u8, 0x20, 0, 0, 0, 0, 0, 0];
+        assert_eq!(canforge_decode_signal(dbc, 0, 1, data.as_ptr(), data.len(), &mut value), 0);
+        assert!(value.valid);
+        assert!((value.as_f64 - 16.0).abs() < f64::EPSILON);
+
+        canforge_dbc_close(dbc);
+    }
+}
@@ -0,0 +1,268 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Windowed aggregation (min/max/mean/last) used to downsample high-rate signals between the
+//! pool and export sinks.
+//!
+//! Windows are tumbling and aligned on multiples of their length; stamps are in microseconds
+//! like `CanMsgData::stamp`.
+//!
+//! ```yaml
+//! rules:
+//!   - signal: "BatteryStatus.*"
+//!     window_ms: 1000
+//!     ops: [min, max, mean]
+//!   - signal: "VehicleSpeed"
+//!     window_ms: 500
+//!     ops: [last]
+//! ```
+
+use crate::database::{signal_matches, MessageDef, SignalDef};
+use crate::layout::SignalValue;
+use dbcparser::clock::{Clock, SystemClock};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{self, Error};
+use std::sync::Arc;
+
+/// Aggregation operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AggregateOp {
+    Min,
+    Max,
+    Mean,
+    Last,
+    Count,
+}
+
+/// One aggregation rule.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AggregateRule {
+    /// `Signal`, `Message.Signal` or `Message.*`; generated or DBC names, `*` matches all.
+    pub signal: String,
+    pub window_ms: u64,
+    pub ops: Vec<AggregateOp>,
+}
+
+/// Aggregation configuration, usually loaded from YAML.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AggregateConfig {
+    #[serde(default)]
+    pub rules: Vec<AggregateRule>,
+}
+
+/// A closed window, only the configured operators are set.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Aggregate {
+    pub canid: u32,
+    pub name: &'static str,
+    /// Window bounds in microseconds, `start` included and `end` excluded.
+    pub start: u64,
+    pub end: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mean: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last: Option<SignalValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+}
+
+/// Result of feeding one sample to the [`Aggregator`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Sample {
+    /// No rule applies, export the value as is.
+    Passthrough(SignalValue),
+    /// Accumulated in the current window.
+    Pending,
+    /// The sample opened a new window and closed the previous one.
+    Window(Aggregate),
+}
+
+struct Window {
+    sig: Arc<SignalDef>,
+    canid: u32,
+    length: u64,
+    ops: Vec<AggregateOp>,
+    start: u64,
+    count: u64,
+    min: f64,
+    max: f64,
+    sum: f64,
+    last: Option<SignalValue>,
+}
+
+impl Window {
+    fn open(&mut self, stamp: u64) {
+        self.start = stamp - stamp % self.length;
+        self.count = 0;
+        self.min = f64::INFINITY;
+        self.max = f64::NEG_INFINITY;
+        self.sum = 0.0;
+        self.last = None;
+    }
+
+    fn add(&mut self, value: SignalValue) {
+        let value_f = value.as_f64();
+        self.count += 1;
+        self.min = self.min.min(value_f);
+        self.max = self.max.max(value_f);
+        self.sum += value_f;
+        self.last = Some(value);
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn close(&self) -> Option<Aggregate> {
+        if self.count == 0 {
+            return None;
+        }
+        let has = |op| self.ops.contains(&op);
+        Some(Aggregate {
+            canid: self.canid,
+            name: self.sig.name,
+            start: self.start,
+            end: self.start + self.length,
+            min: has(AggregateOp::Min).then_some(self.min),
+            max: has(AggregateOp::Max).then_some(self.max),
+            mean: has(AggregateOp::Mean).then_some(self.sum / self.count as f64),
+            last: if has(AggregateOp::Last) { self.last } else { None },
+            count: has(AggregateOp::Count).then_some(self.count),
+        })
+    }
+}
+
+/// Stateful aggregator, one window per (message, signal) matched by a rule.
+#[derive(Default)]
+pub struct Aggregator {
+    config: AggregateConfig,
+    windows: HashMap<(u32, &'static str), Option<Window>>,
+    /// System clock when unset.
+    clock: Option<Box<dyn Clock>>,
+}
+
+impl Aggregator {
+    /// # Errors
+    /// Returns an error when a rule has a null window or no operator.
+    pub fn new(config: AggregateConfig) -> io::Result<Self> {
+        for rule in &config.rules {
+            if rule.window_ms == 0 || rule.ops.is_empty() {
+                return Err(Error::other(format!(
+                    "aggregate rule:{} needs window_ms > 0 and at least one op",
+                    rule.signal
+                )));
+            }
+        }
+        Ok(Aggregator { config, windows: HashMap::new(), clock: None })
+    }
+
+    /// Load a configuration from YAML text.
+    ///
+    /// # Errors
+    /// Returns an error when the YAML is invalid or a rule is inconsistent.
+    pub fn from_yaml(yaml: &str) -> io::Result<Self> {
+        let config: AggregateConfig = serde_yaml::from_str(yaml).map_err(Error::other)?;
+        Aggregator::new(config)
+    }
+
+    /// Time source of [`Aggregator::flush_now`], the system clock by default.
+    pub fn set_clock(&mut self, clock: Box<dyn Clock>) {
+        self.clock = Some(clock);
+    }
+
+    #[must_use]
+    pub fn get_config(&self) -> &AggregateConfig {
+        &self.config
+    }
+
+    fn window(&mut self, msg: &MessageDef, sig: &Arc<SignalDef>) -> Option<&mut Window> {
+        let config = &self.config;
+        self.windows
+            .entry((msg.id, sig.name))
+            .or_insert_with(|| {
+                let rule =
+                    config.rules.iter().find(|rule| signal_matches(&rule.signal, msg, sig))?;
+                let mut window = Window {
+                    sig: Arc::clone(sig),
+                    canid: msg.id,
+                    length: rule.window_ms * 1000,
+                    ops: rule.ops.clone(),
+                    start: 0,
+                    count: 0,
+                    min: 0.0,
+                    max: 0.0,
+                    sum: 0.0,
+                    last: None,
+                };
+                window.open(0);
+                Some(window)
+            })
+            .as_mut()
+    }
+
+    /// Feed one decoded sample, `stamp` in microseconds.
+    pub fn push(
+        &mut self,
+        msg: &MessageDef,
+        sig: &Arc<SignalDef>,
+        stamp: u64,
+        value: SignalValue,
+    ) -> Sample {
+        let Some(window) = self.window(msg, sig) else {
+            return Sample::Passthrough(value);
+        };
+        let closed = if stamp >= window.start + window.length || window.count == 0 {
+            let closed = window.close();
+            window.open(stamp);
+            closed
+        } else {
+            None
+        };
+        window.add(value);
+        match closed {
+            Some(aggregate) => Sample::Window(aggregate),
+            None => Sample::Pending,
+        }
+    }
+
+    /// Close every window that ended before `now` (microseconds), for signals that stopped
+    /// being received.
+    pub fn flush(&mut self, now: u64) -> Vec<Aggregate> {
+        let mut closed: Vec<Aggregate> = self
+            .windows
+            .values_mut()
+            .flatten()
+            .filter(|window| window.count > 0 && window.start + window.length <= now)
+            .filter_map(|window| {
+                let aggregate = window.close();
+                window.count = 0;
+                aggregate
+            })
+            .collect();
+        closed.sort_by_key(|aggregate| (aggregate.start, aggregate.canid));
+        closed
+    }
+
+    /// Close every window that ended before the current time of the aggregator clock.
+    pub fn flush_now(&mut self) -> Vec<Aggregate> {
+        let now = self.clock.as_deref().map_or_else(|| SystemClock.now_us(), Clock::now_us);
+        self.flush(now)
+    }
+}
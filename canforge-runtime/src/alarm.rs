@@ -0,0 +1,264 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Declarative threshold alarms with debounce.
+//!
+//! An alarm is raised once its condition held for `debounce_ms`, and cleared once it has been
+//! false for the same duration. Stamps are in microseconds like `CanMsgData::stamp`; as BCM
+//! only reports changes, call [`AlarmEngine::tick`] periodically so a steady value still trips.
+//! [`AlarmEngine::tick_now`] reads the time from the engine [`Clock`], which replays can pin.
+//!
+//! ```yaml
+//! alarms:
+//!   - name: pack-overheat
+//!     signal: "BatteryStatus.PackTemp"
+//!     compare: ">"
+//!     threshold: 60
+//!     debounce_ms: 5000
+//! ```
+
+use crate::database::{signal_matches, MessageDef, SignalDef};
+use crate::layout::SignalValue;
+use dbcparser::clock::{Clock, SystemClock};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Error};
+use std::sync::mpsc::{self, Receiver, Sender};
+
+/// Comparison between the physical value and the threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Compare {
+    #[serde(rename = "gt", alias = ">")]
+    Greater,
+    #[serde(rename = "ge", alias = ">=")]
+    GreaterOrEqual,
+    #[serde(rename = "lt", alias = "<")]
+    Less,
+    #[serde(rename = "le", alias = "<=")]
+    LessOrEqual,
+    #[serde(rename = "eq", alias = "==")]
+    Equal,
+    #[serde(rename = "ne", alias = "!=")]
+    NotEqual,
+}
+
+impl Compare {
+    #[must_use]
+    #[allow(clippy::float_cmp)]
+    pub fn eval(self, value: f64, threshold: f64) -> bool {
+        match self {
+            Compare::Greater => value > threshold,
+            Compare::GreaterOrEqual => value >= threshold,
+            Compare::Less => value < threshold,
+            Compare::LessOrEqual => value <= threshold,
+            Compare::Equal => value == threshold,
+            Compare::NotEqual => value != threshold,
+        }
+    }
+}
+
+/// One alarm definition.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AlarmRule {
+    pub name: String,
+    /// `Signal` or `Message.Signal`, generated or DBC names.
+    pub signal: String,
+    pub compare: Compare,
+    pub threshold: f64,
+    #[serde(default)]
+    pub debounce_ms: u64,
+}
+
+/// Alarm configuration, usually loaded from YAML.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct AlarmConfig {
+    #[serde(default)]
+    pub alarms: Vec<AlarmRule>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlarmState {
+    Raised,
+    Cleared,
+}
+
+/// Event emitted when an alarm changes state.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AlarmEvent {
+    pub name: String,
+    pub canid: u32,
+    pub signal: &'static str,
+    pub state: AlarmState,
+    /// Microseconds, when the debounce elapsed.
+    pub stamp: u64,
+    /// Last value seen for the signal.
+    pub value: SignalValue,
+}
+
+/// Callback invoked on every alarm transition.
+pub type AlarmCallback = Box<dyn FnMut(&AlarmEvent)>;
+
+#[derive(Default)]
+struct AlarmStatus {
+    active: bool,
+    /// Stamp of the first sample disagreeing with `active`.
+    pending_since: Option<u64>,
+    last: Option<(u32, &'static str, SignalValue)>,
+}
+
+/// Evaluates [`AlarmRule`]s and reports transitions to callbacks and channels.
+#[derive(Default)]
+pub struct AlarmEngine {
+    rules: Vec<AlarmRule>,
+    status: Vec<AlarmStatus>,
+    callbacks: Vec<AlarmCallback>,
+    channels: Vec<Sender<AlarmEvent>>,
+    /// System clock when unset.
+    clock: Option<Box<dyn Clock>>,
+}
+
+impl AlarmEngine {
+    /// # Errors
+    /// Returns an error when two alarms share a name or a threshold is not a number.
+    pub fn new(config: AlarmConfig) -> io::Result<Self> {
+        for (idx, rule) in config.alarms.iter().enumerate() {
+            if rule.threshold.is_nan() {
+                return Err(Error::other(format!("alarm:{} threshold is NaN", rule.name)));
+            }
+            if config.alarms[..idx].iter().any(|other| other.name == rule.name) {
+                return Err(Error::other(format!("alarm:{} defined twice", rule.name)));
+            }
+        }
+        let status = config.alarms.iter().map(|_| AlarmStatus::default()).collect();
+        Ok(AlarmEngine {
+            rules: config.alarms,
+            status,
+            callbacks: Vec::new(),
+            channels: Vec::new(),
+            clock: None,
+        })
+    }
+
+    /// Load a configuration from YAML text.
+    ///
+    /// # Errors
+    /// Returns an error when the YAML is invalid or a rule is inconsistent.
+    pub fn from_yaml(yaml: &str) -> io::Result<Self> {
+        let config: AlarmConfig = serde_yaml::from_str(yaml).map_err(Error::other)?;
+        AlarmEngine::new(config)
+    }
+
+    /// Call `callback` on every transition.
+    pub fn set_callback(&mut self, callback: AlarmCallback) {
+        self.callbacks.push(callback);
+    }
+
+    /// Time source of [`AlarmEngine::tick_now`], the system clock by default.
+    pub fn set_clock(&mut self, clock: Box<dyn Clock>) {
+        self.clock = Some(clock);
+    }
+
+    /// Open a channel receiving every transition, e.g. for another thread.
+    pub fn subscribe(&mut self) -> Receiver<AlarmEvent> {
+        let (sender, receiver) = mpsc::channel();
+        self.channels.push(sender);
+        receiver
+    }
+
+    /// Names of the alarms currently raised.
+    #[must_use]
+    pub fn active(&self) -> Vec<&str> {
+        self.rules
+            .iter()
+            .zip(&self.status)
+            .filter(|(_, status)| status.active)
+            .map(|(rule, _)| rule.name.as_str())
+            .collect()
+    }
+
+    /// Feed one decoded sample, `stamp` in microseconds. Returns the transitions it caused.
+    pub fn push(
+        &mut self,
+        msg: &MessageDef,
+        sig: &SignalDef,
+        stamp: u64,
+        value: SignalValue,
+    ) -> Vec<AlarmEvent> {
+        let mut events = Vec::new();
+        for idx in 0..self.rules.len() {
+            let rule = &self.rules[idx];
+            if !signal_matches(&rule.signal, msg, sig) {
+                continue;
+            }
+            let tripped = rule.compare.eval(value.as_f64(), rule.threshold);
+            let status = &mut self.status[idx];
+            status.last = Some((msg.id, sig.name, value));
+            if tripped == status.active {
+                status.pending_since = None;
+            } else if status.pending_since.is_none() {
+                status.pending_since = Some(stamp);
+            }
+            events.extend(self.settle(idx, stamp));
+        }
+        self.dispatch(&events);
+        events
+    }
+
+    /// Re-evaluate debounces at `now` (microseconds) without a new sample.
+    pub fn tick(&mut self, now: u64) -> Vec<AlarmEvent> {
+        let events: Vec<AlarmEvent> =
+            (0..self.rules.len()).filter_map(|idx| self.settle(idx, now)).collect();
+        self.dispatch(&events);
+        events
+    }
+
+    /// Re-evaluate debounces at the current time of the engine clock.
+    pub fn tick_now(&mut self) -> Vec<AlarmEvent> {
+        let now = self.clock.as_deref().map_or_else(|| SystemClock.now_us(), Clock::now_us);
+        self.tick(now)
+    }
+
+    fn settle(&mut self, idx: usize, now: u64) -> Option<AlarmEvent> {
+        let rule = &self.rules[idx];
+        let status = &mut self.status[idx];
+        let since = status.pending_since?;
+        if now.saturating_sub(since) < rule.debounce_ms * 1000 {
+            return None;
+        }
+        let (canid, signal, value) = status.last?;
+        status.active = !status.active;
+        status.pending_since = None;
+        Some(AlarmEvent {
+            name: rule.name.clone(),
+            canid,
+            signal,
+            state: if status.active { AlarmState::Raised } else { AlarmState::Cleared },
+            stamp: now,
+            value,
+        })
+    }
+
+    fn dispatch(&mut self, events: &[AlarmEvent]) {
+        for event in events {
+            for callback in &mut self.callbacks {
+                callback(event);
+            }
+            // drop channels whose receiver is gone
+            self.channels.retain(|channel| channel.send(event.clone()).is_ok());
+        }
+    }
+}
@@ -0,0 +1,335 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Several CAN interfaces, each feeding its own pool, kept subscribed across link down/up.
+//!
+//! A [`BusManager`] owns one socket per interface, opened by an application callback that
+//! also registers the pool subscriptions (e.g. `pool.subscribe(&sock, opts)` on a BCM socket).
+//! [`BusManager::poll`] reads the pending frames of every interface into its pool. Link
+//! events, parsed from a `NETLINK_ROUTE` socket bound to `RTMGRP_LINK` with
+//! [`LinkEvent::from_netlink`], close the socket of an interface going down and reopen and
+//! resubscribe it when it comes back. While the link is up, a socket that failed to open or
+//! to read is reopened by the next polls, with an exponential backoff:
+//!
+//! ```no_run
+//! # use canforge_runtime::prelude::*;
+//! # use sockcan::prelude::*;
+//! # struct Sock;
+//! # impl BusSocket for Sock {
+//! #     fn recv(&mut self) -> Result<Option<CanMsgData>, CanError> { Ok(None) }
+//! # }
+//! # fn open_bcm(_iface: &str, _pool: &dyn CanDbcPool) -> Result<Sock, CanError> { Ok(Sock) }
+//! # fn read_netlink() -> Vec<u8> { Vec::new() }
+//! # let (body, chassis) = (RuntimeDbc::from_file("body.dbc").unwrap(), RuntimeDbc::from_file("chassis.dbc").unwrap());
+//! let mut buses = BusManager::new(open_bcm);
+//! buses.add_bus("can0", Box::new(RuntimePool::new("Body", body)));
+//! buses.add_bus("can1", Box::new(RuntimePool::new("Chassis", chassis)));
+//! loop {
+//!     for event in LinkEvent::from_netlink(&read_netlink()) {
+//!         buses.link_event(&event);
+//!     }
+//!     buses.poll();
+//! }
+//! ```
+
+use dbcparser::clock::{Clock, SystemClock};
+use sockcan::prelude::*;
+
+/// First delay before reopening a failed socket while its link is up, doubled on each failure.
+pub const REOPEN_BACKOFF_MIN_US: u64 = 100_000;
+/// Longest delay between two reopen attempts.
+pub const REOPEN_BACKOFF_MAX_US: u64 = 10_000_000;
+
+/// Socket of one interface, non blocking.
+pub trait BusSocket {
+    /// Next frame or BCM notification, `None` when nothing is pending. RAW socket frames go
+    /// through [`crate::frame_msg_data`].
+    ///
+    /// # Errors
+    /// Returns an error when the socket fails, the interface is then considered down.
+    fn recv(&mut self) -> Result<Option<CanMsgData>, CanError>;
+}
+
+/// Opens the socket of an interface and registers the subscriptions of its pool.
+type BusOpener<S> = Box<dyn FnMut(&str, &dyn CanDbcPool) -> Result<S, CanError>>;
+
+/// Link state change of a network interface.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkEvent {
+    pub iface: String,
+    /// Administratively up with a carrier (`IFF_UP` and `IFF_RUNNING`).
+    pub up: bool,
+}
+
+const NLMSG_HDRLEN: usize = 16;
+const IFINFOMSG_LEN: usize = 16;
+const RTM_NEWLINK: u16 = 16;
+const RTM_DELLINK: u16 = 17;
+const IFLA_IFNAME: u16 = 3;
+const IFF_UP: u32 = 0x1;
+const IFF_RUNNING: u32 = 0x40;
+
+fn align4(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+fn ne_u16(buf: &[u8], pos: usize) -> Option<u16> {
+    Some(u16::from_ne_bytes(buf.get(pos..pos + 2)?.try_into().ok()?))
+}
+
+fn ne_u32(buf: &[u8], pos: usize) -> Option<u32> {
+    Some(u32::from_ne_bytes(buf.get(pos..pos + 4)?.try_into().ok()?))
+}
+
+impl LinkEvent {
+    /// Link events of a datagram read from a `NETLINK_ROUTE` socket: one per `RTM_NEWLINK` or
+    /// `RTM_DELLINK` message naming its interface, other messages and truncated data ignored.
+    #[must_use]
+    pub fn from_netlink(buf: &[u8]) -> Vec<LinkEvent> {
+        let mut events = Vec::new();
+        let mut pos = 0;
+        while let (Some(len), Some(kind)) = (ne_u32(buf, pos), ne_u16(buf, pos + 4)) {
+            let len = len as usize;
+            let Some(msg) = buf.get(pos..pos + len).filter(|_| len >= NLMSG_HDRLEN) else {
+                break;
+            };
+            if kind == RTM_NEWLINK || kind == RTM_DELLINK {
+                if let Some(event) = LinkEvent::from_ifinfo(kind, &msg[NLMSG_HDRLEN..]) {
+                    events.push(event);
+                }
+            }
+            pos += align4(len);
+        }
+        events
+    }
+
+    /// Event of an `ifinfomsg` and its attributes.
+    fn from_ifinfo(kind: u16, info: &[u8]) -> Option<LinkEvent> {
+        let flags = ne_u32(info, 8)?;
+        let mut pos = IFINFOMSG_LEN;
+        while let (Some(len), Some(attr)) = (ne_u16(info, pos), ne_u16(info, pos + 2)) {
+            let len = usize::from(len);
+            let data = info.get(pos + 4..pos + len.max(4))?;
+            if attr == IFLA_IFNAME {
+                let name = data.split(|byte| *byte == 0).next().unwrap_or_default();
+                return Some(LinkEvent {
+                    iface: String::from_utf8_lossy(name).into_owned(),
+                    up: kind == RTM_NEWLINK
+                        && flags & (IFF_UP | IFF_RUNNING) == IFF_UP | IFF_RUNNING,
+                });
+            }
+            pos += align4(len.max(4));
+        }
+        None
+    }
+}
+
+/// State of one interface, see [`BusManager::get_statuses`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BusStatus {
+    pub iface: String,
+    /// Link up, as reported by the last link event; assumed up until a link down event.
+    pub link: bool,
+    /// Socket open and subscribed.
+    pub up: bool,
+    /// Successful opens, the first one included.
+    pub opens: u32,
+    /// Frames given to the pool.
+    pub frames: u64,
+    /// Frames the pool rejected, e.g. unknown ids.
+    pub rejected: u64,
+    /// Last open, socket or pool error.
+    pub last_error: Option<String>,
+}
+
+struct Bus<S> {
+    pool: Box<dyn CanDbcPool>,
+    socket: Option<S>,
+    status: BusStatus,
+    /// Socket failures since the last successful open, sets the backoff.
+    failures: u32,
+    /// Clock time of the next reopen attempt while the link is up.
+    retry_at: u64,
+}
+
+impl<S> Bus<S> {
+    /// Close the socket after a failure and schedule the next attempt.
+    fn fail(&mut self, now: u64, error: &CanError) {
+        self.socket = None;
+        self.status.up = false;
+        self.status.last_error = Some(error.to_string());
+        let backoff = REOPEN_BACKOFF_MIN_US
+            .saturating_mul(1 << self.failures.min(16))
+            .min(REOPEN_BACKOFF_MAX_US);
+        self.failures += 1;
+        self.retry_at = now.saturating_add(backoff);
+    }
+}
+
+/// Interfaces and their pools, see the module documentation.
+pub struct BusManager<S: BusSocket> {
+    open: BusOpener<S>,
+    buses: Vec<Bus<S>>,
+    /// System clock when unset.
+    clock: Option<Box<dyn Clock>>,
+}
+
+impl<S: BusSocket> BusManager<S> {
+    /// Manager opening sockets with `open`, called with the interface name and its pool each
+    /// time the interface comes up.
+    pub fn new<F>(open: F) -> Self
+    where
+        F: FnMut(&str, &dyn CanDbcPool) -> Result<S, CanError> + 'static,
+    {
+        BusManager { open: Box::new(open), buses: Vec::new(), clock: None }
+    }
+
+    /// Time source of the reopen backoff, the system clock by default.
+    pub fn set_clock(&mut self, clock: Box<dyn Clock>) {
+        self.clock = Some(clock);
+    }
+
+    fn now(&self) -> u64 {
+        self.clock.as_deref().map_or_else(|| SystemClock.now_us(), Clock::now_us)
+    }
+
+    /// Feed `pool` from `iface`, replacing its previous pool. The socket is opened at once;
+    /// when this fails [`BusManager::poll`] retries it. Returns true when open.
+    pub fn add_bus(&mut self, iface: &str, pool: Box<dyn CanDbcPool>) -> bool {
+        self.buses.retain(|bus| bus.status.iface != iface);
+        self.buses.push(Bus {
+            pool,
+            socket: None,
+            status: BusStatus {
+                iface: iface.to_owned(),
+                link: true,
+                up: false,
+                opens: 0,
+                frames: 0,
+                rejected: 0,
+                last_error: None,
+            },
+            failures: 0,
+            retry_at: 0,
+        });
+        let idx = self.buses.len() - 1;
+        self.reopen(idx)
+    }
+
+    /// Close `iface` and give its pool back.
+    pub fn remove_bus(&mut self, iface: &str) -> Option<Box<dyn CanDbcPool>> {
+        let idx = self.buses.iter().position(|bus| bus.status.iface == iface)?;
+        Some(self.buses.remove(idx).pool)
+    }
+
+    /// Open the socket of bus `idx`, true on success.
+    fn reopen(&mut self, idx: usize) -> bool {
+        let now = self.now();
+        let bus = &mut self.buses[idx];
+        bus.socket = None;
+        match (self.open)(&bus.status.iface, bus.pool.as_ref()) {
+            Ok(socket) => {
+                bus.socket = Some(socket);
+                bus.status.up = true;
+                bus.status.opens += 1;
+                bus.failures = 0;
+            },
+            Err(error) => bus.fail(now, &error),
+        }
+        bus.status.up
+    }
+
+    /// Close the socket of an interface going down, reopen and resubscribe one coming up.
+    /// Returns true when the event changed the state of a managed interface.
+    pub fn link_event(&mut self, event: &LinkEvent) -> bool {
+        let Some(idx) = self.buses.iter().position(|bus| bus.status.iface == event.iface) else {
+            return false;
+        };
+        let bus = &mut self.buses[idx];
+        bus.status.link = event.up;
+        match (event.up, bus.status.up) {
+            (true, false) => {
+                // a link coming up is worth an attempt whatever the backoff
+                bus.failures = 0;
+                self.reopen(idx)
+            },
+            (false, true) => {
+                bus.socket = None;
+                bus.status.up = false;
+                true
+            },
+            _ => false,
+        }
+    }
+
+    /// Give the pending frames of every open interface to its pool, returns how many were
+    /// accepted. A socket error closes the interface; while its link is up, the socket is
+    /// reopened by a later poll, after [`REOPEN_BACKOFF_MIN_US`] doubling on each failure up to
+    /// [`REOPEN_BACKOFF_MAX_US`].
+    pub fn poll(&mut self) -> usize {
+        let now = self.now();
+        let mut accepted = 0;
+        for idx in 0..self.buses.len() {
+            let bus = &self.buses[idx];
+            if bus.socket.is_none() && bus.status.link && now >= bus.retry_at {
+                self.reopen(idx);
+            }
+            let bus = &mut self.buses[idx];
+            let Some(socket) = bus.socket.as_mut() else {
+                continue;
+            };
+            loop {
+                match socket.recv() {
+                    Ok(Some(data)) => {
+                        bus.status.frames += 1;
+                        match bus.pool.update(&data) {
+                            Ok(_) => accepted += 1,
+                            Err(error) => {
+                                bus.status.rejected += 1;
+                                bus.status.last_error = Some(error.to_string());
+                            },
+                        }
+                    },
+                    Ok(None) => break,
+                    Err(error) => {
+                        bus.fail(now, &error);
+                        break;
+                    },
+                }
+            }
+        }
+        accepted
+    }
+
+    /// Pool fed by `iface`.
+    #[must_use]
+    pub fn get_pool(&self, iface: &str) -> Option<&dyn CanDbcPool> {
+        let bus = self.buses.iter().find(|bus| bus.status.iface == iface)?;
+        Some(bus.pool.as_ref())
+    }
+
+    /// Socket of `iface` while it is up, e.g. to send frames.
+    pub fn get_socket(&mut self, iface: &str) -> Option<&mut S> {
+        self.buses.iter_mut().find(|bus| bus.status.iface == iface)?.socket.as_mut()
+    }
+
+    /// State of each interface, in the order they were added.
+    #[must_use]
+    pub fn get_statuses(&self) -> Vec<BusStatus> {
+        self.buses.iter().map(|bus| bus.status.clone()).collect()
+    }
+}
@@ -0,0 +1,163 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Bus load, frame rate and error-frame statistics.
+//!
+//! A [`BusMonitor`] is fed every frame read from a RAW socket (Linux `can_id`, error frames
+//! flagged with `CAN_ERR_FLAG`) and reports, over a sliding window, the share of the bit rate
+//! used by worst-case frame bits (see [`crate::frame_bits`]), frames and error frames per
+//! second. Stamps are in microseconds like `CanMsgData::stamp`.
+//!
+//! ```yaml
+//! bitrate: 500000
+//! window_ms: 1000
+//! ```
+
+use crate::errorframe::CAN_ERR_FLAG;
+use crate::governor::frame_bits;
+use dbcparser::clock::{Clock, SystemClock};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::io::{self, Error};
+
+fn default_window_ms() -> u64 {
+    1000
+}
+
+/// Monitor configuration, usually loaded from YAML.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BusMonitorConfig {
+    /// Bus bit rate in bit/s, 0 leaves the load unset.
+    #[serde(default)]
+    pub bitrate: u64,
+    /// Length of the sliding window rates are computed on.
+    #[serde(default = "default_window_ms")]
+    pub window_ms: u64,
+}
+
+impl Default for BusMonitorConfig {
+    fn default() -> Self {
+        BusMonitorConfig { bitrate: 0, window_ms: default_window_ms() }
+    }
+}
+
+/// Bus statistics, rates over the last window and totals since the monitor was created.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct BusStats {
+    /// Percentage of the bit rate used, `None` without a bit rate.
+    pub load_percent: Option<f64>,
+    pub frames_per_sec: f64,
+    pub errors_per_sec: f64,
+    pub frames: u64,
+    pub errors: u64,
+}
+
+/// Computes [`BusStats`], see the module documentation.
+pub struct BusMonitor {
+    config: BusMonitorConfig,
+    /// Stamp and bus bits of the data frames in the window.
+    frames: VecDeque<(u64, u64)>,
+    /// Stamps of the error frames in the window.
+    errors: VecDeque<u64>,
+    total_frames: u64,
+    total_errors: u64,
+    /// System clock when unset.
+    clock: Option<Box<dyn Clock>>,
+}
+
+impl BusMonitor {
+    /// # Errors
+    /// Returns an error when the window is empty.
+    pub fn new(config: BusMonitorConfig) -> io::Result<Self> {
+        if config.window_ms == 0 {
+            return Err(Error::other("bus monitor window_ms must not be 0"));
+        }
+        Ok(BusMonitor {
+            config,
+            frames: VecDeque::new(),
+            errors: VecDeque::new(),
+            total_frames: 0,
+            total_errors: 0,
+            clock: None,
+        })
+    }
+
+    /// Load a configuration from YAML text.
+    ///
+    /// # Errors
+    /// Returns an error when the YAML is invalid or the window empty.
+    pub fn from_yaml(yaml: &str) -> io::Result<Self> {
+        let config: BusMonitorConfig = serde_yaml::from_str(yaml).map_err(Error::other)?;
+        BusMonitor::new(config)
+    }
+
+    /// Time source of [`BusMonitor::get_stats_now`], the system clock by default.
+    pub fn set_clock(&mut self, clock: Box<dyn Clock>) {
+        self.clock = Some(clock);
+    }
+
+    #[must_use]
+    pub fn get_config(&self) -> &BusMonitorConfig {
+        &self.config
+    }
+
+    /// Record a frame of `len` data bytes with Linux `canid` (extended ids keep bit 31 like
+    /// DBC ids) received at `stamp`.
+    pub fn record(&mut self, canid: u32, len: usize, stamp: u64) {
+        if canid & CAN_ERR_FLAG != 0 {
+            self.total_errors += 1;
+            self.errors.push_back(stamp);
+        } else {
+            self.total_frames += 1;
+            self.frames.push_back((stamp, frame_bits(canid, len)));
+        }
+        self.expire(stamp);
+    }
+
+    /// Drop the frames older than the window ending at `now`.
+    fn expire(&mut self, now: u64) {
+        let start = now.saturating_sub(self.config.window_ms * 1000);
+        while self.frames.front().is_some_and(|(stamp, _)| *stamp < start) {
+            self.frames.pop_front();
+        }
+        while self.errors.front().is_some_and(|stamp| *stamp < start) {
+            self.errors.pop_front();
+        }
+    }
+
+    /// Statistics of the window ending at `now` (microseconds).
+    pub fn get_stats(&mut self, now: u64) -> BusStats {
+        self.expire(now);
+        let window_sec = self.config.window_ms as f64 / 1000.0;
+        let bits: u64 = self.frames.iter().map(|(_, bits)| bits).sum();
+        let load_percent = (self.config.bitrate > 0)
+            .then(|| bits as f64 * 100.0 / (self.config.bitrate as f64 * window_sec));
+        BusStats {
+            load_percent,
+            frames_per_sec: self.frames.len() as f64 / window_sec,
+            errors_per_sec: self.errors.len() as f64 / window_sec,
+            frames: self.total_frames,
+            errors: self.total_errors,
+        }
+    }
+
+    /// [`BusMonitor::get_stats`] at the current time of the monitor clock.
+    pub fn get_stats_now(&mut self) -> BusStats {
+        let now = self.clock.as_deref().map_or_else(|| SystemClock.now_us(), Clock::now_us);
+        self.get_stats(now)
+    }
+}
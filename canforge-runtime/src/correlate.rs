@@ -0,0 +1,234 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Time correlation of signal updates received on several buses.
+//!
+//! Each bus decodes its own frames, stamped by its controller (sockcan hardware timestamps),
+//! and hands the samples to [`Correlator::push`]. Buses deliver at their own pace, so samples
+//! are buffered per bus and released merged in stamp order once every bus reported up to that
+//! time: the watermark is the oldest of the newest bus stamps. A bus lagging more than
+//! `max_delay_ms` behind the newest stamp no longer holds the others back; its samples older
+//! than what was already released are counted late and dropped.
+//!
+//! Controllers do not share a clock, `offset_us` is added to the stamps of a bus to bring them
+//! to a common time base.
+//!
+//! ```yaml
+//! max_delay_ms: 100
+//! buses:
+//!   - name: can0
+//!   - name: can1
+//!     offset_us: -1500
+//! ```
+
+use crate::database::{MessageDef, SignalDef};
+use crate::layout::SignalValue;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::io::{self, Error};
+
+/// One bus of the correlation.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CorrelateBus {
+    pub name: String,
+    /// Microseconds added to the stamps of this bus, may be negative.
+    #[serde(default)]
+    pub offset_us: i64,
+}
+
+/// Correlation configuration, usually loaded from YAML.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CorrelateConfig {
+    pub buses: Vec<CorrelateBus>,
+    /// Lag behind the newest stamp after which a silent bus stops holding back the others,
+    /// 0 waits for every bus.
+    #[serde(default)]
+    pub max_delay_ms: u64,
+}
+
+/// One signal update on the common time base.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BusEvent {
+    /// Index of the bus in [`CorrelateConfig::buses`].
+    pub bus: usize,
+    /// Microseconds, bus stamp plus its offset.
+    pub stamp: u64,
+    pub canid: u32,
+    pub message: &'static str,
+    pub signal: &'static str,
+    pub value: SignalValue,
+}
+
+#[derive(Default)]
+struct BusQueue {
+    events: VecDeque<BusEvent>,
+    /// Newest stamp the bus reported, samples and heartbeats, offset applied.
+    last: Option<u64>,
+}
+
+/// Merges the samples of several buses into one time-ordered stream, see the module
+/// documentation.
+pub struct Correlator {
+    config: CorrelateConfig,
+    queues: Vec<BusQueue>,
+    /// Stamp of the last released event.
+    released: u64,
+    late: u64,
+}
+
+impl Correlator {
+    /// # Errors
+    /// Returns an error without bus or when two buses have the same name.
+    pub fn new(config: CorrelateConfig) -> io::Result<Self> {
+        if config.buses.is_empty() {
+            return Err(Error::other("correlate: no bus"));
+        }
+        for (idx, bus) in config.buses.iter().enumerate() {
+            if config.buses[..idx].iter().any(|other| other.name == bus.name) {
+                return Err(Error::other(format!("correlate bus:{} defined twice", bus.name)));
+            }
+        }
+        let queues = config.buses.iter().map(|_| BusQueue::default()).collect();
+        Ok(Correlator { config, queues, released: 0, late: 0 })
+    }
+
+    /// Load a configuration from YAML text.
+    ///
+    /// # Errors
+    /// Returns an error when the YAML is invalid or [`Correlator::new`] fails.
+    pub fn from_yaml(yaml: &str) -> io::Result<Self> {
+        let config: CorrelateConfig = serde_yaml::from_str(yaml).map_err(Error::other)?;
+        Correlator::new(config)
+    }
+
+    #[must_use]
+    pub fn get_config(&self) -> &CorrelateConfig {
+        &self.config
+    }
+
+    /// Index of the bus called `name`, as expected by [`Correlator::push`].
+    #[must_use]
+    pub fn bus_index(&self, name: &str) -> Option<usize> {
+        self.config.buses.iter().position(|bus| bus.name == name)
+    }
+
+    fn queue(&mut self, bus: usize, stamp: u64) -> io::Result<(&mut BusQueue, u64)> {
+        let offset = match self.config.buses.get(bus) {
+            Some(config) => config.offset_us,
+            None => return Err(Error::other(format!("correlate: no bus {bus}"))),
+        };
+        let stamp = stamp.saturating_add_signed(offset);
+        let queue = &mut self.queues[bus];
+        queue.last = Some(queue.last.map_or(stamp, |last| last.max(stamp)));
+        Ok((queue, stamp))
+    }
+
+    /// Buffer one decoded sample of `bus`, `stamp` in microseconds of the bus clock.
+    ///
+    /// # Errors
+    /// Returns an error when `bus` is not a configured bus index.
+    pub fn push(
+        &mut self,
+        bus: usize,
+        msg: &MessageDef,
+        sig: &SignalDef,
+        stamp: u64,
+        value: SignalValue,
+    ) -> io::Result<()> {
+        let released = self.released;
+        let (queue, stamp) = self.queue(bus, stamp)?;
+        if stamp < released {
+            self.late += 1;
+            return Ok(());
+        }
+        // frames of one bus arrive in order, the search only matters for reordered stamps
+        let pos = queue.events.partition_point(|event| event.stamp <= stamp);
+        let event =
+            BusEvent { bus, stamp, canid: msg.id, message: msg.name, signal: sig.name, value };
+        queue.events.insert(pos, event);
+        Ok(())
+    }
+
+    /// Report that `bus` reached `stamp` without a sample to push, e.g. a frame whose signals
+    /// did not change, so an idle bus does not stall the stream.
+    ///
+    /// # Errors
+    /// Returns an error when `bus` is not a configured bus index.
+    pub fn heartbeat(&mut self, bus: usize, stamp: u64) -> io::Result<()> {
+        self.queue(bus, stamp).map(|_| ())
+    }
+
+    /// Stamp up to which every bus reported, `max_delay_ms` applied.
+    #[must_use]
+    pub fn watermark(&self) -> u64 {
+        let newest = self.queues.iter().filter_map(|queue| queue.last).max().unwrap_or(0);
+        let floor = match self.config.max_delay_ms {
+            0 => 0,
+            delay => newest.saturating_sub(delay.saturating_mul(1000)),
+        };
+        self.queues
+            .iter()
+            .map(|queue| queue.last.unwrap_or(0).max(floor))
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Release, in stamp order, the samples up to the watermark. Samples with the same stamp
+    /// come in bus order.
+    pub fn poll(&mut self) -> Vec<BusEvent> {
+        let watermark = self.watermark();
+        self.release(watermark)
+    }
+
+    /// Release every buffered sample in stamp order, at the end of a capture.
+    pub fn drain(&mut self) -> Vec<BusEvent> {
+        self.release(u64::MAX)
+    }
+
+    fn release(&mut self, until: u64) -> Vec<BusEvent> {
+        let mut events = Vec::new();
+        loop {
+            let next = self
+                .queues
+                .iter()
+                .enumerate()
+                .filter_map(|(bus, queue)| queue.events.front().map(|event| (event.stamp, bus)))
+                .filter(|(stamp, _)| *stamp <= until)
+                .min();
+            let Some((stamp, bus)) = next else {
+                break;
+            };
+            if let Some(event) = self.queues[bus].events.pop_front() {
+                self.released = stamp;
+                events.push(event);
+            }
+        }
+        events
+    }
+
+    /// Samples buffered until every bus catches up.
+    #[must_use]
+    pub fn get_pending(&self) -> usize {
+        self.queues.iter().map(|queue| queue.events.len()).sum()
+    }
+
+    /// Samples dropped because they were older than the released stream.
+    #[must_use]
+    pub fn get_late(&self) -> u64 {
+        self.late
+    }
+}
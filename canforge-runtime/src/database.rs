@@ -0,0 +1,425 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! DBC database loaded at runtime: message/signal definitions with decode/encode helpers.
+
+use crate::layout::{SignalKind, SignalLayout, SignalValue};
+use can_dbc::{Dbc, Message, MultiplexIndicator, Signal, Transmitter};
+use dbcparser::gencode::{
+    message_transmitters, resolve_value_tables, signal_float_size, SignalCodeGen, ValCodeGen,
+};
+use std::collections::HashSet;
+use std::fs;
+use std::io::{self, Error};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Return a `&'static str` for `name`, leaking each distinct name only once.
+///
+/// sockcan traits hand out `&'static str` names; interning keeps repeated loads of the same
+/// database from growing memory.
+pub(crate) fn intern(name: &str) -> &'static str {
+    static NAMES: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+    let mut names = NAMES.get_or_init(|| Mutex::new(HashSet::new())).lock().unwrap_or_else(|e| {
+        // a panic while holding the lock cannot leave the set inconsistent
+        e.into_inner()
+    });
+    if let Some(known) = names.get(name) {
+        return known;
+    }
+    let leaked: &'static str = Box::leak(name.to_owned().into_boxed_str());
+    names.insert(leaked);
+    leaked
+}
+
+/// Match a signal against a config pattern: `Signal`, `Message.Signal` or `Message.*`,
+/// with generated or DBC names and `*` as wildcard.
+#[must_use]
+pub fn signal_matches(pattern: &str, msg: &MessageDef, sig: &SignalDef) -> bool {
+    let name_matches = |pattern: &str, generated: &str, dbc_name: &str| {
+        pattern == "*" || pattern == generated || pattern == dbc_name
+    };
+    match pattern.split_once('.') {
+        Some((msg_pat, sig_pat)) => {
+            name_matches(msg_pat, msg.name, &msg.dbc_name)
+                && name_matches(sig_pat, sig.name, &sig.dbc_name)
+        },
+        None => name_matches(pattern, sig.name, &sig.dbc_name),
+    }
+}
+
+/// One value-table entry (`VAL_`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueEntry {
+    pub raw: i64,
+    pub description: String,
+}
+
+/// Runtime signal definition.
+#[derive(Debug, Clone)]
+pub struct SignalDef {
+    /// Identifier used by the generated code (UpperCamelCase).
+    pub name: &'static str,
+    /// Name as written in the DBC file.
+    pub dbc_name: String,
+    pub layout: SignalLayout,
+    pub kind: SignalKind,
+    pub factor: f64,
+    pub offset: f64,
+    pub min: f64,
+    pub max: f64,
+    pub unit: String,
+    pub receivers: Vec<String>,
+    pub multiplexer: MultiplexIndicator,
+    pub values: Vec<ValueEntry>,
+}
+
+impl SignalDef {
+    fn new(dbc: &Dbc, msg: &Message, sig: &Signal) -> io::Result<Self> {
+        let values = dbc
+            .value_descriptions_for_signal(msg.id, sig.name.as_str())
+            .map(|list| {
+                list.iter()
+                    .map(|v| ValueEntry { raw: v.id, description: v.description.clone() })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if sig.factor == 0.0 {
+            return Err(Error::other(format!(
+                "message:{} signal:{} factor 0 cannot be encoded",
+                msg.name, sig.name
+            )));
+        }
+        let float_size = signal_float_size(dbc, msg, sig);
+        if float_size.is_some_and(|bits| bits != sig.size) {
+            return Err(Error::other(format!(
+                "message:{} SIG_VALTYPE_ float signal:{} needs {} bits, not {}",
+                msg.name,
+                sig.name,
+                float_size.unwrap_or_default(),
+                sig.size
+            )));
+        }
+        Ok(SignalDef {
+            name: intern(&sig.get_type_kamel()),
+            dbc_name: sig.name.clone(),
+            layout: SignalLayout::new(sig, msg)?,
+            kind: float_size.map_or_else(|| SignalKind::of(sig), SignalKind::float),
+            factor: sig.factor,
+            offset: sig.offset,
+            min: sig.min,
+            max: sig.max,
+            unit: sig.unit.clone(),
+            receivers: sig.receivers.clone(),
+            multiplexer: sig.multiplexer_indicator,
+            values,
+        })
+    }
+
+    /// Raw multiplexor value as compared against `MultiplexedSignal` selectors.
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn mux_value(&self, data: &[u8]) -> Option<u64> {
+        let raw = self.layout.read_raw(data)?;
+        if self.layout.signed {
+            Some(self.layout.sign_extend(raw) as u64)
+        } else {
+            Some(raw)
+        }
+    }
+
+    /// Decode the physical value, `None` when the payload is too short.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub fn decode(&self, data: &[u8]) -> Option<SignalValue> {
+        let raw = self.layout.read_raw(data)?;
+        let value = match self.kind {
+            SignalKind::Bool => SignalValue::Bool(raw == 1),
+            SignalKind::F64 => {
+                let base = if self.layout.signed {
+                    self.layout.sign_extend(raw) as f64
+                } else {
+                    raw as f64
+                };
+                SignalValue::F64(base * self.factor + self.offset)
+            },
+            SignalKind::U8 => SignalValue::U8(raw as u8),
+            SignalKind::U16 => SignalValue::U16(raw as u16),
+            SignalKind::U32 => SignalValue::U32(raw as u32),
+            SignalKind::U64 => SignalValue::U64(raw),
+            SignalKind::I8 => SignalValue::I8(self.layout.sign_extend(raw) as i8),
+            SignalKind::I16 => SignalValue::I16(self.layout.sign_extend(raw) as i16),
+            SignalKind::I32 => SignalValue::I32(self.layout.sign_extend(raw) as i32),
+            SignalKind::I64 => SignalValue::I64(self.layout.sign_extend(raw)),
+            SignalKind::F32 => SignalValue::F64(self.scale(f64::from(f32::from_bits(raw as u32)))),
+            SignalKind::F64Bits => SignalValue::F64(self.scale(f64::from_bits(raw))),
+        };
+        Some(value)
+    }
+
+    /// Encode a physical value into `data`, range-checked against the DBC min/max.
+    ///
+    /// # Errors
+    /// Returns an error when the value is out of range or `data` is too short.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn encode(&self, value: SignalValue, data: &mut [u8]) -> io::Result<()> {
+        let raw = match value {
+            SignalValue::Bool(flag) => u64::from(flag),
+            _ => {
+                let physical = value.as_f64();
+                let (tmin, tmax) = self.kind.type_range();
+                let (min, max) = (self.min.max(tmin), self.max.min(tmax));
+                if physical < min || max < physical {
+                    return Err(Error::other(format!(
+                        "value={physical} not in [{}..{}]",
+                        self.min, self.max
+                    )));
+                }
+                let raw_f = (physical - self.offset) / self.factor;
+                // integer kinds are unscaled: their value is the raw one, without the f64
+                // precision loss of 64-bit values
+                match (self.kind, value) {
+                    (SignalKind::F32, _) => u64::from((raw_f as f32).to_bits()),
+                    (SignalKind::F64Bits, _) => raw_f.to_bits(),
+                    (SignalKind::F64, _) | (_, SignalValue::F64(_)) => {
+                        if self.layout.signed {
+                            (raw_f as i64) as u64
+                        } else {
+                            raw_f as u64
+                        }
+                    },
+                    (_, SignalValue::I8(v)) => i64::from(v) as u64,
+                    (_, SignalValue::I16(v)) => i64::from(v) as u64,
+                    (_, SignalValue::I32(v)) => i64::from(v) as u64,
+                    (_, SignalValue::I64(v)) => v as u64,
+                    (_, SignalValue::U8(v)) => u64::from(v),
+                    (_, SignalValue::U16(v)) => u64::from(v),
+                    (_, SignalValue::U32(v)) => u64::from(v),
+                    (_, SignalValue::U64(v)) => v,
+                    (_, SignalValue::Bool(v)) => u64::from(v),
+                }
+            },
+        };
+        self.layout.write_raw(data, raw)
+    }
+
+    /// Physical value of the float `raw`, unscaled signals kept as is like the generated code
+    /// (`SignalCodeGen::has_scaling`).
+    fn scale(&self, raw: f64) -> f64 {
+        const EPS: f64 = 1e-12;
+        if self.offset.abs() > EPS || (self.factor - 1.0).abs() > EPS {
+            raw * self.factor + self.offset
+        } else {
+            raw
+        }
+    }
+
+    /// Value description matching a raw value, if any.
+    #[must_use]
+    pub fn describe(&self, raw: i64) -> Option<&str> {
+        self.values.iter().find(|v| v.raw == raw).map(|v| v.description.as_str())
+    }
+}
+
+/// Runtime message definition.
+#[derive(Debug, Clone)]
+pub struct MessageDef {
+    /// Raw DBC id, as returned by the generated `get_id()`.
+    pub id: u32,
+    /// Identifier used by the generated code (UpperCamelCase).
+    pub name: &'static str,
+    /// Name as written in the DBC file.
+    pub dbc_name: String,
+    pub size: u64,
+    pub transmitter: Option<String>,
+    /// Every transmitting node, `transmitter` first then `BO_TX_BU_` entries.
+    pub transmitters: Vec<String>,
+    pub signals: Vec<Arc<SignalDef>>,
+    /// Index of the multiplexor signal in `signals`.
+    pub multiplexor: Option<usize>,
+}
+
+impl MessageDef {
+    fn new(dbc: &Dbc, msg: &Message) -> io::Result<Self> {
+        let signals = msg
+            .signals
+            .iter()
+            .map(|sig| SignalDef::new(dbc, msg, sig).map(Arc::new))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let muxes: Vec<usize> = signals
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, sig)| {
+                matches!(
+                    sig.multiplexer,
+                    MultiplexIndicator::Multiplexor
+                        | MultiplexIndicator::MultiplexorAndMultiplexedSignal(_)
+                )
+                .then_some(idx)
+            })
+            .collect();
+
+        let multiplexor = match muxes.as_slice() {
+            [] => None,
+            [one] => Some(*one),
+            _ => {
+                return Err(Error::other(format!(
+                    "message:{} has multiple multiplexors; unsupported",
+                    msg.name
+                )))
+            },
+        };
+
+        let transmitter = match &msg.transmitter {
+            Transmitter::NodeName(node) => Some(node.clone()),
+            Transmitter::VectorXXX => None,
+        };
+
+        Ok(MessageDef {
+            id: msg.id.raw(),
+            name: intern(&msg.get_type_kamel()),
+            dbc_name: msg.name.clone(),
+            size: msg.size,
+            transmitter,
+            transmitters: message_transmitters(dbc, msg),
+            signals,
+            multiplexor,
+        })
+    }
+
+    /// Find a signal by generated or DBC name.
+    #[must_use]
+    pub fn signal(&self, name: &str) -> Option<&Arc<SignalDef>> {
+        self.signals.iter().find(|sig| sig.name == name || sig.dbc_name == name)
+    }
+
+    /// Decode every signal of a frame, in `signals` order.
+    ///
+    /// Multiplexed signals outside the active page, and signals beyond a short payload,
+    /// decode as `None`.
+    #[must_use]
+    pub fn decode(&self, data: &[u8]) -> Vec<Option<SignalValue>> {
+        let mux_value = self.multiplexor.and_then(|idx| self.signals[idx].mux_value(data));
+        self.signals
+            .iter()
+            .map(|sig| match sig.multiplexer {
+                MultiplexIndicator::MultiplexedSignal(page) if mux_value != Some(page) => None,
+                _ => sig.decode(data),
+            })
+            .collect()
+    }
+}
+
+/// A DBC database interpreted at runtime.
+#[derive(Debug, Clone)]
+pub struct RuntimeDbc {
+    source: Option<PathBuf>,
+    messages: Vec<Arc<MessageDef>>,
+}
+
+impl RuntimeDbc {
+    /// Load and interpret a DBC file.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or is not a valid/supported DBC.
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let buffer = fs::read_to_string(path)?;
+        let mut dbc = RuntimeDbc::from_source(&buffer)?;
+        dbc.source = Some(path.to_path_buf());
+        Ok(dbc)
+    }
+
+    /// Interpret DBC text already loaded in memory.
+    ///
+    /// # Errors
+    /// Returns an error if the text is not a valid/supported DBC, or when two messages share an id.
+    pub fn from_source(source: &str) -> io::Result<Self> {
+        let source = resolve_value_tables(source)?;
+        let dbc =
+            Dbc::try_from(source.as_str()).map_err(|error| Error::other(error.to_string()))?;
+
+        let mut messages = dbc
+            .messages
+            .iter()
+            .map(|msg| MessageDef::new(&dbc, msg).map(Arc::new))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        // sort message by canid (the pool binary-searches on it)
+        messages.sort_by_key(|msg| msg.id);
+        if let Some(pair) = messages.windows(2).find(|pair| pair[0].id == pair[1].id) {
+            return Err(Error::other(format!(
+                "message:{} id:{} already used by message:{}",
+                pair[1].dbc_name, pair[1].id, pair[0].dbc_name
+            )));
+        }
+
+        Ok(RuntimeDbc { source: None, messages })
+    }
+
+    /// Path the database was loaded from, if any.
+    #[must_use]
+    pub fn get_source(&self) -> Option<&Path> {
+        self.source.as_deref()
+    }
+
+    /// Message definitions sorted by CAN id.
+    #[must_use]
+    pub fn get_messages(&self) -> &[Arc<MessageDef>] {
+        &self.messages
+    }
+
+    /// Find a message definition by raw CAN id.
+    #[must_use]
+    pub fn get_message(&self, canid: u32) -> Option<&Arc<MessageDef>> {
+        self.messages
+            .binary_search_by_key(&canid, |msg| msg.id)
+            .ok()
+            .map(|idx| &self.messages[idx])
+    }
+
+    /// Find a message definition by generated or DBC name.
+    #[must_use]
+    pub fn get_message_by_name(&self, name: &str) -> Option<&Arc<MessageDef>> {
+        self.messages.iter().find(|msg| msg.name == name || msg.dbc_name == name)
+    }
+
+    /// Messages transmitted by `node`, including `BO_TX_BU_` co-transmitters.
+    #[must_use]
+    pub fn get_messages_by_transmitter(&self, node: &str) -> Vec<&Arc<MessageDef>> {
+        self.messages
+            .iter()
+            .filter(|msg| msg.transmitters.iter().any(|tx| tx == node))
+            .collect()
+    }
+
+    /// Signals consumed by `node` (listed in their `SG_` receivers), with their message.
+    #[must_use]
+    pub fn get_signals_by_receiver(&self, node: &str) -> Vec<(&Arc<MessageDef>, &Arc<SignalDef>)> {
+        self.messages
+            .iter()
+            .flat_map(|msg| {
+                msg.signals
+                    .iter()
+                    .filter(|sig| sig.receivers.iter().any(|rx| rx == node))
+                    .map(move |sig| (msg, sig))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,150 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Derived (virtual) signals computed from other signals of the same message, either with a
+//! Rust closure or, with the `rhai` feature, a script such as `voltage * current`.
+
+use crate::database::{intern, MessageDef};
+use crate::layout::SignalValue;
+use std::fmt;
+use std::io::{self, Error};
+use std::sync::Arc;
+
+/// Closure computing a derived value from its inputs, in declaration order.
+pub type DeriveFn = Arc<dyn Fn(&[f64]) -> f64 + Send + Sync>;
+
+#[derive(Clone)]
+enum Formula {
+    Closure(DeriveFn),
+    #[cfg(feature = "rhai")]
+    Script(Arc<rhai::Engine>, Arc<rhai::AST>),
+}
+
+/// Definition of a derived signal.
+#[derive(Clone)]
+pub struct DerivedDef {
+    /// Name exposed through the signal interfaces.
+    pub name: &'static str,
+    /// Input signals (generated or DBC names) of the owning message.
+    pub inputs: Vec<String>,
+    pub unit: String,
+    formula: Formula,
+}
+
+impl fmt::Debug for DerivedDef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DerivedDef")
+            .field("name", &self.name)
+            .field("inputs", &self.inputs)
+            .field("unit", &self.unit)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DerivedDef {
+    /// Derived signal computed by a closure.
+    pub fn from_fn(
+        name: &str,
+        inputs: &[&str],
+        unit: &str,
+        formula: impl Fn(&[f64]) -> f64 + Send + Sync + 'static,
+    ) -> Self {
+        DerivedDef {
+            name: intern(name),
+            inputs: inputs.iter().map(|input| (*input).to_owned()).collect(),
+            unit: unit.to_owned(),
+            formula: Formula::Closure(Arc::new(formula)),
+        }
+    }
+
+    /// Derived signal computed by a rhai expression; inputs are visible as variables.
+    ///
+    /// # Errors
+    /// Returns an error when the script does not compile.
+    #[cfg(feature = "rhai")]
+    pub fn from_script(name: &str, inputs: &[&str], unit: &str, script: &str) -> io::Result<Self> {
+        let mut engine = rhai::Engine::new();
+        // derived signals are evaluated on every frame, keep scripts cheap
+        engine.set_max_operations(10_000);
+        let ast = engine
+            .compile_expression(script)
+            .map_err(|error| Error::other(format!("derived:{name} script error: {error}")))?;
+        Ok(DerivedDef {
+            name: intern(name),
+            inputs: inputs.iter().map(|input| (*input).to_owned()).collect(),
+            unit: unit.to_owned(),
+            formula: Formula::Script(Arc::new(engine), Arc::new(ast)),
+        })
+    }
+
+    /// Evaluate the formula on input values.
+    ///
+    /// # Errors
+    /// Returns an error when a script fails or does not return a number.
+    pub fn eval(&self, values: &[f64]) -> io::Result<f64> {
+        match &self.formula {
+            Formula::Closure(formula) => Ok(formula(values)),
+            #[cfg(feature = "rhai")]
+            Formula::Script(engine, ast) => {
+                let mut scope = rhai::Scope::new();
+                for (input, value) in self.inputs.iter().zip(values) {
+                    scope.push_constant(input.as_str(), *value);
+                }
+                let result: rhai::Dynamic = engine
+                    .eval_ast_with_scope(&mut scope, ast)
+                    .map_err(|error| Error::other(format!("derived:{} {error}", self.name)))?;
+                result.as_float().or_else(|_| result.as_int().map(|int| int as f64)).map_err(
+                    |kind| {
+                        Error::other(format!("derived:{} returned {kind}, not a number", self.name))
+                    },
+                )
+            },
+        }
+    }
+
+    /// Index of every input within `msg.signals`.
+    ///
+    /// # Errors
+    /// Returns an error when an input is not a signal of `msg`.
+    pub fn resolve(&self, msg: &MessageDef) -> io::Result<Vec<usize>> {
+        self.inputs
+            .iter()
+            .map(|input| {
+                msg.signals
+                    .iter()
+                    .position(|sig| sig.name == input || sig.dbc_name == *input)
+                    .ok_or_else(|| {
+                        Error::other(format!(
+                            "derived:{} input:{input} not in message:{}",
+                            self.name, msg.name
+                        ))
+                    })
+            })
+            .collect()
+    }
+
+    /// Compute the value from a frame decoded with [`MessageDef::decode`]; `None` when an
+    /// input is not available (short frame, inactive multiplexed page) or the script fails.
+    #[must_use]
+    pub fn compute(&self, inputs: &[usize], values: &[Option<SignalValue>]) -> Option<f64> {
+        let values = inputs
+            .iter()
+            .map(|idx| values.get(*idx).copied().flatten().map(SignalValue::as_f64))
+            .collect::<Option<Vec<f64>>>()?;
+        self.eval(&values).ok()
+    }
+}
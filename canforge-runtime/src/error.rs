@@ -0,0 +1,59 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! `CanError` wrapper carrying a [`CanErrorCode`], usable with `?` in anyhow/thiserror code.
+
+pub use dbcparser::errors::CanErrorCode;
+use sockcan::prelude::*;
+use std::fmt;
+
+/// `CanError` with its code, implements `std::error::Error`.
+#[derive(Debug, Clone)]
+pub struct DbcError {
+    pub code: CanErrorCode,
+    pub uid: String,
+    pub info: String,
+}
+
+impl From<CanError> for DbcError {
+    fn from(error: CanError) -> Self {
+        DbcError {
+            code: CanErrorCode::from_uid(error.get_uid()),
+            uid: error.get_uid().to_owned(),
+            info: error.get_info().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DbcError {
+    fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(format, "{}: {}", self.uid, self.info)
+    }
+}
+
+impl std::error::Error for DbcError {}
+
+/// Code of a sockcan `CanError`.
+pub trait CanErrorCodeExt {
+    fn code(&self) -> CanErrorCode;
+}
+
+impl CanErrorCodeExt for CanError {
+    fn code(&self) -> CanErrorCode {
+        CanErrorCode::from_uid(self.get_uid())
+    }
+}
@@ -0,0 +1,168 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Linux CAN error frames (`linux/can/error.h`).
+//!
+//! With error reporting enabled (`CAN_RAW_ERR_FILTER`), a RAW socket delivers controller
+//! events as frames whose `can_id` carries `CAN_ERR_FLAG` and the error classes, details being
+//! in the 8 data bytes. [`ErrorFrame::decode`] turns them into a structured report and the
+//! [`BusState`] the controller moved to, if any.
+
+use serde::Serialize;
+
+/// Linux `can_id` flag of error frames.
+pub const CAN_ERR_FLAG: u32 = 0x2000_0000;
+
+/// Error classes of the `can_id`.
+const CAN_ERR_TX_TIMEOUT: u32 = 0x0001;
+const CAN_ERR_LOSTARB: u32 = 0x0002;
+const CAN_ERR_CRTL: u32 = 0x0004;
+const CAN_ERR_PROT: u32 = 0x0008;
+const CAN_ERR_TRX: u32 = 0x0010;
+const CAN_ERR_ACK: u32 = 0x0020;
+const CAN_ERR_BUSOFF: u32 = 0x0040;
+const CAN_ERR_BUSERROR: u32 = 0x0080;
+const CAN_ERR_RESTARTED: u32 = 0x0100;
+const CAN_ERR_CNT: u32 = 0x0200;
+
+/// Controller status of `data[1]`.
+const CAN_ERR_CRTL_RX_OVERFLOW: u8 = 0x01;
+const CAN_ERR_CRTL_TX_OVERFLOW: u8 = 0x02;
+const CAN_ERR_CRTL_RX_WARNING: u8 = 0x04;
+const CAN_ERR_CRTL_TX_WARNING: u8 = 0x08;
+const CAN_ERR_CRTL_RX_PASSIVE: u8 = 0x10;
+const CAN_ERR_CRTL_TX_PASSIVE: u8 = 0x20;
+const CAN_ERR_CRTL_ACTIVE: u8 = 0x40;
+
+/// Fault confinement state of the controller.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum BusState {
+    #[default]
+    ErrorActive,
+    /// An error counter reached 96.
+    ErrorWarning,
+    /// An error counter reached 128, the controller no longer sends active error flags.
+    ErrorPassive,
+    /// The transmit counter exceeded 255, the controller left the bus until restarted.
+    BusOff,
+}
+
+/// One error reported by an error frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum BusError {
+    TxTimeout,
+    /// Arbitration lost at `bit`, when the driver knows it.
+    LostArbitration {
+        bit: Option<u8>,
+    },
+    RxOverflow,
+    TxOverflow,
+    /// Protocol violation, `kind` and `location` being the `CAN_ERR_PROT_*` and
+    /// `CAN_ERR_PROT_LOC_*` bytes.
+    Protocol {
+        kind: u8,
+        location: u8,
+    },
+    /// Transceiver status, the `CAN_ERR_TRX_*` byte.
+    Transceiver(u8),
+    NoAck,
+    BusOff,
+    /// Bus error without further details.
+    BusError,
+    /// The controller was restarted after a bus-off.
+    Restarted,
+}
+
+/// Decoded error frame.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ErrorFrame {
+    /// Microseconds, when the frame was received.
+    pub stamp: u64,
+    pub errors: Vec<BusError>,
+    /// State the controller reported moving to, `None` when the frame does not tell.
+    pub state: Option<BusState>,
+    /// Transmit and receive error counters, when the driver reports them.
+    pub tx_errors: Option<u8>,
+    pub rx_errors: Option<u8>,
+}
+
+impl ErrorFrame {
+    /// Decode a frame with Linux `canid` and `data` received at `stamp`, `None` when it is
+    /// not an error frame. Missing data bytes read as 0.
+    #[must_use]
+    pub fn decode(canid: u32, data: &[u8], stamp: u64) -> Option<ErrorFrame> {
+        if canid & CAN_ERR_FLAG == 0 {
+            return None;
+        }
+        let byte = |idx: usize| data.get(idx).copied().unwrap_or(0);
+        let class = |flag: u32| canid & flag != 0;
+        let mut errors = Vec::new();
+        let mut state = None;
+
+        if class(CAN_ERR_TX_TIMEOUT) {
+            errors.push(BusError::TxTimeout);
+        }
+        if class(CAN_ERR_LOSTARB) {
+            errors.push(BusError::LostArbitration { bit: Some(byte(0)).filter(|bit| *bit != 0) });
+        }
+        if class(CAN_ERR_CRTL) {
+            let status = byte(1);
+            if status & CAN_ERR_CRTL_RX_OVERFLOW != 0 {
+                errors.push(BusError::RxOverflow);
+            }
+            if status & CAN_ERR_CRTL_TX_OVERFLOW != 0 {
+                errors.push(BusError::TxOverflow);
+            }
+            if status & (CAN_ERR_CRTL_RX_PASSIVE | CAN_ERR_CRTL_TX_PASSIVE) != 0 {
+                state = Some(BusState::ErrorPassive);
+            } else if status & (CAN_ERR_CRTL_RX_WARNING | CAN_ERR_CRTL_TX_WARNING) != 0 {
+                state = Some(BusState::ErrorWarning);
+            } else if status & CAN_ERR_CRTL_ACTIVE != 0 {
+                state = Some(BusState::ErrorActive);
+            }
+        }
+        if class(CAN_ERR_PROT) {
+            errors.push(BusError::Protocol { kind: byte(2), location: byte(3) });
+        }
+        if class(CAN_ERR_TRX) {
+            errors.push(BusError::Transceiver(byte(4)));
+        }
+        if class(CAN_ERR_ACK) {
+            errors.push(BusError::NoAck);
+        }
+        if class(CAN_ERR_BUSOFF) {
+            errors.push(BusError::BusOff);
+            state = Some(BusState::BusOff);
+        }
+        if class(CAN_ERR_BUSERROR) {
+            errors.push(BusError::BusError);
+        }
+        if class(CAN_ERR_RESTARTED) {
+            errors.push(BusError::Restarted);
+            state = Some(BusState::ErrorActive);
+        }
+        let counters = class(CAN_ERR_CNT);
+
+        Some(ErrorFrame {
+            stamp,
+            errors,
+            state,
+            tx_errors: counters.then(|| byte(6)),
+            rx_errors: counters.then(|| byte(7)),
+        })
+    }
+}
@@ -0,0 +1,255 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Transmit-side rate limiting and bus-load budget.
+//!
+//! Ask a [`TxGovernor`] before sending each frame encoded with the generated `set_values()`
+//! helpers: it enforces a minimum interval per message and a share of the bus bit rate, so a
+//! fast loop cannot flood the bus. The bus budget is a token bucket of bus bits, refilled at
+//! `bitrate * max_load_percent / 100` and holding `burst_ms` of that rate, which lets short
+//! bursts through. Stamps are in microseconds like `CanMsgData::stamp`.
+//!
+//! ```yaml
+//! bitrate: 500000
+//! max_load_percent: 30
+//! burst_ms: 20
+//! min_interval_ms: 0    # messages without a rule
+//! messages:
+//!   - canid: 0x101
+//!     min_interval_ms: 10
+//! ```
+
+use dbcparser::clock::{Clock, SystemClock};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{self, Error};
+
+/// Minimum interval between two frames of one message.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TxRule {
+    /// Raw DBC id, as returned by the generated `get_id()`.
+    pub canid: u32,
+    pub min_interval_ms: u64,
+}
+
+fn default_max_load() -> f64 {
+    100.0
+}
+
+fn default_burst_ms() -> u64 {
+    10
+}
+
+/// Governor configuration, usually loaded from YAML.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TxGovernorConfig {
+    /// Bus bit rate in bit/s, 0 disables the bus-load budget.
+    #[serde(default)]
+    pub bitrate: u64,
+    /// Share of the bus this application may use.
+    #[serde(default = "default_max_load")]
+    pub max_load_percent: f64,
+    /// Bus time at the budget rate a burst may use at once.
+    #[serde(default = "default_burst_ms")]
+    pub burst_ms: u64,
+    /// Minimum interval of the messages without a rule.
+    #[serde(default)]
+    pub min_interval_ms: u64,
+    #[serde(default)]
+    pub messages: Vec<TxRule>,
+}
+
+impl Default for TxGovernorConfig {
+    fn default() -> Self {
+        TxGovernorConfig {
+            bitrate: 0,
+            max_load_percent: default_max_load(),
+            burst_ms: default_burst_ms(),
+            min_interval_ms: 0,
+            messages: Vec::new(),
+        }
+    }
+}
+
+/// Why [`TxGovernor::admit`] refused a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxDenied {
+    /// The message was sent less than its minimum interval ago.
+    TooSoon { retry_in_us: u64 },
+    /// The frame would exceed the bus-load budget.
+    OverBudget { retry_in_us: u64 },
+}
+
+impl TxDenied {
+    /// Microseconds after which the same frame would be admitted.
+    #[must_use]
+    pub fn retry_in_us(self) -> u64 {
+        match self {
+            TxDenied::TooSoon { retry_in_us } | TxDenied::OverBudget { retry_in_us } => retry_in_us,
+        }
+    }
+}
+
+/// Frames admitted and refused since the governor was created.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct TxStats {
+    pub sent: u64,
+    pub too_soon: u64,
+    pub over_budget: u64,
+}
+
+/// Worst-case bus bits of a classic CAN frame with bit stuffing and interframe space, `canid`
+/// being a raw DBC id (bit 31 flags extended ids). CAN FD payloads are counted at the nominal
+/// rate, which overestimates their bus time.
+#[must_use]
+pub fn frame_bits(canid: u32, len: usize) -> u64 {
+    let data = 8 * len as u64;
+    if canid & 0x8000_0000 != 0 || canid > 0x7FF {
+        67 + data + (53 + data) / 4
+    } else {
+        47 + data + (33 + data) / 4
+    }
+}
+
+/// Admits or refuses frames about to be sent, see the module documentation.
+pub struct TxGovernor {
+    config: TxGovernorConfig,
+    intervals: HashMap<u32, u64>,
+    last_sent: HashMap<u32, u64>,
+    /// Bus bits left in the budget bucket.
+    tokens: f64,
+    /// Stamp `tokens` was last refilled at.
+    refilled: Option<u64>,
+    stats: TxStats,
+    /// System clock when unset.
+    clock: Option<Box<dyn Clock>>,
+}
+
+impl TxGovernor {
+    /// # Errors
+    /// Returns an error when the bus share is not in ]0..100] or a message has two rules.
+    pub fn new(config: TxGovernorConfig) -> io::Result<Self> {
+        if !(config.max_load_percent > 0.0 && config.max_load_percent <= 100.0) {
+            return Err(Error::other(format!(
+                "tx governor max_load_percent:{} not in ]0..100]",
+                config.max_load_percent
+            )));
+        }
+        let mut intervals = HashMap::new();
+        for rule in &config.messages {
+            if intervals.insert(rule.canid, rule.min_interval_ms * 1000).is_some() {
+                return Err(Error::other(format!(
+                    "tx governor canid:{} defined twice",
+                    rule.canid
+                )));
+            }
+        }
+        let mut governor = TxGovernor {
+            config,
+            intervals,
+            last_sent: HashMap::new(),
+            tokens: 0.0,
+            refilled: None,
+            stats: TxStats::default(),
+            clock: None,
+        };
+        governor.tokens = governor.capacity();
+        Ok(governor)
+    }
+
+    /// Load a configuration from YAML text.
+    ///
+    /// # Errors
+    /// Returns an error when the YAML is invalid or the configuration inconsistent.
+    pub fn from_yaml(yaml: &str) -> io::Result<Self> {
+        let config: TxGovernorConfig = serde_yaml::from_str(yaml).map_err(Error::other)?;
+        TxGovernor::new(config)
+    }
+
+    /// Time source of [`TxGovernor::admit_now`], the system clock by default.
+    pub fn set_clock(&mut self, clock: Box<dyn Clock>) {
+        self.clock = Some(clock);
+    }
+
+    #[must_use]
+    pub fn get_config(&self) -> &TxGovernorConfig {
+        &self.config
+    }
+
+    #[must_use]
+    pub fn get_stats(&self) -> TxStats {
+        self.stats
+    }
+
+    /// Budget refill in bits per microsecond.
+    fn rate(&self) -> f64 {
+        self.config.bitrate as f64 * self.config.max_load_percent / 100.0 / 1_000_000.0
+    }
+
+    /// Bucket size in bits, at least one 64-byte frame so any frame eventually gets through.
+    fn capacity(&self) -> f64 {
+        let burst = self.rate() * (self.config.burst_ms * 1000) as f64;
+        burst.max(frame_bits(0x8000_0000, 64) as f64)
+    }
+
+    /// Admit a frame of `len` data bytes for `canid` at `now` (microseconds) and count it as
+    /// sent, or tell when to retry. A refused frame consumes nothing.
+    ///
+    /// # Errors
+    /// Returns [`TxDenied`] when the message interval or the bus budget would be exceeded.
+    pub fn admit(&mut self, canid: u32, len: usize, now: u64) -> Result<(), TxDenied> {
+        let interval = self
+            .intervals
+            .get(&canid)
+            .copied()
+            .unwrap_or(self.config.min_interval_ms * 1000);
+        if let Some(last) = self.last_sent.get(&canid) {
+            let elapsed = now.saturating_sub(*last);
+            if elapsed < interval {
+                self.stats.too_soon += 1;
+                return Err(TxDenied::TooSoon { retry_in_us: interval - elapsed });
+            }
+        }
+
+        if self.config.bitrate > 0 {
+            let rate = self.rate();
+            let elapsed = self.refilled.map_or(0, |refilled| now.saturating_sub(refilled));
+            self.tokens = (self.tokens + elapsed as f64 * rate).min(self.capacity());
+            self.refilled = Some(now);
+            let bits = frame_bits(canid, len) as f64;
+            if bits > self.tokens {
+                self.stats.over_budget += 1;
+                let retry_in_us = ((bits - self.tokens) / rate).ceil() as u64;
+                return Err(TxDenied::OverBudget { retry_in_us });
+            }
+            self.tokens -= bits;
+        }
+
+        self.last_sent.insert(canid, now);
+        self.stats.sent += 1;
+        Ok(())
+    }
+
+    /// [`TxGovernor::admit`] at the current time of the governor clock.
+    ///
+    /// # Errors
+    /// Returns [`TxDenied`] when the message interval or the bus budget would be exceeded.
+    pub fn admit_now(&mut self, canid: u32, len: usize) -> Result<(), TxDenied> {
+        let now = self.clock.as_deref().map_or_else(|| SystemClock.now_us(), Clock::now_us);
+        self.admit(canid, len, now)
+    }
+}
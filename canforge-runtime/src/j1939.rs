@@ -0,0 +1,522 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! SAE J1939 helpers: identifier fields, transport protocol, DM1/DM2 diagnostic messages and
+//! address claim.
+//!
+//! Parameter groups longer than 8 bytes are split by the transport protocol (J1939-21): a
+//! TP.CM announce (BAM broadcast or RTS/CTS to one destination) followed by numbered TP.DT
+//! packets. [`TpReassembler`] listens to both and returns the complete payload.
+//!
+//! DM1 (active) and DM2 (previously active) carry the lamp status followed by 4-byte DTCs
+//! (SPN, FMI, occurrence count). With more than one DTC they are sent with the transport
+//! protocol; [`DiagnosticMessage::decode`] takes the reassembled payload of any length.
+//!
+//! Source addresses are dynamic: each ECU claims one with its 64-bit NAME (J1939-81).
+//! [`AddressTable`] follows the claims heard on the bus so frames can be attributed to a
+//! device, [`AddressClaimer`] claims an address for our own transmissions.
+
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, Error};
+
+/// DM1: active diagnostic trouble codes.
+pub const PGN_DM1: u32 = 0xFECA;
+/// DM2: previously active diagnostic trouble codes.
+pub const PGN_DM2: u32 = 0xFECB;
+
+/// Address claimed / cannot claim.
+pub const PGN_ADDRESS_CLAIMED: u32 = 0xEE00;
+/// Request for another PGN.
+pub const PGN_REQUEST: u32 = 0xEA00;
+/// Transport protocol connection management (BAM, RTS/CTS, abort).
+pub const PGN_TP_CM: u32 = 0xEC00;
+/// Transport protocol data transfer.
+pub const PGN_TP_DT: u32 = 0xEB00;
+
+/// Source address of a "cannot claim" message and destination of broadcasts.
+pub const NULL_ADDRESS: u8 = 0xFE;
+pub const GLOBAL_ADDRESS: u8 = 0xFF;
+
+/// Socket CAN flag of 29-bit identifiers, as found in frame and DBC ids.
+const CAN_EFF_FLAG: u32 = 0x8000_0000;
+
+/// Parameter group number of an extended `canid`, `None` for 11-bit identifiers.
+#[must_use]
+pub fn pgn(canid: u32) -> Option<u32> {
+    if canid & CAN_EFF_FLAG == 0 {
+        return None;
+    }
+    let id = canid & 0x1FFF_FFFF;
+    let pf = (id >> 16) & 0xFF;
+    // PDU1 (PF < 240): PS is a destination address, not part of the PGN
+    let pgn = if pf < 0xF0 { (id >> 8) & 0x3_FF00 } else { (id >> 8) & 0x3_FFFF };
+    Some(pgn)
+}
+
+/// Destination address of a PDU1 `canid` (PF < 240), global for PDU2 broadcasts.
+#[must_use]
+pub fn destination_address(canid: u32) -> u8 {
+    if (canid >> 16) & 0xFF < 0xF0 {
+        ((canid >> 8) & 0xFF) as u8
+    } else {
+        GLOBAL_ADDRESS
+    }
+}
+
+/// Source address, the low byte of the identifier.
+#[must_use]
+pub fn source_address(canid: u32) -> u8 {
+    (canid & 0xFF) as u8
+}
+
+/// 2-bit lamp status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum LampStatus {
+    Off,
+    On,
+    Error,
+    NotAvailable,
+}
+
+/// 2-bit lamp flash status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum LampFlash {
+    Slow,
+    Fast,
+    Reserved,
+    Unavailable,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Lamp {
+    pub status: LampStatus,
+    pub flash: LampFlash,
+}
+
+impl Lamp {
+    fn decode(status: u8, flash: u8, shift: u8) -> Self {
+        let status = match (status >> shift) & 0x3 {
+            0 => LampStatus::Off,
+            1 => LampStatus::On,
+            2 => LampStatus::Error,
+            _ => LampStatus::NotAvailable,
+        };
+        let flash = match (flash >> shift) & 0x3 {
+            0 => LampFlash::Slow,
+            1 => LampFlash::Fast,
+            2 => LampFlash::Reserved,
+            _ => LampFlash::Unavailable,
+        };
+        Lamp { status, flash }
+    }
+}
+
+/// Lamps reported by a DM1/DM2 sender.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Lamps {
+    pub malfunction: Lamp,
+    pub red_stop: Lamp,
+    pub amber_warning: Lamp,
+    pub protect: Lamp,
+}
+
+/// Diagnostic trouble code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Dtc {
+    /// Suspect parameter number (19 bits).
+    pub spn: u32,
+    /// Failure mode identifier (5 bits).
+    pub fmi: u8,
+    /// Occurrence count (7 bits), 127 when not available.
+    pub occurrences: u8,
+    /// SPN conversion method bit, set by senders still using the pre-2000 SPN layouts.
+    pub conversion_method: bool,
+}
+
+/// Decoded DM1 or DM2 payload.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiagnosticMessage {
+    pub lamps: Lamps,
+    pub dtcs: Vec<Dtc>,
+}
+
+impl DiagnosticMessage {
+    /// Decode a single-frame or reassembled multi-packet DM1/DM2 payload.
+    ///
+    /// # Errors
+    /// Returns an error when the payload is shorter than the lamp status and one DTC.
+    pub fn decode(payload: &[u8]) -> io::Result<Self> {
+        if payload.len() < 6 {
+            return Err(Error::other(format!(
+                "j1939 DM payload of {} bytes, at least 6 expected",
+                payload.len()
+            )));
+        }
+        let lamps = Lamps {
+            malfunction: Lamp::decode(payload[0], payload[1], 6),
+            red_stop: Lamp::decode(payload[0], payload[1], 4),
+            amber_warning: Lamp::decode(payload[0], payload[1], 2),
+            protect: Lamp::decode(payload[0], payload[1], 0),
+        };
+        let dtcs = payload[2..]
+            .chunks_exact(4)
+            .map(|dtc| Dtc {
+                spn: u32::from(dtc[0]) | u32::from(dtc[1]) << 8 | u32::from(dtc[2] >> 5) << 16,
+                fmi: dtc[2] & 0x1F,
+                occurrences: dtc[3] & 0x7F,
+                conversion_method: dtc[3] & 0x80 != 0,
+            })
+            // "no DTC" is sent as SPN 0/FMI 0, multi-packet payloads are padded with 0xFF
+            .filter(|dtc| dtc.spn != 0 && dtc.spn != 0x7_FFFF)
+            .collect();
+        Ok(DiagnosticMessage { lamps, dtcs })
+    }
+}
+
+/// 64-bit J1939 NAME identifying a device independently of its source address. A lower NAME
+/// wins address contention.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct Name(pub u64);
+
+impl Name {
+    /// NAME of an address claim payload (little-endian).
+    ///
+    /// # Errors
+    /// Returns an error when the payload is not 8 bytes.
+    pub fn from_payload(payload: &[u8]) -> io::Result<Self> {
+        let bytes: [u8; 8] = payload
+            .try_into()
+            .map_err(|_| Error::other(format!("j1939 NAME of {} bytes", payload.len())))?;
+        Ok(Name(u64::from_le_bytes(bytes)))
+    }
+
+    #[must_use]
+    pub fn to_payload(self) -> [u8; 8] {
+        self.0.to_le_bytes()
+    }
+
+    #[must_use]
+    pub fn identity_number(self) -> u32 {
+        (self.0 & 0x1F_FFFF) as u32
+    }
+
+    #[must_use]
+    pub fn manufacturer_code(self) -> u16 {
+        ((self.0 >> 21) & 0x7FF) as u16
+    }
+
+    #[must_use]
+    pub fn ecu_instance(self) -> u8 {
+        ((self.0 >> 32) & 0x7) as u8
+    }
+
+    #[must_use]
+    pub fn function_instance(self) -> u8 {
+        ((self.0 >> 35) & 0x1F) as u8
+    }
+
+    #[must_use]
+    pub fn function(self) -> u8 {
+        ((self.0 >> 40) & 0xFF) as u8
+    }
+
+    #[must_use]
+    pub fn vehicle_system(self) -> u8 {
+        ((self.0 >> 49) & 0x7F) as u8
+    }
+
+    #[must_use]
+    pub fn vehicle_system_instance(self) -> u8 {
+        ((self.0 >> 56) & 0xF) as u8
+    }
+
+    #[must_use]
+    pub fn industry_group(self) -> u8 {
+        ((self.0 >> 60) & 0x7) as u8
+    }
+
+    /// The device may pick another address when it loses contention.
+    #[must_use]
+    pub fn arbitrary_address_capable(self) -> bool {
+        self.0 >> 63 != 0
+    }
+}
+
+/// Extended `canid` of an address claim sent by `source` (priority 6, to global).
+#[must_use]
+pub fn address_claim_id(source: u8) -> u32 {
+    CAN_EFF_FLAG | 0x18EE_FF00 | u32::from(source)
+}
+
+/// Source address to NAME mapping learnt from the address claims heard on the bus.
+#[derive(Debug, Clone, Default)]
+pub struct AddressTable {
+    names: BTreeMap<u8, Name>,
+}
+
+impl AddressTable {
+    /// Record an address claim (or cannot claim, from the null address) sent by `source`.
+    ///
+    /// # Errors
+    /// Returns an error when the payload is not a NAME.
+    pub fn update(&mut self, source: u8, payload: &[u8]) -> io::Result<()> {
+        let name = Name::from_payload(payload)?;
+        // a device claiming a new address, or unable to claim one, leaves its previous one
+        self.names.retain(|_, known| *known != name);
+        if source != NULL_ADDRESS && source != GLOBAL_ADDRESS {
+            match self.names.get(&source) {
+                // contention: the lower NAME keeps the address
+                Some(current) if *current < name => {},
+                _ => {
+                    self.names.insert(source, name);
+                },
+            }
+        }
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn get_name(&self, source: u8) -> Option<Name> {
+        self.names.get(&source).copied()
+    }
+
+    #[must_use]
+    pub fn get_address(&self, name: Name) -> Option<u8> {
+        self.names.iter().find(|(_, known)| **known == name).map(|(source, _)| *source)
+    }
+
+    /// Claimed addresses with their NAME, ordered by address.
+    pub fn iter(&self) -> impl Iterator<Item = (u8, Name)> + '_ {
+        self.names.iter().map(|(source, name)| (*source, *name))
+    }
+}
+
+/// Frame to send on behalf of an [`AddressClaimer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClaimFrame {
+    pub canid: u32,
+    pub data: [u8; 8],
+}
+
+/// Address claim procedure for our own transmissions.
+///
+/// Send [`AddressClaimer::claim`] at startup, then give every received address claim and
+/// request to [`AddressClaimer::receive`] and send the frame it returns, if any. Once 250 ms
+/// passed without losing the address, [`AddressClaimer::address`] can be used as source.
+#[derive(Debug, Clone)]
+pub struct AddressClaimer {
+    name: Name,
+    address: Option<u8>,
+}
+
+impl AddressClaimer {
+    #[must_use]
+    pub fn new(name: Name, preferred: u8) -> Self {
+        AddressClaimer { name, address: Some(preferred) }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> Name {
+        self.name
+    }
+
+    /// Address currently claimed, `None` after losing contention without finding another one.
+    #[must_use]
+    pub fn address(&self) -> Option<u8> {
+        self.address
+    }
+
+    /// Claim (or cannot claim) frame for the current state.
+    #[must_use]
+    pub fn claim(&self) -> ClaimFrame {
+        ClaimFrame {
+            canid: address_claim_id(self.address.unwrap_or(NULL_ADDRESS)),
+            data: self.name.to_payload(),
+        }
+    }
+
+    /// Handle a received frame, `table` holds the claims heard so far and is used to pick a
+    /// free address (128..=247, the self-configurable range) when contention is lost.
+    /// Returns the frame to send in response.
+    #[must_use]
+    pub fn receive(&mut self, canid: u32, data: &[u8], table: &AddressTable) -> Option<ClaimFrame> {
+        let source = source_address(canid);
+        match pgn(canid)? {
+            // request for address claimed, to us or to everybody
+            PGN_REQUEST if data.get(..3) == Some(&[0x00, 0xEE, 0x00]) => {
+                let destination = ((canid >> 8) & 0xFF) as u8;
+                (destination == GLOBAL_ADDRESS || Some(destination) == self.address)
+                    .then(|| self.claim())
+            },
+            PGN_ADDRESS_CLAIMED => {
+                let other = Name::from_payload(data).ok()?;
+                if Some(source) != self.address || other == self.name {
+                    return None;
+                }
+                if self.name < other {
+                    // we keep the address, remind the other device
+                    return Some(self.claim());
+                }
+                self.address = if self.name.arbitrary_address_capable() {
+                    (128..=247u8).find(|candidate| {
+                        *candidate != source && table.get_name(*candidate).is_none()
+                    })
+                } else {
+                    None
+                };
+                Some(self.claim())
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Parameter group rebuilt from transport protocol packets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TpMessage {
+    pub pgn: u32,
+    pub source: u8,
+    pub destination: u8,
+    pub data: Vec<u8>,
+}
+
+struct TpSession {
+    pgn: u32,
+    size: usize,
+    packets: u8,
+    next: u8,
+    stamp: u64,
+    data: Vec<u8>,
+}
+
+/// Passive TP.CM/TP.DT reassembly of BAM and RTS/CTS sessions, keyed by source and
+/// destination. Frame stamps are in microseconds, a session silent for more than 750 ms (T1)
+/// is dropped.
+#[derive(Default)]
+pub struct TpReassembler {
+    sessions: HashMap<(u8, u8), TpSession>,
+}
+
+impl TpReassembler {
+    const TIMEOUT_US: u64 = 750_000;
+
+    /// True when `canid` is a TP.CM or TP.DT frame.
+    #[must_use]
+    pub fn is_transport(canid: u32) -> bool {
+        matches!(pgn(canid), Some(PGN_TP_CM | PGN_TP_DT))
+    }
+
+    /// Feed a frame, returns the message completed by this frame. Frames other than TP.CM
+    /// and TP.DT are ignored.
+    ///
+    /// # Errors
+    /// Returns an error on a malformed announce, a missing packet or a timeout; the session
+    /// is dropped.
+    pub fn push(&mut self, canid: u32, stamp: u64, data: &[u8]) -> io::Result<Option<TpMessage>> {
+        let key = (source_address(canid), destination_address(canid));
+        match pgn(canid) {
+            Some(PGN_TP_CM) => self.control(key, stamp, data).map(|()| None),
+            Some(PGN_TP_DT) => self.transfer(key, stamp, data),
+            _ => Ok(None),
+        }
+    }
+
+    fn control(&mut self, key: (u8, u8), stamp: u64, data: &[u8]) -> io::Result<()> {
+        let Some(&control) = data.first() else {
+            return Err(Error::other("j1939 TP.CM without control byte"));
+        };
+        match control {
+            // RTS, BAM
+            16 | 32 => {
+                self.sessions.remove(&key);
+                if data.len() < 8 {
+                    return Err(Error::other("j1939 TP.CM announce shorter than 8 bytes"));
+                }
+                let size = usize::from(u16::from_le_bytes([data[1], data[2]]));
+                let packets = data[3];
+                if !(9..=1785).contains(&size) || usize::from(packets) != size.div_ceil(7) {
+                    return Err(Error::other(format!(
+                        "j1939 TP.CM announces {size} bytes in {packets} packets"
+                    )));
+                }
+                let pgn = u32::from_le_bytes([data[5], data[6], data[7], 0]);
+                self.sessions.insert(
+                    key,
+                    TpSession {
+                        pgn,
+                        size,
+                        packets,
+                        next: 1,
+                        stamp,
+                        data: Vec::with_capacity(size),
+                    },
+                );
+            },
+            // connection abort, from either side
+            255 => {
+                self.sessions.remove(&key);
+                self.sessions.remove(&(key.1, key.0));
+            },
+            // CTS and end of message acknowledge only matter to the peers
+            _ => {},
+        }
+        Ok(())
+    }
+
+    fn transfer(
+        &mut self,
+        key: (u8, u8),
+        stamp: u64,
+        data: &[u8],
+    ) -> io::Result<Option<TpMessage>> {
+        let Some(session) = self.sessions.get_mut(&key) else {
+            // packets of a session announced before we started listening
+            return Ok(None);
+        };
+        let sequence = data.first().copied().unwrap_or(0);
+        if stamp.saturating_sub(session.stamp) > Self::TIMEOUT_US {
+            self.sessions.remove(&key);
+            return Err(Error::other(format!("j1939 TP from {:#x} timed out", key.0)));
+        }
+        if sequence != session.next {
+            let expected = session.next;
+            self.sessions.remove(&key);
+            return Err(Error::other(format!(
+                "j1939 TP from {:#x} packet {sequence}, expected {expected}",
+                key.0
+            )));
+        }
+        session.data.extend_from_slice(data.get(1..8).unwrap_or_default());
+        session.next = session.next.wrapping_add(1);
+        session.stamp = stamp;
+        if sequence < session.packets {
+            return Ok(None);
+        }
+        let Some(mut session) = self.sessions.remove(&key) else { return Ok(None) };
+        if session.data.len() < session.size {
+            return Err(Error::other(format!("j1939 TP from {:#x} packets too short", key.0)));
+        }
+        session.data.truncate(session.size);
+        Ok(Some(TpMessage {
+            pgn: session.pgn,
+            source: key.0,
+            destination: key.1,
+            data: session.data,
+        }))
+    }
+}
@@ -0,0 +1,245 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Bit-level signal placement and raw/physical conversions.
+//!
+//! Everything here mirrors what `dbcparser::gencode` emits for a signal, so a frame decoded by
+//! the runtime interpreter gives exactly the same values as the generated bindings.
+
+use bitvec::prelude::*;
+use can_dbc::{ByteOrder, Message, Signal, ValueType};
+use dbcparser::gencode::SignalCodeGen;
+use serde::Serialize;
+use std::io::{self, Error};
+
+/// Rust type used by the generated code for a signal value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum SignalKind {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    F64,
+    /// IEEE 754 single precision bits (`SIG_VALTYPE_ 1`), read as `f64` like the generated code.
+    F32,
+    /// IEEE 754 double precision bits (`SIG_VALTYPE_ 2`).
+    F64Bits,
+}
+
+impl SignalKind {
+    /// Select the value type exactly as the generator does (`SignalCodeGen::get_data_type`),
+    /// for an integer signal: float signals need their `SIG_VALTYPE_`, see [`SignalKind::float`].
+    #[must_use]
+    pub fn of(sig: &Signal) -> Self {
+        match sig.get_data_type().as_str() {
+            "bool" => SignalKind::Bool,
+            "u8" => SignalKind::U8,
+            "u16" => SignalKind::U16,
+            "u32" => SignalKind::U32,
+            "u64" => SignalKind::U64,
+            "i8" => SignalKind::I8,
+            "i16" => SignalKind::I16,
+            "i32" => SignalKind::I32,
+            "i64" => SignalKind::I64,
+            _ => SignalKind::F64,
+        }
+    }
+
+    /// Kind of a `SIG_VALTYPE_` float signal of `float_size` bits, see `signal_float_size()`.
+    #[must_use]
+    pub fn float(float_size: u64) -> Self {
+        if float_size == 32 {
+            SignalKind::F32
+        } else {
+            SignalKind::F64Bits
+        }
+    }
+
+    /// Inclusive bounds of the Rust type, used to clamp DBC min/max like `bound_expr` does.
+    #[must_use]
+    pub fn type_range(self) -> (f64, f64) {
+        match self {
+            SignalKind::Bool => (0.0, 1.0),
+            SignalKind::U8 => (0.0, f64::from(u8::MAX)),
+            SignalKind::U16 => (0.0, f64::from(u16::MAX)),
+            SignalKind::U32 => (0.0, f64::from(u32::MAX)),
+            SignalKind::U64 => (0.0, u64::MAX as f64),
+            SignalKind::I8 => (f64::from(i8::MIN), f64::from(i8::MAX)),
+            SignalKind::I16 => (f64::from(i16::MIN), f64::from(i16::MAX)),
+            SignalKind::I32 => (f64::from(i32::MIN), f64::from(i32::MAX)),
+            SignalKind::I64 => (i64::MIN as f64, i64::MAX as f64),
+            SignalKind::F64 | SignalKind::F32 | SignalKind::F64Bits => (f64::MIN, f64::MAX),
+        }
+    }
+}
+
+/// A decoded signal value, typed like the generated `CanDbcType` variants.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum SignalValue {
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    F64(f64),
+}
+
+impl SignalValue {
+    /// Value returned by the generated `get_typed_value()` before any frame was received.
+    #[must_use]
+    pub fn default_for(kind: SignalKind) -> Self {
+        match kind {
+            SignalKind::Bool => SignalValue::Bool(false),
+            SignalKind::U8 => SignalValue::U8(0),
+            SignalKind::U16 => SignalValue::U16(0),
+            SignalKind::U32 => SignalValue::U32(0),
+            SignalKind::U64 => SignalValue::U64(0),
+            SignalKind::I8 => SignalValue::I8(0),
+            SignalKind::I16 => SignalValue::I16(0),
+            SignalKind::I32 => SignalValue::I32(0),
+            SignalKind::I64 => SignalValue::I64(0),
+            SignalKind::F64 | SignalKind::F32 | SignalKind::F64Bits => SignalValue::F64(0.0),
+        }
+    }
+
+    /// Widen the value to `f64` (lossy above 2^53 for 64-bit integers).
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_f64(self) -> f64 {
+        match self {
+            SignalValue::Bool(v) => f64::from(u8::from(v)),
+            SignalValue::U8(v) => f64::from(v),
+            SignalValue::U16(v) => f64::from(v),
+            SignalValue::U32(v) => f64::from(v),
+            SignalValue::U64(v) => v as f64,
+            SignalValue::I8(v) => f64::from(v),
+            SignalValue::I16(v) => f64::from(v),
+            SignalValue::I32(v) => f64::from(v),
+            SignalValue::I64(v) => v as f64,
+            SignalValue::F64(v) => v,
+        }
+    }
+}
+
+/// Bit placement of one signal inside its message payload.
+#[derive(Debug, Clone, Copy)]
+pub struct SignalLayout {
+    /// First bit, in `Lsb0` numbering for little endian and `Msb0` numbering for big endian.
+    pub start: usize,
+    /// One past the last bit (same numbering as `start`).
+    pub end: usize,
+    pub byte_order: ByteOrder,
+    pub signed: bool,
+}
+
+impl SignalLayout {
+    /// Compute the layout with the same checks as the generator.
+    ///
+    /// # Errors
+    /// Returns an error if the signal does not fit in the message payload.
+    pub fn new(sig: &Signal, msg: &Message) -> io::Result<Self> {
+        let (start, end) = match sig.byte_order {
+            ByteOrder::LittleEndian => sig.le_start_end_bit(msg)?,
+            ByteOrder::BigEndian => sig.be_start_end_bit(msg)?,
+        };
+        if sig.size == 0 || sig.size > 64 {
+            return Err(Error::other(format!(
+                "signal:{} size:{} bits unsupported (1..64)",
+                sig.name, sig.size
+            )));
+        }
+        let start = usize::try_from(start).map_err(Error::other)?;
+        let end = usize::try_from(end).map_err(Error::other)?;
+        Ok(SignalLayout {
+            start,
+            end,
+            byte_order: sig.byte_order,
+            signed: sig.value_type == ValueType::Signed,
+        })
+    }
+
+    /// Signal width in bits.
+    #[must_use]
+    pub fn bit_len(&self) -> u32 {
+        u32::try_from(self.end - self.start).unwrap_or(u32::MAX)
+    }
+
+    /// Mask selecting the signal bit-length within a `u64`.
+    #[must_use]
+    pub fn mask(&self) -> u64 {
+        let bits = self.bit_len();
+        if bits >= 64 {
+            u64::MAX
+        } else {
+            (1u64 << bits) - 1
+        }
+    }
+
+    /// Read the raw (unsigned, not sign-extended) bits, `None` when `data` is too short.
+    #[must_use]
+    pub fn read_raw(&self, data: &[u8]) -> Option<u64> {
+        match self.byte_order {
+            ByteOrder::LittleEndian => {
+                data.view_bits::<Lsb0>().get(self.start..self.end).map(BitSlice::load_le::<u64>)
+            },
+            ByteOrder::BigEndian => {
+                data.view_bits::<Msb0>().get(self.start..self.end).map(BitSlice::load_be::<u64>)
+            },
+        }
+    }
+
+    /// Sign-extend a raw value from the signal bit-width.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn sign_extend(&self, raw: u64) -> i64 {
+        let shift = 64 - self.bit_len();
+        ((raw << shift) as i64) >> shift
+    }
+
+    /// Store the low `bit_len()` bits of `raw`, leaving other bits untouched.
+    ///
+    /// # Errors
+    /// Returns an error when `data` is too short for the signal.
+    pub fn write_raw(&self, data: &mut [u8], raw: u64) -> io::Result<()> {
+        let raw = raw & self.mask();
+        let short =
+            || Error::other(format!("frame too short for bits {}..{}", self.start, self.end));
+        match self.byte_order {
+            ByteOrder::LittleEndian => data
+                .view_bits_mut::<Lsb0>()
+                .get_mut(self.start..self.end)
+                .ok_or_else(short)?
+                .store_le(raw),
+            ByteOrder::BigEndian => data
+                .view_bits_mut::<Msb0>()
+                .get_mut(self.start..self.end)
+                .ok_or_else(short)?
+                .store_be(raw),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+// canforge-runtime/src/lib.rs
+
+//! Runtime DBC interpreter.
+//!
+//! Loads a DBC file at startup and exposes it through the same sockcan
+//! `CanDbcPool`/`CanDbcMessage`/`CanDbcSignal` traits as the code produced by
+//! `dbcparser`, for tools (monitors, loggers) that handle databases chosen by the end user.
+//!
+//! ```no_run
+//! use canforge_runtime::prelude::*;
+//!
+//! let dbc = RuntimeDbc::from_file("model3.dbc").expect("valid dbc");
+//! # #[cfg(feature = "sockcan")]
+//! let pool = RuntimePool::new("Model3", dbc);
+//! ```
+
+#![doc(
+    html_logo_url = "https://iot.bzh/images/defaults/company/512-479-max-transp.png",
+    html_favicon_url = "https://iot.bzh/images/defaults/favicon.ico"
+)]
+
+pub mod aggregate;
+pub mod alarm;
+#[cfg(feature = "sockcan")]
+pub mod bus;
+pub mod busload;
+pub mod correlate;
+pub mod database;
+pub mod derive;
+#[cfg(feature = "sockcan")]
+pub mod error;
+pub mod errorframe;
+pub mod governor;
+pub mod j1939;
+pub mod layout;
+pub mod redact;
+pub mod replay;
+pub mod rewrite;
+#[cfg(unix)]
+pub mod shm;
+
+#[cfg(feature = "sockcan")]
+pub mod pool;
+#[cfg(feature = "sockcan")]
+pub mod raw;
+
+pub use crate::aggregate::*;
+pub use crate::alarm::*;
+#[cfg(feature = "sockcan")]
+pub use crate::bus::*;
+pub use crate::busload::*;
+pub use crate::correlate::*;
+pub use crate::database::*;
+pub use crate::derive::*;
+#[cfg(feature = "sockcan")]
+pub use crate::error::*;
+pub use crate::errorframe::*;
+pub use crate::governor::*;
+pub use crate::j1939::*;
+pub use crate::layout::*;
+pub use crate::redact::*;
+pub use crate::replay::*;
+pub use crate::rewrite::*;
+#[cfg(unix)]
+pub use crate::shm::*;
+pub use dbcparser::clock::{Clock, FixedClock, SystemClock};
+
+#[cfg(feature = "sockcan")]
+pub use crate::pool::*;
+#[cfg(feature = "sockcan")]
+pub use crate::raw::*;
+
+pub mod prelude {
+    pub use crate::aggregate::*;
+    pub use crate::alarm::*;
+    #[cfg(feature = "sockcan")]
+    pub use crate::bus::*;
+    pub use crate::busload::*;
+    pub use crate::correlate::*;
+    pub use crate::database::*;
+    pub use crate::derive::*;
+    #[cfg(feature = "sockcan")]
+    pub use crate::error::*;
+    pub use crate::errorframe::*;
+    pub use crate::governor::*;
+    pub use crate::j1939::*;
+    pub use crate::layout::*;
+    pub use crate::redact::*;
+    pub use crate::replay::*;
+    pub use crate::rewrite::*;
+    #[cfg(unix)]
+    pub use crate::shm::*;
+    pub use dbcparser::clock::{Clock, FixedClock, SystemClock};
+
+    #[cfg(feature = "sockcan")]
+    pub use crate::pool::*;
+    #[cfg(feature = "sockcan")]
+    pub use crate::raw::*;
+}
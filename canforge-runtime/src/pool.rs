@@ -0,0 +1,1012 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! sockcan `CanDbcPool`/`CanDbcMessage`/`CanDbcSignal` implementations backed by a
+//! [`RuntimeDbc`] instead of generated code.
+
+use crate::busload::{BusMonitor, BusStats};
+use crate::database::{MessageDef, RuntimeDbc, SignalDef};
+use crate::derive::DerivedDef;
+use crate::errorframe::{BusState, ErrorFrame};
+use crate::j1939::{
+    pgn, source_address, AddressTable, DiagnosticMessage, Dtc, Name, TpMessage, TpReassembler,
+    PGN_ADDRESS_CLAIMED, PGN_DM1, PGN_DM2,
+};
+use crate::layout::{SignalKind, SignalValue};
+use can_dbc::MultiplexIndicator;
+use dbcparser::errors::CanErrorCode;
+use serde::Serialize;
+use sockcan::prelude::*;
+use std::any::Any;
+use std::cell::{RefCell, RefMut};
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, Error};
+use std::rc::Rc;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+
+/// Interpreted signal, the runtime counterpart of a generated signal struct.
+pub struct RuntimeSignal {
+    def: Arc<SignalDef>,
+    callback: Option<RefCell<Box<dyn CanSigCtrl>>>,
+    status: CanDataStatus,
+    stamp: u64,
+    value: Option<SignalValue>,
+}
+
+#[derive(Serialize)]
+struct SignalJson<'a> {
+    status: CanDataStatus,
+    name: &'a str,
+    stamp: u64,
+    value: Option<SignalValue>,
+}
+
+impl RuntimeSignal {
+    #[must_use]
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(def: Arc<SignalDef>) -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
+        Rc::new(RefCell::new(Box::new(RuntimeSignal {
+            def,
+            callback: None,
+            status: CanDataStatus::Unset,
+            stamp: 0,
+            value: None,
+        })))
+    }
+
+    /// DBC definition backing this signal.
+    #[must_use]
+    pub fn get_def(&self) -> &SignalDef {
+        &self.def
+    }
+
+    /// Current value, or the type default when no frame was received yet.
+    #[must_use]
+    pub fn get_typed_value(&self) -> SignalValue {
+        self.value.unwrap_or_else(|| SignalValue::default_for(self.def.kind))
+    }
+
+    fn take_callback(&mut self) -> Option<Box<dyn CanSigCtrl>> {
+        self.callback.take().map(RefCell::into_inner)
+    }
+}
+
+impl CanDbcSignal for RuntimeSignal {
+    fn get_name(&self) -> &'static str {
+        self.def.name
+    }
+
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
+
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => match self.def.decode(&frame.data[..]) {
+                Some(newval) => {
+                    let changed = self.value != Some(newval);
+                    self.value = Some(newval);
+                    if changed {
+                        self.status = CanDataStatus::Updated;
+                        self.stamp = frame.stamp;
+                    } else {
+                        self.status = CanDataStatus::Unchanged;
+                    }
+                },
+                None => self.status = CanDataStatus::Error,
+            },
+            CanBcmOpCode::RxTimeout => {
+                self.status = CanDataStatus::Timeout;
+            },
+            _ => {
+                self.status = CanDataStatus::Error;
+            },
+        }
+        match &self.callback {
+            None => 0,
+            Some(callback) => match callback.try_borrow() {
+                Err(_) => {
+                    println!("fail to get signal callback reference");
+                    -1
+                },
+                Ok(cb_ref) => cb_ref.sig_notification(self),
+            },
+        }
+    }
+
+    fn set_value(&mut self, value: CanDbcType, data: &mut [u8]) -> Result<(), CanError> {
+        let value = match self.def.kind {
+            SignalKind::Bool => SignalValue::Bool(value.cast()?),
+            SignalKind::U8 => SignalValue::U8(value.cast()?),
+            SignalKind::U16 => SignalValue::U16(value.cast()?),
+            SignalKind::U32 => SignalValue::U32(value.cast()?),
+            SignalKind::U64 => SignalValue::U64(value.cast()?),
+            SignalKind::I8 => SignalValue::I8(value.cast()?),
+            SignalKind::I16 => SignalValue::I16(value.cast()?),
+            SignalKind::I32 => SignalValue::I32(value.cast()?),
+            SignalKind::I64 => SignalValue::I64(value.cast()?),
+            SignalKind::F64 | SignalKind::F32 | SignalKind::F64Bits => {
+                SignalValue::F64(value.cast()?)
+            },
+        };
+        self.def.encode(value, data).map_err(|error| {
+            CanError::new(CanErrorCode::InvalidSignalValue.uid(), error.to_string())
+        })
+    }
+
+    fn get_value(&self) -> CanDbcType {
+        match self.get_typed_value() {
+            SignalValue::Bool(v) => CanDbcType::Bool(v),
+            SignalValue::U8(v) => CanDbcType::U8(v),
+            SignalValue::U16(v) => CanDbcType::U16(v),
+            SignalValue::U32(v) => CanDbcType::U32(v),
+            SignalValue::U64(v) => CanDbcType::U64(v),
+            SignalValue::I8(v) => CanDbcType::I8(v),
+            SignalValue::I16(v) => CanDbcType::I16(v),
+            SignalValue::I32(v) => CanDbcType::I32(v),
+            SignalValue::I64(v) => CanDbcType::I64(v),
+            SignalValue::F64(v) => CanDbcType::F64(v),
+        }
+    }
+
+    fn to_json(&self) -> String {
+        let json = SignalJson {
+            status: self.status,
+            name: self.def.name,
+            stamp: self.stamp,
+            value: self.value,
+        };
+        match serde_json::to_string(&json) {
+            Ok(json) => json,
+            _ => "serde-json-error".to_owned(),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.stamp = 0;
+        self.value = None;
+        self.status = CanDataStatus::Unset;
+    }
+
+    fn set_callback(&mut self, callback: Box<dyn CanSigCtrl>) {
+        self.callback = Some(RefCell::new(callback));
+    }
+}
+
+/// Derived signal appended to a message by [`RuntimePool::add_derived`], recomputed on every
+/// update of its message.
+pub struct RuntimeDerivedSignal {
+    def: Arc<DerivedDef>,
+    msg: Arc<MessageDef>,
+    inputs: Vec<usize>,
+    callback: Option<RefCell<Box<dyn CanSigCtrl>>>,
+    status: CanDataStatus,
+    stamp: u64,
+    value: Option<f64>,
+}
+
+impl RuntimeDerivedSignal {
+    /// Derived definition backing this signal.
+    #[must_use]
+    pub fn get_def(&self) -> &DerivedDef {
+        &self.def
+    }
+
+    fn take_callback(&mut self) -> Option<Box<dyn CanSigCtrl>> {
+        self.callback.take().map(RefCell::into_inner)
+    }
+}
+
+impl CanDbcSignal for RuntimeDerivedSignal {
+    fn get_name(&self) -> &'static str {
+        self.def.name
+    }
+
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
+
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let values = self.msg.decode(&frame.data[..]);
+                match self.def.compute(&self.inputs, &values) {
+                    Some(newval) => {
+                        let changed = self.value != Some(newval);
+                        self.value = Some(newval);
+                        if changed {
+                            self.status = CanDataStatus::Updated;
+                            self.stamp = frame.stamp;
+                        } else {
+                            self.status = CanDataStatus::Unchanged;
+                        }
+                    },
+                    // an input is on an inactive multiplexed page
+                    None => self.reset(),
+                }
+            },
+            CanBcmOpCode::RxTimeout => {
+                self.status = CanDataStatus::Timeout;
+            },
+            _ => {
+                self.status = CanDataStatus::Error;
+            },
+        }
+        match &self.callback {
+            None => 0,
+            Some(callback) => match callback.try_borrow() {
+                Err(_) => {
+                    println!("fail to get signal callback reference");
+                    -1
+                },
+                Ok(cb_ref) => cb_ref.sig_notification(self),
+            },
+        }
+    }
+
+    fn set_value(&mut self, _value: CanDbcType, _data: &mut [u8]) -> Result<(), CanError> {
+        Err(CanError::new(
+            CanErrorCode::DerivedSignalReadOnly.uid(),
+            format!("{} is computed from {:?}", self.def.name, self.def.inputs),
+        ))
+    }
+
+    fn get_value(&self) -> CanDbcType {
+        CanDbcType::F64(self.value.unwrap_or_default())
+    }
+
+    fn to_json(&self) -> String {
+        let json = SignalJson {
+            status: self.status,
+            name: self.def.name,
+            stamp: self.stamp,
+            value: self.value.map(SignalValue::F64),
+        };
+        match serde_json::to_string(&json) {
+            Ok(json) => json,
+            _ => "serde-json-error".to_owned(),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.stamp = 0;
+        self.value = None;
+        self.status = CanDataStatus::Unset;
+    }
+
+    fn set_callback(&mut self, callback: Box<dyn CanSigCtrl>) {
+        self.callback = Some(RefCell::new(callback));
+    }
+}
+
+/// Interpreted message, the runtime counterpart of a generated `DbcMessage`.
+pub struct RuntimeMessage {
+    def: Arc<MessageDef>,
+    callback: Option<RefCell<Box<dyn CanMsgCtrl>>>,
+    signals: Vec<Rc<RefCell<Box<dyn CanDbcSignal>>>>,
+    status: CanBcmOpCode,
+    listeners: i32,
+    signal_listeners: Vec<i32>,
+    stamp: u64,
+}
+
+impl RuntimeMessage {
+    #[must_use]
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(def: Arc<MessageDef>) -> Rc<RefCell<Box<dyn CanDbcMessage>>> {
+        let signals = def.signals.iter().map(|sig| RuntimeSignal::new(Arc::clone(sig))).collect();
+        Rc::new(RefCell::new(Box::new(RuntimeMessage {
+            def,
+            callback: None,
+            signals,
+            status: CanBcmOpCode::Unknown,
+            listeners: 0,
+            signal_listeners: Vec::new(),
+            stamp: 0,
+        })))
+    }
+
+    /// DBC definition backing this message.
+    #[must_use]
+    pub fn get_def(&self) -> &MessageDef {
+        &self.def
+    }
+
+    /// Return code of each signal callback (`CanSigCtrl::sig_notification`) on the last frame, in
+    /// `get_signals()` order: 0 without callback or for an inactive mux page, -1 when the
+    /// callback was busy. `get_listeners()` is their sum.
+    #[must_use]
+    pub fn get_signal_listeners(&self) -> &[i32] {
+        &self.signal_listeners
+    }
+
+    fn take_callback(&mut self) -> Option<Box<dyn CanMsgCtrl>> {
+        self.callback.take().map(RefCell::into_inner)
+    }
+
+    fn signal_name(&self, idx: usize) -> &'static str {
+        self.def.signals.get(idx).map_or("derived", |sig| sig.name)
+    }
+
+    fn signal_update(&mut self, idx: usize, frame: &CanMsgData) -> Result<(), CanError> {
+        match Rc::clone(&self.signals[idx]).try_borrow_mut() {
+            Ok(mut signal) => {
+                self.signal_listeners[idx] = signal.update(frame);
+                self.listeners += self.signal_listeners[idx];
+            },
+            Err(_) => {
+                return Err(CanError::new(
+                    CanErrorCode::SignalUpdateFail.uid(),
+                    format!("Internal error {}", self.signal_name(idx)),
+                ))
+            },
+        }
+        Ok(())
+    }
+
+    fn signal_reset(&self, idx: usize, err_tag: &str) -> Result<(), CanError> {
+        match Rc::clone(&self.signals[idx]).try_borrow_mut() {
+            Ok(mut signal) => signal.reset(),
+            Err(_) => {
+                return Err(CanError::new(
+                    err_tag,
+                    format!("Internal error {}", self.signal_name(idx)),
+                ))
+            },
+        }
+        Ok(())
+    }
+}
+
+impl CanDbcMessage for RuntimeMessage {
+    fn reset(&mut self) -> Result<(), CanError> {
+        self.status = CanBcmOpCode::Unknown;
+        self.stamp = 0;
+        for idx in 0..self.signals.len() {
+            self.signal_reset(idx, CanErrorCode::SignalResetFail.uid())?;
+        }
+        Ok(())
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> Result<(), CanError> {
+        self.stamp = frame.stamp;
+        self.status = frame.opcode;
+        self.listeners = 0;
+        self.signal_listeners.clear();
+        self.signal_listeners.resize(self.signals.len(), 0);
+
+        let def = Arc::clone(&self.def);
+        match def.multiplexor {
+            None => {
+                for idx in 0..def.signals.len() {
+                    self.signal_update(idx, frame)?;
+                }
+            },
+            Some(mux_idx) => {
+                // Always update the multiplexor first, then only the signals of the active page.
+                let mux_value = def.signals[mux_idx].mux_value(&frame.data[..]);
+                self.signal_update(mux_idx, frame)?;
+                for (idx, sig) in def.signals.iter().enumerate() {
+                    if idx == mux_idx {
+                        continue;
+                    }
+                    match sig.multiplexer {
+                        MultiplexIndicator::MultiplexedSignal(page) => {
+                            if mux_value == Some(page) {
+                                self.signal_update(idx, frame)?;
+                            } else {
+                                self.signal_reset(idx, CanErrorCode::SignalUpdateFail.uid())?;
+                            }
+                        },
+                        MultiplexIndicator::Plain
+                        | MultiplexIndicator::Multiplexor
+                        | MultiplexIndicator::MultiplexorAndMultiplexedSignal(_) => {
+                            self.signal_update(idx, frame)?;
+                        },
+                    }
+                }
+            },
+        }
+
+        // derived signals come last, once their inputs are up to date
+        for idx in def.signals.len()..self.signals.len() {
+            self.signal_update(idx, frame)?;
+        }
+
+        match &self.callback {
+            None => {},
+            Some(callback) => match callback.try_borrow() {
+                Err(_) => println!("fail to get message callback reference"),
+                Ok(cb_ref) => cb_ref.msg_notification(self),
+            },
+        }
+        Ok(())
+    }
+
+    fn get_signals(&self) -> &[Rc<RefCell<Box<dyn CanDbcSignal>>>] {
+        &self.signals
+    }
+
+    fn get_listeners(&self) -> i32 {
+        self.listeners
+    }
+
+    fn set_callback(&mut self, callback: Box<dyn CanMsgCtrl>) {
+        self.callback = Some(RefCell::new(callback));
+    }
+
+    fn get_name(&self) -> &'static str {
+        self.def.name
+    }
+
+    fn get_status(&self) -> CanBcmOpCode {
+        self.status
+    }
+
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
+
+    fn get_id(&self) -> u32 {
+        self.def.id
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Outcome of [`RuntimePool::reload`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReloadReport {
+    /// CAN ids present only in the new database.
+    pub added: Vec<u32>,
+    /// CAN ids dropped by the new database.
+    pub removed: Vec<u32>,
+    /// Callbacks moved to the new pool, as `Message` or `Message.Signal`.
+    pub kept: Vec<String>,
+    /// Callbacks and derived signals dropped because their message/signal no longer exists.
+    pub orphaned: Vec<String>,
+}
+
+/// Fallback receiving the frames whose id is not in the database.
+pub type RawHandler = Box<dyn Fn(&CanMsgData)>;
+
+/// Callbacks detached from a pool, keyed by generated message/signal names.
+#[derive(Default)]
+struct Subscriptions {
+    messages: HashMap<&'static str, Box<dyn CanMsgCtrl>>,
+    signals: HashMap<(&'static str, &'static str), Box<dyn CanSigCtrl>>,
+}
+
+/// J1939 state: last DM1/DM2 of each source address, address claims heard and transport
+/// sessions in progress.
+#[derive(Default)]
+struct Diagnostics {
+    dm1: BTreeMap<u8, DiagnosticMessage>,
+    dm2: BTreeMap<u8, DiagnosticMessage>,
+    addresses: AddressTable,
+    transport: TpReassembler,
+}
+
+/// Controller health reported by error frames.
+#[derive(Default)]
+struct BusHealth {
+    state: BusState,
+    last: Option<ErrorFrame>,
+    channels: Vec<Sender<ErrorFrame>>,
+}
+
+/// Message pool built from a [`RuntimeDbc`], usable wherever a generated `CanMsgPool` is.
+pub struct RuntimePool {
+    uid: &'static str,
+    dbc: RuntimeDbc,
+    ids: Vec<u32>,
+    pool: Vec<Rc<RefCell<Box<dyn CanDbcMessage>>>>,
+    derived: Vec<(u32, Arc<DerivedDef>)>,
+    j1939: bool,
+    diagnostics: RefCell<Diagnostics>,
+    monitor: Option<RefCell<BusMonitor>>,
+    health: RefCell<BusHealth>,
+    raw_handler: Option<RawHandler>,
+}
+
+impl RuntimePool {
+    #[must_use]
+    pub fn new(uid: &'static str, dbc: RuntimeDbc) -> Self {
+        let ids = dbc.get_messages().iter().map(|msg| msg.id).collect();
+        let pool = dbc
+            .get_messages()
+            .iter()
+            .map(|msg| RuntimeMessage::new(Arc::clone(msg)))
+            .collect();
+        RuntimePool {
+            uid,
+            dbc,
+            ids,
+            pool,
+            derived: Vec::new(),
+            j1939: false,
+            diagnostics: RefCell::new(Diagnostics::default()),
+            monitor: None,
+            health: RefCell::new(BusHealth::default()),
+            raw_handler: None,
+        }
+    }
+
+    /// Handle J1939 frames in `update()`: DM1/DM2 and address claims are recorded whether or
+    /// not the DBC describes them, transport protocol sessions are reassembled and decoded as
+    /// the DBC message of their PGN.
+    pub fn j1939(&mut self, flag: bool) -> &mut Self {
+        self.j1939 = flag;
+        self
+    }
+
+    /// Feed `monitor` with every frame given to `update()`, known to the database or not.
+    pub fn bus_monitor(&mut self, monitor: BusMonitor) -> &mut Self {
+        self.monitor = Some(RefCell::new(monitor));
+        self
+    }
+
+    /// Fallback receiving every frame whose id is not in the database (raw bytes and stamp),
+    /// e.g. to forward or log it from a gateway. `update()` still fails on these frames, see
+    /// [`RuntimePool::update_frame`].
+    pub fn set_raw_handler<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: Fn(&CanMsgData) + 'static,
+    {
+        self.raw_handler = Some(Box::new(handler));
+        self
+    }
+
+    /// True when `update()` hands frames with `canid` to the raw handler.
+    fn forwards(&self, canid: u32) -> bool {
+        self.raw_handler.is_some()
+            && self.ids.binary_search(&canid).is_err()
+            && !(self.j1939 && TpReassembler::is_transport(canid))
+    }
+
+    /// Like `update()`, but a frame given to the raw handler returns `Ok(None)`, so the pool
+    /// can ingest mixed traffic.
+    ///
+    /// # Errors
+    /// Returns the `update()` error of frames not given to the raw handler.
+    pub fn update_frame(
+        &self,
+        data: &CanMsgData,
+    ) -> Result<Option<RefMut<'_, Box<dyn CanDbcMessage>>>, CanError> {
+        match self.update(data) {
+            Ok(msg) => Ok(Some(msg)),
+            Err(error)
+                if self.forwards(data.canid)
+                    && CanErrorCode::from_uid(error.get_uid()) == CanErrorCode::UnknownCanId =>
+            {
+                Ok(None)
+            },
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Record in the bus monitor a frame that does not go through `update()`, such as an error
+    /// frame (`canid` flagged with `CAN_ERR_FLAG`). Does nothing without a monitor.
+    pub fn record_frame(&self, canid: u32, len: usize, stamp: u64) {
+        if let Some(monitor) = &self.monitor {
+            monitor.borrow_mut().record(canid, len, stamp);
+        }
+    }
+
+    /// Bus load, frame and error rates at the current time of the monitor clock, `None`
+    /// without a monitor.
+    #[must_use]
+    pub fn get_bus_stats(&self) -> Option<BusStats> {
+        self.monitor.as_ref().map(|monitor| monitor.borrow_mut().get_stats_now())
+    }
+
+    /// Decode an error frame read from a RAW socket (Linux `canid` with `CAN_ERR_FLAG`): the
+    /// bus state is updated, the frame counted by the bus monitor and sent to the
+    /// [`RuntimePool::subscribe_errors`] channels. Returns `None` for other frames.
+    pub fn update_error(&self, canid: u32, data: &[u8], stamp: u64) -> Option<ErrorFrame> {
+        let frame = ErrorFrame::decode(canid, data, stamp)?;
+        self.record_frame(canid, data.len(), stamp);
+        let mut health = self.health.borrow_mut();
+        if let Some(state) = frame.state {
+            health.state = state;
+        }
+        health.last = Some(frame.clone());
+        // drop channels whose receiver is gone
+        health.channels.retain(|channel| channel.send(frame.clone()).is_ok());
+        Some(frame)
+    }
+
+    /// Open a channel receiving every error frame given to [`RuntimePool::update_error`].
+    pub fn subscribe_errors(&mut self) -> Receiver<ErrorFrame> {
+        let (sender, receiver) = mpsc::channel();
+        self.health.get_mut().channels.push(sender);
+        receiver
+    }
+
+    /// Controller state from the last error frame telling it, `ErrorActive` until then.
+    #[must_use]
+    pub fn get_bus_state(&self) -> BusState {
+        self.health.borrow().state
+    }
+
+    /// Last error frame given to [`RuntimePool::update_error`].
+    #[must_use]
+    pub fn get_last_error(&self) -> Option<ErrorFrame> {
+        self.health.borrow().last.clone()
+    }
+
+    /// Record a DM1/DM2 payload (single frame or reassembled multi-packet) from `source`.
+    /// Returns false when `pgn` is not a DM1/DM2 one.
+    ///
+    /// # Errors
+    /// Returns an error when the payload is too short.
+    pub fn update_diagnostic(&self, pgn: u32, source: u8, payload: &[u8]) -> io::Result<bool> {
+        if pgn != PGN_DM1 && pgn != PGN_DM2 {
+            return Ok(false);
+        }
+        let message = DiagnosticMessage::decode(payload)?;
+        let mut diagnostics = self.diagnostics.borrow_mut();
+        let table = if pgn == PGN_DM1 { &mut diagnostics.dm1 } else { &mut diagnostics.dm2 };
+        table.insert(source, message);
+        Ok(true)
+    }
+
+    /// Last DM1 (active DTCs) sent by `source`.
+    #[must_use]
+    pub fn get_dm1(&self, source: u8) -> Option<DiagnosticMessage> {
+        self.diagnostics.borrow().dm1.get(&source).cloned()
+    }
+
+    /// Last DM2 (previously active DTCs) sent by `source`.
+    #[must_use]
+    pub fn get_dm2(&self, source: u8) -> Option<DiagnosticMessage> {
+        self.diagnostics.borrow().dm2.get(&source).cloned()
+    }
+
+    /// Decode a reassembled transport message with the DBC message of its PGN, preferring the
+    /// one declared for the same source address.
+    fn update_transport(
+        &self,
+        frame: &CanMsgData,
+        message: &TpMessage,
+    ) -> Result<RefMut<'_, Box<dyn CanDbcMessage>>, CanError> {
+        let to_error =
+            |error: Error| CanError::new(CanErrorCode::SignalUpdateFail.uid(), error.to_string());
+        self.update_diagnostic(message.pgn, message.source, &message.data)
+            .map_err(to_error)?;
+        let same_pgn = |id: &&u32| pgn(**id) == Some(message.pgn);
+        let canid = self
+            .ids
+            .iter()
+            .filter(same_pgn)
+            .find(|id| source_address(**id) == message.source)
+            .or_else(|| self.ids.iter().find(same_pgn))
+            .copied()
+            .ok_or_else(|| {
+                CanError::new(
+                    CanErrorCode::UnknownCanId.uid(),
+                    format!("pgn:{:#x} not found", message.pgn),
+                )
+            })?;
+        let mut msg = self.get_mut(canid)?;
+        msg.update(&CanMsgData {
+            canid,
+            stamp: frame.stamp,
+            opcode: frame.opcode,
+            len: u8::try_from(message.data.len()).unwrap_or(u8::MAX),
+            data: message.data.clone(),
+        })?;
+        Ok(msg)
+    }
+
+    /// NAME of the device that claimed `source`.
+    #[must_use]
+    pub fn get_name(&self, source: u8) -> Option<Name> {
+        self.diagnostics.borrow().addresses.get_name(source)
+    }
+
+    /// Address claims heard so far.
+    #[must_use]
+    pub fn get_address_table(&self) -> AddressTable {
+        self.diagnostics.borrow().addresses.clone()
+    }
+
+    /// Active DTCs of every source address, ordered by address; see [`RuntimePool::get_name`]
+    /// to attribute them to a device.
+    #[must_use]
+    pub fn get_active_dtcs(&self) -> Vec<(u8, Dtc)> {
+        self.diagnostics
+            .borrow()
+            .dm1
+            .iter()
+            .flat_map(|(source, dm1)| dm1.dtcs.iter().map(|dtc| (*source, *dtc)))
+            .collect()
+    }
+
+    #[must_use]
+    pub fn get_uid(&self) -> &'static str {
+        self.uid
+    }
+
+    /// Database the pool was built from.
+    #[must_use]
+    pub fn get_dbc(&self) -> &RuntimeDbc {
+        &self.dbc
+    }
+
+    /// Append a derived signal to message `canid`; it is exposed through `get_signals()` like
+    /// DBC signals and recomputed after them on every update.
+    ///
+    /// # Errors
+    /// Returns an error when the message is unknown or busy, an input is not one of its
+    /// signals, or the name is already used in the message.
+    pub fn add_derived(&mut self, canid: u32, def: DerivedDef) -> io::Result<()> {
+        let def = Arc::new(def);
+        self.attach_derived(canid, &def)?;
+        self.derived.push((canid, def));
+        Ok(())
+    }
+
+    fn attach_derived(&self, canid: u32, def: &Arc<DerivedDef>) -> io::Result<()> {
+        let idx = self
+            .ids
+            .binary_search(&canid)
+            .map_err(|_| Error::other(format!("derived:{} canid:{canid} not found", def.name)))?;
+        let mut msg = self.pool[idx]
+            .try_borrow_mut()
+            .map_err(|_| Error::other(format!("derived:{} message busy", def.name)))?;
+        let Some(msg) = msg.as_any().downcast_mut::<RuntimeMessage>() else {
+            return Err(Error::other(format!("derived:{} not a runtime message", def.name)));
+        };
+        let inputs = def.resolve(&msg.def)?;
+        if msg
+            .signals
+            .iter()
+            .any(|sig| sig.try_borrow().is_ok_and(|sig| sig.get_name() == def.name))
+        {
+            return Err(Error::other(format!(
+                "derived:{} already exists in message:{}",
+                def.name, msg.def.name
+            )));
+        }
+        let signal: Box<dyn CanDbcSignal> = Box::new(RuntimeDerivedSignal {
+            def: Arc::clone(def),
+            msg: Arc::clone(&msg.def),
+            inputs,
+            callback: None,
+            status: CanDataStatus::Unset,
+            stamp: 0,
+            value: None,
+        });
+        msg.signals.push(Rc::new(RefCell::new(signal)));
+        Ok(())
+    }
+
+    /// Replace the database while running.
+    ///
+    /// The new pool is fully built before being swapped in, so a failure leaves the current
+    /// one untouched. Derived signals, message and signal callbacks are moved over by name;
+    /// values restart as `Unset`. Message handles obtained from the previous pool keep working on the old
+    /// database and should be dropped by the caller.
+    ///
+    /// # Errors
+    /// Returns an error when a message or signal of the current pool is still borrowed.
+    pub fn reload(&mut self, dbc: RuntimeDbc) -> io::Result<ReloadReport> {
+        // the only fallible step on the current pool, before any of its state moves
+        let mut subscriptions = self.take_subscriptions()?;
+        let mut next = RuntimePool::new(self.uid, dbc);
+        let mut report = ReloadReport {
+            added: next
+                .ids
+                .iter()
+                .filter(|id| self.ids.binary_search(id).is_err())
+                .copied()
+                .collect(),
+            removed: self
+                .ids
+                .iter()
+                .filter(|id| next.ids.binary_search(id).is_err())
+                .copied()
+                .collect(),
+            ..ReloadReport::default()
+        };
+
+        for (canid, def) in &self.derived {
+            let name = format!("{}.{}", self.get_message_name(*canid), def.name);
+            match next.attach_derived(*canid, def) {
+                Ok(()) => next.derived.push((*canid, Arc::clone(def))),
+                Err(_) => report.orphaned.push(name),
+            }
+        }
+
+        for msg in &next.pool {
+            let mut msg = msg.borrow_mut();
+            let msg = match msg.as_any().downcast_mut::<RuntimeMessage>() {
+                Some(msg) => msg,
+                None => continue,
+            };
+            let msg_name = msg.def.name;
+            if let Some(callback) = subscriptions.messages.remove(msg_name) {
+                msg.callback = Some(RefCell::new(callback));
+                report.kept.push(msg_name.to_owned());
+            }
+            for signal in &msg.signals {
+                let mut signal = signal.borrow_mut();
+                let sig_name = signal.get_name();
+                if let Some(callback) = subscriptions.signals.remove(&(msg_name, sig_name)) {
+                    signal.set_callback(callback);
+                    report.kept.push(format!("{msg_name}.{sig_name}"));
+                }
+            }
+        }
+
+        report.orphaned.extend(subscriptions.messages.into_keys().map(str::to_owned));
+        report
+            .orphaned
+            .extend(subscriptions.signals.into_keys().map(|(msg, sig)| format!("{msg}.{sig}")));
+        report.kept.sort();
+        report.orphaned.sort();
+
+        next.j1939 = self.j1939;
+        next.diagnostics = RefCell::new(self.diagnostics.take());
+        next.monitor = self.monitor.take();
+        next.health = RefCell::new(self.health.take());
+        next.raw_handler = self.raw_handler.take();
+        *self = next;
+        Ok(report)
+    }
+
+    fn get_message_name(&self, canid: u32) -> &'static str {
+        self.dbc.get_message(canid).map_or("unknown", |msg| msg.name)
+    }
+
+    /// Reload the database from the file it was loaded from.
+    ///
+    /// # Errors
+    /// Returns an error when the database was not loaded from a file, cannot be parsed, or
+    /// when [`RuntimePool::reload`] fails. The current pool is kept on error.
+    pub fn reload_file(&mut self) -> io::Result<ReloadReport> {
+        let path = self
+            .dbc
+            .get_source()
+            .ok_or_else(|| Error::other(format!("pool:{} not loaded from a file", self.uid)))?;
+        let dbc = RuntimeDbc::from_file(path)?;
+        self.reload(dbc)
+    }
+
+    /// Detach every callback, after checking nothing is borrowed so the pool is left intact
+    /// on error.
+    fn take_subscriptions(&self) -> io::Result<Subscriptions> {
+        let busy = |name: &str| Error::other(format!("pool:{} {name} still borrowed", self.uid));
+        for msg in &self.pool {
+            let msg = msg.try_borrow_mut().map_err(|_| busy("message"))?;
+            for signal in msg.get_signals() {
+                signal.try_borrow_mut().map_err(|_| busy(msg.get_name()))?;
+            }
+        }
+
+        let mut subscriptions = Subscriptions::default();
+        for msg in &self.pool {
+            let mut msg = msg.borrow_mut();
+            let msg = match msg.as_any().downcast_mut::<RuntimeMessage>() {
+                Some(msg) => msg,
+                None => continue,
+            };
+            let msg_name = msg.def.name;
+            if let Some(callback) = msg.take_callback() {
+                subscriptions.messages.insert(msg_name, callback);
+            }
+            for signal in &msg.signals {
+                let mut signal = signal.borrow_mut();
+                let sig_name = signal.get_name();
+                let any = signal.as_any();
+                let callback = if let Some(signal) = any.downcast_mut::<RuntimeSignal>() {
+                    signal.take_callback()
+                } else if let Some(signal) = any.downcast_mut::<RuntimeDerivedSignal>() {
+                    signal.take_callback()
+                } else {
+                    None
+                };
+                if let Some(callback) = callback {
+                    subscriptions.signals.insert((msg_name, sig_name), callback);
+                }
+            }
+        }
+        Ok(subscriptions)
+    }
+}
+
+impl CanDbcPool for RuntimePool {
+    fn get_messages(&self) -> &[Rc<RefCell<Box<dyn CanDbcMessage>>>] {
+        &self.pool
+    }
+
+    fn get_ids(&self) -> &[u32] {
+        &self.ids
+    }
+
+    fn get_mut(&self, canid: u32) -> Result<RefMut<'_, Box<dyn CanDbcMessage>>, CanError> {
+        match self.ids.binary_search(&canid) {
+            Ok(idx) => match self.pool[idx].try_borrow_mut() {
+                Err(_code) => {
+                    Err(CanError::new(CanErrorCode::MessageGetMut.uid(), "internal msg pool error"))
+                },
+                Ok(mut_ref) => Ok(mut_ref),
+            },
+            Err(_) => Err(CanError::new(
+                CanErrorCode::UnknownCanId.uid(),
+                format!("canid:{canid} not found"),
+            )),
+        }
+    }
+
+    fn update(&self, data: &CanMsgData) -> Result<RefMut<'_, Box<dyn CanDbcMessage>>, CanError> {
+        self.record_frame(data.canid, data.data.len(), data.stamp);
+        if self.j1939 && TpReassembler::is_transport(data.canid) {
+            let pushed =
+                self.diagnostics.borrow_mut().transport.push(data.canid, data.stamp, &data.data);
+            return match pushed {
+                Ok(Some(message)) => self.update_transport(data, &message),
+                Ok(None) => Err(CanError::new(
+                    CanErrorCode::TransportPending.uid(),
+                    format!("canid:{:#x} transport session in progress", data.canid),
+                )),
+                Err(error) => {
+                    Err(CanError::new(CanErrorCode::SignalUpdateFail.uid(), error.to_string()))
+                },
+            };
+        }
+        if let Some(pgn) = pgn(data.canid).filter(|_| self.j1939) {
+            let source = source_address(data.canid);
+            let recorded = if pgn == PGN_ADDRESS_CLAIMED {
+                self.diagnostics.borrow_mut().addresses.update(source, &data.data)
+            } else {
+                self.update_diagnostic(pgn, source, &data.data).map(|_| ())
+            };
+            recorded.map_err(|error| {
+                CanError::new(CanErrorCode::SignalUpdateFail.uid(), error.to_string())
+            })?;
+        }
+        if self.forwards(data.canid) {
+            if let Some(handler) = &self.raw_handler {
+                handler(data);
+            }
+        }
+        let mut msg = self.get_mut(data.canid)?;
+        msg.update(data)?;
+        Ok(msg)
+    }
+}
@@ -0,0 +1,68 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! `CanMsgData` from RAW socket frames.
+//!
+//! Pools (generated or [`crate::RuntimePool`]) decode BCM notifications and only act on
+//! `RxChanged`/`RxTimeout` opcodes. A RAW socket delivers every frame without opcode; these
+//! helpers wrap it as the `RxChanged` notification BCM would have sent.
+//!
+//! ```no_run
+//! # use canforge_runtime::prelude::*;
+//! # use sockcan::prelude::*;
+//! # fn feed(pool: &dyn CanDbcPool, frame: &CanAnyFrame, stamp: u64) -> Result<(), CanError> {
+//! pool.update(&frame_msg_data(frame, stamp)?)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::errorframe::CAN_ERR_FLAG;
+use dbcparser::errors::CanErrorCode;
+use sockcan::prelude::*;
+
+/// Linux `can_id` remote request flag.
+const CAN_RTR_FLAG: u32 = 0x4000_0000;
+
+/// `CanMsgData` for a frame with `canid` (Linux `can_id`, extended ids keep bit 31 like DBC
+/// ids) and `data`, received at `stamp` microseconds.
+///
+/// # Errors
+/// Returns an `InvalidFrame` error for error and remote frames, which carry no signal.
+pub fn raw_msg_data(canid: u32, stamp: u64, data: &[u8]) -> Result<CanMsgData, CanError> {
+    if canid & (CAN_ERR_FLAG | CAN_RTR_FLAG) != 0 {
+        return Err(CanError::new(
+            CanErrorCode::InvalidFrame.uid(),
+            format!("canid:{canid:#x} error or remote frame"),
+        ));
+    }
+    let len = u8::try_from(data.len()).map_err(|_| {
+        CanError::new(
+            CanErrorCode::InvalidFrame.uid(),
+            format!("canid:{canid:#x} {} bytes payload", data.len()),
+        )
+    })?;
+    Ok(CanMsgData { canid, stamp, opcode: CanBcmOpCode::RxChanged, len, data: data.to_vec() })
+}
+
+/// `CanMsgData` for a classic or FD frame read from a RAW socket, received at `stamp`
+/// microseconds.
+///
+/// # Errors
+/// Forwards the error of `CanAnyFrame::Err`/`None` frames, see [`raw_msg_data`] otherwise.
+pub fn frame_msg_data(frame: &CanAnyFrame, stamp: u64) -> Result<CanMsgData, CanError> {
+    raw_msg_data(frame.get_id()?, stamp, frame.get_data()?)
+}
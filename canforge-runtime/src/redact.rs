@@ -0,0 +1,189 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Privacy filter applied to decoded values before they reach an export sink.
+//!
+//! ```yaml
+//! salt: "fleet-2026"
+//! rules:
+//!   - signal: "GpsPosition.*"
+//!     action: { round: 0.01 }
+//!   - signal: "VinPart"
+//!     action: hash
+//!   - signal: "DriverId.*"
+//!     action: drop
+//! ```
+
+use crate::database::{signal_matches, MessageDef, SignalDef};
+use crate::layout::SignalValue;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt::Write;
+use std::io::{self, Error};
+use std::sync::Arc;
+
+/// What to do with a matching signal.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RedactAction {
+    /// Never export the signal.
+    Drop,
+    /// Round the physical value to a multiple of `step` (e.g. 0.01 deg for GPS).
+    Round(f64),
+    /// Replace the value with a salted SHA-256 digest (stable, not reversible).
+    Hash,
+}
+
+/// One redaction rule.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RedactRule {
+    /// `Signal`, `Message.Signal` or `Message.*`; generated or DBC names, `*` matches all.
+    pub signal: String,
+    #[serde(with = "serde_yaml::with::singleton_map")]
+    pub action: RedactAction,
+}
+
+/// Redaction configuration, usually loaded from YAML.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct RedactConfig {
+    /// Mixed into every hash so digests cannot be matched across fleets.
+    #[serde(default)]
+    pub salt: String,
+    #[serde(default)]
+    pub rules: Vec<RedactRule>,
+}
+
+/// Value handed to export sinks once redacted.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum ExportValue {
+    Value(SignalValue),
+    Hashed(String),
+}
+
+/// Applies a [`RedactConfig`]; the first matching rule wins.
+#[derive(Debug, Clone, Default)]
+pub struct Redactor {
+    config: RedactConfig,
+}
+
+impl Redactor {
+    /// # Errors
+    /// Returns an error when a rounding step is not strictly positive.
+    pub fn new(config: RedactConfig) -> io::Result<Self> {
+        for rule in &config.rules {
+            if let RedactAction::Round(step) = rule.action {
+                if step.is_nan() || step <= 0.0 {
+                    return Err(Error::other(format!(
+                        "redact rule:{} invalid round step:{step}",
+                        rule.signal
+                    )));
+                }
+            }
+        }
+        Ok(Redactor { config })
+    }
+
+    /// Load a configuration from YAML text.
+    ///
+    /// # Errors
+    /// Returns an error when the YAML is invalid or a rule is inconsistent.
+    pub fn from_yaml(yaml: &str) -> io::Result<Self> {
+        let config: RedactConfig = serde_yaml::from_str(yaml).map_err(Error::other)?;
+        Redactor::new(config)
+    }
+
+    #[must_use]
+    pub fn get_config(&self) -> &RedactConfig {
+        &self.config
+    }
+
+    /// Action applying to a signal, `None` when it is exported as is.
+    #[must_use]
+    pub fn action(&self, msg: &MessageDef, sig: &SignalDef) -> Option<RedactAction> {
+        self.config
+            .rules
+            .iter()
+            .find(|rule| signal_matches(&rule.signal, msg, sig))
+            .map(|rule| rule.action)
+    }
+
+    /// Redact one value, `None` when it must not be exported.
+    #[must_use]
+    pub fn apply(
+        &self,
+        msg: &MessageDef,
+        sig: &SignalDef,
+        value: SignalValue,
+    ) -> Option<ExportValue> {
+        match self.action(msg, sig) {
+            None => Some(ExportValue::Value(value)),
+            Some(RedactAction::Drop) => None,
+            Some(RedactAction::Round(step)) => {
+                let rounded = (value.as_f64() / step).round() * step;
+                Some(ExportValue::Value(SignalValue::F64(rounded)))
+            },
+            Some(RedactAction::Hash) => Some(ExportValue::Hashed(self.digest(msg, sig, value))),
+        }
+    }
+
+    /// Redact a frame decoded with [`MessageDef::decode`], keeping signal order and skipping
+    /// inactive or dropped signals.
+    #[must_use]
+    pub fn apply_frame(
+        &self,
+        msg: &MessageDef,
+        values: &[Option<SignalValue>],
+    ) -> Vec<(Arc<SignalDef>, ExportValue)> {
+        msg.signals
+            .iter()
+            .zip(values)
+            .filter_map(|(sig, value)| {
+                let value = self.apply(msg, sig, (*value)?)?;
+                Some((Arc::clone(sig), value))
+            })
+            .collect()
+    }
+
+    fn digest(&self, msg: &MessageDef, sig: &SignalDef, value: SignalValue) -> String {
+        let mut hasher = Sha256::new();
+        // length prefixed, so "AB"+"C" and "A"+"BC" differ
+        for field in [self.config.salt.as_str(), &msg.dbc_name, &sig.dbc_name] {
+            hasher.update((field.len() as u64).to_le_bytes());
+            hasher.update(field.as_bytes());
+        }
+        match value {
+            // -0.0 and 0.0 are the same sample
+            SignalValue::F64(value) => hasher.update((value + 0.0).to_le_bytes()),
+            // integers hash exactly, as_f64() would merge 64-bit values above 2^53
+            SignalValue::Bool(value) => hasher.update([u8::from(value)]),
+            SignalValue::U8(value) => hasher.update(value.to_le_bytes()),
+            SignalValue::U16(value) => hasher.update(value.to_le_bytes()),
+            SignalValue::U32(value) => hasher.update(value.to_le_bytes()),
+            SignalValue::U64(value) => hasher.update(value.to_le_bytes()),
+            SignalValue::I8(value) => hasher.update(value.to_le_bytes()),
+            SignalValue::I16(value) => hasher.update(value.to_le_bytes()),
+            SignalValue::I32(value) => hasher.update(value.to_le_bytes()),
+            SignalValue::I64(value) => hasher.update(value.to_le_bytes()),
+        }
+        // 64 bits are plenty to correlate samples while keeping exports compact
+        hasher.finalize()[..8].iter().fold(String::with_capacity(16), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+    }
+}
@@ -0,0 +1,324 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Replay of recorded frames with time warping.
+//!
+//! A [`Replay`] holds the frames of a capture, usually a `candump -l` log, and releases them
+//! at the pace they were recorded, sped up or slowed down by `speed` (0.1 to 100). `start_ms`
+//! and `stop_ms`, offsets from the first frame of the log, restrict the replay to a window,
+//! and `step: true` only releases frames one by one through [`Replay::step`], to examine an
+//! event deterministically. Released frames keep their recorded stamp, feed them to a pool
+//! with `raw_msg_data()`.
+//!
+//! ```yaml
+//! speed: 60         # one hour of log in one minute
+//! start_ms: 120000  # skip the first two minutes
+//! stop_ms: 0        # 0: up to the end of the log
+//! step: false
+//! ```
+
+use crate::errorframe::CAN_ERR_FLAG;
+use dbcparser::clock::{Clock, SystemClock};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Error};
+
+/// Linux `can_id` extended frame flag, set on 8 digit candump ids like on DBC ids.
+const CAN_EFF_FLAG: u32 = 0x8000_0000;
+/// Linux `can_id` remote request flag.
+pub(crate) const CAN_RTR_FLAG: u32 = 0x4000_0000;
+
+/// Accepted range of the speed factor.
+pub const REPLAY_SPEED_RANGE: std::ops::RangeInclusive<f64> = 0.1..=100.0;
+
+fn default_speed() -> f64 {
+    1.0
+}
+
+/// Replay configuration, usually loaded from YAML.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ReplayConfig {
+    /// Replay time factor, 2.0 replays twice as fast as recorded.
+    #[serde(default = "default_speed")]
+    pub speed: f64,
+    /// Offset from the first frame of the log where the replay starts.
+    #[serde(default)]
+    pub start_ms: u64,
+    /// Offset from the first frame of the log where the replay stops (excluded), 0 for the
+    /// end of the log.
+    #[serde(default)]
+    pub stop_ms: u64,
+    /// Release frames with [`Replay::step`] only.
+    #[serde(default)]
+    pub step: bool,
+}
+
+impl Default for ReplayConfig {
+    fn default() -> Self {
+        ReplayConfig { speed: default_speed(), start_ms: 0, stop_ms: 0, step: false }
+    }
+}
+
+/// One recorded frame.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReplayFrame {
+    /// Microseconds, as recorded.
+    pub stamp: u64,
+    pub iface: String,
+    /// Linux `can_id`, extended ids keep bit 31 like DBC ids.
+    pub canid: u32,
+    pub data: Vec<u8>,
+}
+
+fn parse_hex(text: &str, line: &str) -> io::Result<u32> {
+    u32::from_str_radix(text, 16)
+        .map_err(|_| Error::other(format!("candump line:{line:?} invalid hex:{text}")))
+}
+
+impl ReplayFrame {
+    /// Parse one `candump -l` line: `(1436509052.249713) can0 123#DEADBEEF`, `##` for CAN FD
+    /// frames (flags nibble first) and `#R` for remote requests.
+    ///
+    /// # Errors
+    /// Returns an error when the line is not in candump log format.
+    pub fn from_candump(line: &str) -> io::Result<Self> {
+        let invalid = || Error::other(format!("candump line:{line:?} not in log format"));
+        let mut fields = line.split_whitespace();
+        let (Some(time), Some(iface), Some(frame), None) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            return Err(invalid());
+        };
+        let time = time.strip_prefix('(').and_then(|time| time.strip_suffix(')'));
+        let (secs, frac) = time.and_then(|time| time.split_once('.')).ok_or_else(invalid)?;
+        let secs: u64 = secs.parse().map_err(|_| invalid())?;
+        // candump writes microseconds, other tools nanoseconds or milliseconds
+        let micros = format!("{frac:0<6}");
+        let micros: u64 = micros.get(..6).ok_or_else(invalid)?.parse().map_err(|_| invalid())?;
+        let stamp = secs.saturating_mul(1_000_000).saturating_add(micros);
+
+        let (id, payload) = frame.split_once('#').ok_or_else(invalid)?;
+        let mut canid = parse_hex(id, line)?;
+        if id.len() > 3 && canid & CAN_ERR_FLAG == 0 {
+            canid |= CAN_EFF_FLAG;
+        }
+        let payload = match payload.strip_prefix('#') {
+            // CAN FD flags nibble, not part of the payload
+            Some(fd) => fd.get(1..).ok_or_else(invalid)?,
+            None if payload.starts_with('R') => {
+                canid |= CAN_RTR_FLAG;
+                ""
+            },
+            None => payload,
+        };
+        // byte offsets below must fall on char boundaries
+        if !payload.is_ascii() || payload.len() % 2 != 0 {
+            return Err(invalid());
+        }
+        let data = (0..payload.len())
+            .step_by(2)
+            .map(|pos| parse_hex(&payload[pos..pos + 2], line).map(|byte| byte as u8))
+            .collect::<io::Result<Vec<u8>>>()?;
+        Ok(ReplayFrame { stamp, iface: iface.to_owned(), canid, data })
+    }
+
+    /// Format the frame as a `candump -l` line, payloads longer than 8 bytes as CAN FD frames.
+    #[must_use]
+    pub fn to_candump(&self) -> String {
+        let id = if self.canid & (CAN_EFF_FLAG | CAN_ERR_FLAG) == 0 {
+            format!("{:03X}", self.canid & 0x7ff)
+        } else {
+            format!("{:08X}", self.canid & !(CAN_EFF_FLAG | CAN_RTR_FLAG))
+        };
+        let payload: String = self.data.iter().map(|byte| format!("{byte:02X}")).collect();
+        let separator = match self.data.len() {
+            _ if self.canid & CAN_RTR_FLAG != 0 => "#R",
+            0..=8 => "#",
+            _ => "##0",
+        };
+        format!(
+            "({}.{:06}) {} {id}{separator}{payload}",
+            self.stamp / 1_000_000,
+            self.stamp % 1_000_000,
+            self.iface
+        )
+    }
+}
+
+/// Releases recorded frames at a warped pace, see the module documentation.
+pub struct Replay {
+    config: ReplayConfig,
+    /// Frames of the replay window, in stamp order.
+    frames: Vec<ReplayFrame>,
+    /// Index of the next frame to release.
+    next: usize,
+    /// Wall time and recorded stamp the replay pace is computed from.
+    origin: Option<(u64, u64)>,
+    /// System clock when unset.
+    clock: Option<Box<dyn Clock>>,
+}
+
+impl Replay {
+    /// Replay `frames`, sorted by stamp, restricted to the configured window.
+    ///
+    /// # Errors
+    /// Returns an error when the speed is out of [`REPLAY_SPEED_RANGE`] or `stop_ms` is not
+    /// after `start_ms`.
+    pub fn new(config: ReplayConfig, mut frames: Vec<ReplayFrame>) -> io::Result<Self> {
+        if !REPLAY_SPEED_RANGE.contains(&config.speed) {
+            return Err(Error::other(format!(
+                "replay speed:{} not in [{}..{}]",
+                config.speed,
+                REPLAY_SPEED_RANGE.start(),
+                REPLAY_SPEED_RANGE.end()
+            )));
+        }
+        if config.stop_ms != 0 && config.stop_ms <= config.start_ms {
+            return Err(Error::other(format!(
+                "replay stop_ms:{} not after start_ms:{}",
+                config.stop_ms, config.start_ms
+            )));
+        }
+        frames.sort_by_key(|frame| frame.stamp);
+        if let Some(first) = frames.first().map(|frame| frame.stamp) {
+            let start = first.saturating_add(config.start_ms.saturating_mul(1000));
+            let stop = match config.stop_ms {
+                0 => u64::MAX,
+                stop => first.saturating_add(stop.saturating_mul(1000)),
+            };
+            frames.retain(|frame| frame.stamp >= start && frame.stamp < stop);
+        }
+        Ok(Replay { config, frames, next: 0, origin: None, clock: None })
+    }
+
+    /// Replay the frames of a `candump -l` log, empty lines ignored.
+    ///
+    /// # Errors
+    /// Returns an error on the first invalid line, see [`Replay::new`] otherwise.
+    pub fn from_candump(config: ReplayConfig, log: &str) -> io::Result<Self> {
+        let frames = log
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(ReplayFrame::from_candump)
+            .collect::<io::Result<Vec<ReplayFrame>>>()?;
+        Replay::new(config, frames)
+    }
+
+    /// Load a configuration from YAML text and replay a `candump -l` log.
+    ///
+    /// # Errors
+    /// Returns an error when the YAML is invalid or [`Replay::from_candump`] fails.
+    pub fn from_yaml(yaml: &str, log: &str) -> io::Result<Self> {
+        let config: ReplayConfig = serde_yaml::from_str(yaml).map_err(Error::other)?;
+        Replay::from_candump(config, log)
+    }
+
+    /// Time source of [`Replay::poll_now`] and [`Replay::delay_now`], the system clock by
+    /// default.
+    pub fn set_clock(&mut self, clock: Box<dyn Clock>) {
+        self.clock = Some(clock);
+    }
+
+    #[must_use]
+    pub fn get_config(&self) -> &ReplayConfig {
+        &self.config
+    }
+
+    /// Frames of the replay window, released or not.
+    #[must_use]
+    pub fn get_frames(&self) -> &[ReplayFrame] {
+        &self.frames
+    }
+
+    /// Frames not released yet.
+    #[must_use]
+    pub fn get_remaining(&self) -> usize {
+        self.frames.len() - self.next
+    }
+
+    #[must_use]
+    pub fn is_done(&self) -> bool {
+        self.next == self.frames.len()
+    }
+
+    fn now(&self) -> u64 {
+        self.clock.as_deref().map_or_else(|| SystemClock.now_us(), Clock::now_us)
+    }
+
+    /// Wall time, in microseconds, the next frame is due at, the first poll anchoring the
+    /// replay at `now`.
+    fn due(&mut self, now: u64) -> Option<u64> {
+        let stamp = self.frames.get(self.next)?.stamp;
+        let (wall, origin) = *self.origin.get_or_insert((now, stamp));
+        let elapsed = stamp.saturating_sub(origin) as f64 / self.config.speed;
+        Some(wall.saturating_add(elapsed as u64))
+    }
+
+    /// Release the frames due at `now` (microseconds of the replay clock). The first call
+    /// releases the first frame of the window and sets the pace of the following ones. Nothing
+    /// is released in step mode.
+    pub fn poll(&mut self, now: u64) -> &[ReplayFrame] {
+        let first = self.next;
+        if !self.config.step {
+            while self.due(now).is_some_and(|due| due <= now) {
+                self.next += 1;
+            }
+        }
+        &self.frames[first..self.next]
+    }
+
+    /// [`Replay::poll`] at the current time of the replay clock.
+    pub fn poll_now(&mut self) -> &[ReplayFrame] {
+        let now = self.now();
+        self.poll(now)
+    }
+
+    /// Microseconds from `now` until the next frame is due, to sleep between polls. `None`
+    /// once the replay is done or in step mode.
+    pub fn delay(&mut self, now: u64) -> Option<u64> {
+        if self.config.step {
+            return None;
+        }
+        self.due(now).map(|due| due.saturating_sub(now))
+    }
+
+    /// [`Replay::delay`] at the current time of the replay clock.
+    pub fn delay_now(&mut self) -> Option<u64> {
+        let now = self.now();
+        self.delay(now)
+    }
+
+    /// Release the next frame whatever its due time. Timed polling resumes from the frame
+    /// after it, as if the replay started there.
+    pub fn step(&mut self) -> Option<&ReplayFrame> {
+        let frame = self.frames.get(self.next)?;
+        self.next += 1;
+        self.origin = None;
+        Some(frame)
+    }
+
+    /// Switch between timed and step mode, timed replay resuming from the next frame.
+    pub fn set_step(&mut self, step: bool) {
+        self.config.step = step;
+        self.origin = None;
+    }
+
+    /// Restart from the first frame of the window.
+    pub fn rewind(&mut self) {
+        self.next = 0;
+        self.origin = None;
+    }
+}
@@ -0,0 +1,228 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Rewriting of recorded frames before a capture leaves the lab.
+//!
+//! A [`LogRewriter`] drops messages, scrubs signals and remaps ids of a recording, so a field
+//! capture can be shared with a supplier without its sensitive channels. Kept frames keep their
+//! stamp, the rewritten log replays with the original timing. Messages are selected by
+//! generated or DBC name or by id, signals with the [`signal_matches`] patterns, and the first
+//! matching rule wins.
+//!
+//! ```yaml
+//! drop_unknown: true        # drop frames the database does not describe
+//! messages:
+//!   - message: "DriverInfo"
+//!     action: drop
+//!   - message: "0x3A0"
+//!     action: { remap: 0x5A0 }
+//! signals:
+//!   - signal: "GpsPosition.*"
+//!     value: 0              # physical value written instead, 0 by default
+//! ```
+
+use crate::database::{signal_matches, MessageDef, RuntimeDbc, SignalDef};
+use crate::errorframe::CAN_ERR_FLAG;
+use crate::layout::{SignalKind, SignalValue};
+use crate::replay::{ReplayFrame, CAN_RTR_FLAG};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Error};
+use std::sync::Arc;
+
+/// What to do with the frames of a matching message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageAction {
+    /// Remove the frames from the log.
+    Drop,
+    /// Replace the id, extended ids with bit 31 set like DBC ids.
+    Remap(u32),
+}
+
+/// One message rule.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MessageRule {
+    /// Generated or DBC name, or id (`0x3A0` or decimal) of a message, in the database or not.
+    pub message: String,
+    #[serde(with = "serde_yaml::with::singleton_map")]
+    pub action: MessageAction,
+}
+
+/// One signal rule, overwriting the signal in every frame it is active in.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ScrubRule {
+    /// `Signal`, `Message.Signal` or `Message.*`; generated or DBC names, `*` matches all.
+    pub signal: String,
+    /// Physical value written instead of the recorded one.
+    #[serde(default)]
+    pub value: f64,
+}
+
+/// Rewriting configuration, usually loaded from YAML.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct RewriteConfig {
+    /// Drop the frames of ids the database does not describe, error frames excepted.
+    #[serde(default)]
+    pub drop_unknown: bool,
+    #[serde(default)]
+    pub messages: Vec<MessageRule>,
+    #[serde(default)]
+    pub signals: Vec<ScrubRule>,
+}
+
+/// Signals a message gets scrubbed, with the value written into them.
+struct Scrub {
+    msg: Arc<MessageDef>,
+    signals: Vec<(Arc<SignalDef>, SignalValue)>,
+}
+
+/// Applies a [`RewriteConfig`] to recorded frames, see the module documentation.
+pub struct LogRewriter {
+    config: RewriteConfig,
+    /// Ids of the database messages.
+    known: HashSet<u32>,
+    /// Action of each id a message rule resolved to.
+    actions: HashMap<u32, MessageAction>,
+    /// Scrubbed signals of each id.
+    scrubs: HashMap<u32, Scrub>,
+}
+
+fn parse_id(text: &str) -> Option<u32> {
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => text.parse().ok(),
+    }
+}
+
+impl LogRewriter {
+    /// Resolve the rules of `config` against the messages of `dbc`.
+    ///
+    /// # Errors
+    /// Returns an error when a message rule names no message of the database or a scrub value
+    /// cannot be encoded into its signal (out of the DBC range).
+    pub fn new(config: RewriteConfig, dbc: &RuntimeDbc) -> io::Result<Self> {
+        let mut actions = HashMap::new();
+        for rule in &config.messages {
+            let id = match dbc.get_message_by_name(&rule.message) {
+                Some(msg) => msg.id,
+                None => parse_id(&rule.message).ok_or_else(|| {
+                    Error::other(format!("rewrite message:{} not in the database", rule.message))
+                })?,
+            };
+            actions.entry(id).or_insert(rule.action);
+        }
+
+        let mut scrubs = HashMap::new();
+        for msg in dbc.get_messages() {
+            let mut signals = Vec::new();
+            for sig in &msg.signals {
+                let Some(rule) =
+                    config.signals.iter().find(|rule| signal_matches(&rule.signal, msg, sig))
+                else {
+                    continue;
+                };
+                let value = match sig.kind {
+                    SignalKind::Bool => SignalValue::Bool(rule.value != 0.0),
+                    _ => SignalValue::F64(rule.value),
+                };
+                let mut data = vec![0u8; usize::try_from(msg.size).unwrap_or(0)];
+                sig.encode(value, &mut data).map_err(|error| {
+                    Error::other(format!(
+                        "rewrite signal:{}.{} value:{}: {error}",
+                        msg.name, sig.name, rule.value
+                    ))
+                })?;
+                signals.push((Arc::clone(sig), value));
+            }
+            if !signals.is_empty() {
+                scrubs.insert(msg.id, Scrub { msg: Arc::clone(msg), signals });
+            }
+        }
+        let known = dbc.get_messages().iter().map(|msg| msg.id).collect();
+        Ok(LogRewriter { config, known, actions, scrubs })
+    }
+
+    /// Load a configuration from YAML text and resolve it against `dbc`.
+    ///
+    /// # Errors
+    /// Returns an error when the YAML is invalid, see [`LogRewriter::new`] otherwise.
+    pub fn from_yaml(yaml: &str, dbc: &RuntimeDbc) -> io::Result<Self> {
+        let config: RewriteConfig = serde_yaml::from_str(yaml).map_err(Error::other)?;
+        LogRewriter::new(config, dbc)
+    }
+
+    #[must_use]
+    pub fn get_config(&self) -> &RewriteConfig {
+        &self.config
+    }
+
+    /// Rewritten copy of `frame`, `None` when it is dropped. Signals of an inactive
+    /// multiplexed page or beyond a short payload are left untouched.
+    #[must_use]
+    pub fn rewrite(&self, frame: &ReplayFrame) -> Option<ReplayFrame> {
+        // error frames carry no payload to protect
+        if frame.canid & CAN_ERR_FLAG != 0 {
+            return Some(frame.clone());
+        }
+        // remote requests match the id of their message
+        let canid = frame.canid & !CAN_RTR_FLAG;
+        let action = self.actions.get(&canid);
+        if action == Some(&MessageAction::Drop)
+            || (self.config.drop_unknown && !self.known.contains(&canid))
+        {
+            return None;
+        }
+
+        let mut frame = frame.clone();
+        if let Some(scrub) = self.scrubs.get(&canid) {
+            let active = scrub.msg.decode(&frame.data);
+            for (sig, value) in &scrub.signals {
+                let idx = scrub.msg.signals.iter().position(|other| Arc::ptr_eq(other, sig));
+                if idx.is_some_and(|idx| active[idx].is_some()) {
+                    // encodable once checked by new(), the payload is long enough when active
+                    let _ = sig.encode(*value, &mut frame.data);
+                }
+            }
+        }
+        if let Some(MessageAction::Remap(id)) = action {
+            frame.canid = id | (frame.canid & CAN_RTR_FLAG);
+        }
+        Some(frame)
+    }
+
+    /// Rewrite every frame of a recording, keeping the order and stamps of the kept ones.
+    #[must_use]
+    pub fn rewrite_frames(&self, frames: &[ReplayFrame]) -> Vec<ReplayFrame> {
+        frames.iter().filter_map(|frame| self.rewrite(frame)).collect()
+    }
+
+    /// Rewrite a `candump -l` log, empty lines ignored.
+    ///
+    /// # Errors
+    /// Returns an error on the first line not in candump log format.
+    pub fn rewrite_candump(&self, log: &str) -> io::Result<String> {
+        let mut rewritten = String::with_capacity(log.len());
+        for line in log.lines().filter(|line| !line.trim().is_empty()) {
+            if let Some(frame) = self.rewrite(&ReplayFrame::from_candump(line)?) {
+                rewritten.push_str(&frame.to_candump());
+                rewritten.push('\n');
+            }
+        }
+        Ok(rewritten)
+    }
+}
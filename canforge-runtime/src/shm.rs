@@ -0,0 +1,195 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Shared memory export of the current signal values.
+//!
+//! [`ShmExporter`] keeps the last value of selected signals in a file, usually under
+//! `/dev/shm`, that other processes of the ECU map read-only (`canforge_shm.h` for C). The
+//! segment is a 64-byte header followed by one 64-byte slot per signal, all integers little
+//! endian:
+//!
+//! | offset | header            | slot                                   |
+//! |--------|-------------------|----------------------------------------|
+//! | 0      | magic `u32` "CFSH"| sequence `u32`, odd while being written |
+//! | 4      | version `u32` (1) | status `u32`: 0 unset, 1 valid          |
+//! | 8      | slot count `u32`  | value `f64`, physical                   |
+//! | 12     | slot size `u32`   |                                         |
+//! | 16     | header size `u32` | stamp `u64`, microseconds               |
+//! | 24     | reserved          | canid `u32`, raw DBC id                 |
+//! | 32     |                   | `Message.Signal` name, NUL terminated   |
+//!
+//! Readers need no lock: read the sequence, skip the slot while it is odd, copy the value and
+//! stamp, then read the sequence again and retry when it changed.
+//!
+//! ```yaml
+//! path: /dev/shm/canforge
+//! signals: ["BatteryStatus.*", "VehicleSpeed"]
+//! ```
+
+use crate::database::{signal_matches, MessageDef, RuntimeDbc, SignalDef};
+use crate::layout::SignalValue;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Error};
+use std::os::unix::fs::FileExt;
+use std::path::Path;
+
+/// "CFSH" read as a little endian `u32`.
+pub const SHM_MAGIC: u32 = u32::from_le_bytes(*b"CFSH");
+pub const SHM_VERSION: u32 = 1;
+pub const SHM_HEADER_SIZE: u32 = 64;
+pub const SHM_SLOT_SIZE: u32 = 64;
+/// Bytes reserved for the slot name, NUL included.
+pub const SHM_NAME_LEN: usize = 32;
+
+/// Exporter configuration, usually loaded from YAML.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ShmConfig {
+    pub path: String,
+    /// `Signal`, `Message.Signal` or `Message.*`; generated or DBC names, `*` matches all.
+    pub signals: Vec<String>,
+}
+
+/// One signal of the segment, in slot order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShmSlot {
+    pub canid: u32,
+    pub message: &'static str,
+    pub signal: &'static str,
+}
+
+/// Writes decoded values to the shared segment, see the module documentation.
+pub struct ShmExporter {
+    config: ShmConfig,
+    file: File,
+    slots: Vec<ShmSlot>,
+    index: HashMap<(u32, &'static str), usize>,
+    sequences: Vec<u32>,
+}
+
+impl ShmExporter {
+    /// Create (or truncate) the segment with one slot per signal of `dbc` matching the
+    /// configuration, in database order.
+    ///
+    /// # Errors
+    /// Returns an error when no signal matches or the file cannot be written.
+    pub fn new(config: ShmConfig, dbc: &RuntimeDbc) -> io::Result<Self> {
+        let mut slots = Vec::new();
+        for msg in dbc.get_messages() {
+            for sig in &msg.signals {
+                if config.signals.iter().any(|pattern| signal_matches(pattern, msg, sig)) {
+                    slots.push(ShmSlot { canid: msg.id, message: msg.name, signal: sig.name });
+                }
+            }
+        }
+        if slots.is_empty() {
+            return Err(Error::other(format!("shm:{} no signal matches", config.path)));
+        }
+        let count = u32::try_from(slots.len())
+            .map_err(|_| Error::other(format!("shm:{} too many signals", config.path)))?;
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(Path::new(&config.path))?;
+        file.set_len(u64::from(SHM_HEADER_SIZE + count * SHM_SLOT_SIZE))?;
+
+        let mut header = [0u8; SHM_HEADER_SIZE as usize];
+        for (offset, field) in [SHM_MAGIC, SHM_VERSION, count, SHM_SLOT_SIZE, SHM_HEADER_SIZE]
+            .iter()
+            .enumerate()
+        {
+            header[offset * 4..offset * 4 + 4].copy_from_slice(&field.to_le_bytes());
+        }
+        file.write_all_at(&header, 0)?;
+        for (idx, slot) in slots.iter().enumerate() {
+            let mut bytes = [0u8; SHM_SLOT_SIZE as usize];
+            bytes[24..28].copy_from_slice(&slot.canid.to_le_bytes());
+            let name = format!("{}.{}", slot.message, slot.signal);
+            let len = name.len().min(SHM_NAME_LEN - 1);
+            bytes[32..32 + len].copy_from_slice(&name.as_bytes()[..len]);
+            file.write_all_at(&bytes, slot_offset(idx))?;
+        }
+
+        let index = slots.iter().enumerate().map(|(idx, slot)| ((slot.canid, slot.signal), idx));
+        Ok(ShmExporter {
+            index: index.collect(),
+            sequences: vec![0; slots.len()],
+            config,
+            file,
+            slots,
+        })
+    }
+
+    /// Load a configuration from YAML text.
+    ///
+    /// # Errors
+    /// Returns an error when the YAML is invalid or [`ShmExporter::new`] fails.
+    pub fn from_yaml(yaml: &str, dbc: &RuntimeDbc) -> io::Result<Self> {
+        let config: ShmConfig = serde_yaml::from_str(yaml).map_err(Error::other)?;
+        ShmExporter::new(config, dbc)
+    }
+
+    #[must_use]
+    pub fn get_config(&self) -> &ShmConfig {
+        &self.config
+    }
+
+    /// Signals of the segment, in slot order.
+    #[must_use]
+    pub fn get_slots(&self) -> &[ShmSlot] {
+        &self.slots
+    }
+
+    /// Publish one decoded sample, `stamp` in microseconds. Returns false when the signal has
+    /// no slot.
+    ///
+    /// # Errors
+    /// Returns an error when the segment cannot be written.
+    pub fn push(
+        &mut self,
+        msg: &MessageDef,
+        sig: &SignalDef,
+        stamp: u64,
+        value: SignalValue,
+    ) -> io::Result<bool> {
+        let Some(&idx) = self.index.get(&(msg.id, sig.name)) else {
+            return Ok(false);
+        };
+        let offset = slot_offset(idx);
+        let mut payload = [0u8; 20];
+        payload[..4].copy_from_slice(&1u32.to_le_bytes());
+        payload[4..12].copy_from_slice(&value.as_f64().to_le_bytes());
+        payload[12..].copy_from_slice(&stamp.to_le_bytes());
+
+        // odd sequence while the slot is inconsistent
+        let sequence = &mut self.sequences[idx];
+        *sequence = sequence.wrapping_add(1);
+        self.file.write_all_at(&sequence.to_le_bytes(), offset)?;
+        self.file.write_all_at(&payload, offset + 4)?;
+        *sequence = sequence.wrapping_add(1);
+        self.file.write_all_at(&sequence.to_le_bytes(), offset)?;
+        Ok(true)
+    }
+}
+
+fn slot_offset(idx: usize) -> u64 {
+    u64::from(SHM_HEADER_SIZE) + idx as u64 * u64::from(SHM_SLOT_SIZE)
+}
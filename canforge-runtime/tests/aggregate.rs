@@ -0,0 +1,56 @@
+use canforge_runtime::prelude::*;
+
+const DBC: &str = r#"VERSION "1.0"
+NS_ :
+BU_: ECU
+BO_ 257 Battery_Status: 8 ECU
+ SG_ pack_voltage_V : 0|16@1+ (0.1,0) [0|1000] "V" ECU
+ SG_ soc : 16|8@1+ (1,0) [0|100] "%" ECU
+"#;
+
+const CONFIG: &str = r#"
+rules:
+  - signal: "BatteryStatus.PackVoltageV"
+    window_ms: 1000
+    ops: [min, max, mean, count]
+"#;
+
+#[test]
+fn downsample_over_window() {
+    let dbc = RuntimeDbc::from_source(DBC).unwrap();
+    let msg = dbc.get_message(257).unwrap();
+    let volt = msg.signal("pack_voltage_V").unwrap();
+    let soc = msg.signal("soc").unwrap();
+    let mut aggregator = Aggregator::from_yaml(CONFIG).unwrap();
+
+    for (stamp, value) in [(100_000, 400.0), (400_000, 410.0), (900_000, 420.0)] {
+        assert_eq!(aggregator.push(msg, volt, stamp, SignalValue::F64(value)), Sample::Pending);
+    }
+    assert_eq!(
+        aggregator.push(msg, soc, 950_000, SignalValue::U8(80)),
+        Sample::Passthrough(SignalValue::U8(80))
+    );
+
+    // first sample of the next window closes the previous one
+    let Sample::Window(window) = aggregator.push(msg, volt, 1_200_000, SignalValue::F64(430.0))
+    else {
+        panic!("window not closed")
+    };
+    assert_eq!((window.start, window.end), (0, 1_000_000));
+    assert_eq!((window.min, window.max, window.count), (Some(400.0), Some(420.0), Some(3)));
+    assert!((window.mean.unwrap() - 410.0).abs() < 1e-9);
+    assert_eq!(window.last, None);
+
+    // the pending window is flushed once its end is reached
+    assert!(aggregator.flush(1_999_999).is_empty());
+    let flushed = aggregator.flush(2_000_000);
+    assert_eq!(flushed.len(), 1);
+    assert_eq!(flushed[0].count, Some(1));
+    assert!(aggregator.flush(3_000_000).is_empty());
+}
+
+#[test]
+fn rejects_empty_rule() {
+    assert!(Aggregator::from_yaml("rules: [{signal: Soc, window_ms: 0, ops: [min]}]").is_err());
+    assert!(Aggregator::from_yaml("rules: [{signal: Soc, window_ms: 10, ops: []}]").is_err());
+}
@@ -0,0 +1,75 @@
+use canforge_runtime::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const DBC: &str = r#"VERSION "1.0"
+NS_ :
+BU_: ECU
+BO_ 257 Battery_Status: 8 ECU
+ SG_ pack_temp : 0|8@1- (1,0) [-40|120] "degC" ECU
+"#;
+
+const CONFIG: &str = r#"
+alarms:
+  - name: pack-overheat
+    signal: "BatteryStatus.PackTemp"
+    compare: ">"
+    threshold: 60
+    debounce_ms: 5000
+"#;
+
+#[test]
+fn raises_after_debounce_and_clears() {
+    let dbc = RuntimeDbc::from_source(DBC).unwrap();
+    let msg = dbc.get_message(257).unwrap();
+    let temp = msg.signal("pack_temp").unwrap();
+    let mut engine = AlarmEngine::from_yaml(CONFIG).unwrap();
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&seen);
+    engine.set_callback(Box::new(move |event| sink.borrow_mut().push(event.state)));
+    let channel = engine.subscribe();
+
+    // short spike: filtered out by the debounce
+    assert!(engine.push(msg, temp, 0, SignalValue::I8(65)).is_empty());
+    assert!(engine.push(msg, temp, 2_000_000, SignalValue::I8(55)).is_empty());
+    assert!(engine.tick(10_000_000).is_empty());
+
+    // sustained: BCM sends no new frame, the tick trips the alarm
+    assert!(engine.push(msg, temp, 11_000_000, SignalValue::I8(70)).is_empty());
+    assert!(engine.tick(15_000_000).is_empty());
+    let events = engine.tick(16_000_000);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].state, AlarmState::Raised);
+    assert_eq!(events[0].value, SignalValue::I8(70));
+    assert_eq!(engine.active(), vec!["pack-overheat"]);
+
+    assert!(engine.push(msg, temp, 20_000_000, SignalValue::I8(50)).is_empty());
+    let events = engine.push(msg, temp, 25_000_000, SignalValue::I8(49));
+    assert_eq!(events[0].state, AlarmState::Cleared);
+    assert!(engine.active().is_empty());
+
+    assert_eq!(*seen.borrow(), vec![AlarmState::Raised, AlarmState::Cleared]);
+    assert_eq!(channel.try_iter().count(), 2);
+}
+
+#[test]
+fn rejects_duplicated_names() {
+    let twice = format!("{CONFIG}{}", CONFIG.replace("alarms:\n", ""));
+    assert!(AlarmEngine::from_yaml(&twice).is_err());
+}
+
+#[test]
+fn ticks_on_injected_clock() {
+    let dbc = RuntimeDbc::from_source(DBC).unwrap();
+    let msg = dbc.get_message(257).unwrap();
+    let temp = msg.signal("pack_temp").unwrap();
+    let mut engine = AlarmEngine::from_yaml(CONFIG).unwrap();
+
+    assert!(engine.push(msg, temp, 1_000_000, SignalValue::I8(70)).is_empty());
+    engine.set_clock(Box::new(FixedClock(4_000_000)));
+    assert!(engine.tick_now().is_empty());
+    engine.set_clock(Box::new(FixedClock(6_000_000)));
+    let events = engine.tick_now();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].state, AlarmState::Raised);
+}
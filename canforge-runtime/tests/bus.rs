@@ -0,0 +1,219 @@
+#![cfg(feature = "sockcan")]
+
+use canforge_runtime::prelude::*;
+use sockcan::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, VecDeque};
+use std::rc::Rc;
+
+const BODY: &str = r#"VERSION "1.0"
+NS_ :
+BU_: ECU
+BO_ 257 Door_Status: 1 ECU
+ SG_ door_open : 0|1@1+ (1,0) [0|1] "" ECU
+"#;
+
+const CHASSIS: &str = r#"VERSION "1.0"
+NS_ :
+BU_: ECU
+BO_ 512 Wheel_Speed: 2 ECU
+ SG_ speed : 0|16@1+ (0.01,0) [0|655.35] "km/h" ECU
+"#;
+
+/// Frames queued per interface, a `None` entry failing the next read.
+type Wire = Rc<RefCell<HashMap<String, VecDeque<Option<CanMsgData>>>>>;
+
+struct FakeSocket {
+    iface: String,
+    wire: Wire,
+}
+
+impl BusSocket for FakeSocket {
+    fn recv(&mut self) -> Result<Option<CanMsgData>, CanError> {
+        let mut wire = self.wire.borrow_mut();
+        match wire.get_mut(&self.iface).and_then(VecDeque::pop_front) {
+            None => Ok(None),
+            Some(Some(data)) => Ok(Some(data)),
+            Some(None) => Err(CanError::new("sock-recv-fail", "network is down")),
+        }
+    }
+}
+
+fn frame(canid: u32, data: &[u8]) -> Option<CanMsgData> {
+    Some(raw_msg_data(canid, 1, data).unwrap())
+}
+
+fn pool(uid: &'static str, dbc: &str) -> Box<dyn CanDbcPool> {
+    Box::new(RuntimePool::new(uid, RuntimeDbc::from_source(dbc).unwrap()))
+}
+
+/// `RTM_NEWLINK`/`RTM_DELLINK` message of `iface` with interface `flags`.
+fn netlink(kind: u16, iface: &str, flags: u32) -> Vec<u8> {
+    let mut name = iface.as_bytes().to_vec();
+    name.push(0);
+    let attr_len = 4 + name.len();
+    let len = 16 + 16 + attr_len.next_multiple_of(4);
+    let mut msg = Vec::new();
+    msg.extend_from_slice(&(len as u32).to_ne_bytes());
+    msg.extend_from_slice(&kind.to_ne_bytes());
+    msg.extend_from_slice(&[0; 10]);
+    // ifinfomsg: family, type, index, flags, change
+    msg.extend_from_slice(&[0; 8]);
+    msg.extend_from_slice(&flags.to_ne_bytes());
+    msg.extend_from_slice(&[0; 4]);
+    msg.extend_from_slice(&(attr_len as u16).to_ne_bytes());
+    msg.extend_from_slice(&3u16.to_ne_bytes());
+    msg.extend_from_slice(&name);
+    msg.resize(len, 0);
+    msg
+}
+
+#[test]
+fn parses_netlink_link_events() {
+    let mut datagram = netlink(16, "can0", 0x41);
+    datagram.extend(netlink(16, "vcan1", 0x1));
+    datagram.extend(netlink(20, "lo", 0));
+    datagram.extend(netlink(17, "can2", 0x41));
+    assert_eq!(
+        LinkEvent::from_netlink(&datagram),
+        vec![
+            LinkEvent { iface: "can0".to_owned(), up: true },
+            LinkEvent { iface: "vcan1".to_owned(), up: false },
+            LinkEvent { iface: "can2".to_owned(), up: false },
+        ]
+    );
+    // truncated messages are ignored
+    assert!(LinkEvent::from_netlink(&datagram[..20]).is_empty());
+}
+
+#[test]
+fn dispatches_and_resubscribes_interfaces() {
+    let wire: Wire = Rc::new(RefCell::new(HashMap::new()));
+    let opened = Rc::new(RefCell::new(Vec::new()));
+    let down = Rc::new(RefCell::new(vec!["can1".to_owned()]));
+    let mut buses = {
+        let (wire, opened, down) = (Rc::clone(&wire), Rc::clone(&opened), Rc::clone(&down));
+        BusManager::new(move |iface: &str, pool: &dyn CanDbcPool| {
+            if down.borrow().iter().any(|name| name == iface) {
+                return Err(CanError::new("sock-open-fail", format!("{iface} is down")));
+            }
+            opened.borrow_mut().push((iface.to_owned(), pool.get_ids().to_vec()));
+            Ok(FakeSocket { iface: iface.to_owned(), wire: Rc::clone(&wire) })
+        })
+    };
+    assert!(buses.add_bus("can0", pool("Body", BODY)));
+    assert!(!buses.add_bus("can1", pool("Chassis", CHASSIS)));
+    assert_eq!(*opened.borrow(), vec![("can0".to_owned(), vec![257])]);
+
+    // can1 comes up: opened and subscribed with its own pool
+    down.borrow_mut().clear();
+    assert!(buses.link_event(&LinkEvent { iface: "can1".to_owned(), up: true }));
+    assert!(!buses.link_event(&LinkEvent { iface: "can1".to_owned(), up: true }));
+    assert!(!buses.link_event(&LinkEvent { iface: "eth0".to_owned(), up: false }));
+    assert_eq!(opened.borrow()[1], ("can1".to_owned(), vec![512]));
+
+    wire.borrow_mut()
+        .insert("can0".to_owned(), [frame(257, &[1]), frame(512, &[0, 1])].into());
+    wire.borrow_mut().insert("can1".to_owned(), [frame(512, &[0x10, 0x27])].into());
+    assert_eq!(buses.poll(), 2);
+    let speed = buses.get_pool("can1").unwrap().get_mut(512).unwrap().get_signals()[0]
+        .borrow()
+        .get_value();
+    assert_eq!(speed, CanDbcType::F64(100.0));
+    let status = buses.get_statuses();
+    assert_eq!((status[0].frames, status[0].rejected), (2, 1));
+
+    // a read failure closes the interface until it comes back
+    wire.borrow_mut().insert("can0".to_owned(), [None, frame(257, &[0])].into());
+    assert_eq!(buses.poll(), 0);
+    assert!(!buses.get_statuses()[0].up);
+    assert!(buses.get_socket("can0").is_none());
+    assert!(buses.link_event(&LinkEvent { iface: "can0".to_owned(), up: true }));
+    assert_eq!(buses.poll(), 1);
+
+    let status = &buses.get_statuses()[0];
+    assert_eq!((status.up, status.opens, status.frames), (true, 2, 3));
+    assert_eq!(status.last_error.as_deref(), Some("sock-recv-fail:network is down"));
+
+    assert!(buses.link_event(&LinkEvent { iface: "can0".to_owned(), up: false }));
+    assert!(buses.remove_bus("can0").is_some());
+    assert_eq!(buses.get_statuses().len(), 1);
+}
+
+/// Clock the test moves by hand.
+struct TestClock(Rc<Cell<u64>>);
+
+impl Clock for TestClock {
+    fn now_us(&self) -> u64 {
+        self.0.get()
+    }
+}
+
+#[test]
+fn retries_reopen_while_link_is_up() {
+    let wire: Wire = Rc::new(RefCell::new(HashMap::new()));
+    let attempts = Rc::new(Cell::new(0));
+    let failing = Rc::new(Cell::new(true));
+    let now = Rc::new(Cell::new(0));
+    let mut buses = {
+        let (wire, attempts, failing) =
+            (Rc::clone(&wire), Rc::clone(&attempts), Rc::clone(&failing));
+        BusManager::new(move |iface: &str, _pool: &dyn CanDbcPool| {
+            attempts.set(attempts.get() + 1);
+            if failing.get() {
+                return Err(CanError::new("sock-open-fail", format!("{iface} is busy")));
+            }
+            Ok(FakeSocket { iface: iface.to_owned(), wire: Rc::clone(&wire) })
+        })
+    };
+    buses.set_clock(Box::new(TestClock(Rc::clone(&now))));
+    assert!(!buses.add_bus("can0", pool("Body", BODY)));
+    assert!(buses.get_statuses()[0].link);
+
+    // no retry before the backoff, then 100ms and 200ms more
+    now.set(99_999);
+    buses.poll();
+    assert_eq!(attempts.get(), 1);
+    now.set(100_000);
+    buses.poll();
+    assert_eq!(attempts.get(), 2);
+    now.set(299_999);
+    buses.poll();
+    assert_eq!(attempts.get(), 2);
+    now.set(300_000);
+    buses.poll();
+    assert_eq!(attempts.get(), 3);
+
+    // the open at 700ms reads in the same poll
+    failing.set(false);
+    wire.borrow_mut().insert("can0".to_owned(), [frame(257, &[1])].into());
+    now.set(699_999);
+    assert_eq!(buses.poll(), 0);
+    now.set(700_000);
+    assert_eq!(buses.poll(), 1);
+    assert_eq!(attempts.get(), 4);
+    assert!(buses.get_statuses()[0].up);
+
+    // a read failure is retried without a link event, the backoff starting over
+    wire.borrow_mut().insert("can0".to_owned(), [None, frame(257, &[0])].into());
+    assert_eq!(buses.poll(), 0);
+    assert!(!buses.get_statuses()[0].up);
+    now.set(799_999);
+    assert_eq!(buses.poll(), 0);
+    now.set(800_000);
+    assert_eq!(buses.poll(), 1);
+    assert_eq!(buses.get_statuses()[0].opens, 2);
+
+    // link down stops the retries, link up reopens at once
+    failing.set(true);
+    assert!(buses.link_event(&LinkEvent { iface: "can0".to_owned(), up: false }));
+    assert!(!buses.get_statuses()[0].link);
+    now.set(60_000_000);
+    buses.poll();
+    assert_eq!(attempts.get(), 5);
+    failing.set(false);
+    assert!(buses.link_event(&LinkEvent { iface: "can0".to_owned(), up: true }));
+    assert_eq!(attempts.get(), 6);
+    let status = &buses.get_statuses()[0];
+    assert_eq!((status.link, status.up, status.opens), (true, true, 3));
+}
@@ -0,0 +1,60 @@
+use canforge_runtime::prelude::*;
+
+#[test]
+fn computes_load_and_rates_over_the_window() {
+    let mut monitor = BusMonitor::from_yaml("bitrate: 125000\nwindow_ms: 100").unwrap();
+    // 100 standard 8-byte frames of 135 bits in 100 ms: 13500 bits out of 12500
+    for index in 0..100 {
+        monitor.record(0x101, 8, index * 1000);
+    }
+    monitor.record(0x2000_0004, 8, 50_000);
+    let stats = monitor.get_stats(99_000);
+    assert_eq!(stats.frames, 100);
+    assert_eq!(stats.errors, 1);
+    assert!((stats.frames_per_sec - 1000.0).abs() < 1e-9);
+    assert!((stats.errors_per_sec - 10.0).abs() < 1e-9);
+    assert!((stats.load_percent.unwrap() - 108.0).abs() < 1e-9);
+
+    // frames older than the window are forgotten, totals are kept
+    let stats = monitor.get_stats(149_500);
+    assert!((stats.frames_per_sec - 500.0).abs() < 1e-9);
+    assert!((stats.errors_per_sec - 10.0).abs() < 1e-9);
+    let stats = monitor.get_stats(1_000_000);
+    assert_eq!((stats.frames_per_sec, stats.errors_per_sec), (0.0, 0.0));
+    assert_eq!((stats.frames, stats.errors), (100, 1));
+
+    let mut clocked = BusMonitor::new(BusMonitorConfig::default()).unwrap();
+    clocked.set_clock(Box::new(FixedClock(500_000)));
+    clocked.record(0x8000_0101, 8, 400_000);
+    let stats = clocked.get_stats_now();
+    assert_eq!(stats.load_percent, None);
+    assert!((stats.frames_per_sec - 1.0).abs() < 1e-9);
+    assert!(BusMonitor::from_yaml("window_ms: 0").is_err());
+}
+
+#[cfg(feature = "sockcan")]
+#[test]
+fn exposes_bus_statistics_in_the_pool() {
+    use sockcan::prelude::*;
+
+    const DBC: &str = r#"VERSION "1.0"
+NS_ :
+BU_: ECU
+BO_ 257 Battery_Status: 8 ECU
+ SG_ pack_voltage_V : 0|16@1+ (0.1,0) [0|1000] "V" ECU
+"#;
+    let mut pool = RuntimePool::new("Test", RuntimeDbc::from_source(DBC).unwrap());
+    assert_eq!(pool.get_bus_stats(), None);
+
+    let mut monitor = BusMonitor::from_yaml("bitrate: 500000").unwrap();
+    monitor.set_clock(Box::new(FixedClock(1_000_000)));
+    pool.bus_monitor(monitor);
+    pool.update(&raw_msg_data(257, 10, &[0; 8]).unwrap()).unwrap();
+    // unknown ids still use the bus
+    assert!(pool.update(&raw_msg_data(258, 20, &[0; 8]).unwrap()).is_err());
+    pool.record_frame(0x2000_0004, 8, 30);
+
+    let stats = pool.get_bus_stats().unwrap();
+    assert_eq!((stats.frames, stats.errors), (2, 1));
+    assert!((stats.load_percent.unwrap() - 0.054).abs() < 1e-9);
+}
@@ -0,0 +1,90 @@
+use canforge_runtime::prelude::*;
+
+const DBC: &str = r#"VERSION "1.0"
+NS_ :
+BU_: ECU
+BO_ 257 Battery_Status: 8 ECU
+ SG_ pack_voltage : 0|16@1+ (0.1,0) [0|800] "V" ECU
+BO_ 258 Vehicle_Status: 8 ECU
+ SG_ vehicle_speed : 0|16@1+ (0.01,0) [0|250] "km/h" ECU
+"#;
+
+const CONFIG: &str = r#"
+max_delay_ms: 10
+buses:
+  - name: powertrain
+  - name: chassis
+    offset_us: -500
+"#;
+
+fn stamps(events: &[BusEvent]) -> Vec<(usize, u64)> {
+    events.iter().map(|event| (event.bus, event.stamp)).collect()
+}
+
+#[test]
+fn merges_buses_in_stamp_order() {
+    let dbc = RuntimeDbc::from_source(DBC).unwrap();
+    let battery = dbc.get_message(257).unwrap();
+    let volt = battery.signal("pack_voltage").unwrap();
+    let vehicle = dbc.get_message(258).unwrap();
+    let speed = vehicle.signal("vehicle_speed").unwrap();
+    let mut correlator = Correlator::from_yaml(CONFIG).unwrap();
+    let (powertrain, chassis) =
+        (correlator.bus_index("powertrain").unwrap(), correlator.bus_index("chassis").unwrap());
+
+    correlator
+        .push(powertrain, battery, volt, 1_000, SignalValue::F64(400.0))
+        .unwrap();
+    correlator
+        .push(powertrain, battery, volt, 3_000, SignalValue::F64(401.0))
+        .unwrap();
+    // nothing from the chassis bus yet
+    assert!(correlator.poll().is_empty());
+
+    // 2_500 on the chassis clock is 2_000 on the common time base
+    correlator.push(chassis, vehicle, speed, 2_500, SignalValue::F64(50.0)).unwrap();
+    let events = correlator.poll();
+    assert_eq!(stamps(&events), vec![(powertrain, 1_000), (chassis, 2_000)]);
+    assert_eq!((events[1].canid, events[1].signal), (258, "VehicleSpeed"));
+    assert_eq!(correlator.get_pending(), 1);
+
+    // the chassis bus reaching 3_000 releases the powertrain sample
+    correlator.heartbeat(chassis, 3_500).unwrap();
+    assert_eq!(stamps(&correlator.poll()), vec![(powertrain, 3_000)]);
+    assert_eq!(correlator.watermark(), 3_000);
+}
+
+#[test]
+fn silent_bus_stops_holding_back() {
+    let dbc = RuntimeDbc::from_source(DBC).unwrap();
+    let battery = dbc.get_message(257).unwrap();
+    let volt = battery.signal("pack_voltage").unwrap();
+    let vehicle = dbc.get_message(258).unwrap();
+    let speed = vehicle.signal("vehicle_speed").unwrap();
+    let mut correlator = Correlator::from_yaml(CONFIG).unwrap();
+
+    correlator.push(0, battery, volt, 5_000, SignalValue::F64(400.0)).unwrap();
+    correlator.push(0, battery, volt, 20_000, SignalValue::F64(401.0)).unwrap();
+    // chassis silent for more than 10 ms behind 20_000
+    assert_eq!(stamps(&correlator.poll()), vec![(0, 5_000)]);
+
+    // a chassis sample older than the released stream is dropped
+    correlator.push(1, vehicle, speed, 4_500, SignalValue::F64(50.0)).unwrap();
+    assert_eq!(correlator.get_late(), 1);
+    correlator.push(1, vehicle, speed, 12_500, SignalValue::F64(51.0)).unwrap();
+    assert_eq!(stamps(&correlator.drain()), vec![(1, 12_000), (0, 20_000)]);
+    assert_eq!(correlator.get_pending(), 0);
+}
+
+#[test]
+fn rejects_inconsistent_config() {
+    assert!(Correlator::from_yaml("buses: []").is_err());
+    assert!(Correlator::from_yaml("buses: [{name: can0}, {name: can0}]").is_err());
+
+    let dbc = RuntimeDbc::from_source(DBC).unwrap();
+    let battery = dbc.get_message(257).unwrap();
+    let volt = battery.signal("pack_voltage").unwrap();
+    let mut correlator = Correlator::from_yaml("buses: [{name: can0}]").unwrap();
+    assert!(correlator.push(1, battery, volt, 0, SignalValue::F64(0.0)).is_err());
+    assert!(correlator.heartbeat(1, 0).is_err());
+}
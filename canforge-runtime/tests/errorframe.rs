@@ -0,0 +1,62 @@
+use canforge_runtime::prelude::*;
+
+#[test]
+fn decodes_linux_error_frames() {
+    assert_eq!(ErrorFrame::decode(0x101, &[0; 8], 0), None);
+
+    // controller went error passive on tx, counters reported
+    let frame = ErrorFrame::decode(0x2000_0204, &[0, 0x20, 0, 0, 0, 0, 130, 12], 42).unwrap();
+    assert_eq!(frame.stamp, 42);
+    assert_eq!(frame.errors, []);
+    assert_eq!(frame.state, Some(BusState::ErrorPassive));
+    assert_eq!((frame.tx_errors, frame.rx_errors), (Some(130), Some(12)));
+
+    let frame = ErrorFrame::decode(0x2000_002A, &[7, 0, 0x04, 0x0A], 0).unwrap();
+    assert_eq!(
+        frame.errors,
+        [
+            BusError::LostArbitration { bit: Some(7) },
+            BusError::Protocol { kind: 0x04, location: 0x0A },
+            BusError::NoAck,
+        ]
+    );
+    assert_eq!(frame.state, None);
+    assert_eq!(frame.tx_errors, None);
+
+    // short frames read as zeros
+    let frame = ErrorFrame::decode(0x2000_0046, &[], 0).unwrap();
+    assert_eq!(frame.errors, [BusError::LostArbitration { bit: None }, BusError::BusOff]);
+    assert_eq!(frame.state, Some(BusState::BusOff));
+    let frame = ErrorFrame::decode(0x2000_0100, &[], 0).unwrap();
+    assert_eq!(frame.state, Some(BusState::ErrorActive));
+}
+
+#[cfg(feature = "sockcan")]
+#[test]
+fn reports_bus_health_through_the_pool() {
+    const DBC: &str = r#"VERSION "1.0"
+NS_ :
+BU_: ECU
+BO_ 257 Battery_Status: 8 ECU
+ SG_ pack_voltage_V : 0|16@1+ (0.1,0) [0|1000] "V" ECU
+"#;
+    let mut pool = RuntimePool::new("Test", RuntimeDbc::from_source(DBC).unwrap());
+    let mut monitor = BusMonitor::new(BusMonitorConfig::default()).unwrap();
+    monitor.set_clock(Box::new(FixedClock(100)));
+    pool.bus_monitor(monitor);
+    let events = pool.subscribe_errors();
+    assert_eq!(pool.get_bus_state(), BusState::ErrorActive);
+
+    assert_eq!(pool.update_error(0x101, &[0; 8], 10), None);
+    let frame = pool.update_error(0x2000_0004, &[0, 0x08, 0, 0, 0, 0, 0, 0], 20).unwrap();
+    assert_eq!(frame.state, Some(BusState::ErrorWarning));
+    pool.update_error(0x2000_0040, &[0; 8], 30).unwrap();
+    // frames without a state keep the previous one
+    pool.update_error(0x2000_0020, &[0; 8], 40).unwrap();
+    assert_eq!(pool.get_bus_state(), BusState::BusOff);
+    assert_eq!(pool.get_last_error().unwrap().errors, [BusError::NoAck]);
+
+    let received: Vec<u64> = events.try_iter().map(|frame| frame.stamp).collect();
+    assert_eq!(received, [20, 30, 40]);
+    assert_eq!(pool.get_bus_stats().unwrap().errors, 3);
+}
@@ -0,0 +1,53 @@
+use canforge_runtime::prelude::*;
+
+const CONFIG: &str = r#"
+bitrate: 500000
+max_load_percent: 10
+burst_ms: 20
+messages:
+  - canid: 0x101
+    min_interval_ms: 10
+"#;
+
+#[test]
+fn enforces_message_intervals() {
+    let mut governor = TxGovernor::from_yaml(CONFIG).unwrap();
+    assert_eq!(governor.admit(0x101, 8, 1_000_000), Ok(()));
+    assert_eq!(governor.admit(0x101, 8, 1_004_000), Err(TxDenied::TooSoon { retry_in_us: 6_000 }));
+    // other messages have no interval by default
+    assert_eq!(governor.admit(0x102, 8, 1_004_000), Ok(()));
+    assert_eq!(governor.admit(0x101, 8, 1_010_000), Ok(()));
+    assert_eq!(governor.get_stats(), TxStats { sent: 3, too_soon: 1, over_budget: 0 });
+}
+
+#[test]
+fn shapes_bursts_to_the_bus_budget() {
+    // 10% of 500 kbit/s: 50 bits per ms, a 20 ms bucket holds 1000 bits or seven 8-byte frames
+    let mut governor = TxGovernor::from_yaml(CONFIG).unwrap();
+    assert_eq!(frame_bits(0x200, 8), 135);
+    assert_eq!(frame_bits(0x8000_0200, 8), 160);
+    for canid in 0x200..0x207 {
+        assert_eq!(governor.admit(canid, 8, 0), Ok(()));
+    }
+    let Err(denied) = governor.admit(0x207, 8, 0) else { panic!("bucket holds 55 bits") };
+    assert!(matches!(denied, TxDenied::OverBudget { .. }));
+    // 80 missing bits at 50 bits per ms
+    assert!((1_600..=1_601).contains(&denied.retry_in_us()));
+    assert_eq!(governor.admit(0x207, 8, denied.retry_in_us()), Ok(()));
+    assert_eq!(governor.get_stats().over_budget, 1);
+
+    let mut clocked = TxGovernor::from_yaml(CONFIG).unwrap();
+    clocked.set_clock(Box::new(FixedClock(0)));
+    for canid in 0x200..0x207 {
+        assert!(clocked.admit_now(canid, 8).is_ok());
+    }
+    assert!(clocked.admit_now(0x207, 8).is_err());
+}
+
+#[test]
+fn rejects_inconsistent_configs() {
+    assert!(TxGovernor::from_yaml("max_load_percent: 0").is_err());
+    assert!(TxGovernor::from_yaml("max_load_percent: 150").is_err());
+    let twice = format!("{CONFIG}  - canid: 0x101\n    min_interval_ms: 5\n");
+    assert!(TxGovernor::from_yaml(&twice).is_err());
+}
@@ -0,0 +1,139 @@
+use canforge_runtime::prelude::*;
+
+#[test]
+fn extracts_pgn_and_source() {
+    // EEC1 from engine #1, PDU2
+    assert_eq!(pgn(0x8CF0_0400), Some(0xF004));
+    assert_eq!(source_address(0x8CF0_0400), 0x00);
+    // request to address 0x21, PDU1: destination address is not part of the PGN
+    assert_eq!(pgn(0x98EA_21F9), Some(0xEA00));
+    // DM1 from source 0x3D
+    assert_eq!(pgn(0x98FE_CA3D), Some(PGN_DM1));
+    assert_eq!(pgn(0x123), None);
+}
+
+#[test]
+fn decodes_dm1_payload() {
+    // amber lamp on, SPN 190 (engine speed) FMI 2 seen 3 times, then SPN 524287 padding
+    let dm1 = DiagnosticMessage::decode(&[0x04, 0xFF, 0xBE, 0x00, 0x02, 0x03, 0xFF, 0xFF]).unwrap();
+    assert_eq!(dm1.lamps.amber_warning.status, LampStatus::On);
+    assert_eq!(dm1.lamps.malfunction.status, LampStatus::Off);
+    assert_eq!(dm1.lamps.amber_warning.flash, LampFlash::Unavailable);
+    assert_eq!(dm1.dtcs, vec![Dtc { spn: 190, fmi: 2, occurrences: 3, conversion_method: false }]);
+
+    // multi-packet payload with two DTCs, SPN high bits in the FMI byte
+    let dm1 = DiagnosticMessage::decode(&[
+        0x40, 0x00, 0x6E, 0x00, 0x00, 0x01, 0x9C, 0x1F, 0x23, 0x81, 0xFF, 0xFF, 0xFF, 0xFF,
+    ])
+    .unwrap();
+    assert_eq!(dm1.lamps.malfunction.status, LampStatus::On);
+    assert_eq!(dm1.dtcs.len(), 2);
+    assert_eq!(dm1.dtcs[1], Dtc { spn: 0x1_1F9C, fmi: 3, occurrences: 1, conversion_method: true });
+
+    // no active DTC
+    assert!(DiagnosticMessage::decode(&[0, 0xFF, 0, 0, 0, 0, 0xFF, 0xFF])
+        .unwrap()
+        .dtcs
+        .is_empty());
+    assert!(DiagnosticMessage::decode(&[0, 0xFF, 0]).is_err());
+}
+
+#[test]
+fn decodes_name_fields() {
+    // arbitrary address capable, industry group 0, function 0 (engine), manufacturer 0x123
+    let name = Name(0x8000_0000_0000_0000 | (0x123 << 21) | 42);
+    assert!(name.arbitrary_address_capable());
+    assert_eq!(name.manufacturer_code(), 0x123);
+    assert_eq!(name.identity_number(), 42);
+    assert_eq!(name.function(), 0);
+    assert_eq!(Name::from_payload(&name.to_payload()).unwrap(), name);
+    assert!(Name::from_payload(&[0; 4]).is_err());
+}
+
+#[test]
+fn follows_address_claims() {
+    let engine = Name(0x10);
+    let brake = Name(0x20);
+    let mut table = AddressTable::default();
+    table.update(0x00, &engine.to_payload()).unwrap();
+    table.update(0x0B, &brake.to_payload()).unwrap();
+    assert_eq!(table.get_name(0x00), Some(engine));
+
+    // contention on 0x00: the higher NAME does not take the address
+    table.update(0x00, &brake.to_payload()).unwrap();
+    assert_eq!(table.get_name(0x00), Some(engine));
+    assert_eq!(table.get_address(brake), None);
+
+    // the engine moves, then cannot claim at all
+    table.update(0x80, &engine.to_payload()).unwrap();
+    assert_eq!((table.get_name(0x00), table.get_address(engine)), (None, Some(0x80)));
+    table.update(NULL_ADDRESS, &engine.to_payload()).unwrap();
+    assert_eq!(table.get_address(engine), None);
+}
+
+#[test]
+fn claims_an_address() {
+    let ours = Name(0x8000_0000_0000_0100);
+    let mut claimer = AddressClaimer::new(ours, 0x25);
+    let claim = claimer.claim();
+    assert_eq!(claim.canid, 0x98EE_FF25);
+    assert_eq!(claim.data, ours.to_payload());
+
+    // request for address claimed to everybody
+    let request = claimer.receive(0x98EA_FFF9, &[0x00, 0xEE, 0x00], &AddressTable::default());
+    assert_eq!(request, Some(claim));
+
+    // a lower NAME claims our address: move to the first free self-configurable address
+    let mut table = AddressTable::default();
+    table.update(0x80, &Name(0x5).to_payload()).unwrap();
+    let lower = Name(0x1);
+    let response = claimer.receive(address_claim_id(0x25), &lower.to_payload(), &table).unwrap();
+    assert_eq!(claimer.address(), Some(0x81));
+    assert_eq!(response.canid, address_claim_id(0x81));
+
+    // a higher NAME claiming our address is reminded that we own it
+    let higher = Name(u64::MAX);
+    let response = claimer.receive(address_claim_id(0x81), &higher.to_payload(), &table);
+    assert_eq!(response.map(|frame| frame.canid), Some(address_claim_id(0x81)));
+
+    // not arbitrary address capable: cannot claim
+    let mut fixed = AddressClaimer::new(Name(0x100), 0x25);
+    let response = fixed.receive(address_claim_id(0x25), &lower.to_payload(), &table).unwrap();
+    assert_eq!((fixed.address(), response.canid), (None, address_claim_id(NULL_ADDRESS)));
+}
+
+#[test]
+fn reassembles_transport_messages() {
+    let mut tp = TpReassembler::default();
+    // BAM from 0x3D: 14 bytes DM1 in 2 packets
+    let bam = [32, 14, 0, 2, 0xFF, 0xCA, 0xFE, 0x00];
+    let first = [1, 0x40, 0x00, 0x6E, 0x00, 0x00, 0x01, 0x9C];
+    let last = [2, 0x1F, 0x23, 0x81, 0xFF, 0xFF, 0xFF, 0xFF];
+    assert!(TpReassembler::is_transport(0x9CEC_FF3D));
+    assert!(!TpReassembler::is_transport(0x98FE_CA3D));
+
+    assert_eq!(tp.push(0x9CEC_FF3D, 0, &bam).unwrap(), None);
+    assert_eq!(tp.push(0x9CEB_FF3D, 50_000, &first).unwrap(), None);
+    let message = tp.push(0x9CEB_FF3D, 100_000, &last).unwrap().unwrap();
+    assert_eq!((message.pgn, message.source, message.destination), (PGN_DM1, 0x3D, 0xFF));
+    assert_eq!(message.data.len(), 14);
+    assert_eq!(DiagnosticMessage::decode(&message.data).unwrap().dtcs.len(), 2);
+
+    // a lost packet drops the session
+    assert_eq!(tp.push(0x9CEC_FF3D, 0, &bam).unwrap(), None);
+    assert!(tp.push(0x9CEB_FF3D, 50_000, &last).is_err());
+    assert_eq!(tp.push(0x9CEB_FF3D, 60_000, &first).unwrap(), None);
+
+    // so does a timeout or an abort
+    assert_eq!(tp.push(0x9CEC_FF3D, 0, &bam).unwrap(), None);
+    assert!(tp.push(0x9CEB_FF3D, 1_000_000, &first).is_err());
+    assert_eq!(tp.push(0x9CEC_FF3D, 0, &bam).unwrap(), None);
+    assert_eq!(
+        tp.push(0x9CEC_FF3D, 10, &[255, 0xFF, 0xFF, 0xFF, 0xFF, 0xCA, 0xFE, 0]).unwrap(),
+        None
+    );
+    assert_eq!(tp.push(0x9CEB_FF3D, 20, &first).unwrap(), None);
+
+    // announce size and packet count must agree
+    assert!(tp.push(0x9CEC_FF3D, 0, &[32, 14, 0, 3, 0xFF, 0xCA, 0xFE, 0]).is_err());
+}
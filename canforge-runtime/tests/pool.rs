@@ -0,0 +1,273 @@
+#![cfg(feature = "sockcan")]
+
+use canforge_runtime::prelude::*;
+use sockcan::prelude::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+const DBC: &str = r#"VERSION "1.0"
+NS_ :
+BU_: ECU
+BO_ 257 Battery_Status: 8 ECU
+ SG_ pack_voltage_V : 0|16@1+ (0.1,0) [0|1000] "V" ECU
+ SG_ pack_current_A : 16|16@1- (0.1,0) [-1000|1000] "A" ECU
+ SG_ isolation_be : 39|9@0+ (1,0) [0|511] "kOhm" ECU
+ SG_ contactor_closed : 48|1@1+ (1,0) [0|1] "" ECU
+BO_ 512 Mux_Frame: 8 ECU
+ SG_ page M : 0|4@1+ (1,0) [0|15] "" ECU
+ SG_ speed m0 : 8|8@1+ (1,0) [0|255] "" ECU
+ SG_ temp m1 : 8|8@1- (1,0) [-128|127] "" ECU
+"#;
+
+struct Counter(Rc<Cell<u32>>);
+
+impl CanSigCtrl for Counter {
+    fn sig_notification(&self, _sig: &dyn CanDbcSignal) -> i32 {
+        self.0.set(self.0.get() + 1);
+        0
+    }
+}
+
+#[test]
+fn reload_keeps_subscriptions_by_name() {
+    let mut pool = RuntimePool::new("Test", RuntimeDbc::from_source(DBC).unwrap());
+    let hits = Rc::new(Cell::new(0));
+    {
+        let msg = pool.get_mut(257).unwrap();
+        msg.get_signals()[0]
+            .borrow_mut()
+            .set_callback(Box::new(Counter(Rc::clone(&hits))));
+        let mux = pool.get_mut(512).unwrap();
+        mux.get_signals()[1]
+            .borrow_mut()
+            .set_callback(Box::new(Counter(Rc::clone(&hits))));
+    }
+
+    // new revision: Battery_Status gains a signal, Mux_Frame is gone, a new frame appears
+    let update = DBC.replace("BO_ 512 Mux_Frame: 8 ECU", "BO_ 768 Other_Frame: 8 ECU").replace(
+        " SG_ contactor_closed",
+        " SG_ precharge_done : 49|1@1+ (1,0) [0|1] \"\" ECU\n SG_ contactor_closed",
+    );
+    let report = pool.reload(RuntimeDbc::from_source(&update).unwrap()).unwrap();
+    assert_eq!(report.added, vec![768]);
+    assert_eq!(report.removed, vec![512]);
+    assert_eq!(report.kept, vec!["BatteryStatus.PackVoltageV".to_owned()]);
+    assert_eq!(report.orphaned, vec!["MuxFrame.Speed".to_owned()]);
+
+    let frame = CanMsgData {
+        canid: 257,
+        stamp: 1,
+        opcode: CanBcmOpCode::RxChanged,
+        len: 8,
+        data: vec![0x10, 0, 0, 0, 0, 0, 0, 0],
+    };
+    pool.update(&frame).unwrap();
+    assert_eq!(hits.get(), 1);
+}
+
+fn frame(canid: u32, data: Vec<u8>) -> CanMsgData {
+    CanMsgData { canid, stamp: 1, opcode: CanBcmOpCode::RxChanged, len: 8, data }
+}
+
+#[test]
+fn derived_signal_exposed_with_dbc_signals() {
+    let mut pool = RuntimePool::new("Test", RuntimeDbc::from_source(DBC).unwrap());
+    let power =
+        DerivedDef::from_fn("PackPower", &["pack_voltage_V", "PackCurrentA"], "W", |v| v[0] * v[1]);
+    pool.add_derived(257, power).unwrap();
+    assert!(pool
+        .add_derived(257, DerivedDef::from_fn("Bad", &["nope"], "", |_| 0.0))
+        .is_err());
+
+    // 400.0 V, 10.0 A
+    let msg = pool.update(&frame(257, vec![0xA0, 0x0F, 0x64, 0x00, 0, 0, 0, 0])).unwrap();
+    let signals = msg.get_signals();
+    assert_eq!(signals.len(), 5);
+    let power = signals[4].borrow();
+    assert_eq!(power.get_name(), "PackPower");
+    assert_eq!(power.get_status(), CanDataStatus::Updated);
+    assert_eq!(power.get_value(), CanDbcType::F64(4000.0));
+    drop(power);
+    drop(msg);
+
+    // derived signals survive a reload
+    let report = pool.reload(RuntimeDbc::from_source(DBC).unwrap()).unwrap();
+    assert!(report.orphaned.is_empty());
+    assert_eq!(pool.get_mut(257).unwrap().get_signals().len(), 5);
+}
+
+#[cfg(feature = "rhai")]
+#[test]
+fn derived_signal_from_script() {
+    let mut pool = RuntimePool::new("Test", RuntimeDbc::from_source(DBC).unwrap());
+    let power = DerivedDef::from_script(
+        "PackPowerKw",
+        &["pack_voltage_V", "pack_current_A"],
+        "kW",
+        "pack_voltage_V * pack_current_A / 1000.0",
+    )
+    .unwrap();
+    pool.add_derived(257, power).unwrap();
+    let msg = pool.update(&frame(257, vec![0xA0, 0x0F, 0x64, 0x00, 0, 0, 0, 0])).unwrap();
+    assert_eq!(msg.get_signals()[4].borrow().get_value(), CanDbcType::F64(4.0));
+    assert!(DerivedDef::from_script("Bad", &[], "", "1 +").is_err());
+}
+
+struct Verdict(i32);
+
+impl CanSigCtrl for Verdict {
+    fn sig_notification(&self, _sig: &dyn CanDbcSignal) -> i32 {
+        self.0
+    }
+}
+
+#[test]
+fn reports_listeners_per_signal() {
+    let pool = RuntimePool::new("Test", RuntimeDbc::from_source(DBC).unwrap());
+    {
+        let msg = pool.get_mut(512).unwrap();
+        msg.get_signals()[0].borrow_mut().set_callback(Box::new(Verdict(1)));
+        msg.get_signals()[1].borrow_mut().set_callback(Box::new(Verdict(-2)));
+        msg.get_signals()[2].borrow_mut().set_callback(Box::new(Verdict(5)));
+    }
+
+    // page 1: speed is reset, only page and temp notify
+    {
+        let mut msg = pool.update(&frame(512, vec![0x01, 0x10, 0, 0, 0, 0, 0, 0])).unwrap();
+        assert_eq!(msg.get_listeners(), 6);
+        let msg = msg.as_any().downcast_mut::<RuntimeMessage>().unwrap();
+        assert_eq!(msg.get_signal_listeners(), &[1, 0, 5]);
+    }
+
+    let mut msg = pool.update(&frame(512, vec![0x00, 0x10, 0, 0, 0, 0, 0, 0])).unwrap();
+    let msg = msg.as_any().downcast_mut::<RuntimeMessage>().unwrap();
+    assert_eq!(msg.get_signal_listeners(), &[1, -2, 0]);
+}
+
+#[test]
+fn errors_carry_a_code() {
+    let pool = RuntimePool::new("Test", RuntimeDbc::from_source(DBC).unwrap());
+    let Err(error) = pool.get_mut(999) else { panic!("canid 999 is not in the DBC") };
+    assert_eq!(error.code(), CanErrorCode::UnknownCanId);
+
+    // DbcError implements std::error::Error, so `?` works in anyhow/Box<dyn Error> code
+    let lookup = || -> Result<(), Box<dyn std::error::Error>> {
+        pool.get_mut(999).map_err(DbcError::from)?;
+        Ok(())
+    };
+    let error = lookup().unwrap_err();
+    let error = error.downcast_ref::<DbcError>().unwrap();
+    assert_eq!(error.code, CanErrorCode::UnknownCanId);
+    assert_eq!(error.to_string(), "fail-canid-search: canid:999 not found");
+    assert_eq!(CanErrorCode::from_uid("not-a-canforge-uid"), CanErrorCode::Other);
+}
+
+#[test]
+fn records_j1939_dm1_frames() {
+    let mut pool = RuntimePool::new("Test", RuntimeDbc::from_source(DBC).unwrap());
+    let dm1 = CanMsgData {
+        canid: 0x98FE_CA3D,
+        stamp: 1,
+        opcode: CanBcmOpCode::RxChanged,
+        len: 8,
+        data: vec![0x04, 0xFF, 0xBE, 0x00, 0x02, 0x03, 0xFF, 0xFF],
+    };
+
+    // ignored unless J1939 is enabled, the frame is not in the DBC either way
+    assert!(pool.update(&dm1).is_err());
+    assert!(pool.get_active_dtcs().is_empty());
+
+    pool.j1939(true);
+    assert!(pool.update(&dm1).is_err());
+    let dtcs = pool.get_active_dtcs();
+    assert_eq!(dtcs.len(), 1);
+    assert_eq!(dtcs[0].0, 0x3D);
+    assert_eq!((dtcs[0].1.spn, dtcs[0].1.fmi), (190, 2));
+    assert!(pool.get_dm1(0x3D).is_some() && pool.get_dm2(0x3D).is_none());
+}
+
+#[test]
+fn failed_reload_keeps_diagnostics() {
+    let mut pool = RuntimePool::new("Test", RuntimeDbc::from_source(DBC).unwrap());
+    pool.j1939(true);
+    let dm1 = CanMsgData {
+        canid: 0x98FE_CA3D,
+        stamp: 1,
+        opcode: CanBcmOpCode::RxChanged,
+        len: 8,
+        data: vec![0x04, 0xFF, 0xBE, 0x00, 0x02, 0x03, 0xFF, 0xFF],
+    };
+    assert!(pool.update(&dm1).is_err());
+    let before = format!("{:?}", pool.get_dm1(0x3D));
+
+    // a message still borrowed makes the reload fail
+    let msg = Rc::clone(&pool.get_messages()[0]);
+    let borrowed = msg.borrow_mut();
+    assert!(pool.reload(RuntimeDbc::from_source(DBC).unwrap()).is_err());
+    drop(borrowed);
+
+    assert_eq!(format!("{:?}", pool.get_dm1(0x3D)), before);
+    assert_eq!(pool.get_active_dtcs().len(), 1);
+    assert!(pool.update(&dm1).is_err());
+    assert_eq!(pool.get_active_dtcs().len(), 1);
+}
+
+#[test]
+fn reassembles_j1939_transport_frames() {
+    let mut pool = RuntimePool::new("Test", RuntimeDbc::from_source(DBC).unwrap());
+    pool.j1939(true);
+    let frame = |canid: u32, data: [u8; 8]| CanMsgData {
+        canid,
+        stamp: 1,
+        opcode: CanBcmOpCode::RxChanged,
+        len: 8,
+        data: data.to_vec(),
+    };
+
+    // multi-packet DM1 broadcast by 0x3D
+    let error = pool.update(&frame(0x9CEC_FF3D, [32, 14, 0, 2, 0xFF, 0xCA, 0xFE, 0])).err();
+    assert_eq!(error.map(|e| e.code()), Some(CanErrorCode::TransportPending));
+    assert!(pool.update(&frame(0x9CEB_FF3D, [1, 0x40, 0, 0x6E, 0, 0, 0x01, 0x9C])).is_err());
+    assert!(pool.get_active_dtcs().is_empty());
+    // complete, recorded even though the DBC has no DM1 message
+    assert!(pool
+        .update(&frame(0x9CEB_FF3D, [2, 0x1F, 0x23, 0x81, 0xFF, 0xFF, 0xFF, 0xFF]))
+        .is_err());
+    let dtcs = pool.get_active_dtcs();
+    assert_eq!(dtcs.len(), 2);
+    assert_eq!((dtcs[1].1.spn, dtcs[1].1.fmi), (0x1_1F9C, 3));
+}
+
+#[test]
+fn resolves_j1939_source_names() {
+    let mut pool = RuntimePool::new("Test", RuntimeDbc::from_source(DBC).unwrap());
+    pool.j1939(true);
+    let claim = CanMsgData {
+        canid: address_claim_id(0x3D),
+        stamp: 1,
+        opcode: CanBcmOpCode::RxChanged,
+        len: 8,
+        data: Name(0x1234).to_payload().to_vec(),
+    };
+    assert!(pool.update(&claim).is_err());
+    assert_eq!(pool.get_name(0x3D), Some(Name(0x1234)));
+    assert_eq!(pool.get_address_table().get_address(Name(0x1234)), Some(0x3D));
+}
+
+#[test]
+fn forwards_unknown_frames_to_the_raw_handler() {
+    let mut pool = RuntimePool::new("Test", RuntimeDbc::from_source(DBC).unwrap());
+    assert!(pool.update_frame(&frame(0x300, vec![0; 8])).is_err());
+
+    let forwarded = Rc::new(Cell::new(0));
+    let counter = Rc::clone(&forwarded);
+    pool.set_raw_handler(move |data: &CanMsgData| {
+        assert_eq!((data.canid, data.stamp, data.data.len()), (0x300, 1, 3));
+        counter.set(counter.get() + 1);
+    });
+    assert!(pool.update_frame(&frame(0x300, vec![1, 2, 3])).unwrap().is_none());
+    assert!(pool.update_frame(&frame(257, vec![0; 8])).unwrap().is_some());
+    // update() still fails, after forwarding
+    assert!(pool.update(&frame(0x300, vec![1, 2, 3])).is_err());
+    assert_eq!(forwarded.get(), 2);
+}
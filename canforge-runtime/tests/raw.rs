@@ -0,0 +1,38 @@
+#![cfg(feature = "sockcan")]
+
+use canforge_runtime::prelude::*;
+use sockcan::prelude::*;
+
+const DBC: &str = r#"VERSION "1.0"
+NS_ :
+BU_: ECU
+BO_ 257 Battery_Status: 8 ECU
+ SG_ pack_voltage_V : 0|16@1+ (0.1,0) [0|1000] "V" ECU
+BO_ 2566844926 Engine_Speed: 8 ECU
+ SG_ rpm : 0|16@1+ (0.125,0) [0|8031.875] "rpm" ECU
+"#;
+
+#[test]
+fn feeds_pool_with_raw_frames() {
+    let pool = RuntimePool::new("Test", RuntimeDbc::from_source(DBC).unwrap());
+
+    let frame = raw_msg_data(257, 42, &[0x10, 0x27, 0, 0, 0, 0, 0, 0]).unwrap();
+    assert_eq!(frame.opcode, CanBcmOpCode::RxChanged);
+    assert_eq!(frame.len, 8);
+    let msg = pool.update(&frame).unwrap();
+    assert_eq!(msg.get_stamp(), 42);
+    assert_eq!(msg.get_signals()[0].borrow().get_value(), CanDbcType::F64(1000.0));
+    drop(msg);
+
+    // extended ids keep the EFF flag, as DBC ids do
+    let msg = pool.update(&raw_msg_data(0x98FE_F1FE, 43, &[0x40, 0x1F, 0, 0, 0, 0, 0, 0]).unwrap());
+    assert_eq!(msg.unwrap().get_signals()[0].borrow().get_value(), CanDbcType::F64(1000.0));
+}
+
+#[test]
+fn rejects_error_and_remote_frames() {
+    for canid in [0x2000_0004, 0x4000_0101] {
+        let Err(error) = raw_msg_data(canid, 0, &[]) else { panic!("{canid:#x} has no data") };
+        assert_eq!(error.code(), CanErrorCode::InvalidFrame);
+    }
+}
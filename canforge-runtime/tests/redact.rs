@@ -0,0 +1,101 @@
+use canforge_runtime::prelude::*;
+
+const DBC: &str = r#"VERSION "1.0"
+NS_ :
+BU_: ECU
+BO_ 300 Gps_Position: 8 ECU
+ SG_ latitude : 0|32@1- (0.000001,0) [-90|90] "deg" ECU
+ SG_ longitude : 32|32@1- (0.000001,0) [-180|180] "deg" ECU
+BO_ 301 Vehicle_Id: 8 ECU
+ SG_ vin_part : 0|32@1+ (1,0) [0|4294967295] "" ECU
+ SG_ driver_id : 32|16@1+ (1,0) [0|65535] "" ECU
+ SG_ odometer : 48|16@1+ (1,0) [0|65535] "km" ECU
+"#;
+
+const CONFIG: &str = r#"
+salt: "fleet-a"
+rules:
+  - signal: "Gps_Position.*"
+    action: { round: 0.01 }
+  - signal: "VinPart"
+    action: hash
+  - signal: "VehicleId.driver_id"
+    action: drop
+"#;
+
+#[test]
+fn redacts_by_rule() {
+    let dbc = RuntimeDbc::from_source(DBC).unwrap();
+    let redactor = Redactor::from_yaml(CONFIG).unwrap();
+
+    let gps = dbc.get_message(300).unwrap();
+    let mut data = [0u8; 8];
+    gps.signal("latitude")
+        .unwrap()
+        .encode(SignalValue::F64(48.856_613), &mut data)
+        .unwrap();
+    gps.signal("longitude")
+        .unwrap()
+        .encode(SignalValue::F64(2.352_222), &mut data)
+        .unwrap();
+    let export = redactor.apply_frame(gps, &gps.decode(&data));
+    assert_eq!(export.len(), 2);
+    match export[0].1 {
+        ExportValue::Value(SignalValue::F64(lat)) => assert!((lat - 48.86).abs() < 1e-9),
+        ref other => panic!("unexpected {other:?}"),
+    }
+
+    let ident = dbc.get_message(301).unwrap();
+    let data = [0x12, 0x34, 0x56, 0x78, 0x01, 0x00, 0x10, 0x27];
+    let export = redactor.apply_frame(ident, &ident.decode(&data));
+    let names: Vec<&str> = export.iter().map(|(sig, _)| sig.name).collect();
+    assert_eq!(names, vec!["VinPart", "Odometer"]);
+    let ExportValue::Hashed(digest) = &export[0].1 else { panic!("vin not hashed") };
+    assert_eq!(digest.len(), 16);
+    assert_eq!(export[1].1, ExportValue::Value(SignalValue::U16(10_000)));
+
+    // a different salt gives a different digest for the same value
+    let other = Redactor::from_yaml(&CONFIG.replace("fleet-a", "fleet-b")).unwrap();
+    assert_ne!(other.apply_frame(ident, &ident.decode(&data))[0].1, export[0].1);
+}
+
+#[test]
+fn rejects_invalid_round_step() {
+    assert!(Redactor::from_yaml("rules: [{signal: Speed, action: {round: 0}}]").is_err());
+}
+
+#[test]
+fn hashes_exact_values() {
+    let dbc = RuntimeDbc::from_source(
+        "VERSION \"1.0\"\nNS_ :\nBU_: ECU\nBO_ 302 Trip: 16 ECU\n \
+         SG_ trip_id : 0|64@1+ (1,0) [0|0] \"\" ECU\n \
+         SG_ heading : 64|32@1- (0.01,0) [-180|180] \"deg\" ECU\n",
+    )
+    .unwrap();
+    let redactor = Redactor::from_yaml("rules: [{signal: \"Trip.*\", action: hash}]").unwrap();
+    let trip = dbc.get_message(302).unwrap();
+    let hash = |name: &str, value| redactor.apply(trip, trip.signal(name).unwrap(), value);
+
+    // distinct above 2^53, where f64 rounds both to the same value
+    let id = 1u64 << 60;
+    assert_ne!(hash("trip_id", SignalValue::U64(id)), hash("trip_id", SignalValue::U64(id + 1)));
+    assert_eq!(hash("heading", SignalValue::F64(-0.0)), hash("heading", SignalValue::F64(0.0)));
+}
+
+#[test]
+fn hashes_names_apart() {
+    let dbc = RuntimeDbc::from_source(
+        "VERSION \"1.0\"\nNS_ :\nBU_: ECU\n\
+         BO_ 303 AB: 1 ECU\n SG_ C : 0|8@1+ (1,0) [0|255] \"\" ECU\n\
+         BO_ 304 A: 1 ECU\n SG_ BC : 0|8@1+ (1,0) [0|255] \"\" ECU\n",
+    )
+    .unwrap();
+    let redactor = Redactor::from_yaml("rules: [{signal: \"*\", action: hash}]").unwrap();
+    let (ab, a) = (dbc.get_message(303).unwrap(), dbc.get_message(304).unwrap());
+
+    // "AB"+"C" and "A"+"BC" must not feed the same bytes to the digest
+    assert_ne!(
+        redactor.apply(ab, ab.signal("C").unwrap(), SignalValue::U8(7)),
+        redactor.apply(a, a.signal("BC").unwrap(), SignalValue::U8(7))
+    );
+}
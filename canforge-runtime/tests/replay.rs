@@ -0,0 +1,87 @@
+use canforge_runtime::prelude::*;
+
+// one frame every 100 ms over one second
+fn log() -> String {
+    (0..10)
+        .map(|idx| format!("(1700000000.{:06}) can0 101#{idx:02X}00\n", idx * 100_000))
+        .collect()
+}
+
+fn payloads(frames: &[ReplayFrame]) -> Vec<u8> {
+    frames.iter().map(|frame| frame.data[0]).collect()
+}
+
+#[test]
+fn parses_candump_lines() {
+    let frame = ReplayFrame::from_candump("(1436509052.249713) vcan0 044#2A366C2BBA").unwrap();
+    assert_eq!(frame.stamp, 1_436_509_052_249_713);
+    assert_eq!((frame.iface.as_str(), frame.canid), ("vcan0", 0x44));
+    assert_eq!(frame.data, vec![0x2a, 0x36, 0x6c, 0x2b, 0xba]);
+
+    let extended = ReplayFrame::from_candump("(0.5) can1 18FEF100#01").unwrap();
+    assert_eq!((extended.stamp, extended.canid), (500_000, 0x98FE_F100));
+    let fd = ReplayFrame::from_candump("(1.000001) can0 123##1AABB").unwrap();
+    assert_eq!((fd.canid, fd.data), (0x123, vec![0xaa, 0xbb]));
+    let remote = ReplayFrame::from_candump("(1.0) can0 123#R").unwrap();
+    assert_eq!((remote.canid, remote.data.len()), (0x4000_0123, 0));
+
+    assert!(ReplayFrame::from_candump("can0 123#00").is_err());
+    assert!(ReplayFrame::from_candump("(1.0) can0 123#0").is_err());
+    // non-ASCII payloads are rejected, not sliced inside a char
+    assert!(ReplayFrame::from_candump("(1.0) can0 123#aé1").is_err());
+    assert!(ReplayFrame::from_candump("(1.0) can0 123##1é").is_err());
+}
+
+#[test]
+fn warps_replay_time() {
+    let config = ReplayConfig { speed: 10.0, ..ReplayConfig::default() };
+    let mut replay = Replay::from_candump(config, &log()).unwrap();
+    // the first poll anchors the replay
+    assert_eq!(payloads(replay.poll(5_000_000)), vec![0]);
+    assert_eq!(replay.delay(5_000_000), Some(10_000));
+    assert!(replay.poll(5_009_999).is_empty());
+    // 100 ms of log every 10 ms
+    assert_eq!(payloads(replay.poll(5_035_000)), vec![1, 2, 3]);
+    assert_eq!(payloads(replay.poll(6_000_000)), vec![4, 5, 6, 7, 8, 9]);
+    assert!(replay.is_done());
+    assert_eq!(replay.delay(6_000_000), None);
+
+    // slowed down, driven by the replay clock
+    let config = ReplayConfig { speed: 0.5, ..ReplayConfig::default() };
+    let mut replay = Replay::from_candump(config, &log()).unwrap();
+    replay.set_clock(Box::new(FixedClock(0)));
+    assert_eq!(payloads(replay.poll_now()), vec![0]);
+    replay.set_clock(Box::new(FixedClock(199_999)));
+    assert!(replay.poll_now().is_empty());
+    assert_eq!(replay.delay_now(), Some(1));
+}
+
+#[test]
+fn replays_a_window_step_by_step() {
+    let yaml = "start_ms: 300\nstop_ms: 600\nstep: true";
+    let mut replay = Replay::from_yaml(yaml, &log()).unwrap();
+    assert_eq!(payloads(replay.get_frames()), vec![3, 4, 5]);
+    assert!(replay.poll(u64::MAX).is_empty());
+    assert_eq!(replay.delay(0), None);
+    assert_eq!(replay.step().map(|frame| frame.data[0]), Some(3));
+    assert_eq!(replay.get_remaining(), 2);
+
+    // timed replay resumes from the next frame
+    replay.set_step(false);
+    assert_eq!(payloads(replay.poll(1_000)), vec![4]);
+    assert_eq!(payloads(replay.poll(101_000)), vec![5]);
+    assert!(replay.step().is_none());
+
+    replay.rewind();
+    assert_eq!(replay.step().map(|frame| frame.stamp), Some(1_700_000_000_300_000));
+}
+
+#[test]
+fn rejects_inconsistent_config() {
+    let log = log();
+    assert!(Replay::from_yaml("speed: 0.05", &log).is_err());
+    assert!(Replay::from_yaml("speed: 150", &log).is_err());
+    assert!(Replay::from_yaml("speed: 100", &log).is_ok());
+    assert!(Replay::from_yaml("start_ms: 500\nstop_ms: 500", &log).is_err());
+    assert!(Replay::from_yaml("{}", "(1.0) can0 123#00\nnot a frame").is_err());
+}
@@ -0,0 +1,83 @@
+use canforge_runtime::prelude::*;
+
+const DBC: &str = r#"VERSION "1.0"
+NS_ :
+BU_: ECU
+BO_ 256 Gps_Position: 8 ECU
+ SG_ latitude : 0|32@1- (0.000001,0) [-90|90] "deg" ECU
+ SG_ speed : 32|16@1+ (0.01,0) [0|655.35] "km/h" ECU
+BO_ 257 Driver_Info: 4 ECU
+ SG_ driver_id : 0|32@1+ (1,0) [0|4294967295] "" ECU
+BO_ 258 Diag: 2 ECU
+ SG_ page M : 0|8@1+ (1,0) [0|255] "" ECU
+ SG_ pin m1 : 8|8@1+ (1,0) [0|255] "" ECU
+ SG_ level m2 : 8|8@1+ (1,0) [0|255] "" ECU
+"#;
+
+const CONFIG: &str = r#"
+drop_unknown: true
+messages:
+  - message: "DriverInfo"
+    action: drop
+  - message: "0x102"
+    action: { remap: 0x5A0 }
+signals:
+  - signal: "GpsPosition.latitude"
+    value: 45.0
+  - signal: "Diag.pin"
+"#;
+
+const LOG: &str = "(1700000000.000100) can0 100#40787D02E803AAAA
+(1700000000.010000) can0 101#78563412
+(1700000000.020000) can0 102#0142
+(1700000000.030000) can0 102#0242
+
+(1700000000.040000) can0 7FF#00
+(1700000000.050000) can0 20000004#0000080000000000
+(1700000000.060000) can0 102#R
+";
+
+#[test]
+fn rewrites_candump_log() {
+    let dbc = RuntimeDbc::from_source(DBC).unwrap();
+    let rewriter = LogRewriter::from_yaml(CONFIG, &dbc).unwrap();
+    let rewritten = rewriter.rewrite_candump(LOG).unwrap();
+    assert_eq!(
+        rewritten,
+        // latitude scrubbed, speed kept
+        "(1700000000.000100) can0 100#40A5AE02E803AAAA
+(1700000000.020000) can0 5A0#0100
+(1700000000.030000) can0 5A0#0242
+(1700000000.050000) can0 20000004#0000080000000000
+(1700000000.060000) can0 5A0#R
+"
+    );
+    // stamps of the kept frames are untouched
+    let frames = Replay::from_candump(ReplayConfig::default(), &rewritten).unwrap();
+    assert_eq!(frames.get_frames()[1].stamp, 1_700_000_000_020_000);
+}
+
+#[test]
+fn keeps_unknown_ids_unless_asked() {
+    let dbc = RuntimeDbc::from_source(DBC).unwrap();
+    let rewriter = LogRewriter::new(RewriteConfig::default(), &dbc).unwrap();
+    let frame = ReplayFrame::from_candump("(1.0) can0 18FEF100#01").unwrap();
+    assert_eq!(rewriter.rewrite(&frame), Some(frame.clone()));
+    assert_eq!(frame.to_candump(), "(1.000000) can0 18FEF100#01");
+
+    let config = RewriteConfig { drop_unknown: true, ..RewriteConfig::default() };
+    let rewriter = LogRewriter::new(config, &dbc).unwrap();
+    assert_eq!(rewriter.rewrite(&frame), None);
+}
+
+#[test]
+fn rejects_invalid_rules() {
+    let dbc = RuntimeDbc::from_source(DBC).unwrap();
+    let unknown = "messages:\n  - message: \"Engine\"\n    action: drop\n";
+    let error = LogRewriter::from_yaml(unknown, &dbc).err().unwrap();
+    assert_eq!(error.to_string(), "rewrite message:Engine not in the database");
+
+    let out_of_range = "signals:\n  - signal: \"latitude\"\n    value: 120\n";
+    let error = LogRewriter::from_yaml(out_of_range, &dbc).err().unwrap();
+    assert!(error.to_string().starts_with("rewrite signal:GpsPosition.Latitude value:120"));
+}
@@ -0,0 +1,178 @@
+use canforge_runtime::prelude::*;
+
+const DBC: &str = r#"VERSION "1.0"
+NS_ :
+BU_: ECU
+BO_ 257 Battery_Status: 8 ECU
+ SG_ pack_voltage_V : 0|16@1+ (0.1,0) [0|1000] "V" ECU
+ SG_ pack_current_A : 16|16@1- (0.1,0) [-1000|1000] "A" ECU
+ SG_ isolation_be : 39|9@0+ (1,0) [0|511] "kOhm" ECU
+ SG_ contactor_closed : 48|1@1+ (1,0) [0|1] "" ECU
+BO_ 512 Mux_Frame: 8 ECU
+ SG_ page M : 0|4@1+ (1,0) [0|15] "" ECU
+ SG_ speed m0 : 8|8@1+ (1,0) [0|255] "" ECU
+ SG_ temp m1 : 8|8@1- (1,0) [-128|127] "" ECU
+"#;
+
+#[test]
+fn loads_messages_with_generated_names() {
+    let dbc = RuntimeDbc::from_source(DBC).unwrap();
+    let ids: Vec<u32> = dbc.get_messages().iter().map(|msg| msg.id).collect();
+    assert_eq!(ids, vec![257, 512]);
+
+    let msg = dbc.get_message(257).unwrap();
+    assert_eq!(msg.name, "BatteryStatus");
+    assert_eq!(msg.signal("pack_voltage_V").unwrap().name, "PackVoltageV");
+    assert_eq!(dbc.get_message_by_name("Mux_Frame").unwrap().multiplexor, Some(0));
+}
+
+#[test]
+fn decode_encode_roundtrip() {
+    let dbc = RuntimeDbc::from_source(DBC).unwrap();
+    let msg = dbc.get_message(257).unwrap();
+    let mut data = [0u8; 8];
+
+    msg.signal("pack_voltage_V")
+        .unwrap()
+        .encode(SignalValue::F64(400.0), &mut data)
+        .unwrap();
+    msg.signal("pack_current_A")
+        .unwrap()
+        .encode(SignalValue::F64(-12.5), &mut data)
+        .unwrap();
+    msg.signal("isolation_be")
+        .unwrap()
+        .encode(SignalValue::U16(300), &mut data)
+        .unwrap();
+    msg.signal("contactor_closed")
+        .unwrap()
+        .encode(SignalValue::Bool(true), &mut data)
+        .unwrap();
+
+    assert_eq!(msg.signal("pack_voltage_V").unwrap().decode(&data), Some(SignalValue::F64(400.0)));
+    assert_eq!(msg.signal("pack_current_A").unwrap().decode(&data), Some(SignalValue::F64(-12.5)));
+    assert_eq!(msg.signal("isolation_be").unwrap().decode(&data), Some(SignalValue::U16(300)));
+    assert_eq!(
+        msg.signal("contactor_closed").unwrap().decode(&data),
+        Some(SignalValue::Bool(true))
+    );
+
+    // out of DBC range
+    assert!(msg
+        .signal("pack_voltage_V")
+        .unwrap()
+        .encode(SignalValue::F64(2000.0), &mut data)
+        .is_err());
+    // frame too short
+    assert_eq!(msg.signal("contactor_closed").unwrap().decode(&data[..4]), None);
+}
+
+#[test]
+fn encodes_integer_values_of_scaled_signals() {
+    let dbc = RuntimeDbc::from_source(DBC).unwrap();
+    let msg = dbc.get_message(257).unwrap();
+    let (voltage, current) =
+        (msg.signal("pack_voltage_V").unwrap(), msg.signal("pack_current_A").unwrap());
+    let mut data = [0u8; 8];
+
+    // physical values, scaled to raw like F64 ones
+    voltage.encode(SignalValue::U16(400), &mut data).unwrap();
+    current.encode(SignalValue::I16(-12), &mut data).unwrap();
+    assert_eq!(&data[..4], &[0xA0, 0x0F, 0x88, 0xFF]);
+    assert_eq!(voltage.decode(&data), Some(SignalValue::F64(400.0)));
+    assert_eq!(current.decode(&data), Some(SignalValue::F64(-12.0)));
+    assert!(voltage.encode(SignalValue::U16(1001), &mut data).is_err());
+
+    let zero = DBC.replace("(0.1,0) [0|1000]", "(0,0) [0|1000]");
+    let error = RuntimeDbc::from_source(&zero).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "message:Battery_Status signal:pack_voltage_V factor 0 cannot be encoded"
+    );
+}
+
+#[test]
+fn lists_every_transmitter() {
+    let source = format!("{DBC}BU_: ECU BMS\nBO_TX_BU_ 257 : ECU,BMS;\n");
+    let dbc = RuntimeDbc::from_source(&source).unwrap();
+    let msg = dbc.get_message(257).unwrap();
+    assert_eq!(msg.transmitter.as_deref(), Some("ECU"));
+    assert_eq!(msg.transmitters, vec!["ECU", "BMS"]);
+    assert_eq!(dbc.get_message(512).unwrap().transmitters, vec!["ECU"]);
+
+    let ids: Vec<u32> = dbc.get_messages_by_transmitter("BMS").iter().map(|msg| msg.id).collect();
+    assert_eq!(ids, vec![257]);
+}
+
+#[test]
+fn finds_signals_by_receiver() {
+    let source = DBC.replace(r#"[0|1] "" ECU"#, r#"[0|1] "" ECU,BMS"#);
+    let dbc = RuntimeDbc::from_source(&source).unwrap();
+    let consumed: Vec<(u32, &str)> = dbc
+        .get_signals_by_receiver("BMS")
+        .iter()
+        .map(|(msg, sig)| (msg.id, sig.name))
+        .collect();
+    assert_eq!(consumed, vec![(257, "ContactorClosed")]);
+    assert_eq!(dbc.get_signals_by_receiver("ECU").len(), 7);
+}
+
+#[test]
+fn resolves_shared_value_tables() {
+    let source = format!(
+        "{DBC}VAL_TABLE_ vt_onoff 0 \"Off\" 1 \"On\" ;\nVAL_ 257 contactor_closed vt_onoff ;\n"
+    );
+    let dbc = RuntimeDbc::from_source(&source).unwrap();
+    let contactor = dbc.get_message(257).unwrap().signal("contactor_closed").unwrap();
+    assert_eq!(contactor.describe(1), Some("On"));
+}
+
+#[test]
+fn rejects_duplicate_message_ids() {
+    let source =
+        format!("{DBC}BO_ 257 Battery_Copy: 8 ECU\n SG_ soc : 0|8@1+ (1,0) [0|100] \"%\" ECU\n");
+    let err = RuntimeDbc::from_source(&source).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "message:Battery_Copy id:257 already used by message:Battery_Status"
+    );
+}
+
+#[test]
+fn decodes_ieee_float_signals() {
+    let dbc =
+        RuntimeDbc::from_source(include_str!("../../dbcparser-cli/tests/dbc/float.dbc")).unwrap();
+    let imu = dbc.get_message(512).unwrap();
+    let (yaw, temperature) = (imu.signal("YawRate").unwrap(), imu.signal("Temperature").unwrap());
+    assert_eq!((yaw.kind, temperature.kind), (SignalKind::F32, SignalKind::F32));
+
+    let mut data = [0u8; 8];
+    yaw.encode(SignalValue::F64(-12.25), &mut data).unwrap();
+    temperature.encode(SignalValue::F64(25.5), &mut data).unwrap();
+    assert_eq!(data[..4], (-12.25_f32).to_bits().to_le_bytes());
+    // scaled: raw float (25.5 - 10) / 0.5
+    assert_eq!(data[4..], 31.0_f32.to_bits().to_le_bytes());
+    assert_eq!(yaw.decode(&data), Some(SignalValue::F64(-12.25)));
+    assert_eq!(temperature.decode(&data), Some(SignalValue::F64(25.5)));
+
+    let latitude = dbc.get_message(513).unwrap().signal("Latitude").unwrap();
+    assert_eq!(latitude.kind, SignalKind::F64Bits);
+    let mut data = [0u8; 8];
+    latitude.encode(SignalValue::F64(48.8566), &mut data).unwrap();
+    assert_eq!(data, 48.8566_f64.to_bits().to_le_bytes());
+    assert_eq!(latitude.decode(&data), Some(SignalValue::F64(48.8566)));
+    assert!(latitude.encode(SignalValue::F64(91.0), &mut data).is_err());
+
+    let heading = dbc.get_message(514).unwrap().signal("Heading").unwrap();
+    let mut data = [0u8; 4];
+    heading.encode(SignalValue::F64(270.5), &mut data).unwrap();
+    assert_eq!(data, 270.5_f32.to_bits().to_be_bytes());
+    assert_eq!(heading.decode(&data), Some(SignalValue::F64(270.5)));
+
+    let source = format!("{DBC}SIG_VALTYPE_ 512 speed : 1;\n");
+    let err = RuntimeDbc::from_source(&source).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "message:Mux_Frame SIG_VALTYPE_ float signal:speed needs 32 bits, not 8"
+    );
+}
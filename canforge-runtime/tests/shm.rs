@@ -0,0 +1,85 @@
+#![cfg(unix)]
+
+use canforge_runtime::prelude::*;
+use std::fs;
+
+const DBC: &str = r#"VERSION "1.0"
+NS_ :
+BU_: ECU
+BO_ 257 Battery_Status: 8 ECU
+ SG_ pack_temp : 0|8@1- (1,0) [-40|120] "degC" ECU
+ SG_ pack_voltage : 8|16@1+ (0.1,0) [0|800] "V" ECU
+BO_ 258 Vehicle_Status: 8 ECU
+ SG_ vehicle_speed : 0|16@1+ (0.01,0) [0|250] "km/h" ECU
+"#;
+
+fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+fn segment(name: &str) -> String {
+    std::env::temp_dir()
+        .join(format!("canforge-shm-{name}-{}", std::process::id()))
+        .to_string_lossy()
+        .into_owned()
+}
+
+#[test]
+fn lays_out_matching_signals() {
+    let dbc = RuntimeDbc::from_source(DBC).unwrap();
+    let path = segment("layout");
+    let config = ShmConfig {
+        path: path.clone(),
+        signals: vec!["BatteryStatus.*".to_owned(), "vehicle_speed".to_owned()],
+    };
+    let exporter = ShmExporter::new(config, &dbc).unwrap();
+    let names: Vec<_> = exporter.get_slots().iter().map(|slot| (slot.canid, slot.signal)).collect();
+    assert_eq!(names, vec![(257, "PackTemp"), (257, "PackVoltage"), (258, "VehicleSpeed")]);
+
+    let bytes = fs::read(&path).unwrap();
+    assert_eq!(bytes.len(), 64 + 3 * 64);
+    assert_eq!(&bytes[..4], b"CFSH");
+    assert_eq!(u32_at(&bytes, 0), SHM_MAGIC);
+    assert_eq!(u32_at(&bytes, 4), SHM_VERSION);
+    assert_eq!(u32_at(&bytes, 8), 3);
+    assert_eq!(u32_at(&bytes, 12), SHM_SLOT_SIZE);
+    assert_eq!(u32_at(&bytes, 16), SHM_HEADER_SIZE);
+    let slot = &bytes[128..192];
+    assert_eq!(u32_at(slot, 0), 0);
+    assert_eq!(u32_at(slot, 4), 0, "unset until pushed");
+    assert_eq!(u32_at(slot, 24), 257);
+    assert_eq!(&slot[32..57], b"BatteryStatus.PackVoltage");
+    assert_eq!(slot[57], 0);
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn publishes_values_with_sequence() {
+    let dbc = RuntimeDbc::from_source(DBC).unwrap();
+    let msg = dbc.get_message(258).unwrap();
+    let speed = msg.signal("vehicle_speed").unwrap();
+    let temp = dbc.get_message(257).unwrap().signal("pack_temp").unwrap();
+    let path = segment("values");
+    let yaml = format!("path: {path}\nsignals: [\"VehicleSpeed\"]\n");
+    let mut exporter = ShmExporter::from_yaml(&yaml, &dbc).unwrap();
+
+    assert!(exporter.push(msg, speed, 1_000_000, SignalValue::F64(42.5)).unwrap());
+    assert!(exporter.push(msg, speed, 2_000_000, SignalValue::F64(43.25)).unwrap());
+    // signals without a slot are ignored
+    assert!(!exporter.push(msg, temp, 2_000_000, SignalValue::I8(20)).unwrap());
+
+    let bytes = fs::read(&path).unwrap();
+    let slot = &bytes[64..128];
+    assert_eq!(u32_at(slot, 0), 4, "two writes, even when consistent");
+    assert_eq!(u32_at(slot, 4), 1);
+    assert_eq!(f64::from_le_bytes(slot[8..16].try_into().unwrap()), 43.25);
+    assert_eq!(u64::from_le_bytes(slot[16..24].try_into().unwrap()), 2_000_000);
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn rejects_configs_without_signals() {
+    let dbc = RuntimeDbc::from_source(DBC).unwrap();
+    let config = ShmConfig { path: segment("empty"), signals: vec!["Unknown".to_owned()] };
+    assert!(ShmExporter::new(config, &dbc).is_err());
+}
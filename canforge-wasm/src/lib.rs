@@ -0,0 +1,117 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! WebAssembly bindings of the runtime decoder.
+//!
+//! Built for `wasm32-unknown-unknown` (no sockcan, no libc), so browser dashboards decode
+//! frames with the same code as the embedded side:
+//!
+//! ```text
+//! wasm-pack build canforge-wasm --target web
+//! ```
+//!
+//! ```js
+//! const dbc = new WasmDbc(dbcText);
+//! const frame = JSON.parse(dbc.decode(0x101, bytes));
+//! ```
+
+#![doc(
+    html_logo_url = "https://iot.bzh/images/defaults/company/512-479-max-transp.png",
+    html_favicon_url = "https://iot.bzh/images/defaults/favicon.ico"
+)]
+
+use canforge_runtime::prelude::*;
+use serde::Serialize;
+use std::io::{self, Error};
+use wasm_bindgen::prelude::*;
+
+#[derive(Serialize)]
+struct SignalJson<'a> {
+    name: &'a str,
+    value: SignalValue,
+    unit: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct FrameJson<'a> {
+    id: u32,
+    name: &'a str,
+    signals: Vec<SignalJson<'a>>,
+}
+
+/// Decode a frame to JSON; multiplexed signals outside the active page are omitted.
+///
+/// # Errors
+/// Returns an error when `canid` is not in the database.
+#[allow(clippy::cast_possible_truncation)]
+pub fn decode_json(dbc: &RuntimeDbc, canid: u32, data: &[u8]) -> io::Result<String> {
+    let msg = dbc
+        .get_message(canid)
+        .ok_or_else(|| Error::other(format!("canid:{canid} not found")))?;
+    let signals = msg
+        .signals
+        .iter()
+        .zip(msg.decode(data))
+        .filter_map(|(sig, value)| {
+            let value = value?;
+            let description = match value {
+                SignalValue::F64(_) => None,
+                _ => sig.describe(value.as_f64() as i64),
+            };
+            Some(SignalJson { name: sig.name, value, unit: &sig.unit, description })
+        })
+        .collect();
+    let frame = FrameJson { id: msg.id, name: msg.name, signals };
+    serde_json::to_string(&frame).map_err(Error::other)
+}
+
+/// DBC database handle exported to JavaScript.
+#[wasm_bindgen]
+pub struct WasmDbc {
+    dbc: RuntimeDbc,
+}
+
+#[wasm_bindgen]
+impl WasmDbc {
+    /// Parse DBC text (files are fetched by the page, there is no filesystem).
+    ///
+    /// # Errors
+    /// Throws when the text is not a valid/supported DBC.
+    #[wasm_bindgen(constructor)]
+    pub fn new(source: &str) -> Result<WasmDbc, JsError> {
+        let dbc =
+            RuntimeDbc::from_source(source).map_err(|error| JsError::new(&error.to_string()))?;
+        Ok(WasmDbc { dbc })
+    }
+
+    /// Raw CAN ids known by the database, sorted.
+    #[wasm_bindgen(js_name = messageIds)]
+    #[must_use]
+    pub fn message_ids(&self) -> Vec<u32> {
+        self.dbc.get_messages().iter().map(|msg| msg.id).collect()
+    }
+
+    /// Decode one frame, returned as a JSON string.
+    ///
+    /// # Errors
+    /// Throws when `canid` is not in the database.
+    pub fn decode(&self, canid: u32, data: &[u8]) -> Result<String, JsError> {
+        decode_json(&self.dbc, canid, data).map_err(|error| JsError::new(&error.to_string()))
+    }
+}
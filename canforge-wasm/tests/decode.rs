@@ -0,0 +1,31 @@
+use canforge_runtime::RuntimeDbc;
+use canforge_wasm::decode_json;
+
+const DBC: &str = r#"VERSION "1.0"
+NS_ :
+BU_: ECU
+BO_ 512 Mux_Frame: 8 ECU
+ SG_ page M : 0|4@1+ (1,0) [0|15] "" ECU
+ SG_ speed m0 : 8|8@1+ (0.5,0) [0|127] "km/h" ECU
+ SG_ gear m1 : 8|8@1+ (1,0) [0|3] "" ECU
+VAL_ 512 gear 0 "park" 1 "drive" ;
+"#;
+
+#[test]
+fn decode_frame_to_json() {
+    let dbc = RuntimeDbc::from_source(DBC).unwrap();
+
+    let json = decode_json(&dbc, 512, &[0x00, 0x20, 0, 0, 0, 0, 0, 0]).unwrap();
+    assert_eq!(
+        json,
+        r#"{"id":512,"name":"MuxFrame","signals":[{"name":"Page","value":0,"unit":""},{"name":"Speed","value":16.0,"unit":"km/h"}]}"#
+    );
+
+    let json = decode_json(&dbc, 512, &[0x01, 0x01, 0, 0, 0, 0, 0, 0]).unwrap();
+    assert_eq!(
+        json,
+        r#"{"id":512,"name":"MuxFrame","signals":[{"name":"Page","value":1,"unit":""},{"name":"Gear","value":1,"unit":"","description":"drive"}]}"#
+    );
+
+    assert!(decode_json(&dbc, 513, &[0; 8]).is_err());
+}
@@ -25,7 +25,7 @@
 // --------------------------------------------------------------
 //       WARNING: Manual modification will be destroyed
 // --------------------------------------------------------------
-// - code generated from ./dbcparser-cli/examples/canforge_dbc_complete_norm/dbc/canforge_dbc_complete_norm.dbc (Wed Jan 28 10:51:38 2026)
+// - code generated from ./dbcparser-cli/examples/canforge_dbc_complete_norm/dbc/canforge_dbc_complete_norm.dbc (Fri Oct 16 13:59:28 2026)
 // - update only with [dbc-parser|build.rs::DbcParser]
 // - source code: https://github.com/redpesk-common/canforge-rs
 // Generated file — DO NOT EDIT.
@@ -56,10 +56,396 @@ extern crate serde;
 
 extern crate bitvec;
 use sockcan::prelude::*;
-use std::cell::{RefCell,RefMut};
+use std::cell::{Cell,RefCell,RefMut};
+use std::collections::BTreeMap;
 use std::rc::{Rc};
 
 
+/// Machine-readable codes of the CanError uids raised by this module
+pub mod errors {
+    use sockcan::prelude::*;
+    use std::error::Error;
+    use std::fmt;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum CanErrorCode {
+        InvalidSignalValue,
+        SignalSetValuesFail,
+        SignalResetFail,
+        SignalUpdateFail,
+        SignalGroupGetFail,
+        SignalGroupSetFail,
+        MessageSnapshotFail,
+        MessageGetMut,
+        UnknownCanId,
+        DerivedSignalReadOnly,
+        TransportPending,
+        InvalidFrame,
+        Other,
+    }
+
+    impl CanErrorCode {
+        /// CanError uid carrying this code
+        pub const fn uid(self) -> &'static str {
+            match self {
+                CanErrorCode::InvalidSignalValue => "invalid-signal-value",
+                CanErrorCode::SignalSetValuesFail => "signal-set-values-fail",
+                CanErrorCode::SignalResetFail => "signal-reset-fail",
+                CanErrorCode::SignalUpdateFail => "signal-update-fail",
+                CanErrorCode::SignalGroupGetFail => "signal-group-get-fail",
+                CanErrorCode::SignalGroupSetFail => "signal-group-set-fail",
+                CanErrorCode::MessageSnapshotFail => "message-snapshot-fail",
+                CanErrorCode::MessageGetMut => "message-get_mut",
+                CanErrorCode::UnknownCanId => "fail-canid-search",
+                CanErrorCode::DerivedSignalReadOnly => "derived-signal-readonly",
+                CanErrorCode::TransportPending => "j1939-tp-pending",
+                CanErrorCode::InvalidFrame => "invalid-raw-frame",
+                CanErrorCode::Other => "other",
+            }
+        }
+
+        /// Code of a CanError uid, Other when it is not raised by generated code
+        pub fn from_uid(uid: &str) -> Self {
+            match uid {
+                "invalid-signal-value" => CanErrorCode::InvalidSignalValue,
+                "signal-set-values-fail" => CanErrorCode::SignalSetValuesFail,
+                "signal-reset-fail" => CanErrorCode::SignalResetFail,
+                "signal-update-fail" => CanErrorCode::SignalUpdateFail,
+                "signal-group-get-fail" => CanErrorCode::SignalGroupGetFail,
+                "signal-group-set-fail" => CanErrorCode::SignalGroupSetFail,
+                "message-snapshot-fail" => CanErrorCode::MessageSnapshotFail,
+                "message-get_mut" => CanErrorCode::MessageGetMut,
+                "fail-canid-search" => CanErrorCode::UnknownCanId,
+                "derived-signal-readonly" => CanErrorCode::DerivedSignalReadOnly,
+                "j1939-tp-pending" => CanErrorCode::TransportPending,
+                "invalid-raw-frame" => CanErrorCode::InvalidFrame,
+                _ => CanErrorCode::Other,
+            }
+        }
+    }
+
+    impl fmt::Display for CanErrorCode {
+        fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {
+            format.write_str(self.uid())
+        }
+    }
+
+    /// CanError with its code, implements the Error trait so `?` works with anyhow/thiserror
+    #[derive(Debug, Clone)]
+    pub struct DbcError {
+        pub code: CanErrorCode,
+        pub uid: String,
+        pub info: String,
+    }
+
+    impl From<CanError> for DbcError {
+        fn from(error: CanError) -> Self {
+            DbcError {
+                code: CanErrorCode::from_uid(error.get_uid()),
+                uid: error.get_uid().to_owned(),
+                info: error.get_info().to_owned(),
+            }
+        }
+    }
+
+    impl fmt::Display for DbcError {
+        fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(format, "{}: {}", self.uid, self.info)
+        }
+    }
+
+    impl Error for DbcError {}
+
+    /// Code of a sockcan CanError
+    pub trait CanErrorCodeExt {
+        fn code(&self) -> CanErrorCode;
+    }
+
+    impl CanErrorCodeExt for CanError {
+        fn code(&self) -> CanErrorCode {
+            CanErrorCode::from_uid(self.get_uid())
+        }
+    }
+}
+
+
+/// SHA-256 of the DBC file this module was generated from
+pub const DBC_SHA256: &str = "6be844ba97514f6b90d82bb9c57d4534d027e3b88578b92dd1e07ea8dc844ac2";
+
+/// Check that the DBC file at `path` is the one this module was generated from
+pub fn verify_dbc<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<()> {
+    let path = path.as_ref();
+    let digest = dbc_sha256(&std::fs::read(path)?);
+    if digest == DBC_SHA256 {
+        Ok(())
+    } else {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("{}: sha256 {} does not match generated code {}", path.display(), digest, DBC_SHA256),
+        ))
+    }
+}
+
+fn dbc_sha256(data: &[u8]) -> String {
+    const K: [u32; 64] = [
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+        0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+        0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+        0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+        0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+        0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+        0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+        0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+    ];
+    let mut hash: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+    ];
+    let mut msg = data.to_vec();
+    msg.push(0x80);
+    while msg.len() % 64 != 56 {
+        msg.push(0);
+    }
+    msg.extend_from_slice(&(data.len() as u64).wrapping_mul(8).to_be_bytes());
+    for chunk in msg.chunks(64) {
+        let mut w = [0u32; 64];
+        for idx in 0..16 {
+            w[idx] = u32::from_be_bytes([chunk[4 * idx], chunk[4 * idx + 1], chunk[4 * idx + 2], chunk[4 * idx + 3]]);
+        }
+        for idx in 16..64 {
+            let s0 = w[idx - 15].rotate_right(7) ^ w[idx - 15].rotate_right(18) ^ (w[idx - 15] >> 3);
+            let s1 = w[idx - 2].rotate_right(17) ^ w[idx - 2].rotate_right(19) ^ (w[idx - 2] >> 10);
+            w[idx] = w[idx - 16].wrapping_add(s0).wrapping_add(w[idx - 7]).wrapping_add(s1);
+        }
+        let mut v = hash;
+        for idx in 0..64 {
+            let s1 = v[4].rotate_right(6) ^ v[4].rotate_right(11) ^ v[4].rotate_right(25);
+            let ch = (v[4] & v[5]) ^ (!v[4] & v[6]);
+            let t1 = v[7].wrapping_add(s1).wrapping_add(ch).wrapping_add(K[idx]).wrapping_add(w[idx]);
+            let s0 = v[0].rotate_right(2) ^ v[0].rotate_right(13) ^ v[0].rotate_right(22);
+            let maj = (v[0] & v[1]) ^ (v[0] & v[2]) ^ (v[1] & v[2]);
+            let t2 = s0.wrapping_add(maj);
+            v = [t1.wrapping_add(t2), v[0], v[1], v[2], v[3].wrapping_add(t1), v[4], v[5], v[6]];
+        }
+        for (word, value) in hash.iter_mut().zip(v) {
+            *word = word.wrapping_add(value);
+        }
+    }
+    hash.iter().map(|word| format!("{:08x}", word)).collect()
+}
+
+/// Serializable view of any message: id, name, status, stamp and signals by name
+pub struct MessageState<'a>(pub &'a dyn CanDbcMessage);
+
+impl serde::Serialize for MessageState<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::{Error, SerializeMap, SerializeStruct};
+        struct Signals<'a>(&'a [Rc<RefCell<Box<dyn CanDbcSignal>>>]);
+        impl serde::Serialize for Signals<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut map = serializer.serialize_map(Some(self.0.len()))?;
+                for signal in self.0 {
+                    let signal = signal.try_borrow().map_err(S::Error::custom)?;
+                    let value: serde_json::Value =
+                        serde_json::from_str(&signal.to_json()).map_err(S::Error::custom)?;
+                    map.serialize_entry(signal.get_name(), &value)?;
+                }
+                map.end()
+            }
+        }
+        let mut state = serializer.serialize_struct("DbcMessage", 5)?;
+        state.serialize_field("id", &self.0.get_id())?;
+        state.serialize_field("name", self.0.get_name())?;
+        state.serialize_field("status", &self.0.get_status())?;
+        state.serialize_field("stamp", &self.0.get_stamp())?;
+        state.serialize_field("signals", &Signals(self.0.get_signals()))?;
+        state.end()
+    }
+}
+
+impl serde::Serialize for CanMsgPool {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::{Error, SerializeSeq, SerializeStruct};
+        struct Messages<'a>(&'a [Rc<RefCell<Box<dyn CanDbcMessage>>>]);
+        impl serde::Serialize for Messages<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+                for msg in self.0 {
+                    let msg = msg.try_borrow().map_err(S::Error::custom)?;
+                    seq.serialize_element(&MessageState(msg.as_ref()))?;
+                }
+                seq.end()
+            }
+        }
+        let mut state = serializer.serialize_struct("CanMsgPool", 2)?;
+        state.serialize_field("uid", self.uid)?;
+        state.serialize_field("messages", &Messages(&self.pool))?;
+        state.end()
+    }
+}
+
+/// Value of one signal in a `PoolSnapshot`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SignalState {
+    pub canid: u32,
+    pub message: &'static str,
+    pub signal: &'static str,
+    pub value: CanDbcType,
+}
+
+/// Signal values of a whole pool at a point in time, see `CanDbcPoolDiff::snapshot()`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PoolSnapshot {
+    pub signals: Vec<SignalState>,
+}
+
+/// Signal whose value changed since a `PoolSnapshot`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SignalChange {
+    pub canid: u32,
+    pub message: &'static str,
+    pub signal: &'static str,
+    pub before: CanDbcType,
+    pub after: CanDbcType,
+}
+
+/// Snapshot every signal value of a pool and list what changed since, e.g. to only publish changes
+pub trait CanDbcPoolDiff {
+    fn snapshot(&self) -> Result<PoolSnapshot, CanError>;
+    fn diff(&self, snapshot: &PoolSnapshot) -> Result<Vec<SignalChange>, CanError>;
+}
+
+impl<T: CanDbcPool + ?Sized> CanDbcPoolDiff for T {
+    fn snapshot(&self) -> Result<PoolSnapshot, CanError> {
+        let mut signals = Vec::new();
+        for msg in self.get_messages() {
+            let msg = match msg.try_borrow() {
+                Ok(msg) => msg,
+                Err(_) => return Err(CanError::new("message-snapshot-fail", "Internal error message borrowed")),
+            };
+            for signal in msg.get_signals() {
+                let signal = match signal.try_borrow() {
+                    Ok(signal) => signal,
+                    Err(_) => return Err(CanError::new("message-snapshot-fail", format!("Internal error {} borrowed", msg.get_name()))),
+                };
+                signals.push(SignalState {
+                    canid: msg.get_id(),
+                    message: msg.get_name(),
+                    signal: signal.get_name(),
+                    value: signal.get_value(),
+                });
+            }
+        }
+        Ok(PoolSnapshot { signals })
+    }
+
+    fn diff(&self, snapshot: &PoolSnapshot) -> Result<Vec<SignalChange>, CanError> {
+        let current = self.snapshot()?;
+        if current.signals.len() != snapshot.signals.len() {
+            return Err(CanError::new("message-snapshot-fail", "snapshot taken from another pool"));
+        }
+        let mut changes = Vec::new();
+        for (before, after) in snapshot.signals.iter().zip(&current.signals) {
+            if before.canid != after.canid || before.signal != after.signal {
+                return Err(CanError::new("message-snapshot-fail", "snapshot taken from another pool"));
+            }
+            if before.value != after.value {
+                changes.push(SignalChange {
+                    canid: after.canid,
+                    message: after.message,
+                    signal: after.signal,
+                    before: before.value,
+                    after: after.value,
+                });
+            }
+        }
+        Ok(changes)
+    }
+}
+
+/// DBC attribute definitions (BA_DEF_) with their constraints and defaults (BA_DEF_DEF_)
+pub mod attributes {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum AttributeScope {
+        Network,
+        Node,
+        Message,
+        Signal,
+        EnvVar,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum AttributeKind {
+        Int,
+        Hex,
+        Float,
+        String,
+        Enum,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum AttributeValue {
+        Int(i64),
+        Float(f64),
+        Str(&'static str),
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct AttributeDef {
+        pub name: &'static str,
+        pub scope: AttributeScope,
+        pub kind: AttributeKind,
+        range: Option<(f64, f64)>,
+        choices: &'static [&'static str],
+        default: Option<AttributeValue>,
+    }
+
+    impl AttributeDef {
+        /// Allowed [min, max] for INT, HEX and FLOAT attributes
+        pub const fn range(&self) -> Option<(f64, f64)> {
+            self.range
+        }
+
+        /// Allowed labels for ENUM attributes
+        pub const fn choices(&self) -> &'static [&'static str] {
+            self.choices
+        }
+
+        pub const fn default(&self) -> Option<AttributeValue> {
+            self.default
+        }
+    }
+
+    pub const GEN_MSG_CYCLE_TIME: AttributeDef = AttributeDef {
+        name: "GenMsgCycleTime",
+        scope: AttributeScope::Message,
+        kind: AttributeKind::Int,
+        range: Some((0.0_f64, 10000.0_f64)),
+        choices: &[],
+        default: Some(AttributeValue::Int(100)),
+    };
+
+    pub const GEN_MSG_SEND_TYPE: AttributeDef = AttributeDef {
+        name: "GenMsgSendType",
+        scope: AttributeScope::Message,
+        kind: AttributeKind::Enum,
+        range: None,
+        choices: &["none", "cyclic", "event"],
+        default: Some(AttributeValue::Str("cyclic")),
+    };
+
+    pub const BUS_TYPE: AttributeDef = AttributeDef {
+        name: "BusType",
+        scope: AttributeScope::Network,
+        kind: AttributeKind::String,
+        range: None,
+        choices: &[],
+        default: Some(AttributeValue::Str("CANFD")),
+    };
+
+    pub const ALL: [AttributeDef; 3] = [GEN_MSG_CYCLE_TIME, GEN_MSG_SEND_TYPE, BUS_TYPE];
+} // end attributes
+
+
 /// Vehicle_Status Message
 /// - ID: 100 (0x64)
 /// - Size: 8 bytes
@@ -77,6 +463,9 @@ pub mod VehicleStatus { /// Message name space
     use std::fmt;
 
     use serde::{Deserialize, Serialize};
+    /// Nodes transmitting this message (BO_ and BO_TX_BU_)
+    pub const TRANSMITTERS: [&str; 1] = ["BODY_CTRL"];
+
     pub enum DbcSignal {
         IgnitionState,
         GearPosition,
@@ -104,6 +493,13 @@ pub mod VehicleStatus { /// Message name space
     /// - Offset: 0
     /// - Byte order: LittleEndian
     /// - Value type: Unsigned
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | Off |
+    /// | 1 | Accessory |
+    /// | 2 | On |
+    /// | 3 | Start |
     #[derive(Serialize, Deserialize)]
     pub struct IgnitionState {
         #[serde(skip)]
@@ -116,6 +512,14 @@ pub mod VehicleStatus { /// Message name space
     }
 
     // DBC definition for MsgID:100 Signal:ignition_state
+    /// IgnitionState values (DBC VAL_)
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | Off |
+    /// | 1 | Accessory |
+    /// | 2 | On |
+    /// | 3 | Start |
     #[derive(Serialize, Deserialize)]
     pub enum DbcIgnitionState {
         Off,
@@ -141,14 +545,47 @@ pub mod VehicleStatus { /// Message name space
 
 
     impl IgnitionState  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["INFOTAINMENT_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 3.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 2;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 0;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(IgnitionState {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            IgnitionState {
                 status: CanDataStatus::Unset,
                 name:"IgnitionState",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
@@ -164,30 +601,82 @@ pub mod VehicleStatus { /// Message name space
             }
 
         }
+        #[deprecated(note = "use set_raw()")]
         pub fn set_raw_value(&mut self, value: u8, data: &mut[u8]) {
-            data.view_bits_mut::<Lsb0>()[0..2].store_le(value);
-
+            self.set_raw(value, data)
         }
         pub fn set_as_def (&mut self, signal_def: DbcIgnitionState, data: &mut[u8])-> Result<(),CanError> {
             match signal_def {
-                DbcIgnitionState::Off => Ok(self.set_raw_value(0, data)),
-                DbcIgnitionState::Accessory => Ok(self.set_raw_value(1, data)),
-                DbcIgnitionState::On => Ok(self.set_raw_value(2, data)),
-                DbcIgnitionState::Start => Ok(self.set_raw_value(3, data)),
-                DbcIgnitionState::_Other(x) => self.set_typed_value(x,data)
+                DbcIgnitionState::Off => Ok(self.set_raw(0, data)),
+                DbcIgnitionState::Accessory => Ok(self.set_raw(1, data)),
+                DbcIgnitionState::On => Ok(self.set_raw(2, data)),
+                DbcIgnitionState::Start => Ok(self.set_raw(3, data)),
+                DbcIgnitionState::_Other(x) => self.set_physical(x,data)
             }
         }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 2 == 64 { u64::MAX } else { (1u64 << 2) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 3_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..3]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[0..2].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 3_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..3]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[0..2].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[0..2].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("VehicleStatus payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // VehicleStatus::IgnitionState impl end
 
 
@@ -209,35 +698,37 @@ pub mod VehicleStatus { /// Message name space
     }
 
 
-    /// VehicleStatus::IgnitionState public api (CanDbcSignal trait)
-    impl CanDbcSignal for IgnitionState {
+/// VehicleStatus::IgnitionState public api (CanDbcSignal trait)
+impl CanDbcSignal for IgnitionState {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[0..2].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[0..2].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -269,7 +760,7 @@ pub mod VehicleStatus { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -311,6 +802,16 @@ pub mod VehicleStatus { /// Message name space
     /// - Offset: 0
     /// - Byte order: LittleEndian
     /// - Value type: Unsigned
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | P |
+    /// | 1 | R |
+    /// | 2 | N |
+    /// | 3 | D |
+    /// | 4 | B |
+    /// | 5 | S |
+    /// | 6 | Unknown |
     #[derive(Serialize, Deserialize)]
     pub struct GearPosition {
         #[serde(skip)]
@@ -323,6 +824,17 @@ pub mod VehicleStatus { /// Message name space
     }
 
     // DBC definition for MsgID:100 Signal:gear_position
+    /// GearPosition values (DBC VAL_)
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | P |
+    /// | 1 | R |
+    /// | 2 | N |
+    /// | 3 | D |
+    /// | 4 | B |
+    /// | 5 | S |
+    /// | 6 | Unknown |
     #[derive(Serialize, Deserialize)]
     pub enum DbcGearPosition {
         P,
@@ -354,14 +866,47 @@ pub mod VehicleStatus { /// Message name space
 
 
     impl GearPosition  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["INFOTAINMENT_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 7.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 3;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 2;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(GearPosition {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            GearPosition {
                 status: CanDataStatus::Unset,
                 name:"GearPosition",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
@@ -380,33 +925,85 @@ pub mod VehicleStatus { /// Message name space
             }
 
         }
+        #[deprecated(note = "use set_raw()")]
         pub fn set_raw_value(&mut self, value: u8, data: &mut[u8]) {
-            data.view_bits_mut::<Lsb0>()[2..5].store_le(value);
-
+            self.set_raw(value, data)
         }
         pub fn set_as_def (&mut self, signal_def: DbcGearPosition, data: &mut[u8])-> Result<(),CanError> {
             match signal_def {
-                DbcGearPosition::P => Ok(self.set_raw_value(0, data)),
-                DbcGearPosition::R => Ok(self.set_raw_value(1, data)),
-                DbcGearPosition::N => Ok(self.set_raw_value(2, data)),
-                DbcGearPosition::D => Ok(self.set_raw_value(3, data)),
-                DbcGearPosition::B => Ok(self.set_raw_value(4, data)),
-                DbcGearPosition::S => Ok(self.set_raw_value(5, data)),
-                DbcGearPosition::Unknown => Ok(self.set_raw_value(6, data)),
-                DbcGearPosition::_Other(x) => self.set_typed_value(x,data)
+                DbcGearPosition::P => Ok(self.set_raw(0, data)),
+                DbcGearPosition::R => Ok(self.set_raw(1, data)),
+                DbcGearPosition::N => Ok(self.set_raw(2, data)),
+                DbcGearPosition::D => Ok(self.set_raw(3, data)),
+                DbcGearPosition::B => Ok(self.set_raw(4, data)),
+                DbcGearPosition::S => Ok(self.set_raw(5, data)),
+                DbcGearPosition::Unknown => Ok(self.set_raw(6, data)),
+                DbcGearPosition::_Other(x) => self.set_physical(x,data)
             }
         }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 3 == 64 { u64::MAX } else { (1u64 << 3) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 7_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..7]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[2..5].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 7_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..7]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[2..5].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[2..5].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("VehicleStatus payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // VehicleStatus::GearPosition impl end
 
 
@@ -428,35 +1025,37 @@ pub mod VehicleStatus { /// Message name space
     }
 
 
-    /// VehicleStatus::GearPosition public api (CanDbcSignal trait)
-    impl CanDbcSignal for GearPosition {
+/// VehicleStatus::GearPosition public api (CanDbcSignal trait)
+impl CanDbcSignal for GearPosition {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[2..5].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[2..5].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -488,7 +1087,7 @@ pub mod VehicleStatus { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -541,37 +1140,118 @@ pub mod VehicleStatus { /// Message name space
 
 
     impl VehicleSpeedKph  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["CHASSIS_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "km/h";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 300.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 0.01;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 16;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 8;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(VehicleSpeedKph {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            VehicleSpeedKph {
                 status: CanDataStatus::Unset,
                 name:"VehicleSpeedKph",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u16) -> f64 {
+            (raw as f64) * 0.01_f64 + 0_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u16 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 16 == 64 { u64::MAX } else { (1u64 << 16) - 1 };
+            let factor = 0.01_f64;
+            let offset = 0_f64;
+            let __raw_f = (value - offset) / factor;
+            ((__raw_f as u64) & __mask) as u16
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < 0_f64 || 300_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..300]",value)));
             }
-            let factor = 0.01_f64;
-            let offset = 0_f64;
-            let value = ((value - offset) / factor) as u16;
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u16, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[8..24].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_f64 || 300_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..300]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[8..24].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[8..24].load_le::<u16>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("VehicleStatus payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // VehicleStatus::VehicleSpeedKph impl end
 
 
@@ -593,44 +1273,43 @@ pub mod VehicleStatus { /// Message name space
     }
 
 
-    /// VehicleStatus::VehicleSpeedKph public api (CanDbcSignal trait)
-    impl CanDbcSignal for VehicleSpeedKph {
+/// VehicleStatus::VehicleSpeedKph public api (CanDbcSignal trait)
+impl CanDbcSignal for VehicleSpeedKph {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u16 = frame.data.view_bits::<Lsb0>()[8..24].load_le::<u16>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[8..24].load_le::<u16>();
 
-                    let factor = 0.01_f64;
-                    let offset = 0_f64;
-                    let newval = (value as f64) * factor + offset;
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -656,7 +1335,7 @@ pub mod VehicleStatus { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -709,38 +1388,122 @@ pub mod VehicleStatus { /// Message name space
 
 
     impl SteeringAngleDeg  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["CHASSIS_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "deg";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = -780.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 780.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 0.1;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 16;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 24;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(SteeringAngleDeg {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            SteeringAngleDeg {
                 status: CanDataStatus::Unset,
                 name:"SteeringAngleDeg",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u16) -> f64 {
+            // Sign-extend raw from the DBC signal bit-width before scaling.
+            let shift = u16::BITS - 16u32;
+            let signed: i16 = ((raw << shift) as i16) >> shift;
+            (signed as f64) * 0.1_f64 + 0_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u16 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 16 == 64 { u64::MAX } else { (1u64 << 16) - 1 };
+            let factor = 0.1_f64;
+            let offset = 0_f64;
+            let __raw_f = (value - offset) / factor;
+            //  Encode signed value as two's complement on 16 bits.
+            ((__raw_f as i64 as u64) & __mask) as u16
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < -780_f64 || 780_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [-780..780]",value)));
             }
-            let factor = 0.1_f64;
-            let offset = 0_f64;
-            let value = ((value - offset) / factor) as u16;
-            let value = u16::from_ne_bytes(value.to_ne_bytes());
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u16, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[24..40].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < -780_f64 || 780_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-780..780]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[24..40].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[24..40].load_le::<u16>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("VehicleStatus payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // VehicleStatus::SteeringAngleDeg impl end
 
 
@@ -762,46 +1525,43 @@ pub mod VehicleStatus { /// Message name space
     }
 
 
-    /// VehicleStatus::SteeringAngleDeg public api (CanDbcSignal trait)
-    impl CanDbcSignal for SteeringAngleDeg {
+/// VehicleStatus::SteeringAngleDeg public api (CanDbcSignal trait)
+impl CanDbcSignal for SteeringAngleDeg {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[24..40].load_le::<u16>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u16 = frame.data.view_bits::<Lsb0>()[24..40].load_le::<u16>();
 
-                    let value = i16::from_ne_bytes(value.to_ne_bytes());
 
-                    let factor = 0.1_f64;
-                    let offset = 0_f64;
-                    let newval = (value as f64) * factor + offset;
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -827,7 +1587,7 @@ pub mod VehicleStatus { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -880,31 +1640,106 @@ pub mod VehicleStatus { /// Message name space
 
 
     impl DoorFlOpen  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["INFOTAINMENT_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 1.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 1;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 40;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(DoorFlOpen {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            DoorFlOpen {
                 status: CanDataStatus::Unset,
                 name:"DoorFlOpen",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> bool {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
-            let value = value as u8;
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> bool {
+            raw == 1
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: bool) -> u8 {
+            value as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[40..41].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[40..41].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> bool {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[40..41].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("VehicleStatus payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // VehicleStatus::DoorFlOpen impl end
 
 
@@ -926,42 +1761,43 @@ pub mod VehicleStatus { /// Message name space
     }
 
 
-    /// VehicleStatus::DoorFlOpen public api (CanDbcSignal trait)
-    impl CanDbcSignal for DoorFlOpen {
+/// VehicleStatus::DoorFlOpen public api (CanDbcSignal trait)
+impl CanDbcSignal for DoorFlOpen {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[40..41].load_le::<u8>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[40..41].load_le::<u8>();
 
-                    let newval = value == 1;
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
                     };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                        self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -987,7 +1823,7 @@ pub mod VehicleStatus { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -1040,31 +1876,106 @@ pub mod VehicleStatus { /// Message name space
 
 
     impl DoorFrOpen  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["INFOTAINMENT_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 1.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 1;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 41;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(DoorFrOpen {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            DoorFrOpen {
                 status: CanDataStatus::Unset,
                 name:"DoorFrOpen",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> bool {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
-            let value = value as u8;
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> bool {
+            raw == 1
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: bool) -> u8 {
+            value as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[41..42].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[41..42].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> bool {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[41..42].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("VehicleStatus payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // VehicleStatus::DoorFrOpen impl end
 
 
@@ -1086,42 +1997,43 @@ pub mod VehicleStatus { /// Message name space
     }
 
 
-    /// VehicleStatus::DoorFrOpen public api (CanDbcSignal trait)
-    impl CanDbcSignal for DoorFrOpen {
+/// VehicleStatus::DoorFrOpen public api (CanDbcSignal trait)
+impl CanDbcSignal for DoorFrOpen {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[41..42].load_le::<u8>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[41..42].load_le::<u8>();
 
-                    let newval = value == 1;
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
                     };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                        self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -1147,7 +2059,7 @@ pub mod VehicleStatus { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -1200,31 +2112,106 @@ pub mod VehicleStatus { /// Message name space
 
 
     impl DoorRlOpen  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["INFOTAINMENT_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 1.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 1;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 42;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(DoorRlOpen {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            DoorRlOpen {
                 status: CanDataStatus::Unset,
                 name:"DoorRlOpen",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> bool {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
-            let value = value as u8;
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> bool {
+            raw == 1
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: bool) -> u8 {
+            value as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[42..43].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[42..43].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> bool {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[42..43].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("VehicleStatus payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // VehicleStatus::DoorRlOpen impl end
 
 
@@ -1246,42 +2233,43 @@ pub mod VehicleStatus { /// Message name space
     }
 
 
-    /// VehicleStatus::DoorRlOpen public api (CanDbcSignal trait)
-    impl CanDbcSignal for DoorRlOpen {
+/// VehicleStatus::DoorRlOpen public api (CanDbcSignal trait)
+impl CanDbcSignal for DoorRlOpen {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[42..43].load_le::<u8>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[42..43].load_le::<u8>();
 
-                    let newval = value == 1;
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
                     };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                        self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -1307,7 +2295,7 @@ pub mod VehicleStatus { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -1360,31 +2348,106 @@ pub mod VehicleStatus { /// Message name space
 
 
     impl DoorRrOpen  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["INFOTAINMENT_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 1.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 1;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 43;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(DoorRrOpen {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            DoorRrOpen {
                 status: CanDataStatus::Unset,
                 name:"DoorRrOpen",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> bool {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
-            let value = value as u8;
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> bool {
+            raw == 1
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: bool) -> u8 {
+            value as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[43..44].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[43..44].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> bool {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[43..44].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("VehicleStatus payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // VehicleStatus::DoorRrOpen impl end
 
 
@@ -1406,42 +2469,43 @@ pub mod VehicleStatus { /// Message name space
     }
 
 
-    /// VehicleStatus::DoorRrOpen public api (CanDbcSignal trait)
-    impl CanDbcSignal for DoorRrOpen {
+/// VehicleStatus::DoorRrOpen public api (CanDbcSignal trait)
+impl CanDbcSignal for DoorRrOpen {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[43..44].load_le::<u8>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[43..44].load_le::<u8>();
 
-                    let newval = value == 1;
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
                     };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                        self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -1467,7 +2531,7 @@ pub mod VehicleStatus { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -1520,38 +2584,122 @@ pub mod VehicleStatus { /// Message name space
 
 
     impl AmbientTempDegC  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["SENSOR_GATEWAY"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "degC";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = -40.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 87.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = -40.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 48;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(AmbientTempDegC {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            AmbientTempDegC {
                 status: CanDataStatus::Unset,
                 name:"AmbientTempDegC",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u8) -> f64 {
+            // Sign-extend raw from the DBC signal bit-width before scaling.
+            let shift = u8::BITS - 8u32;
+            let signed: i8 = ((raw << shift) as i8) >> shift;
+            (signed as f64) * 1_f64 + -40_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            let factor = 1_f64;
+            let offset = -40_f64;
+            let __raw_f = (value - offset) / factor;
+            //  Encode signed value as two's complement on 8 bits.
+            ((__raw_f as i64 as u64) & __mask) as u8
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < -40_f64 || 87_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [-40..87]",value)));
             }
-            let factor = 1_f64;
-            let offset = -40_f64;
-            let value = ((value - offset) / factor) as u8;
-            let value = u8::from_ne_bytes(value.to_ne_bytes());
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[48..56].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < -40_f64 || 87_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-40..87]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[48..56].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[48..56].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("VehicleStatus payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // VehicleStatus::AmbientTempDegC impl end
 
 
@@ -1573,46 +2721,43 @@ pub mod VehicleStatus { /// Message name space
     }
 
 
-    /// VehicleStatus::AmbientTempDegC public api (CanDbcSignal trait)
-    impl CanDbcSignal for AmbientTempDegC {
+/// VehicleStatus::AmbientTempDegC public api (CanDbcSignal trait)
+impl CanDbcSignal for AmbientTempDegC {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[48..56].load_le::<u8>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[48..56].load_le::<u8>();
 
-                    let value = i8::from_ne_bytes(value.to_ne_bytes());
 
-                    let factor = 1_f64;
-                    let offset = -40_f64;
-                    let newval = (value as f64) * factor + offset;
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -1638,7 +2783,7 @@ pub mod VehicleStatus { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -1673,6 +2818,7 @@ pub mod VehicleStatus { /// Message name space
         name: &'static str,
         status: CanBcmOpCode,
         listeners: i32,
+        signal_listeners: [i32;9],
         stamp: u64,
         id: u32,
     }
@@ -1684,6 +2830,7 @@ pub mod VehicleStatus { /// Message name space
                 name: "VehicleStatus",
                 status: CanBcmOpCode::Unknown,
                 listeners: 0,
+                signal_listeners: [0;9],
                 stamp: 0,
                 callback: None,
                 signals: [
@@ -1701,51 +2848,131 @@ pub mod VehicleStatus { /// Message name space
             })))
         }
 
+        /// Return code of each signal callback (`CanSigCtrl::sig_notification`) on the last
+        /// frame, in `DbcSignal` order: 0 without callback or for an inactive mux page, -1 when
+        /// the callback was busy. `get_listeners()` is their sum.
+        pub fn get_signal_listeners(&self) -> &[i32;9] {
+            &self.signal_listeners
+        }
+
+        /// Return code of the `signal` callback on the last frame, see `get_signal_listeners()`.
+        pub fn get_signal_listener(&self, signal: DbcSignal) -> i32 {
+            self.signal_listeners[signal as usize]
+        }
+
+        /// Index in `get_signals()` of the signal called `name`, its `get_name()` or DBC name.
+        pub fn signal_index(name: &str) -> Option<usize> {
+            match name {
+                "IgnitionState" | "ignition_state" => Some(0),
+                "GearPosition" | "gear_position" => Some(1),
+                "VehicleSpeedKph" | "vehicle_speed_kph" => Some(2),
+                "SteeringAngleDeg" | "steering_angle_deg" => Some(3),
+                "DoorFlOpen" | "door_fl_open" => Some(4),
+                "DoorFrOpen" | "door_fr_open" => Some(5),
+                "DoorRlOpen" | "door_rl_open" => Some(6),
+                "DoorRrOpen" | "door_rr_open" => Some(7),
+                "AmbientTempDegC" | "ambient_temp_degC" => Some(8),
+                _ => None,
+            }
+        }
+
+        /// Signal called `name`, its `get_name()` or DBC name, without scanning the signals.
+        pub fn get_signal(&self, name: &str) -> Option<Rc<RefCell<Box<dyn CanDbcSignal>>>> {
+            Self::signal_index(name).map(|idx| Rc::clone(&self.signals[idx]))
+        }
+
+        /// Unit, range, scaling and layout of the signal called `name`, its `get_name()` or DBC
+        /// name, for generic tools holding a `dyn CanDbcSignal`.
+        pub fn signal_meta(name: &str) -> Option<super::SignalMeta> {
+            match name {
+                "IgnitionState" | "ignition_state" => Some(IgnitionState::META),
+                "GearPosition" | "gear_position" => Some(GearPosition::META),
+                "VehicleSpeedKph" | "vehicle_speed_kph" => Some(VehicleSpeedKph::META),
+                "SteeringAngleDeg" | "steering_angle_deg" => Some(SteeringAngleDeg::META),
+                "DoorFlOpen" | "door_fl_open" => Some(DoorFlOpen::META),
+                "DoorFrOpen" | "door_fr_open" => Some(DoorFrOpen::META),
+                "DoorRlOpen" | "door_rl_open" => Some(DoorRlOpen::META),
+                "DoorRrOpen" | "door_rr_open" => Some(DoorRrOpen::META),
+                "AmbientTempDegC" | "ambient_temp_degC" => Some(AmbientTempDegC::META),
+                _ => None,
+            }
+        }
+
+
+        /// CAN id without the extended flag, 11 or 29 bits
+        pub const CAN_ID: u32 = 0x64;
+        /// 29-bit id: `get_id()` and the BCM filter carry CAN_EFF_FLAG (bit 31), like Linux can_id
+        pub const EXTENDED: bool = false;
+
+        pub fn is_extended(&self) -> bool {
+            Self::EXTENDED
+        }
+
+        /// Transmission period from the GenMsgCycleTime attribute (or its default), 0 for a
+        /// message sent on event or without attribute
+        pub const CYCLE_TIME_MS: u32 = 10;
+
+        /// `CYCLE_TIME_MS`, base of the BCM rate and watchdog timers of the message
+        pub fn get_cycle_time_ms(&self) -> u32 {
+            Self::CYCLE_TIME_MS
+        }
+
+        /// Attributes (BA_) set on the message, by DBC attribute name
+        pub const ATTRIBUTES: [(&'static str, super::attributes::AttributeValue); 1] = [("GenMsgCycleTime", super::attributes::AttributeValue::Int(10))];
+
+        /// Value of the message attribute `name`, its BA_DEF_DEF_ default when not set
+        pub fn get_attribute(name: &str) -> Option<super::attributes::AttributeValue> {
+            match name {
+                "GenMsgCycleTime" => Some(super::attributes::AttributeValue::Int(10)),
+                "GenMsgSendType" => super::attributes::GEN_MSG_SEND_TYPE.default(),
+                _ => None,
+            }
+        }
 
         pub fn set_values(&mut self, ignition_state: u8, gear_position: u8, vehicle_speed_kph: f64, steering_angle_deg: f64, door_fl_open: bool, door_fr_open: bool, door_rl_open: bool, door_rr_open: bool, ambient_temp_deg_c: f64, frame: &mut[u8]) -> Result<&mut Self, CanError> {
 
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(ignition_state), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error ignition_state:U8")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(gear_position), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error gear_position:U8")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::F64(vehicle_speed_kph), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error vehicle_speed_kph:F64")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::F64(steering_angle_deg), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error steering_angle_deg:F64")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::Bool(door_fl_open), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error door_fl_open:Bool")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::Bool(door_fr_open), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error door_fr_open:Bool")),
             }
 
-            match Rc::clone (&self.signals[6]).try_borrow_mut() {
+            match Rc::clone(&self.signals[6]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::Bool(door_rl_open), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error door_rl_open:Bool")),
             }
 
-            match Rc::clone (&self.signals[7]).try_borrow_mut() {
+            match Rc::clone(&self.signals[7]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::Bool(door_rr_open), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error door_rr_open:Bool")),
             }
 
-            match Rc::clone (&self.signals[8]).try_borrow_mut() {
+            match Rc::clone(&self.signals[8]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::F64(ambient_temp_deg_c), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error ambient_temp_deg_c:F64")),
             }
@@ -1755,52 +2982,316 @@ pub mod VehicleStatus { /// Message name space
     }
 
 
+    /// `VehicleStatus(0x..) <opcode> stamp:<us> Signal:value ...`, for logs
+    impl fmt::Display for DbcMessage {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(fmt, "VehicleStatus({:#x}) {:?} stamp:{}", self.id, self.status, self.stamp)?;
+            match self.signals[0].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " IgnitionState:{value}")?,
+                None => write!(fmt, " IgnitionState:?")?,
+            }
+            match self.signals[1].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " GearPosition:{value}")?,
+                None => write!(fmt, " GearPosition:?")?,
+            }
+            match self.signals[2].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " VehicleSpeedKph:{value}")?,
+                None => write!(fmt, " VehicleSpeedKph:?")?,
+            }
+            match self.signals[3].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " SteeringAngleDeg:{value}")?,
+                None => write!(fmt, " SteeringAngleDeg:?")?,
+            }
+            match self.signals[4].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()) {
+                Some(value) => write!(fmt, " DoorFlOpen:{value}")?,
+                None => write!(fmt, " DoorFlOpen:?")?,
+            }
+            match self.signals[5].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()) {
+                Some(value) => write!(fmt, " DoorFrOpen:{value}")?,
+                None => write!(fmt, " DoorFrOpen:?")?,
+            }
+            match self.signals[6].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()) {
+                Some(value) => write!(fmt, " DoorRlOpen:{value}")?,
+                None => write!(fmt, " DoorRlOpen:?")?,
+            }
+            match self.signals[7].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()) {
+                Some(value) => write!(fmt, " DoorRrOpen:{value}")?,
+                None => write!(fmt, " DoorRrOpen:?")?,
+            }
+            match self.signals[8].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " AmbientTempDegC:{value}")?,
+                None => write!(fmt, " AmbientTempDegC:?")?,
+            }
+            Ok(())
+        }
+    }
+
+    impl fmt::Debug for DbcMessage {
+        fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {
+            format.debug_struct("VehicleStatus")
+                .field("id", &self.id)
+                .field("status", &self.status)
+                .field("stamp", &self.stamp)
+                .field("ignition_state", &self.signals[0].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("gear_position", &self.signals[1].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("vehicle_speed_kph", &self.signals[2].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .field("steering_angle_deg", &self.signals[3].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .field("door_fl_open", &self.signals[4].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()))
+                .field("door_fr_open", &self.signals[5].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()))
+                .field("door_rl_open", &self.signals[6].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()))
+                .field("door_rr_open", &self.signals[7].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()))
+                .field("ambient_temp_deg_c", &self.signals[8].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .finish()
+        }
+    }
+
+    impl Serialize for DbcMessage {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::MessageState(self).serialize(serializer)
+        }
+    }
+
+
+    /// Values of every VehicleStatus signal, see `DbcMessage::snapshot()`
+    #[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+    pub struct SignalValues {
+        pub ignition_state: u8,
+        pub gear_position: u8,
+        pub vehicle_speed_kph: f64,
+        pub steering_angle_deg: f64,
+        pub door_fl_open: bool,
+        pub door_fr_open: bool,
+        pub door_rl_open: bool,
+        pub door_rr_open: bool,
+        pub ambient_temp_deg_c: f64,
+    }
+
+    /// Copy of a VehicleStatus message taken at a point in time, holding no RefCell borrow
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub struct MessageSnapshot {
+        pub stamp: u64,
+        pub status: CanBcmOpCode,
+        pub values: SignalValues,
+    }
+
+    impl DbcMessage {
+        /// Copy the stamp, status and every signal value of the last decoded frame.
+        pub fn snapshot(&self) -> Result<MessageSnapshot, CanError> {
+            Ok(MessageSnapshot {
+                stamp: self.stamp,
+                status: self.status,
+                values: SignalValues {
+                    ignition_state: match self.signals[0].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error ignition_state")),
+                    },
+                    gear_position: match self.signals[1].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error gear_position")),
+                    },
+                    vehicle_speed_kph: match self.signals[2].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error vehicle_speed_kph")),
+                    },
+                    steering_angle_deg: match self.signals[3].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error steering_angle_deg")),
+                    },
+                    door_fl_open: match self.signals[4].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error door_fl_open")),
+                    },
+                    door_fr_open: match self.signals[5].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error door_fr_open")),
+                    },
+                    door_rl_open: match self.signals[6].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error door_rl_open")),
+                    },
+                    door_rr_open: match self.signals[7].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error door_rr_open")),
+                    },
+                    ambient_temp_deg_c: match self.signals[8].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error ambient_temp_deg_c")),
+                    },
+                },
+            })
+        }
+    }
+
+    /// Decode a VehicleStatus payload into its values, without signal object nor pool.
+    pub fn decode(data: &[u8]) -> Result<SignalValues, CanError> {
+        if data.len() < 8 {
+            return Err(CanError::new("signal-update-fail", format!("VehicleStatus payload len:{} shorter than 8 bytes", data.len())));
+        }
+        Ok(SignalValues {
+            ignition_state: IgnitionState::decode(data),
+            gear_position: GearPosition::decode(data),
+            vehicle_speed_kph: VehicleSpeedKph::decode(data),
+            steering_angle_deg: SteeringAngleDeg::decode(data),
+            door_fl_open: DoorFlOpen::decode(data),
+            door_fr_open: DoorFrOpen::decode(data),
+            door_rl_open: DoorRlOpen::decode(data),
+            door_rr_open: DoorRrOpen::decode(data),
+            ambient_temp_deg_c: AmbientTempDegC::decode(data),
+        })
+    }
+
+    /// Encode `values` into a VehicleStatus payload, without signal object nor pool.
+    pub fn encode(values: &SignalValues, data: &mut [u8]) -> Result<(), CanError> {
+        if data.len() < 8 {
+            return Err(CanError::new("signal-set-values-fail", format!("VehicleStatus payload len:{} shorter than 8 bytes", data.len())));
+        }
+        IgnitionState::encode(values.ignition_state, data)?;
+        GearPosition::encode(values.gear_position, data)?;
+        VehicleSpeedKph::encode(values.vehicle_speed_kph, data)?;
+        SteeringAngleDeg::encode(values.steering_angle_deg, data)?;
+        DoorFlOpen::encode(values.door_fl_open, data)?;
+        DoorFrOpen::encode(values.door_fr_open, data)?;
+        DoorRlOpen::encode(values.door_rl_open, data)?;
+        DoorRrOpen::encode(values.door_rr_open, data)?;
+        AmbientTempDegC::encode(values.ambient_temp_deg_c, data)?;
+        Ok(())
+    }
+
+    /// Typed builder of a VehicleStatus payload: `VehicleStatus::builder().signal(value).build(&mut data)?`.
+    /// Signals without setter call keep their GenSigStartValue, or their default.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct MessageBuilder {
+        values: SignalValues,
+    }
+
+    /// Start a VehicleStatus payload, see `MessageBuilder`.
+    pub fn builder() -> MessageBuilder {
+        MessageBuilder {
+            values: SignalValues::default(),
+        }
+    }
+
+    impl MessageBuilder {
+        /// IgnitionState physical value
+        #[must_use]
+        pub fn ignition_state(mut self, value: u8) -> Self {
+            self.values.ignition_state = value;
+            self
+        }
+
+        /// GearPosition physical value
+        #[must_use]
+        pub fn gear_position(mut self, value: u8) -> Self {
+            self.values.gear_position = value;
+            self
+        }
+
+        /// VehicleSpeedKph physical value in km/h
+        #[must_use]
+        pub fn vehicle_speed_kph(mut self, value: f64) -> Self {
+            self.values.vehicle_speed_kph = value;
+            self
+        }
+
+        /// SteeringAngleDeg physical value in deg
+        #[must_use]
+        pub fn steering_angle_deg(mut self, value: f64) -> Self {
+            self.values.steering_angle_deg = value;
+            self
+        }
+
+        /// DoorFlOpen physical value
+        #[must_use]
+        pub fn door_fl_open(mut self, value: bool) -> Self {
+            self.values.door_fl_open = value;
+            self
+        }
+
+        /// DoorFrOpen physical value
+        #[must_use]
+        pub fn door_fr_open(mut self, value: bool) -> Self {
+            self.values.door_fr_open = value;
+            self
+        }
+
+        /// DoorRlOpen physical value
+        #[must_use]
+        pub fn door_rl_open(mut self, value: bool) -> Self {
+            self.values.door_rl_open = value;
+            self
+        }
+
+        /// DoorRrOpen physical value
+        #[must_use]
+        pub fn door_rr_open(mut self, value: bool) -> Self {
+            self.values.door_rr_open = value;
+            self
+        }
+
+        /// AmbientTempDegC physical value in degC
+        #[must_use]
+        pub fn ambient_temp_deg_c(mut self, value: f64) -> Self {
+            self.values.ambient_temp_deg_c = value;
+            self
+        }
+
+        /// Values `build()` encodes.
+        pub fn get_values(&self) -> &SignalValues {
+            &self.values
+        }
+
+        /// Encode the values into `data`, see `encode()`.
+        pub fn build(&self, data: &mut [u8]) -> Result<(), CanError> {
+            encode(&self.values, data)
+        }
+    }
+
     impl CanDbcMessage for DbcMessage {
         fn reset(&mut self) -> Result<(), CanError> {
             self.status=CanBcmOpCode::Unknown;
             self.stamp=0;
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error ignition_state:U8")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error gear_position:U8")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error vehicle_speed_kph:F64")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error steering_angle_deg:F64")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error door_fl_open:Bool")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error door_fr_open:Bool")),
             }
 
-            match Rc::clone (&self.signals[6]).try_borrow_mut() {
+            match Rc::clone(&self.signals[6]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error door_rl_open:Bool")),
             }
 
-            match Rc::clone (&self.signals[7]).try_borrow_mut() {
+            match Rc::clone(&self.signals[7]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error door_rr_open:Bool")),
             }
 
-            match Rc::clone (&self.signals[8]).try_borrow_mut() {
+            match Rc::clone(&self.signals[8]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error ambient_temp_deg_c:F64")),
             }
@@ -1812,49 +3303,50 @@ pub mod VehicleStatus { /// Message name space
             self.stamp= frame.stamp;
             self.status= frame.opcode;
             self.listeners= 0;
+            self.signal_listeners= [0;9];
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[0] = signal.update(frame); self.listeners += self.signal_listeners[0]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error ignition_state:U8")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[1] = signal.update(frame); self.listeners += self.signal_listeners[1]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error gear_position:U8")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[2] = signal.update(frame); self.listeners += self.signal_listeners[2]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error vehicle_speed_kph:F64")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[3] = signal.update(frame); self.listeners += self.signal_listeners[3]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error steering_angle_deg:F64")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[4] = signal.update(frame); self.listeners += self.signal_listeners[4]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error door_fl_open:Bool")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[5] = signal.update(frame); self.listeners += self.signal_listeners[5]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error door_fr_open:Bool")),
             }
 
-            match Rc::clone (&self.signals[6]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[6]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[6] = signal.update(frame); self.listeners += self.signal_listeners[6]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error door_rl_open:Bool")),
             }
 
-            match Rc::clone (&self.signals[7]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[7]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[7] = signal.update(frame); self.listeners += self.signal_listeners[7]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error door_rr_open:Bool")),
             }
 
-            match Rc::clone (&self.signals[8]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[8]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[8] = signal.update(frame); self.listeners += self.signal_listeners[8]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error ambient_temp_deg_c:F64")),
             }
 
@@ -1862,7 +3354,7 @@ pub mod VehicleStatus { /// Message name space
                 None => {},
                 Some(callback) => {
                     match callback.try_borrow() {
-                        Err(_) => println!("fail to get message callback reference"),
+                        Err(_) => {println!("fail to get message callback reference");},
                         Ok(cb_ref) => cb_ref.msg_notification(self),
                     }
                 }
@@ -1924,6 +3416,9 @@ pub mod EnergyPackStatus { /// Message name space
     use std::fmt;
 
     use serde::{Deserialize, Serialize};
+    /// Nodes transmitting this message (BO_ and BO_TX_BU_)
+    pub const TRANSMITTERS: [&str; 1] = ["ENERGY_CTRL"];
+
     pub enum DbcSignal {
         PackVoltageV,
         PackCurrentA,
@@ -1959,37 +3454,118 @@ pub mod EnergyPackStatus { /// Message name space
 
 
     impl PackVoltageV  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["POWERTRAIN_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "V";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 1000.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 0.1;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 16;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 0;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PackVoltageV {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PackVoltageV {
                 status: CanDataStatus::Unset,
                 name:"PackVoltageV",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u16) -> f64 {
+            (raw as f64) * 0.1_f64 + 0_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u16 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 16 == 64 { u64::MAX } else { (1u64 << 16) - 1 };
+            let factor = 0.1_f64;
+            let offset = 0_f64;
+            let __raw_f = (value - offset) / factor;
+            ((__raw_f as u64) & __mask) as u16
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < 0_f64 || 1000_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..1000]",value)));
             }
-            let factor = 0.1_f64;
-            let offset = 0_f64;
-            let value = ((value - offset) / factor) as u16;
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u16, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[0..16].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_f64 || 1000_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..1000]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[0..16].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[0..16].load_le::<u16>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("EnergyPackStatus payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // EnergyPackStatus::PackVoltageV impl end
 
 
@@ -2011,44 +3587,43 @@ pub mod EnergyPackStatus { /// Message name space
     }
 
 
-    /// EnergyPackStatus::PackVoltageV public api (CanDbcSignal trait)
-    impl CanDbcSignal for PackVoltageV {
+/// EnergyPackStatus::PackVoltageV public api (CanDbcSignal trait)
+impl CanDbcSignal for PackVoltageV {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u16 = frame.data.view_bits::<Lsb0>()[0..16].load_le::<u16>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[0..16].load_le::<u16>();
 
-                    let factor = 0.1_f64;
-                    let offset = 0_f64;
-                    let newval = (value as f64) * factor + offset;
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -2074,7 +3649,7 @@ pub mod EnergyPackStatus { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -2127,38 +3702,122 @@ pub mod EnergyPackStatus { /// Message name space
 
 
     impl PackCurrentA  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["POWERTRAIN_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "A";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = -1000.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 1000.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 0.1;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 16;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 16;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PackCurrentA {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PackCurrentA {
                 status: CanDataStatus::Unset,
                 name:"PackCurrentA",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u16) -> f64 {
+            // Sign-extend raw from the DBC signal bit-width before scaling.
+            let shift = u16::BITS - 16u32;
+            let signed: i16 = ((raw << shift) as i16) >> shift;
+            (signed as f64) * 0.1_f64 + 0_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u16 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 16 == 64 { u64::MAX } else { (1u64 << 16) - 1 };
+            let factor = 0.1_f64;
+            let offset = 0_f64;
+            let __raw_f = (value - offset) / factor;
+            //  Encode signed value as two's complement on 16 bits.
+            ((__raw_f as i64 as u64) & __mask) as u16
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < -1000_f64 || 1000_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [-1000..1000]",value)));
             }
-            let factor = 0.1_f64;
-            let offset = 0_f64;
-            let value = ((value - offset) / factor) as u16;
-            let value = u16::from_ne_bytes(value.to_ne_bytes());
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u16, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[16..32].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < -1000_f64 || 1000_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-1000..1000]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[16..32].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[16..32].load_le::<u16>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("EnergyPackStatus payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // EnergyPackStatus::PackCurrentA impl end
 
 
@@ -2180,46 +3839,43 @@ pub mod EnergyPackStatus { /// Message name space
     }
 
 
-    /// EnergyPackStatus::PackCurrentA public api (CanDbcSignal trait)
-    impl CanDbcSignal for PackCurrentA {
+/// EnergyPackStatus::PackCurrentA public api (CanDbcSignal trait)
+impl CanDbcSignal for PackCurrentA {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[16..32].load_le::<u16>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u16 = frame.data.view_bits::<Lsb0>()[16..32].load_le::<u16>();
 
-                    let value = i16::from_ne_bytes(value.to_ne_bytes());
 
-                    let factor = 0.1_f64;
-                    let offset = 0_f64;
-                    let newval = (value as f64) * factor + offset;
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -2245,7 +3901,7 @@ pub mod EnergyPackStatus { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -2298,37 +3954,118 @@ pub mod EnergyPackStatus { /// Message name space
 
 
     impl SocPercent  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["POWERTRAIN_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "%";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 100.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 0.5;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 32;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(SocPercent {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            SocPercent {
                 status: CanDataStatus::Unset,
                 name:"SocPercent",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u8) -> f64 {
+            (raw as f64) * 0.5_f64 + 0_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            let factor = 0.5_f64;
+            let offset = 0_f64;
+            let __raw_f = (value - offset) / factor;
+            ((__raw_f as u64) & __mask) as u8
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < 0_f64 || 100_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..100]",value)));
             }
-            let factor = 0.5_f64;
-            let offset = 0_f64;
-            let value = ((value - offset) / factor) as u8;
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[32..40].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_f64 || 100_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..100]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[32..40].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[32..40].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("EnergyPackStatus payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // EnergyPackStatus::SocPercent impl end
 
 
@@ -2350,44 +4087,43 @@ pub mod EnergyPackStatus { /// Message name space
     }
 
 
-    /// EnergyPackStatus::SocPercent public api (CanDbcSignal trait)
-    impl CanDbcSignal for SocPercent {
+/// EnergyPackStatus::SocPercent public api (CanDbcSignal trait)
+impl CanDbcSignal for SocPercent {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[32..40].load_le::<u8>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[32..40].load_le::<u8>();
 
-                    let factor = 0.5_f64;
-                    let offset = 0_f64;
-                    let newval = (value as f64) * factor + offset;
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -2413,7 +4149,7 @@ pub mod EnergyPackStatus { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -2466,37 +4202,118 @@ pub mod EnergyPackStatus { /// Message name space
 
 
     impl SohPercent  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["POWERTRAIN_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "%";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 100.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 0.5;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 40;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(SohPercent {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            SohPercent {
                 status: CanDataStatus::Unset,
                 name:"SohPercent",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u8) -> f64 {
+            (raw as f64) * 0.5_f64 + 0_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            let factor = 0.5_f64;
+            let offset = 0_f64;
+            let __raw_f = (value - offset) / factor;
+            ((__raw_f as u64) & __mask) as u8
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < 0_f64 || 100_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..100]",value)));
             }
-            let factor = 0.5_f64;
-            let offset = 0_f64;
-            let value = ((value - offset) / factor) as u8;
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[40..48].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_f64 || 100_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..100]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[40..48].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[40..48].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("EnergyPackStatus payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // EnergyPackStatus::SohPercent impl end
 
 
@@ -2518,44 +4335,43 @@ pub mod EnergyPackStatus { /// Message name space
     }
 
 
-    /// EnergyPackStatus::SohPercent public api (CanDbcSignal trait)
-    impl CanDbcSignal for SohPercent {
+/// EnergyPackStatus::SohPercent public api (CanDbcSignal trait)
+impl CanDbcSignal for SohPercent {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[40..48].load_le::<u8>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[40..48].load_le::<u8>();
 
-                    let factor = 0.5_f64;
-                    let offset = 0_f64;
-                    let newval = (value as f64) * factor + offset;
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -2581,7 +4397,7 @@ pub mod EnergyPackStatus { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -2634,30 +4450,115 @@ pub mod EnergyPackStatus { /// Message name space
 
 
     impl IsolationKohmBe  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["DIAG_TOOL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "kOhm";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 511.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 9;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 55;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(IsolationKohmBe {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            IsolationKohmBe {
                 status: CanDataStatus::Unset,
                 name:"IsolationKohmBe",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u16 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u16, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u16) -> u16 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u16) -> u16 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 9 == 64 { u64::MAX } else { (1u64 << 9) - 1 };
+            ((value as u64) & __mask) as u16
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u16, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u16 || 511_u16 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..511]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u16, data: &mut[u8]) {
             data.view_bits_mut::<Msb0>()[48..57].store_be(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u16, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u16 || 511_u16 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..511]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Msb0>()[48..57].store_be(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u16 {
+            Self::physical_from_raw(data.view_bits::<Msb0>()[48..57].load_be::<u16>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u16, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("EnergyPackStatus payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // EnergyPackStatus::IsolationKohmBe impl end
 
 
@@ -2679,35 +4580,37 @@ pub mod EnergyPackStatus { /// Message name space
     }
 
 
-    /// EnergyPackStatus::IsolationKohmBe public api (CanDbcSignal trait)
-    impl CanDbcSignal for IsolationKohmBe {
+/// EnergyPackStatus::IsolationKohmBe public api (CanDbcSignal trait)
+impl CanDbcSignal for IsolationKohmBe {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Msb0>()[48..57].load_be::<u16>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u16 = frame.data.view_bits::<Msb0>()[48..57].load_be::<u16>();
 
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -2739,7 +4642,7 @@ pub mod EnergyPackStatus { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -2779,6 +4682,15 @@ pub mod EnergyPackStatus { /// Message name space
     /// - Offset: 0
     /// - Byte order: LittleEndian
     /// - Value type: Unsigned
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | None |
+    /// | 1 | Info |
+    /// | 2 | Warning |
+    /// | 3 | Derating |
+    /// | 4 | Critical |
+    /// | 5 | Shutdown |
     #[derive(Serialize, Deserialize)]
     pub struct FaultLevel {
         #[serde(skip)]
@@ -2791,6 +4703,16 @@ pub mod EnergyPackStatus { /// Message name space
     }
 
     // DBC definition for MsgID:101 Signal:fault_level
+    /// FaultLevel values (DBC VAL_)
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | None |
+    /// | 1 | Info |
+    /// | 2 | Warning |
+    /// | 3 | Derating |
+    /// | 4 | Critical |
+    /// | 5 | Shutdown |
     #[derive(Serialize, Deserialize)]
     pub enum DbcFaultLevel {
         None,
@@ -2820,14 +4742,47 @@ pub mod EnergyPackStatus { /// Message name space
 
 
     impl FaultLevel  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["POWERTRAIN_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 5.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 3;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 48;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(FaultLevel {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            FaultLevel {
                 status: CanDataStatus::Unset,
                 name:"FaultLevel",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
@@ -2845,32 +4800,84 @@ pub mod EnergyPackStatus { /// Message name space
             }
 
         }
+        #[deprecated(note = "use set_raw()")]
         pub fn set_raw_value(&mut self, value: u8, data: &mut[u8]) {
-            data.view_bits_mut::<Lsb0>()[48..51].store_le(value);
-
+            self.set_raw(value, data)
         }
         pub fn set_as_def (&mut self, signal_def: DbcFaultLevel, data: &mut[u8])-> Result<(),CanError> {
             match signal_def {
-                DbcFaultLevel::None => Ok(self.set_raw_value(0, data)),
-                DbcFaultLevel::Info => Ok(self.set_raw_value(1, data)),
-                DbcFaultLevel::Warning => Ok(self.set_raw_value(2, data)),
-                DbcFaultLevel::Derating => Ok(self.set_raw_value(3, data)),
-                DbcFaultLevel::Critical => Ok(self.set_raw_value(4, data)),
-                DbcFaultLevel::Shutdown => Ok(self.set_raw_value(5, data)),
-                DbcFaultLevel::_Other(x) => self.set_typed_value(x,data)
+                DbcFaultLevel::None => Ok(self.set_raw(0, data)),
+                DbcFaultLevel::Info => Ok(self.set_raw(1, data)),
+                DbcFaultLevel::Warning => Ok(self.set_raw(2, data)),
+                DbcFaultLevel::Derating => Ok(self.set_raw(3, data)),
+                DbcFaultLevel::Critical => Ok(self.set_raw(4, data)),
+                DbcFaultLevel::Shutdown => Ok(self.set_raw(5, data)),
+                DbcFaultLevel::_Other(x) => self.set_physical(x,data)
             }
         }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 3 == 64 { u64::MAX } else { (1u64 << 3) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 5_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..5]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[48..51].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 5_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..5]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[48..51].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[48..51].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("EnergyPackStatus payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // EnergyPackStatus::FaultLevel impl end
 
 
@@ -2892,35 +4899,37 @@ pub mod EnergyPackStatus { /// Message name space
     }
 
 
-    /// EnergyPackStatus::FaultLevel public api (CanDbcSignal trait)
-    impl CanDbcSignal for FaultLevel {
+/// EnergyPackStatus::FaultLevel public api (CanDbcSignal trait)
+impl CanDbcSignal for FaultLevel {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[48..51].load_le::<u8>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[48..51].load_le::<u8>();
 
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -2952,7 +4961,7 @@ pub mod EnergyPackStatus { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -2987,6 +4996,7 @@ pub mod EnergyPackStatus { /// Message name space
         name: &'static str,
         status: CanBcmOpCode,
         listeners: i32,
+        signal_listeners: [i32;6],
         stamp: u64,
         id: u32,
     }
@@ -2998,6 +5008,7 @@ pub mod EnergyPackStatus { /// Message name space
                 name: "EnergyPackStatus",
                 status: CanBcmOpCode::Unknown,
                 listeners: 0,
+                signal_listeners: [0;6],
                 stamp: 0,
                 callback: None,
                 signals: [
@@ -3012,36 +5023,110 @@ pub mod EnergyPackStatus { /// Message name space
             })))
         }
 
+        /// Return code of each signal callback (`CanSigCtrl::sig_notification`) on the last
+        /// frame, in `DbcSignal` order: 0 without callback or for an inactive mux page, -1 when
+        /// the callback was busy. `get_listeners()` is their sum.
+        pub fn get_signal_listeners(&self) -> &[i32;6] {
+            &self.signal_listeners
+        }
+
+        /// Return code of the `signal` callback on the last frame, see `get_signal_listeners()`.
+        pub fn get_signal_listener(&self, signal: DbcSignal) -> i32 {
+            self.signal_listeners[signal as usize]
+        }
+
+        /// Index in `get_signals()` of the signal called `name`, its `get_name()` or DBC name.
+        pub fn signal_index(name: &str) -> Option<usize> {
+            match name {
+                "PackVoltageV" | "pack_voltage_V" => Some(0),
+                "PackCurrentA" | "pack_current_A" => Some(1),
+                "SocPercent" | "soc_percent" => Some(2),
+                "SohPercent" | "soh_percent" => Some(3),
+                "IsolationKohmBe" | "isolation_kohm_be" => Some(4),
+                "FaultLevel" | "fault_level" => Some(5),
+                _ => None,
+            }
+        }
+
+        /// Signal called `name`, its `get_name()` or DBC name, without scanning the signals.
+        pub fn get_signal(&self, name: &str) -> Option<Rc<RefCell<Box<dyn CanDbcSignal>>>> {
+            Self::signal_index(name).map(|idx| Rc::clone(&self.signals[idx]))
+        }
+
+        /// Unit, range, scaling and layout of the signal called `name`, its `get_name()` or DBC
+        /// name, for generic tools holding a `dyn CanDbcSignal`.
+        pub fn signal_meta(name: &str) -> Option<super::SignalMeta> {
+            match name {
+                "PackVoltageV" | "pack_voltage_V" => Some(PackVoltageV::META),
+                "PackCurrentA" | "pack_current_A" => Some(PackCurrentA::META),
+                "SocPercent" | "soc_percent" => Some(SocPercent::META),
+                "SohPercent" | "soh_percent" => Some(SohPercent::META),
+                "IsolationKohmBe" | "isolation_kohm_be" => Some(IsolationKohmBe::META),
+                "FaultLevel" | "fault_level" => Some(FaultLevel::META),
+                _ => None,
+            }
+        }
+
+
+        /// CAN id without the extended flag, 11 or 29 bits
+        pub const CAN_ID: u32 = 0x65;
+        /// 29-bit id: `get_id()` and the BCM filter carry CAN_EFF_FLAG (bit 31), like Linux can_id
+        pub const EXTENDED: bool = false;
+
+        pub fn is_extended(&self) -> bool {
+            Self::EXTENDED
+        }
+
+        /// Transmission period from the GenMsgCycleTime attribute (or its default), 0 for a
+        /// message sent on event or without attribute
+        pub const CYCLE_TIME_MS: u32 = 100;
+
+        /// `CYCLE_TIME_MS`, base of the BCM rate and watchdog timers of the message
+        pub fn get_cycle_time_ms(&self) -> u32 {
+            Self::CYCLE_TIME_MS
+        }
+
+        /// Attributes (BA_) set on the message, by DBC attribute name
+        pub const ATTRIBUTES: [(&'static str, super::attributes::AttributeValue); 1] = [("GenMsgCycleTime", super::attributes::AttributeValue::Int(100))];
+
+        /// Value of the message attribute `name`, its BA_DEF_DEF_ default when not set
+        pub fn get_attribute(name: &str) -> Option<super::attributes::AttributeValue> {
+            match name {
+                "GenMsgCycleTime" => Some(super::attributes::AttributeValue::Int(100)),
+                "GenMsgSendType" => super::attributes::GEN_MSG_SEND_TYPE.default(),
+                _ => None,
+            }
+        }
 
         pub fn set_values(&mut self, pack_voltage_v: f64, pack_current_a: f64, soc_percent: f64, soh_percent: f64, isolation_kohm_be: u16, fault_level: u8, frame: &mut[u8]) -> Result<&mut Self, CanError> {
 
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::F64(pack_voltage_v), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error pack_voltage_v:F64")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::F64(pack_current_a), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error pack_current_a:F64")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::F64(soc_percent), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error soc_percent:F64")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::F64(soh_percent), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error soh_percent:F64")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U16(isolation_kohm_be), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error isolation_kohm_be:U16")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(fault_level), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error fault_level:U8")),
             }
@@ -3051,37 +5136,244 @@ pub mod EnergyPackStatus { /// Message name space
     }
 
 
+    /// `EnergyPackStatus(0x..) <opcode> stamp:<us> Signal:value ...`, for logs
+    impl fmt::Display for DbcMessage {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(fmt, "EnergyPackStatus({:#x}) {:?} stamp:{}", self.id, self.status, self.stamp)?;
+            match self.signals[0].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " PackVoltageV:{value}")?,
+                None => write!(fmt, " PackVoltageV:?")?,
+            }
+            match self.signals[1].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " PackCurrentA:{value}")?,
+                None => write!(fmt, " PackCurrentA:?")?,
+            }
+            match self.signals[2].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " SocPercent:{value}")?,
+                None => write!(fmt, " SocPercent:?")?,
+            }
+            match self.signals[3].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " SohPercent:{value}")?,
+                None => write!(fmt, " SohPercent:?")?,
+            }
+            match self.signals[4].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u16>().ok()) {
+                Some(value) => write!(fmt, " IsolationKohmBe:{value}")?,
+                None => write!(fmt, " IsolationKohmBe:?")?,
+            }
+            match self.signals[5].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " FaultLevel:{value}")?,
+                None => write!(fmt, " FaultLevel:?")?,
+            }
+            Ok(())
+        }
+    }
+
+    impl fmt::Debug for DbcMessage {
+        fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {
+            format.debug_struct("EnergyPackStatus")
+                .field("id", &self.id)
+                .field("status", &self.status)
+                .field("stamp", &self.stamp)
+                .field("pack_voltage_v", &self.signals[0].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .field("pack_current_a", &self.signals[1].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .field("soc_percent", &self.signals[2].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .field("soh_percent", &self.signals[3].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .field("isolation_kohm_be", &self.signals[4].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u16>().ok()))
+                .field("fault_level", &self.signals[5].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .finish()
+        }
+    }
+
+    impl Serialize for DbcMessage {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::MessageState(self).serialize(serializer)
+        }
+    }
+
+
+    /// Values of every EnergyPackStatus signal, see `DbcMessage::snapshot()`
+    #[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+    pub struct SignalValues {
+        pub pack_voltage_v: f64,
+        pub pack_current_a: f64,
+        pub soc_percent: f64,
+        pub soh_percent: f64,
+        pub isolation_kohm_be: u16,
+        pub fault_level: u8,
+    }
+
+    /// Copy of a EnergyPackStatus message taken at a point in time, holding no RefCell borrow
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub struct MessageSnapshot {
+        pub stamp: u64,
+        pub status: CanBcmOpCode,
+        pub values: SignalValues,
+    }
+
+    impl DbcMessage {
+        /// Copy the stamp, status and every signal value of the last decoded frame.
+        pub fn snapshot(&self) -> Result<MessageSnapshot, CanError> {
+            Ok(MessageSnapshot {
+                stamp: self.stamp,
+                status: self.status,
+                values: SignalValues {
+                    pack_voltage_v: match self.signals[0].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error pack_voltage_v")),
+                    },
+                    pack_current_a: match self.signals[1].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error pack_current_a")),
+                    },
+                    soc_percent: match self.signals[2].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error soc_percent")),
+                    },
+                    soh_percent: match self.signals[3].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error soh_percent")),
+                    },
+                    isolation_kohm_be: match self.signals[4].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error isolation_kohm_be")),
+                    },
+                    fault_level: match self.signals[5].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error fault_level")),
+                    },
+                },
+            })
+        }
+    }
+
+    /// Decode a EnergyPackStatus payload into its values, without signal object nor pool.
+    pub fn decode(data: &[u8]) -> Result<SignalValues, CanError> {
+        if data.len() < 8 {
+            return Err(CanError::new("signal-update-fail", format!("EnergyPackStatus payload len:{} shorter than 8 bytes", data.len())));
+        }
+        Ok(SignalValues {
+            pack_voltage_v: PackVoltageV::decode(data),
+            pack_current_a: PackCurrentA::decode(data),
+            soc_percent: SocPercent::decode(data),
+            soh_percent: SohPercent::decode(data),
+            isolation_kohm_be: IsolationKohmBe::decode(data),
+            fault_level: FaultLevel::decode(data),
+        })
+    }
+
+    /// Encode `values` into a EnergyPackStatus payload, without signal object nor pool.
+    pub fn encode(values: &SignalValues, data: &mut [u8]) -> Result<(), CanError> {
+        if data.len() < 8 {
+            return Err(CanError::new("signal-set-values-fail", format!("EnergyPackStatus payload len:{} shorter than 8 bytes", data.len())));
+        }
+        PackVoltageV::encode(values.pack_voltage_v, data)?;
+        PackCurrentA::encode(values.pack_current_a, data)?;
+        SocPercent::encode(values.soc_percent, data)?;
+        SohPercent::encode(values.soh_percent, data)?;
+        IsolationKohmBe::encode(values.isolation_kohm_be, data)?;
+        FaultLevel::encode(values.fault_level, data)?;
+        Ok(())
+    }
+
+    /// Typed builder of a EnergyPackStatus payload: `EnergyPackStatus::builder().signal(value).build(&mut data)?`.
+    /// Signals without setter call keep their GenSigStartValue, or their default.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct MessageBuilder {
+        values: SignalValues,
+    }
+
+    /// Start a EnergyPackStatus payload, see `MessageBuilder`.
+    pub fn builder() -> MessageBuilder {
+        MessageBuilder {
+            values: SignalValues::default(),
+        }
+    }
+
+    impl MessageBuilder {
+        /// PackVoltageV physical value in V
+        #[must_use]
+        pub fn pack_voltage_v(mut self, value: f64) -> Self {
+            self.values.pack_voltage_v = value;
+            self
+        }
+
+        /// PackCurrentA physical value in A
+        #[must_use]
+        pub fn pack_current_a(mut self, value: f64) -> Self {
+            self.values.pack_current_a = value;
+            self
+        }
+
+        /// SocPercent physical value in %
+        #[must_use]
+        pub fn soc_percent(mut self, value: f64) -> Self {
+            self.values.soc_percent = value;
+            self
+        }
+
+        /// SohPercent physical value in %
+        #[must_use]
+        pub fn soh_percent(mut self, value: f64) -> Self {
+            self.values.soh_percent = value;
+            self
+        }
+
+        /// IsolationKohmBe physical value in kOhm
+        #[must_use]
+        pub fn isolation_kohm_be(mut self, value: u16) -> Self {
+            self.values.isolation_kohm_be = value;
+            self
+        }
+
+        /// FaultLevel physical value
+        #[must_use]
+        pub fn fault_level(mut self, value: u8) -> Self {
+            self.values.fault_level = value;
+            self
+        }
+
+        /// Values `build()` encodes.
+        pub fn get_values(&self) -> &SignalValues {
+            &self.values
+        }
+
+        /// Encode the values into `data`, see `encode()`.
+        pub fn build(&self, data: &mut [u8]) -> Result<(), CanError> {
+            encode(&self.values, data)
+        }
+    }
+
     impl CanDbcMessage for DbcMessage {
         fn reset(&mut self) -> Result<(), CanError> {
             self.status=CanBcmOpCode::Unknown;
             self.stamp=0;
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error pack_voltage_v:F64")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error pack_current_a:F64")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error soc_percent:F64")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error soh_percent:F64")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error isolation_kohm_be:U16")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error fault_level:U8")),
             }
@@ -3093,34 +5385,35 @@ pub mod EnergyPackStatus { /// Message name space
             self.stamp= frame.stamp;
             self.status= frame.opcode;
             self.listeners= 0;
+            self.signal_listeners= [0;6];
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[0] = signal.update(frame); self.listeners += self.signal_listeners[0]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error pack_voltage_v:F64")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[1] = signal.update(frame); self.listeners += self.signal_listeners[1]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error pack_current_a:F64")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[2] = signal.update(frame); self.listeners += self.signal_listeners[2]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error soc_percent:F64")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[3] = signal.update(frame); self.listeners += self.signal_listeners[3]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error soh_percent:F64")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[4] = signal.update(frame); self.listeners += self.signal_listeners[4]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error isolation_kohm_be:U16")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[5] = signal.update(frame); self.listeners += self.signal_listeners[5]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error fault_level:U8")),
             }
 
@@ -3128,7 +5421,7 @@ pub mod EnergyPackStatus { /// Message name space
                 None => {},
                 Some(callback) => {
                     match callback.try_borrow() {
-                        Err(_) => println!("fail to get message callback reference"),
+                        Err(_) => {println!("fail to get message callback reference");},
                         Ok(cb_ref) => cb_ref.msg_notification(self),
                     }
                 }
@@ -3190,6 +5483,9 @@ pub mod PowertrainMux { /// Message name space
     use std::fmt;
 
     use serde::{Deserialize, Serialize};
+    /// Nodes transmitting this message (BO_ and BO_TX_BU_)
+    pub const TRANSMITTERS: [&str; 1] = ["POWERTRAIN_CTRL"];
+
     pub enum DbcSignal {
         PtMux,
         AliveCounter,
@@ -3235,30 +5531,115 @@ pub mod PowertrainMux { /// Message name space
 
 
     impl PtMux  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 15.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 4;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 0;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PtMux {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PtMux {
                 status: CanDataStatus::Unset,
                 name:"PtMux",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 4 == 64 { u64::MAX } else { (1u64 << 4) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 15_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..15]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[0..4].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 15_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..15]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[0..4].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[0..4].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // PowertrainMux::PtMux impl end
 
 
@@ -3280,35 +5661,37 @@ pub mod PowertrainMux { /// Message name space
     }
 
 
-    /// PowertrainMux::PtMux public api (CanDbcSignal trait)
-    impl CanDbcSignal for PtMux {
+/// PowertrainMux::PtMux public api (CanDbcSignal trait)
+impl CanDbcSignal for PtMux {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[0..4].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[0..4].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -3340,7 +5723,7 @@ pub mod PowertrainMux { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -3393,30 +5776,115 @@ pub mod PowertrainMux { /// Message name space
 
 
     impl AliveCounter  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 15.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 4;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 4;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(AliveCounter {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            AliveCounter {
                 status: CanDataStatus::Unset,
                 name:"AliveCounter",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 4 == 64 { u64::MAX } else { (1u64 << 4) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 15_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..15]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[4..8].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 15_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..15]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[4..8].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[4..8].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // PowertrainMux::AliveCounter impl end
 
 
@@ -3438,35 +5906,37 @@ pub mod PowertrainMux { /// Message name space
     }
 
 
-    /// PowertrainMux::AliveCounter public api (CanDbcSignal trait)
-    impl CanDbcSignal for AliveCounter {
+/// PowertrainMux::AliveCounter public api (CanDbcSignal trait)
+impl CanDbcSignal for AliveCounter {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[4..8].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[4..8].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -3498,7 +5968,7 @@ pub mod PowertrainMux { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -3551,30 +6021,115 @@ pub mod PowertrainMux { /// Message name space
 
 
     impl Checksum  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 56;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(Checksum {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            Checksum {
                 status: CanDataStatus::Unset,
                 name:"Checksum",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[56..64].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[56..64].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[56..64].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // PowertrainMux::Checksum impl end
 
 
@@ -3596,35 +6151,37 @@ pub mod PowertrainMux { /// Message name space
     }
 
 
-    /// PowertrainMux::Checksum public api (CanDbcSignal trait)
-    impl CanDbcSignal for Checksum {
+/// PowertrainMux::Checksum public api (CanDbcSignal trait)
+impl CanDbcSignal for Checksum {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[56..64].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[56..64].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -3656,7 +6213,7 @@ pub mod PowertrainMux { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -3709,37 +6266,123 @@ pub mod PowertrainMux { /// Message name space
 
 
     impl EngineRpm  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "rpm";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 8000.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 0.25;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 16;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 8;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(EngineRpm {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            EngineRpm {
                 status: CanDataStatus::Unset,
                 name:"EngineRpm",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u16) -> f64 {
+            (raw as f64) * 0.25_f64 + 0_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u16 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 16 == 64 { u64::MAX } else { (1u64 << 16) - 1 };
+            let factor = 0.25_f64;
+            let offset = 0_f64;
+            let __raw_f = (value - offset) / factor;
+            ((__raw_f as u64) & __mask) as u16
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < 0_f64 || 8000_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..8000]",value)));
             }
-            let factor = 0.25_f64;
-            let offset = 0_f64;
-            let value = ((value - offset) / factor) as u16;
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u16, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[8..24].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_f64 || 8000_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..8000]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[8..24].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[8..24].load_le::<u16>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message,
+        /// or when the frame multiplexor selects another page than the one of this signal.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux payload len:{} shorter than 8 bytes", data.len())));
+            }
+            let __mux_raw_value = PtMux::raw_from_physical(PtMux::decode(data)) as u64;
+            if __mux_raw_value != 0 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux.EngineRpm is on page 0, frame multiplexor selects {__mux_raw_value}")));
+            }
+            Self::encode(value, data)
+        }
+
     } // PowertrainMux::EngineRpm impl end
 
 
@@ -3761,44 +6404,43 @@ pub mod PowertrainMux { /// Message name space
     }
 
 
-    /// PowertrainMux::EngineRpm public api (CanDbcSignal trait)
-    impl CanDbcSignal for EngineRpm {
+/// PowertrainMux::EngineRpm public api (CanDbcSignal trait)
+impl CanDbcSignal for EngineRpm {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[8..24].load_le::<u16>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u16 = frame.data.view_bits::<Lsb0>()[8..24].load_le::<u16>();
 
-                    let factor = 0.25_f64;
-                    let offset = 0_f64;
-                    let newval = (value as f64) * factor + offset;
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -3824,7 +6466,7 @@ pub mod PowertrainMux { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -3877,37 +6519,123 @@ pub mod PowertrainMux { /// Message name space
 
 
     impl ThrottlePosPercent  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "%";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 100.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 0.4;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 24;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(ThrottlePosPercent {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            ThrottlePosPercent {
                 status: CanDataStatus::Unset,
                 name:"ThrottlePosPercent",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u8) -> f64 {
+            (raw as f64) * 0.4_f64 + 0_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            let factor = 0.4_f64;
+            let offset = 0_f64;
+            let __raw_f = (value - offset) / factor;
+            ((__raw_f as u64) & __mask) as u8
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < 0_f64 || 100_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..100]",value)));
             }
-            let factor = 0.4_f64;
-            let offset = 0_f64;
-            let value = ((value - offset) / factor) as u8;
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[24..32].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_f64 || 100_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..100]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[24..32].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[24..32].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message,
+        /// or when the frame multiplexor selects another page than the one of this signal.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux payload len:{} shorter than 8 bytes", data.len())));
+            }
+            let __mux_raw_value = PtMux::raw_from_physical(PtMux::decode(data)) as u64;
+            if __mux_raw_value != 0 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux.ThrottlePosPercent is on page 0, frame multiplexor selects {__mux_raw_value}")));
+            }
+            Self::encode(value, data)
+        }
+
     } // PowertrainMux::ThrottlePosPercent impl end
 
 
@@ -3929,44 +6657,43 @@ pub mod PowertrainMux { /// Message name space
     }
 
 
-    /// PowertrainMux::ThrottlePosPercent public api (CanDbcSignal trait)
-    impl CanDbcSignal for ThrottlePosPercent {
+/// PowertrainMux::ThrottlePosPercent public api (CanDbcSignal trait)
+impl CanDbcSignal for ThrottlePosPercent {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[24..32].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[24..32].load_le::<u8>();
 
-                    let factor = 0.4_f64;
-                    let offset = 0_f64;
-                    let newval = (value as f64) * factor + offset;
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -3992,7 +6719,7 @@ pub mod PowertrainMux { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -4045,37 +6772,123 @@ pub mod PowertrainMux { /// Message name space
 
 
     impl FuelRateLph  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "L/h";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 200.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 0.01;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 16;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 32;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(FuelRateLph {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            FuelRateLph {
                 status: CanDataStatus::Unset,
                 name:"FuelRateLph",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u16) -> f64 {
+            (raw as f64) * 0.01_f64 + 0_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u16 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 16 == 64 { u64::MAX } else { (1u64 << 16) - 1 };
+            let factor = 0.01_f64;
+            let offset = 0_f64;
+            let __raw_f = (value - offset) / factor;
+            ((__raw_f as u64) & __mask) as u16
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < 0_f64 || 200_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..200]",value)));
             }
-            let factor = 0.01_f64;
-            let offset = 0_f64;
-            let value = ((value - offset) / factor) as u16;
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u16, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[32..48].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_f64 || 200_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..200]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[32..48].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[32..48].load_le::<u16>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message,
+        /// or when the frame multiplexor selects another page than the one of this signal.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux payload len:{} shorter than 8 bytes", data.len())));
+            }
+            let __mux_raw_value = PtMux::raw_from_physical(PtMux::decode(data)) as u64;
+            if __mux_raw_value != 0 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux.FuelRateLph is on page 0, frame multiplexor selects {__mux_raw_value}")));
+            }
+            Self::encode(value, data)
+        }
+
     } // PowertrainMux::FuelRateLph impl end
 
 
@@ -4097,44 +6910,43 @@ pub mod PowertrainMux { /// Message name space
     }
 
 
-    /// PowertrainMux::FuelRateLph public api (CanDbcSignal trait)
-    impl CanDbcSignal for FuelRateLph {
+/// PowertrainMux::FuelRateLph public api (CanDbcSignal trait)
+impl CanDbcSignal for FuelRateLph {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[32..48].load_le::<u16>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u16 = frame.data.view_bits::<Lsb0>()[32..48].load_le::<u16>();
 
-                    let factor = 0.01_f64;
-                    let offset = 0_f64;
-                    let newval = (value as f64) * factor + offset;
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -4160,7 +6972,7 @@ pub mod PowertrainMux { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -4213,38 +7025,127 @@ pub mod PowertrainMux { /// Message name space
 
 
     impl MotorTorqueNm  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "Nm";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = -600.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 600.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 0.1;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 16;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 8;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(MotorTorqueNm {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            MotorTorqueNm {
                 status: CanDataStatus::Unset,
                 name:"MotorTorqueNm",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u16) -> f64 {
+            // Sign-extend raw from the DBC signal bit-width before scaling.
+            let shift = u16::BITS - 16u32;
+            let signed: i16 = ((raw << shift) as i16) >> shift;
+            (signed as f64) * 0.1_f64 + 0_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u16 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 16 == 64 { u64::MAX } else { (1u64 << 16) - 1 };
+            let factor = 0.1_f64;
+            let offset = 0_f64;
+            let __raw_f = (value - offset) / factor;
+            //  Encode signed value as two's complement on 16 bits.
+            ((__raw_f as i64 as u64) & __mask) as u16
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < -600_f64 || 600_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [-600..600]",value)));
             }
-            let factor = 0.1_f64;
-            let offset = 0_f64;
-            let value = ((value - offset) / factor) as u16;
-            let value = u16::from_ne_bytes(value.to_ne_bytes());
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u16, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[8..24].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < -600_f64 || 600_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-600..600]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[8..24].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[8..24].load_le::<u16>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message,
+        /// or when the frame multiplexor selects another page than the one of this signal.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux payload len:{} shorter than 8 bytes", data.len())));
+            }
+            let __mux_raw_value = PtMux::raw_from_physical(PtMux::decode(data)) as u64;
+            if __mux_raw_value != 1 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux.MotorTorqueNm is on page 1, frame multiplexor selects {__mux_raw_value}")));
+            }
+            Self::encode(value, data)
+        }
+
     } // PowertrainMux::MotorTorqueNm impl end
 
 
@@ -4266,46 +7167,43 @@ pub mod PowertrainMux { /// Message name space
     }
 
 
-    /// PowertrainMux::MotorTorqueNm public api (CanDbcSignal trait)
-    impl CanDbcSignal for MotorTorqueNm {
+/// PowertrainMux::MotorTorqueNm public api (CanDbcSignal trait)
+impl CanDbcSignal for MotorTorqueNm {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[8..24].load_le::<u16>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u16 = frame.data.view_bits::<Lsb0>()[8..24].load_le::<u16>();
 
-                    let value = i16::from_ne_bytes(value.to_ne_bytes());
 
-                    let factor = 0.1_f64;
-                    let offset = 0_f64;
-                    let newval = (value as f64) * factor + offset;
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -4331,7 +7229,7 @@ pub mod PowertrainMux { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -4384,31 +7282,123 @@ pub mod PowertrainMux { /// Message name space
 
 
     impl MotorSpeedRpm  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "rpm";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = -20000.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 20000.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 16;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 24;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(MotorSpeedRpm {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            MotorSpeedRpm {
                 status: CanDataStatus::Unset,
                 name:"MotorSpeedRpm",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> i16 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:i16, data:&mut [u8]) -> Result<(),CanError> {
-            let value = u16::from_ne_bytes(value.to_ne_bytes());
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u16) -> i16 {
+            let shift = u16::BITS - 16u32;
+            let signed: i16 = ((raw << shift) as i16) >> shift;
+            signed
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: i16) -> u16 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 16 == 64 { u64::MAX } else { (1u64 << 16) - 1 };
+            //  Encode signed integer as two's complement on 16 bits.
+            ((value as i64 as u64) & __mask) as u16
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:i16, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < -20000_i16 || 20000_i16 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-20000..20000]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u16, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[24..40].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:i16, data:&mut [u8]) -> Result<(),CanError> {
+            if value < -20000_i16 || 20000_i16 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-20000..20000]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[24..40].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> i16 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[24..40].load_le::<u16>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message,
+        /// or when the frame multiplexor selects another page than the one of this signal.
+        pub fn set_signal_into(value:i16, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux payload len:{} shorter than 8 bytes", data.len())));
+            }
+            let __mux_raw_value = PtMux::raw_from_physical(PtMux::decode(data)) as u64;
+            if __mux_raw_value != 1 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux.MotorSpeedRpm is on page 1, frame multiplexor selects {__mux_raw_value}")));
+            }
+            Self::encode(value, data)
+        }
+
     } // PowertrainMux::MotorSpeedRpm impl end
 
 
@@ -4430,37 +7420,37 @@ pub mod PowertrainMux { /// Message name space
     }
 
 
-    /// PowertrainMux::MotorSpeedRpm public api (CanDbcSignal trait)
-    impl CanDbcSignal for MotorSpeedRpm {
+/// PowertrainMux::MotorSpeedRpm public api (CanDbcSignal trait)
+impl CanDbcSignal for MotorSpeedRpm {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[24..40].load_le::<u16>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u16 = frame.data.view_bits::<Lsb0>()[24..40].load_le::<u16>();
 
-                    let value = i16::from_ne_bytes(value.to_ne_bytes());
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -4492,7 +7482,7 @@ pub mod PowertrainMux { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -4545,38 +7535,127 @@ pub mod PowertrainMux { /// Message name space
 
 
     impl InverterTempDegC  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "degC";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = -40.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 215.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = -40.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 8;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(InverterTempDegC {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            InverterTempDegC {
                 status: CanDataStatus::Unset,
                 name:"InverterTempDegC",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u8) -> f64 {
+            // Sign-extend raw from the DBC signal bit-width before scaling.
+            let shift = u8::BITS - 8u32;
+            let signed: i8 = ((raw << shift) as i8) >> shift;
+            (signed as f64) * 1_f64 + -40_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            let factor = 1_f64;
+            let offset = -40_f64;
+            let __raw_f = (value - offset) / factor;
+            //  Encode signed value as two's complement on 8 bits.
+            ((__raw_f as i64 as u64) & __mask) as u8
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < -40_f64 || 215_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [-40..215]",value)));
             }
-            let factor = 1_f64;
-            let offset = -40_f64;
-            let value = ((value - offset) / factor) as u8;
-            let value = u8::from_ne_bytes(value.to_ne_bytes());
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[8..16].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < -40_f64 || 215_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-40..215]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[8..16].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[8..16].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message,
+        /// or when the frame multiplexor selects another page than the one of this signal.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux payload len:{} shorter than 8 bytes", data.len())));
+            }
+            let __mux_raw_value = PtMux::raw_from_physical(PtMux::decode(data)) as u64;
+            if __mux_raw_value != 2 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux.InverterTempDegC is on page 2, frame multiplexor selects {__mux_raw_value}")));
+            }
+            Self::encode(value, data)
+        }
+
     } // PowertrainMux::InverterTempDegC impl end
 
 
@@ -4598,46 +7677,43 @@ pub mod PowertrainMux { /// Message name space
     }
 
 
-    /// PowertrainMux::InverterTempDegC public api (CanDbcSignal trait)
-    impl CanDbcSignal for InverterTempDegC {
+/// PowertrainMux::InverterTempDegC public api (CanDbcSignal trait)
+impl CanDbcSignal for InverterTempDegC {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[8..16].load_le::<u8>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[8..16].load_le::<u8>();
 
-                    let value = i8::from_ne_bytes(value.to_ne_bytes());
 
-                    let factor = 1_f64;
-                    let offset = -40_f64;
-                    let newval = (value as f64) * factor + offset;
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -4663,7 +7739,7 @@ pub mod PowertrainMux { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -4716,37 +7792,123 @@ pub mod PowertrainMux { /// Message name space
 
 
     impl DcBusVoltageV  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "V";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 1000.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 0.1;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 16;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 16;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(DcBusVoltageV {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            DcBusVoltageV {
                 status: CanDataStatus::Unset,
                 name:"DcBusVoltageV",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u16) -> f64 {
+            (raw as f64) * 0.1_f64 + 0_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u16 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 16 == 64 { u64::MAX } else { (1u64 << 16) - 1 };
+            let factor = 0.1_f64;
+            let offset = 0_f64;
+            let __raw_f = (value - offset) / factor;
+            ((__raw_f as u64) & __mask) as u16
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < 0_f64 || 1000_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..1000]",value)));
             }
-            let factor = 0.1_f64;
-            let offset = 0_f64;
-            let value = ((value - offset) / factor) as u16;
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u16, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[16..32].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_f64 || 1000_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..1000]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[16..32].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[16..32].load_le::<u16>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message,
+        /// or when the frame multiplexor selects another page than the one of this signal.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux payload len:{} shorter than 8 bytes", data.len())));
+            }
+            let __mux_raw_value = PtMux::raw_from_physical(PtMux::decode(data)) as u64;
+            if __mux_raw_value != 2 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux.DcBusVoltageV is on page 2, frame multiplexor selects {__mux_raw_value}")));
+            }
+            Self::encode(value, data)
+        }
+
     } // PowertrainMux::DcBusVoltageV impl end
 
 
@@ -4768,44 +7930,43 @@ pub mod PowertrainMux { /// Message name space
     }
 
 
-    /// PowertrainMux::DcBusVoltageV public api (CanDbcSignal trait)
-    impl CanDbcSignal for DcBusVoltageV {
+/// PowertrainMux::DcBusVoltageV public api (CanDbcSignal trait)
+impl CanDbcSignal for DcBusVoltageV {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u16 = frame.data.view_bits::<Lsb0>()[16..32].load_le::<u16>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[16..32].load_le::<u16>();
 
-                    let factor = 0.1_f64;
-                    let offset = 0_f64;
-                    let newval = (value as f64) * factor + offset;
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -4831,7 +7992,7 @@ pub mod PowertrainMux { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -4884,38 +8045,127 @@ pub mod PowertrainMux { /// Message name space
 
 
     impl DcBusCurrentA  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "A";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = -1000.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 1000.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 0.1;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 16;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 32;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(DcBusCurrentA {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            DcBusCurrentA {
                 status: CanDataStatus::Unset,
                 name:"DcBusCurrentA",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u16) -> f64 {
+            // Sign-extend raw from the DBC signal bit-width before scaling.
+            let shift = u16::BITS - 16u32;
+            let signed: i16 = ((raw << shift) as i16) >> shift;
+            (signed as f64) * 0.1_f64 + 0_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u16 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 16 == 64 { u64::MAX } else { (1u64 << 16) - 1 };
+            let factor = 0.1_f64;
+            let offset = 0_f64;
+            let __raw_f = (value - offset) / factor;
+            //  Encode signed value as two's complement on 16 bits.
+            ((__raw_f as i64 as u64) & __mask) as u16
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < -1000_f64 || 1000_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [-1000..1000]",value)));
             }
-            let factor = 0.1_f64;
-            let offset = 0_f64;
-            let value = ((value - offset) / factor) as u16;
-            let value = u16::from_ne_bytes(value.to_ne_bytes());
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u16, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[32..48].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < -1000_f64 || 1000_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-1000..1000]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[32..48].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[32..48].load_le::<u16>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message,
+        /// or when the frame multiplexor selects another page than the one of this signal.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux payload len:{} shorter than 8 bytes", data.len())));
+            }
+            let __mux_raw_value = PtMux::raw_from_physical(PtMux::decode(data)) as u64;
+            if __mux_raw_value != 2 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux.DcBusCurrentA is on page 2, frame multiplexor selects {__mux_raw_value}")));
+            }
+            Self::encode(value, data)
+        }
+
     } // PowertrainMux::DcBusCurrentA impl end
 
 
@@ -4937,46 +8187,43 @@ pub mod PowertrainMux { /// Message name space
     }
 
 
-    /// PowertrainMux::DcBusCurrentA public api (CanDbcSignal trait)
-    impl CanDbcSignal for DcBusCurrentA {
+/// PowertrainMux::DcBusCurrentA public api (CanDbcSignal trait)
+impl CanDbcSignal for DcBusCurrentA {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[32..48].load_le::<u16>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u16 = frame.data.view_bits::<Lsb0>()[32..48].load_le::<u16>();
 
-                    let value = i16::from_ne_bytes(value.to_ne_bytes());
 
-                    let factor = 0.1_f64;
-                    let offset = 0_f64;
-                    let newval = (value as f64) * factor + offset;
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -5002,7 +8249,7 @@ pub mod PowertrainMux { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -5055,31 +8302,111 @@ pub mod PowertrainMux { /// Message name space
 
 
     impl RegenEnabled  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 1.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 1;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 8;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(RegenEnabled {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            RegenEnabled {
                 status: CanDataStatus::Unset,
                 name:"RegenEnabled",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> bool {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
-            let value = value as u8;
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> bool {
+            raw == 1
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: bool) -> u8 {
+            value as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[8..9].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[8..9].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> bool {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[8..9].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message,
+        /// or when the frame multiplexor selects another page than the one of this signal.
+        pub fn set_signal_into(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux payload len:{} shorter than 8 bytes", data.len())));
+            }
+            let __mux_raw_value = PtMux::raw_from_physical(PtMux::decode(data)) as u64;
+            if __mux_raw_value != 3 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux.RegenEnabled is on page 3, frame multiplexor selects {__mux_raw_value}")));
+            }
+            Self::encode(value, data)
+        }
+
     } // PowertrainMux::RegenEnabled impl end
 
 
@@ -5101,42 +8428,43 @@ pub mod PowertrainMux { /// Message name space
     }
 
 
-    /// PowertrainMux::RegenEnabled public api (CanDbcSignal trait)
-    impl CanDbcSignal for RegenEnabled {
+/// PowertrainMux::RegenEnabled public api (CanDbcSignal trait)
+impl CanDbcSignal for RegenEnabled {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[8..9].load_le::<u8>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[8..9].load_le::<u8>();
 
-                    let newval = value == 1;
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
                     };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                        self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -5162,7 +8490,7 @@ pub mod PowertrainMux { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -5215,31 +8543,111 @@ pub mod PowertrainMux { /// Message name space
 
 
     impl TorqueLimitActive  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 1.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 1;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 9;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(TorqueLimitActive {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            TorqueLimitActive {
                 status: CanDataStatus::Unset,
                 name:"TorqueLimitActive",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> bool {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
-            let value = value as u8;
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> bool {
+            raw == 1
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: bool) -> u8 {
+            value as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[9..10].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[9..10].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> bool {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[9..10].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message,
+        /// or when the frame multiplexor selects another page than the one of this signal.
+        pub fn set_signal_into(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux payload len:{} shorter than 8 bytes", data.len())));
+            }
+            let __mux_raw_value = PtMux::raw_from_physical(PtMux::decode(data)) as u64;
+            if __mux_raw_value != 3 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux.TorqueLimitActive is on page 3, frame multiplexor selects {__mux_raw_value}")));
+            }
+            Self::encode(value, data)
+        }
+
     } // PowertrainMux::TorqueLimitActive impl end
 
 
@@ -5261,42 +8669,43 @@ pub mod PowertrainMux { /// Message name space
     }
 
 
-    /// PowertrainMux::TorqueLimitActive public api (CanDbcSignal trait)
-    impl CanDbcSignal for TorqueLimitActive {
+/// PowertrainMux::TorqueLimitActive public api (CanDbcSignal trait)
+impl CanDbcSignal for TorqueLimitActive {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[9..10].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[9..10].load_le::<u8>();
 
-                    let newval = value == 1;
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
                     };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                        self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -5322,7 +8731,7 @@ pub mod PowertrainMux { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -5362,6 +8771,13 @@ pub mod PowertrainMux { /// Message name space
     /// - Offset: 0
     /// - Byte order: LittleEndian
     /// - Value type: Unsigned
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | Eco |
+    /// | 1 | Normal |
+    /// | 2 | Sport |
+    /// | 3 | Track |
     #[derive(Serialize, Deserialize)]
     pub struct DriverMode {
         #[serde(skip)]
@@ -5374,6 +8790,14 @@ pub mod PowertrainMux { /// Message name space
     }
 
     // DBC definition for MsgID:200 Signal:driver_mode
+    /// DriverMode values (DBC VAL_)
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | Eco |
+    /// | 1 | Normal |
+    /// | 2 | Sport |
+    /// | 3 | Track |
     #[derive(Serialize, Deserialize)]
     pub enum DbcDriverMode {
         Eco,
@@ -5399,14 +8823,47 @@ pub mod PowertrainMux { /// Message name space
 
 
     impl DriverMode  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 3.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 2;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 10;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(DriverMode {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            DriverMode {
                 status: CanDataStatus::Unset,
                 name:"DriverMode",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
@@ -5422,30 +8879,87 @@ pub mod PowertrainMux { /// Message name space
             }
 
         }
+        #[deprecated(note = "use set_raw()")]
         pub fn set_raw_value(&mut self, value: u8, data: &mut[u8]) {
-            data.view_bits_mut::<Lsb0>()[10..12].store_le(value);
-
+            self.set_raw(value, data)
         }
         pub fn set_as_def (&mut self, signal_def: DbcDriverMode, data: &mut[u8])-> Result<(),CanError> {
             match signal_def {
-                DbcDriverMode::Eco => Ok(self.set_raw_value(0, data)),
-                DbcDriverMode::Normal => Ok(self.set_raw_value(1, data)),
-                DbcDriverMode::Sport => Ok(self.set_raw_value(2, data)),
-                DbcDriverMode::Track => Ok(self.set_raw_value(3, data)),
-                DbcDriverMode::_Other(x) => self.set_typed_value(x,data)
+                DbcDriverMode::Eco => Ok(self.set_raw(0, data)),
+                DbcDriverMode::Normal => Ok(self.set_raw(1, data)),
+                DbcDriverMode::Sport => Ok(self.set_raw(2, data)),
+                DbcDriverMode::Track => Ok(self.set_raw(3, data)),
+                DbcDriverMode::_Other(x) => self.set_physical(x,data)
             }
         }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 2 == 64 { u64::MAX } else { (1u64 << 2) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 3_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..3]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[10..12].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 3_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..3]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[10..12].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[10..12].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message,
+        /// or when the frame multiplexor selects another page than the one of this signal.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux payload len:{} shorter than 8 bytes", data.len())));
+            }
+            let __mux_raw_value = PtMux::raw_from_physical(PtMux::decode(data)) as u64;
+            if __mux_raw_value != 3 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux.DriverMode is on page 3, frame multiplexor selects {__mux_raw_value}")));
+            }
+            Self::encode(value, data)
+        }
+
     } // PowertrainMux::DriverMode impl end
 
 
@@ -5467,35 +8981,37 @@ pub mod PowertrainMux { /// Message name space
     }
 
 
-    /// PowertrainMux::DriverMode public api (CanDbcSignal trait)
-    impl CanDbcSignal for DriverMode {
+/// PowertrainMux::DriverMode public api (CanDbcSignal trait)
+impl CanDbcSignal for DriverMode {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[10..12].load_le::<u8>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[10..12].load_le::<u8>();
 
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -5527,7 +9043,7 @@ pub mod PowertrainMux { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -5562,6 +9078,7 @@ pub mod PowertrainMux { /// Message name space
         name: &'static str,
         status: CanBcmOpCode,
         listeners: i32,
+        signal_listeners: [i32;14],
         stamp: u64,
         id: u32,
     }
@@ -5573,6 +9090,7 @@ pub mod PowertrainMux { /// Message name space
                 name: "PowertrainMux",
                 status: CanBcmOpCode::Unknown,
                 listeners: 0,
+                signal_listeners: [0;14],
                 stamp: 0,
                 callback: None,
                 signals: [
@@ -5595,100 +9113,214 @@ pub mod PowertrainMux { /// Message name space
             })))
         }
 
+        /// Return code of each signal callback (`CanSigCtrl::sig_notification`) on the last
+        /// frame, in `DbcSignal` order: 0 without callback or for an inactive mux page, -1 when
+        /// the callback was busy. `get_listeners()` is their sum.
+        pub fn get_signal_listeners(&self) -> &[i32;14] {
+            &self.signal_listeners
+        }
+
+        /// Return code of the `signal` callback on the last frame, see `get_signal_listeners()`.
+        pub fn get_signal_listener(&self, signal: DbcSignal) -> i32 {
+            self.signal_listeners[signal as usize]
+        }
+
+        /// Index in `get_signals()` of the signal called `name`, its `get_name()` or DBC name.
+        pub fn signal_index(name: &str) -> Option<usize> {
+            match name {
+                "PtMux" | "pt_mux" => Some(0),
+                "AliveCounter" | "alive_counter" => Some(1),
+                "Checksum" | "checksum" => Some(2),
+                "EngineRpm" | "engine_rpm" => Some(3),
+                "ThrottlePosPercent" | "throttle_pos_percent" => Some(4),
+                "FuelRateLph" | "fuel_rate_Lph" => Some(5),
+                "MotorTorqueNm" | "motor_torque_Nm" => Some(6),
+                "MotorSpeedRpm" | "motor_speed_rpm" => Some(7),
+                "InverterTempDegC" | "inverter_temp_degC" => Some(8),
+                "DcBusVoltageV" | "dc_bus_voltage_V" => Some(9),
+                "DcBusCurrentA" | "dc_bus_current_A" => Some(10),
+                "RegenEnabled" | "regen_enabled" => Some(11),
+                "TorqueLimitActive" | "torque_limit_active" => Some(12),
+                "DriverMode" | "driver_mode" => Some(13),
+                _ => None,
+            }
+        }
+
+        /// Signal called `name`, its `get_name()` or DBC name, without scanning the signals.
+        pub fn get_signal(&self, name: &str) -> Option<Rc<RefCell<Box<dyn CanDbcSignal>>>> {
+            Self::signal_index(name).map(|idx| Rc::clone(&self.signals[idx]))
+        }
+
+        /// Unit, range, scaling and layout of the signal called `name`, its `get_name()` or DBC
+        /// name, for generic tools holding a `dyn CanDbcSignal`.
+        pub fn signal_meta(name: &str) -> Option<super::SignalMeta> {
+            match name {
+                "PtMux" | "pt_mux" => Some(PtMux::META),
+                "AliveCounter" | "alive_counter" => Some(AliveCounter::META),
+                "Checksum" | "checksum" => Some(Checksum::META),
+                "EngineRpm" | "engine_rpm" => Some(EngineRpm::META),
+                "ThrottlePosPercent" | "throttle_pos_percent" => Some(ThrottlePosPercent::META),
+                "FuelRateLph" | "fuel_rate_Lph" => Some(FuelRateLph::META),
+                "MotorTorqueNm" | "motor_torque_Nm" => Some(MotorTorqueNm::META),
+                "MotorSpeedRpm" | "motor_speed_rpm" => Some(MotorSpeedRpm::META),
+                "InverterTempDegC" | "inverter_temp_degC" => Some(InverterTempDegC::META),
+                "DcBusVoltageV" | "dc_bus_voltage_V" => Some(DcBusVoltageV::META),
+                "DcBusCurrentA" | "dc_bus_current_A" => Some(DcBusCurrentA::META),
+                "RegenEnabled" | "regen_enabled" => Some(RegenEnabled::META),
+                "TorqueLimitActive" | "torque_limit_active" => Some(TorqueLimitActive::META),
+                "DriverMode" | "driver_mode" => Some(DriverMode::META),
+                _ => None,
+            }
+        }
+
+
+        /// CAN id without the extended flag, 11 or 29 bits
+        pub const CAN_ID: u32 = 0xc8;
+        /// 29-bit id: `get_id()` and the BCM filter carry CAN_EFF_FLAG (bit 31), like Linux can_id
+        pub const EXTENDED: bool = false;
+
+        pub fn is_extended(&self) -> bool {
+            Self::EXTENDED
+        }
+
+        /// Transmission period from the GenMsgCycleTime attribute (or its default), 0 for a
+        /// message sent on event or without attribute
+        pub const CYCLE_TIME_MS: u32 = 20;
+
+        /// `CYCLE_TIME_MS`, base of the BCM rate and watchdog timers of the message
+        pub fn get_cycle_time_ms(&self) -> u32 {
+            Self::CYCLE_TIME_MS
+        }
+
+        /// Attributes (BA_) set on the message, by DBC attribute name
+        pub const ATTRIBUTES: [(&'static str, super::attributes::AttributeValue); 1] = [("GenMsgCycleTime", super::attributes::AttributeValue::Int(20))];
+
+        /// Value of the message attribute `name`, its BA_DEF_DEF_ default when not set
+        pub fn get_attribute(name: &str) -> Option<super::attributes::AttributeValue> {
+            match name {
+                "GenMsgCycleTime" => Some(super::attributes::AttributeValue::Int(20)),
+                "GenMsgSendType" => super::attributes::GEN_MSG_SEND_TYPE.default(),
+                _ => None,
+            }
+        }
 
         pub fn set_values(&mut self, pt_mux: u8, alive_counter: u8, checksum: u8, engine_rpm: f64, throttle_pos_percent: f64, fuel_rate_lph: f64, motor_torque_nm: f64, motor_speed_rpm: i16, inverter_temp_deg_c: f64, dc_bus_voltage_v: f64, dc_bus_current_a: f64, regen_enabled: bool, torque_limit_active: bool, driver_mode: u8, frame: &mut[u8]) -> Result<&mut Self, CanError> {
 
 
-            let __mux_raw_value: u64 = pt_mux as u64;
-            println!("RLM __mux_raw_value{}",__mux_raw_value);
+            let __mux_raw_value: u64 = {
+            let __mask: u64 = if 4 == 64 { u64::MAX } else { (1u64 << 4) - 1 };
+            ((pt_mux as u64) & __mask)
+    };
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(pt_mux), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error pt_mux:U8")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(alive_counter), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error alive_counter:U8")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(checksum), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error checksum:U8")),
             }
 
             if __mux_raw_value == 0 {
-                match Rc::clone (&self.signals[3]).try_borrow_mut() {
+                
+
+                match Rc::clone(&self.signals[3]).try_borrow_mut() {
                     Ok(mut signal) => signal.set_value(CanDbcType::F64(engine_rpm), frame)?,
                     Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error engine_rpm:F64")),
                 }
             }
 
             if __mux_raw_value == 0 {
-                match Rc::clone (&self.signals[4]).try_borrow_mut() {
+                
+
+                match Rc::clone(&self.signals[4]).try_borrow_mut() {
                     Ok(mut signal) => signal.set_value(CanDbcType::F64(throttle_pos_percent), frame)?,
                     Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error throttle_pos_percent:F64")),
                 }
             }
 
             if __mux_raw_value == 0 {
-                match Rc::clone (&self.signals[5]).try_borrow_mut() {
+                
+
+                match Rc::clone(&self.signals[5]).try_borrow_mut() {
                     Ok(mut signal) => signal.set_value(CanDbcType::F64(fuel_rate_lph), frame)?,
                     Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error fuel_rate_lph:F64")),
                 }
             }
 
             if __mux_raw_value == 1 {
-                match Rc::clone (&self.signals[6]).try_borrow_mut() {
+                
+
+                match Rc::clone(&self.signals[6]).try_borrow_mut() {
                     Ok(mut signal) => signal.set_value(CanDbcType::F64(motor_torque_nm), frame)?,
                     Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error motor_torque_nm:F64")),
                 }
             }
 
             if __mux_raw_value == 1 {
-                match Rc::clone (&self.signals[7]).try_borrow_mut() {
+                
+
+                match Rc::clone(&self.signals[7]).try_borrow_mut() {
                     Ok(mut signal) => signal.set_value(CanDbcType::I16(motor_speed_rpm), frame)?,
                     Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error motor_speed_rpm:I16")),
                 }
             }
 
             if __mux_raw_value == 2 {
-                match Rc::clone (&self.signals[8]).try_borrow_mut() {
+                
+
+                match Rc::clone(&self.signals[8]).try_borrow_mut() {
                     Ok(mut signal) => signal.set_value(CanDbcType::F64(inverter_temp_deg_c), frame)?,
                     Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error inverter_temp_deg_c:F64")),
                 }
             }
 
             if __mux_raw_value == 2 {
-                match Rc::clone (&self.signals[9]).try_borrow_mut() {
+                
+
+                match Rc::clone(&self.signals[9]).try_borrow_mut() {
                     Ok(mut signal) => signal.set_value(CanDbcType::F64(dc_bus_voltage_v), frame)?,
                     Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error dc_bus_voltage_v:F64")),
                 }
             }
 
             if __mux_raw_value == 2 {
-                match Rc::clone (&self.signals[10]).try_borrow_mut() {
+                
+
+                match Rc::clone(&self.signals[10]).try_borrow_mut() {
                     Ok(mut signal) => signal.set_value(CanDbcType::F64(dc_bus_current_a), frame)?,
                     Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error dc_bus_current_a:F64")),
                 }
             }
 
             if __mux_raw_value == 3 {
-                match Rc::clone (&self.signals[11]).try_borrow_mut() {
+                
+
+                match Rc::clone(&self.signals[11]).try_borrow_mut() {
                     Ok(mut signal) => signal.set_value(CanDbcType::Bool(regen_enabled), frame)?,
                     Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error regen_enabled:Bool")),
                 }
             }
 
             if __mux_raw_value == 3 {
-                match Rc::clone (&self.signals[12]).try_borrow_mut() {
+                
+
+                match Rc::clone(&self.signals[12]).try_borrow_mut() {
                     Ok(mut signal) => signal.set_value(CanDbcType::Bool(torque_limit_active), frame)?,
                     Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error torque_limit_active:Bool")),
                 }
             }
 
             if __mux_raw_value == 3 {
-                match Rc::clone (&self.signals[13]).try_borrow_mut() {
+                
+
+                match Rc::clone(&self.signals[13]).try_borrow_mut() {
                     Ok(mut signal) => signal.set_value(CanDbcType::U8(driver_mode), frame)?,
                     Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error driver_mode:U8")),
                 }
@@ -5699,77 +9331,483 @@ pub mod PowertrainMux { /// Message name space
     }
 
 
+    /// `PowertrainMux(0x..) <opcode> stamp:<us> Signal:value ...`, for logs
+    impl fmt::Display for DbcMessage {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(fmt, "PowertrainMux({:#x}) {:?} stamp:{}", self.id, self.status, self.stamp)?;
+            match self.signals[0].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " PtMux:{value}")?,
+                None => write!(fmt, " PtMux:?")?,
+            }
+            match self.signals[1].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " AliveCounter:{value}")?,
+                None => write!(fmt, " AliveCounter:?")?,
+            }
+            match self.signals[2].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " Checksum:{value}")?,
+                None => write!(fmt, " Checksum:?")?,
+            }
+            match self.signals[3].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " EngineRpm:{value}")?,
+                None => write!(fmt, " EngineRpm:?")?,
+            }
+            match self.signals[4].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " ThrottlePosPercent:{value}")?,
+                None => write!(fmt, " ThrottlePosPercent:?")?,
+            }
+            match self.signals[5].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " FuelRateLph:{value}")?,
+                None => write!(fmt, " FuelRateLph:?")?,
+            }
+            match self.signals[6].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " MotorTorqueNm:{value}")?,
+                None => write!(fmt, " MotorTorqueNm:?")?,
+            }
+            match self.signals[7].try_borrow().ok().and_then(|signal| signal.get_value().cast::<i16>().ok()) {
+                Some(value) => write!(fmt, " MotorSpeedRpm:{value}")?,
+                None => write!(fmt, " MotorSpeedRpm:?")?,
+            }
+            match self.signals[8].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " InverterTempDegC:{value}")?,
+                None => write!(fmt, " InverterTempDegC:?")?,
+            }
+            match self.signals[9].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " DcBusVoltageV:{value}")?,
+                None => write!(fmt, " DcBusVoltageV:?")?,
+            }
+            match self.signals[10].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " DcBusCurrentA:{value}")?,
+                None => write!(fmt, " DcBusCurrentA:?")?,
+            }
+            match self.signals[11].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()) {
+                Some(value) => write!(fmt, " RegenEnabled:{value}")?,
+                None => write!(fmt, " RegenEnabled:?")?,
+            }
+            match self.signals[12].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()) {
+                Some(value) => write!(fmt, " TorqueLimitActive:{value}")?,
+                None => write!(fmt, " TorqueLimitActive:?")?,
+            }
+            match self.signals[13].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " DriverMode:{value}")?,
+                None => write!(fmt, " DriverMode:?")?,
+            }
+            Ok(())
+        }
+    }
+
+    impl fmt::Debug for DbcMessage {
+        fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {
+            format.debug_struct("PowertrainMux")
+                .field("id", &self.id)
+                .field("status", &self.status)
+                .field("stamp", &self.stamp)
+                .field("pt_mux", &self.signals[0].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("alive_counter", &self.signals[1].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("checksum", &self.signals[2].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("engine_rpm", &self.signals[3].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .field("throttle_pos_percent", &self.signals[4].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .field("fuel_rate_lph", &self.signals[5].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .field("motor_torque_nm", &self.signals[6].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .field("motor_speed_rpm", &self.signals[7].try_borrow().ok().and_then(|signal| signal.get_value().cast::<i16>().ok()))
+                .field("inverter_temp_deg_c", &self.signals[8].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .field("dc_bus_voltage_v", &self.signals[9].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .field("dc_bus_current_a", &self.signals[10].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .field("regen_enabled", &self.signals[11].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()))
+                .field("torque_limit_active", &self.signals[12].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()))
+                .field("driver_mode", &self.signals[13].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .finish()
+        }
+    }
+
+    impl Serialize for DbcMessage {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::MessageState(self).serialize(serializer)
+        }
+    }
+
+
+    /// Pages of the PowertrainMux multiplexor pt_mux, one per selector value with multiplexed
+    /// signals, see `DbcMessage::get_active_group()`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum MuxGroup {
+        M0,
+        M1,
+        M2,
+        M3,
+    }
+
+    impl MuxGroup {
+        /// Multiplexor value selecting this page
+        pub fn selector(self) -> u64 {
+            match self {
+                MuxGroup::M0 => 0,
+                MuxGroup::M1 => 1,
+                MuxGroup::M2 => 2,
+                MuxGroup::M3 => 3,
+            }
+        }
+
+        /// Page selected by the multiplexor `value`, `None` when no signal is multiplexed on it
+        pub fn from_selector(value: u64) -> Option<Self> {
+            match value {
+                0 => Some(MuxGroup::M0),
+                1 => Some(MuxGroup::M1),
+                2 => Some(MuxGroup::M2),
+                3 => Some(MuxGroup::M3),
+                _ => None,
+            }
+        }
+    }
+
+    impl DbcMessage {
+        /// Page selected by the multiplexor of the last decoded or encoded values, `None` for a
+        /// selector value without multiplexed signals
+        pub fn get_active_group(&self) -> Option<MuxGroup> {
+            MuxGroup::from_selector(self.signals[0].try_borrow().ok()?.get_value().cast::<u8>().ok()? as u64)
+        }
+    }
+
+
+    /// Values of every PowertrainMux signal, see `DbcMessage::snapshot()`
+    #[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+    pub struct SignalValues {
+        pub pt_mux: u8,
+        pub alive_counter: u8,
+        pub checksum: u8,
+        pub engine_rpm: f64,
+        pub throttle_pos_percent: f64,
+        pub fuel_rate_lph: f64,
+        pub motor_torque_nm: f64,
+        pub motor_speed_rpm: i16,
+        pub inverter_temp_deg_c: f64,
+        pub dc_bus_voltage_v: f64,
+        pub dc_bus_current_a: f64,
+        pub regen_enabled: bool,
+        pub torque_limit_active: bool,
+        pub driver_mode: u8,
+    }
+
+    /// Copy of a PowertrainMux message taken at a point in time, holding no RefCell borrow
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub struct MessageSnapshot {
+        pub stamp: u64,
+        pub status: CanBcmOpCode,
+        pub values: SignalValues,
+    }
+
+    impl DbcMessage {
+        /// Copy the stamp, status and every signal value of the last decoded frame.
+        pub fn snapshot(&self) -> Result<MessageSnapshot, CanError> {
+            Ok(MessageSnapshot {
+                stamp: self.stamp,
+                status: self.status,
+                values: SignalValues {
+                    pt_mux: match self.signals[0].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error pt_mux")),
+                    },
+                    alive_counter: match self.signals[1].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error alive_counter")),
+                    },
+                    checksum: match self.signals[2].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error checksum")),
+                    },
+                    engine_rpm: match self.signals[3].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error engine_rpm")),
+                    },
+                    throttle_pos_percent: match self.signals[4].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error throttle_pos_percent")),
+                    },
+                    fuel_rate_lph: match self.signals[5].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error fuel_rate_lph")),
+                    },
+                    motor_torque_nm: match self.signals[6].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error motor_torque_nm")),
+                    },
+                    motor_speed_rpm: match self.signals[7].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error motor_speed_rpm")),
+                    },
+                    inverter_temp_deg_c: match self.signals[8].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error inverter_temp_deg_c")),
+                    },
+                    dc_bus_voltage_v: match self.signals[9].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error dc_bus_voltage_v")),
+                    },
+                    dc_bus_current_a: match self.signals[10].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error dc_bus_current_a")),
+                    },
+                    regen_enabled: match self.signals[11].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error regen_enabled")),
+                    },
+                    torque_limit_active: match self.signals[12].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error torque_limit_active")),
+                    },
+                    driver_mode: match self.signals[13].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error driver_mode")),
+                    },
+                },
+            })
+        }
+    }
+
+    /// Decode a PowertrainMux payload into its values, without signal object nor pool.
+    /// Signals of an inactive multiplexed page read as their default value.
+    pub fn decode(data: &[u8]) -> Result<SignalValues, CanError> {
+        if data.len() < 8 {
+            return Err(CanError::new("signal-update-fail", format!("PowertrainMux payload len:{} shorter than 8 bytes", data.len())));
+        }
+
+            let __mux_raw_value: u64 = (data.view_bits::<Lsb0>()[0..4].load_le::<u8>()) as u64;
+        Ok(SignalValues {
+            pt_mux: PtMux::decode(data),
+            alive_counter: AliveCounter::decode(data),
+            checksum: Checksum::decode(data),
+            engine_rpm: if __mux_raw_value == 0 { EngineRpm::decode(data) } else { Default::default() },
+            throttle_pos_percent: if __mux_raw_value == 0 { ThrottlePosPercent::decode(data) } else { Default::default() },
+            fuel_rate_lph: if __mux_raw_value == 0 { FuelRateLph::decode(data) } else { Default::default() },
+            motor_torque_nm: if __mux_raw_value == 1 { MotorTorqueNm::decode(data) } else { Default::default() },
+            motor_speed_rpm: if __mux_raw_value == 1 { MotorSpeedRpm::decode(data) } else { Default::default() },
+            inverter_temp_deg_c: if __mux_raw_value == 2 { InverterTempDegC::decode(data) } else { Default::default() },
+            dc_bus_voltage_v: if __mux_raw_value == 2 { DcBusVoltageV::decode(data) } else { Default::default() },
+            dc_bus_current_a: if __mux_raw_value == 2 { DcBusCurrentA::decode(data) } else { Default::default() },
+            regen_enabled: if __mux_raw_value == 3 { RegenEnabled::decode(data) } else { Default::default() },
+            torque_limit_active: if __mux_raw_value == 3 { TorqueLimitActive::decode(data) } else { Default::default() },
+            driver_mode: if __mux_raw_value == 3 { DriverMode::decode(data) } else { Default::default() },
+        })
+    }
+
+    /// Encode `values` into a PowertrainMux payload, without signal object nor pool.
+    /// Only the multiplexed page selected by the multiplexor value is written.
+    pub fn encode(values: &SignalValues, data: &mut [u8]) -> Result<(), CanError> {
+        if data.len() < 8 {
+            return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux payload len:{} shorter than 8 bytes", data.len())));
+        }
+        let __mux_raw_value = PtMux::raw_from_physical(values.pt_mux) as u64;
+        PtMux::encode(values.pt_mux, data)?;
+        AliveCounter::encode(values.alive_counter, data)?;
+        Checksum::encode(values.checksum, data)?;
+        if __mux_raw_value == 0 { EngineRpm::encode(values.engine_rpm, data)?; }
+        if __mux_raw_value == 0 { ThrottlePosPercent::encode(values.throttle_pos_percent, data)?; }
+        if __mux_raw_value == 0 { FuelRateLph::encode(values.fuel_rate_lph, data)?; }
+        if __mux_raw_value == 1 { MotorTorqueNm::encode(values.motor_torque_nm, data)?; }
+        if __mux_raw_value == 1 { MotorSpeedRpm::encode(values.motor_speed_rpm, data)?; }
+        if __mux_raw_value == 2 { InverterTempDegC::encode(values.inverter_temp_deg_c, data)?; }
+        if __mux_raw_value == 2 { DcBusVoltageV::encode(values.dc_bus_voltage_v, data)?; }
+        if __mux_raw_value == 2 { DcBusCurrentA::encode(values.dc_bus_current_a, data)?; }
+        if __mux_raw_value == 3 { RegenEnabled::encode(values.regen_enabled, data)?; }
+        if __mux_raw_value == 3 { TorqueLimitActive::encode(values.torque_limit_active, data)?; }
+        if __mux_raw_value == 3 { DriverMode::encode(values.driver_mode, data)?; }
+        Ok(())
+    }
+
+    /// Typed builder of a PowertrainMux payload: `PowertrainMux::builder().signal(value).build(&mut data)?`.
+    /// Signals without setter call keep their GenSigStartValue, or their default.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct MessageBuilder {
+        values: SignalValues,
+    }
+
+    /// Start a PowertrainMux payload, see `MessageBuilder`.
+    pub fn builder() -> MessageBuilder {
+        MessageBuilder {
+            values: SignalValues::default(),
+        }
+    }
+
+    impl MessageBuilder {
+        /// PtMux physical value
+        #[must_use]
+        pub fn pt_mux(mut self, value: u8) -> Self {
+            self.values.pt_mux = value;
+            self
+        }
+
+        /// AliveCounter physical value
+        #[must_use]
+        pub fn alive_counter(mut self, value: u8) -> Self {
+            self.values.alive_counter = value;
+            self
+        }
+
+        /// Checksum physical value
+        #[must_use]
+        pub fn checksum(mut self, value: u8) -> Self {
+            self.values.checksum = value;
+            self
+        }
+
+        /// EngineRpm physical value in rpm
+        #[must_use]
+        pub fn engine_rpm(mut self, value: f64) -> Self {
+            self.values.engine_rpm = value;
+            self
+        }
+
+        /// ThrottlePosPercent physical value in %
+        #[must_use]
+        pub fn throttle_pos_percent(mut self, value: f64) -> Self {
+            self.values.throttle_pos_percent = value;
+            self
+        }
+
+        /// FuelRateLph physical value in L/h
+        #[must_use]
+        pub fn fuel_rate_lph(mut self, value: f64) -> Self {
+            self.values.fuel_rate_lph = value;
+            self
+        }
+
+        /// MotorTorqueNm physical value in Nm
+        #[must_use]
+        pub fn motor_torque_nm(mut self, value: f64) -> Self {
+            self.values.motor_torque_nm = value;
+            self
+        }
+
+        /// MotorSpeedRpm physical value in rpm
+        #[must_use]
+        pub fn motor_speed_rpm(mut self, value: i16) -> Self {
+            self.values.motor_speed_rpm = value;
+            self
+        }
+
+        /// InverterTempDegC physical value in degC
+        #[must_use]
+        pub fn inverter_temp_deg_c(mut self, value: f64) -> Self {
+            self.values.inverter_temp_deg_c = value;
+            self
+        }
+
+        /// DcBusVoltageV physical value in V
+        #[must_use]
+        pub fn dc_bus_voltage_v(mut self, value: f64) -> Self {
+            self.values.dc_bus_voltage_v = value;
+            self
+        }
+
+        /// DcBusCurrentA physical value in A
+        #[must_use]
+        pub fn dc_bus_current_a(mut self, value: f64) -> Self {
+            self.values.dc_bus_current_a = value;
+            self
+        }
+
+        /// RegenEnabled physical value
+        #[must_use]
+        pub fn regen_enabled(mut self, value: bool) -> Self {
+            self.values.regen_enabled = value;
+            self
+        }
+
+        /// TorqueLimitActive physical value
+        #[must_use]
+        pub fn torque_limit_active(mut self, value: bool) -> Self {
+            self.values.torque_limit_active = value;
+            self
+        }
+
+        /// DriverMode physical value
+        #[must_use]
+        pub fn driver_mode(mut self, value: u8) -> Self {
+            self.values.driver_mode = value;
+            self
+        }
+
+        /// Values `build()` encodes.
+        pub fn get_values(&self) -> &SignalValues {
+            &self.values
+        }
+
+        /// Encode the values into `data`, see `encode()`.
+        pub fn build(&self, data: &mut [u8]) -> Result<(), CanError> {
+            encode(&self.values, data)
+        }
+    }
+
     impl CanDbcMessage for DbcMessage {
         fn reset(&mut self) -> Result<(), CanError> {
             self.status=CanBcmOpCode::Unknown;
             self.stamp=0;
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error pt_mux:U8")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error alive_counter:U8")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error checksum:U8")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error engine_rpm:F64")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error throttle_pos_percent:F64")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error fuel_rate_lph:F64")),
             }
 
-            match Rc::clone (&self.signals[6]).try_borrow_mut() {
+            match Rc::clone(&self.signals[6]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error motor_torque_nm:F64")),
             }
 
-            match Rc::clone (&self.signals[7]).try_borrow_mut() {
+            match Rc::clone(&self.signals[7]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error motor_speed_rpm:I16")),
             }
 
-            match Rc::clone (&self.signals[8]).try_borrow_mut() {
+            match Rc::clone(&self.signals[8]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error inverter_temp_deg_c:F64")),
             }
 
-            match Rc::clone (&self.signals[9]).try_borrow_mut() {
+            match Rc::clone(&self.signals[9]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error dc_bus_voltage_v:F64")),
             }
 
-            match Rc::clone (&self.signals[10]).try_borrow_mut() {
+            match Rc::clone(&self.signals[10]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error dc_bus_current_a:F64")),
             }
 
-            match Rc::clone (&self.signals[11]).try_borrow_mut() {
+            match Rc::clone(&self.signals[11]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error regen_enabled:Bool")),
             }
 
-            match Rc::clone (&self.signals[12]).try_borrow_mut() {
+            match Rc::clone(&self.signals[12]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error torque_limit_active:Bool")),
             }
 
-            match Rc::clone (&self.signals[13]).try_borrow_mut() {
+            match Rc::clone(&self.signals[13]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error driver_mode:U8")),
             }
@@ -5781,151 +9819,196 @@ pub mod PowertrainMux { /// Message name space
             self.stamp= frame.stamp;
             self.status= frame.opcode;
             self.listeners= 0;
+            self.signal_listeners= [0;14];
 
             let __mux_raw_value: u64 = (frame.data.view_bits::<Lsb0>()[0..4].load_le::<u8>()) as u64;
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[0] = signal.update(frame); self.listeners += self.signal_listeners[0]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error pt_mux:U8")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[1] = signal.update(frame); self.listeners += self.signal_listeners[1]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error alive_counter:U8")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[2] = signal.update(frame); self.listeners += self.signal_listeners[2]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error checksum:U8")),
             }
 
             if __mux_raw_value == 0 {
-                match Rc::clone (&self.signals[3]).try_borrow_mut() {
-                    Ok(mut signal) => self.listeners += signal.update(frame),
+                
+
+                match Rc::clone(&self.signals[3]).try_borrow_mut() {
+                    Ok(mut signal) => { self.signal_listeners[3] = signal.update(frame); self.listeners += self.signal_listeners[3]; },
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error engine_rpm:F64")),
                 }
+
             } else {
-                match Rc::clone (&self.signals[3]).try_borrow_mut() {
+
+                match Rc::clone(&self.signals[3]).try_borrow_mut() {
                     Ok(mut signal) => signal.reset(),
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error engine_rpm:F64")),
                 }
             }
 
             if __mux_raw_value == 0 {
-                match Rc::clone (&self.signals[4]).try_borrow_mut() {
-                    Ok(mut signal) => self.listeners += signal.update(frame),
+                
+
+                match Rc::clone(&self.signals[4]).try_borrow_mut() {
+                    Ok(mut signal) => { self.signal_listeners[4] = signal.update(frame); self.listeners += self.signal_listeners[4]; },
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error throttle_pos_percent:F64")),
                 }
+
             } else {
-                match Rc::clone (&self.signals[4]).try_borrow_mut() {
+
+                match Rc::clone(&self.signals[4]).try_borrow_mut() {
                     Ok(mut signal) => signal.reset(),
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error throttle_pos_percent:F64")),
                 }
             }
 
             if __mux_raw_value == 0 {
-                match Rc::clone (&self.signals[5]).try_borrow_mut() {
-                    Ok(mut signal) => self.listeners += signal.update(frame),
+                
+
+                match Rc::clone(&self.signals[5]).try_borrow_mut() {
+                    Ok(mut signal) => { self.signal_listeners[5] = signal.update(frame); self.listeners += self.signal_listeners[5]; },
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error fuel_rate_lph:F64")),
                 }
+
             } else {
-                match Rc::clone (&self.signals[5]).try_borrow_mut() {
+
+                match Rc::clone(&self.signals[5]).try_borrow_mut() {
                     Ok(mut signal) => signal.reset(),
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error fuel_rate_lph:F64")),
                 }
             }
 
             if __mux_raw_value == 1 {
-                match Rc::clone (&self.signals[6]).try_borrow_mut() {
-                    Ok(mut signal) => self.listeners += signal.update(frame),
+                
+
+                match Rc::clone(&self.signals[6]).try_borrow_mut() {
+                    Ok(mut signal) => { self.signal_listeners[6] = signal.update(frame); self.listeners += self.signal_listeners[6]; },
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error motor_torque_nm:F64")),
                 }
+
             } else {
-                match Rc::clone (&self.signals[6]).try_borrow_mut() {
+
+                match Rc::clone(&self.signals[6]).try_borrow_mut() {
                     Ok(mut signal) => signal.reset(),
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error motor_torque_nm:F64")),
                 }
             }
 
             if __mux_raw_value == 1 {
-                match Rc::clone (&self.signals[7]).try_borrow_mut() {
-                    Ok(mut signal) => self.listeners += signal.update(frame),
+                
+
+                match Rc::clone(&self.signals[7]).try_borrow_mut() {
+                    Ok(mut signal) => { self.signal_listeners[7] = signal.update(frame); self.listeners += self.signal_listeners[7]; },
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error motor_speed_rpm:I16")),
                 }
+
             } else {
-                match Rc::clone (&self.signals[7]).try_borrow_mut() {
+
+                match Rc::clone(&self.signals[7]).try_borrow_mut() {
                     Ok(mut signal) => signal.reset(),
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error motor_speed_rpm:I16")),
                 }
             }
 
             if __mux_raw_value == 2 {
-                match Rc::clone (&self.signals[8]).try_borrow_mut() {
-                    Ok(mut signal) => self.listeners += signal.update(frame),
+                
+
+                match Rc::clone(&self.signals[8]).try_borrow_mut() {
+                    Ok(mut signal) => { self.signal_listeners[8] = signal.update(frame); self.listeners += self.signal_listeners[8]; },
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error inverter_temp_deg_c:F64")),
                 }
+
             } else {
-                match Rc::clone (&self.signals[8]).try_borrow_mut() {
+
+                match Rc::clone(&self.signals[8]).try_borrow_mut() {
                     Ok(mut signal) => signal.reset(),
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error inverter_temp_deg_c:F64")),
                 }
             }
 
             if __mux_raw_value == 2 {
-                match Rc::clone (&self.signals[9]).try_borrow_mut() {
-                    Ok(mut signal) => self.listeners += signal.update(frame),
+                
+
+                match Rc::clone(&self.signals[9]).try_borrow_mut() {
+                    Ok(mut signal) => { self.signal_listeners[9] = signal.update(frame); self.listeners += self.signal_listeners[9]; },
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error dc_bus_voltage_v:F64")),
                 }
+
             } else {
-                match Rc::clone (&self.signals[9]).try_borrow_mut() {
+
+                match Rc::clone(&self.signals[9]).try_borrow_mut() {
                     Ok(mut signal) => signal.reset(),
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error dc_bus_voltage_v:F64")),
                 }
             }
 
             if __mux_raw_value == 2 {
-                match Rc::clone (&self.signals[10]).try_borrow_mut() {
-                    Ok(mut signal) => self.listeners += signal.update(frame),
+                
+
+                match Rc::clone(&self.signals[10]).try_borrow_mut() {
+                    Ok(mut signal) => { self.signal_listeners[10] = signal.update(frame); self.listeners += self.signal_listeners[10]; },
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error dc_bus_current_a:F64")),
                 }
+
             } else {
-                match Rc::clone (&self.signals[10]).try_borrow_mut() {
+
+                match Rc::clone(&self.signals[10]).try_borrow_mut() {
                     Ok(mut signal) => signal.reset(),
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error dc_bus_current_a:F64")),
                 }
             }
 
             if __mux_raw_value == 3 {
-                match Rc::clone (&self.signals[11]).try_borrow_mut() {
-                    Ok(mut signal) => self.listeners += signal.update(frame),
+                
+
+                match Rc::clone(&self.signals[11]).try_borrow_mut() {
+                    Ok(mut signal) => { self.signal_listeners[11] = signal.update(frame); self.listeners += self.signal_listeners[11]; },
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error regen_enabled:Bool")),
                 }
+
             } else {
-                match Rc::clone (&self.signals[11]).try_borrow_mut() {
+
+                match Rc::clone(&self.signals[11]).try_borrow_mut() {
                     Ok(mut signal) => signal.reset(),
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error regen_enabled:Bool")),
                 }
             }
 
             if __mux_raw_value == 3 {
-                match Rc::clone (&self.signals[12]).try_borrow_mut() {
-                    Ok(mut signal) => self.listeners += signal.update(frame),
+                
+
+                match Rc::clone(&self.signals[12]).try_borrow_mut() {
+                    Ok(mut signal) => { self.signal_listeners[12] = signal.update(frame); self.listeners += self.signal_listeners[12]; },
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error torque_limit_active:Bool")),
                 }
+
             } else {
-                match Rc::clone (&self.signals[12]).try_borrow_mut() {
+
+                match Rc::clone(&self.signals[12]).try_borrow_mut() {
                     Ok(mut signal) => signal.reset(),
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error torque_limit_active:Bool")),
                 }
             }
 
             if __mux_raw_value == 3 {
-                match Rc::clone (&self.signals[13]).try_borrow_mut() {
-                    Ok(mut signal) => self.listeners += signal.update(frame),
+                
+
+                match Rc::clone(&self.signals[13]).try_borrow_mut() {
+                    Ok(mut signal) => { self.signal_listeners[13] = signal.update(frame); self.listeners += self.signal_listeners[13]; },
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error driver_mode:U8")),
                 }
+
             } else {
-                match Rc::clone (&self.signals[13]).try_borrow_mut() {
+
+                match Rc::clone(&self.signals[13]).try_borrow_mut() {
                     Ok(mut signal) => signal.reset(),
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error driver_mode:U8")),
                 }
@@ -5935,7 +10018,7 @@ pub mod PowertrainMux { /// Message name space
                 None => {},
                 Some(callback) => {
                     match callback.try_borrow() {
-                        Err(_) => println!("fail to get message callback reference"),
+                        Err(_) => {println!("fail to get message callback reference");},
                         Ok(cb_ref) => cb_ref.msg_notification(self),
                     }
                 }
@@ -5995,6 +10078,9 @@ pub mod ChassisControl { /// Message name space
     use std::fmt;
 
     use serde::{Deserialize, Serialize};
+    /// Nodes transmitting this message (BO_ and BO_TX_BU_)
+    pub const TRANSMITTERS: [&str; 1] = ["CHASSIS_CTRL"];
+
     pub enum DbcSignal {
         AbsActive,
         EscActive,
@@ -6030,31 +10116,106 @@ pub mod ChassisControl { /// Message name space
 
 
     impl AbsActive  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 1.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 1;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 0;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(AbsActive {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            AbsActive {
                 status: CanDataStatus::Unset,
                 name:"AbsActive",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> bool {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
-            let value = value as u8;
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> bool {
+            raw == 1
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: bool) -> u8 {
+            value as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[0..1].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[0..1].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> bool {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[0..1].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("ChassisControl payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // ChassisControl::AbsActive impl end
 
 
@@ -6076,42 +10237,43 @@ pub mod ChassisControl { /// Message name space
     }
 
 
-    /// ChassisControl::AbsActive public api (CanDbcSignal trait)
-    impl CanDbcSignal for AbsActive {
+/// ChassisControl::AbsActive public api (CanDbcSignal trait)
+impl CanDbcSignal for AbsActive {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[0..1].load_le::<u8>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[0..1].load_le::<u8>();
 
-                    let newval = value == 1;
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
                     };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                        self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -6137,7 +10299,7 @@ pub mod ChassisControl { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -6190,31 +10352,106 @@ pub mod ChassisControl { /// Message name space
 
 
     impl EscActive  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 1.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 1;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 1;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(EscActive {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            EscActive {
                 status: CanDataStatus::Unset,
                 name:"EscActive",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> bool {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
-            let value = value as u8;
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> bool {
+            raw == 1
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: bool) -> u8 {
+            value as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[1..2].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[1..2].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> bool {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[1..2].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("ChassisControl payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // ChassisControl::EscActive impl end
 
 
@@ -6236,42 +10473,43 @@ pub mod ChassisControl { /// Message name space
     }
 
 
-    /// ChassisControl::EscActive public api (CanDbcSignal trait)
-    impl CanDbcSignal for EscActive {
+/// ChassisControl::EscActive public api (CanDbcSignal trait)
+impl CanDbcSignal for EscActive {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[1..2].load_le::<u8>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[1..2].load_le::<u8>();
 
-                    let newval = value == 1;
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
                     };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                        self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -6297,7 +10535,7 @@ pub mod ChassisControl { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -6350,37 +10588,118 @@ pub mod ChassisControl { /// Message name space
 
 
     impl BrakePressureBar  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "bar";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 300.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 0.1;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 16;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 8;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(BrakePressureBar {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            BrakePressureBar {
                 status: CanDataStatus::Unset,
                 name:"BrakePressureBar",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u16) -> f64 {
+            (raw as f64) * 0.1_f64 + 0_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u16 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 16 == 64 { u64::MAX } else { (1u64 << 16) - 1 };
+            let factor = 0.1_f64;
+            let offset = 0_f64;
+            let __raw_f = (value - offset) / factor;
+            ((__raw_f as u64) & __mask) as u16
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < 0_f64 || 300_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..300]",value)));
             }
-            let factor = 0.1_f64;
-            let offset = 0_f64;
-            let value = ((value - offset) / factor) as u16;
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u16, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[8..24].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_f64 || 300_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..300]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[8..24].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[8..24].load_le::<u16>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("ChassisControl payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // ChassisControl::BrakePressureBar impl end
 
 
@@ -6402,44 +10721,43 @@ pub mod ChassisControl { /// Message name space
     }
 
 
-    /// ChassisControl::BrakePressureBar public api (CanDbcSignal trait)
-    impl CanDbcSignal for BrakePressureBar {
+/// ChassisControl::BrakePressureBar public api (CanDbcSignal trait)
+impl CanDbcSignal for BrakePressureBar {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[8..24].load_le::<u16>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u16 = frame.data.view_bits::<Lsb0>()[8..24].load_le::<u16>();
 
-                    let factor = 0.1_f64;
-                    let offset = 0_f64;
-                    let newval = (value as f64) * factor + offset;
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -6465,7 +10783,7 @@ pub mod ChassisControl { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -6518,38 +10836,122 @@ pub mod ChassisControl { /// Message name space
 
 
     impl YawRateDps  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "deg/s";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = -500.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 500.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 0.01;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 16;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 24;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(YawRateDps {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            YawRateDps {
                 status: CanDataStatus::Unset,
                 name:"YawRateDps",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u16) -> f64 {
+            // Sign-extend raw from the DBC signal bit-width before scaling.
+            let shift = u16::BITS - 16u32;
+            let signed: i16 = ((raw << shift) as i16) >> shift;
+            (signed as f64) * 0.01_f64 + 0_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u16 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 16 == 64 { u64::MAX } else { (1u64 << 16) - 1 };
+            let factor = 0.01_f64;
+            let offset = 0_f64;
+            let __raw_f = (value - offset) / factor;
+            //  Encode signed value as two's complement on 16 bits.
+            ((__raw_f as i64 as u64) & __mask) as u16
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < -500_f64 || 500_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [-500..500]",value)));
             }
-            let factor = 0.01_f64;
-            let offset = 0_f64;
-            let value = ((value - offset) / factor) as u16;
-            let value = u16::from_ne_bytes(value.to_ne_bytes());
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u16, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[24..40].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < -500_f64 || 500_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-500..500]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[24..40].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[24..40].load_le::<u16>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("ChassisControl payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // ChassisControl::YawRateDps impl end
 
 
@@ -6571,46 +10973,43 @@ pub mod ChassisControl { /// Message name space
     }
 
 
-    /// ChassisControl::YawRateDps public api (CanDbcSignal trait)
-    impl CanDbcSignal for YawRateDps {
+/// ChassisControl::YawRateDps public api (CanDbcSignal trait)
+impl CanDbcSignal for YawRateDps {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[24..40].load_le::<u16>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u16 = frame.data.view_bits::<Lsb0>()[24..40].load_le::<u16>();
 
-                    let value = i16::from_ne_bytes(value.to_ne_bytes());
 
-                    let factor = 0.01_f64;
-                    let offset = 0_f64;
-                    let newval = (value as f64) * factor + offset;
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -6636,7 +11035,7 @@ pub mod ChassisControl { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -6689,38 +11088,122 @@ pub mod ChassisControl { /// Message name space
 
 
     impl LatAccelMps2  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "m/s^2";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = -30.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 30.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 0.01;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 16;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 40;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(LatAccelMps2 {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            LatAccelMps2 {
                 status: CanDataStatus::Unset,
                 name:"LatAccelMps2",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u16) -> f64 {
+            // Sign-extend raw from the DBC signal bit-width before scaling.
+            let shift = u16::BITS - 16u32;
+            let signed: i16 = ((raw << shift) as i16) >> shift;
+            (signed as f64) * 0.01_f64 + 0_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u16 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 16 == 64 { u64::MAX } else { (1u64 << 16) - 1 };
+            let factor = 0.01_f64;
+            let offset = 0_f64;
+            let __raw_f = (value - offset) / factor;
+            //  Encode signed value as two's complement on 16 bits.
+            ((__raw_f as i64 as u64) & __mask) as u16
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < -30_f64 || 30_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [-30..30]",value)));
             }
-            let factor = 0.01_f64;
-            let offset = 0_f64;
-            let value = ((value - offset) / factor) as u16;
-            let value = u16::from_ne_bytes(value.to_ne_bytes());
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u16, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[40..56].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < -30_f64 || 30_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-30..30]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[40..56].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[40..56].load_le::<u16>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("ChassisControl payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // ChassisControl::LatAccelMps2 impl end
 
 
@@ -6742,46 +11225,43 @@ pub mod ChassisControl { /// Message name space
     }
 
 
-    /// ChassisControl::LatAccelMps2 public api (CanDbcSignal trait)
-    impl CanDbcSignal for LatAccelMps2 {
+/// ChassisControl::LatAccelMps2 public api (CanDbcSignal trait)
+impl CanDbcSignal for LatAccelMps2 {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[40..56].load_le::<u16>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u16 = frame.data.view_bits::<Lsb0>()[40..56].load_le::<u16>();
 
-                    let value = i16::from_ne_bytes(value.to_ne_bytes());
 
-                    let factor = 0.01_f64;
-                    let offset = 0_f64;
-                    let newval = (value as f64) * factor + offset;
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -6807,7 +11287,7 @@ pub mod ChassisControl { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -6860,30 +11340,115 @@ pub mod ChassisControl { /// Message name space
 
 
     impl WheelFlKph  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "km/h";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 56;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(WheelFlKph {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            WheelFlKph {
                 status: CanDataStatus::Unset,
                 name:"WheelFlKph",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[56..64].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[56..64].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[56..64].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("ChassisControl payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // ChassisControl::WheelFlKph impl end
 
 
@@ -6905,35 +11470,37 @@ pub mod ChassisControl { /// Message name space
     }
 
 
-    /// ChassisControl::WheelFlKph public api (CanDbcSignal trait)
-    impl CanDbcSignal for WheelFlKph {
+/// ChassisControl::WheelFlKph public api (CanDbcSignal trait)
+impl CanDbcSignal for WheelFlKph {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[56..64].load_le::<u8>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[56..64].load_le::<u8>();
 
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -6965,7 +11532,7 @@ pub mod ChassisControl { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -7000,6 +11567,7 @@ pub mod ChassisControl { /// Message name space
         name: &'static str,
         status: CanBcmOpCode,
         listeners: i32,
+        signal_listeners: [i32;6],
         stamp: u64,
         id: u32,
     }
@@ -7011,6 +11579,7 @@ pub mod ChassisControl { /// Message name space
                 name: "ChassisControl",
                 status: CanBcmOpCode::Unknown,
                 listeners: 0,
+                signal_listeners: [0;6],
                 stamp: 0,
                 callback: None,
                 signals: [
@@ -7025,36 +11594,110 @@ pub mod ChassisControl { /// Message name space
             })))
         }
 
+        /// Return code of each signal callback (`CanSigCtrl::sig_notification`) on the last
+        /// frame, in `DbcSignal` order: 0 without callback or for an inactive mux page, -1 when
+        /// the callback was busy. `get_listeners()` is their sum.
+        pub fn get_signal_listeners(&self) -> &[i32;6] {
+            &self.signal_listeners
+        }
 
-        pub fn set_values(&mut self, abs_active: bool, esc_active: bool, brake_pressure_bar: f64, yaw_rate_dps: f64, lat_accel_mps2: f64, wheel_fl_kph: u8, frame: &mut[u8]) -> Result<&mut Self, CanError> {
-
+        /// Return code of the `signal` callback on the last frame, see `get_signal_listeners()`.
+        pub fn get_signal_listener(&self, signal: DbcSignal) -> i32 {
+            self.signal_listeners[signal as usize]
+        }
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
-                Ok(mut signal) => signal.set_value(CanDbcType::Bool(abs_active), frame)?,
-                Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error abs_active:Bool")),
+        /// Index in `get_signals()` of the signal called `name`, its `get_name()` or DBC name.
+        pub fn signal_index(name: &str) -> Option<usize> {
+            match name {
+                "AbsActive" | "abs_active" => Some(0),
+                "EscActive" | "esc_active" => Some(1),
+                "BrakePressureBar" | "brake_pressure_bar" => Some(2),
+                "YawRateDps" | "yaw_rate_dps" => Some(3),
+                "LatAccelMps2" | "lat_accel_mps2" => Some(4),
+                "WheelFlKph" | "wheel_fl_kph" => Some(5),
+                _ => None,
             }
+        }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
-                Ok(mut signal) => signal.set_value(CanDbcType::Bool(esc_active), frame)?,
-                Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error esc_active:Bool")),
+        /// Signal called `name`, its `get_name()` or DBC name, without scanning the signals.
+        pub fn get_signal(&self, name: &str) -> Option<Rc<RefCell<Box<dyn CanDbcSignal>>>> {
+            Self::signal_index(name).map(|idx| Rc::clone(&self.signals[idx]))
+        }
+
+        /// Unit, range, scaling and layout of the signal called `name`, its `get_name()` or DBC
+        /// name, for generic tools holding a `dyn CanDbcSignal`.
+        pub fn signal_meta(name: &str) -> Option<super::SignalMeta> {
+            match name {
+                "AbsActive" | "abs_active" => Some(AbsActive::META),
+                "EscActive" | "esc_active" => Some(EscActive::META),
+                "BrakePressureBar" | "brake_pressure_bar" => Some(BrakePressureBar::META),
+                "YawRateDps" | "yaw_rate_dps" => Some(YawRateDps::META),
+                "LatAccelMps2" | "lat_accel_mps2" => Some(LatAccelMps2::META),
+                "WheelFlKph" | "wheel_fl_kph" => Some(WheelFlKph::META),
+                _ => None,
+            }
+        }
+
+
+        /// CAN id without the extended flag, 11 or 29 bits
+        pub const CAN_ID: u32 = 0xd2;
+        /// 29-bit id: `get_id()` and the BCM filter carry CAN_EFF_FLAG (bit 31), like Linux can_id
+        pub const EXTENDED: bool = false;
+
+        pub fn is_extended(&self) -> bool {
+            Self::EXTENDED
+        }
+
+        /// Transmission period from the GenMsgCycleTime attribute (or its default), 0 for a
+        /// message sent on event or without attribute
+        pub const CYCLE_TIME_MS: u32 = 10;
+
+        /// `CYCLE_TIME_MS`, base of the BCM rate and watchdog timers of the message
+        pub fn get_cycle_time_ms(&self) -> u32 {
+            Self::CYCLE_TIME_MS
+        }
+
+        /// Attributes (BA_) set on the message, by DBC attribute name
+        pub const ATTRIBUTES: [(&'static str, super::attributes::AttributeValue); 1] = [("GenMsgCycleTime", super::attributes::AttributeValue::Int(10))];
+
+        /// Value of the message attribute `name`, its BA_DEF_DEF_ default when not set
+        pub fn get_attribute(name: &str) -> Option<super::attributes::AttributeValue> {
+            match name {
+                "GenMsgCycleTime" => Some(super::attributes::AttributeValue::Int(10)),
+                "GenMsgSendType" => super::attributes::GEN_MSG_SEND_TYPE.default(),
+                _ => None,
+            }
+        }
+
+        pub fn set_values(&mut self, abs_active: bool, esc_active: bool, brake_pressure_bar: f64, yaw_rate_dps: f64, lat_accel_mps2: f64, wheel_fl_kph: u8, frame: &mut[u8]) -> Result<&mut Self, CanError> {
+
+
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
+                Ok(mut signal) => signal.set_value(CanDbcType::Bool(abs_active), frame)?,
+                Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error abs_active:Bool")),
+            }
+
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
+                Ok(mut signal) => signal.set_value(CanDbcType::Bool(esc_active), frame)?,
+                Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error esc_active:Bool")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::F64(brake_pressure_bar), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error brake_pressure_bar:F64")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::F64(yaw_rate_dps), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error yaw_rate_dps:F64")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::F64(lat_accel_mps2), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error lat_accel_mps2:F64")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(wheel_fl_kph), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error wheel_fl_kph:U8")),
             }
@@ -7064,37 +11707,244 @@ pub mod ChassisControl { /// Message name space
     }
 
 
+    /// `ChassisControl(0x..) <opcode> stamp:<us> Signal:value ...`, for logs
+    impl fmt::Display for DbcMessage {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(fmt, "ChassisControl({:#x}) {:?} stamp:{}", self.id, self.status, self.stamp)?;
+            match self.signals[0].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()) {
+                Some(value) => write!(fmt, " AbsActive:{value}")?,
+                None => write!(fmt, " AbsActive:?")?,
+            }
+            match self.signals[1].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()) {
+                Some(value) => write!(fmt, " EscActive:{value}")?,
+                None => write!(fmt, " EscActive:?")?,
+            }
+            match self.signals[2].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " BrakePressureBar:{value}")?,
+                None => write!(fmt, " BrakePressureBar:?")?,
+            }
+            match self.signals[3].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " YawRateDps:{value}")?,
+                None => write!(fmt, " YawRateDps:?")?,
+            }
+            match self.signals[4].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " LatAccelMps2:{value}")?,
+                None => write!(fmt, " LatAccelMps2:?")?,
+            }
+            match self.signals[5].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " WheelFlKph:{value}")?,
+                None => write!(fmt, " WheelFlKph:?")?,
+            }
+            Ok(())
+        }
+    }
+
+    impl fmt::Debug for DbcMessage {
+        fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {
+            format.debug_struct("ChassisControl")
+                .field("id", &self.id)
+                .field("status", &self.status)
+                .field("stamp", &self.stamp)
+                .field("abs_active", &self.signals[0].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()))
+                .field("esc_active", &self.signals[1].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()))
+                .field("brake_pressure_bar", &self.signals[2].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .field("yaw_rate_dps", &self.signals[3].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .field("lat_accel_mps2", &self.signals[4].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .field("wheel_fl_kph", &self.signals[5].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .finish()
+        }
+    }
+
+    impl Serialize for DbcMessage {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::MessageState(self).serialize(serializer)
+        }
+    }
+
+
+    /// Values of every ChassisControl signal, see `DbcMessage::snapshot()`
+    #[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+    pub struct SignalValues {
+        pub abs_active: bool,
+        pub esc_active: bool,
+        pub brake_pressure_bar: f64,
+        pub yaw_rate_dps: f64,
+        pub lat_accel_mps2: f64,
+        pub wheel_fl_kph: u8,
+    }
+
+    /// Copy of a ChassisControl message taken at a point in time, holding no RefCell borrow
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub struct MessageSnapshot {
+        pub stamp: u64,
+        pub status: CanBcmOpCode,
+        pub values: SignalValues,
+    }
+
+    impl DbcMessage {
+        /// Copy the stamp, status and every signal value of the last decoded frame.
+        pub fn snapshot(&self) -> Result<MessageSnapshot, CanError> {
+            Ok(MessageSnapshot {
+                stamp: self.stamp,
+                status: self.status,
+                values: SignalValues {
+                    abs_active: match self.signals[0].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error abs_active")),
+                    },
+                    esc_active: match self.signals[1].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error esc_active")),
+                    },
+                    brake_pressure_bar: match self.signals[2].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error brake_pressure_bar")),
+                    },
+                    yaw_rate_dps: match self.signals[3].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error yaw_rate_dps")),
+                    },
+                    lat_accel_mps2: match self.signals[4].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error lat_accel_mps2")),
+                    },
+                    wheel_fl_kph: match self.signals[5].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error wheel_fl_kph")),
+                    },
+                },
+            })
+        }
+    }
+
+    /// Decode a ChassisControl payload into its values, without signal object nor pool.
+    pub fn decode(data: &[u8]) -> Result<SignalValues, CanError> {
+        if data.len() < 8 {
+            return Err(CanError::new("signal-update-fail", format!("ChassisControl payload len:{} shorter than 8 bytes", data.len())));
+        }
+        Ok(SignalValues {
+            abs_active: AbsActive::decode(data),
+            esc_active: EscActive::decode(data),
+            brake_pressure_bar: BrakePressureBar::decode(data),
+            yaw_rate_dps: YawRateDps::decode(data),
+            lat_accel_mps2: LatAccelMps2::decode(data),
+            wheel_fl_kph: WheelFlKph::decode(data),
+        })
+    }
+
+    /// Encode `values` into a ChassisControl payload, without signal object nor pool.
+    pub fn encode(values: &SignalValues, data: &mut [u8]) -> Result<(), CanError> {
+        if data.len() < 8 {
+            return Err(CanError::new("signal-set-values-fail", format!("ChassisControl payload len:{} shorter than 8 bytes", data.len())));
+        }
+        AbsActive::encode(values.abs_active, data)?;
+        EscActive::encode(values.esc_active, data)?;
+        BrakePressureBar::encode(values.brake_pressure_bar, data)?;
+        YawRateDps::encode(values.yaw_rate_dps, data)?;
+        LatAccelMps2::encode(values.lat_accel_mps2, data)?;
+        WheelFlKph::encode(values.wheel_fl_kph, data)?;
+        Ok(())
+    }
+
+    /// Typed builder of a ChassisControl payload: `ChassisControl::builder().signal(value).build(&mut data)?`.
+    /// Signals without setter call keep their GenSigStartValue, or their default.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct MessageBuilder {
+        values: SignalValues,
+    }
+
+    /// Start a ChassisControl payload, see `MessageBuilder`.
+    pub fn builder() -> MessageBuilder {
+        MessageBuilder {
+            values: SignalValues::default(),
+        }
+    }
+
+    impl MessageBuilder {
+        /// AbsActive physical value
+        #[must_use]
+        pub fn abs_active(mut self, value: bool) -> Self {
+            self.values.abs_active = value;
+            self
+        }
+
+        /// EscActive physical value
+        #[must_use]
+        pub fn esc_active(mut self, value: bool) -> Self {
+            self.values.esc_active = value;
+            self
+        }
+
+        /// BrakePressureBar physical value in bar
+        #[must_use]
+        pub fn brake_pressure_bar(mut self, value: f64) -> Self {
+            self.values.brake_pressure_bar = value;
+            self
+        }
+
+        /// YawRateDps physical value in deg/s
+        #[must_use]
+        pub fn yaw_rate_dps(mut self, value: f64) -> Self {
+            self.values.yaw_rate_dps = value;
+            self
+        }
+
+        /// LatAccelMps2 physical value in m/s^2
+        #[must_use]
+        pub fn lat_accel_mps2(mut self, value: f64) -> Self {
+            self.values.lat_accel_mps2 = value;
+            self
+        }
+
+        /// WheelFlKph physical value in km/h
+        #[must_use]
+        pub fn wheel_fl_kph(mut self, value: u8) -> Self {
+            self.values.wheel_fl_kph = value;
+            self
+        }
+
+        /// Values `build()` encodes.
+        pub fn get_values(&self) -> &SignalValues {
+            &self.values
+        }
+
+        /// Encode the values into `data`, see `encode()`.
+        pub fn build(&self, data: &mut [u8]) -> Result<(), CanError> {
+            encode(&self.values, data)
+        }
+    }
+
     impl CanDbcMessage for DbcMessage {
         fn reset(&mut self) -> Result<(), CanError> {
             self.status=CanBcmOpCode::Unknown;
             self.stamp=0;
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error abs_active:Bool")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error esc_active:Bool")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error brake_pressure_bar:F64")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error yaw_rate_dps:F64")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error lat_accel_mps2:F64")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error wheel_fl_kph:U8")),
             }
@@ -7106,34 +11956,35 @@ pub mod ChassisControl { /// Message name space
             self.stamp= frame.stamp;
             self.status= frame.opcode;
             self.listeners= 0;
+            self.signal_listeners= [0;6];
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[0] = signal.update(frame); self.listeners += self.signal_listeners[0]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error abs_active:Bool")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[1] = signal.update(frame); self.listeners += self.signal_listeners[1]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error esc_active:Bool")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[2] = signal.update(frame); self.listeners += self.signal_listeners[2]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error brake_pressure_bar:F64")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[3] = signal.update(frame); self.listeners += self.signal_listeners[3]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error yaw_rate_dps:F64")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[4] = signal.update(frame); self.listeners += self.signal_listeners[4]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error lat_accel_mps2:F64")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[5] = signal.update(frame); self.listeners += self.signal_listeners[5]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error wheel_fl_kph:U8")),
             }
 
@@ -7141,7 +11992,7 @@ pub mod ChassisControl { /// Message name space
                 None => {},
                 Some(callback) => {
                     match callback.try_borrow() {
-                        Err(_) => println!("fail to get message callback reference"),
+                        Err(_) => {println!("fail to get message callback reference");},
                         Ok(cb_ref) => cb_ref.msg_notification(self),
                     }
                 }
@@ -7203,6 +12054,9 @@ pub mod DiagFdFrame { /// Message name space
     use std::fmt;
 
     use serde::{Deserialize, Serialize};
+    /// Nodes transmitting this message (BO_ and BO_TX_BU_)
+    pub const TRANSMITTERS: [&str; 1] = ["DIAG_TOOL"];
+
     pub enum DbcSignal {
         DiagServiceId,
         DiagSubfunction,
@@ -7252,30 +12106,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl DiagServiceId  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 0;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(DiagServiceId {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            DiagServiceId {
                 status: CanDataStatus::Unset,
                 name:"DiagServiceId",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[0..8].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[0..8].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[0..8].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::DiagServiceId impl end
 
 
@@ -7297,35 +12236,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::DiagServiceId public api (CanDbcSignal trait)
-    impl CanDbcSignal for DiagServiceId {
+/// DiagFdFrame::DiagServiceId public api (CanDbcSignal trait)
+impl CanDbcSignal for DiagServiceId {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[0..8].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[0..8].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -7357,7 +12298,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -7410,30 +12351,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl DiagSubfunction  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 8;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(DiagSubfunction {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            DiagSubfunction {
                 status: CanDataStatus::Unset,
                 name:"DiagSubfunction",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[8..16].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[8..16].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[8..16].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::DiagSubfunction impl end
 
 
@@ -7455,35 +12481,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::DiagSubfunction public api (CanDbcSignal trait)
-    impl CanDbcSignal for DiagSubfunction {
+/// DiagFdFrame::DiagSubfunction public api (CanDbcSignal trait)
+impl CanDbcSignal for DiagSubfunction {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[8..16].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[8..16].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -7515,7 +12543,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -7568,30 +12596,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl DiagStatus  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 16;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(DiagStatus {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            DiagStatus {
                 status: CanDataStatus::Unset,
                 name:"DiagStatus",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[16..24].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[16..24].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[16..24].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::DiagStatus impl end
 
 
@@ -7613,35 +12726,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::DiagStatus public api (CanDbcSignal trait)
-    impl CanDbcSignal for DiagStatus {
+/// DiagFdFrame::DiagStatus public api (CanDbcSignal trait)
+impl CanDbcSignal for DiagStatus {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[16..24].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[16..24].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -7673,7 +12788,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -7726,30 +12841,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl PayloadLen  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 64.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 24;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PayloadLen {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PayloadLen {
                 status: CanDataStatus::Unset,
                 name:"PayloadLen",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 64_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..64]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[24..32].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 64_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..64]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[24..32].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[24..32].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::PayloadLen impl end
 
 
@@ -7771,35 +12971,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::PayloadLen public api (CanDbcSignal trait)
-    impl CanDbcSignal for PayloadLen {
+/// DiagFdFrame::PayloadLen public api (CanDbcSignal trait)
+impl CanDbcSignal for PayloadLen {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[24..32].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[24..32].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -7831,7 +13033,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -7884,30 +13086,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl PayloadByte0  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 32;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PayloadByte0 {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PayloadByte0 {
                 status: CanDataStatus::Unset,
                 name:"PayloadByte0",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[32..40].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[32..40].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[32..40].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::PayloadByte0 impl end
 
 
@@ -7929,35 +13216,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::PayloadByte0 public api (CanDbcSignal trait)
-    impl CanDbcSignal for PayloadByte0 {
+/// DiagFdFrame::PayloadByte0 public api (CanDbcSignal trait)
+impl CanDbcSignal for PayloadByte0 {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[32..40].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[32..40].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -7989,7 +13278,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -8042,30 +13331,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl PayloadByte1  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 40;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PayloadByte1 {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PayloadByte1 {
                 status: CanDataStatus::Unset,
                 name:"PayloadByte1",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[40..48].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[40..48].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[40..48].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::PayloadByte1 impl end
 
 
@@ -8087,35 +13461,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::PayloadByte1 public api (CanDbcSignal trait)
-    impl CanDbcSignal for PayloadByte1 {
+/// DiagFdFrame::PayloadByte1 public api (CanDbcSignal trait)
+impl CanDbcSignal for PayloadByte1 {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[40..48].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[40..48].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -8147,7 +13523,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -8200,30 +13576,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl PayloadByte2  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 48;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PayloadByte2 {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PayloadByte2 {
                 status: CanDataStatus::Unset,
                 name:"PayloadByte2",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[48..56].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[48..56].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[48..56].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::PayloadByte2 impl end
 
 
@@ -8245,35 +13706,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::PayloadByte2 public api (CanDbcSignal trait)
-    impl CanDbcSignal for PayloadByte2 {
+/// DiagFdFrame::PayloadByte2 public api (CanDbcSignal trait)
+impl CanDbcSignal for PayloadByte2 {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[48..56].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[48..56].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -8305,7 +13768,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -8358,30 +13821,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl PayloadByte3  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 56;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PayloadByte3 {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PayloadByte3 {
                 status: CanDataStatus::Unset,
                 name:"PayloadByte3",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[56..64].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[56..64].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[56..64].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::PayloadByte3 impl end
 
 
@@ -8403,35 +13951,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::PayloadByte3 public api (CanDbcSignal trait)
-    impl CanDbcSignal for PayloadByte3 {
+/// DiagFdFrame::PayloadByte3 public api (CanDbcSignal trait)
+impl CanDbcSignal for PayloadByte3 {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[56..64].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[56..64].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -8463,7 +14013,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -8516,30 +14066,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl PayloadByte4  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 64;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PayloadByte4 {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PayloadByte4 {
                 status: CanDataStatus::Unset,
                 name:"PayloadByte4",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[64..72].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[64..72].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[64..72].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::PayloadByte4 impl end
 
 
@@ -8561,35 +14196,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::PayloadByte4 public api (CanDbcSignal trait)
-    impl CanDbcSignal for PayloadByte4 {
+/// DiagFdFrame::PayloadByte4 public api (CanDbcSignal trait)
+impl CanDbcSignal for PayloadByte4 {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[64..72].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[64..72].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -8621,7 +14258,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -8674,30 +14311,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl PayloadByte5  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 72;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PayloadByte5 {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PayloadByte5 {
                 status: CanDataStatus::Unset,
                 name:"PayloadByte5",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[72..80].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[72..80].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[72..80].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::PayloadByte5 impl end
 
 
@@ -8719,35 +14441,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::PayloadByte5 public api (CanDbcSignal trait)
-    impl CanDbcSignal for PayloadByte5 {
+/// DiagFdFrame::PayloadByte5 public api (CanDbcSignal trait)
+impl CanDbcSignal for PayloadByte5 {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[72..80].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[72..80].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -8779,7 +14503,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -8832,30 +14556,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl PayloadByte6  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 80;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PayloadByte6 {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PayloadByte6 {
                 status: CanDataStatus::Unset,
                 name:"PayloadByte6",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[80..88].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[80..88].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[80..88].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::PayloadByte6 impl end
 
 
@@ -8877,35 +14686,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::PayloadByte6 public api (CanDbcSignal trait)
-    impl CanDbcSignal for PayloadByte6 {
+/// DiagFdFrame::PayloadByte6 public api (CanDbcSignal trait)
+impl CanDbcSignal for PayloadByte6 {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[80..88].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[80..88].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -8937,7 +14748,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -8990,30 +14801,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl PayloadByte7  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 88;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PayloadByte7 {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PayloadByte7 {
                 status: CanDataStatus::Unset,
                 name:"PayloadByte7",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[88..96].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[88..96].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[88..96].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::PayloadByte7 impl end
 
 
@@ -9035,35 +14931,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::PayloadByte7 public api (CanDbcSignal trait)
-    impl CanDbcSignal for PayloadByte7 {
+/// DiagFdFrame::PayloadByte7 public api (CanDbcSignal trait)
+impl CanDbcSignal for PayloadByte7 {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[88..96].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[88..96].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -9095,7 +14993,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -9148,30 +15046,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl PayloadByte8  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 96;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PayloadByte8 {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PayloadByte8 {
                 status: CanDataStatus::Unset,
                 name:"PayloadByte8",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[96..104].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[96..104].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[96..104].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::PayloadByte8 impl end
 
 
@@ -9193,35 +15176,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::PayloadByte8 public api (CanDbcSignal trait)
-    impl CanDbcSignal for PayloadByte8 {
+/// DiagFdFrame::PayloadByte8 public api (CanDbcSignal trait)
+impl CanDbcSignal for PayloadByte8 {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[96..104].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[96..104].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -9253,7 +15238,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -9306,30 +15291,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl PayloadByte9  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 104;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PayloadByte9 {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PayloadByte9 {
                 status: CanDataStatus::Unset,
                 name:"PayloadByte9",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[104..112].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[104..112].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[104..112].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::PayloadByte9 impl end
 
 
@@ -9351,35 +15421,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::PayloadByte9 public api (CanDbcSignal trait)
-    impl CanDbcSignal for PayloadByte9 {
+/// DiagFdFrame::PayloadByte9 public api (CanDbcSignal trait)
+impl CanDbcSignal for PayloadByte9 {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[104..112].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[104..112].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -9411,7 +15483,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -9464,30 +15536,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl PayloadByte10  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 112;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PayloadByte10 {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PayloadByte10 {
                 status: CanDataStatus::Unset,
                 name:"PayloadByte10",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[112..120].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[112..120].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[112..120].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::PayloadByte10 impl end
 
 
@@ -9509,35 +15666,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::PayloadByte10 public api (CanDbcSignal trait)
-    impl CanDbcSignal for PayloadByte10 {
+/// DiagFdFrame::PayloadByte10 public api (CanDbcSignal trait)
+impl CanDbcSignal for PayloadByte10 {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[112..120].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[112..120].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -9569,7 +15728,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -9622,30 +15781,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl PayloadByte11  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 120;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PayloadByte11 {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PayloadByte11 {
                 status: CanDataStatus::Unset,
                 name:"PayloadByte11",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[120..128].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[120..128].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[120..128].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::PayloadByte11 impl end
 
 
@@ -9667,35 +15911,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::PayloadByte11 public api (CanDbcSignal trait)
-    impl CanDbcSignal for PayloadByte11 {
+/// DiagFdFrame::PayloadByte11 public api (CanDbcSignal trait)
+impl CanDbcSignal for PayloadByte11 {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[120..128].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[120..128].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -9727,7 +15973,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -9780,30 +16026,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl PayloadByte12  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 128;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PayloadByte12 {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PayloadByte12 {
                 status: CanDataStatus::Unset,
                 name:"PayloadByte12",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[128..136].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[128..136].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[128..136].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::PayloadByte12 impl end
 
 
@@ -9825,35 +16156,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::PayloadByte12 public api (CanDbcSignal trait)
-    impl CanDbcSignal for PayloadByte12 {
+/// DiagFdFrame::PayloadByte12 public api (CanDbcSignal trait)
+impl CanDbcSignal for PayloadByte12 {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[128..136].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[128..136].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -9885,7 +16218,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -9938,30 +16271,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl PayloadByte13  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 136;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PayloadByte13 {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PayloadByte13 {
                 status: CanDataStatus::Unset,
                 name:"PayloadByte13",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[136..144].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[136..144].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[136..144].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::PayloadByte13 impl end
 
 
@@ -9983,35 +16401,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::PayloadByte13 public api (CanDbcSignal trait)
-    impl CanDbcSignal for PayloadByte13 {
+/// DiagFdFrame::PayloadByte13 public api (CanDbcSignal trait)
+impl CanDbcSignal for PayloadByte13 {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[136..144].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[136..144].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -10043,7 +16463,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -10096,30 +16516,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl PayloadByte14  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 144;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PayloadByte14 {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PayloadByte14 {
                 status: CanDataStatus::Unset,
                 name:"PayloadByte14",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[144..152].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[144..152].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[144..152].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::PayloadByte14 impl end
 
 
@@ -10141,35 +16646,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::PayloadByte14 public api (CanDbcSignal trait)
-    impl CanDbcSignal for PayloadByte14 {
+/// DiagFdFrame::PayloadByte14 public api (CanDbcSignal trait)
+impl CanDbcSignal for PayloadByte14 {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[144..152].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[144..152].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -10201,7 +16708,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -10254,30 +16761,115 @@ pub mod DiagFdFrame { /// Message name space
 
 
     impl PayloadByte15  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 152;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(PayloadByte15 {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            PayloadByte15 {
                 status: CanDataStatus::Unset,
                 name:"PayloadByte15",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[152..160].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[152..160].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[152..160].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DiagFdFrame::PayloadByte15 impl end
 
 
@@ -10299,35 +16891,37 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
-    /// DiagFdFrame::PayloadByte15 public api (CanDbcSignal trait)
-    impl CanDbcSignal for PayloadByte15 {
+/// DiagFdFrame::PayloadByte15 public api (CanDbcSignal trait)
+impl CanDbcSignal for PayloadByte15 {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[152..160].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[152..160].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -10359,7 +16953,7 @@ pub mod DiagFdFrame { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -10394,6 +16988,7 @@ pub mod DiagFdFrame { /// Message name space
         name: &'static str,
         status: CanBcmOpCode,
         listeners: i32,
+        signal_listeners: [i32;20],
         stamp: u64,
         id: u32,
     }
@@ -10405,6 +17000,7 @@ pub mod DiagFdFrame { /// Message name space
                 name: "DiagFdFrame",
                 status: CanBcmOpCode::Unknown,
                 listeners: 0,
+                signal_listeners: [0;20],
                 stamp: 0,
                 callback: None,
                 signals: [
@@ -10433,106 +17029,208 @@ pub mod DiagFdFrame { /// Message name space
             })))
         }
 
+        /// Return code of each signal callback (`CanSigCtrl::sig_notification`) on the last
+        /// frame, in `DbcSignal` order: 0 without callback or for an inactive mux page, -1 when
+        /// the callback was busy. `get_listeners()` is their sum.
+        pub fn get_signal_listeners(&self) -> &[i32;20] {
+            &self.signal_listeners
+        }
+
+        /// Return code of the `signal` callback on the last frame, see `get_signal_listeners()`.
+        pub fn get_signal_listener(&self, signal: DbcSignal) -> i32 {
+            self.signal_listeners[signal as usize]
+        }
+
+        /// Index in `get_signals()` of the signal called `name`, its `get_name()` or DBC name.
+        pub fn signal_index(name: &str) -> Option<usize> {
+            match name {
+                "DiagServiceId" | "diag_service_id" => Some(0),
+                "DiagSubfunction" | "diag_subfunction" => Some(1),
+                "DiagStatus" | "diag_status" => Some(2),
+                "PayloadLen" | "payload_len" => Some(3),
+                "PayloadByte0" | "payload_byte0" => Some(4),
+                "PayloadByte1" | "payload_byte1" => Some(5),
+                "PayloadByte2" | "payload_byte2" => Some(6),
+                "PayloadByte3" | "payload_byte3" => Some(7),
+                "PayloadByte4" | "payload_byte4" => Some(8),
+                "PayloadByte5" | "payload_byte5" => Some(9),
+                "PayloadByte6" | "payload_byte6" => Some(10),
+                "PayloadByte7" | "payload_byte7" => Some(11),
+                "PayloadByte8" | "payload_byte8" => Some(12),
+                "PayloadByte9" | "payload_byte9" => Some(13),
+                "PayloadByte10" | "payload_byte10" => Some(14),
+                "PayloadByte11" | "payload_byte11" => Some(15),
+                "PayloadByte12" | "payload_byte12" => Some(16),
+                "PayloadByte13" | "payload_byte13" => Some(17),
+                "PayloadByte14" | "payload_byte14" => Some(18),
+                "PayloadByte15" | "payload_byte15" => Some(19),
+                _ => None,
+            }
+        }
+
+        /// Signal called `name`, its `get_name()` or DBC name, without scanning the signals.
+        pub fn get_signal(&self, name: &str) -> Option<Rc<RefCell<Box<dyn CanDbcSignal>>>> {
+            Self::signal_index(name).map(|idx| Rc::clone(&self.signals[idx]))
+        }
+
+        /// Unit, range, scaling and layout of the signal called `name`, its `get_name()` or DBC
+        /// name, for generic tools holding a `dyn CanDbcSignal`.
+        pub fn signal_meta(name: &str) -> Option<super::SignalMeta> {
+            match name {
+                "DiagServiceId" | "diag_service_id" => Some(DiagServiceId::META),
+                "DiagSubfunction" | "diag_subfunction" => Some(DiagSubfunction::META),
+                "DiagStatus" | "diag_status" => Some(DiagStatus::META),
+                "PayloadLen" | "payload_len" => Some(PayloadLen::META),
+                "PayloadByte0" | "payload_byte0" => Some(PayloadByte0::META),
+                "PayloadByte1" | "payload_byte1" => Some(PayloadByte1::META),
+                "PayloadByte2" | "payload_byte2" => Some(PayloadByte2::META),
+                "PayloadByte3" | "payload_byte3" => Some(PayloadByte3::META),
+                "PayloadByte4" | "payload_byte4" => Some(PayloadByte4::META),
+                "PayloadByte5" | "payload_byte5" => Some(PayloadByte5::META),
+                "PayloadByte6" | "payload_byte6" => Some(PayloadByte6::META),
+                "PayloadByte7" | "payload_byte7" => Some(PayloadByte7::META),
+                "PayloadByte8" | "payload_byte8" => Some(PayloadByte8::META),
+                "PayloadByte9" | "payload_byte9" => Some(PayloadByte9::META),
+                "PayloadByte10" | "payload_byte10" => Some(PayloadByte10::META),
+                "PayloadByte11" | "payload_byte11" => Some(PayloadByte11::META),
+                "PayloadByte12" | "payload_byte12" => Some(PayloadByte12::META),
+                "PayloadByte13" | "payload_byte13" => Some(PayloadByte13::META),
+                "PayloadByte14" | "payload_byte14" => Some(PayloadByte14::META),
+                "PayloadByte15" | "payload_byte15" => Some(PayloadByte15::META),
+                _ => None,
+            }
+        }
+
+
+        /// CAN id without the extended flag, 11 or 29 bits
+        pub const CAN_ID: u32 = 0x12c;
+        /// 29-bit id: `get_id()` and the BCM filter carry CAN_EFF_FLAG (bit 31), like Linux can_id
+        pub const EXTENDED: bool = false;
+
+        pub fn is_extended(&self) -> bool {
+            Self::EXTENDED
+        }
+
+        /// Transmission period from the GenMsgCycleTime attribute (or its default), 0 for a
+        /// message sent on event or without attribute
+        pub const CYCLE_TIME_MS: u32 = 0;
+
+        /// `CYCLE_TIME_MS`, base of the BCM rate and watchdog timers of the message
+        pub fn get_cycle_time_ms(&self) -> u32 {
+            Self::CYCLE_TIME_MS
+        }
+
+        /// Attributes (BA_) set on the message, by DBC attribute name
+        pub const ATTRIBUTES: [(&'static str, super::attributes::AttributeValue); 2] = [("GenMsgCycleTime", super::attributes::AttributeValue::Int(0)), ("GenMsgSendType", super::attributes::AttributeValue::Str("event"))];
+
+        /// Value of the message attribute `name`, its BA_DEF_DEF_ default when not set
+        pub fn get_attribute(name: &str) -> Option<super::attributes::AttributeValue> {
+            match name {
+                "GenMsgCycleTime" => Some(super::attributes::AttributeValue::Int(0)),
+                "GenMsgSendType" => Some(super::attributes::AttributeValue::Str("event")),
+                _ => None,
+            }
+        }
 
         pub fn set_values(&mut self, diag_service_id: u8, diag_subfunction: u8, diag_status: u8, payload_len: u8, payload_byte0: u8, payload_byte1: u8, payload_byte2: u8, payload_byte3: u8, payload_byte4: u8, payload_byte5: u8, payload_byte6: u8, payload_byte7: u8, payload_byte8: u8, payload_byte9: u8, payload_byte10: u8, payload_byte11: u8, payload_byte12: u8, payload_byte13: u8, payload_byte14: u8, payload_byte15: u8, frame: &mut[u8]) -> Result<&mut Self, CanError> {
 
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(diag_service_id), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error diag_service_id:U8")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(diag_subfunction), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error diag_subfunction:U8")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(diag_status), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error diag_status:U8")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(payload_len), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error payload_len:U8")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(payload_byte0), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error payload_byte0:U8")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(payload_byte1), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error payload_byte1:U8")),
             }
 
-            match Rc::clone (&self.signals[6]).try_borrow_mut() {
+            match Rc::clone(&self.signals[6]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(payload_byte2), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error payload_byte2:U8")),
             }
 
-            match Rc::clone (&self.signals[7]).try_borrow_mut() {
+            match Rc::clone(&self.signals[7]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(payload_byte3), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error payload_byte3:U8")),
             }
 
-            match Rc::clone (&self.signals[8]).try_borrow_mut() {
+            match Rc::clone(&self.signals[8]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(payload_byte4), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error payload_byte4:U8")),
             }
 
-            match Rc::clone (&self.signals[9]).try_borrow_mut() {
+            match Rc::clone(&self.signals[9]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(payload_byte5), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error payload_byte5:U8")),
             }
 
-            match Rc::clone (&self.signals[10]).try_borrow_mut() {
+            match Rc::clone(&self.signals[10]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(payload_byte6), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error payload_byte6:U8")),
             }
 
-            match Rc::clone (&self.signals[11]).try_borrow_mut() {
+            match Rc::clone(&self.signals[11]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(payload_byte7), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error payload_byte7:U8")),
             }
 
-            match Rc::clone (&self.signals[12]).try_borrow_mut() {
+            match Rc::clone(&self.signals[12]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(payload_byte8), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error payload_byte8:U8")),
             }
 
-            match Rc::clone (&self.signals[13]).try_borrow_mut() {
+            match Rc::clone(&self.signals[13]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(payload_byte9), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error payload_byte9:U8")),
             }
 
-            match Rc::clone (&self.signals[14]).try_borrow_mut() {
+            match Rc::clone(&self.signals[14]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(payload_byte10), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error payload_byte10:U8")),
             }
 
-            match Rc::clone (&self.signals[15]).try_borrow_mut() {
+            match Rc::clone(&self.signals[15]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(payload_byte11), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error payload_byte11:U8")),
             }
 
-            match Rc::clone (&self.signals[16]).try_borrow_mut() {
+            match Rc::clone(&self.signals[16]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(payload_byte12), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error payload_byte12:U8")),
             }
 
-            match Rc::clone (&self.signals[17]).try_borrow_mut() {
+            match Rc::clone(&self.signals[17]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(payload_byte13), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error payload_byte13:U8")),
             }
 
-            match Rc::clone (&self.signals[18]).try_borrow_mut() {
+            match Rc::clone(&self.signals[18]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(payload_byte14), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error payload_byte14:U8")),
             }
 
-            match Rc::clone (&self.signals[19]).try_borrow_mut() {
+            match Rc::clone(&self.signals[19]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(payload_byte15), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error payload_byte15:U8")),
             }
@@ -10542,107 +17240,580 @@ pub mod DiagFdFrame { /// Message name space
     }
 
 
+    /// `DiagFdFrame(0x..) <opcode> stamp:<us> Signal:value ...`, for logs
+    impl fmt::Display for DbcMessage {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(fmt, "DiagFdFrame({:#x}) {:?} stamp:{}", self.id, self.status, self.stamp)?;
+            match self.signals[0].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " DiagServiceId:{value}")?,
+                None => write!(fmt, " DiagServiceId:?")?,
+            }
+            match self.signals[1].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " DiagSubfunction:{value}")?,
+                None => write!(fmt, " DiagSubfunction:?")?,
+            }
+            match self.signals[2].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " DiagStatus:{value}")?,
+                None => write!(fmt, " DiagStatus:?")?,
+            }
+            match self.signals[3].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " PayloadLen:{value}")?,
+                None => write!(fmt, " PayloadLen:?")?,
+            }
+            match self.signals[4].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " PayloadByte0:{value}")?,
+                None => write!(fmt, " PayloadByte0:?")?,
+            }
+            match self.signals[5].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " PayloadByte1:{value}")?,
+                None => write!(fmt, " PayloadByte1:?")?,
+            }
+            match self.signals[6].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " PayloadByte2:{value}")?,
+                None => write!(fmt, " PayloadByte2:?")?,
+            }
+            match self.signals[7].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " PayloadByte3:{value}")?,
+                None => write!(fmt, " PayloadByte3:?")?,
+            }
+            match self.signals[8].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " PayloadByte4:{value}")?,
+                None => write!(fmt, " PayloadByte4:?")?,
+            }
+            match self.signals[9].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " PayloadByte5:{value}")?,
+                None => write!(fmt, " PayloadByte5:?")?,
+            }
+            match self.signals[10].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " PayloadByte6:{value}")?,
+                None => write!(fmt, " PayloadByte6:?")?,
+            }
+            match self.signals[11].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " PayloadByte7:{value}")?,
+                None => write!(fmt, " PayloadByte7:?")?,
+            }
+            match self.signals[12].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " PayloadByte8:{value}")?,
+                None => write!(fmt, " PayloadByte8:?")?,
+            }
+            match self.signals[13].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " PayloadByte9:{value}")?,
+                None => write!(fmt, " PayloadByte9:?")?,
+            }
+            match self.signals[14].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " PayloadByte10:{value}")?,
+                None => write!(fmt, " PayloadByte10:?")?,
+            }
+            match self.signals[15].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " PayloadByte11:{value}")?,
+                None => write!(fmt, " PayloadByte11:?")?,
+            }
+            match self.signals[16].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " PayloadByte12:{value}")?,
+                None => write!(fmt, " PayloadByte12:?")?,
+            }
+            match self.signals[17].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " PayloadByte13:{value}")?,
+                None => write!(fmt, " PayloadByte13:?")?,
+            }
+            match self.signals[18].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " PayloadByte14:{value}")?,
+                None => write!(fmt, " PayloadByte14:?")?,
+            }
+            match self.signals[19].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " PayloadByte15:{value}")?,
+                None => write!(fmt, " PayloadByte15:?")?,
+            }
+            Ok(())
+        }
+    }
+
+    impl fmt::Debug for DbcMessage {
+        fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {
+            format.debug_struct("DiagFdFrame")
+                .field("id", &self.id)
+                .field("status", &self.status)
+                .field("stamp", &self.stamp)
+                .field("diag_service_id", &self.signals[0].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("diag_subfunction", &self.signals[1].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("diag_status", &self.signals[2].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("payload_len", &self.signals[3].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("payload_byte0", &self.signals[4].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("payload_byte1", &self.signals[5].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("payload_byte2", &self.signals[6].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("payload_byte3", &self.signals[7].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("payload_byte4", &self.signals[8].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("payload_byte5", &self.signals[9].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("payload_byte6", &self.signals[10].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("payload_byte7", &self.signals[11].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("payload_byte8", &self.signals[12].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("payload_byte9", &self.signals[13].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("payload_byte10", &self.signals[14].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("payload_byte11", &self.signals[15].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("payload_byte12", &self.signals[16].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("payload_byte13", &self.signals[17].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("payload_byte14", &self.signals[18].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("payload_byte15", &self.signals[19].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .finish()
+        }
+    }
+
+    impl Serialize for DbcMessage {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::MessageState(self).serialize(serializer)
+        }
+    }
+
+
+    /// Values of every DiagFdFrame signal, see `DbcMessage::snapshot()`
+    #[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+    pub struct SignalValues {
+        pub diag_service_id: u8,
+        pub diag_subfunction: u8,
+        pub diag_status: u8,
+        pub payload_len: u8,
+        pub payload_byte0: u8,
+        pub payload_byte1: u8,
+        pub payload_byte2: u8,
+        pub payload_byte3: u8,
+        pub payload_byte4: u8,
+        pub payload_byte5: u8,
+        pub payload_byte6: u8,
+        pub payload_byte7: u8,
+        pub payload_byte8: u8,
+        pub payload_byte9: u8,
+        pub payload_byte10: u8,
+        pub payload_byte11: u8,
+        pub payload_byte12: u8,
+        pub payload_byte13: u8,
+        pub payload_byte14: u8,
+        pub payload_byte15: u8,
+    }
+
+    /// Copy of a DiagFdFrame message taken at a point in time, holding no RefCell borrow
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub struct MessageSnapshot {
+        pub stamp: u64,
+        pub status: CanBcmOpCode,
+        pub values: SignalValues,
+    }
+
+    impl DbcMessage {
+        /// Copy the stamp, status and every signal value of the last decoded frame.
+        pub fn snapshot(&self) -> Result<MessageSnapshot, CanError> {
+            Ok(MessageSnapshot {
+                stamp: self.stamp,
+                status: self.status,
+                values: SignalValues {
+                    diag_service_id: match self.signals[0].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error diag_service_id")),
+                    },
+                    diag_subfunction: match self.signals[1].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error diag_subfunction")),
+                    },
+                    diag_status: match self.signals[2].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error diag_status")),
+                    },
+                    payload_len: match self.signals[3].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error payload_len")),
+                    },
+                    payload_byte0: match self.signals[4].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error payload_byte0")),
+                    },
+                    payload_byte1: match self.signals[5].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error payload_byte1")),
+                    },
+                    payload_byte2: match self.signals[6].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error payload_byte2")),
+                    },
+                    payload_byte3: match self.signals[7].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error payload_byte3")),
+                    },
+                    payload_byte4: match self.signals[8].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error payload_byte4")),
+                    },
+                    payload_byte5: match self.signals[9].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error payload_byte5")),
+                    },
+                    payload_byte6: match self.signals[10].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error payload_byte6")),
+                    },
+                    payload_byte7: match self.signals[11].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error payload_byte7")),
+                    },
+                    payload_byte8: match self.signals[12].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error payload_byte8")),
+                    },
+                    payload_byte9: match self.signals[13].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error payload_byte9")),
+                    },
+                    payload_byte10: match self.signals[14].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error payload_byte10")),
+                    },
+                    payload_byte11: match self.signals[15].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error payload_byte11")),
+                    },
+                    payload_byte12: match self.signals[16].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error payload_byte12")),
+                    },
+                    payload_byte13: match self.signals[17].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error payload_byte13")),
+                    },
+                    payload_byte14: match self.signals[18].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error payload_byte14")),
+                    },
+                    payload_byte15: match self.signals[19].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error payload_byte15")),
+                    },
+                },
+            })
+        }
+    }
+
+    /// Decode a DiagFdFrame payload into its values, without signal object nor pool.
+    pub fn decode(data: &[u8]) -> Result<SignalValues, CanError> {
+        if data.len() < 64 {
+            return Err(CanError::new("signal-update-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+        }
+        Ok(SignalValues {
+            diag_service_id: DiagServiceId::decode(data),
+            diag_subfunction: DiagSubfunction::decode(data),
+            diag_status: DiagStatus::decode(data),
+            payload_len: PayloadLen::decode(data),
+            payload_byte0: PayloadByte0::decode(data),
+            payload_byte1: PayloadByte1::decode(data),
+            payload_byte2: PayloadByte2::decode(data),
+            payload_byte3: PayloadByte3::decode(data),
+            payload_byte4: PayloadByte4::decode(data),
+            payload_byte5: PayloadByte5::decode(data),
+            payload_byte6: PayloadByte6::decode(data),
+            payload_byte7: PayloadByte7::decode(data),
+            payload_byte8: PayloadByte8::decode(data),
+            payload_byte9: PayloadByte9::decode(data),
+            payload_byte10: PayloadByte10::decode(data),
+            payload_byte11: PayloadByte11::decode(data),
+            payload_byte12: PayloadByte12::decode(data),
+            payload_byte13: PayloadByte13::decode(data),
+            payload_byte14: PayloadByte14::decode(data),
+            payload_byte15: PayloadByte15::decode(data),
+        })
+    }
+
+    /// Encode `values` into a DiagFdFrame payload, without signal object nor pool.
+    pub fn encode(values: &SignalValues, data: &mut [u8]) -> Result<(), CanError> {
+        if data.len() < 64 {
+            return Err(CanError::new("signal-set-values-fail", format!("DiagFdFrame payload len:{} shorter than 64 bytes", data.len())));
+        }
+        DiagServiceId::encode(values.diag_service_id, data)?;
+        DiagSubfunction::encode(values.diag_subfunction, data)?;
+        DiagStatus::encode(values.diag_status, data)?;
+        PayloadLen::encode(values.payload_len, data)?;
+        PayloadByte0::encode(values.payload_byte0, data)?;
+        PayloadByte1::encode(values.payload_byte1, data)?;
+        PayloadByte2::encode(values.payload_byte2, data)?;
+        PayloadByte3::encode(values.payload_byte3, data)?;
+        PayloadByte4::encode(values.payload_byte4, data)?;
+        PayloadByte5::encode(values.payload_byte5, data)?;
+        PayloadByte6::encode(values.payload_byte6, data)?;
+        PayloadByte7::encode(values.payload_byte7, data)?;
+        PayloadByte8::encode(values.payload_byte8, data)?;
+        PayloadByte9::encode(values.payload_byte9, data)?;
+        PayloadByte10::encode(values.payload_byte10, data)?;
+        PayloadByte11::encode(values.payload_byte11, data)?;
+        PayloadByte12::encode(values.payload_byte12, data)?;
+        PayloadByte13::encode(values.payload_byte13, data)?;
+        PayloadByte14::encode(values.payload_byte14, data)?;
+        PayloadByte15::encode(values.payload_byte15, data)?;
+        Ok(())
+    }
+
+    /// Typed builder of a DiagFdFrame payload: `DiagFdFrame::builder().signal(value).build(&mut data)?`.
+    /// Signals without setter call keep their GenSigStartValue, or their default.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct MessageBuilder {
+        values: SignalValues,
+    }
+
+    /// Start a DiagFdFrame payload, see `MessageBuilder`.
+    pub fn builder() -> MessageBuilder {
+        MessageBuilder {
+            values: SignalValues::default(),
+        }
+    }
+
+    impl MessageBuilder {
+        /// DiagServiceId physical value
+        #[must_use]
+        pub fn diag_service_id(mut self, value: u8) -> Self {
+            self.values.diag_service_id = value;
+            self
+        }
+
+        /// DiagSubfunction physical value
+        #[must_use]
+        pub fn diag_subfunction(mut self, value: u8) -> Self {
+            self.values.diag_subfunction = value;
+            self
+        }
+
+        /// DiagStatus physical value
+        #[must_use]
+        pub fn diag_status(mut self, value: u8) -> Self {
+            self.values.diag_status = value;
+            self
+        }
+
+        /// PayloadLen physical value
+        #[must_use]
+        pub fn payload_len(mut self, value: u8) -> Self {
+            self.values.payload_len = value;
+            self
+        }
+
+        /// PayloadByte0 physical value
+        #[must_use]
+        pub fn payload_byte0(mut self, value: u8) -> Self {
+            self.values.payload_byte0 = value;
+            self
+        }
+
+        /// PayloadByte1 physical value
+        #[must_use]
+        pub fn payload_byte1(mut self, value: u8) -> Self {
+            self.values.payload_byte1 = value;
+            self
+        }
+
+        /// PayloadByte2 physical value
+        #[must_use]
+        pub fn payload_byte2(mut self, value: u8) -> Self {
+            self.values.payload_byte2 = value;
+            self
+        }
+
+        /// PayloadByte3 physical value
+        #[must_use]
+        pub fn payload_byte3(mut self, value: u8) -> Self {
+            self.values.payload_byte3 = value;
+            self
+        }
+
+        /// PayloadByte4 physical value
+        #[must_use]
+        pub fn payload_byte4(mut self, value: u8) -> Self {
+            self.values.payload_byte4 = value;
+            self
+        }
+
+        /// PayloadByte5 physical value
+        #[must_use]
+        pub fn payload_byte5(mut self, value: u8) -> Self {
+            self.values.payload_byte5 = value;
+            self
+        }
+
+        /// PayloadByte6 physical value
+        #[must_use]
+        pub fn payload_byte6(mut self, value: u8) -> Self {
+            self.values.payload_byte6 = value;
+            self
+        }
+
+        /// PayloadByte7 physical value
+        #[must_use]
+        pub fn payload_byte7(mut self, value: u8) -> Self {
+            self.values.payload_byte7 = value;
+            self
+        }
+
+        /// PayloadByte8 physical value
+        #[must_use]
+        pub fn payload_byte8(mut self, value: u8) -> Self {
+            self.values.payload_byte8 = value;
+            self
+        }
+
+        /// PayloadByte9 physical value
+        #[must_use]
+        pub fn payload_byte9(mut self, value: u8) -> Self {
+            self.values.payload_byte9 = value;
+            self
+        }
+
+        /// PayloadByte10 physical value
+        #[must_use]
+        pub fn payload_byte10(mut self, value: u8) -> Self {
+            self.values.payload_byte10 = value;
+            self
+        }
+
+        /// PayloadByte11 physical value
+        #[must_use]
+        pub fn payload_byte11(mut self, value: u8) -> Self {
+            self.values.payload_byte11 = value;
+            self
+        }
+
+        /// PayloadByte12 physical value
+        #[must_use]
+        pub fn payload_byte12(mut self, value: u8) -> Self {
+            self.values.payload_byte12 = value;
+            self
+        }
+
+        /// PayloadByte13 physical value
+        #[must_use]
+        pub fn payload_byte13(mut self, value: u8) -> Self {
+            self.values.payload_byte13 = value;
+            self
+        }
+
+        /// PayloadByte14 physical value
+        #[must_use]
+        pub fn payload_byte14(mut self, value: u8) -> Self {
+            self.values.payload_byte14 = value;
+            self
+        }
+
+        /// PayloadByte15 physical value
+        #[must_use]
+        pub fn payload_byte15(mut self, value: u8) -> Self {
+            self.values.payload_byte15 = value;
+            self
+        }
+
+        /// Values `build()` encodes.
+        pub fn get_values(&self) -> &SignalValues {
+            &self.values
+        }
+
+        /// Encode the values into `data`, see `encode()`.
+        pub fn build(&self, data: &mut [u8]) -> Result<(), CanError> {
+            encode(&self.values, data)
+        }
+    }
+
     impl CanDbcMessage for DbcMessage {
         fn reset(&mut self) -> Result<(), CanError> {
             self.status=CanBcmOpCode::Unknown;
             self.stamp=0;
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error diag_service_id:U8")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error diag_subfunction:U8")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error diag_status:U8")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error payload_len:U8")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error payload_byte0:U8")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error payload_byte1:U8")),
             }
 
-            match Rc::clone (&self.signals[6]).try_borrow_mut() {
+            match Rc::clone(&self.signals[6]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error payload_byte2:U8")),
             }
 
-            match Rc::clone (&self.signals[7]).try_borrow_mut() {
+            match Rc::clone(&self.signals[7]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error payload_byte3:U8")),
             }
 
-            match Rc::clone (&self.signals[8]).try_borrow_mut() {
+            match Rc::clone(&self.signals[8]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error payload_byte4:U8")),
             }
 
-            match Rc::clone (&self.signals[9]).try_borrow_mut() {
+            match Rc::clone(&self.signals[9]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error payload_byte5:U8")),
             }
 
-            match Rc::clone (&self.signals[10]).try_borrow_mut() {
+            match Rc::clone(&self.signals[10]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error payload_byte6:U8")),
             }
 
-            match Rc::clone (&self.signals[11]).try_borrow_mut() {
+            match Rc::clone(&self.signals[11]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error payload_byte7:U8")),
             }
 
-            match Rc::clone (&self.signals[12]).try_borrow_mut() {
+            match Rc::clone(&self.signals[12]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error payload_byte8:U8")),
             }
 
-            match Rc::clone (&self.signals[13]).try_borrow_mut() {
+            match Rc::clone(&self.signals[13]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error payload_byte9:U8")),
             }
 
-            match Rc::clone (&self.signals[14]).try_borrow_mut() {
+            match Rc::clone(&self.signals[14]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error payload_byte10:U8")),
             }
 
-            match Rc::clone (&self.signals[15]).try_borrow_mut() {
+            match Rc::clone(&self.signals[15]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error payload_byte11:U8")),
             }
 
-            match Rc::clone (&self.signals[16]).try_borrow_mut() {
+            match Rc::clone(&self.signals[16]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error payload_byte12:U8")),
             }
 
-            match Rc::clone (&self.signals[17]).try_borrow_mut() {
+            match Rc::clone(&self.signals[17]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error payload_byte13:U8")),
             }
 
-            match Rc::clone (&self.signals[18]).try_borrow_mut() {
+            match Rc::clone(&self.signals[18]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error payload_byte14:U8")),
             }
 
-            match Rc::clone (&self.signals[19]).try_borrow_mut() {
+            match Rc::clone(&self.signals[19]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error payload_byte15:U8")),
             }
@@ -10654,104 +17825,105 @@ pub mod DiagFdFrame { /// Message name space
             self.stamp= frame.stamp;
             self.status= frame.opcode;
             self.listeners= 0;
+            self.signal_listeners= [0;20];
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[0] = signal.update(frame); self.listeners += self.signal_listeners[0]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error diag_service_id:U8")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[1] = signal.update(frame); self.listeners += self.signal_listeners[1]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error diag_subfunction:U8")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[2] = signal.update(frame); self.listeners += self.signal_listeners[2]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error diag_status:U8")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[3] = signal.update(frame); self.listeners += self.signal_listeners[3]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error payload_len:U8")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[4] = signal.update(frame); self.listeners += self.signal_listeners[4]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error payload_byte0:U8")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[5] = signal.update(frame); self.listeners += self.signal_listeners[5]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error payload_byte1:U8")),
             }
 
-            match Rc::clone (&self.signals[6]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[6]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[6] = signal.update(frame); self.listeners += self.signal_listeners[6]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error payload_byte2:U8")),
             }
 
-            match Rc::clone (&self.signals[7]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[7]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[7] = signal.update(frame); self.listeners += self.signal_listeners[7]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error payload_byte3:U8")),
             }
 
-            match Rc::clone (&self.signals[8]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[8]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[8] = signal.update(frame); self.listeners += self.signal_listeners[8]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error payload_byte4:U8")),
             }
 
-            match Rc::clone (&self.signals[9]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[9]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[9] = signal.update(frame); self.listeners += self.signal_listeners[9]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error payload_byte5:U8")),
             }
 
-            match Rc::clone (&self.signals[10]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[10]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[10] = signal.update(frame); self.listeners += self.signal_listeners[10]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error payload_byte6:U8")),
             }
 
-            match Rc::clone (&self.signals[11]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[11]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[11] = signal.update(frame); self.listeners += self.signal_listeners[11]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error payload_byte7:U8")),
             }
 
-            match Rc::clone (&self.signals[12]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[12]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[12] = signal.update(frame); self.listeners += self.signal_listeners[12]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error payload_byte8:U8")),
             }
 
-            match Rc::clone (&self.signals[13]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[13]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[13] = signal.update(frame); self.listeners += self.signal_listeners[13]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error payload_byte9:U8")),
             }
 
-            match Rc::clone (&self.signals[14]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[14]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[14] = signal.update(frame); self.listeners += self.signal_listeners[14]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error payload_byte10:U8")),
             }
 
-            match Rc::clone (&self.signals[15]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[15]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[15] = signal.update(frame); self.listeners += self.signal_listeners[15]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error payload_byte11:U8")),
             }
 
-            match Rc::clone (&self.signals[16]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[16]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[16] = signal.update(frame); self.listeners += self.signal_listeners[16]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error payload_byte12:U8")),
             }
 
-            match Rc::clone (&self.signals[17]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[17]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[17] = signal.update(frame); self.listeners += self.signal_listeners[17]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error payload_byte13:U8")),
             }
 
-            match Rc::clone (&self.signals[18]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[18]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[18] = signal.update(frame); self.listeners += self.signal_listeners[18]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error payload_byte14:U8")),
             }
 
-            match Rc::clone (&self.signals[19]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[19]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[19] = signal.update(frame); self.listeners += self.signal_listeners[19]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error payload_byte15:U8")),
             }
 
@@ -10759,7 +17931,7 @@ pub mod DiagFdFrame { /// Message name space
                 None => {},
                 Some(callback) => {
                     match callback.try_borrow() {
-                        Err(_) => println!("fail to get message callback reference"),
+                        Err(_) => {println!("fail to get message callback reference");},
                         Ok(cb_ref) => cb_ref.msg_notification(self),
                     }
                 }
@@ -10821,6 +17993,9 @@ pub mod DataTypesLe { /// Message name space
     use std::fmt;
 
     use serde::{Deserialize, Serialize};
+    /// Nodes transmitting this message (BO_ and BO_TX_BU_)
+    pub const TRANSMITTERS: [&str; 1] = ["SENSOR_GATEWAY"];
+
     pub enum DbcSignal {
         FlagReadyBool,
         U8Counter,
@@ -10846,6 +18021,11 @@ pub mod DataTypesLe { /// Message name space
     /// - Offset: 0
     /// - Byte order: LittleEndian
     /// - Value type: Unsigned
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | NO |
+    /// | 1 | YES |
     #[derive(Serialize, Deserialize)]
     pub struct FlagReadyBool {
         #[serde(skip)]
@@ -10858,6 +18038,12 @@ pub mod DataTypesLe { /// Message name space
     }
 
     // DBC definition for MsgID:400 Signal:flag_ready_bool
+    /// FlagReadyBool values (DBC VAL_)
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | NO |
+    /// | 1 | YES |
     #[derive(Serialize, Deserialize)]
     pub enum DbcFlagReadyBool {
         No,
@@ -10879,14 +18065,47 @@ pub mod DataTypesLe { /// Message name space
 
 
     impl FlagReadyBool  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 1.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 1;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 0;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(FlagReadyBool {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            FlagReadyBool {
                 status: CanDataStatus::Unset,
                 name:"FlagReadyBool",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
@@ -10899,30 +18118,72 @@ pub mod DataTypesLe { /// Message name space
             }
 
         }
+        #[deprecated(note = "use set_raw()")]
         pub fn set_raw_value(&mut self, value: u8, data: &mut[u8]) {
-            data.view_bits_mut::<Lsb0>()[0..1].store_le(value);
-
+            self.set_raw(value, data)
         }
         pub fn set_as_def (&mut self, signal_def: DbcFlagReadyBool, data: &mut[u8])-> Result<(),CanError> {
             match signal_def {
-                DbcFlagReadyBool::No => Ok(self.set_raw_value(0, data)),
-                DbcFlagReadyBool::Yes => Ok(self.set_raw_value(1, data)),
-                DbcFlagReadyBool::_Other(x) => self.set_typed_value(x,data)
+                DbcFlagReadyBool::No => Ok(self.set_raw(0, data)),
+                DbcFlagReadyBool::Yes => Ok(self.set_raw(1, data)),
+                DbcFlagReadyBool::_Other(x) => self.set_physical(x,data)
             }
         }
 
+        #[inline]
         fn get_typed_value(&self) -> bool {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
-            let value = value as u8;
-            data.view_bits_mut::<Lsb0>()[0..1].store_le(value);
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> bool {
+            raw == 1
+        }
 
-            Ok(())
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: bool) -> u8 {
+            value as u8
         }
 
-    } // DataTypesLe::FlagReadyBool impl end
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
+            data.view_bits_mut::<Lsb0>()[0..1].store_le(value);
+        }
+
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[0..1].store_le(value);
+            Ok(())
+        }
+
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> bool {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[0..1].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DataTypesLe payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
+    } // DataTypesLe::FlagReadyBool impl end
 
 
     impl fmt::Display for FlagReadyBool {
@@ -10943,42 +18204,43 @@ pub mod DataTypesLe { /// Message name space
     }
 
 
-    /// DataTypesLe::FlagReadyBool public api (CanDbcSignal trait)
-    impl CanDbcSignal for FlagReadyBool {
+/// DataTypesLe::FlagReadyBool public api (CanDbcSignal trait)
+impl CanDbcSignal for FlagReadyBool {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[0..1].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[0..1].load_le::<u8>();
 
-                    let newval = value == 1;
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
                     };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                        self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -11004,7 +18266,7 @@ pub mod DataTypesLe { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -11057,30 +18319,115 @@ pub mod DataTypesLe { /// Message name space
 
 
     impl U8Counter  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 8;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(U8Counter {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            U8Counter {
                 status: CanDataStatus::Unset,
                 name:"U8Counter",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[8..16].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[8..16].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[8..16].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DataTypesLe payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DataTypesLe::U8Counter impl end
 
 
@@ -11102,35 +18449,37 @@ pub mod DataTypesLe { /// Message name space
     }
 
 
-    /// DataTypesLe::U8Counter public api (CanDbcSignal trait)
-    impl CanDbcSignal for U8Counter {
+/// DataTypesLe::U8Counter public api (CanDbcSignal trait)
+impl CanDbcSignal for U8Counter {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[8..16].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[8..16].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -11162,7 +18511,7 @@ pub mod DataTypesLe { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -11215,31 +18564,118 @@ pub mod DataTypesLe { /// Message name space
 
 
     impl I8TempRaw  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "raw";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = -128.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 127.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 16;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(I8TempRaw {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            I8TempRaw {
                 status: CanDataStatus::Unset,
                 name:"I8TempRaw",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> i8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:i8, data:&mut [u8]) -> Result<(),CanError> {
-            let value = u8::from_ne_bytes(value.to_ne_bytes());
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> i8 {
+            let shift = u8::BITS - 8u32;
+            let signed: i8 = ((raw << shift) as i8) >> shift;
+            signed
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: i8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            //  Encode signed integer as two's complement on 8 bits.
+            ((value as i64 as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:i8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < -128_i8 || 127_i8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-128..127]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[16..24].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:i8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < -128_i8 || 127_i8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-128..127]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[16..24].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> i8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[16..24].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:i8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DataTypesLe payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DataTypesLe::I8TempRaw impl end
 
 
@@ -11261,37 +18697,37 @@ pub mod DataTypesLe { /// Message name space
     }
 
 
-    /// DataTypesLe::I8TempRaw public api (CanDbcSignal trait)
-    impl CanDbcSignal for I8TempRaw {
+/// DataTypesLe::I8TempRaw public api (CanDbcSignal trait)
+impl CanDbcSignal for I8TempRaw {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[16..24].load_le::<u8>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[16..24].load_le::<u8>();
 
-                    let value = i8::from_ne_bytes(value.to_ne_bytes());
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -11323,7 +18759,7 @@ pub mod DataTypesLe { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -11376,30 +18812,115 @@ pub mod DataTypesLe { /// Message name space
 
 
     impl U16OdometerDm  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "dm";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 65535.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 16;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 24;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(U16OdometerDm {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            U16OdometerDm {
                 status: CanDataStatus::Unset,
                 name:"U16OdometerDm",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u16 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u16, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u16) -> u16 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u16) -> u16 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 16 == 64 { u64::MAX } else { (1u64 << 16) - 1 };
+            ((value as u64) & __mask) as u16
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u16, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u16 || 65535_u16 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..65535]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u16, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[24..40].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u16, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u16 || 65535_u16 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..65535]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[24..40].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u16 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[24..40].load_le::<u16>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u16, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DataTypesLe payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DataTypesLe::U16OdometerDm impl end
 
 
@@ -11421,35 +18942,37 @@ pub mod DataTypesLe { /// Message name space
     }
 
 
-    /// DataTypesLe::U16OdometerDm public api (CanDbcSignal trait)
-    impl CanDbcSignal for U16OdometerDm {
+/// DataTypesLe::U16OdometerDm public api (CanDbcSignal trait)
+impl CanDbcSignal for U16OdometerDm {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[24..40].load_le::<u16>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u16 = frame.data.view_bits::<Lsb0>()[24..40].load_le::<u16>();
 
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -11481,7 +19004,7 @@ pub mod DataTypesLe { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -11534,31 +19057,118 @@ pub mod DataTypesLe { /// Message name space
 
 
     impl I16TorqueRaw  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "raw";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = -32768.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 32767.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 16;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 40;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(I16TorqueRaw {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            I16TorqueRaw {
                 status: CanDataStatus::Unset,
                 name:"I16TorqueRaw",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> i16 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:i16, data:&mut [u8]) -> Result<(),CanError> {
-            let value = u16::from_ne_bytes(value.to_ne_bytes());
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u16) -> i16 {
+            let shift = u16::BITS - 16u32;
+            let signed: i16 = ((raw << shift) as i16) >> shift;
+            signed
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: i16) -> u16 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 16 == 64 { u64::MAX } else { (1u64 << 16) - 1 };
+            //  Encode signed integer as two's complement on 16 bits.
+            ((value as i64 as u64) & __mask) as u16
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:i16, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < -32768_i16 || 32767_i16 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-32768..32767]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u16, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[40..56].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:i16, data:&mut [u8]) -> Result<(),CanError> {
+            if value < -32768_i16 || 32767_i16 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-32768..32767]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[40..56].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> i16 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[40..56].load_le::<u16>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:i16, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DataTypesLe payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DataTypesLe::I16TorqueRaw impl end
 
 
@@ -11580,37 +19190,37 @@ pub mod DataTypesLe { /// Message name space
     }
 
 
-    /// DataTypesLe::I16TorqueRaw public api (CanDbcSignal trait)
-    impl CanDbcSignal for I16TorqueRaw {
+/// DataTypesLe::I16TorqueRaw public api (CanDbcSignal trait)
+impl CanDbcSignal for I16TorqueRaw {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[40..56].load_le::<u16>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u16 = frame.data.view_bits::<Lsb0>()[40..56].load_le::<u16>();
 
-                    let value = i16::from_ne_bytes(value.to_ne_bytes());
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -11642,7 +19252,7 @@ pub mod DataTypesLe { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -11695,30 +19305,115 @@ pub mod DataTypesLe { /// Message name space
 
 
     impl U32TripM  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "m";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 4294967295.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 32;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 56;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(U32TripM {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            U32TripM {
                 status: CanDataStatus::Unset,
                 name:"U32TripM",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u32 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u32, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u32) -> u32 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u32) -> u32 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 32 == 64 { u64::MAX } else { (1u64 << 32) - 1 };
+            ((value as u64) & __mask) as u32
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u32, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u32 || 4294967295_u32 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..4294967295]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u32, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[56..88].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u32, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u32 || 4294967295_u32 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..4294967295]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[56..88].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u32 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[56..88].load_le::<u32>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u32, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DataTypesLe payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DataTypesLe::U32TripM impl end
 
 
@@ -11740,35 +19435,37 @@ pub mod DataTypesLe { /// Message name space
     }
 
 
-    /// DataTypesLe::U32TripM public api (CanDbcSignal trait)
-    impl CanDbcSignal for U32TripM {
+/// DataTypesLe::U32TripM public api (CanDbcSignal trait)
+impl CanDbcSignal for U32TripM {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u32 = frame.data.view_bits::<Lsb0>()[56..88].load_le::<u32>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[56..88].load_le::<u32>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -11800,7 +19497,7 @@ pub mod DataTypesLe { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -11853,31 +19550,118 @@ pub mod DataTypesLe { /// Message name space
 
 
     impl I32EnergyMWh  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "mWh";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = -2147483648.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 2147483647.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 32;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 88;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(I32EnergyMWh {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            I32EnergyMWh {
                 status: CanDataStatus::Unset,
                 name:"I32EnergyMWh",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> i32 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:i32, data:&mut [u8]) -> Result<(),CanError> {
-            let value = u32::from_ne_bytes(value.to_ne_bytes());
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u32) -> i32 {
+            let shift = u32::BITS - 32u32;
+            let signed: i32 = ((raw << shift) as i32) >> shift;
+            signed
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: i32) -> u32 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 32 == 64 { u64::MAX } else { (1u64 << 32) - 1 };
+            //  Encode signed integer as two's complement on 32 bits.
+            ((value as i64 as u64) & __mask) as u32
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:i32, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < -2147483648_i32 || 2147483647_i32 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-2147483648..2147483647]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u32, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[88..120].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:i32, data:&mut [u8]) -> Result<(),CanError> {
+            if value < -2147483648_i32 || 2147483647_i32 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-2147483648..2147483647]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[88..120].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> i32 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[88..120].load_le::<u32>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:i32, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DataTypesLe payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DataTypesLe::I32EnergyMWh impl end
 
 
@@ -11899,37 +19683,37 @@ pub mod DataTypesLe { /// Message name space
     }
 
 
-    /// DataTypesLe::I32EnergyMWh public api (CanDbcSignal trait)
-    impl CanDbcSignal for I32EnergyMWh {
+/// DataTypesLe::I32EnergyMWh public api (CanDbcSignal trait)
+impl CanDbcSignal for I32EnergyMWh {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[88..120].load_le::<u32>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u32 = frame.data.view_bits::<Lsb0>()[88..120].load_le::<u32>();
 
-                    let value = i32::from_ne_bytes(value.to_ne_bytes());
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -11961,7 +19745,7 @@ pub mod DataTypesLe { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -12014,30 +19798,115 @@ pub mod DataTypesLe { /// Message name space
 
 
     impl U64TimestampMs  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "ms";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 1.8446744073709552e19;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 64;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 120;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(U64TimestampMs {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            U64TimestampMs {
                 status: CanDataStatus::Unset,
                 name:"U64TimestampMs",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u64) -> u64 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u64) -> u64 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 64 == 64 { u64::MAX } else { (1u64 << 64) - 1 };
+            ((value as u64) & __mask) as u64
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u64 || u64::MAX < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..18446744073709552000]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u64, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[120..184].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u64 || u64::MAX < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..18446744073709552000]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[120..184].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[120..184].load_le::<u64>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DataTypesLe payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DataTypesLe::U64TimestampMs impl end
 
 
@@ -12059,35 +19928,37 @@ pub mod DataTypesLe { /// Message name space
     }
 
 
-    /// DataTypesLe::U64TimestampMs public api (CanDbcSignal trait)
-    impl CanDbcSignal for U64TimestampMs {
+/// DataTypesLe::U64TimestampMs public api (CanDbcSignal trait)
+impl CanDbcSignal for U64TimestampMs {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[120..184].load_le::<u64>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u64 = frame.data.view_bits::<Lsb0>()[120..184].load_le::<u64>();
 
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -12119,7 +19990,7 @@ pub mod DataTypesLe { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -12172,31 +20043,118 @@ pub mod DataTypesLe { /// Message name space
 
 
     impl I64BalanceNA  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "nA";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = -9.223372036854776e18;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 9.223372036854776e18;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 64;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 184;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(I64BalanceNA {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            I64BalanceNA {
                 status: CanDataStatus::Unset,
                 name:"I64BalanceNA",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> i64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:i64, data:&mut [u8]) -> Result<(),CanError> {
-            let value = u64::from_ne_bytes(value.to_ne_bytes());
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u64) -> i64 {
+            let shift = u64::BITS - 64u32;
+            let signed: i64 = ((raw << shift) as i64) >> shift;
+            signed
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: i64) -> u64 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 64 == 64 { u64::MAX } else { (1u64 << 64) - 1 };
+            //  Encode signed integer as two's complement on 64 bits.
+            ((value as i64 as u64) & __mask) as u64
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:i64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < -9223372036854775808_i64 || i64::MAX < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-9223372036854776000..9223372036854776000]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u64, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[184..248].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:i64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < -9223372036854775808_i64 || i64::MAX < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-9223372036854776000..9223372036854776000]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[184..248].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> i64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[184..248].load_le::<u64>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:i64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DataTypesLe payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DataTypesLe::I64BalanceNA impl end
 
 
@@ -12218,37 +20176,37 @@ pub mod DataTypesLe { /// Message name space
     }
 
 
-    /// DataTypesLe::I64BalanceNA public api (CanDbcSignal trait)
-    impl CanDbcSignal for I64BalanceNA {
+/// DataTypesLe::I64BalanceNA public api (CanDbcSignal trait)
+impl CanDbcSignal for I64BalanceNA {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[184..248].load_le::<u64>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u64 = frame.data.view_bits::<Lsb0>()[184..248].load_le::<u64>();
 
-                    let value = i64::from_ne_bytes(value.to_ne_bytes());
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -12280,7 +20238,7 @@ pub mod DataTypesLe { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -12315,6 +20273,7 @@ pub mod DataTypesLe { /// Message name space
         name: &'static str,
         status: CanBcmOpCode,
         listeners: i32,
+        signal_listeners: [i32;9],
         stamp: u64,
         id: u32,
     }
@@ -12326,6 +20285,7 @@ pub mod DataTypesLe { /// Message name space
                 name: "DataTypesLe",
                 status: CanBcmOpCode::Unknown,
                 listeners: 0,
+                signal_listeners: [0;9],
                 stamp: 0,
                 callback: None,
                 signals: [
@@ -12343,51 +20303,131 @@ pub mod DataTypesLe { /// Message name space
             })))
         }
 
+        /// Return code of each signal callback (`CanSigCtrl::sig_notification`) on the last
+        /// frame, in `DbcSignal` order: 0 without callback or for an inactive mux page, -1 when
+        /// the callback was busy. `get_listeners()` is their sum.
+        pub fn get_signal_listeners(&self) -> &[i32;9] {
+            &self.signal_listeners
+        }
+
+        /// Return code of the `signal` callback on the last frame, see `get_signal_listeners()`.
+        pub fn get_signal_listener(&self, signal: DbcSignal) -> i32 {
+            self.signal_listeners[signal as usize]
+        }
+
+        /// Index in `get_signals()` of the signal called `name`, its `get_name()` or DBC name.
+        pub fn signal_index(name: &str) -> Option<usize> {
+            match name {
+                "FlagReadyBool" | "flag_ready_bool" => Some(0),
+                "U8Counter" | "u8_counter" => Some(1),
+                "I8TempRaw" | "i8_temp_raw" => Some(2),
+                "U16OdometerDm" | "u16_odometer_dm" => Some(3),
+                "I16TorqueRaw" | "i16_torque_raw" => Some(4),
+                "U32TripM" | "u32_trip_m" => Some(5),
+                "I32EnergyMWh" | "i32_energy_mWh" => Some(6),
+                "U64TimestampMs" | "u64_timestamp_ms" => Some(7),
+                "I64BalanceNA" | "i64_balance_nA" => Some(8),
+                _ => None,
+            }
+        }
+
+        /// Signal called `name`, its `get_name()` or DBC name, without scanning the signals.
+        pub fn get_signal(&self, name: &str) -> Option<Rc<RefCell<Box<dyn CanDbcSignal>>>> {
+            Self::signal_index(name).map(|idx| Rc::clone(&self.signals[idx]))
+        }
+
+        /// Unit, range, scaling and layout of the signal called `name`, its `get_name()` or DBC
+        /// name, for generic tools holding a `dyn CanDbcSignal`.
+        pub fn signal_meta(name: &str) -> Option<super::SignalMeta> {
+            match name {
+                "FlagReadyBool" | "flag_ready_bool" => Some(FlagReadyBool::META),
+                "U8Counter" | "u8_counter" => Some(U8Counter::META),
+                "I8TempRaw" | "i8_temp_raw" => Some(I8TempRaw::META),
+                "U16OdometerDm" | "u16_odometer_dm" => Some(U16OdometerDm::META),
+                "I16TorqueRaw" | "i16_torque_raw" => Some(I16TorqueRaw::META),
+                "U32TripM" | "u32_trip_m" => Some(U32TripM::META),
+                "I32EnergyMWh" | "i32_energy_mWh" => Some(I32EnergyMWh::META),
+                "U64TimestampMs" | "u64_timestamp_ms" => Some(U64TimestampMs::META),
+                "I64BalanceNA" | "i64_balance_nA" => Some(I64BalanceNA::META),
+                _ => None,
+            }
+        }
+
+
+        /// CAN id without the extended flag, 11 or 29 bits
+        pub const CAN_ID: u32 = 0x190;
+        /// 29-bit id: `get_id()` and the BCM filter carry CAN_EFF_FLAG (bit 31), like Linux can_id
+        pub const EXTENDED: bool = false;
+
+        pub fn is_extended(&self) -> bool {
+            Self::EXTENDED
+        }
+
+        /// Transmission period from the GenMsgCycleTime attribute (or its default), 0 for a
+        /// message sent on event or without attribute
+        pub const CYCLE_TIME_MS: u32 = 500;
+
+        /// `CYCLE_TIME_MS`, base of the BCM rate and watchdog timers of the message
+        pub fn get_cycle_time_ms(&self) -> u32 {
+            Self::CYCLE_TIME_MS
+        }
+
+        /// Attributes (BA_) set on the message, by DBC attribute name
+        pub const ATTRIBUTES: [(&'static str, super::attributes::AttributeValue); 1] = [("GenMsgCycleTime", super::attributes::AttributeValue::Int(500))];
+
+        /// Value of the message attribute `name`, its BA_DEF_DEF_ default when not set
+        pub fn get_attribute(name: &str) -> Option<super::attributes::AttributeValue> {
+            match name {
+                "GenMsgCycleTime" => Some(super::attributes::AttributeValue::Int(500)),
+                "GenMsgSendType" => super::attributes::GEN_MSG_SEND_TYPE.default(),
+                _ => None,
+            }
+        }
 
         pub fn set_values(&mut self, flag_ready_bool: bool, u8_counter: u8, i8_temp_raw: i8, u16_odometer_dm: u16, i16_torque_raw: i16, u32_trip_m: u32, i32_energy_m_wh: i32, u64_timestamp_ms: u64, i64_balance_n_a: i64, frame: &mut[u8]) -> Result<&mut Self, CanError> {
 
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::Bool(flag_ready_bool), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error flag_ready_bool:Bool")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(u8_counter), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error u8_counter:U8")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::I8(i8_temp_raw), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error i8_temp_raw:I8")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U16(u16_odometer_dm), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error u16_odometer_dm:U16")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::I16(i16_torque_raw), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error i16_torque_raw:I16")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U32(u32_trip_m), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error u32_trip_m:U32")),
             }
 
-            match Rc::clone (&self.signals[6]).try_borrow_mut() {
+            match Rc::clone(&self.signals[6]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::I32(i32_energy_m_wh), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error i32_energy_m_wh:I32")),
             }
 
-            match Rc::clone (&self.signals[7]).try_borrow_mut() {
+            match Rc::clone(&self.signals[7]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U64(u64_timestamp_ms), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error u64_timestamp_ms:U64")),
             }
 
-            match Rc::clone (&self.signals[8]).try_borrow_mut() {
+            match Rc::clone(&self.signals[8]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::I64(i64_balance_n_a), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error i64_balance_n_a:I64")),
             }
@@ -12397,52 +20437,316 @@ pub mod DataTypesLe { /// Message name space
     }
 
 
+    /// `DataTypesLe(0x..) <opcode> stamp:<us> Signal:value ...`, for logs
+    impl fmt::Display for DbcMessage {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(fmt, "DataTypesLe({:#x}) {:?} stamp:{}", self.id, self.status, self.stamp)?;
+            match self.signals[0].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()) {
+                Some(value) => write!(fmt, " FlagReadyBool:{value}")?,
+                None => write!(fmt, " FlagReadyBool:?")?,
+            }
+            match self.signals[1].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " U8Counter:{value}")?,
+                None => write!(fmt, " U8Counter:?")?,
+            }
+            match self.signals[2].try_borrow().ok().and_then(|signal| signal.get_value().cast::<i8>().ok()) {
+                Some(value) => write!(fmt, " I8TempRaw:{value}")?,
+                None => write!(fmt, " I8TempRaw:?")?,
+            }
+            match self.signals[3].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u16>().ok()) {
+                Some(value) => write!(fmt, " U16OdometerDm:{value}")?,
+                None => write!(fmt, " U16OdometerDm:?")?,
+            }
+            match self.signals[4].try_borrow().ok().and_then(|signal| signal.get_value().cast::<i16>().ok()) {
+                Some(value) => write!(fmt, " I16TorqueRaw:{value}")?,
+                None => write!(fmt, " I16TorqueRaw:?")?,
+            }
+            match self.signals[5].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u32>().ok()) {
+                Some(value) => write!(fmt, " U32TripM:{value}")?,
+                None => write!(fmt, " U32TripM:?")?,
+            }
+            match self.signals[6].try_borrow().ok().and_then(|signal| signal.get_value().cast::<i32>().ok()) {
+                Some(value) => write!(fmt, " I32EnergyMWh:{value}")?,
+                None => write!(fmt, " I32EnergyMWh:?")?,
+            }
+            match self.signals[7].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u64>().ok()) {
+                Some(value) => write!(fmt, " U64TimestampMs:{value}")?,
+                None => write!(fmt, " U64TimestampMs:?")?,
+            }
+            match self.signals[8].try_borrow().ok().and_then(|signal| signal.get_value().cast::<i64>().ok()) {
+                Some(value) => write!(fmt, " I64BalanceNA:{value}")?,
+                None => write!(fmt, " I64BalanceNA:?")?,
+            }
+            Ok(())
+        }
+    }
+
+    impl fmt::Debug for DbcMessage {
+        fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {
+            format.debug_struct("DataTypesLe")
+                .field("id", &self.id)
+                .field("status", &self.status)
+                .field("stamp", &self.stamp)
+                .field("flag_ready_bool", &self.signals[0].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()))
+                .field("u8_counter", &self.signals[1].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("i8_temp_raw", &self.signals[2].try_borrow().ok().and_then(|signal| signal.get_value().cast::<i8>().ok()))
+                .field("u16_odometer_dm", &self.signals[3].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u16>().ok()))
+                .field("i16_torque_raw", &self.signals[4].try_borrow().ok().and_then(|signal| signal.get_value().cast::<i16>().ok()))
+                .field("u32_trip_m", &self.signals[5].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u32>().ok()))
+                .field("i32_energy_m_wh", &self.signals[6].try_borrow().ok().and_then(|signal| signal.get_value().cast::<i32>().ok()))
+                .field("u64_timestamp_ms", &self.signals[7].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u64>().ok()))
+                .field("i64_balance_n_a", &self.signals[8].try_borrow().ok().and_then(|signal| signal.get_value().cast::<i64>().ok()))
+                .finish()
+        }
+    }
+
+    impl Serialize for DbcMessage {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::MessageState(self).serialize(serializer)
+        }
+    }
+
+
+    /// Values of every DataTypesLe signal, see `DbcMessage::snapshot()`
+    #[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+    pub struct SignalValues {
+        pub flag_ready_bool: bool,
+        pub u8_counter: u8,
+        pub i8_temp_raw: i8,
+        pub u16_odometer_dm: u16,
+        pub i16_torque_raw: i16,
+        pub u32_trip_m: u32,
+        pub i32_energy_m_wh: i32,
+        pub u64_timestamp_ms: u64,
+        pub i64_balance_n_a: i64,
+    }
+
+    /// Copy of a DataTypesLe message taken at a point in time, holding no RefCell borrow
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub struct MessageSnapshot {
+        pub stamp: u64,
+        pub status: CanBcmOpCode,
+        pub values: SignalValues,
+    }
+
+    impl DbcMessage {
+        /// Copy the stamp, status and every signal value of the last decoded frame.
+        pub fn snapshot(&self) -> Result<MessageSnapshot, CanError> {
+            Ok(MessageSnapshot {
+                stamp: self.stamp,
+                status: self.status,
+                values: SignalValues {
+                    flag_ready_bool: match self.signals[0].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error flag_ready_bool")),
+                    },
+                    u8_counter: match self.signals[1].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error u8_counter")),
+                    },
+                    i8_temp_raw: match self.signals[2].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error i8_temp_raw")),
+                    },
+                    u16_odometer_dm: match self.signals[3].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error u16_odometer_dm")),
+                    },
+                    i16_torque_raw: match self.signals[4].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error i16_torque_raw")),
+                    },
+                    u32_trip_m: match self.signals[5].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error u32_trip_m")),
+                    },
+                    i32_energy_m_wh: match self.signals[6].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error i32_energy_m_wh")),
+                    },
+                    u64_timestamp_ms: match self.signals[7].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error u64_timestamp_ms")),
+                    },
+                    i64_balance_n_a: match self.signals[8].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error i64_balance_n_a")),
+                    },
+                },
+            })
+        }
+    }
+
+    /// Decode a DataTypesLe payload into its values, without signal object nor pool.
+    pub fn decode(data: &[u8]) -> Result<SignalValues, CanError> {
+        if data.len() < 64 {
+            return Err(CanError::new("signal-update-fail", format!("DataTypesLe payload len:{} shorter than 64 bytes", data.len())));
+        }
+        Ok(SignalValues {
+            flag_ready_bool: FlagReadyBool::decode(data),
+            u8_counter: U8Counter::decode(data),
+            i8_temp_raw: I8TempRaw::decode(data),
+            u16_odometer_dm: U16OdometerDm::decode(data),
+            i16_torque_raw: I16TorqueRaw::decode(data),
+            u32_trip_m: U32TripM::decode(data),
+            i32_energy_m_wh: I32EnergyMWh::decode(data),
+            u64_timestamp_ms: U64TimestampMs::decode(data),
+            i64_balance_n_a: I64BalanceNA::decode(data),
+        })
+    }
+
+    /// Encode `values` into a DataTypesLe payload, without signal object nor pool.
+    pub fn encode(values: &SignalValues, data: &mut [u8]) -> Result<(), CanError> {
+        if data.len() < 64 {
+            return Err(CanError::new("signal-set-values-fail", format!("DataTypesLe payload len:{} shorter than 64 bytes", data.len())));
+        }
+        FlagReadyBool::encode(values.flag_ready_bool, data)?;
+        U8Counter::encode(values.u8_counter, data)?;
+        I8TempRaw::encode(values.i8_temp_raw, data)?;
+        U16OdometerDm::encode(values.u16_odometer_dm, data)?;
+        I16TorqueRaw::encode(values.i16_torque_raw, data)?;
+        U32TripM::encode(values.u32_trip_m, data)?;
+        I32EnergyMWh::encode(values.i32_energy_m_wh, data)?;
+        U64TimestampMs::encode(values.u64_timestamp_ms, data)?;
+        I64BalanceNA::encode(values.i64_balance_n_a, data)?;
+        Ok(())
+    }
+
+    /// Typed builder of a DataTypesLe payload: `DataTypesLe::builder().signal(value).build(&mut data)?`.
+    /// Signals without setter call keep their GenSigStartValue, or their default.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct MessageBuilder {
+        values: SignalValues,
+    }
+
+    /// Start a DataTypesLe payload, see `MessageBuilder`.
+    pub fn builder() -> MessageBuilder {
+        MessageBuilder {
+            values: SignalValues::default(),
+        }
+    }
+
+    impl MessageBuilder {
+        /// FlagReadyBool physical value
+        #[must_use]
+        pub fn flag_ready_bool(mut self, value: bool) -> Self {
+            self.values.flag_ready_bool = value;
+            self
+        }
+
+        /// U8Counter physical value
+        #[must_use]
+        pub fn u8_counter(mut self, value: u8) -> Self {
+            self.values.u8_counter = value;
+            self
+        }
+
+        /// I8TempRaw physical value in raw
+        #[must_use]
+        pub fn i8_temp_raw(mut self, value: i8) -> Self {
+            self.values.i8_temp_raw = value;
+            self
+        }
+
+        /// U16OdometerDm physical value in dm
+        #[must_use]
+        pub fn u16_odometer_dm(mut self, value: u16) -> Self {
+            self.values.u16_odometer_dm = value;
+            self
+        }
+
+        /// I16TorqueRaw physical value in raw
+        #[must_use]
+        pub fn i16_torque_raw(mut self, value: i16) -> Self {
+            self.values.i16_torque_raw = value;
+            self
+        }
+
+        /// U32TripM physical value in m
+        #[must_use]
+        pub fn u32_trip_m(mut self, value: u32) -> Self {
+            self.values.u32_trip_m = value;
+            self
+        }
+
+        /// I32EnergyMWh physical value in mWh
+        #[must_use]
+        pub fn i32_energy_m_wh(mut self, value: i32) -> Self {
+            self.values.i32_energy_m_wh = value;
+            self
+        }
+
+        /// U64TimestampMs physical value in ms
+        #[must_use]
+        pub fn u64_timestamp_ms(mut self, value: u64) -> Self {
+            self.values.u64_timestamp_ms = value;
+            self
+        }
+
+        /// I64BalanceNA physical value in nA
+        #[must_use]
+        pub fn i64_balance_n_a(mut self, value: i64) -> Self {
+            self.values.i64_balance_n_a = value;
+            self
+        }
+
+        /// Values `build()` encodes.
+        pub fn get_values(&self) -> &SignalValues {
+            &self.values
+        }
+
+        /// Encode the values into `data`, see `encode()`.
+        pub fn build(&self, data: &mut [u8]) -> Result<(), CanError> {
+            encode(&self.values, data)
+        }
+    }
+
     impl CanDbcMessage for DbcMessage {
         fn reset(&mut self) -> Result<(), CanError> {
             self.status=CanBcmOpCode::Unknown;
             self.stamp=0;
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error flag_ready_bool:Bool")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error u8_counter:U8")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error i8_temp_raw:I8")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error u16_odometer_dm:U16")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error i16_torque_raw:I16")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error u32_trip_m:U32")),
             }
 
-            match Rc::clone (&self.signals[6]).try_borrow_mut() {
+            match Rc::clone(&self.signals[6]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error i32_energy_m_wh:I32")),
             }
 
-            match Rc::clone (&self.signals[7]).try_borrow_mut() {
+            match Rc::clone(&self.signals[7]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error u64_timestamp_ms:U64")),
             }
 
-            match Rc::clone (&self.signals[8]).try_borrow_mut() {
+            match Rc::clone(&self.signals[8]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error i64_balance_n_a:I64")),
             }
@@ -12454,49 +20758,50 @@ pub mod DataTypesLe { /// Message name space
             self.stamp= frame.stamp;
             self.status= frame.opcode;
             self.listeners= 0;
+            self.signal_listeners= [0;9];
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[0] = signal.update(frame); self.listeners += self.signal_listeners[0]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error flag_ready_bool:Bool")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[1] = signal.update(frame); self.listeners += self.signal_listeners[1]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error u8_counter:U8")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[2] = signal.update(frame); self.listeners += self.signal_listeners[2]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error i8_temp_raw:I8")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[3] = signal.update(frame); self.listeners += self.signal_listeners[3]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error u16_odometer_dm:U16")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[4] = signal.update(frame); self.listeners += self.signal_listeners[4]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error i16_torque_raw:I16")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[5] = signal.update(frame); self.listeners += self.signal_listeners[5]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error u32_trip_m:U32")),
             }
 
-            match Rc::clone (&self.signals[6]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[6]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[6] = signal.update(frame); self.listeners += self.signal_listeners[6]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error i32_energy_m_wh:I32")),
             }
 
-            match Rc::clone (&self.signals[7]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[7]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[7] = signal.update(frame); self.listeners += self.signal_listeners[7]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error u64_timestamp_ms:U64")),
             }
 
-            match Rc::clone (&self.signals[8]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[8]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[8] = signal.update(frame); self.listeners += self.signal_listeners[8]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error i64_balance_n_a:I64")),
             }
 
@@ -12504,7 +20809,7 @@ pub mod DataTypesLe { /// Message name space
                 None => {},
                 Some(callback) => {
                     match callback.try_borrow() {
-                        Err(_) => println!("fail to get message callback reference"),
+                        Err(_) => {println!("fail to get message callback reference");},
                         Ok(cb_ref) => cb_ref.msg_notification(self),
                     }
                 }
@@ -12566,6 +20871,9 @@ pub mod DataTypesBe { /// Message name space
     use std::fmt;
 
     use serde::{Deserialize, Serialize};
+    /// Nodes transmitting this message (BO_ and BO_TX_BU_)
+    pub const TRANSMITTERS: [&str; 1] = ["SENSOR_GATEWAY"];
+
     pub enum DbcSignal {
         FlagErrorBool,
         U8ModeBe,
@@ -12590,6 +20898,11 @@ pub mod DataTypesBe { /// Message name space
     /// - Offset: 0
     /// - Byte order: BigEndian
     /// - Value type: Unsigned
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | NO |
+    /// | 1 | YES |
     #[derive(Serialize, Deserialize)]
     pub struct FlagErrorBool {
         #[serde(skip)]
@@ -12602,6 +20915,12 @@ pub mod DataTypesBe { /// Message name space
     }
 
     // DBC definition for MsgID:401 Signal:flag_error_bool
+    /// FlagErrorBool values (DBC VAL_)
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | NO |
+    /// | 1 | YES |
     #[derive(Serialize, Deserialize)]
     pub enum DbcFlagErrorBool {
         No,
@@ -12623,14 +20942,47 @@ pub mod DataTypesBe { /// Message name space
 
 
     impl FlagErrorBool  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 1.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 1;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 0;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(FlagErrorBool {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            FlagErrorBool {
                 status: CanDataStatus::Unset,
                 name:"FlagErrorBool",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
@@ -12643,29 +20995,71 @@ pub mod DataTypesBe { /// Message name space
             }
 
         }
+        #[deprecated(note = "use set_raw()")]
         pub fn set_raw_value(&mut self, value: u8, data: &mut[u8]) {
-            data.view_bits_mut::<Msb0>()[7..8].store_be(value);
-
+            self.set_raw(value, data)
         }
         pub fn set_as_def (&mut self, signal_def: DbcFlagErrorBool, data: &mut[u8])-> Result<(),CanError> {
             match signal_def {
-                DbcFlagErrorBool::No => Ok(self.set_raw_value(0, data)),
-                DbcFlagErrorBool::Yes => Ok(self.set_raw_value(1, data)),
-                DbcFlagErrorBool::_Other(x) => self.set_typed_value(x,data)
+                DbcFlagErrorBool::No => Ok(self.set_raw(0, data)),
+                DbcFlagErrorBool::Yes => Ok(self.set_raw(1, data)),
+                DbcFlagErrorBool::_Other(x) => self.set_physical(x,data)
             }
         }
 
+        #[inline]
         fn get_typed_value(&self) -> bool {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
-            let value = value as u8;
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> bool {
+            raw == 1
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: bool) -> u8 {
+            value as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Msb0>()[7..8].store_be(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Msb0>()[7..8].store_be(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> bool {
+            Self::physical_from_raw(data.view_bits::<Msb0>()[7..8].load_be::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DataTypesBe payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DataTypesBe::FlagErrorBool impl end
 
 
@@ -12687,42 +21081,43 @@ pub mod DataTypesBe { /// Message name space
     }
 
 
-    /// DataTypesBe::FlagErrorBool public api (CanDbcSignal trait)
-    impl CanDbcSignal for FlagErrorBool {
+/// DataTypesBe::FlagErrorBool public api (CanDbcSignal trait)
+impl CanDbcSignal for FlagErrorBool {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Msb0>()[7..8].load_be::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Msb0>()[7..8].load_be::<u8>();
 
-                    let newval = value == 1;
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
                     };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                        self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -12748,7 +21143,7 @@ pub mod DataTypesBe { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -12801,30 +21196,115 @@ pub mod DataTypesBe { /// Message name space
 
 
     impl U8ModeBe  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 255.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 8;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(U8ModeBe {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            U8ModeBe {
                 status: CanDataStatus::Unset,
                 name:"U8ModeBe",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Msb0>()[15..23].store_be(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 255_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..255]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Msb0>()[15..23].store_be(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Msb0>()[15..23].load_be::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DataTypesBe payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DataTypesBe::U8ModeBe impl end
 
 
@@ -12846,35 +21326,37 @@ pub mod DataTypesBe { /// Message name space
     }
 
 
-    /// DataTypesBe::U8ModeBe public api (CanDbcSignal trait)
-    impl CanDbcSignal for U8ModeBe {
+/// DataTypesBe::U8ModeBe public api (CanDbcSignal trait)
+impl CanDbcSignal for U8ModeBe {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Msb0>()[15..23].load_be::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Msb0>()[15..23].load_be::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -12906,7 +21388,7 @@ pub mod DataTypesBe { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -12959,31 +21441,118 @@ pub mod DataTypesBe { /// Message name space
 
 
     impl I8DeltaBe  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = -128.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 127.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 16;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(I8DeltaBe {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            I8DeltaBe {
                 status: CanDataStatus::Unset,
                 name:"I8DeltaBe",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> i8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:i8, data:&mut [u8]) -> Result<(),CanError> {
-            let value = u8::from_ne_bytes(value.to_ne_bytes());
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> i8 {
+            let shift = u8::BITS - 8u32;
+            let signed: i8 = ((raw << shift) as i8) >> shift;
+            signed
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: i8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            //  Encode signed integer as two's complement on 8 bits.
+            ((value as i64 as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:i8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < -128_i8 || 127_i8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-128..127]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Msb0>()[23..31].store_be(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:i8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < -128_i8 || 127_i8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-128..127]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Msb0>()[23..31].store_be(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> i8 {
+            Self::physical_from_raw(data.view_bits::<Msb0>()[23..31].load_be::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:i8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DataTypesBe payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DataTypesBe::I8DeltaBe impl end
 
 
@@ -13005,37 +21574,37 @@ pub mod DataTypesBe { /// Message name space
     }
 
 
-    /// DataTypesBe::I8DeltaBe public api (CanDbcSignal trait)
-    impl CanDbcSignal for I8DeltaBe {
+/// DataTypesBe::I8DeltaBe public api (CanDbcSignal trait)
+impl CanDbcSignal for I8DeltaBe {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Msb0>()[23..31].load_be::<u8>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Msb0>()[23..31].load_be::<u8>();
 
-                    let value = i8::from_ne_bytes(value.to_ne_bytes());
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -13067,7 +21636,7 @@ pub mod DataTypesBe { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -13120,37 +21689,118 @@ pub mod DataTypesBe { /// Message name space
 
 
     impl U16PressureKPaBe  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "kPa";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 32767.5;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 0.5;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 16;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 24;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(U16PressureKPaBe {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            U16PressureKPaBe {
                 status: CanDataStatus::Unset,
                 name:"U16PressureKPaBe",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u16) -> f64 {
+            (raw as f64) * 0.5_f64 + 0_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u16 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 16 == 64 { u64::MAX } else { (1u64 << 16) - 1 };
+            let factor = 0.5_f64;
+            let offset = 0_f64;
+            let __raw_f = (value - offset) / factor;
+            ((__raw_f as u64) & __mask) as u16
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < 0_f64 || 32767.5_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..32767.5]",value)));
             }
-            let factor = 0.5_f64;
-            let offset = 0_f64;
-            let value = ((value - offset) / factor) as u16;
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u16, data: &mut[u8]) {
             data.view_bits_mut::<Msb0>()[31..47].store_be(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_f64 || 32767.5_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..32767.5]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Msb0>()[31..47].store_be(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Msb0>()[31..47].load_be::<u16>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DataTypesBe payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DataTypesBe::U16PressureKPaBe impl end
 
 
@@ -13172,44 +21822,43 @@ pub mod DataTypesBe { /// Message name space
     }
 
 
-    /// DataTypesBe::U16PressureKPaBe public api (CanDbcSignal trait)
-    impl CanDbcSignal for U16PressureKPaBe {
+/// DataTypesBe::U16PressureKPaBe public api (CanDbcSignal trait)
+impl CanDbcSignal for U16PressureKPaBe {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Msb0>()[31..47].load_be::<u16>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u16 = frame.data.view_bits::<Msb0>()[31..47].load_be::<u16>();
 
-                    let factor = 0.5_f64;
-                    let offset = 0_f64;
-                    let newval = (value as f64) * factor + offset;
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -13235,7 +21884,7 @@ pub mod DataTypesBe { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -13288,38 +21937,122 @@ pub mod DataTypesBe { /// Message name space
 
 
     impl I16RateDpsBe  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "deg/s";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = -327.68;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 327.67;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 0.01;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 16;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 40;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(I16RateDpsBe {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            I16RateDpsBe {
                 status: CanDataStatus::Unset,
                 name:"I16RateDpsBe",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u16) -> f64 {
+            // Sign-extend raw from the DBC signal bit-width before scaling.
+            let shift = u16::BITS - 16u32;
+            let signed: i16 = ((raw << shift) as i16) >> shift;
+            (signed as f64) * 0.01_f64 + 0_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u16 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 16 == 64 { u64::MAX } else { (1u64 << 16) - 1 };
+            let factor = 0.01_f64;
+            let offset = 0_f64;
+            let __raw_f = (value - offset) / factor;
+            //  Encode signed value as two's complement on 16 bits.
+            ((__raw_f as i64 as u64) & __mask) as u16
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < -327.68_f64 || 327.67_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [-327.68..327.67]",value)));
             }
-            let factor = 0.01_f64;
-            let offset = 0_f64;
-            let value = ((value - offset) / factor) as u16;
-            let value = u16::from_ne_bytes(value.to_ne_bytes());
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u16, data: &mut[u8]) {
             data.view_bits_mut::<Msb0>()[47..63].store_be(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < -327.68_f64 || 327.67_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-327.68..327.67]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Msb0>()[47..63].store_be(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Msb0>()[47..63].load_be::<u16>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DataTypesBe payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DataTypesBe::I16RateDpsBe impl end
 
 
@@ -13341,46 +22074,43 @@ pub mod DataTypesBe { /// Message name space
     }
 
 
-    /// DataTypesBe::I16RateDpsBe public api (CanDbcSignal trait)
-    impl CanDbcSignal for I16RateDpsBe {
+/// DataTypesBe::I16RateDpsBe public api (CanDbcSignal trait)
+impl CanDbcSignal for I16RateDpsBe {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Msb0>()[47..63].load_be::<u16>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u16 = frame.data.view_bits::<Msb0>()[47..63].load_be::<u16>();
 
-                    let value = i16::from_ne_bytes(value.to_ne_bytes());
 
-                    let factor = 0.01_f64;
-                    let offset = 0_f64;
-                    let newval = (value as f64) * factor + offset;
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -13406,7 +22136,7 @@ pub mod DataTypesBe { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -13459,30 +22189,115 @@ pub mod DataTypesBe { /// Message name space
 
 
     impl U32CrcBe  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 4294967295.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 32;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 56;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(U32CrcBe {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            U32CrcBe {
                 status: CanDataStatus::Unset,
                 name:"U32CrcBe",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u32 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u32, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u32) -> u32 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u32) -> u32 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 32 == 64 { u64::MAX } else { (1u64 << 32) - 1 };
+            ((value as u64) & __mask) as u32
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u32, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u32 || 4294967295_u32 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..4294967295]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u32, data: &mut[u8]) {
             data.view_bits_mut::<Msb0>()[63..95].store_be(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u32, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u32 || 4294967295_u32 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..4294967295]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Msb0>()[63..95].store_be(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u32 {
+            Self::physical_from_raw(data.view_bits::<Msb0>()[63..95].load_be::<u32>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u32, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DataTypesBe payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DataTypesBe::U32CrcBe impl end
 
 
@@ -13504,35 +22319,37 @@ pub mod DataTypesBe { /// Message name space
     }
 
 
-    /// DataTypesBe::U32CrcBe public api (CanDbcSignal trait)
-    impl CanDbcSignal for U32CrcBe {
+/// DataTypesBe::U32CrcBe public api (CanDbcSignal trait)
+impl CanDbcSignal for U32CrcBe {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u32 = frame.data.view_bits::<Msb0>()[63..95].load_be::<u32>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Msb0>()[63..95].load_be::<u32>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -13564,7 +22381,7 @@ pub mod DataTypesBe { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -13617,31 +22434,118 @@ pub mod DataTypesBe { /// Message name space
 
 
     impl I32PosMmBe  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "mm";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = -2147483648.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 2147483647.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 32;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 88;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(I32PosMmBe {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            I32PosMmBe {
                 status: CanDataStatus::Unset,
                 name:"I32PosMmBe",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> i32 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:i32, data:&mut [u8]) -> Result<(),CanError> {
-            let value = u32::from_ne_bytes(value.to_ne_bytes());
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u32) -> i32 {
+            let shift = u32::BITS - 32u32;
+            let signed: i32 = ((raw << shift) as i32) >> shift;
+            signed
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: i32) -> u32 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 32 == 64 { u64::MAX } else { (1u64 << 32) - 1 };
+            //  Encode signed integer as two's complement on 32 bits.
+            ((value as i64 as u64) & __mask) as u32
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:i32, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < -2147483648_i32 || 2147483647_i32 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-2147483648..2147483647]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u32, data: &mut[u8]) {
             data.view_bits_mut::<Msb0>()[95..127].store_be(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:i32, data:&mut [u8]) -> Result<(),CanError> {
+            if value < -2147483648_i32 || 2147483647_i32 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-2147483648..2147483647]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Msb0>()[95..127].store_be(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> i32 {
+            Self::physical_from_raw(data.view_bits::<Msb0>()[95..127].load_be::<u32>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:i32, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DataTypesBe payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DataTypesBe::I32PosMmBe impl end
 
 
@@ -13663,37 +22567,37 @@ pub mod DataTypesBe { /// Message name space
     }
 
 
-    /// DataTypesBe::I32PosMmBe public api (CanDbcSignal trait)
-    impl CanDbcSignal for I32PosMmBe {
+/// DataTypesBe::I32PosMmBe public api (CanDbcSignal trait)
+impl CanDbcSignal for I32PosMmBe {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Msb0>()[95..127].load_be::<u32>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u32 = frame.data.view_bits::<Msb0>()[95..127].load_be::<u32>();
 
-                    let value = i32::from_ne_bytes(value.to_ne_bytes());
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -13725,7 +22629,7 @@ pub mod DataTypesBe { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -13778,30 +22682,115 @@ pub mod DataTypesBe { /// Message name space
 
 
     impl U64TripHashBe  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["LOGGER"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 1.8446744073709552e19;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 64;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 120;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(U64TripHashBe {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            U64TripHashBe {
                 status: CanDataStatus::Unset,
                 name:"U64TripHashBe",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> u64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u64) -> u64 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u64) -> u64 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 64 == 64 { u64::MAX } else { (1u64 << 64) - 1 };
+            ((value as u64) & __mask) as u64
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u64 || u64::MAX < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..18446744073709552000]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u64, data: &mut[u8]) {
             data.view_bits_mut::<Msb0>()[127..191].store_be(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u64 || u64::MAX < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..18446744073709552000]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Msb0>()[127..191].store_be(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u64 {
+            Self::physical_from_raw(data.view_bits::<Msb0>()[127..191].load_be::<u64>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 64 {
+                return Err(CanError::new("signal-set-values-fail", format!("DataTypesBe payload len:{} shorter than 64 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // DataTypesBe::U64TripHashBe impl end
 
 
@@ -13823,35 +22812,37 @@ pub mod DataTypesBe { /// Message name space
     }
 
 
-    /// DataTypesBe::U64TripHashBe public api (CanDbcSignal trait)
-    impl CanDbcSignal for U64TripHashBe {
+/// DataTypesBe::U64TripHashBe public api (CanDbcSignal trait)
+impl CanDbcSignal for U64TripHashBe {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u64 = frame.data.view_bits::<Msb0>()[127..191].load_be::<u64>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Msb0>()[127..191].load_be::<u64>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -13883,7 +22874,7 @@ pub mod DataTypesBe { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -13918,6 +22909,7 @@ pub mod DataTypesBe { /// Message name space
         name: &'static str,
         status: CanBcmOpCode,
         listeners: i32,
+        signal_listeners: [i32;8],
         stamp: u64,
         id: u32,
     }
@@ -13929,6 +22921,7 @@ pub mod DataTypesBe { /// Message name space
                 name: "DataTypesBe",
                 status: CanBcmOpCode::Unknown,
                 listeners: 0,
+                signal_listeners: [0;8],
                 stamp: 0,
                 callback: None,
                 signals: [
@@ -13945,46 +22938,124 @@ pub mod DataTypesBe { /// Message name space
             })))
         }
 
+        /// Return code of each signal callback (`CanSigCtrl::sig_notification`) on the last
+        /// frame, in `DbcSignal` order: 0 without callback or for an inactive mux page, -1 when
+        /// the callback was busy. `get_listeners()` is their sum.
+        pub fn get_signal_listeners(&self) -> &[i32;8] {
+            &self.signal_listeners
+        }
+
+        /// Return code of the `signal` callback on the last frame, see `get_signal_listeners()`.
+        pub fn get_signal_listener(&self, signal: DbcSignal) -> i32 {
+            self.signal_listeners[signal as usize]
+        }
+
+        /// Index in `get_signals()` of the signal called `name`, its `get_name()` or DBC name.
+        pub fn signal_index(name: &str) -> Option<usize> {
+            match name {
+                "FlagErrorBool" | "flag_error_bool" => Some(0),
+                "U8ModeBe" | "u8_mode_be" => Some(1),
+                "I8DeltaBe" | "i8_delta_be" => Some(2),
+                "U16PressureKPaBe" | "u16_pressure_kPa_be" => Some(3),
+                "I16RateDpsBe" | "i16_rate_dps_be" => Some(4),
+                "U32CrcBe" | "u32_crc_be" => Some(5),
+                "I32PosMmBe" | "i32_pos_mm_be" => Some(6),
+                "U64TripHashBe" | "u64_trip_hash_be" => Some(7),
+                _ => None,
+            }
+        }
+
+        /// Signal called `name`, its `get_name()` or DBC name, without scanning the signals.
+        pub fn get_signal(&self, name: &str) -> Option<Rc<RefCell<Box<dyn CanDbcSignal>>>> {
+            Self::signal_index(name).map(|idx| Rc::clone(&self.signals[idx]))
+        }
+
+        /// Unit, range, scaling and layout of the signal called `name`, its `get_name()` or DBC
+        /// name, for generic tools holding a `dyn CanDbcSignal`.
+        pub fn signal_meta(name: &str) -> Option<super::SignalMeta> {
+            match name {
+                "FlagErrorBool" | "flag_error_bool" => Some(FlagErrorBool::META),
+                "U8ModeBe" | "u8_mode_be" => Some(U8ModeBe::META),
+                "I8DeltaBe" | "i8_delta_be" => Some(I8DeltaBe::META),
+                "U16PressureKPaBe" | "u16_pressure_kPa_be" => Some(U16PressureKPaBe::META),
+                "I16RateDpsBe" | "i16_rate_dps_be" => Some(I16RateDpsBe::META),
+                "U32CrcBe" | "u32_crc_be" => Some(U32CrcBe::META),
+                "I32PosMmBe" | "i32_pos_mm_be" => Some(I32PosMmBe::META),
+                "U64TripHashBe" | "u64_trip_hash_be" => Some(U64TripHashBe::META),
+                _ => None,
+            }
+        }
+
+
+        /// CAN id without the extended flag, 11 or 29 bits
+        pub const CAN_ID: u32 = 0x191;
+        /// 29-bit id: `get_id()` and the BCM filter carry CAN_EFF_FLAG (bit 31), like Linux can_id
+        pub const EXTENDED: bool = false;
+
+        pub fn is_extended(&self) -> bool {
+            Self::EXTENDED
+        }
+
+        /// Transmission period from the GenMsgCycleTime attribute (or its default), 0 for a
+        /// message sent on event or without attribute
+        pub const CYCLE_TIME_MS: u32 = 500;
+
+        /// `CYCLE_TIME_MS`, base of the BCM rate and watchdog timers of the message
+        pub fn get_cycle_time_ms(&self) -> u32 {
+            Self::CYCLE_TIME_MS
+        }
+
+        /// Attributes (BA_) set on the message, by DBC attribute name
+        pub const ATTRIBUTES: [(&'static str, super::attributes::AttributeValue); 1] = [("GenMsgCycleTime", super::attributes::AttributeValue::Int(500))];
+
+        /// Value of the message attribute `name`, its BA_DEF_DEF_ default when not set
+        pub fn get_attribute(name: &str) -> Option<super::attributes::AttributeValue> {
+            match name {
+                "GenMsgCycleTime" => Some(super::attributes::AttributeValue::Int(500)),
+                "GenMsgSendType" => super::attributes::GEN_MSG_SEND_TYPE.default(),
+                _ => None,
+            }
+        }
 
         pub fn set_values(&mut self, flag_error_bool: bool, u8_mode_be: u8, i8_delta_be: i8, u16_pressure_k_pa_be: f64, i16_rate_dps_be: f64, u32_crc_be: u32, i32_pos_mm_be: i32, u64_trip_hash_be: u64, frame: &mut[u8]) -> Result<&mut Self, CanError> {
 
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::Bool(flag_error_bool), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error flag_error_bool:Bool")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(u8_mode_be), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error u8_mode_be:U8")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::I8(i8_delta_be), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error i8_delta_be:I8")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::F64(u16_pressure_k_pa_be), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error u16_pressure_k_pa_be:F64")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::F64(i16_rate_dps_be), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error i16_rate_dps_be:F64")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U32(u32_crc_be), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error u32_crc_be:U32")),
             }
 
-            match Rc::clone (&self.signals[6]).try_borrow_mut() {
+            match Rc::clone(&self.signals[6]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::I32(i32_pos_mm_be), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error i32_pos_mm_be:I32")),
             }
 
-            match Rc::clone (&self.signals[7]).try_borrow_mut() {
+            match Rc::clone(&self.signals[7]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U64(u64_trip_hash_be), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error u64_trip_hash_be:U64")),
             }
@@ -13994,47 +23065,292 @@ pub mod DataTypesBe { /// Message name space
     }
 
 
+    /// `DataTypesBe(0x..) <opcode> stamp:<us> Signal:value ...`, for logs
+    impl fmt::Display for DbcMessage {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(fmt, "DataTypesBe({:#x}) {:?} stamp:{}", self.id, self.status, self.stamp)?;
+            match self.signals[0].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()) {
+                Some(value) => write!(fmt, " FlagErrorBool:{value}")?,
+                None => write!(fmt, " FlagErrorBool:?")?,
+            }
+            match self.signals[1].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " U8ModeBe:{value}")?,
+                None => write!(fmt, " U8ModeBe:?")?,
+            }
+            match self.signals[2].try_borrow().ok().and_then(|signal| signal.get_value().cast::<i8>().ok()) {
+                Some(value) => write!(fmt, " I8DeltaBe:{value}")?,
+                None => write!(fmt, " I8DeltaBe:?")?,
+            }
+            match self.signals[3].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " U16PressureKPaBe:{value}")?,
+                None => write!(fmt, " U16PressureKPaBe:?")?,
+            }
+            match self.signals[4].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " I16RateDpsBe:{value}")?,
+                None => write!(fmt, " I16RateDpsBe:?")?,
+            }
+            match self.signals[5].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u32>().ok()) {
+                Some(value) => write!(fmt, " U32CrcBe:{value}")?,
+                None => write!(fmt, " U32CrcBe:?")?,
+            }
+            match self.signals[6].try_borrow().ok().and_then(|signal| signal.get_value().cast::<i32>().ok()) {
+                Some(value) => write!(fmt, " I32PosMmBe:{value}")?,
+                None => write!(fmt, " I32PosMmBe:?")?,
+            }
+            match self.signals[7].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u64>().ok()) {
+                Some(value) => write!(fmt, " U64TripHashBe:{value}")?,
+                None => write!(fmt, " U64TripHashBe:?")?,
+            }
+            Ok(())
+        }
+    }
+
+    impl fmt::Debug for DbcMessage {
+        fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {
+            format.debug_struct("DataTypesBe")
+                .field("id", &self.id)
+                .field("status", &self.status)
+                .field("stamp", &self.stamp)
+                .field("flag_error_bool", &self.signals[0].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()))
+                .field("u8_mode_be", &self.signals[1].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("i8_delta_be", &self.signals[2].try_borrow().ok().and_then(|signal| signal.get_value().cast::<i8>().ok()))
+                .field("u16_pressure_k_pa_be", &self.signals[3].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .field("i16_rate_dps_be", &self.signals[4].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .field("u32_crc_be", &self.signals[5].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u32>().ok()))
+                .field("i32_pos_mm_be", &self.signals[6].try_borrow().ok().and_then(|signal| signal.get_value().cast::<i32>().ok()))
+                .field("u64_trip_hash_be", &self.signals[7].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u64>().ok()))
+                .finish()
+        }
+    }
+
+    impl Serialize for DbcMessage {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::MessageState(self).serialize(serializer)
+        }
+    }
+
+
+    /// Values of every DataTypesBe signal, see `DbcMessage::snapshot()`
+    #[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+    pub struct SignalValues {
+        pub flag_error_bool: bool,
+        pub u8_mode_be: u8,
+        pub i8_delta_be: i8,
+        pub u16_pressure_k_pa_be: f64,
+        pub i16_rate_dps_be: f64,
+        pub u32_crc_be: u32,
+        pub i32_pos_mm_be: i32,
+        pub u64_trip_hash_be: u64,
+    }
+
+    /// Copy of a DataTypesBe message taken at a point in time, holding no RefCell borrow
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub struct MessageSnapshot {
+        pub stamp: u64,
+        pub status: CanBcmOpCode,
+        pub values: SignalValues,
+    }
+
+    impl DbcMessage {
+        /// Copy the stamp, status and every signal value of the last decoded frame.
+        pub fn snapshot(&self) -> Result<MessageSnapshot, CanError> {
+            Ok(MessageSnapshot {
+                stamp: self.stamp,
+                status: self.status,
+                values: SignalValues {
+                    flag_error_bool: match self.signals[0].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error flag_error_bool")),
+                    },
+                    u8_mode_be: match self.signals[1].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error u8_mode_be")),
+                    },
+                    i8_delta_be: match self.signals[2].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error i8_delta_be")),
+                    },
+                    u16_pressure_k_pa_be: match self.signals[3].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error u16_pressure_k_pa_be")),
+                    },
+                    i16_rate_dps_be: match self.signals[4].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error i16_rate_dps_be")),
+                    },
+                    u32_crc_be: match self.signals[5].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error u32_crc_be")),
+                    },
+                    i32_pos_mm_be: match self.signals[6].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error i32_pos_mm_be")),
+                    },
+                    u64_trip_hash_be: match self.signals[7].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error u64_trip_hash_be")),
+                    },
+                },
+            })
+        }
+    }
+
+    /// Decode a DataTypesBe payload into its values, without signal object nor pool.
+    pub fn decode(data: &[u8]) -> Result<SignalValues, CanError> {
+        if data.len() < 64 {
+            return Err(CanError::new("signal-update-fail", format!("DataTypesBe payload len:{} shorter than 64 bytes", data.len())));
+        }
+        Ok(SignalValues {
+            flag_error_bool: FlagErrorBool::decode(data),
+            u8_mode_be: U8ModeBe::decode(data),
+            i8_delta_be: I8DeltaBe::decode(data),
+            u16_pressure_k_pa_be: U16PressureKPaBe::decode(data),
+            i16_rate_dps_be: I16RateDpsBe::decode(data),
+            u32_crc_be: U32CrcBe::decode(data),
+            i32_pos_mm_be: I32PosMmBe::decode(data),
+            u64_trip_hash_be: U64TripHashBe::decode(data),
+        })
+    }
+
+    /// Encode `values` into a DataTypesBe payload, without signal object nor pool.
+    pub fn encode(values: &SignalValues, data: &mut [u8]) -> Result<(), CanError> {
+        if data.len() < 64 {
+            return Err(CanError::new("signal-set-values-fail", format!("DataTypesBe payload len:{} shorter than 64 bytes", data.len())));
+        }
+        FlagErrorBool::encode(values.flag_error_bool, data)?;
+        U8ModeBe::encode(values.u8_mode_be, data)?;
+        I8DeltaBe::encode(values.i8_delta_be, data)?;
+        U16PressureKPaBe::encode(values.u16_pressure_k_pa_be, data)?;
+        I16RateDpsBe::encode(values.i16_rate_dps_be, data)?;
+        U32CrcBe::encode(values.u32_crc_be, data)?;
+        I32PosMmBe::encode(values.i32_pos_mm_be, data)?;
+        U64TripHashBe::encode(values.u64_trip_hash_be, data)?;
+        Ok(())
+    }
+
+    /// Typed builder of a DataTypesBe payload: `DataTypesBe::builder().signal(value).build(&mut data)?`.
+    /// Signals without setter call keep their GenSigStartValue, or their default.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct MessageBuilder {
+        values: SignalValues,
+    }
+
+    /// Start a DataTypesBe payload, see `MessageBuilder`.
+    pub fn builder() -> MessageBuilder {
+        MessageBuilder {
+            values: SignalValues::default(),
+        }
+    }
+
+    impl MessageBuilder {
+        /// FlagErrorBool physical value
+        #[must_use]
+        pub fn flag_error_bool(mut self, value: bool) -> Self {
+            self.values.flag_error_bool = value;
+            self
+        }
+
+        /// U8ModeBe physical value
+        #[must_use]
+        pub fn u8_mode_be(mut self, value: u8) -> Self {
+            self.values.u8_mode_be = value;
+            self
+        }
+
+        /// I8DeltaBe physical value
+        #[must_use]
+        pub fn i8_delta_be(mut self, value: i8) -> Self {
+            self.values.i8_delta_be = value;
+            self
+        }
+
+        /// U16PressureKPaBe physical value in kPa
+        #[must_use]
+        pub fn u16_pressure_k_pa_be(mut self, value: f64) -> Self {
+            self.values.u16_pressure_k_pa_be = value;
+            self
+        }
+
+        /// I16RateDpsBe physical value in deg/s
+        #[must_use]
+        pub fn i16_rate_dps_be(mut self, value: f64) -> Self {
+            self.values.i16_rate_dps_be = value;
+            self
+        }
+
+        /// U32CrcBe physical value
+        #[must_use]
+        pub fn u32_crc_be(mut self, value: u32) -> Self {
+            self.values.u32_crc_be = value;
+            self
+        }
+
+        /// I32PosMmBe physical value in mm
+        #[must_use]
+        pub fn i32_pos_mm_be(mut self, value: i32) -> Self {
+            self.values.i32_pos_mm_be = value;
+            self
+        }
+
+        /// U64TripHashBe physical value
+        #[must_use]
+        pub fn u64_trip_hash_be(mut self, value: u64) -> Self {
+            self.values.u64_trip_hash_be = value;
+            self
+        }
+
+        /// Values `build()` encodes.
+        pub fn get_values(&self) -> &SignalValues {
+            &self.values
+        }
+
+        /// Encode the values into `data`, see `encode()`.
+        pub fn build(&self, data: &mut [u8]) -> Result<(), CanError> {
+            encode(&self.values, data)
+        }
+    }
+
     impl CanDbcMessage for DbcMessage {
         fn reset(&mut self) -> Result<(), CanError> {
             self.status=CanBcmOpCode::Unknown;
             self.stamp=0;
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error flag_error_bool:Bool")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error u8_mode_be:U8")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error i8_delta_be:I8")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error u16_pressure_k_pa_be:F64")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error i16_rate_dps_be:F64")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error u32_crc_be:U32")),
             }
 
-            match Rc::clone (&self.signals[6]).try_borrow_mut() {
+            match Rc::clone(&self.signals[6]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error i32_pos_mm_be:I32")),
             }
 
-            match Rc::clone (&self.signals[7]).try_borrow_mut() {
+            match Rc::clone(&self.signals[7]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error u64_trip_hash_be:U64")),
             }
@@ -14046,44 +23362,45 @@ pub mod DataTypesBe { /// Message name space
             self.stamp= frame.stamp;
             self.status= frame.opcode;
             self.listeners= 0;
+            self.signal_listeners= [0;8];
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[0] = signal.update(frame); self.listeners += self.signal_listeners[0]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error flag_error_bool:Bool")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[1] = signal.update(frame); self.listeners += self.signal_listeners[1]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error u8_mode_be:U8")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[2] = signal.update(frame); self.listeners += self.signal_listeners[2]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error i8_delta_be:I8")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[3] = signal.update(frame); self.listeners += self.signal_listeners[3]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error u16_pressure_k_pa_be:F64")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[4] = signal.update(frame); self.listeners += self.signal_listeners[4]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error i16_rate_dps_be:F64")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[5] = signal.update(frame); self.listeners += self.signal_listeners[5]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error u32_crc_be:U32")),
             }
 
-            match Rc::clone (&self.signals[6]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[6]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[6] = signal.update(frame); self.listeners += self.signal_listeners[6]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error i32_pos_mm_be:I32")),
             }
 
-            match Rc::clone (&self.signals[7]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[7]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[7] = signal.update(frame); self.listeners += self.signal_listeners[7]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error u64_trip_hash_be:U64")),
             }
 
@@ -14091,7 +23408,7 @@ pub mod DataTypesBe { /// Message name space
                 None => {},
                 Some(callback) => {
                     match callback.try_borrow() {
-                        Err(_) => println!("fail to get message callback reference"),
+                        Err(_) => {println!("fail to get message callback reference");},
                         Ok(cb_ref) => cb_ref.msg_notification(self),
                     }
                 }
@@ -14135,6 +23452,59 @@ pub mod DataTypesBe { /// Message name space
 
 } // end DataTypesBe message
 
+
+/// Network nodes (BU_) with the messages they transmit and the signals they consume
+pub mod nodes {
+    pub mod BodyCtrl {
+        pub const NAME: &str = "BODY_CTRL";
+        pub const TRANSMITS: [u32; 1] = [100];
+        /// (canid, signal) consumed by this node
+        pub const CONSUMES: [(u32, &str); 0] = [];
+    }
+    pub mod PowertrainCtrl {
+        pub const NAME: &str = "POWERTRAIN_CTRL";
+        pub const TRANSMITS: [u32; 1] = [200];
+        /// (canid, signal) consumed by this node
+        pub const CONSUMES: [(u32, &str); 5] = [(101, "PackVoltageV"), (101, "PackCurrentA"), (101, "SocPercent"), (101, "SohPercent"), (101, "FaultLevel")];
+    }
+    pub mod EnergyCtrl {
+        pub const NAME: &str = "ENERGY_CTRL";
+        pub const TRANSMITS: [u32; 1] = [101];
+        /// (canid, signal) consumed by this node
+        pub const CONSUMES: [(u32, &str); 0] = [];
+    }
+    pub mod ChassisCtrl {
+        pub const NAME: &str = "CHASSIS_CTRL";
+        pub const TRANSMITS: [u32; 1] = [210];
+        /// (canid, signal) consumed by this node
+        pub const CONSUMES: [(u32, &str); 2] = [(100, "VehicleSpeedKph"), (100, "SteeringAngleDeg")];
+    }
+    pub mod InfotainmentCtrl {
+        pub const NAME: &str = "INFOTAINMENT_CTRL";
+        pub const TRANSMITS: [u32; 0] = [];
+        /// (canid, signal) consumed by this node
+        pub const CONSUMES: [(u32, &str); 6] = [(100, "IgnitionState"), (100, "GearPosition"), (100, "DoorFlOpen"), (100, "DoorFrOpen"), (100, "DoorRlOpen"), (100, "DoorRrOpen")];
+    }
+    pub mod DiagTool {
+        pub const NAME: &str = "DIAG_TOOL";
+        pub const TRANSMITS: [u32; 1] = [300];
+        /// (canid, signal) consumed by this node
+        pub const CONSUMES: [(u32, &str); 1] = [(101, "IsolationKohmBe")];
+    }
+    pub mod SensorGateway {
+        pub const NAME: &str = "SENSOR_GATEWAY";
+        pub const TRANSMITS: [u32; 2] = [400, 401];
+        /// (canid, signal) consumed by this node
+        pub const CONSUMES: [(u32, &str); 1] = [(100, "AmbientTempDegC")];
+    }
+    pub mod Logger {
+        pub const NAME: &str = "LOGGER";
+        pub const TRANSMITS: [u32; 0] = [];
+        /// (canid, signal) consumed by this node
+        pub const CONSUMES: [(u32, &str); 57] = [(200, "PtMux"), (200, "AliveCounter"), (200, "Checksum"), (200, "EngineRpm"), (200, "ThrottlePosPercent"), (200, "FuelRateLph"), (200, "MotorTorqueNm"), (200, "MotorSpeedRpm"), (200, "InverterTempDegC"), (200, "DcBusVoltageV"), (200, "DcBusCurrentA"), (200, "RegenEnabled"), (200, "TorqueLimitActive"), (200, "DriverMode"), (210, "AbsActive"), (210, "EscActive"), (210, "BrakePressureBar"), (210, "YawRateDps"), (210, "LatAccelMps2"), (210, "WheelFlKph"), (300, "DiagServiceId"), (300, "DiagSubfunction"), (300, "DiagStatus"), (300, "PayloadLen"), (300, "PayloadByte0"), (300, "PayloadByte1"), (300, "PayloadByte2"), (300, "PayloadByte3"), (300, "PayloadByte4"), (300, "PayloadByte5"), (300, "PayloadByte6"), (300, "PayloadByte7"), (300, "PayloadByte8"), (300, "PayloadByte9"), (300, "PayloadByte10"), (300, "PayloadByte11"), (300, "PayloadByte12"), (300, "PayloadByte13"), (300, "PayloadByte14"), (300, "PayloadByte15"), (400, "FlagReadyBool"), (400, "U8Counter"), (400, "I8TempRaw"), (400, "U16OdometerDm"), (400, "I16TorqueRaw"), (400, "U32TripM"), (400, "I32EnergyMWh"), (400, "U64TimestampMs"), (400, "I64BalanceNA"), (401, "FlagErrorBool"), (401, "U8ModeBe"), (401, "I8DeltaBe"), (401, "U16PressureKPaBe"), (401, "I16RateDpsBe"), (401, "U32CrcBe"), (401, "I32PosMmBe"), (401, "U64TripHashBe")];
+    }
+} // end nodes
+
 enum DbcMessages {
     VehicleStatus,
     EnergyPackStatus,
@@ -14149,12 +23519,18 @@ enum DbcMessages {
 pub struct CanMsgPool {
     uid: &'static str,
     pool: [Rc<RefCell<Box<dyn CanDbcMessage>>>;7],
+    filters: RefCell<Vec<CanIdFilter>>,
+    stamp: Rc<Cell<u64>>,
+    unknown: RefCell<UnknownIds>,
 }
 
 impl CanMsgPool {
     pub fn new(uid: &'static str) -> Self {
         CanMsgPool {
             uid: uid,
+            filters: RefCell::new(Vec::new()),
+            stamp: Rc::new(Cell::new(0)),
+            unknown: RefCell::new(UnknownIds::default()),
             pool: [
                 VehicleStatus::DbcMessage::new(),
                 EnergyPackStatus::DbcMessage::new(),
@@ -14192,12 +23568,423 @@ impl CanDbcPool for CanMsgPool {
     }
 
     fn update(&self, data: &CanMsgData) -> Result<RefMut<'_, Box<dyn CanDbcMessage>>, CanError> {
-        let mut msg= match self.get_mut(data.canid) {
+        // ids outside the pool may reach a message through a subscribe_ids() filter
+        let canid = self.resolve(data.canid).unwrap_or(data.canid);
+        if self.get_ids().binary_search(&canid).is_err() {
+            self.unknown_frame(data);
+        }
+        let mut msg= match self.get_mut(canid) {
             Err(error) => return Err(error),
             Ok(msg_ref) => msg_ref,
         };
+        // frame time of the CallbackFlow::Pause of subscribe_signals_flow() callbacks
+        self.stamp.set(data.stamp);
         msg.update(data)?;
         Ok(msg)
     }
  }
+
+/// `subscribe_signals()` callback, shared by every matching signal
+struct SignalCallback(Rc<dyn Fn(&dyn CanDbcSignal) -> i32>);
+
+impl CanSigCtrl for SignalCallback {
+    fn sig_notification(&self, signal: &dyn CanDbcSignal) -> i32 {
+        (self.0)(signal)
+    }
+}
+
+/// What a `subscribe_signals_flow()` callback asks the dispatch to do next
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallbackFlow {
+    /// Keep notifying
+    Continue,
+    /// Stop notifying this subscription, for every signal it wired
+    Unsubscribe,
+    /// Skip the notifications of this subscription for this many milliseconds of frame time
+    Pause(u64),
+}
+
+/// One `subscribe_signals_flow()` subscription, shared by every matching signal
+struct FlowSubscription {
+    callback: Box<dyn Fn(&dyn CanDbcSignal) -> CallbackFlow>,
+    /// stamp of the frame the pool is dispatching
+    stamp: Rc<Cell<u64>>,
+    unsubscribed: Cell<bool>,
+    resume_at: Cell<u64>,
+}
+
+struct FlowCallback(Rc<FlowSubscription>);
+
+impl CanSigCtrl for FlowCallback {
+    fn sig_notification(&self, signal: &dyn CanDbcSignal) -> i32 {
+        let flow = &self.0;
+        let now = flow.stamp.get();
+        if flow.unsubscribed.get() || now < flow.resume_at.get() {
+            return 0;
+        }
+        match (flow.callback)(signal) {
+            CallbackFlow::Continue => {},
+            CallbackFlow::Unsubscribe => flow.unsubscribed.set(true),
+            CallbackFlow::Pause(ms) => flow.resume_at.set(now.saturating_add(ms.saturating_mul(1000))),
+        }
+        0
+    }
+}
+
+/// Shell-like match where `*` stands for any sequence of characters
+fn glob_match(pattern: &str, text: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == text,
+        Some((head, tail)) => {
+            text.starts_with(head)
+                && (head.len()..=text.len())
+                    .filter(|idx| text.is_char_boundary(*idx))
+                    .any(|idx| glob_match(tail, &text[idx..]))
+        },
+    }
+}
+
+impl CanMsgPool {
+    /// Set `callback` on every signal matching `pattern`, "Signal" or "Message.Signal" where `*`
+    /// matches any sequence (e.g. "Battery*", "Bms*.*Temp"), replacing their previous callback.
+    /// Returns the number of signals wired.
+    pub fn subscribe_signals<F>(&self, pattern: &str, callback: F) -> Result<usize, CanError>
+    where
+        F: Fn(&dyn CanDbcSignal) -> i32 + 'static,
+    {
+        let callback: Rc<dyn Fn(&dyn CanDbcSignal) -> i32> = Rc::new(callback);
+        self.wire_signals(pattern, || Box::new(SignalCallback(callback.clone())))
+    }
+
+    /// Like `subscribe_signals()`, the `callback` result steering the subscription: `Continue`,
+    /// `Unsubscribe` for good, or `Pause(ms)` skipping notifications until a frame stamped `ms`
+    /// later. Pauses follow the stamps of the frames given to `update()` of this pool.
+    pub fn subscribe_signals_flow<F>(&self, pattern: &str, callback: F) -> Result<usize, CanError>
+    where
+        F: Fn(&dyn CanDbcSignal) -> CallbackFlow + 'static,
+    {
+        let flow = Rc::new(FlowSubscription {
+            callback: Box::new(callback),
+            stamp: Rc::clone(&self.stamp),
+            unsubscribed: Cell::new(false),
+            resume_at: Cell::new(0),
+        });
+        self.wire_signals(pattern, || Box::new(FlowCallback(Rc::clone(&flow))))
+    }
+
+    /// Set a callback from `make` on every signal matching `pattern`
+    fn wire_signals(&self, pattern: &str, make: impl Fn() -> Box<dyn CanSigCtrl>) -> Result<usize, CanError> {
+        let (msg_pattern, sig_pattern) = pattern.split_once('.').unwrap_or(("*", pattern));
+        let mut count = 0;
+        for msg in &self.pool {
+            let msg = match msg.try_borrow() {
+                Ok(msg) => msg,
+                Err(_) => return Err(CanError::new("message-get_mut", "Internal error message borrowed")),
+            };
+            if !glob_match(msg_pattern, msg.get_name()) {
+                continue;
+            }
+            for signal in msg.get_signals() {
+                let mut signal = match signal.try_borrow_mut() {
+                    Ok(signal) => signal,
+                    Err(_) => return Err(CanError::new("message-get_mut", format!("Internal error {} borrowed", msg.get_name()))),
+                };
+                if glob_match(sig_pattern, signal.get_name()) {
+                    signal.set_callback(make());
+                    count += 1;
+                }
+            }
+        }
+        Ok(count)
+    }
+}
+
+/// SG_ unit, range, scaling and layout of a signal, see `CanMsgPool::signal_meta()`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SignalMeta {
+    /// Physical unit, empty when the DBC gives none
+    pub unit: &'static str,
+    /// Minimum physical value, 0 and 0 for an unspecified range
+    pub min: f64,
+    /// Maximum physical value
+    pub max: f64,
+    /// Scaling factor, physical = raw * factor + offset
+    pub factor: f64,
+    /// Scaling offset
+    pub offset: f64,
+    /// Signal size in bits
+    pub bit_length: u32,
+    /// SG_ start bit, in the DBC bit numbering
+    pub start_bit: u32,
+}
+
+impl CanMsgPool {
+    /// Message called `name`, its `get_name()` or DBC name, as `get_mut()` finds it by id:
+    /// configurations name messages whose ids differ between vehicle variants.
+    pub fn get_by_name(&self, name: &str) -> Result<RefMut<'_, Box<dyn CanDbcMessage>>, CanError> {
+        let idx = match name {
+            "VehicleStatus" | "Vehicle_Status" => 0,
+            "EnergyPackStatus" | "Energy_Pack_Status" => 1,
+            "PowertrainMux" | "Powertrain_Mux" => 2,
+            "ChassisControl" | "Chassis_Control" => 3,
+            "DiagFdFrame" | "Diag_FD_Frame" => 4,
+            "DataTypesLe" | "DataTypes_LE" => 5,
+            "DataTypesBe" | "DataTypes_BE" => 6,
+            _ => return Err(CanError::new("fail-canid-search", format!("message:{} not found", name))),
+        };
+        match self.pool[idx].try_borrow_mut() {
+            Err(_code) => Err(CanError::new("message-get_mut", "internal msg pool error")),
+            Ok(mut_ref) => Ok(mut_ref),
+        }
+    }
+
+    /// Signal called `name` (its `get_name()` or DBC name) of the message `canid`, see
+    /// `DbcMessage::signal_index()`. None when the message or signal is unknown, or while the
+    /// message is being updated.
+    pub fn get_signal(&self, canid: u32, name: &str) -> Option<Rc<RefCell<Box<dyn CanDbcSignal>>>> {
+        let (msg, sig) = match canid {
+            100 => (0, VehicleStatus::DbcMessage::signal_index(name)?),
+            101 => (1, EnergyPackStatus::DbcMessage::signal_index(name)?),
+            200 => (2, PowertrainMux::DbcMessage::signal_index(name)?),
+            210 => (3, ChassisControl::DbcMessage::signal_index(name)?),
+            300 => (4, DiagFdFrame::DbcMessage::signal_index(name)?),
+            400 => (5, DataTypesLe::DbcMessage::signal_index(name)?),
+            401 => (6, DataTypesBe::DbcMessage::signal_index(name)?),
+            _ => return None,
+        };
+        let msg = self.pool[msg].try_borrow().ok()?;
+        msg.get_signals().get(sig).cloned()
+    }
+
+    /// Unit, range, scaling and layout of the signal called `name` (its `get_name()` or DBC
+    /// name) of the message `canid`, see `DbcMessage::signal_meta()`. Unlike `get_signal()`,
+    /// it borrows nothing and works while the message is being updated.
+    pub fn signal_meta(canid: u32, name: &str) -> Option<SignalMeta> {
+        match canid {
+            100 => VehicleStatus::DbcMessage::signal_meta(name),
+            101 => EnergyPackStatus::DbcMessage::signal_meta(name),
+            200 => PowertrainMux::DbcMessage::signal_meta(name),
+            210 => ChassisControl::DbcMessage::signal_meta(name),
+            300 => DiagFdFrame::DbcMessage::signal_meta(name),
+            400 => DataTypesLe::DbcMessage::signal_meta(name),
+            401 => DataTypesBe::DbcMessage::signal_meta(name),
+            _ => None,
+        }
+    }
+}
+
+/// Frame ids routed to a pool message whose DBC id differs, e.g. J1939 source addresses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CanIdFilter {
+    /// Ids equal to `value` on the `mask` bits, each routed to the message with the same
+    /// masked id: `{ value: 0x18FEF100, mask: 0x03FFFF00 }` ignores priority and source address
+    Mask { value: u32, mask: u32 },
+    /// Ids in `first..=last`, routed to the only message of the range
+    Range { first: u32, last: u32 },
+}
+
+impl CanIdFilter {
+    /// True when the filter accepts `canid`
+    pub fn matches(&self, canid: u32) -> bool {
+        match *self {
+            CanIdFilter::Mask { value, mask } => canid & mask == value & mask,
+            CanIdFilter::Range { first, last } => (first..=last).contains(&canid),
+        }
+    }
+
+    /// Id among `ids` a frame `canid` accepted by the filter is routed to
+    fn route(&self, canid: u32, ids: &[u32]) -> Option<u32> {
+        if !self.matches(canid) {
+            return None;
+        }
+        match *self {
+            CanIdFilter::Mask { mask, .. } => ids.iter().copied().find(|id| id & mask == canid & mask),
+            CanIdFilter::Range { .. } => ids.iter().copied().find(|id| self.matches(*id)),
+        }
+    }
+}
+
+impl CanMsgPool {
+    /// Route frame ids accepted by `filter` to the pool messages it covers, returns how many
+    /// messages it covers. A filter covering no message, or a range covering several, is rejected.
+    pub fn subscribe_ids(&self, filter: CanIdFilter) -> Result<usize, CanError> {
+        let count = self.get_ids().iter().filter(|id| filter.matches(**id)).count();
+        match (filter, count) {
+            (_, 0) => return Err(CanError::new("fail-canid-search", format!("no message matches {:?}", filter))),
+            (CanIdFilter::Range { .. }, 2..) => {
+                return Err(CanError::new("fail-canid-search", format!("{} messages in {:?}", count, filter)))
+            },
+            _ => {},
+        }
+        match self.filters.try_borrow_mut() {
+            Ok(mut filters) => filters.push(filter),
+            Err(_) => return Err(CanError::new("fail-canid-search", "internal filter list error")),
+        }
+        Ok(count)
+    }
+
+    /// Pool message id a frame `canid` updates: itself when the pool has it, the extended
+    /// message when a 29-bit `canid` comes without CAN_EFF_FLAG, else the message routed by the
+    /// first `subscribe_ids()` filter accepting it
+    pub fn resolve(&self, canid: u32) -> Option<u32> {
+        if self.get_ids().binary_search(&canid).is_ok() {
+            return Some(canid);
+        }
+        let extended = canid | 0x80000000;
+        if canid > 0x7ff && self.get_ids().binary_search(&extended).is_ok() {
+            return Some(extended);
+        }
+        let filters = self.filters.try_borrow().ok()?;
+        filters.iter().find_map(|filter| filter.route(canid, self.get_ids()))
+    }
+}
+
+/// What the pool does with frames whose id is neither in the DBC nor routed by a filter
+#[derive(Clone, Default)]
+pub enum UnknownIdPolicy {
+    /// `update()` and `update_frame()` fail with a fail-canid-search error
+    #[default]
+    Reject,
+    /// `update_frame()` returns `Ok(None)`
+    Ignore,
+    /// As `Ignore`, and the frame is counted in `unknown_frames()`
+    Count,
+    /// As `Count`, and the frame is handed to the raw handler
+    Forward(Rc<dyn Fn(&CanMsgData)>),
+}
+
+/// Frames received for one unknown id
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownFrame {
+    pub canid: u32,
+    pub count: u64,
+    /// Stamp and length of the last frame
+    pub stamp: u64,
+    pub len: u8,
+}
+
+/// Unknown id policy and statistics of a pool
+#[derive(Default)]
+struct UnknownIds {
+    policy: UnknownIdPolicy,
+    frames: BTreeMap<u32, UnknownFrame>,
+}
+
+impl CanMsgPool {
+    /// Policy applied to frames with an unknown id, `Reject` by default
+    pub fn set_unknown_policy(&self, policy: UnknownIdPolicy) {
+        if let Ok(mut unknown) = self.unknown.try_borrow_mut() {
+            unknown.policy = policy;
+        }
+    }
+
+    /// Fallback receiving every frame whose id is unknown to the pool (raw bytes and stamp),
+    /// e.g. to forward or log it from a gateway: the `Forward` policy with `handler`
+    pub fn set_raw_handler<F>(&self, handler: F)
+    where
+        F: Fn(&CanMsgData) + 'static,
+    {
+        self.set_unknown_policy(UnknownIdPolicy::Forward(Rc::new(handler)));
+    }
+
+    /// Like `update()`, but a frame with an unknown id returns `Ok(None)` unless the policy
+    /// is `Reject`, so mixed traffic needs no pre-filtering
+    pub fn update_frame(&self, data: &CanMsgData) -> Result<Option<RefMut<'_, Box<dyn CanDbcMessage>>>, CanError> {
+        let canid = self.resolve(data.canid).unwrap_or(data.canid);
+        let reject = self.unknown.try_borrow().map_or(true, |unknown| matches!(unknown.policy, UnknownIdPolicy::Reject));
+        if !reject && self.get_ids().binary_search(&canid).is_err() {
+            self.unknown_frame(data);
+            return Ok(None);
+        }
+        self.update(data).map(Some)
+    }
+
+    /// Unknown ids counted by the `Count` and `Forward` policies, ordered by id
+    pub fn unknown_frames(&self) -> Vec<UnknownFrame> {
+        match self.unknown.try_borrow() {
+            Ok(unknown) => unknown.frames.values().copied().collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// Forget the unknown ids counted so far
+    pub fn clear_unknown_frames(&self) {
+        if let Ok(mut unknown) = self.unknown.try_borrow_mut() {
+            unknown.frames.clear();
+        }
+    }
+
+    fn unknown_frame(&self, data: &CanMsgData) {
+        let handler = match self.unknown.try_borrow_mut() {
+            Err(_) => return,
+            Ok(mut unknown) => {
+                let handler = match &unknown.policy {
+                    UnknownIdPolicy::Reject | UnknownIdPolicy::Ignore => return,
+                    UnknownIdPolicy::Count => None,
+                    UnknownIdPolicy::Forward(handler) => Some(Rc::clone(handler)),
+                };
+                let frame = unknown.frames.entry(data.canid).or_insert(UnknownFrame { canid: data.canid, count: 0, stamp: 0, len: 0 });
+                frame.count += 1;
+                frame.stamp = data.stamp;
+                frame.len = data.len;
+                handler
+            },
+        };
+        // called without the borrow, the handler may use the pool
+        if let Some(handler) = handler {
+            handler(data);
+        }
+    }
+}
+
+/// BCM receive filters registered by `CanMsgPool::subscribe()`, or their new timers
+/// for `CanMsgPool::set_timers()`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SubscribeOpts {
+    /// At most one RxChanged notification per message every `rate` ms, 0 for every change
+    pub rate: u64,
+    /// RxTimeout notification when a message is not received for `watchdog` ms, 0 to disable
+    pub watchdog: u64,
+    /// CAN ids to subscribe, every message of the pool when None
+    pub only: Option<Vec<u32>>,
+}
+
+impl CanMsgPool {
+    /// Register one BCM RxSetup filter per message on `sock` (a BCM socket), nothing is
+    /// registered when `only` lists a CAN id missing from the pool
+    pub fn subscribe(&self, sock: &SockCanHandle, opts: SubscribeOpts) -> Result<(), CanError> {
+        let mut flags = CanBcmFlag::RX_FILTER_ID;
+        if opts.rate > 0 || opts.watchdog > 0 {
+            flags = flags | CanBcmFlag::SET_TIMER | CanBcmFlag::START_TIMER;
+        }
+        self.rx_setup(sock, &opts, flags)
+    }
+
+    /// Change the `rate` and `watchdog` of the filters `subscribe()` registered on `sock`,
+    /// without reopening it, e.g. to switch between slow polling and high-rate diagnostics:
+    /// 0 stops the throttling or the watchdog. `only` restricts the change to some messages,
+    /// an id not subscribed yet gets a filter.
+    pub fn set_timers(&self, sock: &SockCanHandle, opts: SubscribeOpts) -> Result<(), CanError> {
+        let flags = CanBcmFlag::RX_FILTER_ID | CanBcmFlag::SET_TIMER | CanBcmFlag::START_TIMER;
+        self.rx_setup(sock, &opts, flags)
+    }
+
+    /// One BCM RxSetup per `opts` message, none when an id is missing from the pool
+    fn rx_setup(&self, sock: &SockCanHandle, opts: &SubscribeOpts, flags: CanBcmFlag) -> Result<(), CanError> {
+        let canids = match &opts.only {
+            Some(only) => only.as_slice(),
+            None => self.get_ids(),
+        };
+        if let Some(canid) = canids.iter().find(|canid| !self.get_ids().contains(canid)) {
+            return Err(CanError::new("fail-canid-search", format!("canid:{} not found", canid)));
+        }
+        // extended DBC ids already carry CAN_EFF_FLAG (bit 31), as the BCM expects
+        for canid in canids {
+            SockBcmCmd::new(CanBcmOpCode::RxSetup, flags, *canid)
+                .set_timers(opts.rate, opts.watchdog)
+                .apply(sock)?;
+        }
+        Ok(())
+    }
+}
 } // end dbc generated parser
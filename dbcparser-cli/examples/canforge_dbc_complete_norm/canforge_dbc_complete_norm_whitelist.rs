@@ -25,7 +25,7 @@
 // --------------------------------------------------------------
 //       WARNING: Manual modification will be destroyed
 // --------------------------------------------------------------
-// - code generated from ./dbcparser-cli/examples/canforge_dbc_complete_norm/dbc/canforge_dbc_complete_norm.dbc (Wed Jan 28 10:51:38 2026)
+// - code generated from ./dbcparser-cli/examples/canforge_dbc_complete_norm/dbc/canforge_dbc_complete_norm.dbc (Fri Oct 16 13:59:28 2026)
 // - update only with [dbc-parser|build.rs::DbcParser]
 // - source code: https://github.com/redpesk-common/canforge-rs
 // Generated file — DO NOT EDIT.
@@ -56,10 +56,396 @@ extern crate serde;
 
 extern crate bitvec;
 use sockcan::prelude::*;
-use std::cell::{RefCell,RefMut};
+use std::cell::{Cell,RefCell,RefMut};
+use std::collections::BTreeMap;
 use std::rc::{Rc};
 
 
+/// Machine-readable codes of the CanError uids raised by this module
+pub mod errors {
+    use sockcan::prelude::*;
+    use std::error::Error;
+    use std::fmt;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum CanErrorCode {
+        InvalidSignalValue,
+        SignalSetValuesFail,
+        SignalResetFail,
+        SignalUpdateFail,
+        SignalGroupGetFail,
+        SignalGroupSetFail,
+        MessageSnapshotFail,
+        MessageGetMut,
+        UnknownCanId,
+        DerivedSignalReadOnly,
+        TransportPending,
+        InvalidFrame,
+        Other,
+    }
+
+    impl CanErrorCode {
+        /// CanError uid carrying this code
+        pub const fn uid(self) -> &'static str {
+            match self {
+                CanErrorCode::InvalidSignalValue => "invalid-signal-value",
+                CanErrorCode::SignalSetValuesFail => "signal-set-values-fail",
+                CanErrorCode::SignalResetFail => "signal-reset-fail",
+                CanErrorCode::SignalUpdateFail => "signal-update-fail",
+                CanErrorCode::SignalGroupGetFail => "signal-group-get-fail",
+                CanErrorCode::SignalGroupSetFail => "signal-group-set-fail",
+                CanErrorCode::MessageSnapshotFail => "message-snapshot-fail",
+                CanErrorCode::MessageGetMut => "message-get_mut",
+                CanErrorCode::UnknownCanId => "fail-canid-search",
+                CanErrorCode::DerivedSignalReadOnly => "derived-signal-readonly",
+                CanErrorCode::TransportPending => "j1939-tp-pending",
+                CanErrorCode::InvalidFrame => "invalid-raw-frame",
+                CanErrorCode::Other => "other",
+            }
+        }
+
+        /// Code of a CanError uid, Other when it is not raised by generated code
+        pub fn from_uid(uid: &str) -> Self {
+            match uid {
+                "invalid-signal-value" => CanErrorCode::InvalidSignalValue,
+                "signal-set-values-fail" => CanErrorCode::SignalSetValuesFail,
+                "signal-reset-fail" => CanErrorCode::SignalResetFail,
+                "signal-update-fail" => CanErrorCode::SignalUpdateFail,
+                "signal-group-get-fail" => CanErrorCode::SignalGroupGetFail,
+                "signal-group-set-fail" => CanErrorCode::SignalGroupSetFail,
+                "message-snapshot-fail" => CanErrorCode::MessageSnapshotFail,
+                "message-get_mut" => CanErrorCode::MessageGetMut,
+                "fail-canid-search" => CanErrorCode::UnknownCanId,
+                "derived-signal-readonly" => CanErrorCode::DerivedSignalReadOnly,
+                "j1939-tp-pending" => CanErrorCode::TransportPending,
+                "invalid-raw-frame" => CanErrorCode::InvalidFrame,
+                _ => CanErrorCode::Other,
+            }
+        }
+    }
+
+    impl fmt::Display for CanErrorCode {
+        fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {
+            format.write_str(self.uid())
+        }
+    }
+
+    /// CanError with its code, implements the Error trait so `?` works with anyhow/thiserror
+    #[derive(Debug, Clone)]
+    pub struct DbcError {
+        pub code: CanErrorCode,
+        pub uid: String,
+        pub info: String,
+    }
+
+    impl From<CanError> for DbcError {
+        fn from(error: CanError) -> Self {
+            DbcError {
+                code: CanErrorCode::from_uid(error.get_uid()),
+                uid: error.get_uid().to_owned(),
+                info: error.get_info().to_owned(),
+            }
+        }
+    }
+
+    impl fmt::Display for DbcError {
+        fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(format, "{}: {}", self.uid, self.info)
+        }
+    }
+
+    impl Error for DbcError {}
+
+    /// Code of a sockcan CanError
+    pub trait CanErrorCodeExt {
+        fn code(&self) -> CanErrorCode;
+    }
+
+    impl CanErrorCodeExt for CanError {
+        fn code(&self) -> CanErrorCode {
+            CanErrorCode::from_uid(self.get_uid())
+        }
+    }
+}
+
+
+/// SHA-256 of the DBC file this module was generated from
+pub const DBC_SHA256: &str = "6be844ba97514f6b90d82bb9c57d4534d027e3b88578b92dd1e07ea8dc844ac2";
+
+/// Check that the DBC file at `path` is the one this module was generated from
+pub fn verify_dbc<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<()> {
+    let path = path.as_ref();
+    let digest = dbc_sha256(&std::fs::read(path)?);
+    if digest == DBC_SHA256 {
+        Ok(())
+    } else {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("{}: sha256 {} does not match generated code {}", path.display(), digest, DBC_SHA256),
+        ))
+    }
+}
+
+fn dbc_sha256(data: &[u8]) -> String {
+    const K: [u32; 64] = [
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+        0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+        0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+        0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+        0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+        0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+        0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+        0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+    ];
+    let mut hash: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+    ];
+    let mut msg = data.to_vec();
+    msg.push(0x80);
+    while msg.len() % 64 != 56 {
+        msg.push(0);
+    }
+    msg.extend_from_slice(&(data.len() as u64).wrapping_mul(8).to_be_bytes());
+    for chunk in msg.chunks(64) {
+        let mut w = [0u32; 64];
+        for idx in 0..16 {
+            w[idx] = u32::from_be_bytes([chunk[4 * idx], chunk[4 * idx + 1], chunk[4 * idx + 2], chunk[4 * idx + 3]]);
+        }
+        for idx in 16..64 {
+            let s0 = w[idx - 15].rotate_right(7) ^ w[idx - 15].rotate_right(18) ^ (w[idx - 15] >> 3);
+            let s1 = w[idx - 2].rotate_right(17) ^ w[idx - 2].rotate_right(19) ^ (w[idx - 2] >> 10);
+            w[idx] = w[idx - 16].wrapping_add(s0).wrapping_add(w[idx - 7]).wrapping_add(s1);
+        }
+        let mut v = hash;
+        for idx in 0..64 {
+            let s1 = v[4].rotate_right(6) ^ v[4].rotate_right(11) ^ v[4].rotate_right(25);
+            let ch = (v[4] & v[5]) ^ (!v[4] & v[6]);
+            let t1 = v[7].wrapping_add(s1).wrapping_add(ch).wrapping_add(K[idx]).wrapping_add(w[idx]);
+            let s0 = v[0].rotate_right(2) ^ v[0].rotate_right(13) ^ v[0].rotate_right(22);
+            let maj = (v[0] & v[1]) ^ (v[0] & v[2]) ^ (v[1] & v[2]);
+            let t2 = s0.wrapping_add(maj);
+            v = [t1.wrapping_add(t2), v[0], v[1], v[2], v[3].wrapping_add(t1), v[4], v[5], v[6]];
+        }
+        for (word, value) in hash.iter_mut().zip(v) {
+            *word = word.wrapping_add(value);
+        }
+    }
+    hash.iter().map(|word| format!("{:08x}", word)).collect()
+}
+
+/// Serializable view of any message: id, name, status, stamp and signals by name
+pub struct MessageState<'a>(pub &'a dyn CanDbcMessage);
+
+impl serde::Serialize for MessageState<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::{Error, SerializeMap, SerializeStruct};
+        struct Signals<'a>(&'a [Rc<RefCell<Box<dyn CanDbcSignal>>>]);
+        impl serde::Serialize for Signals<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut map = serializer.serialize_map(Some(self.0.len()))?;
+                for signal in self.0 {
+                    let signal = signal.try_borrow().map_err(S::Error::custom)?;
+                    let value: serde_json::Value =
+                        serde_json::from_str(&signal.to_json()).map_err(S::Error::custom)?;
+                    map.serialize_entry(signal.get_name(), &value)?;
+                }
+                map.end()
+            }
+        }
+        let mut state = serializer.serialize_struct("DbcMessage", 5)?;
+        state.serialize_field("id", &self.0.get_id())?;
+        state.serialize_field("name", self.0.get_name())?;
+        state.serialize_field("status", &self.0.get_status())?;
+        state.serialize_field("stamp", &self.0.get_stamp())?;
+        state.serialize_field("signals", &Signals(self.0.get_signals()))?;
+        state.end()
+    }
+}
+
+impl serde::Serialize for CanMsgPool {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::{Error, SerializeSeq, SerializeStruct};
+        struct Messages<'a>(&'a [Rc<RefCell<Box<dyn CanDbcMessage>>>]);
+        impl serde::Serialize for Messages<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+                for msg in self.0 {
+                    let msg = msg.try_borrow().map_err(S::Error::custom)?;
+                    seq.serialize_element(&MessageState(msg.as_ref()))?;
+                }
+                seq.end()
+            }
+        }
+        let mut state = serializer.serialize_struct("CanMsgPool", 2)?;
+        state.serialize_field("uid", self.uid)?;
+        state.serialize_field("messages", &Messages(&self.pool))?;
+        state.end()
+    }
+}
+
+/// Value of one signal in a `PoolSnapshot`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SignalState {
+    pub canid: u32,
+    pub message: &'static str,
+    pub signal: &'static str,
+    pub value: CanDbcType,
+}
+
+/// Signal values of a whole pool at a point in time, see `CanDbcPoolDiff::snapshot()`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PoolSnapshot {
+    pub signals: Vec<SignalState>,
+}
+
+/// Signal whose value changed since a `PoolSnapshot`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SignalChange {
+    pub canid: u32,
+    pub message: &'static str,
+    pub signal: &'static str,
+    pub before: CanDbcType,
+    pub after: CanDbcType,
+}
+
+/// Snapshot every signal value of a pool and list what changed since, e.g. to only publish changes
+pub trait CanDbcPoolDiff {
+    fn snapshot(&self) -> Result<PoolSnapshot, CanError>;
+    fn diff(&self, snapshot: &PoolSnapshot) -> Result<Vec<SignalChange>, CanError>;
+}
+
+impl<T: CanDbcPool + ?Sized> CanDbcPoolDiff for T {
+    fn snapshot(&self) -> Result<PoolSnapshot, CanError> {
+        let mut signals = Vec::new();
+        for msg in self.get_messages() {
+            let msg = match msg.try_borrow() {
+                Ok(msg) => msg,
+                Err(_) => return Err(CanError::new("message-snapshot-fail", "Internal error message borrowed")),
+            };
+            for signal in msg.get_signals() {
+                let signal = match signal.try_borrow() {
+                    Ok(signal) => signal,
+                    Err(_) => return Err(CanError::new("message-snapshot-fail", format!("Internal error {} borrowed", msg.get_name()))),
+                };
+                signals.push(SignalState {
+                    canid: msg.get_id(),
+                    message: msg.get_name(),
+                    signal: signal.get_name(),
+                    value: signal.get_value(),
+                });
+            }
+        }
+        Ok(PoolSnapshot { signals })
+    }
+
+    fn diff(&self, snapshot: &PoolSnapshot) -> Result<Vec<SignalChange>, CanError> {
+        let current = self.snapshot()?;
+        if current.signals.len() != snapshot.signals.len() {
+            return Err(CanError::new("message-snapshot-fail", "snapshot taken from another pool"));
+        }
+        let mut changes = Vec::new();
+        for (before, after) in snapshot.signals.iter().zip(&current.signals) {
+            if before.canid != after.canid || before.signal != after.signal {
+                return Err(CanError::new("message-snapshot-fail", "snapshot taken from another pool"));
+            }
+            if before.value != after.value {
+                changes.push(SignalChange {
+                    canid: after.canid,
+                    message: after.message,
+                    signal: after.signal,
+                    before: before.value,
+                    after: after.value,
+                });
+            }
+        }
+        Ok(changes)
+    }
+}
+
+/// DBC attribute definitions (BA_DEF_) with their constraints and defaults (BA_DEF_DEF_)
+pub mod attributes {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum AttributeScope {
+        Network,
+        Node,
+        Message,
+        Signal,
+        EnvVar,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum AttributeKind {
+        Int,
+        Hex,
+        Float,
+        String,
+        Enum,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum AttributeValue {
+        Int(i64),
+        Float(f64),
+        Str(&'static str),
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct AttributeDef {
+        pub name: &'static str,
+        pub scope: AttributeScope,
+        pub kind: AttributeKind,
+        range: Option<(f64, f64)>,
+        choices: &'static [&'static str],
+        default: Option<AttributeValue>,
+    }
+
+    impl AttributeDef {
+        /// Allowed [min, max] for INT, HEX and FLOAT attributes
+        pub const fn range(&self) -> Option<(f64, f64)> {
+            self.range
+        }
+
+        /// Allowed labels for ENUM attributes
+        pub const fn choices(&self) -> &'static [&'static str] {
+            self.choices
+        }
+
+        pub const fn default(&self) -> Option<AttributeValue> {
+            self.default
+        }
+    }
+
+    pub const GEN_MSG_CYCLE_TIME: AttributeDef = AttributeDef {
+        name: "GenMsgCycleTime",
+        scope: AttributeScope::Message,
+        kind: AttributeKind::Int,
+        range: Some((0.0_f64, 10000.0_f64)),
+        choices: &[],
+        default: Some(AttributeValue::Int(100)),
+    };
+
+    pub const GEN_MSG_SEND_TYPE: AttributeDef = AttributeDef {
+        name: "GenMsgSendType",
+        scope: AttributeScope::Message,
+        kind: AttributeKind::Enum,
+        range: None,
+        choices: &["none", "cyclic", "event"],
+        default: Some(AttributeValue::Str("cyclic")),
+    };
+
+    pub const BUS_TYPE: AttributeDef = AttributeDef {
+        name: "BusType",
+        scope: AttributeScope::Network,
+        kind: AttributeKind::String,
+        range: None,
+        choices: &[],
+        default: Some(AttributeValue::Str("CANFD")),
+    };
+
+    pub const ALL: [AttributeDef; 3] = [GEN_MSG_CYCLE_TIME, GEN_MSG_SEND_TYPE, BUS_TYPE];
+} // end attributes
+
+
 /// MUX_TEST Message
 /// - ID: 322 (0x142)
 /// - Size: 8 bytes
@@ -77,6 +463,9 @@ pub mod MuxTest { /// Message name space
     use std::fmt;
 
     use serde::{Deserialize, Serialize};
+    /// Nodes transmitting this message (BO_ and BO_TX_BU_)
+    pub const TRANSMITTERS: [&str; 1] = ["POWERTRAIN_CTRL"];
+
     pub enum DbcSignal {
         MuxSignal,
         Open,
@@ -102,6 +491,12 @@ pub mod MuxTest { /// Message name space
     /// - Offset: 0
     /// - Byte order: LittleEndian
     /// - Value type: Unsigned
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | STATE |
+    /// | 1 | MEASURE |
+    /// | 2 | INVALID |
     #[derive(Serialize, Deserialize)]
     pub struct MuxSignal {
         #[serde(skip)]
@@ -114,6 +509,13 @@ pub mod MuxTest { /// Message name space
     }
 
     // DBC definition for MsgID:322 Signal:MUX_signal
+    /// MuxSignal values (DBC VAL_)
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | STATE |
+    /// | 1 | MEASURE |
+    /// | 2 | INVALID |
     #[derive(Serialize, Deserialize)]
     pub enum DbcMuxSignal {
         State,
@@ -137,14 +539,47 @@ pub mod MuxTest { /// Message name space
 
 
     impl MuxSignal  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["INFOTAINMENT_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 2.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 2;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 0;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(MuxSignal {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            MuxSignal {
                 status: CanDataStatus::Unset,
                 name:"MuxSignal",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
@@ -159,29 +594,81 @@ pub mod MuxTest { /// Message name space
             }
 
         }
+        #[deprecated(note = "use set_raw()")]
         pub fn set_raw_value(&mut self, value: u8, data: &mut[u8]) {
-            data.view_bits_mut::<Lsb0>()[0..2].store_le(value);
-
+            self.set_raw(value, data)
         }
         pub fn set_as_def (&mut self, signal_def: DbcMuxSignal, data: &mut[u8])-> Result<(),CanError> {
             match signal_def {
-                DbcMuxSignal::State => Ok(self.set_raw_value(0, data)),
-                DbcMuxSignal::Measure => Ok(self.set_raw_value(1, data)),
-                DbcMuxSignal::Invalid => Ok(self.set_raw_value(2, data)),
-                DbcMuxSignal::_Other(x) => self.set_typed_value(x,data)
+                DbcMuxSignal::State => Ok(self.set_raw(0, data)),
+                DbcMuxSignal::Measure => Ok(self.set_raw(1, data)),
+                DbcMuxSignal::Invalid => Ok(self.set_raw(2, data)),
+                DbcMuxSignal::_Other(x) => self.set_physical(x,data)
             }
         }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 2 == 64 { u64::MAX } else { (1u64 << 2) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 2_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..2]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[0..2].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 2_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..2]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[0..2].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[0..2].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("MuxTest payload len:{} shorter than 8 bytes", data.len())));
+            }
+            Self::encode(value, data)
+        }
+
     } // MuxTest::MuxSignal impl end
 
 
@@ -203,35 +690,37 @@ pub mod MuxTest { /// Message name space
     }
 
 
-    /// MuxTest::MuxSignal public api (CanDbcSignal trait)
-    impl CanDbcSignal for MuxSignal {
+/// MuxTest::MuxSignal public api (CanDbcSignal trait)
+impl CanDbcSignal for MuxSignal {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[0..2].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[0..2].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -263,7 +752,7 @@ pub mod MuxTest { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -305,6 +794,11 @@ pub mod MuxTest { /// Message name space
     /// - Offset: 0
     /// - Byte order: LittleEndian
     /// - Value type: Unsigned
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | NO |
+    /// | 1 | YES |
     #[derive(Serialize, Deserialize)]
     pub struct Open {
         #[serde(skip)]
@@ -317,6 +811,12 @@ pub mod MuxTest { /// Message name space
     }
 
     // DBC definition for MsgID:322 Signal:open
+    /// Open values (DBC VAL_)
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | NO |
+    /// | 1 | YES |
     #[derive(Serialize, Deserialize)]
     pub enum DbcOpen {
         No,
@@ -338,14 +838,47 @@ pub mod MuxTest { /// Message name space
 
 
     impl Open  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["INFOTAINMENT_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 1.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 1;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 3;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(Open {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            Open {
                 status: CanDataStatus::Unset,
                 name:"Open",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
@@ -358,29 +891,76 @@ pub mod MuxTest { /// Message name space
             }
 
         }
+        #[deprecated(note = "use set_raw()")]
         pub fn set_raw_value(&mut self, value: u8, data: &mut[u8]) {
-            data.view_bits_mut::<Lsb0>()[3..4].store_le(value);
-
+            self.set_raw(value, data)
         }
         pub fn set_as_def (&mut self, signal_def: DbcOpen, data: &mut[u8])-> Result<(),CanError> {
             match signal_def {
-                DbcOpen::No => Ok(self.set_raw_value(0, data)),
-                DbcOpen::Yes => Ok(self.set_raw_value(1, data)),
-                DbcOpen::_Other(x) => self.set_typed_value(x,data)
+                DbcOpen::No => Ok(self.set_raw(0, data)),
+                DbcOpen::Yes => Ok(self.set_raw(1, data)),
+                DbcOpen::_Other(x) => self.set_physical(x,data)
             }
         }
 
+        #[inline]
         fn get_typed_value(&self) -> bool {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
-            let value = value as u8;
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> bool {
+            raw == 1
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: bool) -> u8 {
+            value as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[3..4].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[3..4].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> bool {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[3..4].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message,
+        /// or when the frame multiplexor selects another page than the one of this signal.
+        pub fn set_signal_into(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("MuxTest payload len:{} shorter than 8 bytes", data.len())));
+            }
+            let __mux_raw_value = MuxSignal::raw_from_physical(MuxSignal::decode(data)) as u64;
+            if __mux_raw_value != 0 {
+                return Err(CanError::new("signal-set-values-fail", format!("MuxTest.Open is on page 0, frame multiplexor selects {__mux_raw_value}")));
+            }
+            Self::encode(value, data)
+        }
+
     } // MuxTest::Open impl end
 
 
@@ -402,42 +982,43 @@ pub mod MuxTest { /// Message name space
     }
 
 
-    /// MuxTest::Open public api (CanDbcSignal trait)
-    impl CanDbcSignal for Open {
+/// MuxTest::Open public api (CanDbcSignal trait)
+impl CanDbcSignal for Open {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[3..4].load_le::<u8>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[3..4].load_le::<u8>();
 
-                    let newval = value == 1;
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
                     };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                        self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -463,7 +1044,7 @@ pub mod MuxTest { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -505,6 +1086,11 @@ pub mod MuxTest { /// Message name space
     /// - Offset: 0
     /// - Byte order: LittleEndian
     /// - Value type: Unsigned
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | NO |
+    /// | 1 | YES |
     #[derive(Serialize, Deserialize)]
     pub struct Closed {
         #[serde(skip)]
@@ -517,6 +1103,12 @@ pub mod MuxTest { /// Message name space
     }
 
     // DBC definition for MsgID:322 Signal:closed
+    /// Closed values (DBC VAL_)
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | NO |
+    /// | 1 | YES |
     #[derive(Serialize, Deserialize)]
     pub enum DbcClosed {
         No,
@@ -538,14 +1130,47 @@ pub mod MuxTest { /// Message name space
 
 
     impl Closed  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["INFOTAINMENT_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 1.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 1;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 4;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(Closed {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            Closed {
                 status: CanDataStatus::Unset,
                 name:"Closed",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
@@ -558,29 +1183,76 @@ pub mod MuxTest { /// Message name space
             }
 
         }
+        #[deprecated(note = "use set_raw()")]
         pub fn set_raw_value(&mut self, value: u8, data: &mut[u8]) {
-            data.view_bits_mut::<Lsb0>()[4..5].store_le(value);
-
+            self.set_raw(value, data)
         }
         pub fn set_as_def (&mut self, signal_def: DbcClosed, data: &mut[u8])-> Result<(),CanError> {
             match signal_def {
-                DbcClosed::No => Ok(self.set_raw_value(0, data)),
-                DbcClosed::Yes => Ok(self.set_raw_value(1, data)),
-                DbcClosed::_Other(x) => self.set_typed_value(x,data)
+                DbcClosed::No => Ok(self.set_raw(0, data)),
+                DbcClosed::Yes => Ok(self.set_raw(1, data)),
+                DbcClosed::_Other(x) => self.set_physical(x,data)
             }
         }
 
+        #[inline]
         fn get_typed_value(&self) -> bool {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
-            let value = value as u8;
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> bool {
+            raw == 1
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: bool) -> u8 {
+            value as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[4..5].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[4..5].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> bool {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[4..5].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message,
+        /// or when the frame multiplexor selects another page than the one of this signal.
+        pub fn set_signal_into(value:bool, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("MuxTest payload len:{} shorter than 8 bytes", data.len())));
+            }
+            let __mux_raw_value = MuxSignal::raw_from_physical(MuxSignal::decode(data)) as u64;
+            if __mux_raw_value != 0 {
+                return Err(CanError::new("signal-set-values-fail", format!("MuxTest.Closed is on page 0, frame multiplexor selects {__mux_raw_value}")));
+            }
+            Self::encode(value, data)
+        }
+
     } // MuxTest::Closed impl end
 
 
@@ -602,42 +1274,43 @@ pub mod MuxTest { /// Message name space
     }
 
 
-    /// MuxTest::Closed public api (CanDbcSignal trait)
-    impl CanDbcSignal for Closed {
+/// MuxTest::Closed public api (CanDbcSignal trait)
+impl CanDbcSignal for Closed {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[4..5].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[4..5].load_le::<u8>();
 
-                    let newval = value == 1;
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
                     };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                        self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -663,7 +1336,7 @@ pub mod MuxTest { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -705,6 +1378,13 @@ pub mod MuxTest { /// Message name space
     /// - Offset: 0
     /// - Byte order: LittleEndian
     /// - Value type: Unsigned
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | STOP |
+    /// | 1 | OPENING |
+    /// | 2 | CLOSING |
+    /// | 3 | UNKNOWN |
     #[derive(Serialize, Deserialize)]
     pub struct Direction {
         #[serde(skip)]
@@ -717,6 +1397,14 @@ pub mod MuxTest { /// Message name space
     }
 
     // DBC definition for MsgID:322 Signal:direction
+    /// Direction values (DBC VAL_)
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | STOP |
+    /// | 1 | OPENING |
+    /// | 2 | CLOSING |
+    /// | 3 | UNKNOWN |
     #[derive(Serialize, Deserialize)]
     pub enum DbcDirection {
         Stop,
@@ -742,14 +1430,47 @@ pub mod MuxTest { /// Message name space
 
 
     impl Direction  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["INFOTAINMENT_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 3.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 2;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 5;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(Direction {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            Direction {
                 status: CanDataStatus::Unset,
                 name:"Direction",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
@@ -765,37 +1486,94 @@ pub mod MuxTest { /// Message name space
             }
 
         }
+        #[deprecated(note = "use set_raw()")]
         pub fn set_raw_value(&mut self, value: u8, data: &mut[u8]) {
-            data.view_bits_mut::<Lsb0>()[5..7].store_le(value);
-
+            self.set_raw(value, data)
         }
         pub fn set_as_def (&mut self, signal_def: DbcDirection, data: &mut[u8])-> Result<(),CanError> {
             match signal_def {
-                DbcDirection::Stop => Ok(self.set_raw_value(0, data)),
-                DbcDirection::Opening => Ok(self.set_raw_value(1, data)),
-                DbcDirection::Closing => Ok(self.set_raw_value(2, data)),
-                DbcDirection::Unknown => Ok(self.set_raw_value(3, data)),
-                DbcDirection::_Other(x) => self.set_typed_value(x,data)
+                DbcDirection::Stop => Ok(self.set_raw(0, data)),
+                DbcDirection::Opening => Ok(self.set_raw(1, data)),
+                DbcDirection::Closing => Ok(self.set_raw(2, data)),
+                DbcDirection::Unknown => Ok(self.set_raw(3, data)),
+                DbcDirection::_Other(x) => self.set_physical(x,data)
             }
         }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
-            data.view_bits_mut::<Lsb0>()[5..7].store_le(value);
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 2 == 64 { u64::MAX } else { (1u64 << 2) - 1 };
+            ((value as u64) & __mask) as u8
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 3_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..3]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
             Ok(())
         }
 
-    } // MuxTest::Direction impl end
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
+            data.view_bits_mut::<Lsb0>()[5..7].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 3_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..3]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[5..7].store_le(value);
+            Ok(())
+        }
 
-    impl fmt::Display for Direction {
-        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-            let text=format!("Direction:{}", self.get_typed_value());
-            fmt.pad(&text)
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[5..7].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message,
+        /// or when the frame multiplexor selects another page than the one of this signal.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("MuxTest payload len:{} shorter than 8 bytes", data.len())));
+            }
+            let __mux_raw_value = MuxSignal::raw_from_physical(MuxSignal::decode(data)) as u64;
+            if __mux_raw_value != 0 {
+                return Err(CanError::new("signal-set-values-fail", format!("MuxTest.Direction is on page 0, frame multiplexor selects {__mux_raw_value}")));
+            }
+            Self::encode(value, data)
+        }
+
+    } // MuxTest::Direction impl end
+
+
+    impl fmt::Display for Direction {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let text=format!("Direction:{}", self.get_typed_value());
+            fmt.pad(&text)
         }
     }
 
@@ -810,35 +1588,37 @@ pub mod MuxTest { /// Message name space
     }
 
 
-    /// MuxTest::Direction public api (CanDbcSignal trait)
-    impl CanDbcSignal for Direction {
+/// MuxTest::Direction public api (CanDbcSignal trait)
+impl CanDbcSignal for Direction {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[5..7].load_le::<u8>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[5..7].load_le::<u8>();
 
+
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -870,7 +1650,7 @@ pub mod MuxTest { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -912,6 +1692,17 @@ pub mod MuxTest { /// Message name space
     /// - Offset: 0
     /// - Byte order: LittleEndian
     /// - Value type: Unsigned
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | M0 |
+    /// | 1 | M1 |
+    /// | 2 | M2 |
+    /// | 3 | M3 |
+    /// | 4 | M4 |
+    /// | 5 | M5 |
+    /// | 6 | M6 |
+    /// | 7 | M7 |
     #[derive(Serialize, Deserialize)]
     pub struct Mode {
         #[serde(skip)]
@@ -924,6 +1715,18 @@ pub mod MuxTest { /// Message name space
     }
 
     // DBC definition for MsgID:322 Signal:mode
+    /// Mode values (DBC VAL_)
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | M0 |
+    /// | 1 | M1 |
+    /// | 2 | M2 |
+    /// | 3 | M3 |
+    /// | 4 | M4 |
+    /// | 5 | M5 |
+    /// | 6 | M6 |
+    /// | 7 | M7 |
     #[derive(Serialize, Deserialize)]
     pub enum DbcMode {
         M0,
@@ -957,14 +1760,47 @@ pub mod MuxTest { /// Message name space
 
 
     impl Mode  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["INFOTAINMENT_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = 0.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 7.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 3;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 61;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(Mode {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            Mode {
                 status: CanDataStatus::Unset,
                 name:"Mode",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
@@ -984,34 +1820,91 @@ pub mod MuxTest { /// Message name space
             }
 
         }
+        #[deprecated(note = "use set_raw()")]
         pub fn set_raw_value(&mut self, value: u8, data: &mut[u8]) {
-            data.view_bits_mut::<Lsb0>()[61..64].store_le(value);
-
+            self.set_raw(value, data)
         }
         pub fn set_as_def (&mut self, signal_def: DbcMode, data: &mut[u8])-> Result<(),CanError> {
             match signal_def {
-                DbcMode::M0 => Ok(self.set_raw_value(0, data)),
-                DbcMode::M1 => Ok(self.set_raw_value(1, data)),
-                DbcMode::M2 => Ok(self.set_raw_value(2, data)),
-                DbcMode::M3 => Ok(self.set_raw_value(3, data)),
-                DbcMode::M4 => Ok(self.set_raw_value(4, data)),
-                DbcMode::M5 => Ok(self.set_raw_value(5, data)),
-                DbcMode::M6 => Ok(self.set_raw_value(6, data)),
-                DbcMode::M7 => Ok(self.set_raw_value(7, data)),
-                DbcMode::_Other(x) => self.set_typed_value(x,data)
+                DbcMode::M0 => Ok(self.set_raw(0, data)),
+                DbcMode::M1 => Ok(self.set_raw(1, data)),
+                DbcMode::M2 => Ok(self.set_raw(2, data)),
+                DbcMode::M3 => Ok(self.set_raw(3, data)),
+                DbcMode::M4 => Ok(self.set_raw(4, data)),
+                DbcMode::M5 => Ok(self.set_raw(5, data)),
+                DbcMode::M6 => Ok(self.set_raw(6, data)),
+                DbcMode::M7 => Ok(self.set_raw(7, data)),
+                DbcMode::_Other(x) => self.set_physical(x,data)
             }
         }
 
+        #[inline]
         fn get_typed_value(&self) -> u8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> u8 {
+            raw
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 3 == 64 { u64::MAX } else { (1u64 << 3) - 1 };
+            ((value as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < 0_u8 || 7_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..7]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[61..64].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < 0_u8 || 7_u8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..7]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[61..64].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> u8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[61..64].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message,
+        /// or when the frame multiplexor selects another page than the one of this signal.
+        pub fn set_signal_into(value:u8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("MuxTest payload len:{} shorter than 8 bytes", data.len())));
+            }
+            let __mux_raw_value = MuxSignal::raw_from_physical(MuxSignal::decode(data)) as u64;
+            if __mux_raw_value != 0 {
+                return Err(CanError::new("signal-set-values-fail", format!("MuxTest.Mode is on page 0, frame multiplexor selects {__mux_raw_value}")));
+            }
+            Self::encode(value, data)
+        }
+
     } // MuxTest::Mode impl end
 
 
@@ -1033,35 +1926,37 @@ pub mod MuxTest { /// Message name space
     }
 
 
-    /// MuxTest::Mode public api (CanDbcSignal trait)
-    impl CanDbcSignal for Mode {
+/// MuxTest::Mode public api (CanDbcSignal trait)
+impl CanDbcSignal for Mode {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[61..64].load_le::<u8>();
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[61..64].load_le::<u8>();
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -1093,7 +1988,7 @@ pub mod MuxTest { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -1148,38 +2043,127 @@ pub mod MuxTest { /// Message name space
 
 
     impl Speed  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["INFOTAINMENT_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "m/s";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = -51.2;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 51.1;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 0.1;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 10;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 3;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(Speed {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            Speed {
                 status: CanDataStatus::Unset,
                 name:"Speed",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u16) -> f64 {
+            // Sign-extend raw from the DBC signal bit-width before scaling.
+            let shift = u16::BITS - 10u32;
+            let signed: i16 = ((raw << shift) as i16) >> shift;
+            (signed as f64) * 0.1_f64 + 0_f64
+        }
 
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u16 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 10 == 64 { u64::MAX } else { (1u64 << 10) - 1 };
+            let factor = 0.1_f64;
+            let offset = 0_f64;
+            let __raw_f = (value - offset) / factor;
+            //  Encode signed value as two's complement on 10 bits.
+            ((__raw_f as i64 as u64) & __mask) as u16
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < -51.2_f64 || 51.1_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [-51.2..51.1]",value)));
             }
-            let factor = 0.1_f64;
-            let offset = 0_f64;
-            let value = ((value - offset) / factor) as u16;
-            let value = u16::from_ne_bytes(value.to_ne_bytes());
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u16, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[3..13].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if value < -51.2_f64 || 51.1_f64 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-51.2..51.1]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[3..13].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> f64 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[3..13].load_le::<u16>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message,
+        /// or when the frame multiplexor selects another page than the one of this signal.
+        pub fn set_signal_into(value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("MuxTest payload len:{} shorter than 8 bytes", data.len())));
+            }
+            let __mux_raw_value = MuxSignal::raw_from_physical(MuxSignal::decode(data)) as u64;
+            if __mux_raw_value != 1 {
+                return Err(CanError::new("signal-set-values-fail", format!("MuxTest.Speed is on page 1, frame multiplexor selects {__mux_raw_value}")));
+            }
+            Self::encode(value, data)
+        }
+
     } // MuxTest::Speed impl end
 
 
@@ -1201,46 +2185,43 @@ pub mod MuxTest { /// Message name space
     }
 
 
-    /// MuxTest::Speed public api (CanDbcSignal trait)
-    impl CanDbcSignal for Speed {
+/// MuxTest::Speed public api (CanDbcSignal trait)
+impl CanDbcSignal for Speed {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[3..13].load_le::<u16>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u16 = frame.data.view_bits::<Lsb0>()[3..13].load_le::<u16>();
 
-                    let value = i16::from_ne_bytes(value.to_ne_bytes());
 
-                    let factor = 0.1_f64;
-                    let offset = 0_f64;
-                    let newval = (value as f64) * factor + offset;
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
                         Some(old) => old != newval,
-                };
+                    };
                     self.value = Some(newval);
                     if changed {
-                        self.status = CanDataStatus::Updated;
-                        self.stamp = frame.stamp;
-                    } else {
-                       self.status = CanDataStatus::Unchanged;
-                    }
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                } else {
+                        self.status= CanDataStatus::Unchanged;
+                }
 
                 },
                 CanBcmOpCode::RxTimeout => {
@@ -1266,7 +2247,7 @@ pub mod MuxTest { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -1321,31 +2302,123 @@ pub mod MuxTest { /// Message name space
 
 
     impl Temperature  {
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; 1] = ["INFOTAINMENT_CTRL"];
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "degC";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = -128.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 127.0;
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = 0.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 8;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 13;
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        };
+
         pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {
-            Rc::new(RefCell::new(Box::new(Temperature {
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {
+            Temperature {
                 status: CanDataStatus::Unset,
                 name:"Temperature",
                 value: None,
                 stamp: 0,
                 callback: None,
-            })))
+            }
         }
 
         fn reset_value(&mut self) {
             self.value= None;
             }
 
+        #[inline]
         fn get_typed_value(&self) -> i8 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:i8, data:&mut [u8]) -> Result<(),CanError> {
-            let value = u8::from_ne_bytes(value.to_ne_bytes());
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub const fn physical_from_raw(raw: u8) -> i8 {
+            let shift = u8::BITS - 8u32;
+            let signed: i8 = ((raw << shift) as i8) >> shift;
+            signed
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub const fn raw_from_physical(value: i8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 8 == 64 { u64::MAX } else { (1u64 << 8) - 1 };
+            //  Encode signed integer as two's complement on 8 bits.
+            ((value as i64 as u64) & __mask) as u8
+        }
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:i8, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
+            if value < -128_i8 || 127_i8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-128..127]",value)));
+            }
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Lsb0>()[13..21].store_le(value);
+        }
 
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:i8, data:&mut [u8]) -> Result<(),CanError> {
+            if value < -128_i8 || 127_i8 < value {
+                return Err(CanError::new("invalid-signal-value",format!("value={} not in [-128..127]",value)));
+            }
+            let value = Self::raw_from_physical(value);
+            data.view_bits_mut::<Lsb0>()[13..21].store_le(value);
             Ok(())
         }
 
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> i8 {
+            Self::physical_from_raw(data.view_bits::<Lsb0>()[13..21].load_le::<u8>())
+        }
+
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message,
+        /// or when the frame multiplexor selects another page than the one of this signal.
+        pub fn set_signal_into(value:i8, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("MuxTest payload len:{} shorter than 8 bytes", data.len())));
+            }
+            let __mux_raw_value = MuxSignal::raw_from_physical(MuxSignal::decode(data)) as u64;
+            if __mux_raw_value != 1 {
+                return Err(CanError::new("signal-set-values-fail", format!("MuxTest.Temperature is on page 1, frame multiplexor selects {__mux_raw_value}")));
+            }
+            Self::encode(value, data)
+        }
+
     } // MuxTest::Temperature impl end
 
 
@@ -1367,37 +2440,37 @@ pub mod MuxTest { /// Message name space
     }
 
 
-    /// MuxTest::Temperature public api (CanDbcSignal trait)
-    impl CanDbcSignal for Temperature {
+/// MuxTest::Temperature public api (CanDbcSignal trait)
+impl CanDbcSignal for Temperature {
 
-        fn get_name(&self) -> &'static str {
-            self.name
-        }
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
 
-        fn get_stamp(&self) -> u64 {
-            self.stamp
-        }
+    fn get_stamp(&self) -> u64 {
+        self.stamp
+    }
 
-        fn get_status(&self) -> CanDataStatus{
-            self.status
-        }
+    fn get_status(&self) -> CanDataStatus {
+        self.status
+    }
 
-        fn as_any(&mut self) -> &mut dyn Any {
-            self
-        }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
 
-        fn update(&mut self, frame: &CanMsgData) -> i32 {
-            match frame.opcode {
-                CanBcmOpCode::RxChanged => {
-                    let value = frame.data.view_bits::<Lsb0>()[13..21].load_le::<u8>();
+    fn update(&mut self, frame: &CanMsgData) -> i32 {
+        match frame.opcode {
+            CanBcmOpCode::RxChanged => {
+                let raw: u8 = frame.data.view_bits::<Lsb0>()[13..21].load_le::<u8>();
 
-                    let value = i8::from_ne_bytes(value.to_ne_bytes());
 
+                    let newval = Self::physical_from_raw(raw);
                     let changed = match self.value {
                         None => true,
-                        Some(old) => old != value,
+                        Some(old) => old != newval,
                     };
-                    self.value = Some(value);
+                    self.value = Some(newval);
                     if changed {
                         self.status= CanDataStatus::Updated;
                         self.stamp= frame.stamp;
@@ -1429,7 +2502,7 @@ pub mod MuxTest { /// Message name space
                 Ok(val) => val,
                 Err(error) => return Err(error)
             };
-            self.set_typed_value(value, data)
+            self.set_physical(value, data)
         }
 
         fn get_value(&self) -> CanDbcType {
@@ -1464,6 +2537,7 @@ pub mod MuxTest { /// Message name space
         name: &'static str,
         status: CanBcmOpCode,
         listeners: i32,
+        signal_listeners: [i32;7],
         stamp: u64,
         id: u32,
     }
@@ -1475,6 +2549,7 @@ pub mod MuxTest { /// Message name space
                 name: "MuxTest",
                 status: CanBcmOpCode::Unknown,
                 listeners: 0,
+                signal_listeners: [0;7],
                 stamp: 0,
                 callback: None,
                 signals: [
@@ -1490,55 +2565,145 @@ pub mod MuxTest { /// Message name space
             })))
         }
 
+        /// Return code of each signal callback (`CanSigCtrl::sig_notification`) on the last
+        /// frame, in `DbcSignal` order: 0 without callback or for an inactive mux page, -1 when
+        /// the callback was busy. `get_listeners()` is their sum.
+        pub fn get_signal_listeners(&self) -> &[i32;7] {
+            &self.signal_listeners
+        }
+
+        /// Return code of the `signal` callback on the last frame, see `get_signal_listeners()`.
+        pub fn get_signal_listener(&self, signal: DbcSignal) -> i32 {
+            self.signal_listeners[signal as usize]
+        }
+
+        /// Index in `get_signals()` of the signal called `name`, its `get_name()` or DBC name.
+        pub fn signal_index(name: &str) -> Option<usize> {
+            match name {
+                "MuxSignal" | "MUX_signal" => Some(0),
+                "Open" | "open" => Some(1),
+                "Closed" | "closed" => Some(2),
+                "Direction" | "direction" => Some(3),
+                "Mode" | "mode" => Some(4),
+                "Speed" | "speed" => Some(5),
+                "Temperature" | "temperature" => Some(6),
+                _ => None,
+            }
+        }
+
+        /// Signal called `name`, its `get_name()` or DBC name, without scanning the signals.
+        pub fn get_signal(&self, name: &str) -> Option<Rc<RefCell<Box<dyn CanDbcSignal>>>> {
+            Self::signal_index(name).map(|idx| Rc::clone(&self.signals[idx]))
+        }
+
+        /// Unit, range, scaling and layout of the signal called `name`, its `get_name()` or DBC
+        /// name, for generic tools holding a `dyn CanDbcSignal`.
+        pub fn signal_meta(name: &str) -> Option<super::SignalMeta> {
+            match name {
+                "MuxSignal" | "MUX_signal" => Some(MuxSignal::META),
+                "Open" | "open" => Some(Open::META),
+                "Closed" | "closed" => Some(Closed::META),
+                "Direction" | "direction" => Some(Direction::META),
+                "Mode" | "mode" => Some(Mode::META),
+                "Speed" | "speed" => Some(Speed::META),
+                "Temperature" | "temperature" => Some(Temperature::META),
+                _ => None,
+            }
+        }
+
+
+        /// CAN id without the extended flag, 11 or 29 bits
+        pub const CAN_ID: u32 = 0x142;
+        /// 29-bit id: `get_id()` and the BCM filter carry CAN_EFF_FLAG (bit 31), like Linux can_id
+        pub const EXTENDED: bool = false;
+
+        pub fn is_extended(&self) -> bool {
+            Self::EXTENDED
+        }
+
+        /// Transmission period from the GenMsgCycleTime attribute (or its default), 0 for a
+        /// message sent on event or without attribute
+        pub const CYCLE_TIME_MS: u32 = 50;
+
+        /// `CYCLE_TIME_MS`, base of the BCM rate and watchdog timers of the message
+        pub fn get_cycle_time_ms(&self) -> u32 {
+            Self::CYCLE_TIME_MS
+        }
+
+        /// Attributes (BA_) set on the message, by DBC attribute name
+        pub const ATTRIBUTES: [(&'static str, super::attributes::AttributeValue); 1] = [("GenMsgCycleTime", super::attributes::AttributeValue::Int(50))];
+
+        /// Value of the message attribute `name`, its BA_DEF_DEF_ default when not set
+        pub fn get_attribute(name: &str) -> Option<super::attributes::AttributeValue> {
+            match name {
+                "GenMsgCycleTime" => Some(super::attributes::AttributeValue::Int(50)),
+                "GenMsgSendType" => super::attributes::GEN_MSG_SEND_TYPE.default(),
+                _ => None,
+            }
+        }
 
         pub fn set_values(&mut self, mux_signal: u8, open: bool, closed: bool, direction: u8, mode: u8, speed: f64, temperature: i8, frame: &mut[u8]) -> Result<&mut Self, CanError> {
 
 
-            let __mux_raw_value: u64 = mux_signal as u64;
-            println!("RLM __mux_raw_value{}",__mux_raw_value);
+            let __mux_raw_value: u64 = {
+            let __mask: u64 = if 2 == 64 { u64::MAX } else { (1u64 << 2) - 1 };
+            ((mux_signal as u64) & __mask)
+    };
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
                 Ok(mut signal) => signal.set_value(CanDbcType::U8(mux_signal), frame)?,
                 Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error mux_signal:U8")),
             }
 
             if __mux_raw_value == 0 {
-                match Rc::clone (&self.signals[1]).try_borrow_mut() {
+                
+
+                match Rc::clone(&self.signals[1]).try_borrow_mut() {
                     Ok(mut signal) => signal.set_value(CanDbcType::Bool(open), frame)?,
                     Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error open:Bool")),
                 }
             }
 
             if __mux_raw_value == 0 {
-                match Rc::clone (&self.signals[2]).try_borrow_mut() {
+                
+
+                match Rc::clone(&self.signals[2]).try_borrow_mut() {
                     Ok(mut signal) => signal.set_value(CanDbcType::Bool(closed), frame)?,
                     Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error closed:Bool")),
                 }
             }
 
             if __mux_raw_value == 0 {
-                match Rc::clone (&self.signals[3]).try_borrow_mut() {
+                
+
+                match Rc::clone(&self.signals[3]).try_borrow_mut() {
                     Ok(mut signal) => signal.set_value(CanDbcType::U8(direction), frame)?,
                     Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error direction:U8")),
                 }
             }
 
             if __mux_raw_value == 0 {
-                match Rc::clone (&self.signals[4]).try_borrow_mut() {
+                
+
+                match Rc::clone(&self.signals[4]).try_borrow_mut() {
                     Ok(mut signal) => signal.set_value(CanDbcType::U8(mode), frame)?,
                     Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error mode:U8")),
                 }
             }
 
             if __mux_raw_value == 1 {
-                match Rc::clone (&self.signals[5]).try_borrow_mut() {
+                
+
+                match Rc::clone(&self.signals[5]).try_borrow_mut() {
                     Ok(mut signal) => signal.set_value(CanDbcType::F64(speed), frame)?,
                     Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error speed:F64")),
                 }
             }
 
             if __mux_raw_value == 1 {
-                match Rc::clone (&self.signals[6]).try_borrow_mut() {
+                
+
+                match Rc::clone(&self.signals[6]).try_borrow_mut() {
                     Ok(mut signal) => signal.set_value(CanDbcType::I8(temperature), frame)?,
                     Err(_) => return Err(CanError::new("signal-set-values-fail","Internal error temperature:I8")),
                 }
@@ -1549,42 +2714,309 @@ pub mod MuxTest { /// Message name space
     }
 
 
+    /// `MuxTest(0x..) <opcode> stamp:<us> Signal:value ...`, for logs
+    impl fmt::Display for DbcMessage {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(fmt, "MuxTest({:#x}) {:?} stamp:{}", self.id, self.status, self.stamp)?;
+            match self.signals[0].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " MuxSignal:{value}")?,
+                None => write!(fmt, " MuxSignal:?")?,
+            }
+            match self.signals[1].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()) {
+                Some(value) => write!(fmt, " Open:{value}")?,
+                None => write!(fmt, " Open:?")?,
+            }
+            match self.signals[2].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()) {
+                Some(value) => write!(fmt, " Closed:{value}")?,
+                None => write!(fmt, " Closed:?")?,
+            }
+            match self.signals[3].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " Direction:{value}")?,
+                None => write!(fmt, " Direction:?")?,
+            }
+            match self.signals[4].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()) {
+                Some(value) => write!(fmt, " Mode:{value}")?,
+                None => write!(fmt, " Mode:?")?,
+            }
+            match self.signals[5].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()) {
+                Some(value) => write!(fmt, " Speed:{value}")?,
+                None => write!(fmt, " Speed:?")?,
+            }
+            match self.signals[6].try_borrow().ok().and_then(|signal| signal.get_value().cast::<i8>().ok()) {
+                Some(value) => write!(fmt, " Temperature:{value}")?,
+                None => write!(fmt, " Temperature:?")?,
+            }
+            Ok(())
+        }
+    }
+
+    impl fmt::Debug for DbcMessage {
+        fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {
+            format.debug_struct("MuxTest")
+                .field("id", &self.id)
+                .field("status", &self.status)
+                .field("stamp", &self.stamp)
+                .field("mux_signal", &self.signals[0].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("open", &self.signals[1].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()))
+                .field("closed", &self.signals[2].try_borrow().ok().and_then(|signal| signal.get_value().cast::<bool>().ok()))
+                .field("direction", &self.signals[3].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("mode", &self.signals[4].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u8>().ok()))
+                .field("speed", &self.signals[5].try_borrow().ok().and_then(|signal| signal.get_value().cast::<f64>().ok()))
+                .field("temperature", &self.signals[6].try_borrow().ok().and_then(|signal| signal.get_value().cast::<i8>().ok()))
+                .finish()
+        }
+    }
+
+    impl Serialize for DbcMessage {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::MessageState(self).serialize(serializer)
+        }
+    }
+
+
+    /// Pages of the MuxTest multiplexor MUX_signal, one per selector value with multiplexed
+    /// signals, see `DbcMessage::get_active_group()`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum MuxGroup {
+        State,
+        Measure,
+    }
+
+    impl MuxGroup {
+        /// Multiplexor value selecting this page
+        pub fn selector(self) -> u64 {
+            match self {
+                MuxGroup::State => 0,
+                MuxGroup::Measure => 1,
+            }
+        }
+
+        /// Page selected by the multiplexor `value`, `None` when no signal is multiplexed on it
+        pub fn from_selector(value: u64) -> Option<Self> {
+            match value {
+                0 => Some(MuxGroup::State),
+                1 => Some(MuxGroup::Measure),
+                _ => None,
+            }
+        }
+    }
+
+    impl DbcMessage {
+        /// Page selected by the multiplexor of the last decoded or encoded values, `None` for a
+        /// selector value without multiplexed signals
+        pub fn get_active_group(&self) -> Option<MuxGroup> {
+            MuxGroup::from_selector(self.signals[0].try_borrow().ok()?.get_value().cast::<u8>().ok()? as u64)
+        }
+    }
+
+
+    /// Values of every MuxTest signal, see `DbcMessage::snapshot()`
+    #[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+    pub struct SignalValues {
+        pub mux_signal: u8,
+        pub open: bool,
+        pub closed: bool,
+        pub direction: u8,
+        pub mode: u8,
+        pub speed: f64,
+        pub temperature: i8,
+    }
+
+    /// Copy of a MuxTest message taken at a point in time, holding no RefCell borrow
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub struct MessageSnapshot {
+        pub stamp: u64,
+        pub status: CanBcmOpCode,
+        pub values: SignalValues,
+    }
+
+    impl DbcMessage {
+        /// Copy the stamp, status and every signal value of the last decoded frame.
+        pub fn snapshot(&self) -> Result<MessageSnapshot, CanError> {
+            Ok(MessageSnapshot {
+                stamp: self.stamp,
+                status: self.status,
+                values: SignalValues {
+                    mux_signal: match self.signals[0].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error mux_signal")),
+                    },
+                    open: match self.signals[1].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error open")),
+                    },
+                    closed: match self.signals[2].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error closed")),
+                    },
+                    direction: match self.signals[3].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error direction")),
+                    },
+                    mode: match self.signals[4].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error mode")),
+                    },
+                    speed: match self.signals[5].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error speed")),
+                    },
+                    temperature: match self.signals[6].try_borrow() {
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("message-snapshot-fail","Internal error temperature")),
+                    },
+                },
+            })
+        }
+    }
+
+    /// Decode a MuxTest payload into its values, without signal object nor pool.
+    /// Signals of an inactive multiplexed page read as their default value.
+    pub fn decode(data: &[u8]) -> Result<SignalValues, CanError> {
+        if data.len() < 8 {
+            return Err(CanError::new("signal-update-fail", format!("MuxTest payload len:{} shorter than 8 bytes", data.len())));
+        }
+
+            let __mux_raw_value: u64 = (data.view_bits::<Lsb0>()[0..2].load_le::<u8>()) as u64;
+        Ok(SignalValues {
+            mux_signal: MuxSignal::decode(data),
+            open: if __mux_raw_value == 0 { Open::decode(data) } else { Default::default() },
+            closed: if __mux_raw_value == 0 { Closed::decode(data) } else { Default::default() },
+            direction: if __mux_raw_value == 0 { Direction::decode(data) } else { Default::default() },
+            mode: if __mux_raw_value == 0 { Mode::decode(data) } else { Default::default() },
+            speed: if __mux_raw_value == 1 { Speed::decode(data) } else { Default::default() },
+            temperature: if __mux_raw_value == 1 { Temperature::decode(data) } else { Default::default() },
+        })
+    }
+
+    /// Encode `values` into a MuxTest payload, without signal object nor pool.
+    /// Only the multiplexed page selected by the multiplexor value is written.
+    pub fn encode(values: &SignalValues, data: &mut [u8]) -> Result<(), CanError> {
+        if data.len() < 8 {
+            return Err(CanError::new("signal-set-values-fail", format!("MuxTest payload len:{} shorter than 8 bytes", data.len())));
+        }
+        let __mux_raw_value = MuxSignal::raw_from_physical(values.mux_signal) as u64;
+        MuxSignal::encode(values.mux_signal, data)?;
+        if __mux_raw_value == 0 { Open::encode(values.open, data)?; }
+        if __mux_raw_value == 0 { Closed::encode(values.closed, data)?; }
+        if __mux_raw_value == 0 { Direction::encode(values.direction, data)?; }
+        if __mux_raw_value == 0 { Mode::encode(values.mode, data)?; }
+        if __mux_raw_value == 1 { Speed::encode(values.speed, data)?; }
+        if __mux_raw_value == 1 { Temperature::encode(values.temperature, data)?; }
+        Ok(())
+    }
+
+    /// Typed builder of a MuxTest payload: `MuxTest::builder().signal(value).build(&mut data)?`.
+    /// Signals without setter call keep their GenSigStartValue, or their default.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct MessageBuilder {
+        values: SignalValues,
+    }
+
+    /// Start a MuxTest payload, see `MessageBuilder`.
+    pub fn builder() -> MessageBuilder {
+        MessageBuilder {
+            values: SignalValues::default(),
+        }
+    }
+
+    impl MessageBuilder {
+        /// MuxSignal physical value
+        #[must_use]
+        pub fn mux_signal(mut self, value: u8) -> Self {
+            self.values.mux_signal = value;
+            self
+        }
+
+        /// Open physical value
+        #[must_use]
+        pub fn open(mut self, value: bool) -> Self {
+            self.values.open = value;
+            self
+        }
+
+        /// Closed physical value
+        #[must_use]
+        pub fn closed(mut self, value: bool) -> Self {
+            self.values.closed = value;
+            self
+        }
+
+        /// Direction physical value
+        #[must_use]
+        pub fn direction(mut self, value: u8) -> Self {
+            self.values.direction = value;
+            self
+        }
+
+        /// Mode physical value
+        #[must_use]
+        pub fn mode(mut self, value: u8) -> Self {
+            self.values.mode = value;
+            self
+        }
+
+        /// Speed physical value in m/s
+        #[must_use]
+        pub fn speed(mut self, value: f64) -> Self {
+            self.values.speed = value;
+            self
+        }
+
+        /// Temperature physical value in degC
+        #[must_use]
+        pub fn temperature(mut self, value: i8) -> Self {
+            self.values.temperature = value;
+            self
+        }
+
+        /// Values `build()` encodes.
+        pub fn get_values(&self) -> &SignalValues {
+            &self.values
+        }
+
+        /// Encode the values into `data`, see `encode()`.
+        pub fn build(&self, data: &mut [u8]) -> Result<(), CanError> {
+            encode(&self.values, data)
+        }
+    }
+
     impl CanDbcMessage for DbcMessage {
         fn reset(&mut self) -> Result<(), CanError> {
             self.status=CanBcmOpCode::Unknown;
             self.stamp=0;
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error mux_signal:U8")),
             }
 
-            match Rc::clone (&self.signals[1]).try_borrow_mut() {
+            match Rc::clone(&self.signals[1]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error open:Bool")),
             }
 
-            match Rc::clone (&self.signals[2]).try_borrow_mut() {
+            match Rc::clone(&self.signals[2]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error closed:Bool")),
             }
 
-            match Rc::clone (&self.signals[3]).try_borrow_mut() {
+            match Rc::clone(&self.signals[3]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error direction:U8")),
             }
 
-            match Rc::clone (&self.signals[4]).try_borrow_mut() {
+            match Rc::clone(&self.signals[4]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error mode:U8")),
             }
 
-            match Rc::clone (&self.signals[5]).try_borrow_mut() {
+            match Rc::clone(&self.signals[5]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error speed:F64")),
             }
 
-            match Rc::clone (&self.signals[6]).try_borrow_mut() {
+            match Rc::clone(&self.signals[6]).try_borrow_mut() {
                 Ok(mut signal) => signal.reset(),
                 Err(_) => return Err(CanError::new("signal-reset-fail","Internal error temperature:I8")),
             }
@@ -1596,81 +3028,106 @@ pub mod MuxTest { /// Message name space
             self.stamp= frame.stamp;
             self.status= frame.opcode;
             self.listeners= 0;
+            self.signal_listeners= [0;7];
 
             let __mux_raw_value: u64 = (frame.data.view_bits::<Lsb0>()[0..2].load_le::<u8>()) as u64;
 
-            match Rc::clone (&self.signals[0]).try_borrow_mut() {
-                Ok(mut signal) => self.listeners += signal.update(frame),
+            match Rc::clone(&self.signals[0]).try_borrow_mut() {
+                Ok(mut signal) => { self.signal_listeners[0] = signal.update(frame); self.listeners += self.signal_listeners[0]; },
                 Err(_) => return Err(CanError::new("signal-update-fail","Internal error mux_signal:U8")),
             }
 
             if __mux_raw_value == 0 {
-                match Rc::clone (&self.signals[1]).try_borrow_mut() {
-                    Ok(mut signal) => self.listeners += signal.update(frame),
+                
+
+                match Rc::clone(&self.signals[1]).try_borrow_mut() {
+                    Ok(mut signal) => { self.signal_listeners[1] = signal.update(frame); self.listeners += self.signal_listeners[1]; },
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error open:Bool")),
                 }
+
             } else {
-                match Rc::clone (&self.signals[1]).try_borrow_mut() {
+
+                match Rc::clone(&self.signals[1]).try_borrow_mut() {
                     Ok(mut signal) => signal.reset(),
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error open:Bool")),
                 }
             }
 
             if __mux_raw_value == 0 {
-                match Rc::clone (&self.signals[2]).try_borrow_mut() {
-                    Ok(mut signal) => self.listeners += signal.update(frame),
+                
+
+                match Rc::clone(&self.signals[2]).try_borrow_mut() {
+                    Ok(mut signal) => { self.signal_listeners[2] = signal.update(frame); self.listeners += self.signal_listeners[2]; },
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error closed:Bool")),
                 }
+
             } else {
-                match Rc::clone (&self.signals[2]).try_borrow_mut() {
+
+                match Rc::clone(&self.signals[2]).try_borrow_mut() {
                     Ok(mut signal) => signal.reset(),
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error closed:Bool")),
                 }
             }
 
             if __mux_raw_value == 0 {
-                match Rc::clone (&self.signals[3]).try_borrow_mut() {
-                    Ok(mut signal) => self.listeners += signal.update(frame),
+                
+
+                match Rc::clone(&self.signals[3]).try_borrow_mut() {
+                    Ok(mut signal) => { self.signal_listeners[3] = signal.update(frame); self.listeners += self.signal_listeners[3]; },
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error direction:U8")),
                 }
+
             } else {
-                match Rc::clone (&self.signals[3]).try_borrow_mut() {
+
+                match Rc::clone(&self.signals[3]).try_borrow_mut() {
                     Ok(mut signal) => signal.reset(),
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error direction:U8")),
                 }
             }
 
             if __mux_raw_value == 0 {
-                match Rc::clone (&self.signals[4]).try_borrow_mut() {
-                    Ok(mut signal) => self.listeners += signal.update(frame),
+                
+
+                match Rc::clone(&self.signals[4]).try_borrow_mut() {
+                    Ok(mut signal) => { self.signal_listeners[4] = signal.update(frame); self.listeners += self.signal_listeners[4]; },
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error mode:U8")),
                 }
+
             } else {
-                match Rc::clone (&self.signals[4]).try_borrow_mut() {
+
+                match Rc::clone(&self.signals[4]).try_borrow_mut() {
                     Ok(mut signal) => signal.reset(),
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error mode:U8")),
                 }
             }
 
             if __mux_raw_value == 1 {
-                match Rc::clone (&self.signals[5]).try_borrow_mut() {
-                    Ok(mut signal) => self.listeners += signal.update(frame),
+                
+
+                match Rc::clone(&self.signals[5]).try_borrow_mut() {
+                    Ok(mut signal) => { self.signal_listeners[5] = signal.update(frame); self.listeners += self.signal_listeners[5]; },
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error speed:F64")),
                 }
+
             } else {
-                match Rc::clone (&self.signals[5]).try_borrow_mut() {
+
+                match Rc::clone(&self.signals[5]).try_borrow_mut() {
                     Ok(mut signal) => signal.reset(),
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error speed:F64")),
                 }
             }
 
             if __mux_raw_value == 1 {
-                match Rc::clone (&self.signals[6]).try_borrow_mut() {
-                    Ok(mut signal) => self.listeners += signal.update(frame),
+                
+
+                match Rc::clone(&self.signals[6]).try_borrow_mut() {
+                    Ok(mut signal) => { self.signal_listeners[6] = signal.update(frame); self.listeners += self.signal_listeners[6]; },
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error temperature:I8")),
                 }
+
             } else {
-                match Rc::clone (&self.signals[6]).try_borrow_mut() {
+
+                match Rc::clone(&self.signals[6]).try_borrow_mut() {
                     Ok(mut signal) => signal.reset(),
                     Err(_) => return Err(CanError::new("signal-update-fail","Internal error temperature:I8")),
                 }
@@ -1680,7 +3137,7 @@ pub mod MuxTest { /// Message name space
                 None => {},
                 Some(callback) => {
                     match callback.try_borrow() {
-                        Err(_) => println!("fail to get message callback reference"),
+                        Err(_) => {println!("fail to get message callback reference");},
                         Ok(cb_ref) => cb_ref.msg_notification(self),
                     }
                 }
@@ -1724,6 +3181,59 @@ pub mod MuxTest { /// Message name space
 
 } // end MuxTest message
 
+
+/// Network nodes (BU_) with the messages they transmit and the signals they consume
+pub mod nodes {
+    pub mod BodyCtrl {
+        pub const NAME: &str = "BODY_CTRL";
+        pub const TRANSMITS: [u32; 0] = [];
+        /// (canid, signal) consumed by this node
+        pub const CONSUMES: [(u32, &str); 0] = [];
+    }
+    pub mod PowertrainCtrl {
+        pub const NAME: &str = "POWERTRAIN_CTRL";
+        pub const TRANSMITS: [u32; 1] = [322];
+        /// (canid, signal) consumed by this node
+        pub const CONSUMES: [(u32, &str); 0] = [];
+    }
+    pub mod EnergyCtrl {
+        pub const NAME: &str = "ENERGY_CTRL";
+        pub const TRANSMITS: [u32; 0] = [];
+        /// (canid, signal) consumed by this node
+        pub const CONSUMES: [(u32, &str); 0] = [];
+    }
+    pub mod ChassisCtrl {
+        pub const NAME: &str = "CHASSIS_CTRL";
+        pub const TRANSMITS: [u32; 0] = [];
+        /// (canid, signal) consumed by this node
+        pub const CONSUMES: [(u32, &str); 0] = [];
+    }
+    pub mod InfotainmentCtrl {
+        pub const NAME: &str = "INFOTAINMENT_CTRL";
+        pub const TRANSMITS: [u32; 0] = [];
+        /// (canid, signal) consumed by this node
+        pub const CONSUMES: [(u32, &str); 7] = [(322, "MuxSignal"), (322, "Open"), (322, "Closed"), (322, "Direction"), (322, "Mode"), (322, "Speed"), (322, "Temperature")];
+    }
+    pub mod DiagTool {
+        pub const NAME: &str = "DIAG_TOOL";
+        pub const TRANSMITS: [u32; 0] = [];
+        /// (canid, signal) consumed by this node
+        pub const CONSUMES: [(u32, &str); 0] = [];
+    }
+    pub mod SensorGateway {
+        pub const NAME: &str = "SENSOR_GATEWAY";
+        pub const TRANSMITS: [u32; 0] = [];
+        /// (canid, signal) consumed by this node
+        pub const CONSUMES: [(u32, &str); 0] = [];
+    }
+    pub mod Logger {
+        pub const NAME: &str = "LOGGER";
+        pub const TRANSMITS: [u32; 0] = [];
+        /// (canid, signal) consumed by this node
+        pub const CONSUMES: [(u32, &str); 0] = [];
+    }
+} // end nodes
+
 enum DbcMessages {
     MuxTest,
 
@@ -1732,12 +3242,18 @@ enum DbcMessages {
 pub struct CanMsgPool {
     uid: &'static str,
     pool: [Rc<RefCell<Box<dyn CanDbcMessage>>>;1],
+    filters: RefCell<Vec<CanIdFilter>>,
+    stamp: Rc<Cell<u64>>,
+    unknown: RefCell<UnknownIds>,
 }
 
 impl CanMsgPool {
     pub fn new(uid: &'static str) -> Self {
         CanMsgPool {
             uid: uid,
+            filters: RefCell::new(Vec::new()),
+            stamp: Rc::new(Cell::new(0)),
+            unknown: RefCell::new(UnknownIds::default()),
             pool: [
                 MuxTest::DbcMessage::new(),
 
@@ -1769,12 +3285,405 @@ impl CanDbcPool for CanMsgPool {
     }
 
     fn update(&self, data: &CanMsgData) -> Result<RefMut<'_, Box<dyn CanDbcMessage>>, CanError> {
-        let mut msg= match self.get_mut(data.canid) {
+        // ids outside the pool may reach a message through a subscribe_ids() filter
+        let canid = self.resolve(data.canid).unwrap_or(data.canid);
+        if self.get_ids().binary_search(&canid).is_err() {
+            self.unknown_frame(data);
+        }
+        let mut msg= match self.get_mut(canid) {
             Err(error) => return Err(error),
             Ok(msg_ref) => msg_ref,
         };
+        // frame time of the CallbackFlow::Pause of subscribe_signals_flow() callbacks
+        self.stamp.set(data.stamp);
         msg.update(data)?;
         Ok(msg)
     }
  }
+
+/// `subscribe_signals()` callback, shared by every matching signal
+struct SignalCallback(Rc<dyn Fn(&dyn CanDbcSignal) -> i32>);
+
+impl CanSigCtrl for SignalCallback {
+    fn sig_notification(&self, signal: &dyn CanDbcSignal) -> i32 {
+        (self.0)(signal)
+    }
+}
+
+/// What a `subscribe_signals_flow()` callback asks the dispatch to do next
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallbackFlow {
+    /// Keep notifying
+    Continue,
+    /// Stop notifying this subscription, for every signal it wired
+    Unsubscribe,
+    /// Skip the notifications of this subscription for this many milliseconds of frame time
+    Pause(u64),
+}
+
+/// One `subscribe_signals_flow()` subscription, shared by every matching signal
+struct FlowSubscription {
+    callback: Box<dyn Fn(&dyn CanDbcSignal) -> CallbackFlow>,
+    /// stamp of the frame the pool is dispatching
+    stamp: Rc<Cell<u64>>,
+    unsubscribed: Cell<bool>,
+    resume_at: Cell<u64>,
+}
+
+struct FlowCallback(Rc<FlowSubscription>);
+
+impl CanSigCtrl for FlowCallback {
+    fn sig_notification(&self, signal: &dyn CanDbcSignal) -> i32 {
+        let flow = &self.0;
+        let now = flow.stamp.get();
+        if flow.unsubscribed.get() || now < flow.resume_at.get() {
+            return 0;
+        }
+        match (flow.callback)(signal) {
+            CallbackFlow::Continue => {},
+            CallbackFlow::Unsubscribe => flow.unsubscribed.set(true),
+            CallbackFlow::Pause(ms) => flow.resume_at.set(now.saturating_add(ms.saturating_mul(1000))),
+        }
+        0
+    }
+}
+
+/// Shell-like match where `*` stands for any sequence of characters
+fn glob_match(pattern: &str, text: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == text,
+        Some((head, tail)) => {
+            text.starts_with(head)
+                && (head.len()..=text.len())
+                    .filter(|idx| text.is_char_boundary(*idx))
+                    .any(|idx| glob_match(tail, &text[idx..]))
+        },
+    }
+}
+
+impl CanMsgPool {
+    /// Set `callback` on every signal matching `pattern`, "Signal" or "Message.Signal" where `*`
+    /// matches any sequence (e.g. "Battery*", "Bms*.*Temp"), replacing their previous callback.
+    /// Returns the number of signals wired.
+    pub fn subscribe_signals<F>(&self, pattern: &str, callback: F) -> Result<usize, CanError>
+    where
+        F: Fn(&dyn CanDbcSignal) -> i32 + 'static,
+    {
+        let callback: Rc<dyn Fn(&dyn CanDbcSignal) -> i32> = Rc::new(callback);
+        self.wire_signals(pattern, || Box::new(SignalCallback(callback.clone())))
+    }
+
+    /// Like `subscribe_signals()`, the `callback` result steering the subscription: `Continue`,
+    /// `Unsubscribe` for good, or `Pause(ms)` skipping notifications until a frame stamped `ms`
+    /// later. Pauses follow the stamps of the frames given to `update()` of this pool.
+    pub fn subscribe_signals_flow<F>(&self, pattern: &str, callback: F) -> Result<usize, CanError>
+    where
+        F: Fn(&dyn CanDbcSignal) -> CallbackFlow + 'static,
+    {
+        let flow = Rc::new(FlowSubscription {
+            callback: Box::new(callback),
+            stamp: Rc::clone(&self.stamp),
+            unsubscribed: Cell::new(false),
+            resume_at: Cell::new(0),
+        });
+        self.wire_signals(pattern, || Box::new(FlowCallback(Rc::clone(&flow))))
+    }
+
+    /// Set a callback from `make` on every signal matching `pattern`
+    fn wire_signals(&self, pattern: &str, make: impl Fn() -> Box<dyn CanSigCtrl>) -> Result<usize, CanError> {
+        let (msg_pattern, sig_pattern) = pattern.split_once('.').unwrap_or(("*", pattern));
+        let mut count = 0;
+        for msg in &self.pool {
+            let msg = match msg.try_borrow() {
+                Ok(msg) => msg,
+                Err(_) => return Err(CanError::new("message-get_mut", "Internal error message borrowed")),
+            };
+            if !glob_match(msg_pattern, msg.get_name()) {
+                continue;
+            }
+            for signal in msg.get_signals() {
+                let mut signal = match signal.try_borrow_mut() {
+                    Ok(signal) => signal,
+                    Err(_) => return Err(CanError::new("message-get_mut", format!("Internal error {} borrowed", msg.get_name()))),
+                };
+                if glob_match(sig_pattern, signal.get_name()) {
+                    signal.set_callback(make());
+                    count += 1;
+                }
+            }
+        }
+        Ok(count)
+    }
+}
+
+/// SG_ unit, range, scaling and layout of a signal, see `CanMsgPool::signal_meta()`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SignalMeta {
+    /// Physical unit, empty when the DBC gives none
+    pub unit: &'static str,
+    /// Minimum physical value, 0 and 0 for an unspecified range
+    pub min: f64,
+    /// Maximum physical value
+    pub max: f64,
+    /// Scaling factor, physical = raw * factor + offset
+    pub factor: f64,
+    /// Scaling offset
+    pub offset: f64,
+    /// Signal size in bits
+    pub bit_length: u32,
+    /// SG_ start bit, in the DBC bit numbering
+    pub start_bit: u32,
+}
+
+impl CanMsgPool {
+    /// Message called `name`, its `get_name()` or DBC name, as `get_mut()` finds it by id:
+    /// configurations name messages whose ids differ between vehicle variants.
+    pub fn get_by_name(&self, name: &str) -> Result<RefMut<'_, Box<dyn CanDbcMessage>>, CanError> {
+        let idx = match name {
+            "MuxTest" | "MUX_TEST" => 0,
+            _ => return Err(CanError::new("fail-canid-search", format!("message:{} not found", name))),
+        };
+        match self.pool[idx].try_borrow_mut() {
+            Err(_code) => Err(CanError::new("message-get_mut", "internal msg pool error")),
+            Ok(mut_ref) => Ok(mut_ref),
+        }
+    }
+
+    /// Signal called `name` (its `get_name()` or DBC name) of the message `canid`, see
+    /// `DbcMessage::signal_index()`. None when the message or signal is unknown, or while the
+    /// message is being updated.
+    pub fn get_signal(&self, canid: u32, name: &str) -> Option<Rc<RefCell<Box<dyn CanDbcSignal>>>> {
+        let (msg, sig) = match canid {
+            322 => (0, MuxTest::DbcMessage::signal_index(name)?),
+            _ => return None,
+        };
+        let msg = self.pool[msg].try_borrow().ok()?;
+        msg.get_signals().get(sig).cloned()
+    }
+
+    /// Unit, range, scaling and layout of the signal called `name` (its `get_name()` or DBC
+    /// name) of the message `canid`, see `DbcMessage::signal_meta()`. Unlike `get_signal()`,
+    /// it borrows nothing and works while the message is being updated.
+    pub fn signal_meta(canid: u32, name: &str) -> Option<SignalMeta> {
+        match canid {
+            322 => MuxTest::DbcMessage::signal_meta(name),
+            _ => None,
+        }
+    }
+}
+
+/// Frame ids routed to a pool message whose DBC id differs, e.g. J1939 source addresses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CanIdFilter {
+    /// Ids equal to `value` on the `mask` bits, each routed to the message with the same
+    /// masked id: `{ value: 0x18FEF100, mask: 0x03FFFF00 }` ignores priority and source address
+    Mask { value: u32, mask: u32 },
+    /// Ids in `first..=last`, routed to the only message of the range
+    Range { first: u32, last: u32 },
+}
+
+impl CanIdFilter {
+    /// True when the filter accepts `canid`
+    pub fn matches(&self, canid: u32) -> bool {
+        match *self {
+            CanIdFilter::Mask { value, mask } => canid & mask == value & mask,
+            CanIdFilter::Range { first, last } => (first..=last).contains(&canid),
+        }
+    }
+
+    /// Id among `ids` a frame `canid` accepted by the filter is routed to
+    fn route(&self, canid: u32, ids: &[u32]) -> Option<u32> {
+        if !self.matches(canid) {
+            return None;
+        }
+        match *self {
+            CanIdFilter::Mask { mask, .. } => ids.iter().copied().find(|id| id & mask == canid & mask),
+            CanIdFilter::Range { .. } => ids.iter().copied().find(|id| self.matches(*id)),
+        }
+    }
+}
+
+impl CanMsgPool {
+    /// Route frame ids accepted by `filter` to the pool messages it covers, returns how many
+    /// messages it covers. A filter covering no message, or a range covering several, is rejected.
+    pub fn subscribe_ids(&self, filter: CanIdFilter) -> Result<usize, CanError> {
+        let count = self.get_ids().iter().filter(|id| filter.matches(**id)).count();
+        match (filter, count) {
+            (_, 0) => return Err(CanError::new("fail-canid-search", format!("no message matches {:?}", filter))),
+            (CanIdFilter::Range { .. }, 2..) => {
+                return Err(CanError::new("fail-canid-search", format!("{} messages in {:?}", count, filter)))
+            },
+            _ => {},
+        }
+        match self.filters.try_borrow_mut() {
+            Ok(mut filters) => filters.push(filter),
+            Err(_) => return Err(CanError::new("fail-canid-search", "internal filter list error")),
+        }
+        Ok(count)
+    }
+
+    /// Pool message id a frame `canid` updates: itself when the pool has it, the extended
+    /// message when a 29-bit `canid` comes without CAN_EFF_FLAG, else the message routed by the
+    /// first `subscribe_ids()` filter accepting it
+    pub fn resolve(&self, canid: u32) -> Option<u32> {
+        if self.get_ids().binary_search(&canid).is_ok() {
+            return Some(canid);
+        }
+        let extended = canid | 0x80000000;
+        if canid > 0x7ff && self.get_ids().binary_search(&extended).is_ok() {
+            return Some(extended);
+        }
+        let filters = self.filters.try_borrow().ok()?;
+        filters.iter().find_map(|filter| filter.route(canid, self.get_ids()))
+    }
+}
+
+/// What the pool does with frames whose id is neither in the DBC nor routed by a filter
+#[derive(Clone, Default)]
+pub enum UnknownIdPolicy {
+    /// `update()` and `update_frame()` fail with a fail-canid-search error
+    #[default]
+    Reject,
+    /// `update_frame()` returns `Ok(None)`
+    Ignore,
+    /// As `Ignore`, and the frame is counted in `unknown_frames()`
+    Count,
+    /// As `Count`, and the frame is handed to the raw handler
+    Forward(Rc<dyn Fn(&CanMsgData)>),
+}
+
+/// Frames received for one unknown id
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownFrame {
+    pub canid: u32,
+    pub count: u64,
+    /// Stamp and length of the last frame
+    pub stamp: u64,
+    pub len: u8,
+}
+
+/// Unknown id policy and statistics of a pool
+#[derive(Default)]
+struct UnknownIds {
+    policy: UnknownIdPolicy,
+    frames: BTreeMap<u32, UnknownFrame>,
+}
+
+impl CanMsgPool {
+    /// Policy applied to frames with an unknown id, `Reject` by default
+    pub fn set_unknown_policy(&self, policy: UnknownIdPolicy) {
+        if let Ok(mut unknown) = self.unknown.try_borrow_mut() {
+            unknown.policy = policy;
+        }
+    }
+
+    /// Fallback receiving every frame whose id is unknown to the pool (raw bytes and stamp),
+    /// e.g. to forward or log it from a gateway: the `Forward` policy with `handler`
+    pub fn set_raw_handler<F>(&self, handler: F)
+    where
+        F: Fn(&CanMsgData) + 'static,
+    {
+        self.set_unknown_policy(UnknownIdPolicy::Forward(Rc::new(handler)));
+    }
+
+    /// Like `update()`, but a frame with an unknown id returns `Ok(None)` unless the policy
+    /// is `Reject`, so mixed traffic needs no pre-filtering
+    pub fn update_frame(&self, data: &CanMsgData) -> Result<Option<RefMut<'_, Box<dyn CanDbcMessage>>>, CanError> {
+        let canid = self.resolve(data.canid).unwrap_or(data.canid);
+        let reject = self.unknown.try_borrow().map_or(true, |unknown| matches!(unknown.policy, UnknownIdPolicy::Reject));
+        if !reject && self.get_ids().binary_search(&canid).is_err() {
+            self.unknown_frame(data);
+            return Ok(None);
+        }
+        self.update(data).map(Some)
+    }
+
+    /// Unknown ids counted by the `Count` and `Forward` policies, ordered by id
+    pub fn unknown_frames(&self) -> Vec<UnknownFrame> {
+        match self.unknown.try_borrow() {
+            Ok(unknown) => unknown.frames.values().copied().collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// Forget the unknown ids counted so far
+    pub fn clear_unknown_frames(&self) {
+        if let Ok(mut unknown) = self.unknown.try_borrow_mut() {
+            unknown.frames.clear();
+        }
+    }
+
+    fn unknown_frame(&self, data: &CanMsgData) {
+        let handler = match self.unknown.try_borrow_mut() {
+            Err(_) => return,
+            Ok(mut unknown) => {
+                let handler = match &unknown.policy {
+                    UnknownIdPolicy::Reject | UnknownIdPolicy::Ignore => return,
+                    UnknownIdPolicy::Count => None,
+                    UnknownIdPolicy::Forward(handler) => Some(Rc::clone(handler)),
+                };
+                let frame = unknown.frames.entry(data.canid).or_insert(UnknownFrame { canid: data.canid, count: 0, stamp: 0, len: 0 });
+                frame.count += 1;
+                frame.stamp = data.stamp;
+                frame.len = data.len;
+                handler
+            },
+        };
+        // called without the borrow, the handler may use the pool
+        if let Some(handler) = handler {
+            handler(data);
+        }
+    }
+}
+
+/// BCM receive filters registered by `CanMsgPool::subscribe()`, or their new timers
+/// for `CanMsgPool::set_timers()`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SubscribeOpts {
+    /// At most one RxChanged notification per message every `rate` ms, 0 for every change
+    pub rate: u64,
+    /// RxTimeout notification when a message is not received for `watchdog` ms, 0 to disable
+    pub watchdog: u64,
+    /// CAN ids to subscribe, every message of the pool when None
+    pub only: Option<Vec<u32>>,
+}
+
+impl CanMsgPool {
+    /// Register one BCM RxSetup filter per message on `sock` (a BCM socket), nothing is
+    /// registered when `only` lists a CAN id missing from the pool
+    pub fn subscribe(&self, sock: &SockCanHandle, opts: SubscribeOpts) -> Result<(), CanError> {
+        let mut flags = CanBcmFlag::RX_FILTER_ID;
+        if opts.rate > 0 || opts.watchdog > 0 {
+            flags = flags | CanBcmFlag::SET_TIMER | CanBcmFlag::START_TIMER;
+        }
+        self.rx_setup(sock, &opts, flags)
+    }
+
+    /// Change the `rate` and `watchdog` of the filters `subscribe()` registered on `sock`,
+    /// without reopening it, e.g. to switch between slow polling and high-rate diagnostics:
+    /// 0 stops the throttling or the watchdog. `only` restricts the change to some messages,
+    /// an id not subscribed yet gets a filter.
+    pub fn set_timers(&self, sock: &SockCanHandle, opts: SubscribeOpts) -> Result<(), CanError> {
+        let flags = CanBcmFlag::RX_FILTER_ID | CanBcmFlag::SET_TIMER | CanBcmFlag::START_TIMER;
+        self.rx_setup(sock, &opts, flags)
+    }
+
+    /// One BCM RxSetup per `opts` message, none when an id is missing from the pool
+    fn rx_setup(&self, sock: &SockCanHandle, opts: &SubscribeOpts, flags: CanBcmFlag) -> Result<(), CanError> {
+        let canids = match &opts.only {
+            Some(only) => only.as_slice(),
+            None => self.get_ids(),
+        };
+        if let Some(canid) = canids.iter().find(|canid| !self.get_ids().contains(canid)) {
+            return Err(CanError::new("fail-canid-search", format!("canid:{} not found", canid)));
+        }
+        // extended DBC ids already carry CAN_EFF_FLAG (bit 31), as the BCM expects
+        for canid in canids {
+            SockBcmCmd::new(CanBcmOpCode::RxSetup, flags, *canid)
+                .set_timers(opts.rate, opts.watchdog)
+                .apply(sock)?;
+        }
+        Ok(())
+    }
+}
 } // end dbc generated parser
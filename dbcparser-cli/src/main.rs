@@ -1,15 +1,26 @@
 extern crate dbcparser;
 
 use anyhow::{anyhow, Context, Result};
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
 
+use dbcparser::compat::{next_version, parse_version, DbcDiff};
+use dbcparser::e2e::CrcAlgorithm;
+use dbcparser::gencode::Copyright;
 use dbcparser::gencode::DbcParser;
+use dbcparser::gencode::RenameMap;
+use dbcparser::gencode::SignalOverride;
 use dbcparser::gencode::DEFAULT_HEADER;
+use dbcparser::gencode::{generated_drift, write_generated};
+use dbcparser::gencode::{MessageStruct, SignalStruct};
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
+mod shell;
+mod socketcan;
+
 #[derive(Debug, Deserialize, Serialize)]
 struct OptionParser {
     infile: String,
@@ -17,8 +28,210 @@ struct OptionParser {
     uid: String,
     header_file: Option<String>,
     no_header: bool,
+    #[serde(default)]
+    no_timestamp: bool,
     whitelist: Option<String>,
     blacklist: Option<String>,
+    #[serde(default)]
+    nodes: Vec<String>,
+    #[serde(default)]
+    plugin_abi: bool,
+    #[serde(default)]
+    node_id: u8,
+    #[serde(default)]
+    drop_signals: Vec<String>,
+    #[serde(default)]
+    rename_map: Option<String>,
+    #[serde(default)]
+    dbc_excerpts: bool,
+    #[serde(default)]
+    emit_tests: bool,
+    #[serde(default)]
+    emit_benches: Option<String>,
+    #[serde(default)]
+    emit_crate: Option<String>,
+    #[serde(default)]
+    sna: Vec<String>,
+    #[serde(default)]
+    check_values: Vec<String>,
+    #[serde(default)]
+    j1939: bool,
+    #[serde(default)]
+    no_sockcan: bool,
+    #[serde(default)]
+    no_std: bool,
+    #[serde(default)]
+    static_pool: bool,
+    #[serde(default)]
+    canfd: bool,
+    #[serde(default)]
+    crate_root: bool,
+    #[serde(default)]
+    module_doc: Option<String>,
+    #[serde(default)]
+    overrides: Vec<OverrideOption>,
+    #[serde(default)]
+    serde_feature: Option<String>,
+    #[serde(default)]
+    text_signals: Vec<String>,
+    #[serde(default)]
+    counters: Vec<String>,
+    #[serde(default)]
+    checksums: Vec<String>,
+    #[serde(default)]
+    crc_algorithms: Vec<CrcOption>,
+    #[serde(default)]
+    signal_structs: Vec<SignalStructOption>,
+    #[serde(default)]
+    message_structs: Vec<MessageStructOption>,
+    #[serde(default)]
+    copyright: Option<CopyrightOption>,
+    #[serde(default)]
+    extra_inputs: Vec<InputOption>,
+    #[serde(default)]
+    json_ir: Option<String>,
+    #[serde(default)]
+    json_schema: Option<String>,
+    #[serde(default)]
+    markdown: Option<String>,
+}
+
+/// Additional input generated in its own `uid` module of the same output file
+#[derive(Debug, Deserialize, Serialize)]
+struct InputOption {
+    infile: String,
+    uid: String,
+}
+
+/// Per-message/signal exception to the global range check and serde support:
+/// `{pattern, range_check, serde_json}`, unset flags keep the global setting
+#[derive(Debug, Deserialize, Serialize)]
+struct OverrideOption {
+    pattern: String,
+    #[serde(default)]
+    range_check: Option<bool>,
+    #[serde(default)]
+    serde_json: Option<bool>,
+}
+
+/// Signals of several messages read as one struct: `{name, max_age_ms, fields}`
+#[derive(Debug, Deserialize, Serialize)]
+struct SignalStructOption {
+    name: String,
+    #[serde(default)]
+    max_age_ms: u64,
+    fields: Vec<StructFieldOption>,
+}
+
+/// Field of a signal struct and its `Message.Signal` member (DBC names)
+#[derive(Debug, Deserialize, Serialize)]
+struct StructFieldOption {
+    field: String,
+    signal: String,
+}
+
+/// Application struct converted from/to a message: `{message, path, fields}`, every signal
+/// when `fields` is unset
+#[derive(Debug, Deserialize, Serialize)]
+struct MessageStructOption {
+    message: String,
+    path: String,
+    #[serde(default)]
+    fields: Vec<String>,
+}
+
+/// CRC algorithm added to the built-in ones: `{name, width, poly, init, xorout, reflect}`
+#[derive(Debug, Deserialize, Serialize)]
+struct CrcOption {
+    name: String,
+    width: u32,
+    poly: u64,
+    #[serde(default)]
+    init: u64,
+    #[serde(default)]
+    xorout: u64,
+    #[serde(default)]
+    reflect: bool,
+}
+
+/// Copyright block of the generated banner: `{holder, years, spdx}`
+#[derive(Debug, Deserialize, Serialize)]
+struct CopyrightOption {
+    holder: String,
+    #[serde(default)]
+    years: Option<String>,
+    #[serde(default)]
+    spdx: Option<String>,
+}
+
+/// Rename-map file content: `{"messages": {"Old": "New"}, "signals": {"Msg.Old": "New"}}`,
+/// plus the optional `keep_names` flag and message/signal prefixes and suffixes
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct RenameFile {
+    messages: HashMap<String, String>,
+    signals: HashMap<String, String>,
+    keep_names: bool,
+    message_prefix: String,
+    message_suffix: String,
+    signal_prefix: String,
+    signal_suffix: String,
+}
+
+/// Load a rename map from JSON, or YAML when the file extension says so
+fn load_rename_map(path: &str) -> Result<RenameMap> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("cannot read rename map: {path}"))?;
+    let file: RenameFile = if path.ends_with(".yaml") || path.ends_with(".yml") {
+        serde_yaml::from_str(&text).with_context(|| format!("invalid YAML rename map in {path}"))?
+    } else {
+        serde_json::from_str(&text).with_context(|| format!("invalid JSON rename map in {path}"))?
+    };
+    Ok(RenameMap {
+        messages: file.messages,
+        signals: file.signals,
+        keep_names: file.keep_names,
+        message_prefix: file.message_prefix,
+        message_suffix: file.message_suffix,
+        signal_prefix: file.signal_prefix,
+        signal_suffix: file.signal_suffix,
+    })
+}
+
+/// Expand `${VAR}` and a leading `~` in a path read from the YAML configuration
+fn expand_path(raw: &str) -> Result<String> {
+    let home = || std::env::var("HOME").context("cannot expand '~': HOME is not set");
+    let mut out = match raw.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with('/') => home()? + rest,
+        _ => raw.to_owned(),
+    };
+    // values are not expanded again
+    let mut cursor = 0;
+    while let Some(start) = out[cursor..].find("${").map(|start| cursor + start) {
+        let end = out[start..]
+            .find('}')
+            .map(|end| start + end)
+            .ok_or_else(|| anyhow!("unterminated '${{' in path: {raw}"))?;
+        let name = &out[start + 2..end];
+        let value = std::env::var(name)
+            .with_context(|| format!("environment variable {name} is not set (in path: {raw})"))?;
+        out.replace_range(start..=end, &value);
+        cursor = start + value.len();
+    }
+    Ok(out)
+}
+
+/// Path doctests and benchmarks include `outfile` from: relative to the manifest directory of
+/// the enclosing crate, so the generated code does not depend on the checkout location, or
+/// absolute outside any crate
+fn include_path(outfile: &str) -> Result<std::path::PathBuf> {
+    let path = std::path::absolute(outfile)
+        .with_context(|| format!("cannot resolve output path: {outfile}"))?;
+    let manifest_dir = path.ancestors().skip(1).find(|dir| dir.join("Cargo.toml").is_file());
+    Ok(match manifest_dir.and_then(|dir| path.strip_prefix(dir).ok()) {
+        Some(relative) => relative.to_path_buf(),
+        None => path,
+    })
 }
 
 /// Parse a list of CAN identifiers in the form "0x101,0x121,289" etc.
@@ -44,19 +257,33 @@ fn parse_id_list(input: &str) -> Result<Vec<u32>> {
 
 /// CLI
 #[derive(Debug, Parser)]
-#[command(name = "dbc-gen", version, about = "Generate Rust code from a DBC file")]
+#[command(
+    name = "dbc-gen",
+    version,
+    about = "Generate Rust code from a DBC (or CANopen EDS/DCF, LIN LDF) file",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
 struct Cli {
-    /// Input DBC file (required unless a YAML config is provided)
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Input DBC, EDS/DCF or LDF file (required unless a YAML config is provided), repeat for several buses
     #[arg(short = 'i', long = "in", value_name = "INFILE", required_unless_present = "config")]
-    infile: Option<String>,
+    infile: Vec<String>,
 
-    /// Output Rust file path (required unless a YAML config is provided)
-    #[arg(short = 'o', long = "out", value_name = "OUTFILE", required_unless_present = "config")]
+    /// Output Rust file path (required unless a YAML config, --check-generated, --emit-crate or --compat is provided)
+    #[arg(
+        short = 'o',
+        long = "out",
+        value_name = "OUTFILE",
+        required_unless_present_any = ["config", "check_generated", "emit_crate", "compat"]
+    )]
     outfile: Option<String>,
 
-    /// Optional UID (module/namespace root in generated code)
-    #[arg(long, default_value = "DbcSimple")]
-    uid: String,
+    /// Optional UID (module/namespace root in generated code) [default: DbcSimple], one per input when repeated
+    #[arg(long)]
+    uid: Vec<String>,
 
     /// Header text file to prepend (overrides built-in header if provided)
     #[arg(long = "header-file")]
@@ -66,6 +293,10 @@ struct Cli {
     #[arg(long = "no-header", default_value_t = false)]
     no_header: bool,
 
+    /// Leave the generation time out of the banner, for byte-identical output (SOURCE_DATE_EPOCH pins it otherwise)
+    #[arg(long = "no-timestamp", default_value_t = false)]
+    no_timestamp: bool,
+
     /// Whitelist CAN IDs (CSV, hex 0xABC or decimal): e.g. "0x101,0x121,201"
     #[arg(long = "whitelist")]
     whitelist: Option<String>,
@@ -74,10 +305,146 @@ struct Cli {
     #[arg(long = "blacklist")]
     blacklist: Option<String>,
 
+    /// Only generate messages transmitted by this BU_ node or with a signal it receives (repeatable)
+    #[arg(long = "node", value_name = "NAME")]
+    node: Vec<String>,
+
+    /// Append the binder plugin C ABI (canforge_plugin_*) to the generated code
+    #[arg(long = "plugin-abi", default_value_t = false)]
+    plugin_abi: bool,
+
+    /// CANopen node id resolving $NODEID when the input is an EDS/DCF file
+    #[arg(long = "node-id", default_value_t = 0)]
+    node_id: u8,
+
+    /// Omit signals from generation, keeping their message: "Signal" or "Message.Signal", '*' wildcard (repeatable)
+    #[arg(long = "drop-signal", value_name = "PATTERN")]
+    drop_signal: Vec<String>,
+
+    /// JSON (or YAML) file mapping DBC message/signal names to generated names
+    #[arg(long = "rename-map", value_name = "FILE")]
+    rename_map: Option<String>,
+
+    /// Quote the source BO_/SG_ lines in each generated message doc comment
+    #[arg(long = "dbc-excerpts", default_value_t = false)]
+    dbc_excerpts: bool,
+
+    /// Add a doctest per message to its doc comment, encoding, decoding and reading every signal
+    #[arg(long = "emit-tests", default_value_t = false)]
+    emit_tests: bool,
+
+    /// Also write criterion benchmarks of each message decode/encode to this file
+    #[arg(long = "emit-benches", value_name = "FILE")]
+    emit_benches: Option<String>,
+
+    /// Write a complete cargo package named after this directory: Cargo.toml, src/lib.rs (default output) and tests (implies --crate-root)
+    #[arg(long = "emit-crate", value_name = "DIR")]
+    emit_crate: Option<String>,
+
+    /// Signals reporting "not available" on a raw value: "Signal[=raw]" or "Message.Signal[=raw]", '*' wildcard, raw defaults to all ones (repeatable)
+    #[arg(long = "sna", value_name = "PATTERN[=RAW]")]
+    sna: Vec<String>,
+
+    /// Signals flagging raw values missing from their VAL_ table (status Error): "Signal" or "Message.Signal", '*' wildcard (repeatable)
+    #[arg(long = "check-values", value_name = "PATTERN")]
+    check_values: Vec<String>,
+
+    /// J1939 database: raw 0xFE../0xFF.. ranges report an error indicator or "not available", extended messages get their PGN
+    #[arg(long = "j1939", default_value_t = false)]
+    j1939: bool,
+
+    /// Generate code without the sockcan crate, with local frame/error/status types (offline decoding)
+    #[arg(long = "no-sockcan", default_value_t = false)]
+    no_sockcan: bool,
+
+    /// Generate code for bare-metal targets, using core/alloc only (implies --no-sockcan)
+    #[arg(long = "no-std", default_value_t = false)]
+    no_std: bool,
+
+    /// Also generate a StaticPool decoding frames into messages held by value, without allocation nor dynamic dispatch
+    #[arg(long = "static-pool", default_value_t = false)]
+    static_pool: bool,
+
+    /// CAN FD messages up to 64 bytes: frame lengths checked against the message size, SIZE/DLC constants
+    #[arg(long = "canfd", default_value_t = false)]
+    canfd: bool,
+
+    /// Emit the items at the top level, without the uid module, so the output is a complete crate root (lib.rs)
+    #[arg(long = "crate-root", default_value_t = false)]
+    crate_root: bool,
+
+    /// Documentation of the generated module, or of the crate with --crate-root, written as //! lines
+    #[arg(long = "module-doc", value_name = "TEXT")]
+    module_doc: Option<String>,
+
+    /// Skip the set_physical() range check of matching signals: "Signal" or "Message.Signal", '*' wildcard (repeatable)
+    #[arg(long = "no-range-check", value_name = "PATTERN")]
+    no_range_check: Vec<String>,
+
+    /// No serde derives for matching signals, serialized as null: "Signal" or "Message.Signal", '*' wildcard (repeatable)
+    #[arg(long = "no-serde", value_name = "PATTERN")]
+    no_serde: Vec<String>,
+
+    /// Compile the generated serde code only with this cargo feature of the including crate, e.g. "serde"
+    #[arg(long = "serde-feature", value_name = "FEATURE")]
+    serde_feature: Option<String>,
+
+    /// Signals holding ASCII text, with get_text()/set_text(): "Signal" or "Message.Signal", '*' wildcard (repeatable)
+    #[arg(long = "text", value_name = "PATTERN")]
+    text: Vec<String>,
+
+    /// Rolling counter signals, frames with a repeated or backward counter are ignored: "Signal" or "Message.Signal", '*' wildcard (repeatable)
+    #[arg(long = "counter", value_name = "PATTERN")]
+    counter: Vec<String>,
+
+    /// E2E checksum signals, filled by set_values() and checked by update(): "Signal[=crc]" or "Message.Signal[=crc]", '*' wildcard, crc algorithm by signal size when omitted (repeatable)
+    #[arg(long = "checksum", value_name = "PATTERN[=CRC]")]
+    checksum: Vec<String>,
+
+    /// Signals read together as one struct with a pool getter: "Name[@max_age_ms]=field:Message.Signal,..." (repeatable)
+    #[arg(long = "signal-struct", value_name = "SPEC")]
+    signal_struct: Vec<String>,
+
+    /// Application struct converted from/to a message, fields named after its signals: "Message=crate::path::Struct[(field,...)]" (repeatable)
+    #[arg(long = "message-struct", value_name = "SPEC")]
+    message_struct: Vec<String>,
+
+    /// Also write the parsed database as JSON (messages, signals, value tables) to this file
+    #[arg(long = "json-ir", value_name = "FILE")]
+    json_ir: Option<String>,
+
+    /// Also write a JSON Schema of the exported message JSON (one definition per message) to this file
+    #[arg(long = "json-schema", value_name = "FILE")]
+    json_schema: Option<String>,
+
+    /// Also write a Markdown reference of the database to this file
+    #[arg(long = "markdown", value_name = "FILE")]
+    markdown: Option<String>,
+
+    /// Copyright holder replacing the IoT.bzh/Apache-2.0 block of the generated banner
+    #[arg(long = "copyright-holder", value_name = "HOLDER")]
+    copyright_holder: Option<String>,
+
+    /// Copyright year or year range, e.g. "2021-2025"
+    #[arg(long = "copyright-years", value_name = "YEARS", requires = "copyright_holder")]
+    copyright_years: Option<String>,
+
+    /// SPDX license identifier of the generated code, e.g. "MIT"
+    #[arg(long = "spdx", value_name = "ID", requires = "copyright_holder")]
+    spdx: Option<String>,
+
     /// Load parameters from a YAML configuration file
     #[arg(long = "config", value_name = "YAML")]
     config: Option<String>,
 
+    /// Regenerate in memory and fail with a diff summary when this generated file is stale
+    #[arg(long = "check-generated", value_name = "EXISTING")]
+    check_generated: Option<String>,
+
+    /// Compare the input with this previous revision, print the changes and the suggested version bump, generate nothing
+    #[arg(long = "compat", value_name = "OLD")]
+    compat: Option<String>,
+
     /// Save the effective parameters to this YAML file
     #[arg(long = "save-config", value_name = "YAML")]
     save_config: Option<String>,
@@ -87,8 +454,54 @@ struct Cli {
     verbose: bool,
 }
 
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Interactive shell sending messages and watching signals on a CAN interface
+    Shell(ShellArgs),
+}
+
+#[derive(Debug, Args)]
+struct ShellArgs {
+    /// Input DBC file
+    #[arg(short = 'i', long = "in", value_name = "INFILE")]
+    infile: String,
+
+    /// SocketCAN interface, e.g. vcan0; without it frames are printed instead of sent
+    #[arg(long = "iface", value_name = "IFACE")]
+    iface: Option<String>,
+}
+
+/// Print the changes from `old` to `infile` and the version bump they need, failing when the
+/// DBC `VERSION` of `infile` is below it
+fn check_compat(old: &str, infile: &str, node_id: u8) -> Result<()> {
+    let diff = DbcDiff::from_files(old, infile, node_id)
+        .map_err(|e| anyhow!("compatibility check failed: {e}"))?;
+    let version = |path: &str| -> Result<Option<String>> {
+        let text = fs::read_to_string(path).with_context(|| format!("cannot read: {path}"))?;
+        Ok(dbcparser::package::dbc_version(&text))
+    };
+    let current =
+        version(old)?.unwrap_or_else(|| dbcparser::package::DEFAULT_CRATE_VERSION.to_owned());
+    let bump = diff.bump();
+    let suggested = next_version(&current, bump).expect("dbc_version() is semantic");
+
+    print!("{}", diff.report());
+    println!("suggested bump: {bump} ({current} -> {suggested})");
+    if let Some(declared) = version(infile)? {
+        if parse_version(&declared) < parse_version(&suggested) {
+            return Err(anyhow!(
+                "{infile}: VERSION {declared} too low for a {bump} change, expected {suggested}"
+            ));
+        }
+    }
+    Ok(())
+}
+
 fn main() -> Result<()> {
     let cli = Cli::parse();
+    if let Some(Command::Shell(args)) = &cli.command {
+        return shell::run(&args.infile, args.iface.as_deref());
+    }
 
     // Build effective options:
     // - If --config is provided: load from YAML
@@ -96,28 +509,183 @@ fn main() -> Result<()> {
     let options: OptionParser = if let Some(cfg_path) = &cli.config {
         let cfg_str = fs::read_to_string(cfg_path)
             .with_context(|| format!("cannot read config file: {cfg_path}"))?;
-        serde_yaml::from_str(&cfg_str)
-            .with_context(|| format!("invalid YAML configuration in {cfg_path}"))?
+        let mut options: OptionParser = serde_yaml::from_str(&cfg_str)
+            .with_context(|| format!("invalid YAML configuration in {cfg_path}"))?;
+        // saved configs are shared between machines, resolve their paths locally
+        options.infile = expand_path(&options.infile)?;
+        options.outfile = expand_path(&options.outfile)?;
+        options.header_file = options.header_file.as_deref().map(expand_path).transpose()?;
+        options.rename_map = options.rename_map.as_deref().map(expand_path).transpose()?;
+        for input in &mut options.extra_inputs {
+            input.infile = expand_path(&input.infile)?;
+        }
+        options.json_ir = options.json_ir.as_deref().map(expand_path).transpose()?;
+        options.json_schema = options.json_schema.as_deref().map(expand_path).transpose()?;
+        options.markdown = options.markdown.as_deref().map(expand_path).transpose()?;
+        options.emit_benches = options.emit_benches.as_deref().map(expand_path).transpose()?;
+        options.emit_crate = options.emit_crate.as_deref().map(expand_path).transpose()?;
+        options
     } else {
+        // several inputs need one uid each, a single one keeps the DbcSimple default
+        if cli.infile.len() > 1 && cli.uid.len() != cli.infile.len() {
+            return Err(anyhow!(
+                "{} inputs need as many --uid, got {}",
+                cli.infile.len(),
+                cli.uid.len()
+            ));
+        }
+        if cli.uid.len() > cli.infile.len() {
+            return Err(anyhow!("more --uid than --in inputs"));
+        }
         OptionParser {
             infile: cli
                 .infile
-                .clone()
+                .first()
+                .cloned()
                 .expect("clap guarantees infile is provided when config is not"),
             outfile: cli
                 .outfile
                 .clone()
-                .expect("clap guarantees outfile is provided when config is not"),
-            uid: cli.uid.clone(),
+                .or_else(|| cli.check_generated.clone())
+                .or_else(|| cli.compat.as_ref().map(|_| String::new()))
+                .or_else(|| {
+                    let lib = Path::new(cli.emit_crate.as_ref()?).join("src").join("lib.rs");
+                    Some(lib.display().to_string())
+                })
+                .expect(
+                    "clap guarantees outfile, check-generated or emit-crate is provided when config is not",
+                ),
+            uid: cli.uid.first().cloned().unwrap_or_else(|| "DbcSimple".to_owned()),
             header_file: cli.header_file.clone(),
             no_header: cli.no_header,
+            no_timestamp: cli.no_timestamp,
             whitelist: cli.whitelist.clone(),
             blacklist: cli.blacklist.clone(),
+            nodes: cli.node.clone(),
+            plugin_abi: cli.plugin_abi,
+            node_id: cli.node_id,
+            drop_signals: cli.drop_signal.clone(),
+            rename_map: cli.rename_map.clone(),
+            dbc_excerpts: cli.dbc_excerpts,
+            emit_tests: cli.emit_tests,
+            emit_benches: cli.emit_benches.clone(),
+            emit_crate: cli.emit_crate.clone(),
+            sna: cli.sna.clone(),
+            check_values: cli.check_values.clone(),
+            j1939: cli.j1939,
+            no_sockcan: cli.no_sockcan,
+            no_std: cli.no_std,
+            static_pool: cli.static_pool,
+            canfd: cli.canfd,
+            crate_root: cli.crate_root,
+            module_doc: cli.module_doc.clone(),
+            overrides: cli
+                .no_range_check
+                .iter()
+                .map(|pattern| OverrideOption {
+                    pattern: pattern.clone(),
+                    range_check: Some(false),
+                    serde_json: None,
+                })
+                .chain(cli.no_serde.iter().map(|pattern| OverrideOption {
+                    pattern: pattern.clone(),
+                    range_check: None,
+                    serde_json: Some(false),
+                }))
+                .collect(),
+            serde_feature: cli.serde_feature.clone(),
+            text_signals: cli.text.clone(),
+            counters: cli.counter.clone(),
+            checksums: cli.checksum.clone(),
+            crc_algorithms: Vec::new(),
+            signal_structs: cli
+                .signal_struct
+                .iter()
+                .map(|spec| {
+                    let entry = SignalStruct::parse(spec)?;
+                    Ok(SignalStructOption {
+                        name: entry.name,
+                        max_age_ms: entry.max_age_ms,
+                        fields: entry
+                            .fields
+                            .into_iter()
+                            .map(|(field, signal)| StructFieldOption { field, signal })
+                            .collect(),
+                    })
+                })
+                .collect::<Result<_>>()?,
+            message_structs: cli
+                .message_struct
+                .iter()
+                .map(|spec| {
+                    let entry = MessageStruct::parse(spec)?;
+                    Ok(MessageStructOption {
+                        message: entry.message,
+                        path: entry.path,
+                        fields: entry.fields,
+                    })
+                })
+                .collect::<Result<_>>()?,
+            copyright: cli.copyright_holder.clone().map(|holder| CopyrightOption {
+                holder,
+                years: cli.copyright_years.clone(),
+                spdx: cli.spdx.clone(),
+            }),
+            extra_inputs: cli
+                .infile
+                .iter()
+                .zip(&cli.uid)
+                .skip(1)
+                .map(|(infile, uid)| InputOption { infile: infile.clone(), uid: uid.clone() })
+                .collect(),
+            json_ir: cli.json_ir.clone(),
+            json_schema: cli.json_schema.clone(),
+            markdown: cli.markdown.clone(),
         }
     };
-    // Optionnel: validations supplémentaires (ex. existence du fichier d’entrée)
-    if !Path::new(&options.infile).exists() {
-        return Err(anyhow!("input file does not exist: {}", options.infile));
+
+    // (infile, uid) of every generated module
+    let inputs: Vec<(&str, &str)> =
+        std::iter::once((options.infile.as_str(), options.uid.as_str()))
+            .chain(
+                options
+                    .extra_inputs
+                    .iter()
+                    .map(|input| (input.infile.as_str(), input.uid.as_str())),
+            )
+            .collect();
+    for (idx, (infile, uid)) in inputs.iter().enumerate() {
+        // every input must exist and generate its own uid module
+        if !Path::new(infile).exists() {
+            return Err(anyhow!("input file does not exist: {infile}"));
+        }
+        if inputs[..idx].iter().any(|(_, other)| other == uid) {
+            return Err(anyhow!("uid {uid} is used by several inputs"));
+        }
+    }
+    if let Some(old) = &cli.compat {
+        if inputs.len() > 1 {
+            return Err(anyhow!("--compat compares a single input"));
+        }
+        return check_compat(old, &options.infile, options.node_id);
+    }
+    if options.plugin_abi && inputs.len() > 1 {
+        return Err(anyhow!("--plugin-abi exports a single pool, it needs a single input"));
+    }
+    if (options.json_ir.is_some() || options.json_schema.is_some() || options.markdown.is_some())
+        && inputs.len() > 1
+    {
+        return Err(anyhow!("--json-ir, --json-schema and --markdown describe a single input"));
+    }
+    // a package holds a single database at its crate root
+    let crate_root = options.crate_root || options.emit_crate.is_some();
+    if crate_root && inputs.len() > 1 {
+        return Err(anyhow!(
+            "--crate-root and --emit-crate generate a single input, without uid module"
+        ));
+    }
+    if options.emit_benches.is_some() && inputs.len() > 1 {
+        return Err(anyhow!("--emit-benches benchmarks a single input"));
     }
 
     // Parse whitelist / blacklist from the *effective* options
@@ -127,6 +695,11 @@ fn main() -> Result<()> {
     let blacklist_ids =
         if let Some(s) = &options.blacklist { parse_id_list(s)? } else { Vec::new() };
 
+    let renames = match &options.rename_map {
+        Some(path) => load_rename_map(path)?,
+        None => RenameMap::default(),
+    };
+
     // Resolve header text
     let header: &'static str = if options.no_header {
         ""
@@ -134,6 +707,9 @@ fn main() -> Result<()> {
         fs::read_to_string(path)
             .with_context(|| format!("cannot read header file: {path}"))?
             .leak()
+    } else if crate_root {
+        // the default header attributes apply to the uid module
+        ""
     } else {
         DEFAULT_HEADER
     };
@@ -154,19 +730,148 @@ fn main() -> Result<()> {
         }
     }
 
-    // uid has to be 'static for DbcParser, so we leak it on purpose
-    let uid_static: &'static str = Box::leak(options.uid.clone().into_boxed_str());
-
-    DbcParser::new(uid_static)
-        .dbcfile(&options.infile)
-        .outfile(&options.outfile)
-        .header(header)
-        .range_check(true)
-        .serde_json(true)
-        .whitelist(whitelist_ids)
-        .blacklist(blacklist_ids)
-        .generate()
-        .map_err(|e| anyhow!("code generation failed: {e}"))?;
+    // every input is generated in its own uid module, one after the other in the same file
+    let mut code = String::new();
+    for (infile, uid) in &inputs {
+        // uid has to be 'static for DbcParser, so we leak it on purpose
+        let uid_static: &'static str = Box::leak((*uid).to_owned().into_boxed_str());
+
+        let mut parser = DbcParser::new(uid_static);
+        parser
+            .dbcfile(infile)
+            .header(header)
+            .range_check(true)
+            .serde_json(true)
+            .whitelist(whitelist_ids.clone())
+            .blacklist(blacklist_ids.clone())
+            .plugin_abi(options.plugin_abi)
+            .node_id(options.node_id)
+            .drop_signals(options.drop_signals.clone())
+            .rename_map(renames.clone())
+            .dbc_excerpts(options.dbc_excerpts)
+            .sna(options.sna.clone())
+            .check_values(options.check_values.clone())
+            .j1939(options.j1939)
+            .sockcan(!options.no_sockcan)
+            .no_std(options.no_std)
+            .static_pool(options.static_pool)
+            .canfd(options.canfd)
+            .crate_root(crate_root)
+            .timestamp(!options.no_timestamp)
+            .overrides(
+                options
+                    .overrides
+                    .iter()
+                    .map(|entry| SignalOverride {
+                        pattern: entry.pattern.clone(),
+                        range_check: entry.range_check,
+                        serde_json: entry.serde_json,
+                    })
+                    .collect(),
+            )
+            .text_signals(options.text_signals.clone())
+            .counters(options.counters.clone())
+            .checksums(options.checksums.clone())
+            .crc_algorithms(
+                options
+                    .crc_algorithms
+                    .iter()
+                    .map(|algo| {
+                        CrcAlgorithm::new(
+                            &algo.name,
+                            algo.width,
+                            algo.poly,
+                            algo.init,
+                            algo.xorout,
+                            algo.reflect,
+                        )
+                    })
+                    .collect(),
+            )
+            .signal_structs(
+                options
+                    .signal_structs
+                    .iter()
+                    .map(|entry| SignalStruct {
+                        name: entry.name.clone(),
+                        fields: entry
+                            .fields
+                            .iter()
+                            .map(|field| (field.field.clone(), field.signal.clone()))
+                            .collect(),
+                        max_age_ms: entry.max_age_ms,
+                    })
+                    .collect(),
+            )
+            .message_structs(
+                options
+                    .message_structs
+                    .iter()
+                    .map(|entry| MessageStruct {
+                        message: entry.message.clone(),
+                        path: entry.path.clone(),
+                        fields: entry.fields.clone(),
+                    })
+                    .collect(),
+            );
+        for node in &options.nodes {
+            parser.node(node);
+        }
+        if let Some(feature) = &options.serde_feature {
+            parser.serde_feature(feature);
+        }
+        if let Some(doc) = &options.module_doc {
+            parser.module_doc(doc);
+        }
+        // --check-generated only compares the code, it writes nothing
+        if cli.check_generated.is_none() {
+            if let Some(path) = &options.json_ir {
+                parser.json_ir(path);
+            }
+            if let Some(path) = &options.json_schema {
+                parser.json_schema(path);
+            }
+            if let Some(path) = &options.markdown {
+                parser.markdown(path);
+            }
+        }
+        // doctests include the generated file, wherever the crate is tested from
+        if options.emit_tests {
+            parser.emit_tests(include_path(&options.outfile)?);
+        }
+        if let Some(dir) = options.emit_crate.as_ref().filter(|_| cli.check_generated.is_none()) {
+            parser.emit_crate(dir);
+        }
+        // benchmarks include the generated file like doctests
+        if let Some(path) = options.emit_benches.as_ref().filter(|_| cli.check_generated.is_none())
+        {
+            parser.emit_benches(path, include_path(&options.outfile)?);
+        }
+        if let Some(copyright) = &options.copyright {
+            parser.copyright(Copyright {
+                holder: copyright.holder.clone(),
+                years: copyright.years.clone(),
+                spdx: copyright.spdx.clone(),
+            });
+        }
+        let section = parser
+            .generate_string()
+            .map_err(|e| anyhow!("code generation failed for {infile}: {e}"))?;
+        code.push_str(&section);
+    }
+
+    if let Some(path) = &cli.check_generated {
+        let existing = fs::read_to_string(path)
+            .with_context(|| format!("cannot read generated file: {path}"))?;
+        if let Some(summary) = generated_drift(&existing, &code) {
+            return Err(anyhow!("{path}: {summary}"));
+        }
+        eprintln!("Up to date: {path}");
+        return Ok(());
+    }
+
+    write_generated(&options.outfile, &code)
+        .with_context(|| format!("cannot write generated file: {}", options.outfile))?;
 
     eprintln!("Generated: {}", options.outfile);
     Ok(())
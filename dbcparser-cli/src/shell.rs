@@ -0,0 +1,475 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! `dbcparser-cli shell`: send messages and watch signals of a DBC on a CAN interface.
+//!
+//! The database is interpreted at runtime (`canforge-runtime`), so no code is generated. A
+//! terminal gets line editing, history and tab completion over commands, message and signal
+//! names; piped commands are run one per line, e.g. from a bench script.
+
+use crate::socketcan::{CanSocket, CAN_EFF_FLAG};
+use anyhow::{anyhow, Context, Result};
+use canforge_runtime::prelude::*;
+use crossterm::cursor::MoveToColumn;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType};
+use crossterm::{queue, ExecutableCommand};
+use std::collections::HashMap;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+const COMMANDS: [&str; 6] = ["send", "watch", "unwatch", "list", "help", "quit"];
+
+const HELP: &str = "\
+send <Message> [Signal=value ...]  encode and send a frame, other signals keep their last value
+watch <Signal|Message|Message.Signal>  print the signal values when they change ('*' wildcard)
+unwatch [pattern]                  stop watching a pattern, or everything
+list [Message]                     messages of the database, or signals of a message
+help                               this help
+quit                               leave the shell (also Ctrl-D)";
+
+struct Shell {
+    dbc: RuntimeDbc,
+    iface: Option<String>,
+    socket: Option<CanSocket>,
+    /// Frames read from the interface by the reader thread.
+    frames: Option<Receiver<(u32, Vec<u8>)>>,
+    /// Last payload sent of each message, by raw id.
+    payloads: HashMap<u32, Vec<u8>>,
+    watches: Vec<String>,
+    /// Last value printed of each watched signal, by (raw id, signal name).
+    shown: HashMap<(u32, &'static str), String>,
+}
+
+/// Run the shell on DBC `infile`, sending to and watching `iface`. Without an interface the
+/// frames are printed instead of sent.
+pub fn run(infile: &str, iface: Option<&str>) -> Result<()> {
+    let dbc = RuntimeDbc::from_file(infile).with_context(|| format!("cannot load: {infile}"))?;
+    let (socket, frames) = match iface {
+        Some(iface) => {
+            let socket = CanSocket::open(iface).with_context(|| format!("cannot open: {iface}"))?;
+            let mut reader = socket.try_clone()?;
+            let (sender, frames) = mpsc::channel();
+            thread::spawn(move || {
+                while let Ok(frame) = reader.recv() {
+                    if sender.send(frame).is_err() {
+                        break;
+                    }
+                }
+            });
+            (Some(socket), Some(frames))
+        },
+        None => (None, None),
+    };
+    let mut shell = Shell {
+        dbc,
+        iface: iface.map(str::to_owned),
+        socket,
+        frames,
+        payloads: HashMap::new(),
+        watches: Vec::new(),
+        shown: HashMap::new(),
+    };
+
+    if !io::stdin().is_terminal() {
+        for line in io::stdin().lock().lines() {
+            shell.print_pending();
+            if !shell.execute(&line?) {
+                break;
+            }
+        }
+        return Ok(());
+    }
+
+    let stem = Path::new(infile).file_stem().unwrap_or_default().to_string_lossy();
+    let prompt = format!("{stem}> ");
+    let count = shell.dbc.get_messages().len();
+    println!("{count} messages loaded, 'help' for commands, Tab completes names");
+    let mut history: Vec<String> = Vec::new();
+    while let Some(line) = shell.read_line(&prompt, &history)? {
+        if !line.trim().is_empty() && history.last() != Some(&line) {
+            history.push(line.clone());
+        }
+        if !shell.execute(&line) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+impl Shell {
+    /// Run one command line, false to leave the shell.
+    fn execute(&mut self, line: &str) -> bool {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((command, args)) = words.split_first() else {
+            return true;
+        };
+        let status = match *command {
+            "send" => self.send(args),
+            "watch" => self.watch(args),
+            "unwatch" => {
+                match args.first() {
+                    Some(pattern) => self.watches.retain(|watch| watch != pattern),
+                    None => self.watches.clear(),
+                }
+                self.shown.clear();
+                Ok(())
+            },
+            "list" => self.list(args),
+            "help" => {
+                println!("{HELP}");
+                Ok(())
+            },
+            "quit" | "exit" => return false,
+            other => Err(anyhow!("unknown command:{other}, 'help' lists them")),
+        };
+        if let Err(error) = status {
+            println!("error: {error:#}");
+        }
+        true
+    }
+
+    fn send(&mut self, args: &[&str]) -> Result<()> {
+        let Some((name, assignments)) = args.split_first() else {
+            return Err(anyhow!("usage: send <Message> [Signal=value ...]"));
+        };
+        let msg = self
+            .dbc
+            .get_message_by_name(name)
+            .ok_or_else(|| anyhow!("unknown message:{name}"))?;
+        let mut data = match self.payloads.get(&msg.id) {
+            Some(data) => data.clone(),
+            None => vec![0; usize::try_from(msg.size)?],
+        };
+        for assignment in assignments {
+            let (sig_name, text) = assignment
+                .split_once('=')
+                .ok_or_else(|| anyhow!("expected Signal=value, got:{assignment}"))?;
+            let sig = msg
+                .signal(sig_name)
+                .ok_or_else(|| anyhow!("unknown signal:{}.{sig_name}", msg.name))?;
+            set_signal(sig, text, &mut data)
+                .with_context(|| format!("{}.{}", msg.name, sig.name))?;
+        }
+        let frame = frame_text(msg.id, &data);
+        match (self.socket.as_mut(), &self.iface) {
+            (Some(socket), Some(iface)) => {
+                socket.send(msg.id, &data).with_context(|| format!("cannot send on: {iface}"))?;
+                println!("{iface}  {frame}");
+            },
+            _ => println!("{frame}"),
+        }
+        self.payloads.insert(msg.id, data);
+        Ok(())
+    }
+
+    fn watch(&mut self, args: &[&str]) -> Result<()> {
+        if self.frames.is_none() {
+            return Err(anyhow!("watch needs an interface (--iface)"));
+        }
+        let [pattern] = args else {
+            return Err(anyhow!("usage: watch <Signal|Message|Message.Signal>"));
+        };
+        let pattern = match self.dbc.get_message_by_name(pattern) {
+            Some(msg) => format!("{}.*", msg.name),
+            None => (*pattern).to_owned(),
+        };
+        let count = self
+            .dbc
+            .get_messages()
+            .iter()
+            .flat_map(|msg| msg.signals.iter().map(move |sig| (msg, sig)))
+            .filter(|(msg, sig)| signal_matches(&pattern, msg, sig))
+            .count();
+        if count == 0 {
+            return Err(anyhow!("no signal matches:{pattern}"));
+        }
+        println!("watching {count} signal(s) of {pattern}");
+        self.watches.push(pattern);
+        Ok(())
+    }
+
+    fn list(&self, args: &[&str]) -> Result<()> {
+        let Some(name) = args.first() else {
+            for msg in self.dbc.get_messages() {
+                println!("{:<8} {} ({} signals)", id_text(msg.id), msg.name, msg.signals.len());
+            }
+            return Ok(());
+        };
+        let msg = self
+            .dbc
+            .get_message_by_name(name)
+            .ok_or_else(|| anyhow!("unknown message:{name}"))?;
+        for sig in &msg.signals {
+            let mut line = format!("{} [{}..{}]", sig.name, sig.min, sig.max);
+            if !sig.unit.is_empty() {
+                line = format!("{line} {}", sig.unit);
+            }
+            for entry in &sig.values {
+                line = format!("{line} {}={}", entry.raw, entry.description);
+            }
+            println!("{line}");
+        }
+        Ok(())
+    }
+
+    /// Watched signal values changed by the frames read since the last call, one line each.
+    fn pending(&mut self) -> Vec<String> {
+        let Some(frames) = &self.frames else {
+            return Vec::new();
+        };
+        let mut lines = Vec::new();
+        for (canid, data) in frames.try_iter() {
+            if self.watches.is_empty() {
+                continue;
+            }
+            let Some(msg) = self.dbc.get_message(canid) else {
+                continue;
+            };
+            for (sig, value) in msg.signals.iter().zip(msg.decode(&data)) {
+                if value.is_none()
+                    || !self.watches.iter().any(|pattern| signal_matches(pattern, msg, sig))
+                {
+                    continue;
+                }
+                let text = value_text(sig, &data);
+                if self.shown.get(&(msg.id, sig.name)) != Some(&text) {
+                    lines.push(format!("{}.{} = {text}", msg.name, sig.name));
+                    self.shown.insert((msg.id, sig.name), text);
+                }
+            }
+        }
+        lines
+    }
+
+    fn print_pending(&mut self) {
+        for line in self.pending() {
+            println!("{line}");
+        }
+    }
+
+    /// Completion candidates of the last word of `line`, with the position of that word.
+    fn complete(&self, line: &str) -> (usize, Vec<String>) {
+        let start = line.rfind(' ').map_or(0, |pos| pos + 1);
+        let word = &line[start..];
+        let messages = || self.dbc.get_messages().iter().map(|msg| msg.name.to_owned());
+        let words: Vec<&str> = line[..start].split_whitespace().collect();
+        let mut candidates: Vec<String> = match words.as_slice() {
+            [] => COMMANDS.iter().map(|command| (*command).to_owned()).collect(),
+            ["send" | "list"] => messages().collect(),
+            ["send", name, ..] => match self.dbc.get_message_by_name(name) {
+                Some(msg) => msg.signals.iter().map(|sig| format!("{}=", sig.name)).collect(),
+                None => Vec::new(),
+            },
+            ["watch"] => messages()
+                .chain(
+                    self.dbc
+                        .get_messages()
+                        .iter()
+                        .flat_map(|msg| msg.signals.iter().map(|sig| sig.name.to_owned())),
+                )
+                .collect(),
+            ["unwatch"] => self.watches.clone(),
+            _ => Vec::new(),
+        };
+        candidates.retain(|candidate| candidate.starts_with(word));
+        candidates.sort();
+        candidates.dedup();
+        (start, candidates)
+    }
+
+    /// Edit one line in raw mode, printing the watched values meanwhile. None on Ctrl-D.
+    fn read_line(&mut self, prompt: &str, history: &[String]) -> Result<Option<String>> {
+        terminal::enable_raw_mode()?;
+        let line = self.edit(prompt, history);
+        terminal::disable_raw_mode()?;
+        line
+    }
+
+    fn edit(&mut self, prompt: &str, history: &[String]) -> Result<Option<String>> {
+        let mut out = io::stdout();
+        let mut line = String::new();
+        let mut recall = history.len();
+        redraw(&mut out, prompt, &line)?;
+        loop {
+            if !event::poll(Duration::from_millis(50))? {
+                let lines = self.pending();
+                if !lines.is_empty() {
+                    queue!(out, MoveToColumn(0), Clear(ClearType::CurrentLine))?;
+                    for text in lines {
+                        write!(out, "{text}\r\n")?;
+                    }
+                    redraw(&mut out, prompt, &line)?;
+                }
+                continue;
+            }
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            let control = key.modifiers.contains(KeyModifiers::CONTROL);
+            match key.code {
+                KeyCode::Char('d') if control && line.is_empty() => {
+                    out.execute(Print("\r\n"))?;
+                    return Ok(None);
+                },
+                KeyCode::Char('c') if control => {
+                    write!(out, "^C\r\n")?;
+                    line.clear();
+                },
+                KeyCode::Char(c) if !control => line.push(c),
+                KeyCode::Backspace => {
+                    line.pop();
+                },
+                KeyCode::Enter => {
+                    out.execute(Print("\r\n"))?;
+                    return Ok(Some(line));
+                },
+                KeyCode::Up if recall > 0 => {
+                    recall -= 1;
+                    line.clone_from(&history[recall]);
+                },
+                KeyCode::Down if recall < history.len() => {
+                    recall += 1;
+                    line = history.get(recall).cloned().unwrap_or_default();
+                },
+                KeyCode::Tab => {
+                    let (start, candidates) = self.complete(&line);
+                    match candidates.as_slice() {
+                        [] => {},
+                        [one] => {
+                            line.truncate(start);
+                            line.push_str(one);
+                            if !one.ends_with('=') {
+                                line.push(' ');
+                            }
+                        },
+                        many => {
+                            let prefix = common_prefix(many);
+                            if prefix.len() > line.len() - start {
+                                line.truncate(start);
+                                line.push_str(prefix);
+                            } else {
+                                write!(out, "\r\n{}\r\n", many.join("  "))?;
+                            }
+                        },
+                    }
+                },
+                _ => {},
+            }
+            redraw(&mut out, prompt, &line)?;
+        }
+    }
+}
+
+fn redraw(out: &mut io::Stdout, prompt: &str, line: &str) -> io::Result<()> {
+    queue!(out, MoveToColumn(0), Clear(ClearType::CurrentLine), Print(prompt), Print(line))?;
+    out.flush()
+}
+
+fn common_prefix(words: &[String]) -> &str {
+    let first = &words[0];
+    let len = words[1..].iter().fold(first.len(), |len, word| {
+        first.bytes().zip(word.bytes()).take(len).take_while(|(a, b)| a == b).count()
+    });
+    &first[..len]
+}
+
+/// Write `text` into `sig`: a number, true/false for a bool, or a `VAL_` label.
+fn set_signal(sig: &SignalDef, text: &str, data: &mut [u8]) -> Result<()> {
+    if let Some(entry) =
+        sig.values.iter().find(|entry| entry.description.eq_ignore_ascii_case(text))
+    {
+        #[allow(clippy::cast_sign_loss)]
+        return Ok(sig.layout.write_raw(data, entry.raw as u64)?);
+    }
+    let invalid = || anyhow!("invalid value:{text}");
+    let value = match sig.kind {
+        SignalKind::Bool => match text {
+            "true" | "1" => SignalValue::Bool(true),
+            "false" | "0" => SignalValue::Bool(false),
+            _ => return Err(invalid()),
+        },
+        SignalKind::U8 => SignalValue::U8(text.parse().map_err(|_| invalid())?),
+        SignalKind::U16 => SignalValue::U16(text.parse().map_err(|_| invalid())?),
+        SignalKind::U32 => SignalValue::U32(text.parse().map_err(|_| invalid())?),
+        SignalKind::U64 => SignalValue::U64(text.parse().map_err(|_| invalid())?),
+        SignalKind::I8 => SignalValue::I8(text.parse().map_err(|_| invalid())?),
+        SignalKind::I16 => SignalValue::I16(text.parse().map_err(|_| invalid())?),
+        SignalKind::I32 => SignalValue::I32(text.parse().map_err(|_| invalid())?),
+        SignalKind::I64 => SignalValue::I64(text.parse().map_err(|_| invalid())?),
+        SignalKind::F64 | SignalKind::F32 | SignalKind::F64Bits => {
+            SignalValue::F64(text.parse().map_err(|_| invalid())?)
+        },
+    };
+    Ok(sig.encode(value, data)?)
+}
+
+/// Decoded value of `sig` in `data` with its unit and `VAL_` label.
+fn value_text(sig: &SignalDef, data: &[u8]) -> String {
+    let value = match sig.decode(data) {
+        Some(SignalValue::Bool(v)) => v.to_string(),
+        Some(SignalValue::U8(v)) => v.to_string(),
+        Some(SignalValue::U16(v)) => v.to_string(),
+        Some(SignalValue::U32(v)) => v.to_string(),
+        Some(SignalValue::U64(v)) => v.to_string(),
+        Some(SignalValue::I8(v)) => v.to_string(),
+        Some(SignalValue::I16(v)) => v.to_string(),
+        Some(SignalValue::I32(v)) => v.to_string(),
+        Some(SignalValue::I64(v)) => v.to_string(),
+        Some(SignalValue::F64(v)) => v.to_string(),
+        None => return "-".to_owned(),
+    };
+    let raw = sig.layout.read_raw(data).map(|raw| {
+        #[allow(clippy::cast_possible_wrap)]
+        if sig.layout.signed {
+            sig.layout.sign_extend(raw)
+        } else {
+            raw as i64
+        }
+    });
+    let mut text = value;
+    if !sig.unit.is_empty() {
+        text = format!("{text} {}", sig.unit);
+    }
+    match raw.and_then(|raw| sig.describe(raw)) {
+        Some(label) => format!("{text} ({label})"),
+        None => text,
+    }
+}
+
+/// CAN id as written by can-utils: 3 hex digits, 8 for extended ids.
+fn id_text(canid: u32) -> String {
+    if canid & CAN_EFF_FLAG == 0 {
+        format!("{canid:03X}")
+    } else {
+        format!("{:08X}", canid & !CAN_EFF_FLAG)
+    }
+}
+
+/// Frame in `cansend` syntax, `##0` for a CAN FD frame.
+fn frame_text(canid: u32, data: &[u8]) -> String {
+    let hex: String = data.iter().map(|byte| format!("{byte:02X}")).collect();
+    let separator = if data.len() > 8 { "##0" } else { "#" };
+    format!("{}{separator}{hex}", id_text(canid))
+}
@@ -0,0 +1,139 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Minimal SocketCAN RAW socket of the shell: classic and FD frames, read and write.
+//!
+//! Frames go through `read()`/`write()` as `struct canfd_frame` bytes: can_id, len, flags,
+//! two reserved bytes then the data, 16 bytes for a classic frame (`CAN_MTU`) and 72 for FD.
+
+use std::fs::File;
+use std::io::{self, Error, Read, Write};
+
+/// Extended frame flag of `can_id`, also carried by the raw DBC id of 29-bit messages.
+pub const CAN_EFF_FLAG: u32 = 0x8000_0000;
+const CAN_EFF_MASK: u32 = 0x1FFF_FFFF;
+const CAN_MTU: usize = 16;
+const CANFD_MTU: usize = 72;
+
+/// RAW socket bound to one interface.
+pub struct CanSocket {
+    file: File,
+}
+
+impl CanSocket {
+    /// Open a RAW socket on `iface` (e.g. "vcan0"), FD frames enabled when the interface
+    /// supports them.
+    ///
+    /// # Errors
+    /// Returns an error for an unknown interface, or when SocketCAN is not available.
+    #[cfg(target_os = "linux")]
+    pub fn open(iface: &str) -> io::Result<Self> {
+        use std::ffi::CString;
+        use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+
+        let name = CString::new(iface).map_err(Error::other)?;
+        // SAFETY: plain libc calls, every pointer refers to a live local of the given size
+        unsafe {
+            let index = libc::if_nametoindex(name.as_ptr());
+            if index == 0 {
+                return Err(Error::other(format!("unknown CAN interface:{iface}")));
+            }
+            let fd = libc::socket(libc::PF_CAN, libc::SOCK_RAW, libc::CAN_RAW);
+            if fd < 0 {
+                return Err(Error::last_os_error());
+            }
+            let fd = OwnedFd::from_raw_fd(fd);
+            let enable: libc::c_int = 1;
+            // classic only controllers refuse FD frames, they still get classic ones
+            libc::setsockopt(
+                fd.as_raw_fd(),
+                libc::SOL_CAN_RAW,
+                libc::CAN_RAW_FD_FRAMES,
+                std::ptr::addr_of!(enable).cast(),
+                std::mem::size_of::<libc::c_int>() as libc::socklen_t,
+            );
+            let mut addr: libc::sockaddr_can = std::mem::zeroed();
+            addr.can_family = libc::AF_CAN as libc::sa_family_t;
+            addr.can_ifindex = index as libc::c_int;
+            let status = libc::bind(
+                fd.as_raw_fd(),
+                std::ptr::addr_of!(addr).cast(),
+                std::mem::size_of::<libc::sockaddr_can>() as libc::socklen_t,
+            );
+            if status < 0 {
+                return Err(Error::last_os_error());
+            }
+            Ok(CanSocket { file: File::from(fd) })
+        }
+    }
+
+    /// SocketCAN only exists on Linux.
+    ///
+    /// # Errors
+    /// Always.
+    #[cfg(not(target_os = "linux"))]
+    pub fn open(iface: &str) -> io::Result<Self> {
+        Err(Error::other(format!("{iface}: SocketCAN needs Linux")))
+    }
+
+    /// Second handle on the same socket, e.g. to read from another thread.
+    ///
+    /// # Errors
+    /// Returns an error when the descriptor cannot be duplicated.
+    pub fn try_clone(&self) -> io::Result<Self> {
+        Ok(CanSocket { file: self.file.try_clone()? })
+    }
+
+    /// Send `data` with `canid`, the raw DBC id: an FD frame above 8 bytes.
+    ///
+    /// # Errors
+    /// Returns an error for more than 64 bytes or when the write fails.
+    pub fn send(&mut self, canid: u32, data: &[u8]) -> io::Result<()> {
+        let mtu = match data.len() {
+            0..=8 => CAN_MTU,
+            9..=64 => CANFD_MTU,
+            len => return Err(Error::other(format!("frame of {len} bytes over 64"))),
+        };
+        let mut frame = [0u8; CANFD_MTU];
+        frame[..4].copy_from_slice(&canid.to_ne_bytes());
+        frame[4] = data.len() as u8;
+        frame[8..8 + data.len()].copy_from_slice(data);
+        self.file.write_all(&frame[..mtu])
+    }
+
+    /// Next frame as (raw DBC id, data), blocking. Remote and error frames are skipped.
+    ///
+    /// # Errors
+    /// Returns an error when the read fails.
+    pub fn recv(&mut self) -> io::Result<(u32, Vec<u8>)> {
+        const CAN_RTR_FLAG: u32 = 0x4000_0000;
+        const CAN_ERR_FLAG: u32 = 0x2000_0000;
+        let mut frame = [0u8; CANFD_MTU];
+        loop {
+            let len = self.file.read(&mut frame)?;
+            if len != CAN_MTU && len != CANFD_MTU {
+                continue;
+            }
+            let canid = u32::from_ne_bytes([frame[0], frame[1], frame[2], frame[3]]);
+            if canid & (CAN_RTR_FLAG | CAN_ERR_FLAG) != 0 {
+                continue;
+            }
+            let size = usize::from(frame[4]).min(len - 8);
+            return Ok((canid & (CAN_EFF_FLAG | CAN_EFF_MASK), frame[8..8 + size].to_vec()));
+        }
+    }
+}
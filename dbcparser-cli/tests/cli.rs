@@ -76,6 +76,41 @@ fn generates_without_header_when_no_header_flag() {
     out.assert(predicate::str::contains("<- DBC file Rust mapping ->").not());
 }
 
+#[test]
+fn pins_generation_time_with_source_date_epoch() {
+    let tmp = assert_fs::TempDir::new().unwrap();
+    let dbc = tmp.child("in.dbc");
+    dbc.write_str(MIN_DBC).unwrap();
+    let header = tmp.child("header.txt");
+    header.write_str("// generated on {date}\n").unwrap();
+    let out = tmp.child("gen.rs");
+    let args = [
+        "-i",
+        dbc.path().to_str().unwrap(),
+        "-o",
+        out.path().to_str().unwrap(),
+        "--header-file",
+        header.path().to_str().unwrap(),
+    ];
+
+    // 2024-03-15 23:30:00 UTC, already the 16th east of UTC
+    Command::new(bin_path())
+        .args(args)
+        .env("SOURCE_DATE_EPOCH", "1710545400")
+        .env("TZ", "Asia/Tokyo")
+        .assert()
+        .success();
+    out.assert(predicate::str::starts_with("// generated on 2024-03-15\n"));
+    out.assert(predicate::str::contains("(Fri Mar 15 23:30:00 2024)"));
+
+    Command::new(bin_path())
+        .args(args)
+        .env("SOURCE_DATE_EPOCH", "yesterday")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("SOURCE_DATE_EPOCH:yesterday is not a number of seconds"));
+}
+
 #[test]
 fn generates_with_custom_header_file() {
     let tmp = assert_fs::TempDir::new().unwrap();
@@ -103,6 +138,39 @@ fn generates_with_custom_header_file() {
     out.assert(predicate::str::contains("MY-CUSTOM-HEADER"));
 }
 
+#[test]
+fn expands_header_placeholders() {
+    let tmp = assert_fs::TempDir::new().unwrap();
+    let dbc = tmp.child("in.dbc");
+    dbc.write_str(MIN_DBC).unwrap();
+
+    let header = tmp.child("header.txt");
+    header
+        .write_str("// source {dbc_path} sha256:{dbc_sha256}\n// rev:{git_rev} on {date} {other}\n")
+        .unwrap();
+
+    let out = tmp.child("gen.rs");
+
+    Command::new(bin_path())
+        .args([
+            "-i",
+            dbc.path().to_str().unwrap(),
+            "-o",
+            out.path().to_str().unwrap(),
+            "--header-file",
+            header.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    out.assert(predicate::str::contains(format!("// source {}", dbc.path().display())));
+    out.assert(predicate::str::contains(
+        "sha256:5be121e30233a3ec8df506ac2e3b251f7f8f3a5537408cd167fffa7ab13255fc",
+    ));
+    // the temporary directory is not a git checkout, unknown placeholders are kept
+    out.assert(predicate::str::is_match(r"// rev:unknown on \d{4}-\d{2}-\d{2} \{other\}").unwrap());
+}
+
 #[test]
 fn accepts_whitelist_and_blacklist_hex_and_dec() {
     let tmp = assert_fs::TempDir::new().unwrap();
@@ -158,3 +226,496 @@ fn rejects_bad_whitelist_value() {
         .failure()
         .stderr(predicate::str::contains("invalid hex id"));
 }
+
+#[test]
+fn rejects_unknown_rename_map_entry() {
+    let tmp = assert_fs::TempDir::new().unwrap();
+    let dbc = tmp.child("in.dbc");
+    dbc.write_str(MIN_DBC).unwrap();
+    let renames = tmp.child("renames.json");
+    renames.write_str(r#"{"messages": {"NOPE": "Other"}}"#).unwrap();
+
+    let out = tmp.child("gen.rs");
+
+    Command::new(bin_path())
+        .args([
+            "-i",
+            dbc.path().to_str().unwrap(),
+            "-o",
+            out.path().to_str().unwrap(),
+            "--rename-map",
+            renames.path().to_str().unwrap(),
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("message:NOPE not found"));
+}
+
+#[test]
+fn checks_generated_file_drift() {
+    let tmp = assert_fs::TempDir::new().unwrap();
+    let dbc = tmp.child("in.dbc");
+    dbc.write_str(MIN_DBC).unwrap();
+    let out = tmp.child("gen.rs");
+
+    Command::new(bin_path())
+        .args(["-i", dbc.path().to_str().unwrap(), "-o", out.path().to_str().unwrap()])
+        .assert()
+        .success();
+
+    // the generation time differs, the code does not
+    Command::new(bin_path())
+        .args(["-i", dbc.path().to_str().unwrap()])
+        .args(["--check-generated", out.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Up to date:"));
+
+    dbc.write_str(&format!("{MIN_DBC}BO_ 2 OTHER: 8 ECU\n")).unwrap();
+    Command::new(bin_path())
+        .args(["-i", dbc.path().to_str().unwrap()])
+        .args(["--check-generated", out.path().to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("generated code is stale"))
+        .stderr(predicate::str::contains("+ pub const DBC_SHA256"));
+    // the checked file is left untouched
+    out.assert(predicate::str::contains("OTHER").not());
+}
+
+#[test]
+fn replaces_copyright_block_from_config() {
+    let tmp = assert_fs::TempDir::new().unwrap();
+    let dbc = tmp.child("in.dbc");
+    dbc.write_str(MIN_DBC).unwrap();
+    let out = tmp.child("gen.rs");
+    let config = tmp.child("config.yaml");
+    config
+        .write_str(&format!(
+            "infile: {}\noutfile: {}\nuid: Acme\nheader_file: null\nno_header: true\n\
+             whitelist: null\nblacklist: null\n\
+             copyright:\n  holder: ACME Corp\n  years: 2021-2025\n  spdx: MIT\n",
+            dbc.path().display(),
+            out.path().display()
+        ))
+        .unwrap();
+
+    Command::new(bin_path())
+        .args(["--config", config.path().to_str().unwrap()])
+        .assert()
+        .success();
+
+    out.assert(predicate::str::contains(
+        "// Copyright (C) 2021-2025 ACME Corp\n// SPDX-License-Identifier: MIT\n",
+    ));
+    out.assert(predicate::str::contains("IoT.bzh").not());
+
+    // the command line flags fill the same option
+    Command::new(bin_path())
+        .args(["-i", dbc.path().to_str().unwrap(), "-o", out.path().to_str().unwrap()])
+        .args(["--no-header", "--copyright-holder", "ACME Corp"])
+        .assert()
+        .success();
+    out.assert(predicate::str::contains("// Copyright (C) ACME Corp\n// -----"));
+}
+
+#[test]
+fn applies_signal_overrides_from_config() {
+    let tmp = assert_fs::TempDir::new().unwrap();
+    let dbc = tmp.child("in.dbc");
+    dbc.write_str(&format!(
+        "{MIN_DBC} SG_ Speed : 0|8@1+ (1,0) [0|200] \"\" ECU\n SG_ Raw : 8|8@1+ (1,0) [0|100] \"\" ECU\n"
+    ))
+    .unwrap();
+    let out = tmp.child("gen.rs");
+    let config = tmp.child("config.yaml");
+    config
+        .write_str(&format!(
+            "infile: {}\noutfile: {}\nuid: Acme\nheader_file: null\nno_header: true\n\
+             whitelist: null\nblacklist: null\n\
+             overrides:\n  - pattern: MSG.Raw\n    range_check: false\n    serde_json: false\n",
+            dbc.path().display(),
+            out.path().display()
+        ))
+        .unwrap();
+
+    Command::new(bin_path())
+        .args(["--config", config.path().to_str().unwrap()])
+        .assert()
+        .success();
+
+    out.assert(predicate::str::contains("not in [0..200]"));
+    out.assert(predicate::str::contains("not in [0..100]").not());
+    out.assert(predicate::str::contains("\"null\".to_owned()"));
+
+    // the command line flags fill the same option
+    Command::new(bin_path())
+        .args(["-i", dbc.path().to_str().unwrap(), "-o", out.path().to_str().unwrap()])
+        .args(["--no-range-check", "Speed"])
+        .assert()
+        .success();
+    out.assert(predicate::str::contains("not in [0..200]").not());
+    out.assert(predicate::str::contains("not in [0..100]"));
+    out.assert(predicate::str::contains("\"null\".to_owned()").not());
+}
+
+#[test]
+fn reads_signal_structs_from_config() {
+    let tmp = assert_fs::TempDir::new().unwrap();
+    let out = tmp.child("gen.rs");
+    let config = tmp.child("config.yaml");
+    config
+        .write_str(&format!(
+            "infile: tests/dbc/gps.dbc\noutfile: {}\nuid: Gps\nheader_file: null\nno_header: true\n\
+             whitelist: null\nblacklist: null\n\
+             signal_structs:\n  - name: gps_fix\n    max_age_ms: 500\n    fields:\n\
+             \x20     - {{ field: Lat, signal: GpsPosition.Latitude }}\n\
+             \x20     - {{ field: Valid, signal: FixValid }}\n",
+            out.path().display()
+        ))
+        .unwrap();
+
+    Command::new(bin_path())
+        .args(["--config", config.path().to_str().unwrap()])
+        .assert()
+        .success();
+
+    out.assert(predicate::str::contains(
+        "pub struct GpsFix {\n    pub lat: f64,\n    pub valid: bool,",
+    ));
+    out.assert(predicate::str::contains("pub const MAX_AGE_MS: u64 = 500;"));
+    out.assert(predicate::str::contains("pub fn get_gps_fix(&self)"));
+}
+
+#[test]
+fn generates_one_module_per_input() {
+    let tmp = assert_fs::TempDir::new().unwrap();
+    let body = tmp.child("body.dbc");
+    body.write_str(MIN_DBC).unwrap();
+    let chassis = tmp.child("chassis.dbc");
+    chassis.write_str(&MIN_DBC.replace("BO_ 1 MSG", "BO_ 2 WHEEL")).unwrap();
+    let out = tmp.child("gen.rs");
+
+    Command::new(bin_path())
+        .args(["-i", body.path().to_str().unwrap(), "--uid", "BodyBus"])
+        .args(["-i", chassis.path().to_str().unwrap(), "--uid", "ChassisBus"])
+        .args(["-o", out.path().to_str().unwrap(), "--no-header"])
+        .assert()
+        .success();
+    out.assert(predicate::str::contains("mod BodyBus {"));
+    out.assert(predicate::str::contains("mod ChassisBus {"));
+    out.assert(predicate::str::contains("pub mod Wheel {"));
+
+    // one uid per input, all distinct
+    Command::new(bin_path())
+        .args(["-i", body.path().to_str().unwrap(), "-i", chassis.path().to_str().unwrap()])
+        .args(["-o", out.path().to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("2 inputs need as many --uid, got 0"));
+    Command::new(bin_path())
+        .args(["-i", body.path().to_str().unwrap(), "--uid", "Bus"])
+        .args(["-i", chassis.path().to_str().unwrap(), "--uid", "Bus"])
+        .args(["-o", out.path().to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("uid Bus is used by several inputs"));
+
+    // the YAML configuration lists additional inputs
+    let config = tmp.child("config.yaml");
+    config
+        .write_str(&format!(
+            "infile: {}\noutfile: {}\nuid: BodyBus\nheader_file: null\nno_header: true\n\
+             whitelist: null\nblacklist: null\n\
+             extra_inputs:\n  - infile: {}\n    uid: ChassisBus\n",
+            body.path().display(),
+            out.path().display(),
+            chassis.path().display()
+        ))
+        .unwrap();
+    Command::new(bin_path())
+        .args(["--config", config.path().to_str().unwrap()])
+        .args(["--check-generated", out.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Up to date"));
+}
+
+#[test]
+fn writes_json_ir_and_markdown_in_one_run() {
+    let tmp = assert_fs::TempDir::new().unwrap();
+    let out = tmp.child("gen.rs");
+    let json = tmp.child("db.json");
+    let markdown = tmp.child("db.md");
+
+    Command::new(bin_path())
+        .args(["-i", "tests/dbc/val.dbc", "-o", out.path().to_str().unwrap()])
+        .args(["--json-ir", json.path().to_str().unwrap()])
+        .args(["--markdown", markdown.path().to_str().unwrap()])
+        .assert()
+        .success();
+    out.assert(predicate::str::contains("pub mod Main {"));
+
+    let ir: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(json.path()).unwrap()).unwrap();
+    assert_eq!(ir["uid"], "DbcSimple");
+    assert_eq!(ir["messages"][0]["id"], 641);
+    assert_eq!(ir["messages"][0]["signals"][1]["name"], "LengthWithCode");
+    assert_eq!(ir["messages"][0]["signals"][1]["factor"], 0.5);
+    assert_eq!(ir["messages"][0]["signals"][1]["values"][0]["description"], "TOO_LONG");
+
+    markdown.assert(predicate::str::contains("| 0x281 | [Main](#main) | 8 |  |"));
+    markdown.assert(predicate::str::contains(
+        "| LengthWithCode | 2\\|2 | BE | unsigned | 0.5 | 0 | 0 | 1.5 |  |  |  |",
+    ));
+}
+
+#[test]
+fn includes_doc_examples_from_the_crate_root() {
+    let tmp = assert_fs::TempDir::new().unwrap();
+    tmp.child("Cargo.toml").write_str("[package]\nname = \"vehicle\"\n").unwrap();
+    let out = tmp.child("src/dbc.rs");
+    std::fs::create_dir_all(tmp.child("src").path()).unwrap();
+
+    Command::new(bin_path())
+        .args(["-i", "tests/dbc/heartbeat.dbc", "-o", out.path().to_str().unwrap()])
+        .args(["--emit-tests"])
+        .assert()
+        .success();
+    // independent of the checkout location
+    out.assert(predicate::str::contains(
+        r#"/// # include!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/dbc.rs"));"#,
+    ));
+}
+
+#[test]
+fn writes_criterion_benches() {
+    let tmp = assert_fs::TempDir::new().unwrap();
+    let dbc = tmp.child("mux.dbc");
+    dbc.write_str(
+        "VERSION \"\"\n\nNS_ :\n\nBS_:\n\nBU_: ECU\n\n\
+         BO_ 256 Mux: 8 ECU\n SG_ Page M : 0|8@1+ (1,0) [0|255] \"\" ECU\n\
+         SG_ Speed m2 : 8|16@1+ (0.1,0) [0|250] \"\" ECU\n\
+         SG_ Temp m3 : 8|8@1- (1,-40) [-40|85] \"\" ECU\n\n\
+         BO_ 257 Broken: 2 ECU\n SG_ Level : 0|8@1+ (1,0) [10|5] \"\" ECU\n",
+    )
+    .unwrap();
+    let out = tmp.child("gen.rs");
+    let benches = tmp.child("dbc_bench.rs");
+
+    Command::new(bin_path())
+        .args(["-i", dbc.path().to_str().unwrap(), "-o", out.path().to_str().unwrap()])
+        .args(["--emit-benches", benches.path().to_str().unwrap()])
+        .args(["--static-pool"])
+        .assert()
+        .success();
+    benches.assert(predicate::str::contains(format!("include!({:?});", out.path())));
+    // first multiplexed page, other pages left to their default
+    benches.assert(predicate::str::contains(
+        "    DbcSimple::Mux::SignalValues {
+        page: 2,
+        speed: 125.0,
+        ..Default::default()
+    }",
+    ));
+    // no Level value passes [10|5]: decoded from zeros, not encoded
+    benches.assert(predicate::str::contains("fn broken_frame() -> [u8; 2] {\n    [0u8; 2]\n}"));
+    benches.assert(predicate::str::contains("fn broken_values()").not());
+    benches.assert(predicate::str::contains(
+        "criterion_group!(benches, decode, encode, pool_update, static_pool_update);",
+    ));
+}
+
+#[test]
+fn writes_cargo_package() {
+    let tmp = assert_fs::TempDir::new().unwrap();
+    let dbc = tmp.child("car.dbc");
+    dbc.write_str(
+        "VERSION \"2.3.1\"\n\nNS_ :\n\nBS_:\n\nBU_: ECU\n\n\
+         BO_ 256 Speed: 2 ECU\n SG_ Value : 0|16@1+ (0.1,0) [0|250] \"km/h\" ECU\n",
+    )
+    .unwrap();
+    let package = tmp.child("my-car-dbc");
+
+    Command::new(bin_path())
+        .args(["-i", dbc.path().to_str().unwrap()])
+        .args(["--emit-crate", package.path().to_str().unwrap()])
+        .args(["--serde-feature", "serde"])
+        .assert()
+        .success();
+    let manifest = package.child("Cargo.toml");
+    manifest.assert(predicate::str::contains(
+        "[package]\nname = \"my-car-dbc\"\nversion = \"2.3.1\"\nedition = \"2021\"",
+    ));
+    manifest.assert(predicate::str::contains(
+        "serde = { version = \"1.0.229\", features = [\"derive\"], optional = true }",
+    ));
+    manifest.assert(predicate::str::contains("sockcan = { git = "));
+    manifest.assert(predicate::str::contains(
+        "[features]\nserde = [\"dep:serde\", \"dep:serde_json\"]",
+    ));
+    // lib.rs is the default output, at the crate root
+    let lib = package.child("src/lib.rs");
+    lib.assert(predicate::str::contains("//! CAN messages and signals of car.dbc"));
+    lib.assert(predicate::str::contains("mod DbcSimple {").not());
+    package.child("tests/messages.rs").assert(predicate::str::contains(
+        "    my_car_dbc::Speed::encode(&values, &mut data).expect(\"Speed values\");",
+    ));
+
+    // package names come from the directory
+    Command::new(bin_path())
+        .args(["-i", dbc.path().to_str().unwrap()])
+        .args(["--emit-crate", tmp.child("2car").path().to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not named after a crate"));
+}
+
+#[test]
+fn suggests_semver_bump_of_dbc_changes() {
+    let tmp = assert_fs::TempDir::new().unwrap();
+    let base = "NS_ :\n\nBS_:\n\nBU_: ECU HMI\n\n\
+                BO_ 256 Speed: 4 ECU\n SG_ Value : 0|16@1+ (0.1,0) [0|250] \"km/h\" HMI\n\
+                SG_ Gear : 16|4@1+ (1,0) [0|8] \"\" HMI\n\n\
+                CM_ BO_ 256 \"vehicle speed\";\n";
+    let old = tmp.child("old.dbc");
+    old.write_str(&format!("VERSION \"1.2.0\"\n\n{base}")).unwrap();
+    let new = tmp.child("new.dbc");
+    let commented = base.replace("vehicle speed", "speed over ground");
+    new.write_str(&format!("VERSION \"1.2.1\"\n\n{commented}")).unwrap();
+
+    Command::new(bin_path())
+        .args(["-i", new.path().to_str().unwrap(), "--compat", old.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(
+            "patch: comment of message Speed changed\nsuggested bump: patch (1.2.0 -> 1.2.1)\n",
+        );
+
+    // Gear moved: a breaking change the declared VERSION does not follow
+    let moved = commented.replace("16|4@1+", "20|4@1+");
+    new.write_str(&format!("VERSION \"1.3.0\"\n\n{moved}")).unwrap();
+    Command::new(bin_path())
+        .args(["-i", new.path().to_str().unwrap(), "--compat", old.path().to_str().unwrap()])
+        .assert()
+        .failure()
+        .stdout(predicate::str::starts_with(
+            "major: signal Speed.Gear moved 16@LittleEndian -> 20@LittleEndian\n",
+        ))
+        .stdout(predicate::str::contains("suggested bump: major (1.2.0 -> 2.0.0)"))
+        .stderr(predicate::str::contains("VERSION 1.3.0 too low for a major change"));
+
+    use dbcparser::compat::{next_version, Bump};
+    assert_eq!(next_version("0.4.2", Bump::Major).as_deref(), Some("0.5.0"));
+    assert_eq!(next_version("0.4.2", Bump::Minor).as_deref(), Some("0.4.3"));
+    assert_eq!(next_version("1.4.2", Bump::Minor).as_deref(), Some("1.5.0"));
+    assert_eq!(next_version("1.4", Bump::Patch), None);
+}
+
+#[test]
+fn writes_message_json_schema() {
+    let tmp = assert_fs::TempDir::new().unwrap();
+    let out = tmp.child("gen.rs");
+    let schema = tmp.child("db.schema.json");
+
+    Command::new(bin_path())
+        .args(["-i", "tests/dbc/val.dbc", "-o", out.path().to_str().unwrap()])
+        .args(["--json-schema", schema.path().to_str().unwrap()])
+        .assert()
+        .success();
+
+    let schema: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(schema.path()).unwrap()).unwrap();
+    assert_eq!(schema["properties"]["messages"]["items"]["oneOf"][0]["$ref"], "#/$defs/Main");
+    let main = &schema["$defs"]["Main"];
+    assert_eq!(main["properties"]["id"]["const"], 641);
+    let value = &main["properties"]["signals"]["properties"]["MyCode"]["properties"]["value"];
+    assert_eq!(value["type"], serde_json::json!(["integer", "null"]));
+    assert_eq!(value["x-enum-varnames"][1], "One");
+    let value =
+        &main["properties"]["signals"]["properties"]["LengthWithCode"]["properties"]["value"];
+    assert_eq!(value["type"], serde_json::json!(["number", "null"]));
+    assert_eq!(value["maximum"], 1.5);
+}
+
+#[test]
+fn expands_environment_in_config_paths() {
+    let tmp = assert_fs::TempDir::new().unwrap();
+    let dbc = tmp.child("in.dbc");
+    dbc.write_str(MIN_DBC).unwrap();
+    let config = tmp.child("config.yaml");
+    config
+        .write_str(
+            "infile: ${CANFORGE_TEST_DIR}/in.dbc\noutfile: ~/gen.rs\nuid: Env\n\
+             header_file: null\nno_header: true\nwhitelist: null\nblacklist: null\n",
+        )
+        .unwrap();
+
+    Command::new(bin_path())
+        .args(["--config", config.path().to_str().unwrap()])
+        .env("CANFORGE_TEST_DIR", tmp.path())
+        .env("HOME", tmp.path())
+        .assert()
+        .success();
+    tmp.child("gen.rs").assert(predicate::path::exists());
+
+    Command::new(bin_path())
+        .args(["--config", config.path().to_str().unwrap()])
+        .env_remove("CANFORGE_TEST_DIR")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("environment variable CANFORGE_TEST_DIR is not set"));
+}
+
+#[test]
+fn keeps_previous_output_when_generation_fails() {
+    let tmp = assert_fs::TempDir::new().unwrap();
+    let dbc = tmp.child("in.dbc");
+    // two multiplexors are rejected while the message code is generated
+    dbc.write_str(&format!(
+        "{MIN_DBC} SG_ MuxA M : 0|4@1+ (1,0) [0|15] \"\" ECU\n SG_ MuxB M : 4|4@1+ (1,0) [0|15] \"\" ECU\n"
+    ))
+    .unwrap();
+    let out = tmp.child("gen.rs");
+    out.write_str("// previous generation\n").unwrap();
+
+    Command::new(bin_path())
+        .args(["-i", dbc.path().to_str().unwrap(), "-o", out.path().to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("multiple multiplexors"));
+
+    out.assert("// previous generation\n");
+    // no temporary file is left behind
+    assert_eq!(std::fs::read_dir(tmp.path()).unwrap().count(), 2);
+}
+
+#[test]
+fn shell_sends_piped_commands() {
+    // without --iface the frames are printed in cansend syntax
+    assert_cmd::Command::new(bin_path())
+        .args(["shell", "-i", "tests/dbc/gps.dbc"])
+        .write_stdin(
+            "send GpsPosition Latitude=1.5 Longitude=-3\n\
+             send GpsPosition Latitude=0\n\
+             send GpsAltitude Altitude=10000\n\
+             list GpsAltitude\n\
+             quit\n\
+             send GpsAltitude FixValid=true\n",
+        )
+        .assert()
+        .success()
+        .stdout(
+            "321#C0E1E400803C36FE\n\
+             321#00000000803C36FE\n\
+             error: GpsAltitude.Altitude: value=10000 not in [-500..9000]\n\
+             Altitude [-500..9000] m\n\
+             FixValid [0..1]\n",
+        );
+    assert_cmd::Command::new(bin_path())
+        .args(["shell", "-i", "tests/dbc/val.dbc"])
+        .write_stdin("send Main LengthWithCode=too_long\nwatch MyCode\n")
+        .assert()
+        .success()
+        .stdout("281#0600000000000000\nerror: watch needs an interface (--iface)\n");
+}
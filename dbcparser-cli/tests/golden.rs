@@ -0,0 +1,51 @@
+// The golden references are generated code users copy as-is: tests_codegen.rs only
+// diffs them, compiling them here catches output that diffs cleanly but does not build.
+
+mod complete {
+    include!("../examples/canforge_dbc_complete_norm/canforge_dbc_complete_norm.rs");
+
+    #[test]
+    fn golden_reference_builds() {
+        assert_eq!(DbcSimple::DBC_SHA256.len(), 64);
+    }
+
+    #[test]
+    fn looks_up_signal_metadata_by_name() {
+        use DbcSimple::SignalMeta;
+        let speed = SignalMeta {
+            unit: "km/h",
+            min: 0.0,
+            max: 300.0,
+            factor: 0.01,
+            offset: 0.0,
+            bit_length: 16,
+            start_bit: 8,
+        };
+        assert_eq!(DbcSimple::CanMsgPool::signal_meta(100, "VehicleSpeedKph"), Some(speed));
+        assert_eq!(DbcSimple::CanMsgPool::signal_meta(100, "vehicle_speed_kph"), Some(speed));
+        assert_eq!(
+            DbcSimple::VehicleStatus::DbcMessage::signal_meta("vehicle_speed_kph"),
+            Some(speed)
+        );
+        assert_eq!(DbcSimple::CanMsgPool::signal_meta(100, "engine_rpm"), None);
+        assert_eq!(DbcSimple::CanMsgPool::signal_meta(1, "VehicleSpeedKph"), None);
+    }
+}
+
+mod whitelist {
+    include!("../examples/canforge_dbc_complete_norm/canforge_dbc_complete_norm_whitelist.rs");
+
+    #[test]
+    fn golden_reference_builds() {
+        assert_eq!(DbcSimple::DBC_SHA256.len(), 64);
+    }
+}
+
+mod blacklist {
+    include!("../examples/canforge_dbc_complete_norm/canforge_dbc_complete_norm_blacklist.rs");
+
+    #[test]
+    fn golden_reference_builds() {
+        assert_eq!(DbcSimple::DBC_SHA256.len(), 64);
+    }
+}
@@ -13,12 +13,8 @@ fn bin_path() -> std::path::PathBuf {
 
 /// Read a file, filtering out the line that contains the auto-generated date comment.
 fn filter_content(path: &Path) -> String {
-    fs::read_to_string(path)
+    dbcparser::golden::read_filtered(path)
         .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e))
-        .lines()
-        .filter(|line| !line.contains("// - code generated from"))
-        .collect::<Vec<_>>()
-        .join("\n")
 }
 #[test]
 fn test_codegen() {}
@@ -150,33 +146,1786 @@ fn generates_test_sections_not_in_order() {
 fn test_variant_generation() {
     codegen_test_snippet(
         "tests/dbc/val.dbc",
-        r#"pub fn set_raw_value(&mut self, value: u8, data: &mut[u8]) {
-            data.view_bits_mut::<Msb0>()[5..7].store_be(value);
-
+        r#"#[deprecated(note = "use set_raw()")]
+        pub fn set_raw_value(&mut self, value: u8, data: &mut[u8]) {
+            self.set_raw(value, data)
         }
         pub fn set_as_def (&mut self, signal_def: DbcLengthWithCode, data: &mut[u8])-> Result<(),CanError> {
             match signal_def {
-                DbcLengthWithCode::TooLong => Ok(self.set_raw_value(3, data)),
-                DbcLengthWithCode::_Other(x) => self.set_typed_value(x,data)
+                DbcLengthWithCode::TooLong => Ok(self.set_raw(3, data)),
+                DbcLengthWithCode::_Other(x) => self.set_physical(x,data)
             }
         }
 
+        #[inline]
         fn get_typed_value(&self) -> f64 {
             self.value.unwrap_or_default()
         }
 
-        fn set_typed_value(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub fn physical_from_raw(raw: u8) -> f64 {
+            (raw as f64) * 0.5_f64 + 0_f64
+        }
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub fn raw_from_physical(value: f64) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 2 == 64 { u64::MAX } else { (1u64 << 2) - 1 };
+            let factor = 0.5_f64;
+            let offset = 0_f64;
+            let __raw_f = (value - offset) / factor;
+            ((__raw_f as u64) & __mask) as u8
+        }
 
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {
+            // Range-check clamped to the target type limits (avoids overflowing literals).
             if value < 0_f64 || 1.5_f64 < value {
                 return Err(CanError::new("invalid-signal-value",format!("value={} not in [0..1.5]",value)));
             }
-            let factor = 0.5_f64;
-            let offset = 0_f64;
-            let value = ((value - offset) / factor) as u8;
+            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: u8, data: &mut[u8]) {
             data.view_bits_mut::<Msb0>()[5..7].store_be(value);
+        }"#,
+        vec![],
+    );
+}
 
-            Ok(())
+#[test]
+fn generates_const_conversions() {
+    codegen_test_snippet(
+        "tests/dbc/val.dbc",
+        r"        #[inline]
+        pub const fn raw_from_physical(value: u8) -> u8 {
+            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if 2 == 64 { u64::MAX } else { (1u64 << 2) - 1 };
+            ((value as u64) & __mask) as u8
+        }",
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/val.dbc",
+        "                    let newval = Self::physical_from_raw(raw);",
+        vec![],
+    );
+}
+
+#[test]
+fn generates_plugin_abi() {
+    codegen_test_snippet(
+        "tests/dbc/val.dbc",
+        r#"#[no_mangle]
+pub extern "C" fn canforge_plugin_init() -> *mut c_void {
+    Box::into_raw(Box::new(CanMsgPool::new("DbcSimple"))) as *mut c_void
+}"#,
+        vec!["--plugin-abi"],
+    );
+    // len is narrowed to the u8 of CanMsgData, longer payloads are refused first; (NULL, 0)
+    // is an empty DLC 0 payload
+    codegen_test_snippet(
+        "tests/dbc/val.dbc",
+        r#"    if (data.is_null() && len > 0) || len > 64 {
+        return -1;
+    }"#,
+        vec!["--plugin-abi"],
+    );
+    codegen_test_snippet(
+        "tests/dbc/val.dbc",
+        r"        data: if len == 0 { Vec::new() } else { std::slice::from_raw_parts(data, len).to_vec() },",
+        vec!["--plugin-abi"],
+    );
+}
+
+#[test]
+fn generates_from_canopen_eds() {
+    codegen_test_snippet(
+        "tests/eds/io_module.eds",
+        r"/// TPDO1 Message
+/// - ID: 389 (0x185)
+/// - Size: 4 bytes
+/// - Transmitter: IO_Module",
+        vec!["--node-id", "5"],
+    );
+}
+
+#[test]
+fn generates_from_lin_ldf() {
+    codegen_test_snippet(
+        "tests/ldf/seat.ldf",
+        r"/// HeaterCmd Message
+/// - ID: 16 (0x10)
+/// - Size: 1 bytes
+/// - Transmitter: BodyEcu",
+        vec![],
+    );
+}
+
+#[test]
+fn generates_typed_attributes() {
+    codegen_test_snippet(
+        "tests/dbc/attributes.dbc",
+        r#"    pub const GEN_MSG_SEND_TYPE: AttributeDef = AttributeDef {
+        name: "GenMsgSendType",
+        scope: AttributeScope::Message,
+        kind: AttributeKind::Enum,
+        range: None,
+        choices: &["none", "cyclic", "event"],
+        default: Some(AttributeValue::Str("cyclic")),
+    };"#,
+        vec![],
+    );
+}
+
+#[test]
+fn generates_attribute_values() {
+    codegen_test_snippet(
+        "tests/dbc/attributes.dbc",
+        r#"        pub const ATTRIBUTES: [(&'static str, super::attributes::AttributeValue); 1] = [("GenMsgCycleTime", super::attributes::AttributeValue::Int(50))];
+
+        /// Value of the message attribute `name`, its BA_DEF_DEF_ default when not set
+        pub fn get_attribute(name: &str) -> Option<super::attributes::AttributeValue> {
+            match name {
+                "GenMsgCycleTime" => Some(super::attributes::AttributeValue::Int(50)),
+                "GenMsgSendType" => super::attributes::GEN_MSG_SEND_TYPE.default(),
+                _ => None,
+            }
         }"#,
         vec![],
     );
+    codegen_test_snippet(
+        "tests/dbc/attributes.dbc",
+        r#"                "GenSigStartValue" => Some(super::attributes::AttributeValue::Float(40.0_f64)),"#,
+        vec![],
+    );
+
+    let dbc = std::env::temp_dir().join("canforge_attribute_values.dbc");
+    let generate = |value: &str| {
+        std::fs::write(
+            &dbc,
+            format!(
+                "VERSION \"\"\n\nNS_ :\n\nBS_:\n\nBU_: ECU\n\n\
+                 BO_ 1 Door: 1 ECU\n SG_ Open : 0|1@1+ (1,0) [0|1] \"\" ECU\n\n\
+                 BA_DEF_ SG_  \"SafetyLevel\" ENUM  \"QM\",\"ASIL_A\",\"ASIL_B\";\n\
+                 BA_ \"SafetyLevel\" SG_ 1 Open {value};\n"
+            ),
+        )
+        .unwrap();
+        dbcparser::gencode::DbcParser::new("DbcSimple").dbcfile(&dbc).generate_string()
+    };
+    // enum values are given by their label index
+    let code = generate("2").unwrap();
+    assert!(code.contains(r#"("SafetyLevel", super::attributes::AttributeValue::Str("ASIL_B"))"#));
+    let err = generate("3").unwrap_err();
+    assert!(err.to_string().contains("signal:Door.Open invalid SafetyLevel value:3"), "{err}");
+}
+
+#[test]
+fn generates_signal_group_struct() {
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r"    pub struct BrakeSafetyGroup {
+        pub brake_pressure: f64,
+        pub brake_active: bool,
+        pub alive_counter: u8,
+        pub checksum: u8,
+    }",
+        vec![],
+    );
+}
+
+#[test]
+fn generates_multiple_transmitters() {
+    codegen_test_snippet(
+        "tests/dbc/multi_tx.dbc",
+        r#"/// - Transmitters: Bms, Vcu
+
+pub mod PackLimits { /// Message name space"#,
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/multi_tx.dbc",
+        r#"    pub mod Vcu {
+        pub const NAME: &str = "Vcu";
+        pub const TRANSMITS: [u32; 1] = [768];"#,
+        vec![],
+    );
+}
+
+#[test]
+fn generates_receiver_constants() {
+    codegen_test_snippet(
+        "tests/dbc/multi_tx.dbc",
+        r#"        pub const RECEIVERS: [&'static str; 2] = ["Bms", "Vcu"];"#,
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/multi_tx.dbc",
+        r#"        pub const CONSUMES: [(u32, &str); 2] = [(768, "MaxDischargeCurrent"), (769, "OutputVoltage")];"#,
+        vec![],
+    );
+}
+
+#[test]
+fn generates_presence_only_messages() {
+    codegen_test_snippet(
+        "tests/dbc/heartbeat.dbc",
+        r"pub mod Heartbeat { /// Message name space, presence only (no signal)",
+        vec![],
+    );
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/heartbeat.dbc")
+        .generate_string()
+        .unwrap();
+    let heartbeat = &code[code.find("pub mod Heartbeat").unwrap()..];
+    let heartbeat = &heartbeat[..heartbeat.find("// end Heartbeat message").unwrap()];
+    assert!(heartbeat.contains("            &[]\n"));
+    assert!(!heartbeat.contains("pub enum DbcSignal"));
+    assert!(!heartbeat.contains("set_values"));
+}
+
+#[test]
+fn generates_byte_array_signals() {
+    codegen_test_snippet(
+        "tests/dbc/wide.dbc",
+        r"        pub fn set_values(&mut self, vin: [u8; 17], serial: [u8; 16], revision: u8, frame: &mut[u8]) -> Result<&mut Self, CanError> {",
+        vec![],
+    );
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/wide.dbc")
+        .generate_string()
+        .unwrap();
+    assert!(code.contains("        value: Option<[u8; 17]>,"));
+    assert!(code.contains(
+        "        pub fn set_ascii(&mut self, text: &str, data: &mut [u8]) -> Result<(),CanError> {"
+    ));
+    assert!(code.contains("                bits[start..(start + 8).min(264)].store_be(*byte);"));
+    assert!(!code.contains("pub vin:"));
+}
+
+#[test]
+fn generates_text_signals() {
+    codegen_test_snippet(
+        "tests/dbc/wide.dbc",
+        r"            let bytes = &raw.to_be_bytes()[6..];",
+        vec!["--text", "Firmware.Build"],
+    );
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/wide.dbc")
+        .text_signals(vec!["Vin".to_owned(), "Firmware.V*".to_owned()])
+        .generate_string()
+        .unwrap();
+    assert!(code.contains("            let bytes = &raw.to_le_bytes()[..6];"));
+    assert!(code.contains("            self.set_ascii(text, data)"));
+    assert_eq!(code.matches("pub fn get_text(&self) -> String {").count(), 2);
+
+    let err = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/wide.dbc")
+        .text_signals(vec!["Patch".to_owned()])
+        .generate_string()
+        .unwrap_err();
+    assert!(err.to_string().contains("text signal:Patch needs a whole number of bytes"));
+}
+
+#[test]
+fn generates_counter_checks() {
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r"                    Some(last) => match counter.wrapping_sub(last) & 0xf {
+                        0 => super::CounterStatus::Replayed,
+                        step if step <= 0x8 => super::CounterStatus::Fresh,
+                        _ => super::CounterStatus::Stale,
+                    },",
+        vec!["--counter", "BrakeStatus.AliveCounter"],
+    );
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/sig_group.dbc")
+        .generate_string()
+        .unwrap();
+    assert!(!code.contains("CounterStatus"));
+
+    let err = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/sig_group.dbc")
+        .counters(vec!["BrakePressure".to_owned()])
+        .generate_string()
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("counter:BrakePressure must be a plain 2 to 64 bits signal"));
+}
+
+#[test]
+fn generates_e2e_protection() {
+    // counter and checksum from GenSigFuncType, data id from E2EDataID
+    codegen_test_snippet(
+        "tests/dbc/e2e.dbc",
+        r"        pub const CHECKSUM_ALGORITHM: super::CrcAlgorithm = super::CRC8_H2F;
+        /// Payload bytes of the Checksum signal
+        pub const CHECKSUM_BYTES: std::ops::Range<usize> = 7..8;
+        /// E2E data id mixed into the checksum, from the E2EDataID attribute
+        pub const DATA_ID: u16 = 0x123;",
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/e2e.dbc",
+        r"            // E2E protection: next rolling counter, then the checksum of the final payload
+            frame.view_bits_mut::<Lsb0>()[16..20].store_le(self.tx_counter as u8);
+            self.tx_counter= (self.tx_counter + 1) % 15;
+            let checksum = DbcMessage::checksum(frame) as u8;
+            frame.view_bits_mut::<Lsb0>()[56..64].store_le(checksum);",
+        vec![],
+    );
+    // algorithm by signal size, or named by the pattern
+    codegen_test_snippet(
+        "tests/dbc/e2e.dbc",
+        r"pub const CRC16_CCITT_FALSE: CrcAlgorithm = CrcAlgorithm {",
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/e2e.dbc",
+        r"            Self::CHECKSUM_ALGORITHM.compute(&[&data[..4], &data[5..]])",
+        vec!["--checksum", "Odometer.OdoCrc=crc8_h2f"],
+    );
+
+    let generate = |patterns: &[&str]| {
+        dbcparser::gencode::DbcParser::new("DbcSimple")
+            .dbcfile("tests/dbc/e2e.dbc")
+            .checksums(patterns.iter().map(|pattern| (*pattern).to_owned()).collect())
+            .generate_string()
+    };
+    let err = generate(&["OdoCrc=CRC7"]).unwrap_err();
+    assert!(err.to_string().contains("checksum:OdoCrc unknown crc algorithm:CRC7"), "{err}");
+    let err = generate(&["Distance=CRC32"]).unwrap_err();
+    assert!(err.to_string().contains("checksum:Distance must be a plain byte aligned"), "{err}");
+    let err = generate(&["WheelSpeed.Speed"]).unwrap_err();
+    assert!(err.to_string().contains("message:WheelSpeed has several checksums"), "{err}");
+}
+
+#[test]
+fn crc_algorithms_match_check_values() {
+    use dbcparser::e2e::{builtin_crc_algorithms, e2e_function, CrcAlgorithm, E2eFunction};
+
+    let checks: Vec<(String, u64)> = builtin_crc_algorithms()
+        .iter()
+        .map(|algo| (algo.name.clone(), algo.check()))
+        .collect();
+    assert_eq!(
+        checks,
+        [
+            ("CRC8_SAE_J1850", 0x4B),
+            ("CRC8_H2F", 0xDF),
+            ("CRC16_CCITT_FALSE", 0x29B1),
+            ("CRC32", 0xCBF4_3926),
+            ("CRC32_P4", 0x1697_D06A),
+            ("CRC64_XZ", 0x995D_C9BB_DF19_39FA),
+        ]
+        .map(|(name, check)| (name.to_owned(), check))
+    );
+    let custom = CrcAlgorithm::new("CRC16_ARC", 16, 0x8005, 0, 0, true);
+    assert_eq!(custom.check(), 0xBB3D);
+
+    let algorithms = [custom];
+    assert_eq!(e2e_function("CNTR_AR_01", &algorithms), Some(E2eFunction::Counter));
+    assert_eq!(
+        e2e_function("crc16_arc", &algorithms),
+        Some(E2eFunction::Checksum(Some("CRC16_ARC".to_owned())))
+    );
+    assert_eq!(e2e_function("CHK", &algorithms), Some(E2eFunction::Checksum(None)));
+    assert_eq!(e2e_function("NoFunction", &algorithms), None);
+}
+
+#[test]
+fn drops_signals_from_generation() {
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r"        pub fn set_values(&mut self, brake_pressure: f64, brake_active: bool, alive_counter: u8, frame: &mut[u8]) -> Result<&mut Self, CanError> {",
+        vec!["--drop-signal", "Spare", "--drop-signal", "BrakeStatus.Check*"],
+    );
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r"    pub struct BrakeSafetyGroup {
+        pub brake_pressure: f64,
+        pub brake_active: bool,
+        pub alive_counter: u8,
+    }",
+        vec!["--drop-signal", "*.Checksum"],
+    );
+}
+
+#[test]
+fn renames_messages_and_signals() {
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r"    pub struct BrakeSafetyGroup {
+        pub brake_pressure: f64,
+        pub brake_active: bool,
+        pub rolling_counter: u8,
+        pub crc: u8,
+    }",
+        vec!["--rename-map", "tests/dbc/renames.json"],
+    );
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        "pub mod BrakeState { /// Message name space",
+        vec!["--rename-map", "tests/dbc/renames.json"],
+    );
+}
+
+#[test]
+fn keeps_dbc_names_with_affixes() {
+    let args = vec!["--rename-map", "tests/dbc/renames_keep.yaml"];
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r"    pub struct BrakeSafetyGroup {
+        pub BrakePressure_sig: f64,
+        pub BrakeActive_sig: bool,
+        pub AliveCounter_sig: u8,
+        pub Crc: u8,
+    }",
+        args.clone(),
+    );
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        "pub mod Can_BrakeStatus { /// Message name space",
+        args,
+    );
+
+    let renames =
+        dbcparser::gencode::RenameMap { signal_prefix: "My-".to_owned(), ..Default::default() };
+    let error = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/sig_group.dbc")
+        .rename_map(renames)
+        .generate_string()
+        .unwrap_err();
+    assert_eq!(error.to_string(), r#"rename-map: affix:"My-" not an identifier part"#);
+}
+
+#[test]
+fn documents_value_tables() {
+    codegen_test_snippet(
+        "tests/dbc/val.dbc",
+        r"    /// MyCode values (DBC VAL_)
+    ///
+    /// | Value | Meaning |
+    /// |---|---|
+    /// | 0 | ZERO |
+    /// | 1 | ONE |
+    /// | 2 | TWO |
+    /// | 3 | THREE |",
+        vec![],
+    );
+}
+
+#[test]
+fn quotes_dbc_excerpts() {
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r#"/// ```text
+/// BO_ 512 BrakeStatus: 8 Brake
+///  SG_ BrakePressure : 0|16@1+ (0.1,0) [0|6553.5] "bar" Gateway"#,
+        vec!["--dbc-excerpts"],
+    );
+}
+
+#[test]
+fn generates_error_codes() {
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r#"        pub fn from_uid(uid: &str) -> Self {
+            match uid {
+                "invalid-signal-value" => CanErrorCode::InvalidSignalValue,"#,
+        vec![],
+    );
+}
+
+#[test]
+fn embeds_dbc_checksum() {
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r#"/// SHA-256 of the DBC file this module was generated from
+pub const DBC_SHA256: &str = "32b73b8fcd470fe9190be82522d59b4755f5647fdfeee362e150299918bcf2a0";
+
+/// Check that the DBC file at `path` is the one this module was generated from
+pub fn verify_dbc<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<()> {"#,
+        vec![],
+    );
+}
+
+#[test]
+fn generates_message_snapshots() {
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r#"    pub struct SignalValues {
+        pub brake_pressure: f64,
+        pub brake_active: bool,"#,
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r#"        pub fn snapshot(&self) -> Result<MessageSnapshot, CanError> {
+            Ok(MessageSnapshot {
+                stamp: self.stamp,
+                status: self.status,
+                values: SignalValues {
+                    brake_pressure: match self.signals[0].try_borrow() {"#,
+        vec![],
+    );
+}
+
+#[test]
+fn generates_pool_snapshot_diff() {
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r#"impl<T: CanDbcPool + ?Sized> CanDbcPoolDiff for T {
+    fn snapshot(&self) -> Result<PoolSnapshot, CanError> {"#,
+        vec![],
+    );
+}
+
+#[test]
+fn serializes_messages_and_pool() {
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r#"    impl Serialize for DbcMessage {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::MessageState(self).serialize(serializer)
+        }
+    }"#,
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r#"impl serde::Serialize for CanMsgPool {"#,
+        vec![],
+    );
+}
+
+#[test]
+fn generates_sna_handling() {
+    // from the GenSigSNA attribute
+    codegen_test_snippet(
+        "tests/dbc/sna.dbc",
+        r#"        /// Raw value meaning "signal not available" (SNA)
+        pub const SNA_RAW: u16 = 65535;"#,
+        vec![],
+    );
+    // from the command line
+    codegen_test_snippet(
+        "tests/dbc/sna.dbc",
+        r#"        /// Raw value meaning "signal not available" (SNA)
+        pub const SNA_RAW: u8 = 254;"#,
+        vec!["--sna", "EngineData.CoolantTemp=0xFE"],
+    );
+    codegen_test_snippet(
+        "tests/dbc/sna.dbc",
+        r#"        /// Raw value meaning "signal not available" (SNA)
+        pub const SNA_RAW: u8 = 255;"#,
+        vec!["--sna", "Oil*"],
+    );
+}
+
+#[test]
+fn flags_values_outside_val_table() {
+    codegen_test_snippet(
+        "tests/dbc/val.dbc",
+        r#"        /// Raw values of the VAL_ table, others set the status to Error
+        pub const DOMAIN_RAW: [u8; 1] = [3];"#,
+        vec!["--check-values", "Main.LengthWithCode"],
+    );
+    codegen_test_snippet(
+        "tests/dbc/val.dbc",
+        r#"                    self.out_of_domain= !Self::DOMAIN_RAW.contains(&raw);
+                    if self.out_of_domain {
+                        // raw value missing from the VAL_ table: the DBC and the sender disagree
+                        self.status= CanDataStatus::Error;
+                    }"#,
+        vec!["--check-values", "MyCode"],
+    );
+    // unchecked signals keep the plain decoding
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/val.dbc")
+        .generate_string()
+        .unwrap();
+    assert!(!code.contains("out_of_domain"));
+}
+
+#[test]
+fn generates_j1939_ranges() {
+    codegen_test_snippet(
+        "tests/dbc/j1939.dbc",
+        r#"        /// J1939 raw range meaning "error indicator"
+        pub const J1939_ERROR: std::ops::RangeInclusive<u16> = 0xfe00..=0xfeff;
+        /// J1939 raw range meaning "not available"
+        pub const J1939_NOT_AVAILABLE: std::ops::RangeInclusive<u16> = 0xff00..=0xffff;"#,
+        vec!["--j1939"],
+    );
+    codegen_test_snippet(
+        "tests/dbc/j1939.dbc",
+        r"        pub const J1939_ERROR: std::ops::RangeInclusive<u8> = 0x2..=0x2;",
+        vec!["--j1939"],
+    );
+}
+
+#[test]
+#[cfg(unix)]
+fn generates_from_non_utf8_paths() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let tmp = assert_fs::TempDir::new().unwrap();
+    // "d\xe9p\xf4t" is latin-1, not valid UTF-8
+    let dir = tmp.path().join(OsStr::from_bytes(b"d\xe9p\xf4t"));
+    fs::create_dir(&dir).unwrap();
+    fs::copy("tests/dbc/sig_group.dbc", dir.join("in.dbc")).unwrap();
+
+    dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile(dir.join("in.dbc"))
+        .outfile(dir.join("gen.rs"))
+        .generate()
+        .unwrap();
+    assert!(fs::read_to_string(dir.join("gen.rs")).unwrap().contains("mod DbcSimple {"));
+}
+
+#[test]
+fn pins_generation_time_with_clock() {
+    use dbcparser::clock::FixedClock;
+
+    // 2024-03-15 12:00:00 UTC, the same day in every usual timezone
+    let generate = || {
+        dbcparser::gencode::DbcParser::new("DbcSimple")
+            .dbcfile("tests/dbc/sig_group.dbc")
+            .header("// generated on {date}\n")
+            .clock(Box::new(FixedClock(1_710_504_000_000_000)))
+            .generate_string()
+            .unwrap()
+    };
+    let code = generate();
+    assert!(code.starts_with("// generated on 2024-03-15\n"));
+    assert_eq!(code, generate());
+}
+
+#[test]
+fn formats_time_without_libc() {
+    use dbcparser::clock::FixedClock;
+    use dbcparser::gencode::format_time;
+
+    let noon = FixedClock(1_710_504_000_000_000);
+    assert_eq!(format_time(&noon, "%Y-%m-%d").unwrap(), "2024-03-15");
+    assert!(format_time(&noon, "%c").unwrap().contains("Mar 15"));
+    assert!(format_time(&noon, "%Q").is_err());
+}
+
+#[test]
+fn generates_without_sockcan() {
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/sig_group.dbc")
+        .sockcan(false)
+        .generate_string()
+        .unwrap();
+    assert!(!code.contains("use sockcan::prelude::*;"));
+    assert!(code.contains("use self::sockcan::prelude::*;"));
+    assert!(code.contains("pub mod sockcan {\npub mod prelude {"));
+    assert!(code.contains("    pub struct CanMsgData {"));
+    // message modules reach the local prelude of the uid module
+    assert!(code.contains(
+        "pub mod BrakeStatus { /// Message name space\n    use super::sockcan::prelude::*;"
+    ));
+
+    codegen_test_snippet("tests/dbc/val.dbc", "pub trait CanDbcPool {", vec!["--no-sockcan"]);
+}
+
+#[test]
+fn overrides_range_check_and_serde_per_signal() {
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/sig_group.dbc")
+        .range_check(false)
+        .serde_json(false)
+        .overrides(vec![dbcparser::gencode::SignalOverride {
+            pattern: "BrakeStatus.BrakePressure".to_owned(),
+            range_check: Some(true),
+            serde_json: Some(true),
+        }])
+        .generate_string()
+        .unwrap();
+    assert_eq!(code.matches("// Range-check clamped").count(), 1);
+    assert!(code.contains("not in [0..6553.5]"));
+    assert_eq!(code.matches("    #[derive(Serialize, Deserialize)]\n    pub struct").count(), 1);
+    // the message module imports serde for its only serialized signal
+    assert!(code.contains("    use serde::{Deserialize, Serialize};"));
+    assert!(!code.contains("pub struct MessageState"));
+}
+
+#[test]
+fn generates_signal_wildcard_subscription() {
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r"    pub fn subscribe_signals<F>(&self, pattern: &str, callback: F) -> Result<usize, CanError>
+    where
+        F: Fn(&dyn CanDbcSignal) -> i32 + 'static,
+    {",
+        vec![],
+    );
+    // callbacks do not depend on a BCM socket
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        "impl CanSigCtrl for SignalCallback {",
+        vec!["--no-sockcan"],
+    );
+}
+
+#[test]
+fn generates_pool_id_filters() {
+    codegen_test_snippet(
+        "tests/dbc/j1939.dbc",
+        r"        // ids outside the pool may reach a message through a subscribe_ids() filter
+        let canid = self.resolve(data.canid).unwrap_or(data.canid);",
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/j1939.dbc",
+        "    pub fn subscribe_ids(&self, filter: CanIdFilter) -> Result<usize, CanError> {",
+        vec!["--no-sockcan"],
+    );
+}
+
+#[test]
+fn generates_pool_subscription() {
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r#"            SockBcmCmd::new(CanBcmOpCode::RxSetup, flags, *canid)
+                .set_timers(opts.rate, opts.watchdog)
+                .apply(sock)?;"#,
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r"    pub fn set_timers(&self, sock: &SockCanHandle, opts: SubscribeOpts) -> Result<(), CanError> {
+        let flags = CanBcmFlag::RX_FILTER_ID | CanBcmFlag::SET_TIMER | CanBcmFlag::START_TIMER;
+        self.rx_setup(sock, &opts, flags)
+    }",
+        vec![],
+    );
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/sig_group.dbc")
+        .sockcan(false)
+        .generate_string()
+        .unwrap();
+    assert!(!code.contains("pub struct SubscribeOpts"));
+    assert!(!code.contains("pub fn set_timers("));
+}
+
+#[test]
+fn generates_message_doc_examples() {
+    codegen_test_snippet(
+        "tests/dbc/wide.dbc",
+        r"/// // Vin: byte array, written with Identity::Vin::set_bytes()
+/// // Serial: byte array, written with Identity::Serial::set_bytes()
+/// signals[2].borrow_mut().set_value(CanDbcType::U8(191), &mut data)?; // Revision",
+        vec!["--emit-tests"],
+    );
+    codegen_test_snippet(
+        "tests/dbc/wide.dbc",
+        r"/// let revision: u8 = signals[2].borrow().get_value().cast()?;
+/// assert_eq!(revision, 191);",
+        vec!["--emit-tests"],
+    );
+    // only the first page is in the frame, the multiplexor selects it
+    codegen_test_snippet(
+        "tests/dbc/mux.dbc",
+        r"/// signals[0].borrow_mut().set_value(CanDbcType::I8(1), &mut data)?; // PtMux
+/// signals[1].borrow_mut().set_value(CanDbcType::U16(49151), &mut data)?; // EngineRpm
+/// // MotorTorque: page 2, not in this frame
+/// // InverterTemp: page 3, not in this frame",
+        vec!["--emit-tests"],
+    );
+    codegen_test_snippet(
+        "tests/dbc/mux.dbc",
+        r"/// let pt_mux: i8 = signals[0].borrow().get_value().cast()?;
+/// assert_eq!(pt_mux, 1);
+/// let engine_rpm: u16 = signals[1].borrow().get_value().cast()?;
+/// assert_eq!(engine_rpm, 49151);
+/// # Ok(())",
+        vec!["--emit-tests"],
+    );
+    codegen_test_snippet(
+        "tests/dbc/wide.dbc",
+        "/// let patch: f64 = signals[2].borrow().get_value().cast()?;",
+        vec!["--emit-tests"],
+    );
+    codegen_test_snippet(
+        "tests/dbc/heartbeat.dbc",
+        r"/// use DbcSimple::sockcan::prelude::*;
+/// use DbcSimple::Heartbeat;
+///
+/// let msg = Heartbeat::DbcMessage::new();
+/// msg.borrow_mut().update(&CanMsgData {",
+        vec!["--emit-tests", "--no-sockcan"],
+    );
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/heartbeat.dbc")
+        .emit_tests("/build/out/dbc.rs")
+        .generate_string()
+        .unwrap();
+    assert!(code.contains("/// # include!(\"/build/out/dbc.rs\");"));
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/heartbeat.dbc")
+        .emit_tests("src/dbc.rs")
+        .generate_string()
+        .unwrap();
+    assert!(code.contains(r#"/// # include!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/dbc.rs"));"#));
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/heartbeat.dbc")
+        .generate_string()
+        .unwrap();
+    assert!(!code.contains("/// # Examples"));
+}
+
+#[test]
+fn generates_signal_listeners() {
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r"                Ok(mut signal) => { self.signal_listeners[3] = signal.update(frame); self.listeners += self.signal_listeners[3]; },",
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r"        pub fn get_signal_listener(&self, signal: DbcSignal) -> i32 {
+            self.signal_listeners[signal as usize]
+        }",
+        vec![],
+    );
+}
+
+#[test]
+fn generates_flow_callbacks() {
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r"    pub fn subscribe_signals_flow<F>(&self, pattern: &str, callback: F) -> Result<usize, CanError>
+    where
+        F: Fn(&dyn CanDbcSignal) -> CallbackFlow + 'static,",
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r"        self.stamp.set(data.stamp);
+        msg.update(data)?;",
+        vec!["--no-sockcan"],
+    );
+}
+
+#[test]
+fn generates_canfd_frames() {
+    codegen_test_snippet(
+        "tests/dbc/wide.dbc",
+        r"        /// Payload bytes of the message
+        pub const SIZE: usize = 40;
+        /// DLC of the CAN FD frame carrying the message, see `super::canfd_len()`
+        pub const DLC: u8 = 14;",
+        vec!["--canfd"],
+    );
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/wide.dbc")
+        .canfd(true)
+        .generate_string()
+        .unwrap();
+    assert!(code.contains("pub fn canfd_len(dlc: u8) -> usize {"));
+    assert!(code.contains("            if frame.len() < Self::SIZE {"));
+    assert!(code.contains("(len < Self::SIZE || !super::CANFD_LENGTHS.contains(&len))"));
+
+    let classic = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/wide.dbc")
+        .generate_string()
+        .unwrap();
+    assert!(!classic.contains("CANFD_LENGTHS"));
+
+    let oversized = std::env::temp_dir().join("canforge_canfd_oversized.dbc");
+    std::fs::write(
+        &oversized,
+        "VERSION \"\"\n\nNS_ :\n\nBS_:\n\nBU_: ECU\n\nBO_ 1 Huge: 72 ECU\n SG_ Head : 0|8@1+ (1,0) [0|255] \"\" ECU\n",
+    )
+    .unwrap();
+    let err = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile(&oversized)
+        .canfd(true)
+        .generate_string()
+        .unwrap_err();
+    assert!(err.to_string().contains("exceeds the 64 bytes of CAN FD"), "{err}");
+}
+
+#[test]
+fn generates_unknown_id_policy() {
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r"        if self.get_ids().binary_search(&canid).is_err() {
+            self.unknown_frame(data);
+        }",
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r"    pub fn update_frame(&self, data: &CanMsgData) -> Result<Option<RefMut<'_, Box<dyn CanDbcMessage>>>, CanError> {",
+        vec!["--no-sockcan"],
+    );
+}
+
+#[test]
+fn generates_raw_handler() {
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r"    pub fn set_raw_handler<F>(&self, handler: F)
+    where
+        F: Fn(&CanMsgData) + 'static,
+    {
+        self.set_unknown_policy(UnknownIdPolicy::Forward(Rc::new(handler)));
+    }",
+        vec![],
+    );
+}
+
+#[test]
+fn generates_extended_id_accessors() {
+    codegen_test_snippet(
+        "tests/dbc/j1939.dbc",
+        r"        /// CAN id without the extended flag, 11 or 29 bits
+        pub const CAN_ID: u32 = 0xcf00400;",
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/j1939.dbc",
+        "/// - ID: 217056256 (0xcf00400), extended",
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/heartbeat.dbc",
+        r"        pub const EXTENDED: bool = false;",
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/j1939.dbc",
+        r"        let extended = canid | 0x80000000;
+        if canid > 0x7ff && self.get_ids().binary_search(&extended).is_ok() {",
+        vec![],
+    );
+}
+
+#[test]
+fn generates_no_std_code() {
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r"use self::sockcan::prelude::*;
+use core::cell::{Cell,RefCell,RefMut};
+use alloc::collections::BTreeMap;
+use alloc::rc::{Rc};",
+        vec!["--no-std"],
+    );
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/sig_group.dbc")
+        .no_std(true)
+        .generate_string()
+        .unwrap();
+    assert!(!code.contains("std::"), "std path left in no_std code");
+    assert!(!code.contains("println!"));
+    assert!(!code.contains("pub fn verify_dbc"));
+    assert!(code.contains("    impl core::error::Error for CanError {}"));
+
+    let err = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/sig_group.dbc")
+        .no_std(true)
+        .plugin_abi(true)
+        .generate_string()
+        .unwrap_err();
+    assert!(err.to_string().contains("not available with no_std"), "{err}");
+}
+
+#[test]
+fn generates_signal_structs() {
+    let spec =
+        "GpsFix@200=lat:GpsPosition.Latitude,lon:GpsPosition.Longitude,alt:GpsAltitude.Altitude";
+    codegen_test_snippet(
+        "tests/dbc/gps.dbc",
+        r"pub struct GpsFix {
+    pub lat: f64,
+    pub lon: f64,
+    pub alt: f64,
+    /// Message stamp of the newest member
+    pub stamp: u64,
+}",
+        vec!["--signal-struct", spec],
+    );
+    codegen_test_snippet(
+        "tests/dbc/gps.dbc",
+        r"        let Some((alt, alt_stamp)) = self.fresh_value(1, 0, GpsFix::MAX_AGE_MS)? else { return Ok(None) };
+        Ok(Some(GpsFix {
+            lat: lat.cast()?,
+            lon: lon.cast()?,
+            alt: alt.cast()?,
+            stamp: lat_stamp.max(lon_stamp).max(alt_stamp),
+        }))",
+        vec!["--signal-struct", spec],
+    );
+
+    let parsed = dbcparser::gencode::SignalStruct::parse(spec).unwrap();
+    assert_eq!(parsed.name, "GpsFix");
+    assert_eq!(parsed.max_age_ms, 200);
+    assert_eq!(parsed.fields[2], ("alt".to_owned(), "GpsAltitude.Altitude".to_owned()));
+    assert!(dbcparser::gencode::SignalStruct::parse("GpsFix=lat").is_err());
+
+    let generate = |spec: &str| {
+        dbcparser::gencode::DbcParser::new("DbcSimple")
+            .dbcfile("tests/dbc/gps.dbc")
+            .signal_structs(vec![dbcparser::gencode::SignalStruct::parse(spec).unwrap()])
+            .generate_string()
+    };
+    let err = generate("GpsFix=lat:GpsPosition.Heading").unwrap_err();
+    assert!(err.to_string().contains("matches 0 signals"), "{err}");
+    let err = generate("GpsFix=pos:GpsPosition.*").unwrap_err();
+    assert!(err.to_string().contains("matches 2 signals"), "{err}");
+    let err = generate("GpsFix=lat:Latitude,lat:Longitude").unwrap_err();
+    assert!(err.to_string().contains("defined twice"), "{err}");
+    let err = generate("GpsPosition=lat:Latitude").unwrap_err();
+    assert!(err.to_string().contains("name already used"), "{err}");
+}
+
+#[test]
+fn exposes_signal_unit_and_range() {
+    codegen_test_snippet(
+        "tests/dbc/gps.dbc",
+        r#"        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = "deg";
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = -90.0;
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = 90.0;"#,
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/gps.dbc",
+        r"        fn get_unit(&self) -> &'static str {
+            Self::UNIT
+        }",
+        vec!["--no-sockcan"],
+    );
+    // sockcan's CanDbcSignal has no metadata accessors to implement, the lookup by name
+    // serves the default output
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/gps.dbc")
+        .generate_string()
+        .unwrap();
+    assert!(!code.contains("fn get_unit("));
+    assert!(code.contains(
+        r#"                "Latitude" => Some(Latitude::META),
+                "Longitude" => Some(Longitude::META),"#
+    ));
+    assert!(code.contains(
+        r"            801 => GpsPosition::DbcMessage::signal_meta(name),
+            802 => GpsAltitude::DbcMessage::signal_meta(name),"
+    ));
+}
+
+#[test]
+fn exposes_signal_scaling_and_layout() {
+    codegen_test_snippet(
+        "tests/dbc/gps.dbc",
+        r"        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = 1.0;
+        /// Scaling offset
+        pub const OFFSET: f64 = -500.0;
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = 16;
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = 0;",
+        vec![],
+    );
+    codegen_test_snippet("tests/dbc/gps.dbc", "        pub const FACTOR: f64 = 1e-7;\n", vec![]);
+    codegen_test_snippet(
+        "tests/dbc/gps.dbc",
+        r"        fn get_bit_length(&self) -> u32 {
+            Self::BIT_LENGTH
+        }
+
+        fn get_start_bit(&self) -> u32 {
+            Self::START_BIT
+        }",
+        vec!["--no-sockcan"],
+    );
+}
+
+#[test]
+fn generates_signal_lookup_by_name() {
+    codegen_test_snippet(
+        "tests/dbc/gps.dbc",
+        r#"        pub fn signal_index(name: &str) -> Option<usize> {
+            match name {
+                "Altitude" => Some(0),
+                "FixValid" => Some(1),
+                _ => None,
+            }
+        }"#,
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/gps.dbc",
+        r"        let (msg, sig) = match canid {
+            801 => (0, GpsPosition::DbcMessage::signal_index(name)?),
+            802 => (1, GpsAltitude::DbcMessage::signal_index(name)?),
+            _ => return None,
+        };",
+        vec![],
+    );
+    // generated names differing from the DBC ones match both
+    codegen_test_snippet(
+        "examples/canforge_dbc_complete_norm/dbc/canforge_dbc_complete_norm.dbc",
+        r#"                "VehicleSpeedKph" | "vehicle_speed_kph" => Some(2),"#,
+        vec![],
+    );
+}
+
+#[test]
+fn generates_message_lookup_by_name() {
+    codegen_test_snippet(
+        "tests/dbc/gps.dbc",
+        r#"        let idx = match name {
+            "GpsPosition" => 0,
+            "GpsAltitude" => 1,
+            _ => return Err(CanError::new("fail-canid-search", format!("message:{} not found", name))),
+        };"#,
+        vec![],
+    );
+    codegen_test_snippet(
+        "examples/canforge_dbc_complete_norm/dbc/canforge_dbc_complete_norm.dbc",
+        r#"            "DiagFdFrame" | "Diag_FD_Frame" => 4,"#,
+        vec!["--no-sockcan"],
+    );
+}
+
+#[test]
+fn generates_crate_root() {
+    let args = vec!["--crate-root", "--module-doc", "Gps bus of the demo car.\n\nSee gps.dbc."];
+    codegen_test_snippet(
+        "tests/dbc/gps.dbc",
+        r"// -------------------------------------------------------------
+//! Gps bus of the demo car.
+//!
+//! See gps.dbc.
+#![allow(non_upper_case_globals)]",
+        args.clone(),
+    );
+    codegen_test_snippet(
+        "tests/dbc/gps.dbc",
+        "#![allow(warnings, clippy::all, clippy::pedantic, clippy::nursery)]\nextern crate serde;",
+        args,
+    );
+    codegen_test_snippet(
+        "tests/dbc/gps.dbc",
+        "//! CAN messages and signals of gps.dbc, generated by dbcparser.\n#![no_std]",
+        vec!["--crate-root", "--no-std"],
+    );
+
+    let generate = |crate_root: bool| {
+        dbcparser::gencode::DbcParser::new("DbcSimple")
+            .dbcfile("tests/dbc/gps.dbc")
+            .crate_root(crate_root)
+            .plugin_abi(true)
+            .generate_string()
+            .unwrap()
+    };
+    let code = generate(true);
+    assert!(!code.contains("mod DbcSimple {"));
+    assert!(!code.contains("// end dbc generated parser"));
+    assert!(code.contains("use crate::CanMsgPool;"));
+    assert!(generate(false).contains("use super::DbcSimple::CanMsgPool;"));
+
+    let err = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/gps.dbc")
+        .crate_root(true)
+        .header(dbcparser::gencode::DEFAULT_HEADER)
+        .generate_string()
+        .unwrap_err();
+    assert!(err.to_string().contains("header attributes need the uid module"), "{err}");
+}
+
+#[test]
+fn formats_messages_for_logs() {
+    codegen_test_snippet(
+        "tests/dbc/wide.dbc",
+        r#"    impl fmt::Display for DbcMessage {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(fmt, "Identity({:#x}) {:?} stamp:{}", self.id, self.status, self.stamp)?;
+            match self.signals[0].try_borrow_mut().ok().and_then(|mut signal| signal.as_any().downcast_ref::<Vin>().map(Vin::to_hex)) {
+                Some(value) => write!(fmt, " Vin:{value}")?,
+                None => write!(fmt, " Vin:?")?,
+            }"#,
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/wide.dbc",
+        r#"                .field("stamp", &self.stamp)
+                .field("version", &self.signals[0].try_borrow().ok().and_then(|signal| signal.get_value().cast::<u64>().ok()))"#,
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/heartbeat.dbc",
+        r#"            write!(fmt, "Heartbeat({:#x}) {:?} stamp:{}", self.id, self.status, self.stamp)?;
+            Ok(())"#,
+        vec![],
+    );
+}
+
+#[test]
+fn gates_serde_behind_feature() {
+    let args = vec!["--serde-feature", "serde"];
+    codegen_test_snippet(
+        "tests/dbc/val.dbc",
+        "#[cfg(feature = \"serde\")]\nextern crate serde;",
+        args.clone(),
+    );
+    codegen_test_snippet(
+        "tests/dbc/val.dbc",
+        r#"    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct MyCode {
+        #[cfg_attr(feature = "serde", serde(skip))]"#,
+        args.clone(),
+    );
+    codegen_test_snippet(
+        "tests/dbc/val.dbc",
+        r#"        #[cfg(feature = "serde")]
+        fn to_json(&self) -> String {"#,
+        args.clone(),
+    );
+    codegen_test_snippet(
+        "tests/dbc/val.dbc",
+        r#"    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct SignalValues {"#,
+        args,
+    );
+
+    let err = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/val.dbc")
+        .serde_feature("serde\")]")
+        .generate_string()
+        .unwrap_err();
+    assert!(err.to_string().contains("not a cargo feature name"), "{err}");
+}
+
+#[test]
+fn generates_message_builder() {
+    codegen_test_snippet(
+        "tests/dbc/attributes.dbc",
+        r"    pub fn builder() -> MessageBuilder {
+        MessageBuilder {
+            values: SignalValues {
+                my_code: MyCode::START_VALUE,
+                temperature: Temperature::START_VALUE,
+            },
+        }
+    }",
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/attributes.dbc",
+        r"        /// Temperature physical value in degC
+        #[must_use]
+        pub fn temperature(mut self, value: f64) -> Self {
+            self.values.temperature = value;
+            self
+        }",
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/gps.dbc",
+        r"        MessageBuilder {
+            values: SignalValues::default(),
+        }",
+        vec![],
+    );
+}
+
+#[test]
+fn generates_message_structs() {
+    let spec = "GpsPosition=crate::model::Position(latitude)";
+    codegen_test_snippet(
+        "tests/dbc/gps.dbc",
+        r"    impl From<&DbcMessage> for crate::model::Position {
+        fn from(msg: &DbcMessage) -> Self {
+            crate::model::Position {
+                latitude: msg.signals[0].try_borrow().ok().and_then(|signal| signal.get_value().cast().ok()).unwrap_or_default(),
+            }
+        }
+    }",
+        vec!["--message-struct", spec],
+    );
+    codegen_test_snippet(
+        "tests/dbc/gps.dbc",
+        r"    impl From<&crate::model::Position> for SignalValues {
+        fn from(value: &crate::model::Position) -> Self {
+            SignalValues {
+                latitude: value.latitude,
+                ..Default::default()
+            }
+        }
+    }",
+        vec!["--message-struct", spec],
+    );
+
+    let parsed = dbcparser::gencode::MessageStruct::parse(spec).unwrap();
+    assert_eq!(parsed.message, "GpsPosition");
+    assert_eq!(parsed.path, "crate::model::Position");
+    assert_eq!(parsed.fields, vec!["latitude".to_owned()]);
+    assert!(dbcparser::gencode::MessageStruct::parse("GpsPosition=Position(").is_err());
+
+    let generate = |spec: &str| {
+        dbcparser::gencode::DbcParser::new("DbcSimple")
+            .dbcfile("tests/dbc/gps.dbc")
+            .message_structs(vec![dbcparser::gencode::MessageStruct::parse(spec).unwrap()])
+            .generate_string()
+    };
+    let err = generate("Engine=crate::Engine").unwrap_err();
+    assert!(err.to_string().contains("message:Engine not in the database"), "{err}");
+    let err = generate("GpsPosition=crate::Position(heading)").unwrap_err();
+    assert!(err.to_string().contains("field:heading not a signal of GpsPosition"), "{err}");
+    let err = generate("GpsPosition=crate::Position(Latitude,latitude)").unwrap_err();
+    assert!(err.to_string().contains("defined twice"), "{err}");
+    let err = generate("GpsPosition=crate::model-Position").unwrap_err();
+    assert!(err.to_string().contains("not a type path"), "{err}");
+}
+
+#[test]
+fn generates_static_pool() {
+    codegen_test_snippet(
+        "tests/dbc/j1939.dbc",
+        r"        match data.canid {
+            0x8cf00400 | 0xcf00400 => {
+                self.eec1.update(data)?;
+                Ok(Some(StaticMessageMut::Eec1(&mut self.eec1)))
+            },
+            _ => Ok(None),
+        }",
+        vec!["--static-pool"],
+    );
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/sig_group.dbc")
+        .static_pool(true)
+        .generate_string()
+        .unwrap();
+    assert!(code.contains("                    brake_pressure: BrakePressure::init(),"));
+    assert!(code.contains("                let signal = &mut self.signals.brake_pressure;"));
+
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/sig_group.dbc")
+        .generate_string()
+        .unwrap();
+    assert!(!code.contains("StaticPool"));
+}
+
+#[test]
+fn generates_stateless_codec() {
+    codegen_test_snippet(
+        "tests/dbc/sig_group.dbc",
+        r"    pub fn encode(values: &SignalValues, data: &mut [u8]) -> Result<(), CanError> {
+        if data.len() < 8 {
+            return Err(CanError::new(",
+        vec![],
+    );
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/sig_group.dbc")
+        .generate_string()
+        .unwrap();
+    assert!(code.contains("            brake_pressure: BrakePressure::decode(data),"));
+    assert!(code.contains("        BrakePressure::encode(values.brake_pressure, data)?;"));
+    assert!(code.contains(
+        "            Self::physical_from_raw(data.view_bits::<Lsb0>()[0..16].load_le::<u16>())"
+    ));
+}
+
+#[test]
+fn decodes_ieee754_float_signals() {
+    codegen_test_snippet(
+        "tests/dbc/float.dbc",
+        r"        pub fn physical_from_raw(raw: u32) -> f64 {
+            f64::from(f32::from_bits(raw)) * 0.5_f64 + 10_f64
+        }",
+        vec![],
+    );
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/float.dbc")
+        .generate_string()
+        .unwrap();
+    assert!(code.contains("        pub fn set_physical(&mut self, value:f64, data:&mut [u8]) -> Result<(),CanError> {"));
+    assert!(code.contains("            f64::from_bits(raw)\n"));
+    assert!(code.contains("            (__raw_f as f32).to_bits()\n"));
+    assert!(code.contains("            value.to_bits()\n"));
+    assert!(code.contains("        pub yaw_rate: f64,"));
+
+    let mismatch = std::env::temp_dir().join("canforge_float_mismatch.dbc");
+    std::fs::write(
+        &mismatch,
+        std::fs::read_to_string("tests/dbc/float.dbc")
+            .unwrap()
+            .replace("SIG_VALTYPE_ 513 Latitude : 2;", "SIG_VALTYPE_ 513 Latitude : 1;"),
+    )
+    .unwrap();
+    let err = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile(&mismatch)
+        .generate_string()
+        .unwrap_err();
+    assert!(err.to_string().contains("float signal:Latitude needs 32 bits"), "{err}");
+}
+
+#[test]
+fn generates_reference_tests_from_comments() {
+    codegen_test_snippet(
+        "tests/dbc/reference.dbc",
+        r"    #[cfg(test)]
+    mod reference_tests {
+        use super::*;
+
+        /// `@test` reference values of the EngineSpeed comment
+        #[test]
+        fn engine_speed() {
+            let value = EngineSpeed::physical_from_raw(0x1f40);",
+        vec![],
+    );
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/reference.dbc")
+        .generate_string()
+        .unwrap();
+    assert!(code.contains("            assert_eq!(Torque::raw_from_physical(-5), 0xffb);"));
+    assert!(code.contains("            assert!(Running::physical_from_raw(0x1));"));
+    assert!(code.contains("Load::raw_from_physical(91.0_f64).abs_diff(0x42b60000) <= 1"));
+    // the @testbench mention is no annotation, Gear has none
+    assert_eq!(code.matches("CoolantTemp::physical_from_raw(").count(), 1);
+    assert!(!code.contains("fn gear()"));
+
+    let invalid = std::env::temp_dir().join("canforge_reference_invalid.dbc");
+    std::fs::write(
+        &invalid,
+        std::fs::read_to_string("tests/dbc/reference.dbc")
+            .unwrap()
+            .replace("raw=0xFFB phys=-5 ", "raw=0xFFB phys=-5.5 "),
+    )
+    .unwrap();
+    let err = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile(&invalid)
+        .generate_string()
+        .unwrap_err();
+    assert!(err.to_string().contains("signal:Torque @test raw=0xFFB phys=-5.5"), "{err}");
+}
+
+#[test]
+fn golden_helpers_ignore_stamps_only() {
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/sig_group.dbc")
+        .generate_string()
+        .unwrap();
+    // checked-in reference generated from elsewhere, with CRLF line endings
+    let checked_in = code
+        .replace("// - code generated from ", "// - code generated from /elsewhere/model.dbc ")
+        .replace('\n', "\r\n");
+    let reference = std::env::temp_dir().join("canforge_golden_reference.rs");
+    fs::write(&reference, &checked_in).unwrap();
+    dbcparser::golden::assert_golden(&reference, &code);
+    assert_eq!(dbcparser::golden::compare(&checked_in, &code), None);
+    assert_eq!(
+        dbcparser::golden::read_filtered(&reference).unwrap(),
+        dbcparser::golden::filter_content(&code)
+    );
+
+    let mutated = code.replacen("load_le::<u16>()", "load_le::<u8>()", 1);
+    assert_ne!(mutated, code);
+    let summary = dbcparser::golden::compare(&checked_in, &mutated).unwrap();
+    assert!(summary.contains("+ ") && summary.contains("load_le::<u8>()"), "{summary}");
+    let drift = std::panic::catch_unwind(|| dbcparser::golden::assert_golden(&reference, &mutated));
+    assert!(drift.is_err());
+}
+
+#[test]
+fn filters_messages_by_node() {
+    let generate = |nodes: &[&str]| {
+        let mut parser = dbcparser::gencode::DbcParser::new("DbcSimple");
+        parser.dbcfile("tests/dbc/nodes.dbc");
+        for node in nodes {
+            parser.node(node);
+        }
+        parser.generate_string()
+    };
+    let modules = |code: &str| {
+        ["EngineData", "BrakeData", "DashInfo", "GatewayForward"]
+            .into_iter()
+            .filter(|msg| code.contains(&format!("pub mod {msg} {{")))
+            .collect::<Vec<_>>()
+    };
+
+    // transmitted EngineData, received BrakePressure
+    assert_eq!(modules(&generate(&["Engine"]).unwrap()), vec!["EngineData", "BrakeData"]);
+    // BO_TX_BU_ transmitters count
+    assert_eq!(modules(&generate(&["Brake"]).unwrap()), vec!["BrakeData", "GatewayForward"]);
+    assert_eq!(
+        modules(&generate(&["Dash", "Brake"]).unwrap()),
+        vec!["EngineData", "BrakeData", "DashInfo", "GatewayForward"]
+    );
+    let err = generate(&["Gateway"]).unwrap_err();
+    assert!(err.to_string().contains("node:Gateway is not declared in BU_"), "{err}");
+
+    codegen_test_snippet(
+        "tests/dbc/nodes.dbc",
+        r"        pub const TRANSMITS: [u32; 1] = [256];",
+        vec!["--node", "Engine"],
+    );
+}
+
+#[test]
+fn exposes_message_cycle_time() {
+    codegen_test_snippet(
+        "tests/dbc/attributes.dbc",
+        r"        /// Transmission period from the GenMsgCycleTime attribute (or its default), 0 for a
+        /// message sent on event or without attribute
+        pub const CYCLE_TIME_MS: u32 = 50;
+
+        /// `CYCLE_TIME_MS`, base of the BCM rate and watchdog timers of the message
+        pub fn get_cycle_time_ms(&self) -> u32 {
+            Self::CYCLE_TIME_MS
+        }",
+        vec![],
+    );
+    // presence-only messages too, 0 without attribute
+    codegen_test_snippet(
+        "tests/dbc/heartbeat.dbc",
+        r"        pub const CYCLE_TIME_MS: u32 = 0;",
+        vec![],
+    );
+
+    let dbc = std::env::temp_dir().join("canforge_cycle_time.dbc");
+    let generate = |value: &str| {
+        std::fs::write(
+            &dbc,
+            format!(
+                "VERSION \"\"\n\nNS_ :\n\nBS_:\n\nBU_: ECU\n\n\
+                 BO_ 1 Fast: 1 ECU\n SG_ A : 0|8@1+ (1,0) [0|255] \"\" ECU\n\n\
+                 BO_ 2 Slow: 1 ECU\n SG_ B : 0|8@1+ (1,0) [0|255] \"\" ECU\n\n\
+                 BA_DEF_ BO_  \"GenMsgCycleTime\" INT 0 10000;\n\
+                 BA_DEF_DEF_  \"GenMsgCycleTime\" 100;\n\
+                 BA_ \"GenMsgCycleTime\" BO_ 1 {value};\n"
+            ),
+        )
+        .unwrap();
+        dbcparser::gencode::DbcParser::new("DbcSimple").dbcfile(&dbc).generate_string()
+    };
+    let code = generate("10").unwrap();
+    let periods: Vec<&str> =
+        code.lines().filter(|line| line.contains("pub const CYCLE_TIME_MS")).collect();
+    // Slow falls back to the BA_DEF_DEF_ default
+    assert_eq!(
+        periods,
+        vec![
+            "        pub const CYCLE_TIME_MS: u32 = 10;",
+            "        pub const CYCLE_TIME_MS: u32 = 100;"
+        ]
+    );
+    let err = generate("-5").unwrap_err();
+    assert!(err.to_string().contains("message:Fast invalid GenMsgCycleTime value:-5"), "{err}");
+}
+
+#[test]
+fn initializes_signals_to_start_value() {
+    codegen_test_snippet(
+        "tests/dbc/attributes.dbc",
+        r"        /// Value read before any frame and after `reset()`, from the GenSigStartValue attribute (raw 40)
+        pub const START_VALUE: f64 = 20.0;",
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/attributes.dbc",
+        r"        fn get_typed_value(&self) -> f64 {
+            self.value.unwrap_or(Self::START_VALUE)
+        }",
+        vec![],
+    );
+    // MyCode falls back to the BA_DEF_DEF_ default
+    codegen_test_snippet(
+        "tests/dbc/attributes.dbc",
+        r"        pub const START_VALUE: u8 = 0;",
+        vec![],
+    );
+
+    let dbc = std::env::temp_dir().join("canforge_start_value.dbc");
+    let generate = |value: &str| {
+        std::fs::write(
+            &dbc,
+            format!(
+                "VERSION \"\"\n\nNS_ :\n\nBS_:\n\nBU_: ECU\n\n\
+                 BO_ 1 Status: 1 ECU\n SG_ Level : 0|4@1- (1,0) [-8|7] \"\" ECU\n\n\
+                 BA_DEF_ SG_  \"GenSigStartValue\" INT -100 100;\n\
+                 BA_ \"GenSigStartValue\" SG_ 1 Level {value};\n"
+            ),
+        )
+        .unwrap();
+        dbcparser::gencode::DbcParser::new("DbcSimple").dbcfile(&dbc).generate_string()
+    };
+    assert!(generate("-3").unwrap().contains("        pub const START_VALUE: i8 = -3;"));
+    let err = generate("8").unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("signal:Status.Level GenSigStartValue raw value:8 does not fit 4 bits"),
+        "{err}"
+    );
+    // without attribute, no constant
+    let code = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile("tests/dbc/val.dbc")
+        .generate_string()
+        .unwrap();
+    assert!(!code.contains("START_VALUE"));
+}
+
+#[test]
+fn exposes_j1939_pgn_and_spn() {
+    codegen_test_snippet(
+        "tests/dbc/j1939.dbc",
+        r"        /// J1939 parameter group number of the DBC id, PDU1 destination address cleared
+        pub const PGN: u32 = 0xf004;
+        /// J1939 priority of the DBC id, 0 (highest) to 7
+        pub const PRIORITY: u8 = 3;
+        /// J1939 source address of the DBC id
+        pub const SOURCE_ADDRESS: u8 = 0x0;
+
+        pub fn get_pgn(&self) -> u32 {
+            Self::PGN
+        }",
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/j1939.dbc",
+        r"        /// J1939 suspect parameter number, from the SPN attribute
+        pub const SPN: u32 = 190;
+
+        pub fn get_spn(&self) -> u32 {
+            Self::SPN
+        }",
+        vec![],
+    );
+
+    // PDU1 request to 0x00 from 0xF9, no VFrameFormat: J1939 with --j1939 only
+    let dbc = std::env::temp_dir().join("canforge_j1939_pdu1.dbc");
+    std::fs::write(
+        &dbc,
+        "VERSION \"\"\n\nNS_ :\n\nBS_:\n\nBU_: Tool\n\n\
+         BO_ 2565472505 Request: 3 Tool\n SG_ RequestedPgn : 0|24@1+ (1,0) [0|262143] \"\" Tool\n",
+    )
+    .unwrap();
+    let generate = |j1939: bool| {
+        dbcparser::gencode::DbcParser::new("DbcSimple")
+            .dbcfile(&dbc)
+            .j1939(j1939)
+            .generate_string()
+            .unwrap()
+    };
+    let code = generate(true);
+    assert!(code.contains("        pub const PGN: u32 = 0xea00;"));
+    assert!(code.contains("        pub const PRIORITY: u8 = 6;"));
+    assert!(code.contains("        pub const SOURCE_ADDRESS: u8 = 0xf9;"));
+    assert!(!generate(false).contains("pub const PGN"));
+}
+
+#[test]
+fn generates_mux_groups() {
+    codegen_test_snippet(
+        "tests/dbc/mux.dbc",
+        r#"    pub enum MuxGroup {
+        Engine,
+        Motor,
+        M3,
+    }"#,
+        vec![],
+    );
+    codegen_test_snippet(
+        "tests/dbc/mux.dbc",
+        r#"                2 => Some(MuxGroup::Motor),
+                3 => Some(MuxGroup::M3),
+                _ => None,"#,
+        vec![],
+    );
+    // signed multiplexor, sign extended like the raw selector of update()
+    codegen_test_snippet(
+        "tests/dbc/mux.dbc",
+        "MuxGroup::from_selector((self.signals.pt_mux.get_value().cast::<i8>().ok()? as i64) as u64)",
+        vec!["--static-pool"],
+    );
+}
+
+#[test]
+fn generates_reproducible_output() {
+    let generate = |timestamp: bool| {
+        dbcparser::gencode::DbcParser::new("DbcSimple")
+            .dbcfile("tests/dbc/sig_group.dbc")
+            .timestamp(timestamp)
+            .generate_string()
+            .unwrap()
+    };
+    let code = generate(false);
+    assert!(code.contains("// - code generated from tests/dbc/sig_group.dbc\n"));
+    assert_eq!(code, generate(false));
+    assert!(!generate(true).contains("// - code generated from tests/dbc/sig_group.dbc\n"));
+}
+
+#[test]
+fn generates_set_signal_into() {
+    codegen_test_snippet(
+        "tests/dbc/mux.dbc",
+        r#"        pub fn set_signal_into(value:u16, data:&mut [u8]) -> Result<(),CanError> {
+            if data.len() < 8 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux payload len:{} shorter than 8 bytes", data.len())));
+            }
+            let __mux_raw_value = PtMux::raw_from_physical(PtMux::decode(data)) as u64;
+            if __mux_raw_value != 2 {
+                return Err(CanError::new("signal-set-values-fail", format!("PowertrainMux.MotorTorque is on page 2, frame multiplexor selects {__mux_raw_value}")));
+            }
+            Self::encode(value, data)
+        }"#,
+        vec![],
+    );
+}
+
+#[test]
+fn generates_enums_of_shared_value_tables() {
+    codegen_test_snippet(
+        "tests/dbc/value_tables.dbc",
+        r#"    pub enum DbcGear {
+        P,
+        R,
+        N,
+        D,
+"#,
+        vec![],
+    );
+    // table wrapped over two lines
+    codegen_test_snippet(
+        "tests/dbc/value_tables.dbc",
+        r#"    pub enum DbcSport {
+        Off,
+        On,
+"#,
+        vec![],
+    );
+
+    let dbc = std::env::temp_dir().join("canforge_unknown_value_table.dbc");
+    std::fs::write(
+        &dbc,
+        "VERSION \"\"\n\nNS_ :\n\nBS_:\n\nBU_: ECU\n\n\
+         BO_ 1 Door: 1 ECU\n SG_ Open : 0|1@1+ (1,0) [0|1] \"\" ECU\n\n\
+         VAL_ 1 Open vt_open ;\n",
+    )
+    .unwrap();
+    let err = dbcparser::gencode::DbcParser::new("DbcSimple")
+        .dbcfile(&dbc)
+        .generate_string()
+        .unwrap_err();
+    assert!(err.to_string().contains("VAL_ 1 Open: unknown value table:vt_open"), "{err}");
 }
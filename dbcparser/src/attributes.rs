@@ -0,0 +1,279 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! DBC attribute definitions (`BA_DEF_`), their defaults (`BA_DEF_DEF_`) and signal values
+//! (`BA_ ... SG_`).
+//!
+//! can-dbc keeps attribute definitions as raw text, this module gives them a type, a range or
+//! a list of choices, and a default, so the generator can emit a typed `attributes` module.
+
+/// Object an attribute applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeScope {
+    Network,
+    Node,
+    Message,
+    Signal,
+    EnvVar,
+}
+
+/// Attribute value type with its constraints.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttributeKind {
+    Int { min: i64, max: i64 },
+    Hex { min: i64, max: i64 },
+    Float { min: f64, max: f64 },
+    String,
+    Enum(Vec<String>),
+}
+
+/// Value of a `BA_DEF_DEF_` entry.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttributeDefault {
+    Int(i64),
+    Float(f64),
+    Str(String),
+}
+
+/// One `BA_DEF_` entry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DbcAttribute {
+    pub name: String,
+    pub scope: AttributeScope,
+    pub kind: AttributeKind,
+    pub default: Option<AttributeDefault>,
+}
+
+/// One `BA_ "name" SG_ <message id> <signal> <value>;` assignment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignalAttributeValue {
+    pub name: String,
+    pub message_id: u32,
+    pub signal: String,
+    pub value: String,
+}
+
+/// One `BA_ "name" BO_ <message id> <value>;` assignment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageAttributeValue {
+    pub name: String,
+    pub message_id: u32,
+    pub value: String,
+}
+
+impl DbcAttribute {
+    /// Typed `value` of a `BA_` assignment of this attribute, enum indexes given as their label.
+    /// None when `value` does not parse or is an enum index out of range.
+    #[must_use]
+    pub fn parse_value(&self, value: &str) -> Option<AttributeDefault> {
+        typed_value(&self.kind, value, false)
+    }
+
+    /// Allowed `[min, max]` for numeric attributes.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn range(&self) -> Option<(f64, f64)> {
+        match &self.kind {
+            AttributeKind::Int { min, max } | AttributeKind::Hex { min, max } => {
+                Some((*min as f64, *max as f64))
+            },
+            AttributeKind::Float { min, max } => Some((*min, *max)),
+            AttributeKind::String | AttributeKind::Enum(_) => None,
+        }
+    }
+}
+
+/// Split a statement into words, keeping quoted strings (without quotes) as one word.
+fn words(statement: &str) -> Vec<(String, bool)> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    for c in statement.chars() {
+        match c {
+            '"' if quoted => {
+                words.push((std::mem::take(&mut current), true));
+                quoted = false;
+            },
+            '"' => quoted = true,
+            c if quoted => current.push(c),
+            c if c.is_whitespace() || c == ',' => {
+                if !current.is_empty() {
+                    words.push((std::mem::take(&mut current), false));
+                }
+            },
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        words.push((current, false));
+    }
+    words
+}
+
+/// `BA_*` statements of `text`, joined over lines up to their `;` outside quotes.
+fn statements(text: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut current: Option<String> = None;
+    for line in text.lines() {
+        let line = line.trim();
+        let statement = match current.as_mut() {
+            Some(statement) => {
+                statement.push(' ');
+                statement
+            },
+            // skip the bare keywords listed in the NS_ section
+            None if line.starts_with("BA_") && line.contains(char::is_whitespace) => {
+                current.insert(String::new())
+            },
+            None => continue,
+        };
+        statement.push_str(line);
+        if statement.matches('"').count() % 2 == 0 && statement.ends_with(';') {
+            statement.pop();
+            statements.extend(current.take());
+        }
+    }
+    statements
+}
+
+pub(crate) fn parse_int(word: &str) -> Option<i64> {
+    match word.strip_prefix("0x").or_else(|| word.strip_prefix("0X")) {
+        Some(hex) => i64::from_str_radix(hex, 16).ok(),
+        // some tools write integer bounds as floats
+        None => word.parse().ok().or_else(|| word.parse::<f64>().ok().map(|v| v as i64)),
+    }
+}
+
+/// Value of `kind` written `value` in the DBC, None when it does not parse.
+fn typed_value(kind: &AttributeKind, value: &str, quoted: bool) -> Option<AttributeDefault> {
+    match kind {
+        AttributeKind::Int { .. } | AttributeKind::Hex { .. } => {
+            parse_int(value).map(AttributeDefault::Int)
+        },
+        AttributeKind::Float { .. } => value.parse().ok().map(AttributeDefault::Float),
+        AttributeKind::String => Some(AttributeDefault::Str(value.to_owned())),
+        // enum values are usually the label index, sometimes the label
+        AttributeKind::Enum(choices) => match (quoted, parse_int(value)) {
+            (false, Some(idx)) => usize::try_from(idx)
+                .ok()
+                .and_then(|idx| choices.get(idx))
+                .map(|label| AttributeDefault::Str(label.clone())),
+            _ => Some(AttributeDefault::Str(value.to_owned())),
+        },
+    }
+}
+
+/// Parse every `BA_DEF_`/`BA_DEF_DEF_` statement of DBC `text`, in declaration order.
+/// Relation attributes (`BA_DEF_REL_`) and malformed definitions are skipped.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn parse_attributes(text: &str) -> Vec<DbcAttribute> {
+    let mut attributes: Vec<DbcAttribute> = Vec::new();
+    for statement in statements(text) {
+        let words = words(&statement);
+        let Some((keyword, _)) = words.first() else { continue };
+        match keyword.as_str() {
+            "BA_DEF_" => {
+                let (scope, rest) = match words.get(1).map(|(w, q)| (w.as_str(), *q)) {
+                    Some(("BU_", false)) => (AttributeScope::Node, &words[2..]),
+                    Some(("BO_", false)) => (AttributeScope::Message, &words[2..]),
+                    Some(("SG_", false)) => (AttributeScope::Signal, &words[2..]),
+                    Some(("EV_", false)) => (AttributeScope::EnvVar, &words[2..]),
+                    _ => (AttributeScope::Network, &words[1..]),
+                };
+                let Some(((name, true), (kind, _))) = rest.first().zip(rest.get(1)) else {
+                    continue;
+                };
+                let bound = |idx: usize| rest.get(idx).and_then(|(w, _)| parse_int(w));
+                let fbound = |idx: usize| rest.get(idx).and_then(|(w, _)| w.parse::<f64>().ok());
+                let kind = match kind.as_str() {
+                    "INT" => AttributeKind::Int {
+                        min: bound(2).unwrap_or(0),
+                        max: bound(3).unwrap_or(0),
+                    },
+                    "HEX" => AttributeKind::Hex {
+                        min: bound(2).unwrap_or(0),
+                        max: bound(3).unwrap_or(0),
+                    },
+                    "FLOAT" => AttributeKind::Float {
+                        min: fbound(2).unwrap_or(0.0),
+                        max: fbound(3).unwrap_or(0.0),
+                    },
+                    "STRING" => AttributeKind::String,
+                    "ENUM" => {
+                        AttributeKind::Enum(rest[2..].iter().map(|(w, _)| w.clone()).collect())
+                    },
+                    _ => continue,
+                };
+                attributes.retain(|attr| attr.name != *name);
+                attributes.push(DbcAttribute { name: name.clone(), scope, kind, default: None });
+            },
+            "BA_DEF_DEF_" => {
+                let Some(((name, _), (value, quoted))) = words.get(1).zip(words.get(2)) else {
+                    continue;
+                };
+                let Some(attr) = attributes.iter_mut().find(|attr| attr.name == *name) else {
+                    continue;
+                };
+                attr.default = typed_value(&attr.kind, value, *quoted);
+            },
+            _ => {},
+        }
+    }
+    attributes
+}
+
+/// Parse every signal attribute assignment (`BA_ "name" SG_ id signal value;`) of DBC `text`.
+#[must_use]
+pub fn parse_signal_attribute_values(text: &str) -> Vec<SignalAttributeValue> {
+    statements(text)
+        .iter()
+        .filter_map(|statement| match words(statement).as_slice() {
+            [(keyword, false), (name, true), (scope, false), (id, false), (signal, false), (value, _)]
+                if keyword == "BA_" && scope == "SG_" =>
+            {
+                Some(SignalAttributeValue {
+                    name: name.clone(),
+                    message_id: id.parse().ok()?,
+                    signal: signal.clone(),
+                    value: value.clone(),
+                })
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Parse every message attribute assignment (`BA_ "name" BO_ id value;`) of DBC `text`.
+#[must_use]
+pub fn parse_message_attribute_values(text: &str) -> Vec<MessageAttributeValue> {
+    statements(text)
+        .iter()
+        .filter_map(|statement| match words(statement).as_slice() {
+            [(keyword, false), (name, true), (scope, false), (id, false), (value, _)]
+                if keyword == "BA_" && scope == "BO_" =>
+            {
+                Some(MessageAttributeValue {
+                    name: name.clone(),
+                    message_id: id.parse().ok()?,
+                    value: value.clone(),
+                })
+            },
+            _ => None,
+        })
+        .collect()
+}
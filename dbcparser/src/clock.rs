@@ -0,0 +1,51 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Time source of the generator banner and of the runtime periodic checks.
+//!
+//! Everything reading the current time goes through a [`Clock`], [`SystemClock`] by default,
+//! so tests and replays can pin it with a [`FixedClock`].
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Source of the current time.
+pub trait Clock {
+    /// Microseconds since the Unix epoch, the unit of `CanMsgData::stamp`.
+    fn now_us(&self) -> u64;
+}
+
+/// Wall clock time. wasm32-unknown-unknown has no clock, use a [`FixedClock`] there.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_us(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX))
+    }
+}
+
+/// Clock stuck at a given time, in microseconds since the Unix epoch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FixedClock(pub u64);
+
+impl Clock for FixedClock {
+    fn now_us(&self) -> u64 {
+        self.0
+    }
+}
@@ -0,0 +1,384 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Compatibility of two revisions of a database, as a semantic version bump.
+//!
+//! [`DbcDiff`] lists the changes between an old and a new DBC, each rated by what it breaks
+//! in the generated crate and on the bus:
+//!
+//! - major: a message or signal removed or renamed, a message id or size changed, a signal
+//!   moved, resized, rescaled, retyped, remultiplexed or given another unit, a range narrowed,
+//!   a `VAL_` value removed or relabeled, a node removed,
+//! - minor: a message, signal, node or `VAL_` value added, a range widened, transmitters,
+//!   receivers or attribute values (cycle time, start value, ...) changed,
+//! - patch: comments changed.
+//!
+//! The highest rating is the bump, [`next_version`] applies it to the crate version following
+//! cargo rules: below 1.0.0, major changes bump the minor number and the others the patch one.
+
+use crate::attributes::{parse_message_attribute_values, parse_signal_attribute_values};
+use crate::gencode::resolve_value_tables;
+use can_dbc::{Comment, Dbc, Message, Signal, Transmitter};
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, Error};
+use std::path::Path;
+
+/// Semantic version bump, ordered from none to major.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Bump {
+    None,
+    Patch,
+    Minor,
+    Major,
+}
+
+impl fmt::Display for Bump {
+    fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format.write_str(match self {
+            Bump::None => "none",
+            Bump::Patch => "patch",
+            Bump::Minor => "minor",
+            Bump::Major => "major",
+        })
+    }
+}
+
+/// One change between two revisions, described with DBC names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DbcChange {
+    pub bump: Bump,
+    pub what: String,
+}
+
+/// Changes from an old to a new revision of a database, see the module documentation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DbcDiff {
+    pub changes: Vec<DbcChange>,
+}
+
+fn parse(text: &str) -> io::Result<Dbc> {
+    let text = resolve_value_tables(text)?;
+    Dbc::try_from(text.as_str()).map_err(|error| Error::other(error.to_string()))
+}
+
+/// DBC text of `path`, converted from CANopen EDS/DCF (for `node_id`) or LIN LDF files.
+fn read_dbc(path: &Path, node_id: u8) -> io::Result<String> {
+    let text = fs::read_to_string(path)?;
+    if crate::eds::is_eds_file(path) {
+        crate::eds::eds_to_dbc(&text, node_id)
+    } else if crate::ldf::is_ldf_file(path) {
+        crate::ldf::ldf_to_dbc(&text)
+    } else {
+        Ok(text)
+    }
+}
+
+fn transmitter(msg: &Message) -> &str {
+    match &msg.transmitter {
+        Transmitter::NodeName(node) => node,
+        Transmitter::VectorXXX => "Vector__XXX",
+    }
+}
+
+/// Comments by subject, for messages and signals by name.
+fn comments(dbc: &Dbc) -> BTreeMap<String, &str> {
+    let name = |id: u32| {
+        dbc.messages
+            .iter()
+            .find(|msg| msg.id.raw() == id)
+            .map_or_else(|| format!("{id:#x}"), |msg| msg.name.clone())
+    };
+    dbc.comments
+        .iter()
+        .map(|comment| match comment {
+            Comment::Node { name, comment } => (format!("node {name}"), comment.as_str()),
+            Comment::Message { id, comment } => {
+                (format!("message {}", name(id.raw())), comment.as_str())
+            },
+            Comment::Signal { message_id, name: signal, comment } => {
+                (format!("signal {}.{signal}", name(message_id.raw())), comment.as_str())
+            },
+            Comment::Plain { comment } => ("database".to_owned(), comment.as_str()),
+        })
+        .collect()
+}
+
+/// Attribute values by `message[.signal] attribute` subject.
+fn attribute_values(dbc: &Dbc, text: &str) -> BTreeMap<String, String> {
+    let name = |id: u32| dbc.messages.iter().find(|msg| msg.id.raw() == id).map(|msg| &msg.name);
+    let messages = parse_message_attribute_values(text)
+        .into_iter()
+        .filter_map(|attr| Some((format!("{} {}", name(attr.message_id)?, attr.name), attr.value)));
+    let signals = parse_signal_attribute_values(text).into_iter().filter_map(|attr| {
+        Some((format!("{}.{} {}", name(attr.message_id)?, attr.signal, attr.name), attr.value))
+    });
+    messages.chain(signals).collect()
+}
+
+fn nodes(dbc: &Dbc) -> Vec<&String> {
+    dbc.nodes.iter().flat_map(|node| &node.0).collect()
+}
+
+impl DbcDiff {
+    /// Compare the `old` and `new` DBC texts.
+    ///
+    /// # Errors
+    /// Returns an error when either text is not a valid DBC.
+    pub fn new(old: &str, new: &str) -> io::Result<Self> {
+        let (old_dbc, new_dbc) = (parse(old)?, parse(new)?);
+        let mut diff = DbcDiff::default();
+
+        for node in nodes(&old_dbc) {
+            if !nodes(&new_dbc).contains(&node) {
+                diff.push(Bump::Major, format!("node {node} removed"));
+            }
+        }
+        for node in nodes(&new_dbc) {
+            if !nodes(&old_dbc).contains(&node) {
+                diff.push(Bump::Minor, format!("node {node} added"));
+            }
+        }
+
+        for old_msg in &old_dbc.messages {
+            match new_dbc.messages.iter().find(|msg| msg.name == old_msg.name) {
+                Some(new_msg) => diff.message(&old_dbc, &new_dbc, old_msg, new_msg),
+                None => match new_dbc.messages.iter().find(|msg| msg.id == old_msg.id) {
+                    Some(new_msg) => diff.push(
+                        Bump::Major,
+                        format!("message {} renamed {}", old_msg.name, new_msg.name),
+                    ),
+                    None => diff.push(Bump::Major, format!("message {} removed", old_msg.name)),
+                },
+            }
+        }
+        for new_msg in &new_dbc.messages {
+            let known = old_dbc
+                .messages
+                .iter()
+                .any(|msg| msg.name == new_msg.name || msg.id == new_msg.id);
+            if !known {
+                diff.push(Bump::Minor, format!("message {} added", new_msg.name));
+            }
+        }
+
+        let (old_attrs, new_attrs) =
+            (attribute_values(&old_dbc, old), attribute_values(&new_dbc, new));
+        diff.compare_maps("attribute", &old_attrs, &new_attrs, Bump::Minor);
+        let (old_comments, new_comments) = (comments(&old_dbc), comments(&new_dbc));
+        diff.compare_maps("comment of", &old_comments, &new_comments, Bump::Patch);
+        Ok(diff)
+    }
+
+    /// Compare the DBC files at `old` and `new`, EDS/DCF and LDF files converted first.
+    ///
+    /// # Errors
+    /// Returns an error when a file cannot be read or converted, see [`DbcDiff::new`].
+    pub fn from_files<P: AsRef<Path>, Q: AsRef<Path>>(
+        old: P,
+        new: Q,
+        node_id: u8,
+    ) -> io::Result<Self> {
+        DbcDiff::new(&read_dbc(old.as_ref(), node_id)?, &read_dbc(new.as_ref(), node_id)?)
+    }
+
+    /// Highest bump of the changes, `Bump::None` without change.
+    #[must_use]
+    pub fn bump(&self) -> Bump {
+        self.changes.iter().map(|change| change.bump).max().unwrap_or(Bump::None)
+    }
+
+    /// One `<bump>: <change>` line per change, major ones first.
+    #[must_use]
+    pub fn report(&self) -> String {
+        let mut changes: Vec<&DbcChange> = self.changes.iter().collect();
+        changes.sort_by_key(|change| Reverse(change.bump));
+        changes
+            .iter()
+            .map(|change| format!("{}: {}\n", change.bump, change.what))
+            .collect()
+    }
+
+    fn push(&mut self, bump: Bump, what: String) {
+        self.changes.push(DbcChange { bump, what });
+    }
+
+    fn compare_maps<V: PartialEq + fmt::Debug>(
+        &mut self,
+        label: &str,
+        old: &BTreeMap<String, V>,
+        new: &BTreeMap<String, V>,
+        bump: Bump,
+    ) {
+        for (subject, value) in old {
+            match new.get(subject) {
+                None => self.push(bump, format!("{label} {subject} removed")),
+                Some(other) if other != value => {
+                    self.push(bump, format!("{label} {subject} changed"));
+                },
+                Some(_) => {},
+            }
+        }
+        for subject in new.keys().filter(|subject| !old.contains_key(*subject)) {
+            self.push(bump, format!("{label} {subject} added"));
+        }
+    }
+
+    fn message(&mut self, old_dbc: &Dbc, new_dbc: &Dbc, old: &Message, new: &Message) {
+        let name = &old.name;
+        if old.id != new.id {
+            self.push(
+                Bump::Major,
+                format!("message {name} id {:#x} -> {:#x}", old.id.raw(), new.id.raw()),
+            );
+        }
+        if old.size != new.size {
+            self.push(
+                Bump::Major,
+                format!("message {name} size {} -> {} bytes", old.size, new.size),
+            );
+        }
+        if transmitter(old) != transmitter(new) {
+            self.push(
+                Bump::Minor,
+                format!("message {name} transmitter {} -> {}", transmitter(old), transmitter(new)),
+            );
+        }
+        for old_sig in &old.signals {
+            match new.signals.iter().find(|sig| sig.name == old_sig.name) {
+                Some(new_sig) => self.signal(old_dbc, new_dbc, (old, old_sig), (new, new_sig)),
+                None => self.push(Bump::Major, format!("signal {name}.{} removed", old_sig.name)),
+            }
+        }
+        for new_sig in &new.signals {
+            if !old.signals.iter().any(|sig| sig.name == new_sig.name) {
+                self.push(Bump::Minor, format!("signal {name}.{} added", new_sig.name));
+            }
+        }
+    }
+
+    fn signal(
+        &mut self,
+        old_dbc: &Dbc,
+        new_dbc: &Dbc,
+        (old_msg, old): (&Message, &Signal),
+        (new_msg, new): (&Message, &Signal),
+    ) {
+        let name = format!("{}.{}", old_msg.name, old.name);
+        if old.start_bit != new.start_bit || old.byte_order != new.byte_order {
+            self.push(
+                Bump::Major,
+                format!(
+                    "signal {name} moved {}@{:?} -> {}@{:?}",
+                    old.start_bit, old.byte_order, new.start_bit, new.byte_order
+                ),
+            );
+        }
+        if old.size != new.size {
+            self.push(
+                Bump::Major,
+                format!("signal {name} resized {} -> {} bits", old.size, new.size),
+            );
+        }
+        let old_type = old_dbc.extended_value_type_for_signal(old_msg.id, &old.name);
+        let new_type = new_dbc.extended_value_type_for_signal(new_msg.id, &new.name);
+        if old.value_type != new.value_type || old_type != new_type {
+            self.push(Bump::Major, format!("signal {name} value type changed"));
+        }
+        if old.factor != new.factor || old.offset != new.offset {
+            self.push(
+                Bump::Major,
+                format!(
+                    "signal {name} rescaled ({},{}) -> ({},{})",
+                    old.factor, old.offset, new.factor, new.offset
+                ),
+            );
+        }
+        if old.multiplexer_indicator != new.multiplexer_indicator {
+            self.push(Bump::Major, format!("signal {name} multiplexing changed"));
+        }
+        if old.unit != new.unit {
+            self.push(Bump::Major, format!("signal {name} unit {:?} -> {:?}", old.unit, new.unit));
+        }
+        if old.min != new.min || old.max != new.max {
+            let narrowed = new.min > old.min || new.max < old.max;
+            self.push(
+                if narrowed { Bump::Major } else { Bump::Minor },
+                format!(
+                    "signal {name} range [{}|{}] -> [{}|{}]",
+                    old.min, old.max, new.min, new.max
+                ),
+            );
+        }
+        if old.receivers != new.receivers {
+            self.push(Bump::Minor, format!("signal {name} receivers changed"));
+        }
+
+        let old_values =
+            old_dbc.value_descriptions_for_signal(old_msg.id, &old.name).unwrap_or(&[]);
+        let new_values =
+            new_dbc.value_descriptions_for_signal(new_msg.id, &new.name).unwrap_or(&[]);
+        for value in old_values {
+            match new_values.iter().find(|other| other.id == value.id) {
+                None => self.push(Bump::Major, format!("signal {name} value {} removed", value.id)),
+                Some(other) if other.description != value.description => self.push(
+                    Bump::Major,
+                    format!(
+                        "signal {name} value {} relabeled {:?} -> {:?}",
+                        value.id, value.description, other.description
+                    ),
+                ),
+                Some(_) => {},
+            }
+        }
+        for value in new_values {
+            if !old_values.iter().any(|other| other.id == value.id) {
+                self.push(Bump::Minor, format!("signal {name} value {} added", value.id));
+            }
+        }
+    }
+}
+
+/// `(major, minor, patch)` of a `major.minor.patch` version, `None` when not semantic.
+#[must_use]
+pub fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let number = |part: &str| {
+        (!part.is_empty() && part.chars().all(|c| c.is_ascii_digit())).then(|| part.parse().ok())?
+    };
+    let parts: Vec<u64> = version.split('.').map(number).collect::<Option<_>>()?;
+    match parts[..] {
+        [major, minor, patch] => Some((major, minor, patch)),
+        _ => None,
+    }
+}
+
+/// `major.minor.patch` `version` after a `bump`, cargo rules below 1.0.0. `None` when the
+/// version is not semantic.
+#[must_use]
+pub fn next_version(version: &str, bump: Bump) -> Option<String> {
+    let (major, minor, patch) = parse_version(version)?;
+    let next = match (bump, major) {
+        (Bump::None, _) => (major, minor, patch),
+        (Bump::Major, 0) => (0, minor + 1, 0),
+        (Bump::Major, _) => (major + 1, 0, 0),
+        (Bump::Minor, 0) | (Bump::Patch, _) => (major, minor, patch + 1),
+        (Bump::Minor, _) => (major, minor + 1, 0),
+    };
+    Some(format!("{}.{}.{}", next.0, next.1, next.2))
+}
@@ -0,0 +1,137 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! End-to-end (E2E) protection of messages: rolling counter and checksum signals.
+//!
+//! DBCs tell the role of these signals with a function attribute, e.g.
+//! `BA_ "GenSigFuncType" SG_ 512 Checksum "CRC8_SAE_J1850";`. A message with a checksum is
+//! E2E protected: its generated `set_values()` writes the next counter value and the CRC, and
+//! its `update()` ignores frames whose CRC does not match. The CRC parameters come from
+//! [`builtin_crc_algorithms`], `DbcParser::crc_algorithms()` adds or replaces entries.
+
+/// Signal attributes holding the E2E role of a signal, the first one set wins.
+pub const E2E_FUNCTION_ATTRIBUTES: [&str; 2] = ["GenSigFuncType", "GenSigEVName"];
+
+/// Message attribute holding the 16 bits data id mixed into the checksum.
+pub const DATA_ID_ATTRIBUTE: &str = "E2EDataID";
+
+/// CRC parameters, Rocksoft model with input and output reflected together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrcAlgorithm {
+    /// Name used by attributes and `--checksum`, e.g. `CRC8_SAE_J1850`.
+    pub name: String,
+    /// CRC size, 8 to 64 bits, also the size of its checksum signal.
+    pub width: u32,
+    pub poly: u64,
+    pub init: u64,
+    pub xorout: u64,
+    /// Bytes processed least significant bit first (CRC32 style).
+    pub reflect: bool,
+}
+
+impl CrcAlgorithm {
+    #[must_use]
+    pub fn new(name: &str, width: u32, poly: u64, init: u64, xorout: u64, reflect: bool) -> Self {
+        CrcAlgorithm { name: name.to_owned(), width, poly, init, xorout, reflect }
+    }
+
+    /// CRC of the concatenated `chunks`, the same computation as the generated code.
+    ///
+    /// # Panics
+    /// Panics when the width is not 8 to 64 bits.
+    #[must_use]
+    pub fn compute(&self, chunks: &[&[u8]]) -> u64 {
+        assert!((8..=64).contains(&self.width), "crc:{} width:{} not 8..64", self.name, self.width);
+        let mask = u64::MAX >> (64 - self.width);
+        let top = 1u64 << (self.width - 1);
+        // reflected algorithms shift right through the reversed polynomial and init
+        let reflected = |value: u64| value.reverse_bits() >> (64 - self.width);
+        let poly = if self.reflect { reflected(self.poly) } else { self.poly };
+        let mut crc = if self.reflect { reflected(self.init) } else { self.init };
+        for byte in chunks.iter().flat_map(|chunk| chunk.iter()) {
+            if self.reflect {
+                crc ^= u64::from(*byte);
+                for _ in 0..8 {
+                    crc = if crc & 1 == 0 { crc >> 1 } else { (crc >> 1) ^ poly };
+                }
+            } else {
+                crc ^= u64::from(*byte) << (self.width - 8);
+                for _ in 0..8 {
+                    crc = if crc & top == 0 { crc << 1 } else { (crc << 1) ^ poly } & mask;
+                }
+            }
+        }
+        (crc ^ self.xorout) & mask
+    }
+
+    /// CRC of the ASCII string "123456789", the check value of CRC catalogues.
+    #[must_use]
+    pub fn check(&self) -> u64 {
+        self.compute(&[b"123456789"])
+    }
+}
+
+/// CRC algorithms known without configuration: the AUTOSAR E2E profile ones and SAE J1850.
+#[must_use]
+pub fn builtin_crc_algorithms() -> Vec<CrcAlgorithm> {
+    vec![
+        CrcAlgorithm::new("CRC8_SAE_J1850", 8, 0x1D, 0xFF, 0xFF, false),
+        CrcAlgorithm::new("CRC8_H2F", 8, 0x2F, 0xFF, 0xFF, false),
+        CrcAlgorithm::new("CRC16_CCITT_FALSE", 16, 0x1021, 0xFFFF, 0, false),
+        CrcAlgorithm::new("CRC32", 32, 0x04C1_1DB7, 0xFFFF_FFFF, 0xFFFF_FFFF, true),
+        CrcAlgorithm::new("CRC32_P4", 32, 0xF4AC_FB13, 0xFFFF_FFFF, 0xFFFF_FFFF, true),
+        CrcAlgorithm::new("CRC64_XZ", 64, 0x42F0_E1EB_A9EA_3693, u64::MAX, u64::MAX, true),
+    ]
+}
+
+/// Algorithm of a checksum signal naming none, chosen by the signal size.
+#[must_use]
+pub fn default_crc_algorithm(width: u64) -> Option<&'static str> {
+    match width {
+        8 => Some("CRC8_SAE_J1850"),
+        16 => Some("CRC16_CCITT_FALSE"),
+        32 => Some("CRC32"),
+        64 => Some("CRC64_XZ"),
+        _ => None,
+    }
+}
+
+/// E2E role of a signal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum E2eFunction {
+    /// Rolling counter, incremented on each sent frame.
+    Counter,
+    /// Checksum, with the name of its algorithm when given.
+    Checksum(Option<String>),
+}
+
+/// Role told by a function attribute value: `CNTR...`/`ALIVE...` counters, `CHK...`/`CRC...`
+/// checksums, the name of a known algorithm a checksum using it. Other values have no role.
+#[must_use]
+pub fn e2e_function(value: &str, algorithms: &[CrcAlgorithm]) -> Option<E2eFunction> {
+    if let Some(algo) = algorithms.iter().find(|algo| algo.name.eq_ignore_ascii_case(value)) {
+        return Some(E2eFunction::Checksum(Some(algo.name.clone())));
+    }
+    let value = value.to_ascii_uppercase();
+    if value.starts_with("CNTR") || value.starts_with("ALIVE") {
+        Some(E2eFunction::Counter)
+    } else if ["CHK", "CRC", "CHECKSUM"].iter().any(|prefix| value.starts_with(prefix)) {
+        Some(E2eFunction::Checksum(None))
+    } else {
+        None
+    }
+}
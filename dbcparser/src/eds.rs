@@ -0,0 +1,266 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Reference:
+ *   CiA 306 (EDS/DCF file format), CiA 301 (PDO communication/mapping objects)
+ */
+
+//! CANopen EDS/DCF import.
+//!
+//! TPDO/RPDO communication (0x1800/0x1400) and mapping (0x1A00/0x1600) objects are turned
+//! into DBC `BO_`/`SG_` definitions, so CANopen devices go through the same parser and code
+//! generator as native DBC files. DCF `ParameterValue` entries take precedence over EDS
+//! `DefaultValue`, and `$NODEID` is resolved with the given node id.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::io::{self, Error};
+use std::path::Path;
+
+/// INI sections keyed by lowercase name, entries keyed by lowercase key.
+struct EdsFile {
+    sections: HashMap<String, HashMap<String, String>>,
+}
+
+impl EdsFile {
+    fn parse(text: &str) -> Self {
+        let mut sections: HashMap<String, HashMap<String, String>> = HashMap::new();
+        let mut current = String::new();
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                current = name.trim().to_ascii_lowercase();
+                sections.entry(current.clone()).or_default();
+            } else if let Some((key, value)) = line.split_once('=') {
+                sections
+                    .entry(current.clone())
+                    .or_default()
+                    .insert(key.trim().to_ascii_lowercase(), value.trim().to_owned());
+            }
+        }
+        EdsFile { sections }
+    }
+
+    fn entry(&self, section: &str, key: &str) -> Option<&str> {
+        self.sections
+            .get(section)
+            .and_then(|entries| entries.get(key))
+            .map(String::as_str)
+    }
+
+    /// DCF value if present, EDS default otherwise.
+    fn value(&self, section: &str, node_id: u8) -> Option<u32> {
+        let raw = self
+            .entry(section, "parametervalue")
+            .or_else(|| self.entry(section, "defaultvalue"))?;
+        parse_value(raw, node_id)
+    }
+}
+
+/// Parse an EDS number: decimal, `0x` hex, leading-zero octal, with an optional `$NODEID`
+/// term (`$NODEID+0x180`, `0x180+$NODEID`).
+fn parse_value(raw: &str, node_id: u8) -> Option<u32> {
+    raw.split('+').try_fold(0u32, |acc, term| {
+        let term = term.trim();
+        let value = if term.eq_ignore_ascii_case("$NODEID") {
+            u32::from(node_id)
+        } else if let Some(hex) = term.strip_prefix("0x").or_else(|| term.strip_prefix("0X")) {
+            u32::from_str_radix(hex, 16).ok()?
+        } else if term.len() > 1 && term.starts_with('0') {
+            u32::from_str_radix(&term[1..], 8).ok()?
+        } else {
+            term.parse().ok()?
+        };
+        acc.checked_add(value)
+    })
+}
+
+fn object_section(index: u16, sub: u8) -> String {
+    format!("{index:04x}sub{sub:x}")
+}
+
+/// Turn an EDS parameter name into a DBC identifier.
+fn identifier(name: &str) -> String {
+    let mut ident: String =
+        name.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect();
+    while ident.contains("__") {
+        ident = ident.replace("__", "_");
+    }
+    let ident = ident.trim_matches('_').to_owned();
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("obj_{ident}")
+    } else {
+        ident
+    }
+}
+
+/// CiA 301 basic data types: (signed, float).
+fn data_type(code: u32) -> (bool, bool) {
+    match code {
+        0x0002..=0x0004 | 0x0010 | 0x0012..=0x0015 => (true, false),
+        0x0008 | 0x0011 => (true, true),
+        _ => (false, false),
+    }
+}
+
+struct PdoSignal {
+    name: String,
+    start: u32,
+    size: u32,
+    signed: bool,
+    float: bool,
+}
+
+/// Convert EDS/DCF text into DBC text for `node_id`.
+///
+/// # Errors
+/// Returns an error when a mapping entry is malformed or a PDO exceeds 64 bits.
+pub fn eds_to_dbc(text: &str, node_id: u8) -> io::Result<String> {
+    let eds = EdsFile::parse(text);
+    let node = eds
+        .entry("deviceinfo", "productname")
+        .map_or_else(|| format!("Node{node_id}"), identifier);
+
+    let mut dbc = String::new();
+    let mut float_signals = Vec::new();
+    let _ = writeln!(dbc, "VERSION \"\"\n\nNS_ :\n\nBS_:\n\nBU_: {node}\n");
+
+    // (kind, communication base, mapping base): TPDOs are sent by the node, RPDOs received
+    for (kind, comm_base, map_base) in [("TPDO", 0x1800u16, 0x1A00u16), ("RPDO", 0x1400, 0x1600)] {
+        for pdo in 0..512u16 {
+            let comm = comm_base + pdo;
+            let mapping = map_base + pdo;
+            let Some(cob_id) = eds.value(&object_section(comm, 1), node_id) else {
+                continue;
+            };
+            // bit 31: PDO does not exist/is disabled
+            if cob_id & 0x8000_0000 != 0 {
+                continue;
+            }
+            let count = eds.value(&object_section(mapping, 0), node_id).unwrap_or(0);
+
+            let mut signals = Vec::new();
+            let mut start = 0u32;
+            for sub in 1..=count.min(64) {
+                let sub = u8::try_from(sub).unwrap_or(u8::MAX);
+                let entry = eds.value(&object_section(mapping, sub), node_id).ok_or_else(|| {
+                    Error::other(format!("{kind}{}: mapping sub{sub} missing", pdo + 1))
+                })?;
+                let (index, subindex, size) =
+                    ((entry >> 16) as u16, ((entry >> 8) & 0xFF) as u8, entry & 0xFF);
+                if size == 0 {
+                    return Err(Error::other(format!(
+                        "{kind}{}: mapping sub{sub} has a null length",
+                        pdo + 1
+                    )));
+                }
+                // indexes below 0x0008 are dummy entries used as padding
+                if index >= 0x0008 {
+                    let section =
+                        if subindex == 0 && eds.sections.contains_key(&format!("{index:04x}")) {
+                            format!("{index:04x}")
+                        } else {
+                            object_section(index, subindex)
+                        };
+                    let name = eds
+                        .entry(&section, "parametername")
+                        .map_or_else(|| format!("obj_{index:04X}_{subindex:02X}"), identifier);
+                    let (signed, float) = data_type(
+                        eds.entry(&section, "datatype")
+                            .and_then(|code| parse_value(code, node_id))
+                            .unwrap_or(0),
+                    );
+                    signals.push(PdoSignal { name, start, size, signed, float });
+                }
+                start += size;
+            }
+            if start > 64 {
+                return Err(Error::other(format!(
+                    "{kind}{}: mapping uses {start} bits, CAN classic PDOs are limited to 64",
+                    pdo + 1
+                )));
+            }
+            if signals.is_empty() {
+                continue;
+            }
+
+            // bit 29: 29-bit identifier, DBC flags extended frames with bit 31
+            let dbc_id = if cob_id & 0x2000_0000 != 0 {
+                (cob_id & 0x1FFF_FFFF) | 0x8000_0000
+            } else {
+                cob_id & 0x7FF
+            };
+            let (transmitter, receiver) = if kind == "TPDO" {
+                (node.as_str(), "Vector__XXX")
+            } else {
+                ("Vector__XXX", node.as_str())
+            };
+            let _ = writeln!(
+                dbc,
+                "BO_ {dbc_id} {kind}{}: {} {transmitter}",
+                pdo + 1,
+                start.div_ceil(8)
+            );
+
+            let mut names: HashMap<String, usize> = HashMap::new();
+            for sig in signals {
+                // the same object can be mapped twice, keep DBC names unique
+                let seen = names.entry(sig.name.clone()).or_insert(0);
+                *seen += 1;
+                let name = if *seen > 1 { format!("{}_{seen}", sig.name) } else { sig.name };
+                let sign = if sig.signed && !sig.float { '-' } else { '+' };
+                let (min, max) = range(sig.size, sig.signed && !sig.float);
+                let _ = writeln!(
+                    dbc,
+                    " SG_ {name} : {}|{}@1{sign} (1,0) [{min}|{max}] \"\" {receiver}",
+                    sig.start, sig.size
+                );
+                if sig.float {
+                    float_signals.push((dbc_id, name, if sig.size == 64 { 2 } else { 1 }));
+                }
+            }
+            dbc.push('\n');
+        }
+    }
+
+    for (dbc_id, name, valtype) in float_signals {
+        let _ = writeln!(dbc, "SIG_VALTYPE_ {dbc_id} {name} : {valtype};");
+    }
+    Ok(dbc)
+}
+
+/// Raw range of a `size` bits integer.
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn range(size: u32, signed: bool) -> (f64, f64) {
+    let size = size.min(64);
+    if signed {
+        let half = 2f64.powi(i32::try_from(size).unwrap_or(64) - 1);
+        (-half, half - 1.0)
+    } else {
+        (0.0, 2f64.powi(i32::try_from(size).unwrap_or(64)) - 1.0)
+    }
+}
+
+/// True when `path` looks like an EDS/DCF file.
+#[must_use]
+pub fn is_eds_file<P: AsRef<Path>>(path: P) -> bool {
+    path.as_ref()
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("eds") || ext.eq_ignore_ascii_case("dcf"))
+}
@@ -0,0 +1,108 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Machine-readable codes for the `CanError` uids raised by generated code and the runtime.
+//!
+//! sockcan's `CanError` only carries a uid string, this enum gives applications something to
+//! match on. The generator emits the same enum in its output so generated crates do not need
+//! this one at runtime.
+
+use std::fmt;
+
+/// Code of a `CanError`, one per uid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CanErrorCode {
+    /// Value outside the DBC `[min|max]` range or not representable.
+    InvalidSignalValue,
+    /// Encoding a message with `set_values` failed.
+    SignalSetValuesFail,
+    /// Resetting a signal failed.
+    SignalResetFail,
+    /// Decoding a signal from a frame failed.
+    SignalUpdateFail,
+    /// Reading a signal group or a signal struct failed.
+    SignalGroupGetFail,
+    /// Encoding a signal group failed.
+    SignalGroupSetFail,
+    /// Reading the signals of a message snapshot failed.
+    MessageSnapshotFail,
+    /// The message is already borrowed in the pool.
+    MessageGetMut,
+    /// No message with this CAN id in the pool.
+    UnknownCanId,
+    /// Derived signals are computed, they cannot be set.
+    DerivedSignalReadOnly,
+    /// J1939 transport frame consumed, the message is not complete yet.
+    TransportPending,
+    /// RAW socket frame that carries no data to decode (error or remote frame).
+    InvalidFrame,
+    /// Uid not raised by canforge (e.g. sockcan's own errors).
+    Other,
+}
+
+impl CanErrorCode {
+    pub const ALL: [CanErrorCode; 13] = [
+        CanErrorCode::InvalidSignalValue,
+        CanErrorCode::SignalSetValuesFail,
+        CanErrorCode::SignalResetFail,
+        CanErrorCode::SignalUpdateFail,
+        CanErrorCode::SignalGroupGetFail,
+        CanErrorCode::SignalGroupSetFail,
+        CanErrorCode::MessageSnapshotFail,
+        CanErrorCode::MessageGetMut,
+        CanErrorCode::UnknownCanId,
+        CanErrorCode::DerivedSignalReadOnly,
+        CanErrorCode::TransportPending,
+        CanErrorCode::InvalidFrame,
+        CanErrorCode::Other,
+    ];
+
+    /// `CanError` uid carrying this code.
+    #[must_use]
+    pub const fn uid(self) -> &'static str {
+        match self {
+            CanErrorCode::InvalidSignalValue => "invalid-signal-value",
+            CanErrorCode::SignalSetValuesFail => "signal-set-values-fail",
+            CanErrorCode::SignalResetFail => "signal-reset-fail",
+            CanErrorCode::SignalUpdateFail => "signal-update-fail",
+            CanErrorCode::SignalGroupGetFail => "signal-group-get-fail",
+            CanErrorCode::SignalGroupSetFail => "signal-group-set-fail",
+            CanErrorCode::MessageSnapshotFail => "message-snapshot-fail",
+            CanErrorCode::MessageGetMut => "message-get_mut",
+            CanErrorCode::UnknownCanId => "fail-canid-search",
+            CanErrorCode::DerivedSignalReadOnly => "derived-signal-readonly",
+            CanErrorCode::TransportPending => "j1939-tp-pending",
+            CanErrorCode::InvalidFrame => "invalid-raw-frame",
+            CanErrorCode::Other => "other",
+        }
+    }
+
+    /// Code of a `CanError` uid, `Other` when the uid is not a canforge one.
+    #[must_use]
+    pub fn from_uid(uid: &str) -> Self {
+        CanErrorCode::ALL
+            .into_iter()
+            .find(|code| code.uid() == uid)
+            .unwrap_or(CanErrorCode::Other)
+    }
+}
+
+impl fmt::Display for CanErrorCode {
+    fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format.write_str(self.uid())
+    }
+}
@@ -0,0 +1,340 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! JSON IR, JSON Schema and Markdown documentation of a parsed DBC.
+//!
+//! They describe the database the Rust code is generated from, after filtering, dropped
+//! signals and renames, so `DbcParser` writes them next to the code from the same parse.
+
+use crate::gencode::{
+    message_transmitters, signal_float_size, signal_receivers, SignalCodeGen, ValCodeGen,
+};
+use can_dbc::{ByteOrder, Dbc, Message, MultiplexIndicator, Signal, ValueType};
+use std::fmt::Write;
+
+/// JSON string literal of `text`.
+fn json_string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if u32::from(c) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", u32::from(c));
+            },
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_list<T: AsRef<str>>(items: &[T]) -> String {
+    let items: Vec<String> = items.iter().map(|item| json_string(item.as_ref())).collect();
+    format!("[{}]", items.join(", "))
+}
+
+fn json_comment(comment: Option<&str>) -> String {
+    comment.map_or_else(|| "null".to_owned(), |comment| json_string(comment.trim()))
+}
+
+/// Multiplexing role of `sig` and its multiplexor value, if any.
+fn multiplex(sig: &Signal) -> (&'static str, Option<u64>) {
+    match sig.multiplexer_indicator {
+        MultiplexIndicator::Plain => ("plain", None),
+        MultiplexIndicator::Multiplexor => ("multiplexor", None),
+        MultiplexIndicator::MultiplexedSignal(value) => ("multiplexed", Some(value)),
+        MultiplexIndicator::MultiplexorAndMultiplexedSignal(value) => {
+            ("multiplexor_and_multiplexed", Some(value))
+        },
+    }
+}
+
+fn json_signal(dbc: &Dbc, msg: &Message, sig: &Signal) -> String {
+    let byte_order = match sig.byte_order {
+        ByteOrder::LittleEndian => "little_endian",
+        ByteOrder::BigEndian => "big_endian",
+    };
+    let value_type = match sig.value_type {
+        ValueType::Signed => "signed",
+        ValueType::Unsigned => "unsigned",
+    };
+    let (mux, mux_value) = multiplex(sig);
+    let mux_value = mux_value.map_or_else(|| "null".to_owned(), |value| value.to_string());
+    let values: Vec<String> = dbc
+        .value_descriptions_for_signal(msg.id, &sig.name)
+        .unwrap_or_default()
+        .iter()
+        .map(|val| {
+            format!("{{\"value\": {}, \"description\": {}}}", val.id, json_string(&val.description))
+        })
+        .collect();
+    format!(
+        "{{\"name\": {}, \"start_bit\": {}, \"size\": {}, \"byte_order\": \"{byte_order}\", \
+         \"value_type\": \"{value_type}\", \"factor\": {}, \"offset\": {}, \"min\": {}, \
+         \"max\": {}, \"unit\": {}, \"receivers\": {}, \"multiplex\": \"{mux}\", \
+         \"multiplex_value\": {mux_value}, \"comment\": {}, \"values\": [{}]}}",
+        json_string(&sig.name),
+        sig.start_bit,
+        sig.size,
+        sig.factor,
+        sig.offset,
+        sig.min,
+        sig.max,
+        json_string(&sig.unit),
+        json_list(&signal_receivers(sig)),
+        json_comment(dbc.signal_comment(msg.id, &sig.name)),
+        values.join(", ")
+    )
+}
+
+/// Messages, signals, value tables and comments of `dbc` as JSON, for tools that need the
+/// database without a DBC parser (dashboards, documentation, diffing).
+#[must_use]
+pub fn dbc_to_json(dbc: &Dbc, uid: &str) -> String {
+    let nodes: Vec<&String> = dbc.nodes.iter().flat_map(|node| node.0.iter()).collect();
+    let mut json = format!(
+        "{{\n  \"uid\": {},\n  \"nodes\": {},\n  \"messages\": [",
+        json_string(uid),
+        json_list(&nodes)
+    );
+    for (idx, msg) in dbc.messages.iter().enumerate() {
+        let separator = if idx == 0 { "" } else { "," };
+        let signals: Vec<String> = msg
+            .signals
+            .iter()
+            .map(|sig| format!("\n        {}", json_signal(dbc, msg, sig)))
+            .collect();
+        let _ = write!(
+            json,
+            "{separator}\n    {{\n      \"id\": {},\n      \"name\": {},\n      \"size\": {},\n      \
+             \"transmitters\": {},\n      \"comment\": {},\n      \"signals\": [{}\n      ]\n    }}",
+            msg.id.raw(),
+            json_string(&msg.name),
+            msg.size,
+            json_list(&message_transmitters(dbc, msg)),
+            json_comment(dbc.message_comment(msg.id)),
+            signals.join(",")
+        );
+    }
+    json.push_str("\n  ]\n}\n");
+    json
+}
+
+/// Markdown table cell: pipes escaped, lines joined.
+fn md_cell(text: &str) -> String {
+    text.trim().replace('|', "\\|").replace('\n', " ")
+}
+
+/// Markdown reference of `dbc`: a message index, then one section per message with its
+/// signal layout and value tables.
+#[must_use]
+pub fn dbc_to_markdown(dbc: &Dbc, uid: &str) -> String {
+    let mut md = format!(
+        "# {uid} CAN database\n\n| ID | Message | Size | Transmitters |\n|---|---|---|---|\n"
+    );
+    for msg in &dbc.messages {
+        let _ = writeln!(
+            md,
+            "| {:#x} | [{}](#{}) | {} | {} |",
+            msg.id.raw(),
+            msg.name,
+            msg.name.to_lowercase(),
+            msg.size,
+            message_transmitters(dbc, msg).join(", ")
+        );
+    }
+
+    for msg in &dbc.messages {
+        let _ = write!(md, "\n## {}\n\nID {:#x}, {} bytes.\n", msg.name, msg.id.raw(), msg.size);
+        if let Some(comment) = dbc.message_comment(msg.id) {
+            let _ = writeln!(md, "\n{}", comment.trim());
+        }
+        if msg.signals.is_empty() {
+            continue;
+        }
+        md.push_str(
+            "\n| Signal | Start\\|Size | Order | Type | Factor | Offset | Min | Max | Unit | Receivers | Comment |\n\
+             |---|---|---|---|---|---|---|---|---|---|---|\n",
+        );
+        for sig in &msg.signals {
+            let order = match sig.byte_order {
+                ByteOrder::LittleEndian => "LE",
+                ByteOrder::BigEndian => "BE",
+            };
+            let value_type = match sig.value_type {
+                ValueType::Signed => "signed",
+                ValueType::Unsigned => "unsigned",
+            };
+            let mux = match multiplex(sig) {
+                ("plain", _) => String::new(),
+                (_, Some(value)) => format!(" (mux {value})"),
+                (_, None) => " (multiplexor)".to_owned(),
+            };
+            let _ = writeln!(
+                md,
+                "| {}{mux} | {}\\|{} | {order} | {value_type} | {} | {} | {} | {} | {} | {} | {} |",
+                sig.name,
+                sig.start_bit,
+                sig.size,
+                sig.factor,
+                sig.offset,
+                sig.min,
+                sig.max,
+                md_cell(&sig.unit),
+                signal_receivers(sig).join(", "),
+                md_cell(dbc.signal_comment(msg.id, &sig.name).unwrap_or_default())
+            );
+        }
+        for sig in &msg.signals {
+            let Some(values) = dbc.value_descriptions_for_signal(msg.id, &sig.name) else {
+                continue;
+            };
+            let _ = write!(md, "\n`{}` values:\n\n| Value | Meaning |\n|---|---|\n", sig.name);
+            for val in values {
+                let _ = writeln!(md, "| {} | {} |", val.id, md_cell(&val.description));
+            }
+        }
+    }
+    md
+}
+
+/// Sockcan `CanDataStatus` names, the `status` of a serialized signal.
+const SIGNAL_STATUS: [&str; 5] = ["Unset", "Updated", "Unchanged", "Timeout", "Error"];
+
+/// Schema of the `value` of a serialized signal, null until a frame is decoded.
+fn json_value_schema(dbc: &Dbc, msg: &Message, sig: &Signal) -> String {
+    let data_type = match signal_float_size(dbc, msg, sig) {
+        Some(_) => "f64".to_owned(),
+        None => sig.get_data_type(),
+    };
+    let mut schema = match data_type.as_str() {
+        "bool" => "\"type\": [\"boolean\", \"null\"]".to_owned(),
+        "f64" => "\"type\": [\"number\", \"null\"]".to_owned(),
+        _ if sig.is_byte_array() => format!(
+            "\"type\": [\"string\", \"null\"], \"pattern\": \"^[0-9a-f]{{{}}}$\"",
+            sig.size.div_ceil(8) * 2
+        ),
+        _ => "\"type\": [\"integer\", \"null\"]".to_owned(),
+    };
+    if data_type != "bool" && !sig.is_byte_array() && sig.min < sig.max {
+        let _ = write!(schema, ", \"minimum\": {}, \"maximum\": {}", sig.min, sig.max);
+    }
+    if !sig.unit.is_empty() {
+        let _ = write!(schema, ", \"x-unit\": {}", json_string(&sig.unit));
+    }
+    // value tables are annotations rather than an `enum`: the bus may send other values
+    if let Some(values) = dbc.value_descriptions_for_signal(msg.id, &sig.name) {
+        if data_type != "f64" && !sig.is_byte_array() {
+            let ids: Vec<String> = values
+                .iter()
+                .map(|val| {
+                    if data_type == "bool" {
+                        (val.id == 1).to_string()
+                    } else {
+                        val.id.to_string()
+                    }
+                })
+                .collect();
+            let names: Vec<String> = values.iter().map(ValCodeGen::get_type_kamel).collect();
+            let descriptions: Vec<&str> =
+                values.iter().map(|val| val.description.as_str()).collect();
+            let _ = write!(
+                schema,
+                ", \"x-enum-values\": [{}], \"x-enum-varnames\": {}, \"x-enum-descriptions\": {}",
+                ids.join(", "),
+                json_list(&names),
+                json_list(&descriptions)
+            );
+        }
+    }
+    format!("{{{schema}}}")
+}
+
+fn json_message_schema(dbc: &Dbc, msg: &Message) -> String {
+    let msg_name = msg.get_type_kamel();
+    let signals: Vec<String> = msg
+        .signals
+        .iter()
+        .map(|sig| {
+            let sig_name = sig.get_type_kamel();
+            let mut schema = format!(
+                "\n            {}: {{\n              \"type\": \"object\",\n",
+                json_string(&sig_name)
+            );
+            if let Some(comment) = dbc.signal_comment(msg.id, &sig.name) {
+                let _ = writeln!(schema, "              \"description\": {},", json_string(comment.trim()));
+            }
+            let _ = write!(
+                schema,
+                "              \"properties\": {{\n                \"name\": {{\"const\": {}}},\n                \
+                 \"stamp\": {{\"type\": \"integer\"}},\n                \"status\": {{\"enum\": {}}},\n                \
+                 \"value\": {}\n              }},\n              \"required\": [\"name\", \"stamp\", \"status\", \"value\"]\n            }}",
+                json_string(&sig_name),
+                json_list(&SIGNAL_STATUS),
+                json_value_schema(dbc, msg, sig)
+            );
+            schema
+        })
+        .collect();
+    let names: Vec<String> = msg.signals.iter().map(SignalCodeGen::get_type_kamel).collect();
+    let mut schema = format!(
+        "\n    {}: {{\n      \"type\": \"object\",\n      \"title\": {},\n",
+        json_string(&msg_name),
+        json_string(&msg_name)
+    );
+    if let Some(comment) = dbc.message_comment(msg.id) {
+        let _ = writeln!(schema, "      \"description\": {},", json_string(comment.trim()));
+    }
+    let _ = write!(
+        schema,
+        "      \"properties\": {{\n        \"id\": {{\"const\": {}}},\n        \"name\": {{\"const\": {}}},\n        \
+         \"status\": {{\"type\": \"string\"}},\n        \"stamp\": {{\"type\": \"integer\"}},\n        \
+         \"signals\": {{\n          \"type\": \"object\",\n          \"properties\": {{{}\n          }},\n          \
+         \"required\": {}\n        }}\n      }},\n      \"required\": [\"id\", \"name\", \"status\", \"stamp\", \"signals\"]\n    }}",
+        msg.id.raw(),
+        json_string(&msg_name),
+        signals.join(","),
+        json_list(&names)
+    );
+    schema
+}
+
+/// JSON Schema (draft 2020-12, usable as OpenAPI 3.1 components) of the JSON telemetry the
+/// generated code exports: one `$defs` entry per message, the document itself validates a
+/// serialized pool. Signals excluded from serde are serialized as null and not described.
+#[must_use]
+pub fn dbc_to_json_schema(dbc: &Dbc, uid: &str) -> String {
+    let defs: Vec<String> = dbc.messages.iter().map(|msg| json_message_schema(dbc, msg)).collect();
+    let refs: Vec<String> = dbc
+        .messages
+        .iter()
+        .map(|msg| format!("{{\"$ref\": \"#/$defs/{}\"}}", msg.get_type_kamel()))
+        .collect();
+    format!(
+        "{{\n  \"$schema\": \"https://json-schema.org/draft/2020-12/schema\",\n  \"title\": {},\n  \
+         \"type\": \"object\",\n  \"properties\": {{\n    \"uid\": {{\"type\": \"string\"}},\n    \
+         \"messages\": {{\"type\": \"array\", \"items\": {{\"oneOf\": [{}]}}}}\n  }},\n  \"$defs\": {{{}\n  }}\n}}\n",
+        json_string(&format!("{uid} CAN messages")),
+        refs.join(", "),
+        defs.join(",")
+    )
+}
@@ -24,13 +24,31 @@
  *   http://mcu.so/Microcontroller/Automotive/dbc-file-format-documentation_compress.pdf
  */
 
-use heck::{ToSnakeCase, ToUpperCamelCase};
+use crate::attributes::{
+    parse_attributes, parse_int, parse_message_attribute_values, parse_signal_attribute_values,
+    AttributeDefault, AttributeKind, AttributeScope, DbcAttribute, MessageAttributeValue,
+    SignalAttributeValue,
+};
+use crate::clock::{Clock, FixedClock, SystemClock};
+use crate::e2e::{
+    builtin_crc_algorithms, default_crc_algorithm, e2e_function, CrcAlgorithm, E2eFunction,
+    DATA_ID_ATTRIBUTE, E2E_FUNCTION_ATTRIBUTES,
+};
+use crate::errors::CanErrorCode;
+use crate::package::CargoPackage;
+use chrono::{DateTime, Local};
+use heck::{ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+use sha2::{Digest, Sha256};
 
 use can_dbc::*;
-use libc;
-use std::ffi::CString;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::ffi::OsString;
+use std::fmt::Write as _;
 use std::fs::{self, File};
 use std::io::{self, Error, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
 
 pub trait SigCodeGen<T> {
     /// Generate code for a signal.
@@ -70,6 +88,12 @@ pub trait SigCodeGen<T> {
     /// # Errors
     /// Returns an error if writing to the output fails.
     fn gen_signal_enum(&self, code: T, msg: &Message) -> io::Result<()>;
+
+    /// Generate a signal wider than 64 bits, backed by a byte array with hex/ASCII accessors.
+    ///
+    /// # Errors
+    /// Returns an error if the signal does not fit the message or writing fails.
+    fn gen_byte_array_signal(&self, code: T, msg: &Message) -> io::Result<()>;
 }
 
 pub trait MsgCodeGen<T> {
@@ -89,6 +113,66 @@ pub trait MsgCodeGen<T> {
     /// # Errors
     /// Returns an error if writing to the output fails.
     fn gen_can_dbc_impl(&self, code: T) -> io::Result<()>;
+
+    /// Generate one struct with combined accessors per SIG_GROUP_ of the message.
+    ///
+    /// # Errors
+    /// Returns an error if a group references an unknown signal or writing fails.
+    fn gen_signal_groups(&self, code: T) -> io::Result<()>;
+
+    /// Generate the `MessageSnapshot` value struct and `DbcMessage::snapshot()`.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the output fails.
+    fn gen_snapshot(&self, code: T) -> io::Result<()>;
+
+    /// Generate the stateless `decode()`/`encode()` functions converting a payload from/to
+    /// `SignalValues` without signal objects.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the output fails.
+    fn gen_values_codec(&self, code: T) -> io::Result<()>;
+
+    /// Generate the `MessageBuilder` encoding a payload from typed per-signal setters.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the output fails.
+    fn gen_builder(&self, code: T) -> io::Result<()>;
+
+    /// Generate the conversions between the message and the user structs of
+    /// `DbcParser::message_structs()`.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the output fails.
+    fn gen_message_structs(&self, code: T) -> io::Result<()>;
+
+    /// Generate a `#[cfg(test)]` module checking the `@test raw=.. phys=..` reference values of
+    /// the signal comments against `physical_from_raw()`/`raw_from_physical()`.
+    ///
+    /// # Errors
+    /// Returns an error for a malformed annotation or if writing to the output fails.
+    fn gen_reference_tests(&self, code: T) -> io::Result<()>;
+
+    /// Generate the presence-only `DbcMessage` of a message without signals (DLC 0
+    /// heartbeats), whose update only records stamp and status.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the output fails.
+    fn gen_presence_message(&self, code: T) -> io::Result<()>;
+
+    /// Generate the `StaticMessage` of `DbcParser::static_pool()`, owning its signal structs
+    /// instead of shared cells.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the output fails.
+    fn gen_static_message(&self, code: T) -> io::Result<()>;
+
+    /// Generate the `# Examples` doctest of the message doc comment, see
+    /// [`DbcParser::emit_tests`].
+    ///
+    /// # Errors
+    /// Returns an error if writing to the output fails.
+    fn gen_doc_example(&self, code: T) -> io::Result<()>;
 }
 
 pub trait ValCodeGen {
@@ -104,6 +188,13 @@ pub trait SignalCodeGen {
     fn get_data_usize(&self) -> String;
     fn get_data_isize(&self) -> String;
     fn has_scaling(&self) -> bool;
+    /// True for signals wider than 64 bits, generated as `[u8; N]` in frame byte order.
+    fn is_byte_array(&self) -> bool;
+    /// Check a signal flagged as text holds whole characters.
+    ///
+    /// # Errors
+    /// Returns an error for a size that is not a whole number of bytes or a factor/offset.
+    fn check_text(&self, msg: &Message) -> io::Result<()>;
     fn get_data_type(&self) -> String;
     fn get_type_kamel(&self) -> String;
     fn get_type_snake(&self) -> String;
@@ -111,20 +202,304 @@ pub trait SignalCodeGen {
 
 pub struct DbcCodeGen {
     outfd: Option<File>,
+    capture: Option<RefCell<Vec<u8>>>,
     dbcfd: Dbc,
     range_check: bool,
     serde_json: bool,
+    /// Cargo feature the serde code is compiled under, always compiled when None.
+    serde_feature: Option<String>,
+    excerpts: HashMap<u32, Vec<String>>,
+    sna: HashMap<(u32, String), SignalSna>,
+    /// Raw values of the VAL_ tables checked on update.
+    domains: HashMap<(u32, String), Vec<u64>>,
+    start_values: HashMap<(u32, String), i64>,
+    cycle_times: HashMap<u32, u32>,
+    /// Raw ids of the J1939 parameter groups.
+    j1939_messages: HashSet<u32>,
+    /// E2E checksum of the protected messages, by raw id.
+    checksums: HashMap<u32, Checksum>,
+    spns: HashMap<(u32, String), u32>,
+    /// Attribute definitions (BA_DEF_).
+    attributes: Vec<DbcAttribute>,
+    /// BA_ assignments of each message, by raw id.
+    message_attributes: HashMap<u32, AttributeLiterals>,
+    signal_attributes: HashMap<(u32, String), AttributeLiterals>,
+    overrides: HashMap<(u32, String), SignalFlags>,
+    signal_structs: Vec<StructMembers>,
+    /// User structs each message converts from/to, by raw id.
+    message_structs: HashMap<u32, Vec<StructMapping>>,
+    sockcan: bool,
+    no_std: bool,
+    static_pool: bool,
+    canfd: bool,
+    examples: Option<DocExamples>,
+}
+
+/// Message doctests of `DbcParser::emit_tests()`.
+struct DocExamples {
+    /// Module holding the messages, the parser uid.
+    uid: &'static str,
+    /// `include!` argument of each doctest, see `include_expr()`.
+    include: String,
 }
 
 pub struct DbcParser {
     uid: &'static str,
-    infile: Option<String>,
-    outfile: Option<String>,
+    infile: Option<PathBuf>,
+    outfile: Option<PathBuf>,
     range_check: bool,
     serde_json: bool,
+    serde_feature: Option<String>,
     header: Option<&'static str>,
     whitelist: Option<Vec<u32>>,
     blacklist: Option<Vec<u32>>,
+    nodes: Vec<String>,
+    plugin_abi: bool,
+    node_id: u8,
+    drop_signals: Vec<String>,
+    rename_map: RenameMap,
+    dbc_excerpts: bool,
+    sna: Vec<String>,
+    check_values: Vec<String>,
+    j1939: bool,
+    sockcan: bool,
+    no_std: bool,
+    static_pool: bool,
+    canfd: bool,
+    crate_root: bool,
+    module_doc: Option<String>,
+    overrides: Vec<SignalOverride>,
+    text_signals: Vec<String>,
+    counters: Vec<String>,
+    checksums: Vec<String>,
+    crc_algorithms: Vec<CrcAlgorithm>,
+    signal_structs: Vec<SignalStruct>,
+    message_structs: Vec<MessageStruct>,
+    json_ir: Option<PathBuf>,
+    json_schema: Option<PathBuf>,
+    markdown: Option<PathBuf>,
+    emit_tests: Option<PathBuf>,
+    /// Benchmark file and the generated file it includes.
+    emit_benches: Option<(PathBuf, PathBuf)>,
+    /// Directory of the cargo package around the code.
+    emit_crate: Option<PathBuf>,
+    copyright: Option<Copyright>,
+    clock: Option<Box<dyn Clock>>,
+    timestamp: bool,
+}
+
+/// Copyright block of the generated banner, replacing the default IoT.bzh/Apache-2.0 one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Copyright {
+    /// Copyright holder, e.g. "ACME Corp".
+    pub holder: String,
+    /// Year or year range, e.g. "2024" or "2021-2025".
+    pub years: Option<String>,
+    /// SPDX license identifier, e.g. "MIT" or "LicenseRef-Proprietary".
+    pub spdx: Option<String>,
+}
+
+impl Copyright {
+    /// Banner lines, each starting with `// `.
+    fn banner(&self) -> String {
+        let mut banner = match &self.years {
+            Some(years) => format!("// Copyright (C) {years} {}", self.holder),
+            None => format!("// Copyright (C) {}", self.holder),
+        };
+        if let Some(spdx) = &self.spdx {
+            let _ = write!(banner, "\n// SPDX-License-Identifier: {spdx}");
+        }
+        banner
+    }
+}
+
+const DEFAULT_COPYRIGHT: &str = r#"// Copyright (C) 2023 IoT.bzh Company
+// Author: Fulup Ar Foll <fulup@iot.bzh>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License."#;
+
+/// Generated names overriding DBC ones, applied before identifiers are derived.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenameMap {
+    /// DBC message name to new name.
+    pub messages: HashMap<String, String>,
+    /// `Signal` or `Message.Signal` (DBC names) to new name, the qualified form wins.
+    pub signals: HashMap<String, String>,
+    /// Message and signal identifiers keep their DBC names instead of UpperCamelCase and
+    /// snake_case ones, keywords still get the `X` prefix.
+    pub keep_names: bool,
+    /// Prepended to the messages absent from `messages`, e.g. `Body` for `BodyDoorStatus`.
+    pub message_prefix: String,
+    /// Appended to the messages absent from `messages`.
+    pub message_suffix: String,
+    /// Prepended to the signals absent from `signals`.
+    pub signal_prefix: String,
+    /// Appended to the signals absent from `signals`.
+    pub signal_suffix: String,
+}
+
+impl RenameMap {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+            && self.signals.is_empty()
+            && self.message_prefix.is_empty()
+            && self.message_suffix.is_empty()
+            && self.signal_prefix.is_empty()
+            && self.signal_suffix.is_empty()
+    }
+}
+
+thread_local! {
+    /// `RenameMap::keep_names` of the generation in progress on this thread.
+    static KEEP_NAMES: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Sets `KEEP_NAMES` for the lifetime of the guard.
+struct KeepNames(bool);
+
+impl KeepNames {
+    fn set(keep: bool) -> Self {
+        KeepNames(KEEP_NAMES.replace(keep))
+    }
+}
+
+impl Drop for KeepNames {
+    fn drop(&mut self) {
+        KEEP_NAMES.set(self.0);
+    }
+}
+
+/// Identifier of a DBC message or signal name: `case` applied unless DBC names are kept,
+/// prefixed with `X` when the name is a keyword or does not start with a letter.
+fn dbc_ident(name: &str, case: fn(&str) -> String) -> String {
+    let name = if needs_prefix(name) { format!("X{name}") } else { name.to_owned() };
+    if KEEP_NAMES.get() {
+        name
+    } else {
+        case(&name)
+    }
+}
+
+/// Per-message or per-signal override of the global `range_check`/`serde_json` settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SignalOverride {
+    /// `Signal` or `Message.Signal` with DBC names, `*` matches any sequence (`Message.*`).
+    pub pattern: String,
+    /// Range check in `set_physical()`, the global setting when None.
+    pub range_check: Option<bool>,
+    /// Serde derives and `to_json()` of the signal, the global setting when None.
+    pub serde_json: Option<bool>,
+}
+
+/// Signals of one or several messages read together as a user-named struct, e.g. a `GpsFix`
+/// made of latitude, longitude and altitude sent in different frames.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SignalStruct {
+    /// Struct name, UpperCamelCase in the generated code.
+    pub name: String,
+    /// Field name and `Message.Signal` member (DBC names) of each field, in order.
+    pub fields: Vec<(String, String)>,
+    /// Oldest member accepted, relative to the last frame given to the pool, 0 for no limit.
+    pub max_age_ms: u64,
+}
+
+impl SignalStruct {
+    /// Parse `Name[@max_age_ms]=field:Message.Signal,...`, e.g.
+    /// `GpsFix@200=lat:GpsPosition.Latitude,lon:GpsPosition.Longitude`.
+    ///
+    /// # Errors
+    /// Returns an error when the specification is malformed.
+    pub fn parse(spec: &str) -> io::Result<Self> {
+        let invalid = || {
+            Error::other(format!("signal struct:{spec} expects Name[@ms]=field:Message.Signal,..."))
+        };
+        let (head, members) = spec.split_once('=').ok_or_else(invalid)?;
+        let (name, max_age_ms) = match head.split_once('@') {
+            Some((name, age)) => (name, age.trim().parse::<u64>().map_err(|_| invalid())?),
+            None => (head, 0),
+        };
+        let fields = members
+            .split(',')
+            .map(|member| {
+                let (field, signal) = member.split_once(':').ok_or_else(invalid)?;
+                Ok((field.trim().to_owned(), signal.trim().to_owned()))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        if name.trim().is_empty()
+            || fields.iter().any(|(field, signal)| field.is_empty() || signal.is_empty())
+        {
+            return Err(invalid());
+        }
+        Ok(SignalStruct { name: name.trim().to_owned(), fields, max_age_ms })
+    }
+}
+
+/// `SignalStruct` with its members resolved to (field, raw message id, signal name).
+struct StructMembers {
+    name: String,
+    max_age_ms: u64,
+    fields: Vec<(String, u32, String)>,
+}
+
+/// Application struct a message converts from and to, its fields named after the generated
+/// (snake_case) names of the message signals, e.g. `Brake { pressure: f64, active: bool }`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageStruct {
+    /// Generated or DBC name of the message.
+    pub message: String,
+    /// Path of the struct from the generated code, e.g. `crate::model::Brake`.
+    pub path: String,
+    /// Signals the struct holds, every signal of the message when empty.
+    pub fields: Vec<String>,
+}
+
+impl MessageStruct {
+    /// Parse `Message=path[(field,...)]`, e.g. `BrakeStatus=crate::model::Brake(pressure,active)`.
+    ///
+    /// # Errors
+    /// Returns an error when the specification is malformed.
+    pub fn parse(spec: &str) -> io::Result<Self> {
+        let invalid =
+            || Error::other(format!("message struct:{spec} expects Message=path[(field,...)]"));
+        let (message, target) = spec.split_once('=').ok_or_else(invalid)?;
+        let (path, fields) = match target.split_once('(') {
+            Some((path, fields)) => {
+                let fields = fields.trim().strip_suffix(')').ok_or_else(invalid)?;
+                let fields: Vec<String> =
+                    fields.split(',').map(|field| field.trim().to_owned()).collect();
+                if fields.iter().any(String::is_empty) {
+                    return Err(invalid());
+                }
+                (path, fields)
+            },
+            None => (target, Vec::new()),
+        };
+        if message.trim().is_empty() || path.trim().is_empty() {
+            return Err(invalid());
+        }
+        Ok(MessageStruct {
+            message: message.trim().to_owned(),
+            path: path.trim().to_owned(),
+            fields,
+        })
+    }
+}
+
+/// `MessageStruct` resolved to the generated names of its signals.
+struct StructMapping {
+    path: String,
+    fields: Vec<String>,
 }
 
 const KEYWORDS: [&str; 53] = [
@@ -146,10 +521,19 @@ macro_rules! code_output {
 
 enum SigMutAction<'a> {
     SetValue { frame: &'a str },
-    Update { frame: &'a str, listeners: &'a str },
+    Update { frame: &'a str, listeners: &'a str, codes: &'a str },
     Reset,
 }
 
+/// How message code reaches its signals.
+#[derive(Clone, Copy)]
+enum SigAccess {
+    /// `self.signals[idx]`, the shared signal cells of `DbcMessage`
+    Shared,
+    /// `self.signals.<field>`, the signal structs held by `StaticMessage`
+    Inline,
+}
+
 fn emit_signal_try_borrow_mut(
     code: &DbcCodeGen,
     indent: &str,
@@ -174,1858 +558,8486 @@ fn emit_signal_try_borrow_mut(
 fn emit_signal_mut_action(
     code: &DbcCodeGen,
     indent: &str,
+    msg: &Message,
     idx: usize,
-    sig: &Signal,
     action: SigMutAction<'_>,
+    access: SigAccess,
     err_tag: &str,
 ) -> io::Result<()> {
-    let dtype_enum = sig.get_data_type().to_upper_camel_case();
+    let sig = &msg.signals[idx];
     let sig_snake = sig.get_type_snake();
+    if sig.is_byte_array() {
+        if let SigMutAction::SetValue { frame } = &action {
+            // CanDbcType has no byte-array variant, write the bits without the signal
+            let sig_type = sig.get_type_kamel();
+            return code_output!(
+                code,
+                format!(
+                    r#"
+{indent}{sig_type}::store_bytes(&{sig_snake}, {frame});"#
+                )
+            );
+        }
+    }
+    let dtype_enum = if sig.is_byte_array() {
+        "Bytes".to_owned()
+    } else {
+        code.data_type(msg, sig).to_upper_camel_case()
+    };
 
     let ok_expr = match action {
         SigMutAction::SetValue { frame } => {
             format!("signal.set_value(CanDbcType::{dtype_enum}({sig_snake}), {frame})?")
         },
-        SigMutAction::Update { frame, listeners } => {
-            format!("{listeners} += signal.update({frame})")
+        SigMutAction::Update { frame, listeners, codes } => {
+            format!("{{ {codes}[{idx}] = signal.update({frame}); {listeners} += {codes}[{idx}]; }}")
         },
         SigMutAction::Reset => "signal.reset()".to_string(),
     };
 
-    emit_signal_try_borrow_mut(code, indent, idx, &sig_snake, &dtype_enum, &ok_expr, err_tag)
-}
-
-fn find_mux_idx(msg: &Message) -> io::Result<Option<usize>> {
-    let idxs: Vec<usize> = msg
-        .signals
-        .iter()
-        .enumerate()
-        .filter_map(|(i, s)| {
-            matches!(
-                s.multiplexer_indicator,
-                MultiplexIndicator::Multiplexor
-                    | MultiplexIndicator::MultiplexorAndMultiplexedSignal(_)
+    match access {
+        SigAccess::Shared => emit_signal_try_borrow_mut(
+            code,
+            indent,
+            idx,
+            &sig_snake,
+            &dtype_enum,
+            &ok_expr,
+            err_tag,
+        ),
+        SigAccess::Inline => code_output!(
+            code,
+            format!(
+                r#"
+{indent}{{
+{indent}    let signal = &mut self.signals.{sig_snake};
+{indent}    {ok_expr};
+{indent}}}"#
             )
-            .then_some(i)
-        })
-        .collect();
-
-    match idxs.as_slice() {
-        [] => Ok(None),
-        [one] => Ok(Some(*one)),
-        _ => Err(Error::other(format!(
-            "message:{} has multiple multiplexors; unsupported",
-            msg.get_type_kamel()
-        ))),
+        ),
     }
 }
 
-fn validate_mux(msg: &Message, mux_sig: &Signal) -> io::Result<()> {
-    if mux_sig.has_scaling() {
-        return Err(Error::other(format!(
-            "message:{} mux:{} has factor/offset; unsupported (mux must be raw integer)",
-            msg.get_type_kamel(),
-            mux_sig.name
-        )));
+/// Reset body of a message: status, stamp, rolling counter and every signal.
+fn emit_message_reset(code: &DbcCodeGen, msg: &Message, access: SigAccess) -> io::Result<()> {
+    code_output!(
+        code,
+        r#"            self.status=CanBcmOpCode::Unknown;
+            self.stamp=0;"#
+    )?;
+    let counter = code.counter_for(msg)?;
+    if counter.is_some() {
+        code_output!(
+            code,
+            r#"            self.counter= None;
+            self.counter_status= super::CounterStatus::Unknown;
+            self.counter_rejected= 0;"#
+        )?;
     }
-    if mux_sig.size > 64 {
-        return Err(Error::other(format!(
-            "message:{} mux:{} size > 64 bits; unsupported",
-            msg.get_type_kamel(),
-            mux_sig.name
-        )));
+    if code.checksum_for(msg).is_some() {
+        code_output!(code, "            self.checksum_rejected= 0;")?;
+    }
+
+    for idx in 0..msg.signals.len() {
+        emit_signal_mut_action(
+            code,
+            "            ",
+            msg,
+            idx,
+            SigMutAction::Reset,
+            access,
+            CanErrorCode::SignalResetFail.uid(),
+        )?;
     }
     Ok(())
 }
 
-fn has_multiplexed_signals(msg: &Message) -> bool {
-    msg.signals
-        .iter()
-        .any(|s| matches!(s.multiplexer_indicator, MultiplexIndicator::MultiplexedSignal(_)))
-}
+/// E2E constants and checksum of a protected message, in the `DbcMessage` impl.
+fn gen_e2e_api(code: &DbcCodeGen, msg: &Message) -> io::Result<()> {
+    let Some((sig, checksum)) = code.checksum_for(msg) else { return Ok(()) };
+    let (start, end) = checksum.bytes;
+    let (sig_name, algo) = (&sig.name, &checksum.algorithm.name);
+    let (data_id_const, data_id_chunk) = match checksum.data_id {
+        Some(data_id) => (
+            format!(
+                r#"
+        /// E2E data id mixed into the checksum, from the {DATA_ID_ATTRIBUTE} attribute
+        pub const DATA_ID: u16 = {data_id:#x};"#
+            ),
+            "&Self::DATA_ID.to_le_bytes(), ",
+        ),
+        None => (String::new(), ""),
+    };
+    let order = match checksum.data_id {
+        Some(_) => "the DATA_ID bytes (low byte first) then ",
+        None => "",
+    };
+    code_output!(
+        code,
+        format!(
+            r#"
+        /// E2E checksum algorithm of the {sig_name} signal
+        pub const CHECKSUM_ALGORITHM: super::CrcAlgorithm = super::{const_name};
+        /// Payload bytes of the {sig_name} signal
+        pub const CHECKSUM_BYTES: {core}::ops::Range<usize> = {start}..{end};{data_id_const}
+
+        /// {algo} of {order}the payload `data` without CHECKSUM_BYTES
+        pub fn checksum(data: &[u8]) -> u64 {{
+            Self::CHECKSUM_ALGORITHM.compute(&[{data_id_chunk}&data[..{start}], &data[{end}..]])
+        }}
 
-fn get_ctime(format: &str) -> io::Result<String> {
-    let fmt = CString::new(format)
-        .map_err(|_| io::Error::other("invalid format string (CString::new)"))?;
+        /// Frames ignored since the last reset because their {sig_name} did not match the checksum
+        pub fn checksum_rejected(&self) -> u64 {{
+            self.checksum_rejected
+        }}"#,
+            const_name = algo.to_shouty_snake_case(),
+            core = code.core_crate(),
+        )
+    )
+}
 
-    // SAFETY: libc::time(NULL) returns current time or -1 on error.
-    let t = unsafe { libc::time(std::ptr::null_mut()) };
-    if t == -1 {
-        return Err(io::Error::last_os_error());
+/// Value after `tx_counter` of an E2E counter, wrapping after the DBC maximum when below
+/// the signal range (e.g. AUTOSAR profile 1 counters stop at 14).
+fn next_counter_expr(counter: &Signal) -> String {
+    let mask = all_ones(counter.size);
+    if counter.max >= 1.0 && (counter.max as u64) < mask {
+        format!("(self.tx_counter + 1) % {}", counter.max as u64 + 1)
+    } else if counter.size >= 64 {
+        "self.tx_counter.wrapping_add(1)".to_owned()
+    } else {
+        format!("(self.tx_counter + 1) & {mask:#x}")
     }
+}
 
-    let mut tm = std::mem::MaybeUninit::<libc::tm>::uninit();
-
-    // SAFETY:
-    // - &t is a valid pointer to time_t
-    // - tm.as_mut_ptr() is valid for writes of libc::tm
-    // - if localtime_r returns non-null, tm is initialized
-    let tm_ptr = unsafe { libc::localtime_r(&t as *const libc::time_t, tm.as_mut_ptr()) };
-    if tm_ptr.is_null() {
-        return Err(io::Error::last_os_error());
+/// Statements of `set_values()`/`encode()` protecting the payload `data`: the next rolling
+/// counter when `tx_counter`, then the checksum.
+fn emit_e2e_protect(
+    code: &DbcCodeGen,
+    msg: &Message,
+    indent: &str,
+    data: &str,
+    tx_counter: bool,
+) -> io::Result<()> {
+    let Some((checksum, ..)) = code.checksum_for(msg) else { return Ok(()) };
+    match code.counter_for(msg)?.filter(|_| tx_counter) {
+        Some(counter) => {
+            let value = format!("self.tx_counter as {}", counter.get_data_usize());
+            code_output!(
+                code,
+                format!(
+                    r#"{indent}// E2E protection: next rolling counter, then the checksum of the final payload
+{indent}{}
+{indent}self.tx_counter= {};"#,
+                    raw_store_expr(counter, msg, data, &value)?,
+                    next_counter_expr(counter),
+                )
+            )?;
+        },
+        None => {
+            code_output!(code, format!("{indent}// E2E protection: checksum of the final payload"))?
+        },
     }
+    code_output!(
+        code,
+        format!(
+            r#"{indent}let checksum = DbcMessage::checksum({data}) as {};
+{indent}{}"#,
+            checksum.get_data_usize(),
+            raw_store_expr(checksum, msg, data, "checksum")?,
+        )
+    )
+}
 
-    let tm = unsafe { tm.assume_init() };
-
-    let mut buf = [0u8; 128];
-
-    // SAFETY:
-    // - buf is valid for writes of buf.len()
-    // - fmt is a valid NUL-terminated C string
-    // - &tm points to an initialized libc::tm
-    let n = unsafe {
-        libc::strftime(
-            buf.as_mut_ptr() as *mut libc::c_char,
-            buf.len(),
-            fmt.as_ptr(),
-            &tm as *const libc::tm,
+/// Update body of a message from a received frame, message callback excepted.
+fn emit_message_update(code: &DbcCodeGen, msg: &Message, access: SigAccess) -> io::Result<()> {
+    let counter = code.counter_for(msg)?;
+    if code.canfd {
+        code_output!(
+            code,
+            format!(
+                r#"            let len = frame.data.len();
+            if matches!(frame.opcode, CanBcmOpCode::RxChanged) && (len < Self::SIZE || !super::CANFD_LENGTHS.contains(&len)) {{
+                return Err(CanError::new("{uid}", format!("{name} frame len:{{len}} not a CAN FD length of {{}} bytes or more", Self::SIZE)));
+            }}"#,
+                uid = CanErrorCode::SignalUpdateFail.uid(),
+                name = msg.name,
+            )
+        )?;
+    }
+    if let Some((checksum, ..)) = code.checksum_for(msg) {
+        let read_fn = raw_read_expr(checksum, msg)?;
+        code_output!(
+            code,
+            format!(
+                r#"            if matches!(frame.opcode, CanBcmOpCode::RxChanged)
+                && (frame.data.len() < DbcMessage::CHECKSUM_BYTES.end || ({read_fn}) as u64 != DbcMessage::checksum(&frame.data))
+            {{
+                // corrupted or forged frame: keep the values of the last valid one
+                self.checksum_rejected += 1;
+                return Ok(());
+            }}"#
+            )
+        )?;
+    }
+    if let Some(counter) = counter {
+        let read_fn = raw_read_expr(counter, msg)?;
+        let mask = all_ones(counter.size);
+        let half = 1u64 << (counter.size - 1);
+        code_output!(
+            code,
+            format!(
+                r#"            if matches!(frame.opcode, CanBcmOpCode::RxChanged) {{
+                let counter = ({read_fn}) as u64;
+                self.counter_status = match self.counter {{
+                    None => super::CounterStatus::Fresh,
+                    Some(last) => match counter.wrapping_sub(last) & {mask:#x} {{
+                        0 => super::CounterStatus::Replayed,
+                        step if step <= {half:#x} => super::CounterStatus::Fresh,
+                        _ => super::CounterStatus::Stale,
+                    }},
+                }};
+                if self.counter_status != super::CounterStatus::Fresh {{
+                    // repeated or out of order frame: keep the values of the last fresh one
+                    self.counter_rejected += 1;
+                    return Ok(());
+                }}
+                self.counter = Some(counter);
+            }}"#
+            )
+        )?;
+    }
+    code_output!(
+        code,
+        format!(
+            r#"            self.stamp= frame.stamp;
+            self.status= frame.opcode;
+            self.listeners= 0;
+            self.signal_listeners= [0;{}];"#,
+            msg.signals.len()
         )
-    };
+    )?;
 
-    if n == 0 {
-        return Err(io::Error::other("strftime() returned 0"));
+    // Mux validation (generator-time):
+    let mux_idx = find_mux_idx(msg)?;
+    if has_multiplexed_signals(msg) && mux_idx.is_none() {
+        return Err(Error::other(format!(
+            "message:{} has multiplexed signals but no multiplexor",
+            msg.get_type_kamel()
+        )));
     }
 
-    Ok(String::from_utf8_lossy(&buf[..n]).into_owned())
-}
+    if let Some(mux_idx) = mux_idx {
+        let mux_sig = &msg.signals[mux_idx];
+        validate_mux(msg, mux_sig)?;
 
-/// Returns current time formatted with `format`.
-///
-/// # Errors
-/// Returns an I/O error if time formatting fails or the system clock is unavailable.
-pub fn get_time(format: &str) -> Result<String, Error> {
-    get_ctime(format).map_err(|e| Error::other(format!("get_ctime failed: {e}")))
-}
+        emit_mux_raw_value(code, msg, mux_sig, "frame.data")?;
 
-fn is_keyword(ident: &str) -> bool {
-    KEYWORDS.iter().any(|kw| kw.eq_ignore_ascii_case(ident))
-}
+        // Always update the multiplexor itself first.
+        emit_signal_mut_action(
+            code,
+            "            ",
+            msg,
+            mux_idx,
+            SigMutAction::Update {
+                frame: "frame",
+                listeners: "self.listeners",
+                codes: "self.signal_listeners",
+            },
+            access,
+            CanErrorCode::SignalUpdateFail.uid(),
+        )?;
 
-fn needs_prefix(ident: &str) -> bool {
-    is_keyword(ident) || !ident.starts_with(|c: char| c.is_ascii_alphabetic())
-}
+        // Update/reset other signals based on mux value.
+        for idx in 0..msg.signals.len() {
+            if idx == mux_idx {
+                continue;
+            }
 
-fn int_type_range(typ: &str) -> Option<(i128, i128)> {
-    //  Return inclusive (min,max) bounds for Rust integer primitives.
-    //
-    // This helper runs in the *code generator* only. We intentionally use i128 as a
-    // "wide" intermediate domain so we can represent both:
-    //   - unsigned ranges up to u64::MAX, and
-    //   - signed ranges down to i64::MIN,
-    // in a single type for clamping/formatting.
-    //
-    // The generated code does NOT use i128: we only emit typed literals like
-    // `123_u32`, `-7_i16`, or `u64::MAX`. Therefore no 128-bit operations leak
-    // into the runtime bindings.
-    match typ {
-        "u8" => Some((u8::MIN as i128, u8::MAX as i128)),
-        "u16" => Some((u16::MIN as i128, u16::MAX as i128)),
-        "u32" => Some((u32::MIN as i128, u32::MAX as i128)),
-        "u64" => Some((u64::MIN as i128, u64::MAX as i128)),
-        "i8" => Some((i8::MIN as i128, i8::MAX as i128)),
-        "i16" => Some((i16::MIN as i128, i16::MAX as i128)),
-        "i32" => Some((i32::MIN as i128, i32::MAX as i128)),
-        "i64" => Some((i64::MIN as i128, i64::MAX as i128)),
-        _ => None,
+            match msg.signals[idx].multiplexer_indicator {
+                MultiplexIndicator::MultiplexedSignal(mux_val) => {
+                    code_output!(
+                        code,
+                        format!(
+                            r#"
+            if __mux_raw_value == {mux_val} {{
+                "#
+                        )
+                    )?;
+                    emit_signal_mut_action(
+                        code,
+                        "                ",
+                        msg,
+                        idx,
+                        SigMutAction::Update {
+                            frame: "frame",
+                            listeners: "self.listeners",
+                            codes: "self.signal_listeners",
+                        },
+                        access,
+                        CanErrorCode::SignalUpdateFail.uid(),
+                    )?;
+                    code_output!(
+                        code,
+                        r#"
+            } else {"#
+                    )?;
+                    emit_signal_mut_action(
+                        code,
+                        "                ",
+                        msg,
+                        idx,
+                        SigMutAction::Reset,
+                        access,
+                        CanErrorCode::SignalUpdateFail.uid(),
+                    )?;
+                    code_output!(code, r#"            }"#)?;
+                },
+
+                MultiplexIndicator::Plain
+                | MultiplexIndicator::Multiplexor
+                | MultiplexIndicator::MultiplexorAndMultiplexedSignal(_) => {
+                    emit_signal_mut_action(
+                        code,
+                        "            ",
+                        msg,
+                        idx,
+                        SigMutAction::Update {
+                            frame: "frame",
+                            listeners: "self.listeners",
+                            codes: "self.signal_listeners",
+                        },
+                        access,
+                        CanErrorCode::SignalUpdateFail.uid(),
+                    )?;
+                },
+            }
+        }
+    } else {
+        // Non-multiplexed message
+        for idx in 0..msg.signals.len() {
+            emit_signal_mut_action(
+                code,
+                "            ",
+                msg,
+                idx,
+                SigMutAction::Update {
+                    frame: "frame",
+                    listeners: "self.listeners",
+                    codes: "self.signal_listeners",
+                },
+                access,
+                CanErrorCode::SignalUpdateFail.uid(),
+            )?;
+        }
     }
+    Ok(())
 }
 
-fn bound_expr(bound: f64, typ: &str, is_min: bool) -> String {
-    //  Build a *compilable* bound expression, clamping to the target type limits when needed.
-    //
-    // This prevents emitting overflowing literals (e.g. `4294970000_u32`) when DBC
-    // min/max exceeds the selected Rust type range. The clamping decision happens
-    // in the generator; the output remains a typed literal or `{typ}::MIN/MAX`.
-    if typ == "f64" {
-        return format!("{bound}_f64");
+/// `__mux_raw_value` statement: multiplexor raw value read from the `data` bytes, sign extended.
+fn emit_mux_raw_value(
+    code: &DbcCodeGen,
+    msg: &Message,
+    mux_sig: &Signal,
+    data: &str,
+) -> io::Result<()> {
+    let mux_read_fn = raw_read_expr(mux_sig, msg)?.replace("frame.data", data);
+
+    if mux_sig.value_type == ValueType::Signed {
+        let data_usize = mux_sig.get_data_usize();
+        let data_isize = mux_sig.get_data_isize();
+        let bits = mux_sig.size;
+        code_output!(
+            code,
+            format!(
+                r#"
+            let __mux_raw_value: u64 = {{
+            let value = {mux_read_fn};
+            // Sign-extend mux raw value from its bit-width (mux selectors are raw values).
+            let shift = {data_usize}::BITS - {bits}u32;
+            let signed: {data_isize} = ((value << shift) as {data_isize}) >> shift;
+            (signed as i64) as u64
+    }};"#
+            )
+        )?;
+    } else {
+        code_output!(
+            code,
+            format!(
+                r#"
+            let __mux_raw_value: u64 = ({mux_read_fn}) as u64;"#
+            )
+        )?;
     }
-    if let Some((tmin, tmax)) = int_type_range(typ) {
-        let b = bound;
-        let tmin_f = tmin as f64;
-        let tmax_f = tmax as f64;
+    Ok(())
+}
 
-        if is_min && b < tmin_f {
-            return format!("{typ}::MIN");
-        }
-        if !is_min && b > tmax_f {
-            return format!("{typ}::MAX");
-        }
+fn find_mux_idx(msg: &Message) -> io::Result<Option<usize>> {
+    let idxs: Vec<usize> = msg
+        .signals
+        .iter()
+        .enumerate()
+        .filter_map(|(i, s)| {
+            matches!(
+                s.multiplexer_indicator,
+                MultiplexIndicator::Multiplexor
+                    | MultiplexIndicator::MultiplexorAndMultiplexedSignal(_)
+            )
+            .then_some(i)
+        })
+        .collect();
 
-        // Best-effort: DBC min/max for integer signals should be integral.
-        let ival = b.round() as i128;
-        format!("{ival}_{typ}")
-    } else {
-        // bool or unknown: keep existing behavior (should not be used for bool).
-        format!("{bound}_{typ}")
+    match idxs.as_slice() {
+        [] => Ok(None),
+        [one] => Ok(Some(*one)),
+        _ => Err(Error::other(format!(
+            "message:{} has multiple multiplexors; unsupported",
+            msg.get_type_kamel()
+        ))),
     }
 }
 
-fn variant_typed_literal(sig: &Signal, variant_id: i64, data_type: &str) -> String {
-    //  Produce a typed literal for enum/value-description mapping that never overflows.
-    //
-    // Note: computations here are generator-side only. We may use i128 as an intermediate
-    // to clamp values safely (especially when targeting `u64`), but we only emit literals
-    // typed as `u8/u16/u32/u64/i8/i16/i32/i64` (or `{typ}::MIN/MAX`).
-    match data_type {
-        "bool" => (variant_id == 1).to_string(),
-        "f64" => format!("{variant_id}_f64"),
-        _ => {
-            if let Some((tmin, tmax)) = int_type_range(data_type) {
-                // Wide intermediate used only for clamping/formatting. Generated code does NOT use i128.
-                let mut v = variant_id as i128;
+fn validate_mux(msg: &Message, mux_sig: &Signal) -> io::Result<()> {
+    if mux_sig.has_scaling() {
+        return Err(Error::other(format!(
+            "message:{} mux:{} has factor/offset; unsupported (mux must be raw integer)",
+            msg.get_type_kamel(),
+            mux_sig.name
+        )));
+    }
+    if mux_sig.size > 64 {
+        return Err(Error::other(format!(
+            "message:{} mux:{} size > 64 bits; unsupported",
+            msg.get_type_kamel(),
+            mux_sig.name
+        )));
+    }
+    Ok(())
+}
 
-                //  If a signed signal uses raw two's-complement IDs in VAL_ (e.g. 1901 for -147 on 11 bits),
-                // interpret positive out-of-range values as raw and convert to signed.
-                if sig.value_type == ValueType::Signed && data_type.starts_with('i') {
-                    let bits = sig.size as u32;
-                    if bits > 0 && bits < 128 {
-                        let sign_threshold = 1i128 << (bits - 1);
-                        if v >= sign_threshold {
-                            v -= 1i128 << bits;
-                        }
-                    }
-                }
+/// Generated expression loading the raw bits of `sig` from `frame.data`.
+fn raw_read_expr(sig: &Signal, msg: &Message) -> io::Result<String> {
+    Ok(match sig.byte_order {
+        ByteOrder::LittleEndian => {
+            let (start, end) = sig.le_start_end_bit(msg)?;
+            format!(
+                "frame.data.view_bits::<Lsb0>()[{start}..{end}].load_le::<{}>()",
+                sig.get_data_usize()
+            )
+        },
+        ByteOrder::BigEndian => {
+            let (start, end) = sig.be_start_end_bit(msg)?;
+            format!(
+                "frame.data.view_bits::<Msb0>()[{start}..{end}].load_be::<{}>()",
+                sig.get_data_usize()
+            )
+        },
+    })
+}
 
-                v = v.clamp(tmin, tmax);
-                format!("{v}_{data_type}")
-            } else {
-                format!("{variant_id}_{data_type}")
-            }
+/// Statement writing the raw `value` expression into the signal bits of `data`.
+fn raw_store_expr(sig: &Signal, msg: &Message, data: &str, value: &str) -> io::Result<String> {
+    Ok(match sig.byte_order {
+        ByteOrder::LittleEndian => {
+            let (start, end) = sig.le_start_end_bit(msg)?;
+            format!("{data}.view_bits_mut::<Lsb0>()[{start}..{end}].store_le({value});")
         },
-    }
+        ByteOrder::BigEndian => {
+            let (start, end) = sig.be_start_end_bit(msg)?;
+            format!("{data}.view_bits_mut::<Msb0>()[{start}..{end}].store_be({value});")
+        },
+    })
 }
 
-impl ValCodeGen for ValDescription {
-    fn get_type_kamel(&self) -> String {
-        if needs_prefix(&self.description) {
-            format!("X{}", self.description).to_upper_camel_case()
-        } else {
-            // to_upper_camel_case() takes &self; no clone/owned needed
-            self.description.to_upper_camel_case()
-        }
-    }
+fn has_multiplexed_signals(msg: &Message) -> bool {
+    msg.signals
+        .iter()
+        .any(|s| matches!(s.multiplexer_indicator, MultiplexIndicator::MultiplexedSignal(_)))
+}
 
-    #[allow(clippy::cast_possible_truncation)]
-    fn get_data_value(&self, data: &str) -> String {
-        match data {
-            "bool" => (self.id == 1).to_string(),
-            "f64" => format!("{}_f64", self.id),
-            _ => format!("{}_{}", self.id, data),
-        }
+/// Pages of a multiplexed message as (selector value, `MuxGroup` variant), in selector order.
+/// A variant is named after the multiplexor VAL_ label of its value, else `M<value>`.
+fn mux_groups(code: &DbcCodeGen, msg: &Message, mux_sig: &Signal) -> Vec<(u64, String)> {
+    let mut values: Vec<u64> = msg
+        .signals
+        .iter()
+        .filter_map(|sig| match sig.multiplexer_indicator {
+            MultiplexIndicator::MultiplexedSignal(value) => Some(value),
+            _ => None,
+        })
+        .collect();
+    values.sort_unstable();
+    values.dedup();
+    let labels = code
+        .dbcfd
+        .value_descriptions_for_signal(msg.id, &mux_sig.name)
+        .unwrap_or_default();
+    let mut groups: Vec<(u64, String)> = Vec::with_capacity(values.len());
+    for value in values {
+        let variant = labels
+            .iter()
+            .find(|label| u64::try_from(label.id) == Ok(value))
+            .map(ValCodeGen::get_type_kamel)
+            .filter(|name| !groups.iter().any(|(_, other)| other == name))
+            .unwrap_or_else(|| format!("M{value}"));
+        groups.push((value, variant));
     }
+    groups
 }
 
-impl ValCodeGen for Message {
-    fn get_type_kamel(&self) -> String {
-        if needs_prefix(&self.name) {
-            format!("X{}", self.name).to_upper_camel_case()
-        } else {
-            self.name.to_upper_camel_case()
-        }
+/// Expression of the current multiplexor value of a message as u64, sign extended like
+/// `__mux_raw_value`, from the `signal` expression of the multiplexor.
+fn mux_selector_expr(code: &DbcCodeGen, msg: &Message, mux_sig: &Signal, signal: &str) -> String {
+    let data_type = code.data_type(msg, mux_sig);
+    let value = format!("{signal}.get_value().cast::<{data_type}>().ok()?");
+    if mux_sig.value_type == ValueType::Signed {
+        format!("({value} as i64) as u64")
+    } else {
+        format!("{value} as u64")
     }
 }
 
-impl SignalCodeGen for Signal {
-    fn le_start_end_bit(&self, msg: &Message) -> io::Result<(u64, u64)> {
-        let msg_bits = msg.size.checked_mul(8).ok_or_else(|| {
-            Error::other(format!(
-                "message:{} size overflow while computing bits (size:{} bytes)",
-                msg.name, msg.size
-            ))
-        })?;
+/// `MuxGroup` enum of the pages of a multiplexed message and `DbcMessage::get_active_group()`.
+fn gen_mux_group_api(code: &DbcCodeGen, msg: &Message) -> io::Result<()> {
+    let Some(mux_idx) = find_mux_idx(msg)? else {
+        return Ok(());
+    };
+    let mux_sig = &msg.signals[mux_idx];
+    let groups = mux_groups(code, msg, mux_sig);
+    if groups.is_empty() {
+        return Ok(());
+    }
+    let msg_type = msg.get_type_kamel();
+    let mux_name = &mux_sig.name;
+    let variants: String = groups.iter().map(|(_, name)| format!("\n        {name},")).collect();
+    let selectors: String = groups
+        .iter()
+        .map(|(value, name)| format!("\n                MuxGroup::{name} => {value},"))
+        .collect();
+    let from_selectors: String = groups
+        .iter()
+        .map(|(value, name)| format!("\n                {value} => Some(MuxGroup::{name}),"))
+        .collect();
+    let selector = mux_selector_expr(
+        code,
+        msg,
+        mux_sig,
+        &format!("self.signals[{mux_idx}].try_borrow().ok()?"),
+    );
+    code_output!(
+        code,
+        format!(
+            r#"
+    /// Pages of the {msg_type} multiplexor {mux_name}, one per selector value with multiplexed
+    /// signals, see `DbcMessage::get_active_group()`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum MuxGroup {{{variants}
+    }}
 
-        let start_bit = self.start_bit;
-        let end_bit = self
-            .start_bit
-            .checked_add(self.size)
-            .ok_or_else(|| Error::other(format!("signal:{} end_bit overflow", self.name)))?;
+    impl MuxGroup {{
+        /// Multiplexor value selecting this page
+        pub fn selector(self) -> u64 {{
+            match self {{{selectors}
+            }}
+        }}
 
-        if start_bit >= msg_bits {
-            return Err(Error::other(format!(
-                "signal:{} starts at {}, but message is only {} bits",
-                self.name, start_bit, msg_bits
-            )));
-        }
+        /// Page selected by the multiplexor `value`, `None` when no signal is multiplexed on it
+        pub fn from_selector(value: u64) -> Option<Self> {{
+            match value {{{from_selectors}
+                _ => None,
+            }}
+        }}
+    }}
 
-        if end_bit > msg_bits {
-            return Err(Error::other(format!(
-                "signal:{} ends at {}, but message is only {} bits",
-                self.name, end_bit, msg_bits
-            )));
-        }
+    impl DbcMessage {{
+        /// Page selected by the multiplexor of the last decoded or encoded values, `None` for a
+        /// selector value without multiplexed signals
+        pub fn get_active_group(&self) -> Option<MuxGroup> {{
+            MuxGroup::from_selector({selector})
+        }}
+    }}
+"#
+        )
+    )
+}
 
-        Ok((start_bit, end_bit))
-    }
+/// Format `secs` since the Unix epoch with strftime-like `format` in the local timezone,
+/// UTC when `local` is false or where the platform has no timezone information
+/// (wasm32-unknown-unknown).
+fn get_ctime(format: &str, secs: u64, local: bool) -> io::Result<String> {
+    let utc = i64::try_from(secs)
+        .ok()
+        .and_then(|secs| DateTime::from_timestamp(secs, 0))
+        .ok_or_else(|| io::Error::other(format!("time {secs} out of range")))?;
+    let mut text = String::new();
+    // chrono reports unknown specifiers as a formatting error instead of panicking
+    let status = if local {
+        write!(text, "{}", utc.with_timezone(&Local).format(format))
+    } else {
+        write!(text, "{}", utc.format(format))
+    };
+    status.map_err(|_| io::Error::other(format!("invalid time format '{format}'")))?;
+    Ok(text)
+}
 
-    fn be_start_end_bit(&self, msg: &Message) -> io::Result<(u64, u64)> {
-        let msg_bits = msg.size.checked_mul(8).ok_or_else(|| {
-            Error::other(format!(
-                "message:{} size overflow while computing bits (size:{} bytes)",
-                msg.name, msg.size
-            ))
-        })?;
+/// Returns the time of `clock` formatted with `format` in the local timezone.
+///
+/// # Errors
+/// Returns an I/O error if time formatting fails.
+pub fn format_time(clock: &dyn Clock, format: &str) -> Result<String, Error> {
+    get_ctime(format, clock.now_us() / 1_000_000, true)
+        .map_err(|e| Error::other(format!("get_ctime failed: {e}")))
+}
 
-        let byte_base = self
-            .start_bit
-            .checked_div(8)
-            .and_then(|v| v.checked_mul(8))
-            .ok_or_else(|| Error::other(format!("signal:{} start_bit overflow", self.name)))?;
+/// Environment variable pinning the generation time of `DbcParser::generate()`, in seconds
+/// since the Unix epoch (<https://reproducible-builds.org/specs/source-date-epoch/>).
+pub const SOURCE_DATE_EPOCH: &str = "SOURCE_DATE_EPOCH";
+
+/// Seconds of `SOURCE_DATE_EPOCH`, `None` when it is unset or empty.
+fn source_date_epoch() -> io::Result<Option<u64>> {
+    match std::env::var(SOURCE_DATE_EPOCH) {
+        Ok(value) if !value.trim().is_empty() => value.trim().parse().map(Some).map_err(|_| {
+            Error::other(format!("{SOURCE_DATE_EPOCH}:{value} is not a number of seconds"))
+        }),
+        _ => Ok(None),
+    }
+}
 
-        let bit_in_byte = self
-            .start_bit
-            .checked_rem(8)
-            .ok_or_else(|| Error::other(format!("signal:{} start_bit overflow", self.name)))?;
+/// Time source of a generation: the `DbcParser::clock()`, else `SOURCE_DATE_EPOCH` formatted
+/// in UTC so the output does not depend on the timezone, else the local system time.
+struct GenClock<'a> {
+    clock: &'a dyn Clock,
+    local: bool,
+}
 
-        let bit_from_msb = 7u64
-            .checked_sub(bit_in_byte)
-            .ok_or_else(|| Error::other(format!("signal:{} start_bit overflow", self.name)))?;
+impl GenClock<'_> {
+    fn format(&self, format: &str) -> io::Result<String> {
+        get_ctime(format, self.clock.now_us() / 1_000_000, self.local)
+            .map_err(|e| Error::other(format!("get_ctime failed: {e}")))
+    }
+}
 
-        let start_bit = byte_base
-            .checked_add(bit_from_msb)
-            .ok_or_else(|| Error::other(format!("signal:{} start_bit overflow", self.name)))?;
+/// Returns current time formatted with `format`.
+///
+/// # Errors
+/// Returns an I/O error if time formatting fails or the system clock is unavailable.
+pub fn get_time(format: &str) -> Result<String, Error> {
+    format_time(&SystemClock, format)
+}
 
-        let end_bit = start_bit
-            .checked_add(self.size)
-            .ok_or_else(|| Error::other(format!("signal:{} end_bit overflow", self.name)))?;
+/// Lowercase hex SHA-256 of `data`.
+#[must_use]
+pub fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data).iter().fold(String::with_capacity(64), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
 
-        if start_bit > msg_bits {
-            return Err(Error::other(format!(
-                "signal:{} starts at {}, but message is only {} bits",
-                self.name, start_bit, msg_bits
-            )));
-        }
+/// Short revision of the git checkout holding `path`, "unknown" outside a repository.
+fn git_rev(path: &Path) -> String {
+    let dir = path.parent().filter(|dir| !dir.as_os_str().is_empty());
+    Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .current_dir(dir.unwrap_or_else(|| Path::new(".")))
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|rev| rev.trim().to_owned())
+        .filter(|rev| !rev.is_empty())
+        .unwrap_or_else(|| "unknown".to_owned())
+}
 
-        if end_bit > msg_bits {
-            return Err(Error::other(format!(
-                "signal:{} ends at {}, but message is only {} bits",
-                self.name, end_bit, msg_bits
-            )));
-        }
+/// Expand the `{dbc_path}`, `{dbc_sha256}`, `{git_rev}` and `{date}` placeholders of a header.
+/// Other braces are kept as is; git and the clock are only queried when used.
+fn expand_header(
+    header: &str,
+    dbc_path: &Path,
+    dbc_sha256: &str,
+    clock: &GenClock<'_>,
+) -> io::Result<String> {
+    let mut text = header
+        .replace("{dbc_path}", &dbc_path.display().to_string())
+        .replace("{dbc_sha256}", dbc_sha256);
+    if text.contains("{git_rev}") {
+        text = text.replace("{git_rev}", &git_rev(dbc_path));
+    }
+    if text.contains("{date}") {
+        text = text.replace("{date}", &clock.format("%Y-%m-%d")?);
+    }
+    Ok(text)
+}
 
-        Ok((start_bit, end_bit))
+/// Every node transmitting `msg`: the `BO_` transmitter followed by its `BO_TX_BU_` entries.
+#[must_use]
+pub fn message_transmitters(dbc: &Dbc, msg: &Message) -> Vec<String> {
+    let mut transmitters = Vec::new();
+    let extra = dbc
+        .message_transmitters
+        .iter()
+        .filter(|entry| entry.message_id == msg.id)
+        .flat_map(|entry| entry.transmitter.iter());
+    for transmitter in std::iter::once(&msg.transmitter).chain(extra) {
+        if let Transmitter::NodeName(node) = transmitter {
+            if !transmitters.contains(node) {
+                transmitters.push(node.clone());
+            }
+        }
     }
+    transmitters
+}
 
-    fn get_data_usize(&self) -> String {
-        let size = match self.size {
-            n if n <= 8 => "u8",
-            n if n <= 16 => "u16",
-            n if n <= 32 => "u32",
-            _ => "u64",
-        };
-        size.to_string()
+/// Receivers of `sig`, without the `Vector__XXX` placeholder.
+#[must_use]
+pub fn signal_receivers(sig: &Signal) -> Vec<&str> {
+    sig.receivers
+        .iter()
+        .map(String::as_str)
+        .filter(|node| *node != "Vector__XXX")
+        .collect()
+}
+
+/// IEEE 754 width of `sig` when `SIG_VALTYPE_` declares it float (32) or double (64).
+#[must_use]
+pub fn signal_float_size(dbc: &Dbc, msg: &Message, sig: &Signal) -> Option<u64> {
+    match dbc.extended_value_type_for_signal(msg.id, &sig.name)? {
+        SignalExtendedValueType::IEEEfloat32Bit => Some(32),
+        SignalExtendedValueType::IEEEdouble64bit => Some(64),
+        SignalExtendedValueType::SignedOrUnsignedInteger => None,
     }
+}
 
-    fn get_data_isize(&self) -> String {
-        let size = match self.size {
-            n if n <= 8 => "i8",
-            n if n <= 16 => "i16",
-            n if n <= 32 => "i32",
-            _ => "i64",
-        };
-        size.to_string()
+/// Shell-like match where `*` stands for any sequence of characters.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == text,
+        Some((head, tail)) => {
+            text.starts_with(head)
+                && (0..=text.len() - head.len())
+                    .filter(|idx| text.is_char_boundary(head.len() + idx))
+                    .any(|idx| glob_match(tail, &text[head.len() + idx..]))
+        },
     }
+}
 
-    #[inline]
-    fn has_scaling(&self) -> bool {
-        const EPS: f64 = 1e-12;
-        self.offset.abs() > EPS || (self.factor - 1.0).abs() > EPS
+/// `Signal` or `Message.Signal` pattern match on DBC names.
+fn signal_match(pattern: &str, msg: &Message, sig: &Signal) -> bool {
+    match pattern.split_once('.') {
+        Some((msg_pattern, sig_pattern)) => {
+            glob_match(msg_pattern, &msg.name) && glob_match(sig_pattern, &sig.name)
+        },
+        None => glob_match(pattern, &sig.name),
     }
+}
 
-    fn get_data_type(&self) -> String {
-        if self.size == 1 {
-            "bool".into()
-        } else if self.has_scaling() {
-            "f64".into()
-        } else {
-            let size = match self.size {
-                n if n <= 8 => "8",
-                n if n <= 16 => "16",
-                n if n <= 32 => "32",
-                _ => "64",
-            };
-            match self.value_type {
-                ValueType::Signed => format!("i{size}"),
-                ValueType::Unsigned => format!("u{size}"),
-            }
+/// Keep the messages transmitted by one of `nodes` or with a signal one of them receives.
+fn retain_nodes(dbcfd: &mut Dbc, nodes: &[String]) -> io::Result<()> {
+    for node in nodes {
+        if !dbcfd.nodes.iter().flat_map(|entry| entry.0.iter()).any(|name| name == node) {
+            return Err(Error::other(format!("node:{node} is not declared in BU_")));
         }
     }
+    let selected = |name: &str| nodes.iter().any(|node| node == name);
+    let kept: Vec<bool> = dbcfd
+        .messages
+        .iter()
+        .map(|msg| {
+            message_transmitters(dbcfd, msg).iter().any(|name| selected(name))
+                || msg.signals.iter().flat_map(signal_receivers).any(selected)
+        })
+        .collect();
+    let mut kept = kept.into_iter();
+    dbcfd.messages.retain(|_| kept.next().unwrap_or(false));
+    Ok(())
+}
 
-    fn get_type_kamel(&self) -> String {
-        if needs_prefix(&self.name) {
-            format!("X{}", self.name).to_upper_camel_case()
-        } else {
-            self.name.to_upper_camel_case()
+fn drop_signals(dbcfd: &mut Dbc, patterns: &[String]) -> io::Result<()> {
+    let dropped = |msg: &Message, sig: &Signal| {
+        patterns.iter().any(|pattern| signal_match(pattern, msg, sig))
+    };
+
+    let mut removed = Vec::new();
+    for msg in &mut dbcfd.messages {
+        let before = msg.signals.len();
+        let keep: Vec<Signal> =
+            msg.signals.iter().filter(|sig| !dropped(msg, sig)).cloned().collect();
+        if keep.len() == before {
+            continue;
+        }
+        if has_multiplexed_signals(msg) && find_mux_idx(msg)?.is_some() {
+            let mux_kept = keep.iter().any(|sig| {
+                matches!(
+                    sig.multiplexer_indicator,
+                    MultiplexIndicator::Multiplexor
+                        | MultiplexIndicator::MultiplexorAndMultiplexedSignal(_)
+                )
+            });
+            let muxed_kept = keep.iter().any(|sig| {
+                matches!(sig.multiplexer_indicator, MultiplexIndicator::MultiplexedSignal(_))
+            });
+            if muxed_kept && !mux_kept {
+                return Err(Error::other(format!(
+                    "message:{} cannot drop its multiplexor and keep multiplexed signals",
+                    msg.name
+                )));
+            }
         }
+        for sig in &msg.signals {
+            if !keep.iter().any(|kept| kept.name == sig.name) {
+                removed.push((msg.id, sig.name.clone()));
+            }
+        }
+        msg.signals = keep;
     }
 
-    fn get_type_snake(&self) -> String {
-        if needs_prefix(&self.name) {
-            format!("X{}", self.name).to_snake_case()
-        } else {
-            self.name.to_snake_case()
-        }
+    // dropped signals leave their groups
+    for group in &mut dbcfd.signal_groups {
+        group
+            .signal_names
+            .retain(|name| !removed.contains(&(group.message_id, name.clone())));
     }
+    dbcfd.signal_groups.retain(|group| !group.signal_names.is_empty());
+    Ok(())
 }
 
-impl SigCodeGen<&DbcCodeGen> for Signal {
-    #[allow(clippy::too_many_lines)]
-    fn gen_signal_trait(&self, code: &DbcCodeGen, msg: &Message) -> io::Result<()> {
-        let msg_type = msg.get_type_kamel();
-        let sig_type = self.get_type_kamel();
-        let raw_ty = self.get_data_usize();
+/// DBC signal attribute declaring the raw "signal not available" value.
+pub const SNA_ATTRIBUTE: &str = "GenSigSNA";
 
-        let read_fn = match self.byte_order {
-            ByteOrder::LittleEndian => {
-                let (start_bit, end_bit) = self.le_start_end_bit(msg)?;
-                format!(
-                    "frame.data.view_bits::<Lsb0>()[{start}..{end}].load_le::<{typ}>()",
-                    typ = raw_ty,
-                    start = start_bit,
-                    end = end_bit,
-                )
-            },
-            ByteOrder::BigEndian => {
-                let (start_bit, end_bit) = self.be_start_end_bit(msg)?;
-                format!(
-                    "frame.data.view_bits::<Msb0>()[{start}..{end}].load_be::<{typ}>()",
-                    typ = raw_ty,
-                    start = start_bit,
-                    end = end_bit,
-                )
+/// DBC signal attribute declaring the raw value of a signal before any frame.
+pub const START_VALUE_ATTRIBUTE: &str = "GenSigStartValue";
+
+/// DBC message attribute declaring the frame format, `J1939PG` for J1939 parameter groups.
+pub const FRAME_FORMAT_ATTRIBUTE: &str = "VFrameFormat";
+
+/// DBC signal attribute declaring the J1939 suspect parameter number.
+pub const SPN_ATTRIBUTE: &str = "SPN";
+
+/// DBC message attribute declaring the transmission period in milliseconds.
+pub const CYCLE_TIME_ATTRIBUTE: &str = "GenMsgCycleTime";
+
+/// `GenMsgCycleTime` of each message keyed by raw message id, the `BA_DEF_DEF_` default for
+/// messages without a value. Messages without either are left out.
+fn message_cycle_times(
+    dbcfd: &Dbc,
+    definitions: &[DbcAttribute],
+    values: &[MessageAttributeValue],
+) -> io::Result<HashMap<u32, u32>> {
+    let period = |value: i64, msg: &str| {
+        u32::try_from(value).map_err(|_| {
+            Error::other(format!("message:{msg} invalid {CYCLE_TIME_ATTRIBUTE} value:{value}"))
+        })
+    };
+    let default = definitions
+        .iter()
+        .find(|attr| attr.name == CYCLE_TIME_ATTRIBUTE && attr.scope == AttributeScope::Message)
+        .and_then(|attr| match attr.default {
+            Some(AttributeDefault::Int(value)) => Some(value),
+            Some(AttributeDefault::Float(value)) => Some(value as i64),
+            _ => None,
+        });
+
+    let mut cycle_times = HashMap::new();
+    for msg in &dbcfd.messages {
+        let value = values
+            .iter()
+            .find(|attr| attr.name == CYCLE_TIME_ATTRIBUTE && attr.message_id == msg.id.raw());
+        let value = match value {
+            Some(attr) => parse_int(&attr.value).ok_or_else(|| {
+                Error::other(format!(
+                    "message:{} invalid {CYCLE_TIME_ATTRIBUTE} value:{}",
+                    msg.name, attr.value
+                ))
+            })?,
+            None => match default {
+                Some(value) => value,
+                None => continue,
             },
         };
+        cycle_times.insert(msg.id.raw(), period(value, &msg.name)?);
+    }
+    Ok(cycle_times)
+}
 
-        code_output!(
-            code,
-            format!(
-                r#"
-/// {msg_type}::{sig_type} public api (CanDbcSignal trait)
-impl CanDbcSignal for {sig_type} {{
+/// Raw ids of the messages sent as J1939 parameter groups: 29-bit ids whose `VFrameFormat`
+/// (or its default) is `J1939PG`, every 29-bit id with `j1939`.
+fn j1939_messages(
+    dbcfd: &Dbc,
+    definitions: &[DbcAttribute],
+    values: &[MessageAttributeValue],
+    j1939: bool,
+) -> HashSet<u32> {
+    let definition = definitions
+        .iter()
+        .find(|attr| attr.name == FRAME_FORMAT_ATTRIBUTE && attr.scope == AttributeScope::Message);
+    let default = definition.and_then(|attr| match &attr.default {
+        Some(AttributeDefault::Str(label)) => Some(label.as_str()),
+        _ => None,
+    });
 
-    fn get_name(&self) -> &'static str {{
-        self.name
-    }}
+    dbcfd
+        .messages
+        .iter()
+        .filter(|msg| bus_id(msg.id).1)
+        .filter(|msg| {
+            let value = values.iter().find(|attr| {
+                attr.name == FRAME_FORMAT_ATTRIBUTE && attr.message_id == msg.id.raw()
+            });
+            let format = match value {
+                // enum values are written as the index of their label
+                Some(attr) => match (definition.map(|attr| &attr.kind), parse_int(&attr.value)) {
+                    (Some(AttributeKind::Enum(choices)), Some(idx)) => usize::try_from(idx)
+                        .ok()
+                        .and_then(|idx| choices.get(idx))
+                        .map(String::as_str),
+                    _ => Some(attr.value.as_str()),
+                },
+                None => default,
+            };
+            j1939 || format == Some("J1939PG")
+        })
+        .map(|msg| msg.id.raw())
+        .collect()
+}
 
-    fn get_stamp(&self) -> u64 {{
-        self.stamp
-    }}
+/// `SPN` attribute of each signal keyed by (raw message id, DBC name). The `BA_DEF_DEF_` default
+/// is not applied, SPNs identify one parameter each.
+fn signal_spns(
+    dbcfd: &Dbc,
+    attributes: &[SignalAttributeValue],
+) -> io::Result<HashMap<(u32, String), u32>> {
+    let mut spns = HashMap::new();
+    for msg in &dbcfd.messages {
+        for sig in &msg.signals {
+            let Some(attr) = attributes.iter().find(|attr| {
+                attr.name == SPN_ATTRIBUTE
+                    && attr.message_id == msg.id.raw()
+                    && attr.signal == sig.name
+            }) else {
+                continue;
+            };
+            // SPNs are 19-bit numbers
+            let spn = parse_int(&attr.value)
+                .and_then(|spn| u32::try_from(spn).ok())
+                .filter(|spn| *spn <= 0x7_FFFF)
+                .ok_or_else(|| {
+                    Error::other(format!(
+                        "signal:{}.{} invalid {SPN_ATTRIBUTE} value:{}",
+                        msg.name, sig.name, attr.value
+                    ))
+                })?;
+            spns.insert((msg.id.raw(), sig.name.clone()), spn);
+        }
+    }
+    Ok(spns)
+}
 
-    fn get_status(&self) -> CanDataStatus {{
-        self.status
-    }}
+/// (DBC attribute name, `AttributeValue` literal) of the `BA_` assignments of a message or
+/// signal, in DBC order.
+type AttributeLiterals = Vec<(String, String)>;
 
-    fn as_any(&mut self) -> &mut dyn Any {{
-        self
-    }}
+/// `AttributeValue` literal of the generated `attributes` module.
+fn attribute_value_literal(value: &AttributeDefault) -> String {
+    match value {
+        AttributeDefault::Int(value) => format!("AttributeValue::Int({value})"),
+        AttributeDefault::Float(value) => format!("AttributeValue::Float({value:?}_f64)"),
+        AttributeDefault::Str(value) => format!("AttributeValue::Str({value:?})"),
+    }
+}
 
-    fn update(&mut self, frame: &CanMsgData) -> i32 {{
-        match frame.opcode {{
-            CanBcmOpCode::RxChanged => {{
-                let raw: {raw_ty} = {read_fn};
-"#,
-                msg_type = msg_type,
-                sig_type = sig_type,
-                raw_ty = raw_ty,
-                read_fn = read_fn,
-            )
-        )?;
+/// (`name`, value literal) of a `BA_` assignment made in `context`, None when no `BA_DEF_` of
+/// `scope` declares `name`.
+fn attribute_literal(
+    definitions: &[DbcAttribute],
+    scope: AttributeScope,
+    (name, value): (&str, &str),
+    context: &str,
+) -> io::Result<Option<(String, String)>> {
+    let Some(attr) = definitions.iter().find(|attr| attr.name == name && attr.scope == scope)
+    else {
+        return Ok(None);
+    };
+    let typed = attr
+        .parse_value(value)
+        .ok_or_else(|| Error::other(format!("{context} invalid {name} value:{value}")))?;
+    Ok(Some((name.to_owned(), attribute_value_literal(&typed))))
+}
 
-        let data_type = self.get_data_type();
-        let new_value_code = if self.size == 1 {
-            "raw == 1".to_string()
-        } else if self.has_scaling() {
-            if self.value_type == ValueType::Signed {
-                let isz = self.get_data_isize();
-                let factor = self.factor;
-                let offset = self.offset;
-                let size = self.size;
-                format!(
-                    "{{
-    // Sign-extend raw from the DBC signal bit-width before scaling.
-    let shift = {raw_ty}::BITS - {size}u32;
-    let signed: {isz} = ((raw << shift) as {isz}) >> shift;
-    (signed as f64) * {factor}_f64 + {offset}_f64
-                }}",
-                )
-            } else {
-                format!("(raw as f64) * {}_f64 + {}_f64", self.factor, self.offset)
+/// Message `BA_` assignments keyed by raw message id, the last one of an attribute winning. Assignments without a message `BA_DEF_` are left out.
+fn message_attribute_literals(
+    dbcfd: &Dbc,
+    definitions: &[DbcAttribute],
+    values: &[MessageAttributeValue],
+) -> io::Result<HashMap<u32, AttributeLiterals>> {
+    let mut literals: HashMap<u32, AttributeLiterals> = HashMap::new();
+    for msg in &dbcfd.messages {
+        let context = format!("message:{}", msg.name);
+        for attr in values.iter().filter(|attr| attr.message_id == msg.id.raw()) {
+            let pair = (attr.name.as_str(), attr.value.as_str());
+            if let Some((name, literal)) =
+                attribute_literal(definitions, AttributeScope::Message, pair, &context)?
+            {
+                let entry = literals.entry(msg.id.raw()).or_default();
+                entry.retain(|(other, _)| *other != name);
+                entry.push((name, literal));
             }
-        } else if self.value_type == ValueType::Signed {
-            // Sign-extend raw from the DBC signal bit-width (works for widths like 11 bits).
-            let isz = self.get_data_isize();
-            let raw_ty = raw_ty.clone();
-            let size = self.size;
-            format!(
-                r#"{{
-    let shift = {raw_ty}::BITS - {size}u32;
-    let signed: {isz} = ((raw << shift) as {isz}) >> shift;
-    signed
-}}"#
-            )
-        } else {
-            "raw".to_string()
-        };
+        }
+    }
+    Ok(literals)
+}
 
-        code_output!(
-            code,
-            format!(
-                r#"
-                    let newval = {new_value_code};
-                    let changed = match self.value {{
-                        None => true,
-                        Some(old) => old != newval,
-                    }};
-                    self.value = Some(newval);
-                    if changed {{
-                        self.status= CanDataStatus::Updated;
-                        self.stamp= frame.stamp;
-                }} else {{
-                        self.status= CanDataStatus::Unchanged;
-                }}"#
-            )
-        )?;
+/// Signal `BA_` assignments keyed by (raw message id, DBC signal name), like
+/// `message_attribute_literals`.
+fn signal_attribute_literals(
+    dbcfd: &Dbc,
+    definitions: &[DbcAttribute],
+    values: &[SignalAttributeValue],
+) -> io::Result<HashMap<(u32, String), AttributeLiterals>> {
+    let mut literals: HashMap<(u32, String), AttributeLiterals> = HashMap::new();
+    for msg in &dbcfd.messages {
+        for sig in &msg.signals {
+            let context = format!("signal:{}.{}", msg.name, sig.name);
+            for attr in values
+                .iter()
+                .filter(|attr| attr.message_id == msg.id.raw() && attr.signal == sig.name)
+            {
+                let pair = (attr.name.as_str(), attr.value.as_str());
+                if let Some((name, literal)) =
+                    attribute_literal(definitions, AttributeScope::Signal, pair, &context)?
+                {
+                    let entry = literals.entry((msg.id.raw(), sig.name.clone())).or_default();
+                    entry.retain(|(other, _)| *other != name);
+                    entry.push((name, literal));
+                }
+            }
+        }
+    }
+    Ok(literals)
+}
 
-        let dtype_enum = data_type.as_str().to_upper_camel_case();
+/// `ATTRIBUTES` table and `get_attribute()` of a message or signal impl, `values` being its
+/// `BA_` assignments. Empty when no `BA_DEF_` has `scope`.
+fn attribute_api(code: &DbcCodeGen, scope: AttributeScope, values: &[(String, String)]) -> String {
+    let definitions: Vec<&DbcAttribute> =
+        code.attributes.iter().filter(|attr| attr.scope == scope).collect();
+    if definitions.is_empty() {
+        return String::new();
+    }
+    let object = if scope == AttributeScope::Message { "message" } else { "signal" };
+    let table: Vec<String> = values
+        .iter()
+        .map(|(name, literal)| format!("({name:?}, super::attributes::{literal})"))
+        .collect();
+    let mut arms = String::new();
+    for attr in definitions {
+        let value = match values.iter().find(|(name, _)| *name == attr.name) {
+            Some((_, literal)) => format!("Some(super::attributes::{literal})"),
+            None => format!("super::attributes::{}.default()", attr.name.to_shouty_snake_case()),
+        };
+        arms.push_str(&format!("\n                {:?} => {value},", attr.name));
+    }
+    format!(
+        r#"
 
-        code_output!(
-            code,
-            format!(
-                r#"
-                }},
-                CanBcmOpCode::RxTimeout => {{
-                    self.status=CanDataStatus::Timeout;
-                }},
-                _ => {{
-                    self.status=CanDataStatus::Error;
-                }},
+        /// Attributes (BA_) set on the {object}, by DBC attribute name
+        pub const ATTRIBUTES: [(&'static str, super::attributes::AttributeValue); {count}] = [{table}];
+
+        /// Value of the {object} attribute `name`, its BA_DEF_DEF_ default when not set
+        pub fn get_attribute(name: &str) -> Option<super::attributes::AttributeValue> {{
+            match name {{{arms}
+                _ => None,
             }}
-            match &self.callback {{
-                None => 0,
-                Some(callback) => {{
-                    match callback.try_borrow() {{
-                        Err(_) => {{println!("fail to get signal callback reference"); -1}},
-                        Ok(cb_ref) => cb_ref.sig_notification(self),
-                    }}
-                }}
-            }}
-        }}
+        }}"#,
+        count = table.len(),
+        table = table.join(", "),
+    )
+}
 
-        fn set_value(&mut self, value:CanDbcType, data:&mut [u8]) -> Result<(),CanError> {{
-            let value:{data_type}= match value.cast() {{
-                Ok(val) => val,
-                Err(error) => return Err(error)
-            }};
-            self.set_typed_value(value, data)
-        }}
+/// E2E checksum of a message.
+#[derive(Debug, Clone)]
+struct Checksum {
+    /// DBC name of the checksum signal.
+    signal: String,
+    algorithm: CrcAlgorithm,
+    /// `E2EDataID` attribute of the message.
+    data_id: Option<u16>,
+    /// Payload bytes of the signal, end excluded.
+    bytes: (u64, u64),
+}
 
-        fn get_value(&self) -> CanDbcType {{
-            CanDbcType::{dtype_enum}(self.get_typed_value())
-        }}
-"#
-            )
-        )?;
+/// Text of an attribute `value`, enum indexes resolved to their label.
+fn attribute_text(definitions: &[DbcAttribute], name: &str, value: &str) -> String {
+    let kind = definitions.iter().find(|attr| attr.name == name).map(|attr| &attr.kind);
+    match (kind, parse_int(value)) {
+        // enum values are written as the index of their label
+        (Some(AttributeKind::Enum(choices)), Some(idx)) => usize::try_from(idx)
+            .ok()
+            .and_then(|idx| choices.get(idx))
+            .map_or_else(|| value.to_owned(), Clone::clone),
+        _ => value.to_owned(),
+    }
+}
 
-        if code.serde_json {
-            code_output!(
-                code,
-                r#"
-        fn to_json(&self) -> String {
-            match serde_json::to_string(self) {
-                Ok(json)=> json,
-                _ => "serde-json-error".to_owned()
+/// Checksum of each E2E protected message keyed by raw message id, rolling counters flagged in
+/// `overrides`. Roles come from the `E2E_FUNCTION_ATTRIBUTES` then the `Signal[=ALGORITHM]`
+/// checksum patterns; the algorithm defaults to the one of the signal size.
+fn e2e_signals(
+    dbcfd: &Dbc,
+    attributes: (&[DbcAttribute], &[SignalAttributeValue], &[MessageAttributeValue]),
+    patterns: &[String],
+    algorithms: &[CrcAlgorithm],
+    overrides: &mut HashMap<(u32, String), SignalFlags>,
+) -> io::Result<HashMap<u32, Checksum>> {
+    let (definitions, signal_values, message_values) = attributes;
+    let mut checksums = HashMap::new();
+    for msg in &dbcfd.messages {
+        let mut checksum: Option<(&Signal, Option<String>)> = None;
+        for sig in &msg.signals {
+            let attribute = E2E_FUNCTION_ATTRIBUTES.iter().find_map(|name| {
+                signal_values.iter().find(|attr| {
+                    attr.name == *name && attr.message_id == msg.id.raw() && attr.signal == sig.name
+                })
+            });
+            let mut function = attribute.and_then(|attr| {
+                e2e_function(&attribute_text(definitions, &attr.name, &attr.value), algorithms)
+            });
+            for entry in patterns {
+                let (pattern, algorithm) = match entry.split_once('=') {
+                    Some((pattern, algorithm)) => (pattern, Some(algorithm.to_owned())),
+                    None => (entry.as_str(), None),
+                };
+                if signal_match(pattern, msg, sig) {
+                    function = Some(E2eFunction::Checksum(algorithm));
+                }
+            }
+            match function {
+                Some(E2eFunction::Counter) => {
+                    overrides.entry((msg.id.raw(), sig.name.clone())).or_default().counter = true;
+                },
+                Some(E2eFunction::Checksum(algorithm)) => {
+                    if let Some((other, _)) = checksum {
+                        return Err(Error::other(format!(
+                            "message:{} has several checksums: {} and {}",
+                            msg.name, other.name, sig.name
+                        )));
+                    }
+                    checksum = Some((sig, algorithm));
+                },
+                None => {},
             }
         }
-"#
-            )?;
+        let Some((sig, algorithm)) = checksum else { continue };
+        let name =
+            algorithm
+                .as_deref()
+                .or_else(|| default_crc_algorithm(sig.size))
+                .ok_or_else(|| {
+                    Error::other(format!(
+                        "message:{} checksum:{} no default crc algorithm for {} bits",
+                        msg.name, sig.name, sig.size
+                    ))
+                })?;
+        let algorithm = algorithms
+            .iter()
+            .find(|algo| algo.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| {
+                Error::other(format!(
+                    "message:{} checksum:{} unknown crc algorithm:{name}",
+                    msg.name, sig.name
+                ))
+            })?;
+        let (start, end) = match sig.byte_order {
+            ByteOrder::LittleEndian => sig.le_start_end_bit(msg)?,
+            ByteOrder::BigEndian => sig.be_start_end_bit(msg)?,
+        };
+        let width = algorithm.width;
+        if sig.multiplexer_indicator != MultiplexIndicator::Plain
+            || sig.value_type == ValueType::Signed
+            || sig.has_scaling()
+            || start % 8 != 0
+            || u64::from(width) != sig.size
+            || !(8..=64).contains(&width)
+        {
+            return Err(Error::other(format!(
+                "message:{} checksum:{} must be a plain byte aligned unsigned {width} bits signal without factor/offset for {}",
+                msg.name, sig.name, algorithm.name
+            )));
         }
+        let data_id = message_values
+            .iter()
+            .find(|attr| attr.name == DATA_ID_ATTRIBUTE && attr.message_id == msg.id.raw())
+            .map(|attr| {
+                parse_int(&attr.value).and_then(|id| u16::try_from(id).ok()).ok_or_else(|| {
+                    Error::other(format!(
+                        "message:{} invalid {DATA_ID_ATTRIBUTE} value:{}",
+                        msg.name, attr.value
+                    ))
+                })
+            })
+            .transpose()?;
+        checksums.insert(
+            msg.id.raw(),
+            Checksum {
+                signal: sig.name.clone(),
+                algorithm: algorithm.clone(),
+                data_id,
+                bytes: (start / 8, end / 8),
+            },
+        );
+    }
+    Ok(checksums)
+}
 
-        // reset signal values + set signal notification callback + impl footer
-        code_output!(
-            code,
-            format!(
-                r#"
-        fn reset(&mut self) {{
-            self.stamp=0;
-            self.reset_value();
-            self.status=CanDataStatus::Unset;
-        }}
+fn all_ones(size: u64) -> u64 {
+    if size >= 64 {
+        u64::MAX
+    } else {
+        (1u64 << size) - 1
+    }
+}
 
-        fn set_callback(&mut self, callback: Box<dyn CanSigCtrl>)  {{
-            self.callback= Some(RefCell::new(callback));
-        }}
+/// Raw values a signal uses instead of a physical value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SignalSna {
+    /// Single "not available" raw value.
+    Raw(u64),
+    /// SAE J1939-71 ranges: "error indicator" and "not available", inclusive.
+    J1939 { error: (u64, u64), not_available: (u64, u64) },
+}
 
-    }} // end {msg_type}::{sig_type} public api
-"#
-            )
-        )?;
+/// J1939 ranges for an unsigned SPN of `size` bits: the top byte 0xFE/0xFF for multi-byte
+/// parameters, the two highest values for discrete ones (e.g. 10b/11b for 2-bit states).
+fn j1939_ranges(size: u64) -> Option<SignalSna> {
+    match size {
+        0 | 1 => None,
+        2..=8 => {
+            let max = all_ones(size);
+            Some(SignalSna::J1939 { error: (max - 1, max - 1), not_available: (max, max) })
+        },
+        _ => {
+            let shift = size.min(64) - 8;
+            let low = all_ones(shift);
+            Some(SignalSna::J1939 {
+                error: (0xFE << shift, (0xFE << shift) | low),
+                not_available: (0xFF << shift, (0xFF << shift) | low),
+            })
+        },
+    }
+}
 
-        Ok(())
+/// Raw "signal not available" value of each signal keyed by (raw message id, DBC name), from
+/// `GenSigSNA` attributes then `Signal[=raw]` patterns (all ones when raw is omitted). With
+/// `j1939`, other unsigned signals get the J1939 error/not available ranges.
+fn signal_sna(
+    dbcfd: &Dbc,
+    attributes: &[SignalAttributeValue],
+    patterns: &[String],
+    j1939: bool,
+) -> io::Result<HashMap<(u32, String), SignalSna>> {
+    let mut sna = HashMap::new();
+    for msg in &dbcfd.messages {
+        // byte-array signals have no single raw value to compare
+        for sig in msg.signals.iter().filter(|sig| !sig.is_byte_array()) {
+            let attribute = attributes.iter().find(|attr| {
+                attr.name == SNA_ATTRIBUTE
+                    && attr.message_id == msg.id.raw()
+                    && attr.signal == sig.name
+            });
+            let mut raw = match attribute {
+                Some(attr) => Some(parse_int(&attr.value).ok_or_else(|| {
+                    Error::other(format!(
+                        "signal:{}.{} invalid {SNA_ATTRIBUTE} value:{}",
+                        msg.name, sig.name, attr.value
+                    ))
+                })?),
+                None => None,
+            };
+            for entry in patterns {
+                let (pattern, value) = match entry.split_once('=') {
+                    Some((pattern, value)) => (pattern, Some(value)),
+                    None => (entry.as_str(), None),
+                };
+                if !signal_match(pattern, msg, sig) {
+                    continue;
+                }
+                raw = match value {
+                    Some(value) => Some(parse_int(value).ok_or_else(|| {
+                        Error::other(format!("sna:{entry} invalid raw value:{value}"))
+                    })?),
+                    None => Some(-1),
+                };
+            }
+            let sna_value = match raw {
+                // negative values are two's complement raw patterns, e.g. -1 for all ones
+                Some(raw) => SignalSna::Raw((raw as u64) & all_ones(sig.size)),
+                None if j1939 && sig.value_type == ValueType::Unsigned => {
+                    let Some(ranges) = j1939_ranges(sig.size) else { continue };
+                    ranges
+                },
+                None => continue,
+            };
+            sna.insert((msg.id.raw(), sig.name.clone()), sna_value);
+        }
     }
+    Ok(sna)
+}
 
-    fn gen_dbc_min_max(&self, code: &DbcCodeGen, _msg: &Message) -> io::Result<()> {
-        if self.size == 1 {
-            return Ok(());
+/// Raw values of the `VAL_` table of each signal matching a `--check-values` pattern, keyed by
+/// (raw message id, DBC name), sorted and masked to the signal size like SNA values. Signals
+/// without a table, floats and byte arrays are left out.
+fn signal_domains(dbcfd: &Dbc, patterns: &[String]) -> HashMap<(u32, String), Vec<u64>> {
+    let mut domains = HashMap::new();
+    for msg in &dbcfd.messages {
+        for sig in msg.signals.iter().filter(|sig| !sig.is_byte_array()) {
+            if !patterns.iter().any(|pattern| signal_match(pattern, msg, sig))
+                || signal_float_size(dbcfd, msg, sig).is_some()
+            {
+                continue;
+            }
+            let Some(variants) = dbcfd.value_descriptions_for_signal(msg.id, &sig.name) else {
+                continue;
+            };
+            // negative ids are two's complement raw patterns of signed signals
+            let mut raws: Vec<u64> =
+                variants.iter().map(|val| (val.id as u64) & all_ones(sig.size)).collect();
+            raws.sort_unstable();
+            raws.dedup();
+            domains.insert((msg.id.raw(), sig.name.clone()), raws);
         }
+    }
+    domains
+}
 
-        let typ = self.get_data_type();
-        let name_uc = self.get_type_kamel().to_uppercase();
-        let min = self.min;
-        let max = self.max;
+/// Raw `GenSigStartValue` of each signal keyed by (raw message id, DBC name), the `BA_DEF_DEF_`
+/// default for signals without a value. Byte arrays and signals without either are left out.
+fn signal_start_values(
+    dbcfd: &Dbc,
+    definitions: &[DbcAttribute],
+    attributes: &[SignalAttributeValue],
+) -> io::Result<HashMap<(u32, String), i64>> {
+    let default = definitions
+        .iter()
+        .find(|attr| attr.name == START_VALUE_ATTRIBUTE && attr.scope == AttributeScope::Signal)
+        .and_then(|attr| match attr.default {
+            Some(AttributeDefault::Int(value)) => Some(value),
+            Some(AttributeDefault::Float(value)) => Some(value as i64),
+            _ => None,
+        });
+
+    let mut start_values = HashMap::new();
+    for msg in &dbcfd.messages {
+        for sig in msg.signals.iter().filter(|sig| !sig.is_byte_array()) {
+            let value = attributes.iter().find(|attr| {
+                attr.name == START_VALUE_ATTRIBUTE
+                    && attr.message_id == msg.id.raw()
+                    && attr.signal == sig.name
+            });
+            let raw = match value {
+                Some(attr) => parse_int(&attr.value).ok_or_else(|| {
+                    Error::other(format!(
+                        "signal:{}.{} invalid {START_VALUE_ATTRIBUTE} value:{}",
+                        msg.name, sig.name, attr.value
+                    ))
+                })?,
+                None => match default {
+                    Some(raw) => raw,
+                    None => continue,
+                },
+            };
+            start_values.insert((msg.id.raw(), sig.name.clone()), raw);
+        }
+    }
+    Ok(start_values)
+}
 
-        //  Clamp to type limits to avoid overflowing literals (e.g. 4294970000_u32).
-        let (min_expr, max_expr) = if typ == "f64" {
-            (format!("{min}_f64"), format!("{max}_f64"))
-        } else {
-            (bound_expr(min, &typ, true), bound_expr(max, &typ, false))
+/// `START_VALUE` literal of `sig`, of `data_type`, for its raw `GenSigStartValue`: scaled
+/// like a decoded raw value, float signals taking it as their IEEE 754 value.
+fn start_value_literal(
+    msg: &Message,
+    sig: &Signal,
+    data_type: &str,
+    float_size: Option<u64>,
+    raw: i64,
+) -> io::Result<String> {
+    let out_of_range = || {
+        Error::other(format!(
+            "signal:{}.{} {START_VALUE_ATTRIBUTE} raw value:{raw} does not fit {} bits",
+            msg.name, sig.name, sig.size
+        ))
+    };
+    if float_size.is_none() {
+        let fits = match sig.value_type {
+            ValueType::Signed if sig.size < 64 => {
+                let half = 1i64 << (sig.size - 1);
+                (-half..half).contains(&raw)
+            },
+            ValueType::Signed => true,
+            ValueType::Unsigned => u64::try_from(raw).is_ok_and(|raw| raw <= all_ones(sig.size)),
         };
+        if !fits {
+            return Err(out_of_range());
+        }
+    }
+    Ok(match data_type {
+        "bool" => (raw != 0).to_string(),
+        "f64" => format!("{:?}", raw as f64 * sig.factor + sig.offset),
+        _ => raw.to_string(),
+    })
+}
 
-        code_output!(
-            code,
-            format!(
-                r#"
-        pub const {name_uc}_MIN: {typ} = {min_expr};
-        pub const {name_uc}_MAX: {typ} = {max_expr};
-"#
-            )
-        )?;
-        Ok(())
+/// Settings of a signal matched by at least one [`SignalOverride`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct SignalFlags {
+    range_check: Option<bool>,
+    serde_json: Option<bool>,
+    text: bool,
+    counter: bool,
+}
+
+/// Overridden settings of each signal keyed by (raw message id, DBC name), later overrides
+/// replace the fields set by earlier ones. Signals matching a `text` (`counters`) pattern are
+/// flagged text (rolling counter).
+fn signal_overrides(
+    dbcfd: &Dbc,
+    overrides: &[SignalOverride],
+    text: &[String],
+    counters: &[String],
+) -> HashMap<(u32, String), SignalFlags> {
+    let mut flags: HashMap<(u32, String), SignalFlags> = HashMap::new();
+    for msg in &dbcfd.messages {
+        for sig in &msg.signals {
+            for entry in overrides.iter().filter(|entry| signal_match(&entry.pattern, msg, sig)) {
+                let sig_flags = flags.entry((msg.id.raw(), sig.name.clone())).or_default();
+                sig_flags.range_check = entry.range_check.or(sig_flags.range_check);
+                sig_flags.serde_json = entry.serde_json.or(sig_flags.serde_json);
+            }
+            if text.iter().any(|pattern| signal_match(pattern, msg, sig)) {
+                flags.entry((msg.id.raw(), sig.name.clone())).or_default().text = true;
+            }
+            if counters.iter().any(|pattern| signal_match(pattern, msg, sig)) {
+                flags.entry((msg.id.raw(), sig.name.clone())).or_default().counter = true;
+            }
+        }
     }
+    flags
+}
 
-    fn gen_signal_enum(&self, code: &DbcCodeGen, msg: &Message) -> io::Result<()> {
-        if let Some(variants) = code.dbcfd.value_descriptions_for_signal(msg.id, self.name.as_str())
+/// Re-key a (raw message id, DBC name) map after renaming signals.
+fn renamed_keys<V>(
+    map: HashMap<(u32, String), V>,
+    renamed: &[(MessageId, String, String)],
+) -> HashMap<(u32, String), V> {
+    map.into_iter()
+        .map(|((id, name), value)| {
+            let name = renamed
+                .iter()
+                .find(|(msg_id, old, _)| msg_id.raw() == id && *old == name)
+                .map_or(name, |(_, _, new)| new.clone());
+            ((id, name), value)
+        })
+        .collect()
+}
+
+/// Resolve the members of each signal struct to a single signal of the database.
+fn resolve_signal_structs(dbcfd: &Dbc, structs: &[SignalStruct]) -> io::Result<Vec<StructMembers>> {
+    let mut resolved: Vec<StructMembers> = Vec::new();
+    for entry in structs {
+        let name = entry.name.to_upper_camel_case();
+        if entry.fields.is_empty() {
+            return Err(Error::other(format!("signal struct:{name} has no field")));
+        }
+        if resolved.iter().any(|other| other.name == name)
+            || dbcfd.messages.iter().any(|msg| msg.get_type_kamel() == name)
         {
-            let id = msg.id.raw();
-            let name = self.name.as_str();
-            let type_kamel = self.get_type_kamel();
-            code_output!(code, format!(r#"    // DBC definition for MsgID:{id} Signal:{name}"#))?;
-            if code.serde_json {
-                code_output!(code, r#"    #[derive(Serialize, Deserialize)]"#)?;
+            return Err(Error::other(format!("signal struct:{name} name already used")));
+        }
+        let mut fields: Vec<(String, u32, String)> = Vec::new();
+        for (field, member) in &entry.fields {
+            let field = field.to_snake_case();
+            if KEYWORDS.contains(&field.as_str()) {
+                return Err(Error::other(format!(
+                    "signal struct:{name} field:{field} is a keyword"
+                )));
             }
-            code_output!(code, format!(r#"    pub enum Dbc{type_kamel} {{"#))?;
-            for variant in variants {
-                let variant_name = variant.get_type_kamel();
-                code_output!(code, format!(r#"        {variant_name},"#))?;
+            if field == "stamp" || fields.iter().any(|(other, _, _)| *other == field) {
+                return Err(Error::other(format!(
+                    "signal struct:{name} field:{field} defined twice"
+                )));
+            }
+            let matches: Vec<(&Message, &Signal)> = dbcfd
+                .messages
+                .iter()
+                .flat_map(|msg| msg.signals.iter().map(move |sig| (msg, sig)))
+                .filter(|(msg, sig)| signal_match(member, msg, sig))
+                .collect();
+            let [(msg, sig)] = matches[..] else {
+                return Err(Error::other(format!(
+                    "signal struct:{name} field:{field} matches {} signals:{member}",
+                    matches.len()
+                )));
+            };
+            if sig.is_byte_array() {
+                return Err(Error::other(format!(
+                    "signal struct:{name} field:{field} wider than 64 bits; unsupported"
+                )));
             }
+            fields.push((field, msg.id.raw(), sig.name.clone()));
+        }
+        resolved.push(StructMembers { name, max_age_ms: entry.max_age_ms, fields });
+    }
+    Ok(resolved)
+}
 
-            let data_type = self.get_data_type();
-            code_output!(
-                code,
-                format!(
-                    r#"
-        _Other({data_type}),
-    }}
+/// Resolve the message and fields of each message struct, once signals are renamed.
+fn resolve_message_structs(
+    dbcfd: &Dbc,
+    structs: &[MessageStruct],
+) -> io::Result<HashMap<u32, Vec<StructMapping>>> {
+    let mut resolved: HashMap<u32, Vec<StructMapping>> = HashMap::new();
+    for entry in structs {
+        let path = &entry.path;
+        let is_ident = |segment: &str| {
+            segment
+                .chars()
+                .next()
+                .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+                && segment.chars().all(|char| char.is_ascii_alphanumeric() || char == '_')
+        };
+        if !path.split("::").all(is_ident) {
+            return Err(Error::other(format!("message struct:{path} not a type path")));
+        }
+        let msg = dbcfd
+            .messages
+            .iter()
+            .find(|msg| msg.name == entry.message || msg.get_type_kamel() == entry.message)
+            .ok_or_else(|| {
+                Error::other(format!(
+                    "message struct:{path} message:{} not in the database",
+                    entry.message
+                ))
+            })?;
+        let msg_type = msg.get_type_kamel();
+        if msg.signals.is_empty() {
+            return Err(Error::other(format!(
+                "message struct:{path} message:{msg_type} has no signal"
+            )));
+        }
+        let mappings = resolved.entry(msg.id.raw()).or_default();
+        if mappings.iter().any(|other| other.path == *path) {
+            return Err(Error::other(format!(
+                "message struct:{path} mapped twice onto {msg_type}"
+            )));
+        }
+        let fields = if entry.fields.is_empty() {
+            msg.signals
+                .iter()
+                .filter(|sig| !sig.is_byte_array())
+                .map(SignalCodeGen::get_type_snake)
+                .collect()
+        } else {
+            let mut fields: Vec<String> = Vec::new();
+            for field in &entry.fields {
+                let sig = msg
+                    .signals
+                    .iter()
+                    .find(|sig| sig.name == *field || sig.get_type_snake() == *field)
+                    .ok_or_else(|| {
+                        Error::other(format!(
+                            "message struct:{path} field:{field} not a signal of {msg_type}"
+                        ))
+                    })?;
+                if sig.is_byte_array() {
+                    return Err(Error::other(format!(
+                        "message struct:{path} field:{field} wider than 64 bits; unsupported"
+                    )));
+                }
+                let field = sig.get_type_snake();
+                if fields.contains(&field) {
+                    return Err(Error::other(format!(
+                        "message struct:{path} field:{field} defined twice"
+                    )));
+                }
+                fields.push(field);
+            }
+            fields
+        };
+        mappings.push(StructMapping { path: path.clone(), fields });
+    }
+    Ok(resolved)
+}
 
-    impl From<Dbc{type_kamel}> for {data_type} {{
-        fn from (val: Dbc{type_kamel}) -> {data_type} {{
-            match val {{"#
-                )
-            )?;
-            for variant in variants {
-                let type_kamel = self.get_type_kamel();
-                let variant_type_kamel = variant.get_type_kamel();
-                let variant_data_type = variant_typed_literal(self, variant.id, &data_type);
-                code_output!(
-                    code,
-                    format!(
-                        r#"                Dbc{type_kamel}::{variant_type_kamel} => {variant_data_type},"#
-                    )
-                )?;
+/// Source `BO_` line and its `SG_` lines, keyed by raw message id.
+fn dbc_excerpts(text: &str) -> HashMap<u32, Vec<String>> {
+    let mut excerpts: HashMap<u32, Vec<String>> = HashMap::new();
+    let mut current = None;
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if let Some(rest) = trimmed.strip_prefix("BO_ ") {
+            current = rest.split_whitespace().next().and_then(|id| id.parse::<u32>().ok());
+            if let Some(id) = current {
+                excerpts.insert(id, vec![trimmed.to_owned()]);
             }
-            let type_kamel = self.get_type_kamel();
-            code_output!(
-                code,
-                format!(
-                    r#"
-                Dbc{type_kamel}::_Other(x) => x
-            }}
-        }}
-    }}
-"#
-                )
-            )?;
+        } else if let (Some(id), true) = (current, trimmed.starts_with("SG_ ")) {
+            excerpts.entry(id).or_default().push(format!(" {trimmed}"));
+        } else {
+            current = None;
         }
-        Ok(())
     }
+    excerpts
+}
 
-    #[allow(clippy::too_many_lines)]
-    fn gen_signal_impl(&self, code: &DbcCodeGen, msg: &Message) -> io::Result<()> {
-        // signal comments and metadata
-        let msg_type_kamel = msg.get_type_kamel();
-        let min = self.min;
-        let max = self.max;
-        let unit = self.unit.as_str();
-        let receivers = self.receivers.join(", ");
-        let start_bit = self.start_bit;
-        let size = self.size;
-        let factor = self.factor;
-        let offset = self.offset;
-        let byte_order = self.byte_order;
-        let value_type = self.value_type;
+/// Byte offset of the `;` ending a DBC statement, outside quoted strings.
+fn statement_end(text: &str) -> Option<usize> {
+    let mut quoted = false;
+    for (idx, char) in text.char_indices() {
+        match char {
+            '"' => quoted = !quoted,
+            ';' if !quoted => return Some(idx),
+            _ => {},
+        }
+    }
+    None
+}
 
-        let type_kamel = self.get_type_kamel();
+/// Descriptions of each `VAL_TABLE_`, by table name, as written in the DBC.
+fn value_tables(text: &str) -> HashMap<String, String> {
+    let mut tables = HashMap::new();
+    let mut lines = text.lines();
+    while let Some(line) = lines.next() {
+        let Some(rest) = line.trim().strip_prefix("VAL_TABLE_ ") else {
+            continue;
+        };
+        // long tables may be wrapped over several lines
+        let mut statement = rest.to_owned();
+        while statement_end(&statement).is_none() {
+            let Some(next) = lines.next() else {
+                break;
+            };
+            statement.push(' ');
+            statement.push_str(next.trim());
+        }
+        let body = statement_end(&statement).map_or(statement.as_str(), |end| &statement[..end]);
+        let mut parts = body.trim().splitn(2, char::is_whitespace);
+        if let Some(name) = parts.next().filter(|name| !name.is_empty()) {
+            tables.insert(name.to_owned(), parts.next().unwrap_or_default().trim().to_owned());
+        }
+    }
+    tables
+}
 
-        let data_type = self.get_data_type();
-        let data_usize = self.get_data_usize();
+/// Message id, signal and table name of a `VAL_ <id> <signal> <table>;` line.
+fn value_table_reference(line: &str) -> Option<(&str, &str, &str)> {
+    let rest = line.trim().strip_prefix("VAL_ ")?.strip_suffix(';')?;
+    let mut tokens = rest.split_whitespace();
+    let (id, signal, table) = (tokens.next()?, tokens.next()?, tokens.next()?);
+    let ident = table.starts_with(|char: char| char.is_ascii_alphabetic() || char == '_')
+        && table.chars().all(|char| char.is_ascii_alphanumeric() || char == '_');
+    (tokens.next().is_none() && id.parse::<u32>().is_ok() && ident).then_some((id, signal, table))
+}
 
-        code_output!(code, format!(r#"    /// {msg_type_kamel}::{type_kamel}"#))?;
-        if let Some(comment) = code.dbcfd.signal_comment(msg.id, self.name.as_str()) {
-            code_output!(code, r#"    ///"#)?;
+/// Replace each `VAL_ <id> <signal> <table>;` reference to a shared `VAL_TABLE_` with the
+/// descriptions of the table, so the signal gets its values (and its `Dbc<Signal>` enum) like
+/// with inline descriptions. Other lines are kept as is.
+///
+/// # Errors
+/// Returns an error for a reference to an undefined table.
+pub fn resolve_value_tables(text: &str) -> io::Result<String> {
+    let tables = value_tables(text);
+    let mut resolved = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\r', '\n']);
+        match value_table_reference(content) {
+            Some((id, signal, table)) => {
+                let descriptions = tables.get(table).ok_or_else(|| {
+                    Error::other(format!("VAL_ {id} {signal}: unknown value table:{table}"))
+                })?;
+                let _ = write!(resolved, "VAL_ {id} {signal} {descriptions};");
+                resolved.push_str(&line[content.len()..]);
+            },
+            None => resolved.push_str(line),
+        }
+    }
+    Ok(resolved)
+}
 
-            for line in comment.trim().lines() {
-                code_output!(code, format!(r#"    /// {line}"#))?;
+/// Reject rename keys matching nothing in the DBC, they are most likely typos, and affixes
+/// that cannot be part of an identifier.
+fn check_renames(dbcfd: &Dbc, renames: &RenameMap) -> io::Result<()> {
+    for affix in [
+        &renames.message_prefix,
+        &renames.message_suffix,
+        &renames.signal_prefix,
+        &renames.signal_suffix,
+    ] {
+        if !affix.chars().all(|char| char.is_ascii_alphanumeric() || char == '_') {
+            return Err(Error::other(format!(
+                "rename-map: affix:{affix:?} not an identifier part"
+            )));
+        }
+    }
+    for name in renames.messages.keys() {
+        if !dbcfd.messages.iter().any(|msg| msg.name == *name) {
+            return Err(Error::other(format!("rename-map: message:{name} not found")));
+        }
+    }
+    for key in renames.signals.keys() {
+        let found = dbcfd.messages.iter().any(|msg| {
+            msg.signals.iter().any(|sig| match key.split_once('.') {
+                Some((msg_name, sig_name)) => msg.name == msg_name && sig.name == sig_name,
+                None => sig.name == *key,
+            })
+        });
+        if !found {
+            return Err(Error::other(format!("rename-map: signal:{key} not found")));
+        }
+    }
+    Ok(())
+}
+
+/// Returns the renamed signals as (message id, old name, new name).
+fn rename(dbcfd: &mut Dbc, renames: &RenameMap) -> io::Result<Vec<(MessageId, String, String)>> {
+    let mut renamed = Vec::new();
+    for msg in &mut dbcfd.messages {
+        for sig in &mut msg.signals {
+            let new_name = renames
+                .signals
+                .get(&format!("{}.{}", msg.name, sig.name))
+                .or_else(|| renames.signals.get(&sig.name))
+                .cloned()
+                .or_else(|| affixed(&renames.signal_prefix, &sig.name, &renames.signal_suffix));
+            if let Some(new_name) = new_name {
+                renamed.push((msg.id, sig.name.clone(), new_name.clone()));
+                sig.name = new_name;
+            }
+        }
+        for (idx, sig) in msg.signals.iter().enumerate() {
+            if msg.signals[..idx].iter().any(|other| other.name == sig.name) {
+                return Err(Error::other(format!(
+                    "rename-map: message:{} has two signals named {}",
+                    msg.name, sig.name
+                )));
             }
         }
+        let new_name = renames
+            .messages
+            .get(&msg.name)
+            .cloned()
+            .or_else(|| affixed(&renames.message_prefix, &msg.name, &renames.message_suffix));
+        if let Some(new_name) = new_name {
+            msg.name = new_name;
+        }
+    }
 
-        code_output!(
-            code,
-            format!(
-                r#"
-    /// - Min: {min}
-    /// - Max: {max}
-    /// - Unit: {unit:?}
+    // keep comments, value tables, groups and value types attached to renamed signals
+    let new_name = |id: MessageId, name: &str| {
+        renamed
+            .iter()
+            .find(|(msg_id, old, _)| *msg_id == id && old == name)
+            .map(|(_, _, new)| new.clone())
+    };
+    for comment in &mut dbcfd.comments {
+        if let Comment::Signal { message_id, name, .. } = comment {
+            if let Some(new) = new_name(*message_id, name) {
+                *name = new;
+            }
+        }
+    }
+    for description in &mut dbcfd.value_descriptions {
+        if let ValueDescription::Signal { message_id, name, .. } = description {
+            if let Some(new) = new_name(*message_id, name) {
+                *name = new;
+            }
+        }
+    }
+    for group in &mut dbcfd.signal_groups {
+        for name in &mut group.signal_names {
+            if let Some(new) = new_name(group.message_id, name) {
+                *name = new;
+            }
+        }
+    }
+    for valtype in &mut dbcfd.signal_extended_value_type_list {
+        if let Some(new) = new_name(valtype.message_id, &valtype.signal_name) {
+            valtype.signal_name = new;
+        }
+    }
+    Ok(renamed)
+}
+
+/// `name` with a prefix and suffix, None when both are empty.
+fn affixed(prefix: &str, name: &str, suffix: &str) -> Option<String> {
+    (!prefix.is_empty() || !suffix.is_empty()).then(|| format!("{prefix}{name}{suffix}"))
+}
+
+fn is_keyword(ident: &str) -> bool {
+    KEYWORDS.iter().any(|kw| kw.eq_ignore_ascii_case(ident))
+}
+
+fn needs_prefix(ident: &str) -> bool {
+    is_keyword(ident) || !ident.starts_with(|c: char| c.is_ascii_alphabetic())
+}
+
+fn int_type_range(typ: &str) -> Option<(i128, i128)> {
+    //  Return inclusive (min,max) bounds for Rust integer primitives.
+    //
+    // This helper runs in the *code generator* only. We intentionally use i128 as a
+    // "wide" intermediate domain so we can represent both:
+    //   - unsigned ranges up to u64::MAX, and
+    //   - signed ranges down to i64::MIN,
+    // in a single type for clamping/formatting.
+    //
+    // The generated code does NOT use i128: we only emit typed literals like
+    // `123_u32`, `-7_i16`, or `u64::MAX`. Therefore no 128-bit operations leak
+    // into the runtime bindings.
+    match typ {
+        "u8" => Some((u8::MIN as i128, u8::MAX as i128)),
+        "u16" => Some((u16::MIN as i128, u16::MAX as i128)),
+        "u32" => Some((u32::MIN as i128, u32::MAX as i128)),
+        "u64" => Some((u64::MIN as i128, u64::MAX as i128)),
+        "i8" => Some((i8::MIN as i128, i8::MAX as i128)),
+        "i16" => Some((i16::MIN as i128, i16::MAX as i128)),
+        "i32" => Some((i32::MIN as i128, i32::MAX as i128)),
+        "i64" => Some((i64::MIN as i128, i64::MAX as i128)),
+        _ => None,
+    }
+}
+
+fn bound_expr(bound: f64, typ: &str) -> String {
+    //  Build a *compilable* bound expression, clamping to the target type limits when needed.
+    //
+    // This prevents emitting overflowing literals (e.g. `4294970000_u32`) when DBC
+    // min/max exceeds the selected Rust type range. The clamping decision happens
+    // in the generator; the output remains a typed literal or `{typ}::MIN/MAX`.
+    if typ == "f64" {
+        return format!("{bound}_f64");
+    }
+    if let Some((tmin, tmax)) = int_type_range(typ) {
+        // Compare in i128: `u64::MAX as f64` rounds up to 2^64, so an f64 comparison
+        // lets a 2^64 bound through and emits an overflowing literal.
+        // Best-effort: DBC min/max for integer signals should be integral.
+        let ival = bound.round() as i128;
+
+        if ival < tmin {
+            format!("{typ}::MIN")
+        } else if ival > tmax {
+            format!("{typ}::MAX")
+        } else {
+            format!("{ival}_{typ}")
+        }
+    } else {
+        // bool or unknown: keep existing behavior (should not be used for bool).
+        format!("{bound}_{typ}")
+    }
+}
+
+/// `VAL_` table as markdown doc-comment lines, for IDE hover documentation.
+fn value_table_doc(variants: &[ValDescription], indent: &str) -> String {
+    let mut doc = format!("{indent}///\n{indent}/// | Value | Meaning |\n{indent}/// |---|---|");
+    for variant in variants {
+        let meaning = variant.description.trim().replace('|', "\\|");
+        doc.push_str(&format!("\n{indent}/// | {} | {meaning} |", variant.id));
+    }
+    doc
+}
+
+fn variant_typed_literal(sig: &Signal, variant_id: i64, data_type: &str) -> String {
+    //  Produce a typed literal for enum/value-description mapping that never overflows.
+    //
+    // Note: computations here are generator-side only. We may use i128 as an intermediate
+    // to clamp values safely (especially when targeting `u64`), but we only emit literals
+    // typed as `u8/u16/u32/u64/i8/i16/i32/i64` (or `{typ}::MIN/MAX`).
+    match data_type {
+        "bool" => (variant_id == 1).to_string(),
+        "f64" => format!("{variant_id}_f64"),
+        _ => {
+            if let Some((tmin, tmax)) = int_type_range(data_type) {
+                // Wide intermediate used only for clamping/formatting. Generated code does NOT use i128.
+                let mut v = variant_id as i128;
+
+                //  If a signed signal uses raw two's-complement IDs in VAL_ (e.g. 1901 for -147 on 11 bits),
+                // interpret positive out-of-range values as raw and convert to signed.
+                if sig.value_type == ValueType::Signed && data_type.starts_with('i') {
+                    let bits = sig.size as u32;
+                    if bits > 0 && bits < 128 {
+                        let sign_threshold = 1i128 << (bits - 1);
+                        if v >= sign_threshold {
+                            v -= 1i128 << bits;
+                        }
+                    }
+                }
+
+                v = v.clamp(tmin, tmax);
+                format!("{v}_{data_type}")
+            } else {
+                format!("{variant_id}_{data_type}")
+            }
+        },
+    }
+}
+
+impl ValCodeGen for ValDescription {
+    fn get_type_kamel(&self) -> String {
+        if needs_prefix(&self.description) {
+            format!("X{}", self.description).to_upper_camel_case()
+        } else {
+            // to_upper_camel_case() takes &self; no clone/owned needed
+            self.description.to_upper_camel_case()
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn get_data_value(&self, data: &str) -> String {
+        match data {
+            "bool" => (self.id == 1).to_string(),
+            "f64" => format!("{}_f64", self.id),
+            _ => format!("{}_{}", self.id, data),
+        }
+    }
+}
+
+impl ValCodeGen for Message {
+    fn get_type_kamel(&self) -> String {
+        dbc_ident(&self.name, ToUpperCamelCase::to_upper_camel_case)
+    }
+}
+
+impl SignalCodeGen for Signal {
+    fn le_start_end_bit(&self, msg: &Message) -> io::Result<(u64, u64)> {
+        let msg_bits = msg.size.checked_mul(8).ok_or_else(|| {
+            Error::other(format!(
+                "message:{} size overflow while computing bits (size:{} bytes)",
+                msg.name, msg.size
+            ))
+        })?;
+
+        let start_bit = self.start_bit;
+        let end_bit = self
+            .start_bit
+            .checked_add(self.size)
+            .ok_or_else(|| Error::other(format!("signal:{} end_bit overflow", self.name)))?;
+
+        if start_bit >= msg_bits {
+            return Err(Error::other(format!(
+                "signal:{} starts at {}, but message is only {} bits",
+                self.name, start_bit, msg_bits
+            )));
+        }
+
+        if end_bit > msg_bits {
+            return Err(Error::other(format!(
+                "signal:{} ends at {}, but message is only {} bits",
+                self.name, end_bit, msg_bits
+            )));
+        }
+
+        Ok((start_bit, end_bit))
+    }
+
+    fn be_start_end_bit(&self, msg: &Message) -> io::Result<(u64, u64)> {
+        let msg_bits = msg.size.checked_mul(8).ok_or_else(|| {
+            Error::other(format!(
+                "message:{} size overflow while computing bits (size:{} bytes)",
+                msg.name, msg.size
+            ))
+        })?;
+
+        let byte_base = self
+            .start_bit
+            .checked_div(8)
+            .and_then(|v| v.checked_mul(8))
+            .ok_or_else(|| Error::other(format!("signal:{} start_bit overflow", self.name)))?;
+
+        let bit_in_byte = self
+            .start_bit
+            .checked_rem(8)
+            .ok_or_else(|| Error::other(format!("signal:{} start_bit overflow", self.name)))?;
+
+        let bit_from_msb = 7u64
+            .checked_sub(bit_in_byte)
+            .ok_or_else(|| Error::other(format!("signal:{} start_bit overflow", self.name)))?;
+
+        let start_bit = byte_base
+            .checked_add(bit_from_msb)
+            .ok_or_else(|| Error::other(format!("signal:{} start_bit overflow", self.name)))?;
+
+        let end_bit = start_bit
+            .checked_add(self.size)
+            .ok_or_else(|| Error::other(format!("signal:{} end_bit overflow", self.name)))?;
+
+        if start_bit > msg_bits {
+            return Err(Error::other(format!(
+                "signal:{} starts at {}, but message is only {} bits",
+                self.name, start_bit, msg_bits
+            )));
+        }
+
+        if end_bit > msg_bits {
+            return Err(Error::other(format!(
+                "signal:{} ends at {}, but message is only {} bits",
+                self.name, end_bit, msg_bits
+            )));
+        }
+
+        Ok((start_bit, end_bit))
+    }
+
+    fn get_data_usize(&self) -> String {
+        let size = match self.size {
+            n if n <= 8 => "u8",
+            n if n <= 16 => "u16",
+            n if n <= 32 => "u32",
+            _ => "u64",
+        };
+        size.to_string()
+    }
+
+    fn get_data_isize(&self) -> String {
+        let size = match self.size {
+            n if n <= 8 => "i8",
+            n if n <= 16 => "i16",
+            n if n <= 32 => "i32",
+            _ => "i64",
+        };
+        size.to_string()
+    }
+
+    #[inline]
+    fn has_scaling(&self) -> bool {
+        const EPS: f64 = 1e-12;
+        self.offset.abs() > EPS || (self.factor - 1.0).abs() > EPS
+    }
+
+    #[inline]
+    fn is_byte_array(&self) -> bool {
+        self.size > 64
+    }
+
+    fn check_text(&self, msg: &Message) -> io::Result<()> {
+        if !self.size.is_multiple_of(8) || self.has_scaling() {
+            return Err(Error::other(format!(
+                "message:{} text signal:{} needs a whole number of bytes without factor/offset",
+                msg.get_type_kamel(),
+                self.name
+            )));
+        }
+        Ok(())
+    }
+
+    fn get_data_type(&self) -> String {
+        if self.is_byte_array() {
+            format!("[u8; {}]", self.size.div_ceil(8))
+        } else if self.size == 1 {
+            "bool".into()
+        } else if self.has_scaling() {
+            "f64".into()
+        } else {
+            let size = match self.size {
+                n if n <= 8 => "8",
+                n if n <= 16 => "16",
+                n if n <= 32 => "32",
+                _ => "64",
+            };
+            match self.value_type {
+                ValueType::Signed => format!("i{size}"),
+                ValueType::Unsigned => format!("u{size}"),
+            }
+        }
+    }
+
+    fn get_type_kamel(&self) -> String {
+        dbc_ident(&self.name, ToUpperCamelCase::to_upper_camel_case)
+    }
+
+    fn get_type_snake(&self) -> String {
+        dbc_ident(&self.name, ToSnakeCase::to_snake_case)
+    }
+}
+
+/// Generated statements turning `bytes` (frame order) into text: trailing NUL/space padding
+/// removed, non printable bytes shown as '.'.
+const TEXT_FROM_BYTES: &str = r#"let len = bytes.iter().rposition(|byte| *byte != 0 && *byte != b' ').map_or(0, |pos| pos + 1);
+            bytes[..len].iter().map(|byte| if byte.is_ascii_graphic() || *byte == b' ' { char::from(*byte) } else { '.' }).collect()"#;
+
+/// Body of the generated `physical_from_raw(raw)`: sign extension, then factor/offset.
+fn physical_from_raw_expr(sig: &Signal, float_size: Option<u64>) -> String {
+    let raw_ty = sig.get_data_usize();
+    if let Some(bits) = float_size {
+        let float = match bits {
+            32 => "f64::from(f32::from_bits(raw))",
+            _ => "f64::from_bits(raw)",
+        };
+        if sig.has_scaling() {
+            format!("{float} * {}_f64 + {}_f64", sig.factor, sig.offset)
+        } else {
+            float.to_string()
+        }
+    } else if sig.size == 1 {
+        "raw == 1".to_string()
+    } else if sig.has_scaling() {
+        if sig.value_type == ValueType::Signed {
+            let isz = sig.get_data_isize();
+            let factor = sig.factor;
+            let offset = sig.offset;
+            let size = sig.size;
+            format!(
+                r#"// Sign-extend raw from the DBC signal bit-width before scaling.
+            let shift = {raw_ty}::BITS - {size}u32;
+            let signed: {isz} = ((raw << shift) as {isz}) >> shift;
+            (signed as f64) * {factor}_f64 + {offset}_f64"#,
+            )
+        } else {
+            format!("(raw as f64) * {}_f64 + {}_f64", sig.factor, sig.offset)
+        }
+    } else if sig.value_type == ValueType::Signed {
+        // Sign-extend raw from the DBC signal bit-width (works for widths like 11 bits).
+        let isz = sig.get_data_isize();
+        let size = sig.size;
+        format!(
+            r#"let shift = {raw_ty}::BITS - {size}u32;
+            let signed: {isz} = ((raw << shift) as {isz}) >> shift;
+            signed"#
+        )
+    } else {
+        "raw".to_string()
+    }
+}
+
+impl SigCodeGen<&DbcCodeGen> for Signal {
+    #[allow(clippy::too_many_lines)]
+    fn gen_signal_trait(&self, code: &DbcCodeGen, msg: &Message) -> io::Result<()> {
+        let msg_type = msg.get_type_kamel();
+        let sig_type = self.get_type_kamel();
+        let raw_ty = self.get_data_usize();
+
+        let read_fn = match self.byte_order {
+            ByteOrder::LittleEndian => {
+                let (start_bit, end_bit) = self.le_start_end_bit(msg)?;
+                format!(
+                    "frame.data.view_bits::<Lsb0>()[{start}..{end}].load_le::<{typ}>()",
+                    typ = raw_ty,
+                    start = start_bit,
+                    end = end_bit,
+                )
+            },
+            ByteOrder::BigEndian => {
+                let (start_bit, end_bit) = self.be_start_end_bit(msg)?;
+                format!(
+                    "frame.data.view_bits::<Msb0>()[{start}..{end}].load_be::<{typ}>()",
+                    typ = raw_ty,
+                    start = start_bit,
+                    end = end_bit,
+                )
+            },
+        };
+
+        code_output!(
+            code,
+            format!(
+                r#"
+/// {msg_type}::{sig_type} public api (CanDbcSignal trait)
+impl CanDbcSignal for {sig_type} {{
+
+    fn get_name(&self) -> &'static str {{
+        self.name
+    }}
+
+    fn get_stamp(&self) -> u64 {{
+        self.stamp
+    }}
+
+    fn get_status(&self) -> CanDataStatus {{
+        self.status
+    }}
+
+    fn as_any(&mut self) -> &mut dyn Any {{
+        self
+    }}
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {{
+        match frame.opcode {{
+            CanBcmOpCode::RxChanged => {{
+                let raw: {raw_ty} = {read_fn};
+"#,
+                msg_type = msg_type,
+                sig_type = sig_type,
+                raw_ty = raw_ty,
+                read_fn = read_fn,
+            )
+        )?;
+
+        let data_type = code.data_type(msg, self);
+
+        let sna = code.sna.get(&(msg.id.raw(), self.name.clone()));
+        let domain = code.domains.contains_key(&(msg.id.raw(), self.name.clone()));
+        if domain && sna.is_some() {
+            code_output!(code, "                self.out_of_domain= false;")?;
+        }
+        match sna {
+            None => {},
+            Some(SignalSna::Raw(_)) => code_output!(
+                code,
+                r#"
+                if raw == Self::SNA_RAW {
+                    // signal not available: no physical value, sockcan has no dedicated status
+                    if !self.not_available {
+                        self.stamp= frame.stamp;
+                    }
+                    self.not_available= true;
+                    self.value= None;
+                    self.status= CanDataStatus::Unset;
+                } else {
+                    self.not_available= false;"#
+            )?,
+            Some(SignalSna::J1939 { .. }) => code_output!(
+                code,
+                r#"
+                if Self::J1939_NOT_AVAILABLE.contains(&raw) {
+                    // signal not available: no physical value, sockcan has no dedicated status
+                    if !self.not_available {
+                        self.stamp= frame.stamp;
+                    }
+                    self.not_available= true;
+                    self.error_indicator= false;
+                    self.value= None;
+                    self.status= CanDataStatus::Unset;
+                } else if Self::J1939_ERROR.contains(&raw) {
+                    // error indicator: the sender reports a fault on this parameter
+                    if !self.error_indicator {
+                        self.stamp= frame.stamp;
+                    }
+                    self.error_indicator= true;
+                    self.not_available= false;
+                    self.value= None;
+                    self.status= CanDataStatus::Error;
+                } else {
+                    self.not_available= false;
+                    self.error_indicator= false;"#
+            )?,
+        }
+
+        code_output!(
+            code,
+            format!(
+                r#"
+                    let newval = Self::physical_from_raw(raw);
+                    let changed = match self.value {{
+                        None => true,
+                        Some(old) => old != newval,
+                    }};
+                    self.value = Some(newval);
+                    if changed {{
+                        self.status= CanDataStatus::Updated;
+                        self.stamp= frame.stamp;
+                }} else {{
+                        self.status= CanDataStatus::Unchanged;
+                }}"#
+            )
+        )?;
+        if domain {
+            code_output!(
+                code,
+                r#"                    self.out_of_domain= !Self::DOMAIN_RAW.contains(&raw);
+                    if self.out_of_domain {
+                        // raw value missing from the VAL_ table: the DBC and the sender disagree
+                        self.status= CanDataStatus::Error;
+                    }"#
+            )?;
+        }
+        if sna.is_some() {
+            code_output!(code, "                }")?;
+        }
+
+        let dtype_enum = data_type.as_str().to_upper_camel_case();
+        let trace = code.trace("fail to get signal callback reference");
+
+        code_output!(
+            code,
+            format!(
+                r#"
+                }},
+                CanBcmOpCode::RxTimeout => {{
+                    self.status=CanDataStatus::Timeout;
+                }},
+                _ => {{
+                    self.status=CanDataStatus::Error;
+                }},
+            }}
+            match &self.callback {{
+                None => 0,
+                Some(callback) => {{
+                    match callback.try_borrow() {{
+                        Err(_) => {{{trace} -1}},
+                        Ok(cb_ref) => cb_ref.sig_notification(self),
+                    }}
+                }}
+            }}
+        }}
+
+        fn set_value(&mut self, value:CanDbcType, data:&mut [u8]) -> Result<(),CanError> {{
+            let value:{data_type}= match value.cast() {{
+                Ok(val) => val,
+                Err(error) => return Err(error)
+            }};
+            self.set_physical(value, data)
+        }}
+
+        fn get_value(&self) -> CanDbcType {{
+            CanDbcType::{dtype_enum}(self.get_typed_value())
+        }}
+{meta_impl}"#,
+                meta_impl = signal_meta_impl(code),
+            )
+        )?;
+
+        if code.serde_json_for(msg, self) {
+            code_output!(
+                code,
+                format!(
+                    r#"
+        {}fn to_json(&self) -> String {{
+            match serde_json::to_string(self) {{
+                Ok(json)=> json,
+                _ => "serde-json-error".to_owned()
+            }}
+        }}
+"#,
+                    code.serde_cfg("        ")
+                )
+            )?;
+        } else if code.serde_json {
+            // excluded from serde, keeps MessageState valid JSON
+            code_output!(
+                code,
+                format!(
+                    r#"
+        {}fn to_json(&self) -> String {{
+            "null".to_owned()
+        }}
+"#,
+                    code.serde_cfg("        ")
+                )
+            )?;
+        }
+
+        // reset signal values + set signal notification callback + impl footer
+        code_output!(
+            code,
+            format!(
+                r#"
+        fn reset(&mut self) {{
+            self.stamp=0;
+            self.reset_value();
+            self.status=CanDataStatus::Unset;
+        }}
+
+        fn set_callback(&mut self, callback: Box<dyn CanSigCtrl>)  {{
+            self.callback= Some(RefCell::new(callback));
+        }}
+
+    }} // end {msg_type}::{sig_type} public api
+"#
+            )
+        )?;
+
+        Ok(())
+    }
+
+    fn gen_dbc_min_max(&self, code: &DbcCodeGen, msg: &Message) -> io::Result<()> {
+        if self.size == 1 {
+            return Ok(());
+        }
+
+        let typ = code.data_type(msg, self);
+        let name_uc = self.get_type_kamel().to_uppercase();
+        let min = self.min;
+        let max = self.max;
+
+        //  Clamp to type limits to avoid overflowing literals (e.g. 4294970000_u32).
+        let (min_expr, max_expr) = if typ == "f64" {
+            (format!("{min}_f64"), format!("{max}_f64"))
+        } else {
+            (bound_expr(min, &typ), bound_expr(max, &typ))
+        };
+
+        code_output!(
+            code,
+            format!(
+                r#"
+        pub const {name_uc}_MIN: {typ} = {min_expr};
+        pub const {name_uc}_MAX: {typ} = {max_expr};
+"#
+            )
+        )?;
+        Ok(())
+    }
+
+    fn gen_signal_enum(&self, code: &DbcCodeGen, msg: &Message) -> io::Result<()> {
+        if let Some(variants) = code.dbcfd.value_descriptions_for_signal(msg.id, self.name.as_str())
+        {
+            let id = msg.id.raw();
+            let name = self.name.as_str();
+            let type_kamel = self.get_type_kamel();
+            code_output!(code, format!(r#"    // DBC definition for MsgID:{id} Signal:{name}"#))?;
+            code_output!(code, format!(r#"    /// {type_kamel} values (DBC VAL_)"#))?;
+            code_output!(code, value_table_doc(variants, "    "))?;
+            if code.serde_json_for(msg, self) {
+                code_output!(
+                    code,
+                    format!("    {}", code.serde_attr("derive(Serialize, Deserialize)"))
+                )?;
+            }
+            code_output!(code, format!(r#"    pub enum Dbc{type_kamel} {{"#))?;
+            for variant in variants {
+                let variant_name = variant.get_type_kamel();
+                code_output!(code, format!(r#"        {variant_name},"#))?;
+            }
+
+            let data_type = code.data_type(msg, self);
+            code_output!(
+                code,
+                format!(
+                    r#"
+        _Other({data_type}),
+    }}
+
+    impl From<Dbc{type_kamel}> for {data_type} {{
+        fn from (val: Dbc{type_kamel}) -> {data_type} {{
+            match val {{"#
+                )
+            )?;
+            for variant in variants {
+                let type_kamel = self.get_type_kamel();
+                let variant_type_kamel = variant.get_type_kamel();
+                let variant_data_type = variant_typed_literal(self, variant.id, &data_type);
+                code_output!(
+                    code,
+                    format!(
+                        r#"                Dbc{type_kamel}::{variant_type_kamel} => {variant_data_type},"#
+                    )
+                )?;
+            }
+            let type_kamel = self.get_type_kamel();
+            code_output!(
+                code,
+                format!(
+                    r#"
+                Dbc{type_kamel}::_Other(x) => x
+            }}
+        }}
+    }}
+"#
+                )
+            )?;
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn gen_signal_impl(&self, code: &DbcCodeGen, msg: &Message) -> io::Result<()> {
+        // signal comments and metadata
+        let msg_type_kamel = msg.get_type_kamel();
+        let min = self.min;
+        let max = self.max;
+        let unit = self.unit.as_str();
+        let receivers = self.receivers.join(", ");
+        let start_bit = self.start_bit;
+        let size = self.size;
+        let factor = self.factor;
+        let offset = self.offset;
+        let byte_order = self.byte_order;
+        let value_type = self.value_type;
+
+        let type_kamel = self.get_type_kamel();
+
+        let float_size = code.float_size(msg, self);
+        if float_size.is_some_and(|bits| bits != self.size) {
+            return Err(Error::other(format!(
+                "message:{} SIG_VALTYPE_ float signal:{} needs {} bits, not {}",
+                msg_type_kamel,
+                self.name,
+                float_size.unwrap_or_default(),
+                self.size
+            )));
+        }
+        let data_type = code.data_type(msg, self);
+        let data_usize = self.get_data_usize();
+
+        code_output!(code, format!(r#"    /// {msg_type_kamel}::{type_kamel}"#))?;
+        if let Some(comment) = code.dbcfd.signal_comment(msg.id, self.name.as_str()) {
+            code_output!(code, r#"    ///"#)?;
+
+            for line in comment.trim().lines() {
+                code_output!(code, format!(r#"    /// {line}"#))?;
+            }
+        }
+
+        code_output!(
+            code,
+            format!(
+                r#"
+    /// - Min: {min}
+    /// - Max: {max}
+    /// - Unit: {unit:?}
+    /// - Receivers: {receivers}
+    /// - Start bit: {start_bit}
+    /// - Signal size: {size} bits
+    /// - Factor: {factor}
+    /// - Offset: {offset}
+    /// - Byte order: {byte_order:?}
+    /// - Value type: {value_type:?}"#
+            )
+        )?;
+        if let Some(bits) = float_size {
+            code_output!(code, format!(r#"    /// - IEEE 754: {bits} bits float"#))?;
+        }
+        if let Some(variants) = code.dbcfd.value_descriptions_for_signal(msg.id, self.name.as_str())
+        {
+            code_output!(code, value_table_doc(variants, "    "))?;
+        }
+
+        let serde_json = code.serde_json_for(msg, self);
+        if serde_json {
+            code_output!(
+                code,
+                format!("    {}", code.serde_attr("derive(Serialize, Deserialize)"))
+            )?;
+        }
+        code_output!(code, format!(r#"    pub struct {type_kamel} {{"#))?;
+
+        if serde_json {
+            code_output!(code, format!("        {}", code.serde_attr("serde(skip)")))?;
+        }
+        let sna = code.sna.get(&(msg.id.raw(), self.name.clone()));
+        let (sna_field, sna_init, sna_reset) = match sna {
+            Some(SignalSna::Raw(_)) => (
+                "\n        not_available: bool,",
+                "\n                not_available: false,",
+                "\n            self.not_available= false;",
+            ),
+            Some(SignalSna::J1939 { .. }) => (
+                "\n        not_available: bool,\n        error_indicator: bool,",
+                "\n                not_available: false,\n                error_indicator: false,",
+                "\n            self.not_available= false;\n            self.error_indicator= false;",
+            ),
+            None => ("", "", ""),
+        };
+        let domain = code.domains.get(&(msg.id.raw(), self.name.clone()));
+        let (domain_field, domain_init, domain_reset) = match domain {
+            Some(_) => (
+                "\n        out_of_domain: bool,",
+                "\n                out_of_domain: false,",
+                "\n            self.out_of_domain= false;",
+            ),
+            None => ("", "", ""),
+        };
+        code_output!(
+            code,
+            format!(
+                r#"
+        callback: Option<RefCell<Box<dyn CanSigCtrl>>>,
+        status: CanDataStatus,
+        name: &'static str,
+        stamp: u64,
+        value: Option<{data_type}>,{sna_field}{domain_field}
+    }}
+"#
+            )
+        )?;
+
+        self.gen_signal_enum(code, msg)?;
+
+        let receiver_list = signal_receivers(self);
+        let receiver_count = receiver_list.len();
+        let raw_ty = self.get_data_usize();
+        let sna_api = match sna {
+            Some(SignalSna::Raw(raw)) => format!(
+                r#"
+
+        /// Raw value meaning "signal not available" (SNA)
+        pub const SNA_RAW: {raw_ty} = {raw};
+
+        /// True when the last frame carried SNA_RAW: the value is cleared and the status Unset
+        pub fn is_not_available(&self) -> bool {{
+            self.not_available
+        }}"#
+            ),
+            Some(SignalSna::J1939 { error, not_available }) => format!(
+                r#"
+
+        /// J1939 raw range meaning "error indicator"
+        pub const J1939_ERROR: {core}::ops::RangeInclusive<{raw_ty}> = {:#x}..={:#x};
+        /// J1939 raw range meaning "not available"
+        pub const J1939_NOT_AVAILABLE: {core}::ops::RangeInclusive<{raw_ty}> = {:#x}..={:#x};
+
+        /// True when the last frame carried a J1939_NOT_AVAILABLE value: the value is cleared and the status Unset
+        pub fn is_not_available(&self) -> bool {{
+            self.not_available
+        }}
+
+        /// True when the last frame carried a J1939_ERROR value: the value is cleared and the status Error
+        pub fn is_error_indicator(&self) -> bool {{
+            self.error_indicator
+        }}"#,
+                error.0,
+                error.1,
+                not_available.0,
+                not_available.1,
+                core = code.core_crate(),
+            ),
+            None => String::new(),
+        };
+        let start_value = code.start_values.get(&(msg.id.raw(), self.name.clone()));
+        let start_api = match start_value {
+            Some(raw) => format!(
+                r#"
+
+        /// Value read before any frame and after `reset()`, from the GenSigStartValue attribute (raw {raw})
+        pub const START_VALUE: {data_type} = {};"#,
+                start_value_literal(msg, self, &data_type, float_size, *raw)?
+            ),
+            None => String::new(),
+        };
+        let domain_api = match domain {
+            Some(raws) => format!(
+                r#"
+
+        /// Raw values of the VAL_ table, others set the status to Error
+        pub const DOMAIN_RAW: [{raw_ty}; {}] = {raws:?};
+
+        /// True when the last frame carried a raw value missing from DOMAIN_RAW: the value is decoded anyway
+        pub fn is_out_of_domain(&self) -> bool {{
+            self.out_of_domain
+        }}"#,
+                raws.len()
+            ),
+            None => String::new(),
+        };
+        let spn_api = match code.spns.get(&(msg.id.raw(), self.name.clone())) {
+            Some(spn) => format!(
+                r#"
+
+        /// J1939 suspect parameter number, from the SPN attribute
+        pub const SPN: u32 = {spn};
+
+        pub fn get_spn(&self) -> u32 {{
+            Self::SPN
+        }}"#
+            ),
+            None => String::new(),
+        };
+        let attribute_api = attribute_api(
+            code,
+            AttributeScope::Signal,
+            code.signal_attributes
+                .get(&(msg.id.raw(), self.name.clone()))
+                .map_or(&[][..], Vec::as_slice),
+        );
+
+        let meta_api = signal_meta_api(self);
+
+        // start signal implementation
+        code_output!(
+            code,
+            format!(
+                r#"
+    impl {type_kamel}  {{
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; {receiver_count}] = {receiver_list:?};{meta_api}{sna_api}{domain_api}{start_api}{spn_api}{attribute_api}
+
+        pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {{
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }}
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {{
+            {type_kamel} {{
+                status: CanDataStatus::Unset,
+                name:"{type_kamel}",
+                value: None,
+                stamp: 0,
+                callback: None,{sna_init}{domain_init}
+            }}
+        }}
+
+        fn reset_value(&mut self) {{
+            self.value= None;{sna_reset}{domain_reset}
+            }}"#
+            )
+        )?;
+
+        if let Some(variants) = code.dbcfd.value_descriptions_for_signal(msg.id, self.name.as_str())
+        {
+            code_output!(
+                code,
+                format!(r#"        pub fn get_as_def (&self) -> Dbc{type_kamel} {{"#)
+            )?;
+
+            // float is not compatible with match
+            if data_type == "f64" {
+                code_output!(
+                    code,
+                    format!(r#"                Dbc{type_kamel}::_Other(self.get_typed_value())"#)
+                )?;
+            } else {
+                let mut count = 0;
+                code_output!(code, r#"            match self.get_typed_value() {"#)?;
+                for variant in variants {
+                    count += 1;
+
+                    let data_value = variant_typed_literal(self, variant.id, &data_type);
+                    let variant_type_kamel = variant.get_type_kamel();
+                    code_output!(
+                        code,
+                        format!(
+                            r#"                {data_value} => Dbc{type_kamel}::{variant_type_kamel},"#
+                        )
+                    )?;
+                }
+
+                // Help in buggy DBC file support
+                if count != 2 || self.size != 1 {
+                    code_output!(
+                        code,
+                        format!(
+                            r#"                _ => Dbc{type_kamel}::_Other(self.get_typed_value()),"#
+                        )
+                    )?;
+                }
+                code_output!(code, r#"            }"#)?;
+            }
+            code_output!(
+                code,
+                format!(
+                    r#"
+        }}
+        #[deprecated(note = "use set_raw()")]
+        pub fn set_raw_value(&mut self, value: {data_usize}, data: &mut[u8]) {{
+            self.set_raw(value, data)
+        }}
+        pub fn set_as_def (&mut self, signal_def: Dbc{type_kamel}, data: &mut[u8])-> Result<(),CanError> {{
+            match signal_def {{"#
+                )
+            )?;
+            for variant in variants {
+                let variant_type_kamel = variant.get_type_kamel();
+                let data_value = variant.id;
+                code_output!(
+                    code,
+                    format!(
+                        r#"                Dbc{type_kamel}::{variant_type_kamel} => Ok(self.set_raw({data_value}, data)),"#
+                    )
+                )?;
+            }
+            code_output!(
+                code,
+                format!(
+                    r#"                Dbc{type_kamel}::_Other(x) => self.set_physical(x,data)"#
+                )
+            )?;
+            code_output!(
+                code,
+                r#"            }
+        }"#
+            )?;
+        }
+
+        // scaling-free conversions only do integer ops, callers can fold them at compile time
+        let constness = if data_type == "f64" { "" } else { "const " };
+        let from_raw = physical_from_raw_expr(self, float_size);
+        let unset_value = match start_value {
+            Some(_) => "unwrap_or(Self::START_VALUE)",
+            None => "unwrap_or_default()",
+        };
+        code_output!(
+            code,
+            format!(
+                r#"
+        #[inline]
+        fn get_typed_value(&self) -> {data_type} {{
+            self.value.{unset_value}
+        }}
+
+        /// Physical value of the raw signal bits (sign extended, then scaled)
+        #[inline]
+        pub {constness}fn physical_from_raw(raw: {raw_ty}) -> {data_type} {{
+            {from_raw}
+        }}
+
+        /// Raw signal bits of the physical `value` (scaled, then masked to the signal bit-length)
+        #[inline]
+        pub {constness}fn raw_from_physical(value: {data_type}) -> {data_usize} {{"#
+            )
+        )?;
+
+        if let Some(bits) = float_size {
+            let value = if self.has_scaling() {
+                let factor = self.factor;
+                let offset = self.offset;
+                code_output!(
+                    code,
+                    format!(
+                        r#"            let factor = {factor}_f64;
+            let offset = {offset}_f64;
+            let __raw_f = (value - offset) / factor;"#
+                    )
+                )?;
+                "__raw_f"
+            } else {
+                "value"
+            };
+            let bits_expr = if bits == 32 {
+                format!("({value} as f32).to_bits()")
+            } else {
+                format!("{value}.to_bits()")
+            };
+            code_output!(
+                code,
+                format!(
+                    r#"            //  IEEE 754 {bits} bits, SIG_VALTYPE_ float signal.
+            {bits_expr}"#
+                )
+            )?;
+        } else if self.size == 1 {
+            code_output!(code, r#"            value as u8"#)?;
+        } else {
+            let bits = self.size;
+            code_output!(
+                code,
+                format!(
+                    r#"            //  Mask to the signal bit-length (prevents leaking upper bits).
+            let __mask: u64 = if {bits} == 64 {{ u64::MAX }} else {{ (1u64 << {bits}) - 1 }};"#
+                )
+            )?;
+
+            if self.has_scaling() {
+                let factor = self.factor;
+                let offset = self.offset;
+                code_output!(
+                    code,
+                    format!(
+                        r#"            let factor = {factor}_f64;
+            let offset = {offset}_f64;
+            let __raw_f = (value - offset) / factor;"#
+                    )
+                )?;
+
+                if self.value_type == ValueType::Signed {
+                    code_output!(
+                        code,
+                        format!(
+                            r#"            //  Encode signed value as two's complement on {bits} bits.
+            ((__raw_f as i64 as u64) & __mask) as {data_usize}"#
+                        )
+                    )?;
+                } else {
+                    code_output!(
+                        code,
+                        format!(r#"            ((__raw_f as u64) & __mask) as {data_usize}"#)
+                    )?;
+                }
+            } else if self.value_type == ValueType::Signed {
+                code_output!(
+                    code,
+                    format!(
+                        r#"            //  Encode signed integer as two's complement on {bits} bits.
+            ((value as i64 as u64) & __mask) as {data_usize}"#
+                    )
+                )?;
+            } else {
+                code_output!(
+                    code,
+                    format!(r#"            ((value as u64) & __mask) as {data_usize}"#)
+                )?;
+            }
+        }
+
+        code_output!(
+            code,
+            format!(
+                r#"        }}
+
+        /// Encode the physical `value` (range checked, scaled to raw) into the signal bits of `data`
+        #[inline]
+        pub fn set_physical(&mut self, value:{data_type}, data:&mut [u8]) -> Result<(),CanError> {{"#
+            )
+        )?;
+
+        // shared by set_physical() and the stateless encode()
+        let range_check = if self.size != 1 && code.range_check_for(msg, self) {
+            let min_expr = bound_expr(min, &data_type);
+            let max_expr = bound_expr(max, &data_type);
+            format!(
+                r#"
+            if value < {min_expr} || {max_expr} < value {{
+                return Err(CanError::new("invalid-signal-value",format!("value={{}} not in [{min}..{max}]",value)));
+            }}"#
+            )
+        } else {
+            String::new()
+        };
+        if !range_check.is_empty() {
+            code_output!(
+                code,
+                format!(
+                    r#"            // Range-check clamped to the target type limits (avoids overflowing literals).{range_check}"#
+                )
+            )?;
+        }
+
+        let store = format!("            {}", raw_store_expr(self, msg, "data", "value")?);
+
+        let msg_type = msg.get_type_kamel();
+        let sig_type = self.get_type_kamel();
+        let read_fn = raw_read_expr(self, msg)?.replace("frame.data", "data");
+
+        code_output!(
+            code,
+            format!(
+                r#"            self.set_raw(Self::raw_from_physical(value), data);
+            Ok(())
+        }}
+
+        /// Write the raw `value` into the signal bits of `data`, without range check nor scaling
+        #[inline]
+        pub fn set_raw(&mut self, value: {data_usize}, data: &mut[u8]) {{
+{store}
+        }}
+
+        /// Encode the physical `value` into `data` like `set_physical()`, without signal object
+        #[inline]
+        pub fn encode(value:{data_type}, data:&mut [u8]) -> Result<(),CanError> {{{range_check}
+            let value = Self::raw_from_physical(value);
+{store}
+            Ok(())
+        }}
+
+        /// Physical value of the signal bits of `data`, without signal object
+        #[inline]
+        pub fn decode(data: &[u8]) -> {data_type} {{
+            Self::physical_from_raw({read_fn})
+        }}"#
+            )
+        )?;
+
+        // read-modify-write of one signal in a forwarded frame
+        let size = msg.size;
+        let set_fail = CanErrorCode::SignalSetValuesFail.uid();
+        let page_check = match (self.multiplexer_indicator, find_mux_idx(msg)?) {
+            (MultiplexIndicator::MultiplexedSignal(page), Some(mux_idx)) => {
+                let mux_type = msg.signals[mux_idx].get_type_kamel();
+                format!(
+                    r#"
+            let __mux_raw_value = {mux_type}::raw_from_physical({mux_type}::decode(data)) as u64;
+            if __mux_raw_value != {page} {{
+                return Err(CanError::new("{set_fail}", format!("{msg_type}.{sig_type} is on page {page}, frame multiplexor selects {{__mux_raw_value}}")));
+            }}"#
+                )
+            },
+            _ => String::new(),
+        };
+        code_output!(
+            code,
+            format!(
+                r#"
+        /// Encode the physical `value` into the signal bits of an existing `data` frame, leaving
+        /// the other signals untouched. Fails on a frame shorter than the message{page_doc}
+        pub fn set_signal_into(value:{data_type}, data:&mut [u8]) -> Result<(),CanError> {{
+            if data.len() < {size} {{
+                return Err(CanError::new("{set_fail}", format!("{msg_type} payload len:{{}} shorter than {size} bytes", data.len())));
+            }}{page_check}
+            Self::encode(value, data)
+        }}"#,
+                page_doc = if page_check.is_empty() {
+                    "."
+                } else {
+                    ",\n        /// or when the frame multiplexor selects another page than the one of this signal."
+                },
+            )
+        )?;
+
+        if code.text_for(msg, self) {
+            self.check_text(msg)?;
+            // characters in frame order: the raw low byte comes first on Intel signals
+            let bytes = self.size / 8;
+            let (to_bytes, from_bytes, range) = match self.byte_order {
+                ByteOrder::LittleEndian => ("to_le_bytes", "from_le_bytes", format!("..{bytes}")),
+                ByteOrder::BigEndian => {
+                    ("to_be_bytes", "from_be_bytes", format!("{}..", 8 - bytes))
+                },
+            };
+            let start = if self.byte_order == ByteOrder::BigEndian { 8 - bytes } else { 0 };
+            code_output!(
+                code,
+                format!(
+                    r#"
+        /// Signal bytes as text, without trailing NUL/space padding, non printable bytes as '.'
+        pub fn get_text(&self) -> String {{
+            let raw = Self::raw_from_physical(self.get_typed_value()) as u64;
+            let bytes = &raw.{to_bytes}()[{range}];
+            {TEXT_FROM_BYTES}
+        }}
+
+        /// Write the ASCII `text`, NUL padded, into the signal bits of `data`
+        pub fn set_text(&mut self, text: &str, data: &mut [u8]) -> Result<(),CanError> {{
+            if !text.is_ascii() || text.len() > {bytes} {{
+                return Err(CanError::new("invalid-signal-value",format!("value={{:?}} not {bytes} ascii chars max",text)));
+            }}
+            let mut bytes = [0u8; 8];
+            bytes[{start}..{start} + text.len()].copy_from_slice(text.as_bytes());
+            self.set_raw(u64::{from_bytes}(bytes) as {data_usize}, data);
+            Ok(())
+        }}"#
+                )
+            )?;
+        }
+
+        code_output!(
+            code,
+            format!(
+                r#"
+    }} // {msg_type}::{sig_type} impl end
+"#
+            )
+        )?;
+
+        Ok(())
+    }
+
+    fn gen_can_std_frame(&self, _code: &DbcCodeGen, _msg: &Message) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn gen_can_any_frame(&self, code: &DbcCodeGen, msg: &Message) -> io::Result<()> {
+        match self.multiplexer_indicator {
+            MultiplexIndicator::Plain => self.gen_can_std_frame(code, msg)?,
+            MultiplexIndicator::Multiplexor
+            | MultiplexIndicator::MultiplexedSignal(_)
+            | MultiplexIndicator::MultiplexorAndMultiplexedSignal(_) => {
+                // (optional) any shared handling for multiplexed cases
+            },
+        }
+        let sig_type = self.get_type_kamel();
+
+        code_output!(
+            code,
+            format!(
+                r#"
+    impl fmt::Display for {sig_type} {{
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {{
+            let text=format!("{sig_type}:{{}}", self.get_typed_value());
+            fmt.pad(&text)
+        }}
+    }}
+
+    impl fmt::Debug for {sig_type} {{
+        fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {{
+            format.debug_struct("{sig_type}")
+                .field("val", &self.get_typed_value())
+                .field("stamp", &self.get_stamp())
+                .field("status", &self.get_status())
+                .finish()
+        }}
+    }}
+"#
+            )
+        )?;
+
+        Ok(())
+    }
+
+    fn gen_code_signal(&self, code: &DbcCodeGen, msg: &Message) -> io::Result<()> {
+        if self.is_byte_array() {
+            return self.gen_byte_array_signal(code, msg);
+        }
+        self.gen_signal_impl(code, msg)?;
+        self.gen_can_any_frame(code, msg)?;
+        self.gen_signal_trait(code, msg)?;
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn gen_byte_array_signal(&self, code: &DbcCodeGen, msg: &Message) -> io::Result<()> {
+        let meta_api = signal_meta_api(self);
+        let attribute_api = attribute_api(
+            code,
+            AttributeScope::Signal,
+            code.signal_attributes
+                .get(&(msg.id.raw(), self.name.clone()))
+                .map_or(&[][..], Vec::as_slice),
+        );
+        let msg_type = msg.get_type_kamel();
+        let sig_type = self.get_type_kamel();
+        let bytes = self.size.div_ceil(8);
+        let (order, start, end, load, store) = match self.byte_order {
+            ByteOrder::LittleEndian => {
+                let (start, end) = self.le_start_end_bit(msg)?;
+                ("Lsb0", start, end, "load_le", "store_le")
+            },
+            ByteOrder::BigEndian => {
+                let (start, end) = self.be_start_end_bit(msg)?;
+                ("Msb0", start, end, "load_be", "store_be")
+            },
+        };
+
+        let text_api = if code.text_for(msg, self) {
+            self.check_text(msg)?;
+            r#"
+
+        /// Text signal: same as to_ascii()
+        pub fn get_text(&self) -> String {
+            self.to_ascii()
+        }
+
+        /// Text signal: same as set_ascii()
+        pub fn set_text(&mut self, text: &str, data: &mut [u8]) -> Result<(),CanError> {
+            self.set_ascii(text, data)
+        }"#
+        } else {
+            ""
+        };
+
+        code_output!(code, format!(r#"    /// {msg_type}::{sig_type}"#))?;
+        if let Some(comment) = code.dbcfd.signal_comment(msg.id, self.name.as_str()) {
+            code_output!(code, r#"    ///"#)?;
+            for line in comment.trim().lines() {
+                code_output!(code, format!(r#"    /// {line}"#))?;
+            }
+        }
+        let receiver_list = signal_receivers(self);
+        code_output!(
+            code,
+            format!(
+                r#"
     /// - Receivers: {receivers}
     /// - Start bit: {start_bit}
-    /// - Signal size: {size} bits
-    /// - Factor: {factor}
-    /// - Offset: {offset}
+    /// - Signal size: {size} bits, {bytes} bytes in frame byte order
     /// - Byte order: {byte_order:?}
-    /// - Value type: {value_type:?}"#
+    pub struct {sig_type} {{
+        callback: Option<RefCell<Box<dyn CanSigCtrl>>>,
+        status: CanDataStatus,
+        name: &'static str,
+        stamp: u64,
+        value: Option<[u8; {bytes}]>,
+    }}
+
+    impl {sig_type} {{
+        /// Nodes consuming this signal (SG_ receivers)
+        pub const RECEIVERS: [&'static str; {receiver_count}] = {receiver_list:?};{meta_api}{attribute_api}
+
+        pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {{
+            Rc::new(RefCell::new(Box::new(Self::init())))
+        }}
+
+        /// Signal without value, as held by `StaticMessage`
+        pub fn init() -> Self {{
+            {sig_type} {{
+                status: CanDataStatus::Unset,
+                name:"{sig_type}",
+                value: None,
+                stamp: 0,
+                callback: None,
+            }}
+        }}
+
+        fn reset_value(&mut self) {{
+            self.value= None;
+        }}
+
+        /// Signal bytes in frame order, zeros until a frame is received
+        pub fn get_bytes(&self) -> [u8; {bytes}] {{
+            self.value.unwrap_or([0; {bytes}])
+        }}
+
+        /// Signal bytes as lowercase hexadecimal
+        pub fn to_hex(&self) -> String {{
+            self.get_bytes().iter().map(|byte| format!("{{byte:02x}}")).collect()
+        }}
+
+        /// Signal bytes as text, without trailing NUL/space padding, non printable bytes as '.'
+        pub fn to_ascii(&self) -> String {{
+            let bytes = self.get_bytes();
+            {TEXT_FROM_BYTES}
+        }}
+
+        fn store_bytes(value: &[u8; {bytes}], data: &mut [u8]) {{
+            let bits = data.view_bits_mut::<{order}>();
+            for (idx, byte) in value.iter().enumerate() {{
+                let start = {start} + idx * 8;
+                bits[start..(start + 8).min({end})].{store}(*byte);
+            }}
+        }}
+
+        /// Write `value`, in frame byte order, into the signal bits of `data`
+        pub fn set_bytes(&mut self, value: &[u8; {bytes}], data: &mut [u8]) {{
+            Self::store_bytes(value, data)
+        }}
+
+        /// Write the ASCII `text`, NUL padded, into the signal bits of `data`
+        pub fn set_ascii(&mut self, text: &str, data: &mut [u8]) -> Result<(),CanError> {{
+            if !text.is_ascii() || text.len() > {bytes} {{
+                return Err(CanError::new("invalid-signal-value",format!("value={{:?}} not {bytes} ascii chars max",text)));
+            }}
+            let mut value = [0u8; {bytes}];
+            value[..text.len()].copy_from_slice(text.as_bytes());
+            Self::store_bytes(&value, data);
+            Ok(())
+        }}{text_api}
+
+    }} // {msg_type}::{sig_type} impl end
+
+    impl fmt::Display for {sig_type} {{
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {{
+            let text=format!("{sig_type}:{{}}", self.to_hex());
+            fmt.pad(&text)
+        }}
+    }}
+
+    impl fmt::Debug for {sig_type} {{
+        fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {{
+            format.debug_struct("{sig_type}")
+                .field("val", &self.to_hex())
+                .field("stamp", &self.get_stamp())
+                .field("status", &self.get_status())
+                .finish()
+        }}
+    }}
+"#,
+                text_api = text_api,
+                receivers = self.receivers.join(", "),
+                start_bit = self.start_bit,
+                size = self.size,
+                byte_order = self.byte_order,
+                receiver_count = receiver_list.len(),
+            )
+        )?;
+
+        let serde_json = code.serde_json_for(msg, self);
+        if serde_json {
+            // serde derives stop at 32 elements arrays, the value is serialized as hex
+            code_output!(
+                code,
+                format!(
+                    r#"
+    {cfg}impl Serialize for {sig_type} {{
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+            use serde::ser::SerializeStruct;
+            let mut state = serializer.serialize_struct("{sig_type}", 4)?;
+            state.serialize_field("status", &self.status)?;
+            state.serialize_field("name", self.name)?;
+            state.serialize_field("stamp", &self.stamp)?;
+            state.serialize_field("value", &self.value.map(|_| self.to_hex()))?;
+            state.end()
+        }}
+    }}
+"#,
+                    cfg = code.serde_cfg("    "),
+                )
+            )?;
+        }
+
+        let trace = code.trace("fail to get signal callback reference");
+        code_output!(
+            code,
+            format!(
+                r#"
+/// {msg_type}::{sig_type} public api (CanDbcSignal trait)
+impl CanDbcSignal for {sig_type} {{
+
+    fn get_name(&self) -> &'static str {{
+        self.name
+    }}
+
+    fn get_stamp(&self) -> u64 {{
+        self.stamp
+    }}
+
+    fn get_status(&self) -> CanDataStatus {{
+        self.status
+    }}
+
+    fn as_any(&mut self) -> &mut dyn Any {{
+        self
+    }}
+
+    fn update(&mut self, frame: &CanMsgData) -> i32 {{
+        match frame.opcode {{
+            CanBcmOpCode::RxChanged => {{
+                let bits = frame.data.view_bits::<{order}>();
+                let mut newval = [0u8; {bytes}];
+                for (idx, byte) in newval.iter_mut().enumerate() {{
+                    let start = {start} + idx * 8;
+                    *byte = bits[start..(start + 8).min({end})].{load}::<u8>();
+                }}
+                if self.value != Some(newval) {{
+                    self.value = Some(newval);
+                    self.status= CanDataStatus::Updated;
+                    self.stamp= frame.stamp;
+                }} else {{
+                    self.status= CanDataStatus::Unchanged;
+                }}
+                }},
+                CanBcmOpCode::RxTimeout => {{
+                    self.status=CanDataStatus::Timeout;
+                }},
+                _ => {{
+                    self.status=CanDataStatus::Error;
+                }},
+            }}
+            match &self.callback {{
+                None => 0,
+                Some(callback) => {{
+                    match callback.try_borrow() {{
+                        Err(_) => {{{trace} -1}},
+                        Ok(cb_ref) => cb_ref.sig_notification(self),
+                    }}
+                }}
+            }}
+        }}
+
+        fn set_value(&mut self, _value:CanDbcType, _data:&mut [u8]) -> Result<(),CanError> {{
+            Err(CanError::new("invalid-signal-value","{sig_type} is a byte array, use set_bytes()"))
+        }}
+
+        /// CanDbcType has no byte-array variant: the first 8 bytes as a big-endian U64
+        fn get_value(&self) -> CanDbcType {{
+            let mut head = [0u8; 8];
+            head.copy_from_slice(&self.get_bytes()[..8]);
+            CanDbcType::U64(u64::from_be_bytes(head))
+        }}
+{meta_impl}"#,
+                meta_impl = signal_meta_impl(code),
+            )
+        )?;
+
+        if serde_json {
+            code_output!(
+                code,
+                format!(
+                    r#"
+        {}fn to_json(&self) -> String {{
+            match serde_json::to_string(self) {{
+                Ok(json)=> json,
+                _ => "serde-json-error".to_owned()
+            }}
+        }}
+"#,
+                    code.serde_cfg("        ")
+                )
+            )?;
+        } else if code.serde_json {
+            code_output!(
+                code,
+                format!(
+                    r#"
+        {}fn to_json(&self) -> String {{
+            "null".to_owned()
+        }}
+"#,
+                    code.serde_cfg("        ")
+                )
+            )?;
+        }
+
+        code_output!(
+            code,
+            format!(
+                r#"
+        fn reset(&mut self) {{
+            self.stamp=0;
+            self.reset_value();
+            self.status=CanDataStatus::Unset;
+        }}
+
+        fn set_callback(&mut self, callback: Box<dyn CanSigCtrl>)  {{
+            self.callback= Some(RefCell::new(callback));
+        }}
+
+    }} // end {msg_type}::{sig_type} public api
+"#
+            )
+        )
+    }
+}
+
+impl MsgCodeGen<&DbcCodeGen> for Message {
+    fn gen_can_dbc_impl(&self, code: &DbcCodeGen) -> io::Result<()> {
+        let sig_count = self.signals.len();
+        let msg_id = self.id.raw();
+        let msg_name = self.get_type_kamel();
+        let counter = code.counter_for(self)?;
+        let (counter_fields, counter_init) = if counter.is_some() {
+            (
+                "\n        counter: Option<u64>,\n        counter_status: super::CounterStatus,\n        counter_rejected: u64,",
+                "\n                counter: None,\n                counter_status: super::CounterStatus::Unknown,\n                counter_rejected: 0,",
+            )
+        } else {
+            ("", "")
+        };
+        let checksum = code.checksum_for(self);
+        let (e2e_fields, e2e_init) = match (checksum, counter) {
+            (Some(_), Some(_)) => (
+                "\n        checksum_rejected: u64,\n        tx_counter: u64,",
+                "\n                checksum_rejected: 0,\n                tx_counter: 0,",
+            ),
+            (Some(_), None) => {
+                ("\n        checksum_rejected: u64,", "\n                checksum_rejected: 0,")
+            },
+            (None, _) => ("", ""),
+        };
+
+        code_output!(
+            code,
+            format!(
+                r#"
+    pub struct DbcMessage {{
+        callback: Option<RefCell<Box<dyn CanMsgCtrl>>>,
+        signals: [Rc<RefCell<Box<dyn CanDbcSignal>>>;{sig_count}],
+        name: &'static str,
+        status: CanBcmOpCode,
+        listeners: i32,
+        signal_listeners: [i32;{sig_count}],
+        stamp: u64,
+        id: u32,{counter_fields}{e2e_fields}
+    }}
+
+    impl DbcMessage {{
+        pub fn new() -> Rc<RefCell<Box <dyn CanDbcMessage>>> {{
+            Rc::new(RefCell::new(Box::new (DbcMessage {{
+                id: {msg_id},
+                name: "{msg_name}",
+                status: CanBcmOpCode::Unknown,
+                listeners: 0,
+                signal_listeners: [0;{sig_count}],
+                stamp: 0,
+                callback: None,{counter_init}{e2e_init}
+                signals: ["#
+            )
+        )?;
+
+        for signal in &self.signals {
+            let type_id = signal.get_type_kamel();
+            code_output!(code, format!(r#"                    {type_id}::new(),"#))?;
+        }
+        code_output!(
+            code,
+            format!(
+                r#"
+                ],
+            }})))
+        }}
+
+        /// Return code of each signal callback (`CanSigCtrl::sig_notification`) on the last
+        /// frame, in `DbcSignal` order: 0 without callback or for an inactive mux page, -1 when
+        /// the callback was busy. `get_listeners()` is their sum.
+        pub fn get_signal_listeners(&self) -> &[i32;{sig_count}] {{
+            &self.signal_listeners
+        }}
+
+        /// Return code of the `signal` callback on the last frame, see `get_signal_listeners()`.
+        pub fn get_signal_listener(&self, signal: DbcSignal) -> i32 {{
+            self.signal_listeners[signal as usize]
+        }}
+
+        /// Index in `get_signals()` of the signal called `name`, its `get_name()` or DBC name.
+        pub fn signal_index(name: &str) -> Option<usize> {{
+            match name {{"#,
+                sig_count = self.signals.len(),
+            )
+        )?;
+        for (idx, signal) in self.signals.iter().enumerate() {
+            let type_id = signal.get_type_kamel();
+            let names = if type_id == signal.name {
+                format!("{type_id:?}")
+            } else {
+                format!("{type_id:?} | {:?}", signal.name)
+            };
+            code_output!(code, format!("                {names} => Some({idx}),"))?;
+        }
+        code_output!(
+            code,
+            r"                _ => None,
+            }
+        }
+
+        /// Signal called `name`, its `get_name()` or DBC name, without scanning the signals.
+        pub fn get_signal(&self, name: &str) -> Option<Rc<RefCell<Box<dyn CanDbcSignal>>>> {
+            Self::signal_index(name).map(|idx| Rc::clone(&self.signals[idx]))
+        }
+
+        /// Unit, range, scaling and layout of the signal called `name`, its `get_name()` or DBC
+        /// name, for generic tools holding a `dyn CanDbcSignal`.
+        pub fn signal_meta(name: &str) -> Option<super::SignalMeta> {
+            match name {"
+        )?;
+        for signal in &self.signals {
+            let type_id = signal.get_type_kamel();
+            let names = if type_id == signal.name {
+                format!("{type_id:?}")
+            } else {
+                format!("{type_id:?} | {:?}", signal.name)
+            };
+            code_output!(code, format!("                {names} => Some({type_id}::META),"))?;
+        }
+        code_output!(
+            code,
+            r"                _ => None,
+            }
+        }
+"
+        )?;
+        gen_message_id_api(code, self)?;
+        gen_message_attribute_api(code, self)?;
+        gen_e2e_api(code, self)?;
+
+        if code.canfd {
+            let size = self.size;
+            let dlc = canfd_dlc(size).ok_or_else(|| {
+                Error::other(format!(
+                    "message:{} size:{size} bytes exceeds the 64 bytes of CAN FD",
+                    self.name
+                ))
+            })?;
+            code_output!(
+                code,
+                format!(
+                    r#"
+        /// Payload bytes of the message
+        pub const SIZE: usize = {size};
+        /// DLC of the CAN FD frame carrying the message, see `super::canfd_len()`
+        pub const DLC: u8 = {dlc};
+"#
+                )
+            )?;
+        }
+
+        // build message signal:type list (may be empty once signals are dropped)
+        let mut args: Vec<String> = self
+            .signals
+            .iter()
+            .map(|signal| format!("{}: {}", signal.get_type_snake(), code.data_type(self, signal)))
+            .collect();
+        args.push("frame: &mut[u8]".to_owned());
+        let args_str = args.join(", ");
+
+        code_output!(
+            code,
+            format!(
+                r#"
+        pub fn set_values(&mut self, {args_str}) -> Result<&mut Self, CanError> {{
+"#
+            )
+        )?;
+        if code.canfd {
+            code_output!(
+                code,
+                format!(
+                    r#"            if frame.len() < Self::SIZE {{
+                return Err(CanError::new("{uid}", format!("frame len:{{}} < {name} size:{{}}", frame.len(), Self::SIZE)));
+            }}"#,
+                    uid = CanErrorCode::SignalSetValuesFail.uid(),
+                    name = self.name,
+                )
+            )?;
+        }
+
+        // Mux validation (generator-time):
+        let mux_idx = find_mux_idx(self)?;
+        if has_multiplexed_signals(self) && mux_idx.is_none() {
+            return Err(Error::other(format!(
+                "message:{} has multiplexed signals but no multiplexor",
+                self.get_type_kamel()
+            )));
+        }
+
+        if let Some(mux_idx) = mux_idx {
+            let mux_sig = &self.signals[mux_idx];
+            validate_mux(self, mux_sig)?;
+
+            let mux_arg = mux_sig.get_type_snake();
+            let mux_bits = mux_sig.size;
+
+            // Compute multiplexor RAW value (DBC selectors are defined on raw values).
+            let mux_raw_expr = if mux_sig.size == 1 {
+                format!(
+                    r#"
+            if {mux_arg} {{ 1 }} else {{ 0 }}"#
+                )
+            } else if mux_sig.value_type == ValueType::Signed {
+                format!(
+                    r#"{{
+            let __mask: u64 = if {mux_bits} == 64 {{ u64::MAX }} else {{ (1u64 << {mux_bits}) - 1 }};
+            ((({mux_arg} as i64) as u64) & __mask)
+    }}"#
+                )
+            } else {
+                format!(
+                    r#"{{
+            let __mask: u64 = if {mux_bits} == 64 {{ u64::MAX }} else {{ (1u64 << {mux_bits}) - 1 }};
+            (({mux_arg} as u64) & __mask)
+    }}"#
+                )
+            };
+
+            code_output!(
+                code,
+                format!(
+                    r#"
+            let __mux_raw_value: u64 = {mux_raw_expr};"#
+                )
+            )?;
+
+            // 1) Always pack the multiplexor itself first.
+            emit_signal_mut_action(
+                code,
+                "            ",
+                self,
+                mux_idx,
+                SigMutAction::SetValue { frame: "frame" },
+                SigAccess::Shared,
+                CanErrorCode::SignalSetValuesFail.uid(),
+            )?;
+
+            // 2) Pack other signals (plain always, multiplexed only if mux matches).
+            for idx in 0..self.signals.len() {
+                if idx == mux_idx {
+                    continue;
+                }
+
+                match self.signals[idx].multiplexer_indicator {
+                    MultiplexIndicator::Plain
+                    | MultiplexIndicator::Multiplexor
+                    | MultiplexIndicator::MultiplexorAndMultiplexedSignal(_) => {
+                        emit_signal_mut_action(
+                            code,
+                            "            ",
+                            self,
+                            idx,
+                            SigMutAction::SetValue { frame: "frame" },
+                            SigAccess::Shared,
+                            CanErrorCode::SignalSetValuesFail.uid(),
+                        )?;
+                    },
+                    MultiplexIndicator::MultiplexedSignal(mux_val) => {
+                        code_output!(
+                            code,
+                            format!(
+                                r#"
+            if __mux_raw_value == {mux_val} {{
+                "#
+                            )
+                        )?;
+                        emit_signal_mut_action(
+                            code,
+                            "                ",
+                            self,
+                            idx,
+                            SigMutAction::SetValue { frame: "frame" },
+                            SigAccess::Shared,
+                            CanErrorCode::SignalSetValuesFail.uid(),
+                        )?;
+                        code_output!(code, r#"            }"#)?;
+                    },
+                }
+            }
+        } else {
+            // Non-multiplexed message: pack all signals.
+            for idx in 0..self.signals.len() {
+                emit_signal_mut_action(
+                    code,
+                    "            ",
+                    self,
+                    idx,
+                    SigMutAction::SetValue { frame: "frame" },
+                    SigAccess::Shared,
+                    CanErrorCode::SignalSetValuesFail.uid(),
+                )?;
+            }
+        }
+
+        emit_e2e_protect(code, self, "            ", "frame", true)?;
+        code_output!(
+            code,
+            r#"
+            Ok(self)
+        }"#
+        )?;
+
+        if let Some(counter) = counter {
+            let counter_name = &counter.name;
+            code_output!(
+                code,
+                format!(
+                    r#"
+        /// Freshness of the last frame judged by its {counter_name} rolling counter
+        pub fn counter_status(&self) -> super::CounterStatus {{
+            self.counter_status
+        }}
+
+        /// Frames ignored since the last reset because their counter was repeated or went backward
+        pub fn counter_rejected(&self) -> u64 {{
+            self.counter_rejected
+        }}"#
+                )
+            )?;
+        }
+
+        code_output!(
+            code,
+            r#"    }
+"#
+        )?;
+        gen_message_fmt(code, self)?;
+
+        if code.serde_json {
+            code_output!(
+                code,
+                format!(
+                    r#"    {}impl Serialize for DbcMessage {{
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+            super::MessageState(self).serialize(serializer)
+        }}
+    }}
+"#,
+                    code.serde_cfg("    ")
+                )
+            )?;
+        }
+        gen_mux_group_api(code, self)?;
+
+        Ok(())
+    }
+
+    fn gen_can_dbc_message(&self, code: &DbcCodeGen) -> io::Result<()> {
+        code_output!(
+            code,
+            r#"
+    impl CanDbcMessage for DbcMessage {
+        fn reset(&mut self) -> Result<(), CanError> {"#
+        )?;
+        emit_message_reset(code, self, SigAccess::Shared)?;
+        code_output!(
+            code,
+            r#"
+        Ok(())
+    }
+
+        fn update(&mut self, frame: &CanMsgData) -> Result<(), CanError> {"#
+        )?;
+        emit_message_update(code, self, SigAccess::Shared)?;
+        let msg_type = self.get_type_kamel();
+        let trace = code.trace("fail to get message callback reference");
+        code_output!(
+            code,
+            format!(
+                r#"
+            match &self.callback {{
+                None => {{}},
+                Some(callback) => {{
+                    match callback.try_borrow() {{
+                        Err(_) => {{{trace}}},
+                        Ok(cb_ref) => cb_ref.msg_notification(self),
+                    }}
+                }}
+            }}
+            Ok(())
+        }}
+
+        fn get_signals(&self) -> &[Rc<RefCell<Box<dyn CanDbcSignal>>>] {{
+            &self.signals
+        }}
+
+        fn get_listeners(&self) -> i32 {{
+            self.listeners
+        }}
+
+        fn set_callback(&mut self, callback: Box<dyn CanMsgCtrl>)  {{
+            self.callback= Some(RefCell::new(callback));
+        }}
+
+        fn get_name(&self) -> &'static str {{
+            self.name
+        }}
+
+        fn get_status(&self) -> CanBcmOpCode {{
+            self.status
+        }}
+
+        fn get_stamp(&self) -> u64 {{
+            self.stamp
+        }}
+
+        fn get_id(&self) -> u32 {{
+            self.id
+        }}
+
+        fn as_any(&mut self) -> &mut dyn Any {{
+            self
+        }}
+
+    }} // end {msg_type} impl for CanDbcMessage"#
+            )
+        )?;
+
+        Ok(())
+    }
+
+    fn gen_snapshot(&self, code: &DbcCodeGen) -> io::Result<()> {
+        let msg_type = self.get_type_kamel();
+        let (derive_serde, serde_line) =
+            code.serde_derives(code.serde_json, "Serialize, Deserialize", "    ");
+        code_output!(
+            code,
+            format!(
+                r#"
+    /// Values of every {msg_type} signal, see `DbcMessage::snapshot()`
+    #[derive(Debug, Clone, Copy, PartialEq, Default{derive_serde})]{serde_line}
+    pub struct SignalValues {{"#
+            )
+        )?;
+        // byte arrays are read with get_bytes(), derives stop at 32 elements arrays
+        for sig in self.signals.iter().filter(|sig| !sig.is_byte_array()) {
+            code_output!(
+                code,
+                format!("        pub {}: {},", sig.get_type_snake(), code.data_type(self, sig))
+            )?;
+        }
+        code_output!(
+            code,
+            format!(
+                r#"    }}
+
+    /// Copy of a {msg_type} message taken at a point in time, holding no RefCell borrow
+    #[derive(Debug, Clone, Copy, PartialEq{derive_serde})]{serde_line}
+    pub struct MessageSnapshot {{
+        pub stamp: u64,
+        pub status: CanBcmOpCode,
+        pub values: SignalValues,
+    }}
+
+    impl DbcMessage {{
+        /// Copy the stamp, status and every signal value of the last decoded frame.
+        pub fn snapshot(&self) -> Result<MessageSnapshot, CanError> {{
+            Ok(MessageSnapshot {{
+                stamp: self.stamp,
+                status: self.status,
+                values: SignalValues {{"#
+            )
+        )?;
+        let uid = CanErrorCode::MessageSnapshotFail.uid();
+        for (idx, sig) in self.signals.iter().enumerate().filter(|(_, sig)| !sig.is_byte_array()) {
+            let sig_snake = sig.get_type_snake();
+            code_output!(
+                code,
+                format!(
+                    r#"                    {sig_snake}: match self.signals[{idx}].try_borrow() {{
+                        Ok(signal) => signal.get_value().cast()?,
+                        Err(_) => return Err(CanError::new("{uid}","Internal error {sig_snake}")),
+                    }},"#
+                )
+            )?;
+        }
+        code_output!(
+            code,
+            r#"                },
+            })
+        }
+    }"#
+        )
+    }
+
+    fn gen_values_codec(&self, code: &DbcCodeGen) -> io::Result<()> {
+        let msg_type = self.get_type_kamel();
+        let size = self.size;
+        let length_check = |uid: &str| {
+            if size == 0 {
+                String::new()
+            } else {
+                format!(
+                    r#"
+        if data.len() < {size} {{
+            return Err(CanError::new("{uid}", format!("{msg_type} payload len:{{}} shorter than {size} bytes", data.len())));
+        }}"#
+                )
+            }
+        };
+        let signals = || self.signals.iter().filter(|sig| !sig.is_byte_array());
+        let mux_idx = find_mux_idx(self)?;
+        let (decode_mux, encode_mux) = if mux_idx.is_some() {
+            (
+                "\n    /// Signals of an inactive multiplexed page read as their default value.",
+                "\n    /// Only the multiplexed page selected by the multiplexor value is written.",
+            )
+        } else {
+            ("", "")
+        };
+
+        code_output!(
+            code,
+            format!(
+                r#"
+    /// Decode a {msg_type} payload into its values, without signal object nor pool.{decode_mux}
+    pub fn decode(data: &[u8]) -> Result<SignalValues, CanError> {{{check}"#,
+                check = length_check(CanErrorCode::SignalUpdateFail.uid()),
+            )
+        )?;
+        if let Some(mux_idx) = mux_idx {
+            emit_mux_raw_value(code, self, &self.signals[mux_idx], "data")?;
+        }
+        code_output!(code, r#"        Ok(SignalValues {"#)?;
+        for sig in signals() {
+            let (sig_snake, sig_type) = (sig.get_type_snake(), sig.get_type_kamel());
+            let value = match sig.multiplexer_indicator {
+                MultiplexIndicator::MultiplexedSignal(mux_val) => format!(
+                    "if __mux_raw_value == {mux_val} {{ {sig_type}::decode(data) }} else {{ Default::default() }}"
+                ),
+                _ => format!("{sig_type}::decode(data)"),
+            };
+            code_output!(code, format!(r#"            {sig_snake}: {value},"#))?;
+        }
+        code_output!(
+            code,
+            format!(
+                r#"        }})
+    }}
+
+    /// Encode `values` into a {msg_type} payload, without signal object nor pool.{encode_mux}
+    pub fn encode(values: &SignalValues, data: &mut [u8]) -> Result<(), CanError> {{{check}"#,
+                check = length_check(CanErrorCode::SignalSetValuesFail.uid()),
+            )
+        )?;
+        if let Some(mux_idx) = mux_idx {
+            let mux_sig = &self.signals[mux_idx];
+            code_output!(
+                code,
+                format!(
+                    r#"        let __mux_raw_value = {}::raw_from_physical(values.{}) as u64;"#,
+                    mux_sig.get_type_kamel(),
+                    mux_sig.get_type_snake()
+                )
+            )?;
+        }
+        for sig in signals() {
+            let (sig_snake, sig_type) = (sig.get_type_snake(), sig.get_type_kamel());
+            let encode = format!("{sig_type}::encode(values.{sig_snake}, data)?;");
+            match sig.multiplexer_indicator {
+                MultiplexIndicator::MultiplexedSignal(mux_val) => code_output!(
+                    code,
+                    format!(r#"        if __mux_raw_value == {mux_val} {{ {encode} }}"#)
+                )?,
+                _ => code_output!(code, format!(r#"        {encode}"#))?,
+            }
+        }
+        emit_e2e_protect(code, self, "        ", "data", false)?;
+        code_output!(
+            code,
+            r#"        Ok(())
+    }"#
+        )
+    }
+
+    fn gen_builder(&self, code: &DbcCodeGen) -> io::Result<()> {
+        let msg_type = self.get_type_kamel();
+        let signals = || self.signals.iter().filter(|sig| !sig.is_byte_array());
+        let starts: Vec<String> = signals()
+            .filter(|sig| code.start_values.contains_key(&(self.id.raw(), sig.name.clone())))
+            .map(|sig| {
+                format!(
+                    "\n                {}: {}::START_VALUE,",
+                    sig.get_type_snake(),
+                    sig.get_type_kamel()
+                )
+            })
+            .collect();
+        let values = match starts.len() {
+            0 => "SignalValues::default()".to_owned(),
+            count if count == signals().count() => {
+                format!("SignalValues {{{}\n            }}", starts.concat())
+            },
+            _ => format!(
+                "SignalValues {{{}\n                ..Default::default()\n            }}",
+                starts.concat()
+            ),
+        };
+        code_output!(
+            code,
+            format!(
+                r#"
+    /// Typed builder of a {msg_type} payload: `{msg_type}::builder().signal(value).build(&mut data)?`.
+    /// Signals without setter call keep their GenSigStartValue, or their default.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct MessageBuilder {{
+        values: SignalValues,
+    }}
+
+    /// Start a {msg_type} payload, see `MessageBuilder`.
+    pub fn builder() -> MessageBuilder {{
+        MessageBuilder {{
+            values: {values},
+        }}
+    }}
+
+    impl MessageBuilder {{"#
+            )
+        )?;
+        for sig in signals() {
+            let sig_snake = sig.get_type_snake();
+            // keep build()/get_values() reachable whatever the signal names
+            let setter = if ["build", "get_values"].contains(&sig_snake.as_str()) {
+                format!("set_{sig_snake}")
+            } else {
+                sig_snake.clone()
+            };
+            let unit = match sig.unit.as_str() {
+                "" => String::new(),
+                unit => format!(" in {unit}"),
+            };
+            code_output!(
+                code,
+                format!(
+                    r#"        /// {sig_type} physical value{unit}
+        #[must_use]
+        pub fn {setter}(mut self, value: {data_type}) -> Self {{
+            self.values.{sig_snake} = value;
+            self
+        }}
+"#,
+                    sig_type = sig.get_type_kamel(),
+                    data_type = code.data_type(self, sig),
+                )
+            )?;
+        }
+        code_output!(
+            code,
+            r#"        /// Values `build()` encodes.
+        pub fn get_values(&self) -> &SignalValues {
+            &self.values
+        }
+
+        /// Encode the values into `data`, see `encode()`.
+        pub fn build(&self, data: &mut [u8]) -> Result<(), CanError> {
+            encode(&self.values, data)
+        }
+    }"#
+        )
+    }
+
+    fn gen_message_structs(&self, code: &DbcCodeGen) -> io::Result<()> {
+        let Some(mappings) = code.message_structs.get(&self.id.raw()) else { return Ok(()) };
+        let msg_type = self.get_type_kamel();
+        let signals = self.signals.iter().filter(|sig| !sig.is_byte_array()).count();
+        for StructMapping { path, fields } in mappings {
+            // the message fields a subset struct leaves out are encoded as their default
+            let rest =
+                if fields.len() < signals { "\n                ..Default::default()" } else { "" };
+            let copy = |from: &str| {
+                fields
+                    .iter()
+                    .map(|field| format!("\n                {field}: {from}.{field},"))
+                    .collect::<String>()
+            };
+            let read = fields
+                .iter()
+                .map(|field| {
+                    let idx = self
+                        .signals
+                        .iter()
+                        .position(|sig| sig.get_type_snake() == *field)
+                        .unwrap_or_default();
+                    format!(
+                        "\n                {field}: msg.signals[{idx}].try_borrow().ok().and_then(|signal| signal.get_value().cast().ok()).unwrap_or_default(),"
+                    )
+                })
+                .collect::<String>();
+            code_output!(
+                code,
+                format!(
+                    r#"
+    /// {msg_type} values of a snapshot or of `decode()`.
+    impl From<&SignalValues> for {path} {{
+        fn from(values: &SignalValues) -> Self {{
+            {path} {{{from_values}
+            }}
+        }}
+    }}
+
+    /// Current {msg_type} values, a signal borrowed elsewhere reading as its default.
+    impl From<&DbcMessage> for {path} {{
+        fn from(msg: &DbcMessage) -> Self {{
+            {path} {{{read}
+            }}
+        }}
+    }}
+
+    /// Values to `encode()` from the struct.
+    impl From<&{path}> for SignalValues {{
+        fn from(value: &{path}) -> Self {{
+            SignalValues {{{from_struct}{rest}
+            }}
+        }}
+    }}"#,
+                    from_values = copy("values"),
+                    from_struct = copy("value"),
+                )
+            )?;
+            if code.static_pool {
+                let read = fields
+                    .iter()
+                    .map(|field| {
+                        format!("\n                {field}: msg.signals.{field}.get_typed_value(),")
+                    })
+                    .collect::<String>();
+                code_output!(
+                    code,
+                    format!(
+                        r#"
+    /// Last {msg_type} values held by the static message.
+    impl From<&StaticMessage> for {path} {{
+        fn from(msg: &StaticMessage) -> Self {{
+            {path} {{{read}
+            }}
+        }}
+    }}"#
+                    )
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn gen_reference_tests(&self, code: &DbcCodeGen) -> io::Result<()> {
+        let mut tests = Vec::new();
+        for sig in &self.signals {
+            let Some(comment) = code.dbcfd.signal_comment(self.id, &sig.name) else { continue };
+            let references = reference_values(comment).map_err(|error| {
+                Error::other(format!(
+                    "message:{} signal:{} {error}",
+                    self.get_type_kamel(),
+                    sig.name
+                ))
+            })?;
+            if references.is_empty() {
+                continue;
+            }
+            let data_type = code.data_type(self, sig);
+            let mut checks = Vec::new();
+            for reference in &references {
+                let check = reference_check(sig, &data_type, reference).map_err(|error| {
+                    Error::other(format!(
+                        "message:{} signal:{} @test {}: {error}",
+                        self.get_type_kamel(),
+                        sig.name,
+                        reference.text
+                    ))
+                })?;
+                checks.push(check);
+            }
+            tests.push((sig, checks));
+        }
+        if tests.is_empty() {
+            return Ok(());
+        }
+
+        code_output!(
+            code,
+            r#"
+    #[cfg(test)]
+    mod reference_tests {
+        use super::*;"#
+        )?;
+        for (sig, checks) in tests {
+            let sig_type = sig.get_type_kamel();
+            let sig_snake = sig.get_type_snake();
+            code_output!(
+                code,
+                format!(
+                    r#"
+        /// `@test` reference values of the {sig_type} comment
+        #[test]
+        fn {sig_snake}() {{"#
+                )
+            )?;
+            for check in checks {
+                code_output!(code, check)?;
+            }
+            code_output!(code, r#"        }"#)?;
+        }
+        code_output!(code, r#"    }"#)
+    }
+
+    fn gen_signal_groups(&self, code: &DbcCodeGen) -> io::Result<()> {
+        let msg_type = self.get_type_kamel();
+        for group in code.dbcfd.signal_groups.iter().filter(|group| group.message_id == self.id) {
+            let members = group
+                .signal_names
+                .iter()
+                .map(|name| {
+                    let idx =
+                        self.signals.iter().position(|sig| sig.name == *name).ok_or_else(|| {
+                            Error::other(format!(
+                                "message:{msg_type} signal group:{} unknown signal:{name}",
+                                group.name
+                            ))
+                        })?;
+                    if self.signals[idx].is_byte_array() {
+                        return Err(Error::other(format!(
+                            "message:{msg_type} signal group:{} signal:{name} wider than 64 bits; unsupported",
+                            group.name
+                        )));
+                    }
+                    Ok(idx)
+                })
+                .collect::<io::Result<Vec<usize>>>()?;
+
+            let group_type = format!("{}Group", group.name.to_upper_camel_case());
+            let group_snake = group.name.to_snake_case();
+            let names: Vec<&str> = group.signal_names.iter().map(String::as_str).collect();
+
+            code_output!(
+                code,
+                format!(
+                    r#"
+    /// {msg_type}::{group} signal group (SIG_GROUP_), members: {members}
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    pub struct {group_type} {{"#,
+                    group = group.name,
+                    members = names.join(", "),
+                )
+            )?;
+            for idx in &members {
+                let sig = &self.signals[*idx];
+                code_output!(
+                    code,
+                    format!("        pub {}: {},", sig.get_type_snake(), code.data_type(self, sig))
+                )?;
+            }
+            code_output!(
+                code,
+                format!(
+                    r#"    }}
+
+    impl {group_type} {{
+        pub const SIGNALS: [&'static str; {count}] = {names:?};
+    }}
+
+    impl DbcMessage {{
+        /// Read every member of {group} from the last decoded frame.
+        pub fn get_{group_snake}(&self) -> Result<{group_type}, CanError> {{
+            Ok({group_type} {{"#,
+                    group = group.name,
+                    count = names.len(),
+                )
+            )?;
+            for idx in &members {
+                let sig = &self.signals[*idx];
+                let sig_snake = sig.get_type_snake();
+                code_output!(
+                    code,
+                    format!(
+                        r#"                {sig_snake}: match self.signals[{idx}].try_borrow() {{
+                    Ok(signal) => signal.get_value().cast()?,
+                    Err(_) => return Err(CanError::new("signal-group-get-fail","Internal error {sig_snake}")),
+                }},"#
+                    )
+                )?;
+            }
+            code_output!(
+                code,
+                format!(
+                    r#"            }})
+        }}
+
+        /// Write every member of {group}; `frame` is only modified when all values are valid.
+        pub fn set_{group_snake}(&mut self, values: {group_type}, frame: &mut[u8]) -> Result<&mut Self, CanError> {{
+            let mut staged = frame.to_vec();"#,
+                    group = group.name,
+                )
+            )?;
+            for idx in &members {
+                let sig = &self.signals[*idx];
+                let dtype_enum = code.data_type(self, sig).to_upper_camel_case();
+                let sig_snake = sig.get_type_snake();
+                emit_signal_try_borrow_mut(
+                    code,
+                    "            ",
+                    *idx,
+                    &sig_snake,
+                    &dtype_enum,
+                    &format!(
+                        "signal.set_value(CanDbcType::{dtype_enum}(values.{sig_snake}), &mut staged)?"
+                    ),
+                    CanErrorCode::SignalGroupSetFail.uid(),
+                )?;
+            }
+            let changed: Vec<String> = members
+                .iter()
+                .map(|idx| {
+                    format!(
+                        "self.signals[{idx}].try_borrow().map_or(false, |signal| matches!(signal.get_status(), CanDataStatus::Updated))"
+                    )
+                })
+                .collect();
+            code_output!(
+                code,
+                format!(
+                    r#"
+            frame.copy_from_slice(&staged);
+            Ok(self)
+        }}
+
+        /// True when at least one member of {group} changed with the last frame.
+        pub fn {group_snake}_changed(&self) -> bool {{
+            {changed}
+        }}
+    }} // end {msg_type}::{group_type}
+"#,
+                    group = group.name,
+                    changed = changed.join("\n                || "),
+                )
+            )?;
+        }
+        Ok(())
+    }
+
+    fn gen_code_message(&self, code: &DbcCodeGen) -> io::Result<()> {
+        // message header
+        let name = &self.name;
+        let id = self.id.raw();
+        let size = self.size;
+        let (can_id, extended) = bus_id(self.id);
+        let kind = if extended { ", extended" } else { "" };
+        code_output!(
+            code,
+            format!(
+                r#"
+/// {name} Message
+/// - ID: {can_id} (0x{can_id:x}){kind}
+/// - Size: {size} bytes"#
+            )
+        )?;
+
+        let transmitters = message_transmitters(&code.dbcfd, self);
+        match transmitters.as_slice() {
+            [] => {},
+            [transmitter] => code_output!(code, format!(r"/// - Transmitter: {transmitter}"))?,
+            _ => code_output!(code, format!(r"/// - Transmitters: {}", transmitters.join(", ")))?,
+        }
+
+        if let Some(comment) = code.dbcfd.message_comment(self.id) {
+            code_output!(code, "///")?;
+            for line in comment.trim().lines() {
+                code_output!(code, format!(r"/// {line}"))?;
+            }
+        }
+
+        if let Some(lines) = code.excerpts.get(&id) {
+            code_output!(code, "///\n/// ```text")?;
+            for line in lines {
+                code_output!(code, format!(r"/// {line}"))?;
+            }
+            code_output!(code, "/// ```")?;
+        }
+
+        if code.examples.is_some() {
+            self.gen_doc_example(code)?;
+        }
+
+        // per message module/name-space
+        let msg_mod = self.get_type_kamel();
+        let prelude = code.sockcan_prelude(1);
+        let (core, alloc) = (code.core_crate(), code.alloc_crate());
+        let alloc_prelude = code.alloc_prelude();
+
+        if self.signals.is_empty() {
+            return self.gen_presence_message(code);
+        }
+
+        code_output!(
+            code,
+            format!(
+                r#"
+pub mod {msg_mod} {{ /// Message name space
+    use {prelude};
+    use bitvec::prelude::*;
+    use {core}::any::Any;
+    use {core}::cell::{{RefCell}};
+    use {alloc}::rc::Rc;{alloc_prelude}
+
+    use {core}::fmt;
+"#
+            )
+        )?;
+
+        if code.uses_serde(Some(self)) {
+            code_output!(
+                code,
+                format!("    {}use serde::{{Deserialize, Serialize}};", code.serde_cfg("    "))
+            )?;
+        }
+
+        code_output!(
+            code,
+            format!(
+                r#"    /// Nodes transmitting this message (BO_ and BO_TX_BU_)
+    pub const TRANSMITTERS: [&str; {count}] = {transmitters:?};
+"#,
+                count = transmitters.len(),
+            )
+        )?;
+
+        // enumeration with all signal type
+        code_output!(code, r#"    pub enum DbcSignal {"#)?;
+        for signal in &self.signals {
+            let type_id = signal.get_type_kamel();
+            code_output!(code, format!(r#"        {type_id},"#))?;
+        }
+        code_output!(
+            code,
+            r#"
+    }
+"#
+        )?;
+        // signals structures and implementation
+        for signal in &self.signals {
+            signal.gen_code_signal(code, self)?;
+        }
+
+        self.gen_can_dbc_impl(code)?;
+        self.gen_signal_groups(code)?;
+        self.gen_snapshot(code)?;
+        self.gen_values_codec(code)?;
+        self.gen_builder(code)?;
+        self.gen_message_structs(code)?;
+        self.gen_can_dbc_message(code)?;
+        self.gen_reference_tests(code)?;
+        if code.static_pool {
+            self.gen_static_message(code)?;
+        }
+        let msg_type = self.get_type_kamel();
+        code_output!(
+            code,
+            format!(
+                r#"
+}} // end {msg_type} message
+"#
+            )
+        )?;
+        Ok(())
+    }
+
+    fn gen_presence_message(&self, code: &DbcCodeGen) -> io::Result<()> {
+        let msg_mod = self.get_type_kamel();
+        let msg_id = self.id.raw();
+        let prelude = code.sockcan_prelude(1);
+        let (core, alloc) = (code.core_crate(), code.alloc_crate());
+        let alloc_prelude = code.alloc_prelude();
+        let transmitters = message_transmitters(&code.dbcfd, self);
+        code_output!(
+            code,
+            format!(
+                r#"
+pub mod {msg_mod} {{ /// Message name space, presence only (no signal)
+    use {prelude};
+    use {core}::any::Any;
+    use {core}::cell::{{RefCell}};
+    use {core}::fmt;
+    use {alloc}::rc::Rc;{alloc_prelude}
+
+    /// Nodes transmitting this message (BO_ and BO_TX_BU_)
+    pub const TRANSMITTERS: [&str; {count}] = {transmitters:?};
+
+    pub struct DbcMessage {{
+        callback: Option<RefCell<Box<dyn CanMsgCtrl>>>,
+        name: &'static str,
+        status: CanBcmOpCode,
+        stamp: u64,
+        id: u32,
+    }}
+
+    impl DbcMessage {{
+        pub fn new() -> Rc<RefCell<Box <dyn CanDbcMessage>>> {{
+            Rc::new(RefCell::new(Box::new (DbcMessage {{
+                id: {msg_id},
+                name: "{msg_mod}",
+                status: CanBcmOpCode::Unknown,
+                stamp: 0,
+                callback: None,
+            }})))
+        }}
+
+        /// True once a frame was received since the last reset.
+        pub fn is_present(&self) -> bool {{
+            self.stamp != 0
+        }}"#,
+                count = transmitters.len(),
+            )
+        )?;
+        gen_message_id_api(code, self)?;
+        gen_message_attribute_api(code, self)?;
+        code_output!(code, "    }\n")?;
+        gen_message_fmt(code, self)?;
+
+        if code.serde_json {
+            code_output!(
+                code,
+                format!(
+                    r#"    {}impl serde::Serialize for DbcMessage {{
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+            super::MessageState(self).serialize(serializer)
+        }}
+    }}
+"#,
+                    code.serde_cfg("    ")
+                )
+            )?;
+        }
+
+        let trace = code.trace("fail to get message callback reference");
+        code_output!(
+            code,
+            format!(
+                r#"    impl CanDbcMessage for DbcMessage {{
+        fn reset(&mut self) -> Result<(), CanError> {{
+            self.status=CanBcmOpCode::Unknown;
+            self.stamp=0;
+            Ok(())
+        }}
+
+        fn update(&mut self, frame: &CanMsgData) -> Result<(), CanError> {{
+            self.stamp= frame.stamp;
+            self.status= frame.opcode;
+
+            match &self.callback {{
+                None => {{}},
+                Some(callback) => {{
+                    match callback.try_borrow() {{
+                        Err(_) => {{{trace}}},
+                        Ok(cb_ref) => cb_ref.msg_notification(self),
+                    }}
+                }}
+            }}
+            Ok(())
+        }}
+
+        fn get_signals(&self) -> &[Rc<RefCell<Box<dyn CanDbcSignal>>>] {{
+            &[]
+        }}
+
+        fn get_listeners(&self) -> i32 {{
+            0
+        }}
+
+        fn set_callback(&mut self, callback: Box<dyn CanMsgCtrl>)  {{
+            self.callback= Some(RefCell::new(callback));
+        }}
+
+        fn get_name(&self) -> &'static str {{
+            self.name
+        }}
+
+        fn get_status(&self) -> CanBcmOpCode {{
+            self.status
+        }}
+
+        fn get_stamp(&self) -> u64 {{
+            self.stamp
+        }}
+
+        fn get_id(&self) -> u32 {{
+            self.id
+        }}
+
+        fn as_any(&mut self) -> &mut dyn Any {{
+            self
+        }}
+
+    }} // end {msg_mod} impl for CanDbcMessage"#
+            )
+        )?;
+        if code.static_pool {
+            self.gen_static_message(code)?;
+        }
+        code_output!(
+            code,
+            format!(
+                r#"
+}} // end {msg_mod} message
+"#
+            )
+        )
+    }
+
+    fn gen_static_message(&self, code: &DbcCodeGen) -> io::Result<()> {
+        let msg_type = self.get_type_kamel();
+        let sig_count = self.signals.len();
+        let counter = code.counter_for(self)?;
+        let (mut counter_fields, mut counter_init) = if counter.is_some() {
+            (
+                "\n        counter: Option<u64>,\n        counter_status: super::CounterStatus,\n        counter_rejected: u64,".to_owned(),
+                "\n                counter: None,\n                counter_status: super::CounterStatus::Unknown,\n                counter_rejected: 0,".to_owned(),
+            )
+        } else {
+            (String::new(), String::new())
+        };
+        let checksum = code.checksum_for(self);
+        if checksum.is_some() {
+            counter_fields.push_str("\n        checksum_rejected: u64,");
+            counter_init.push_str("\n                checksum_rejected: 0,");
+        }
+
+        code_output!(
+            code,
+            format!(
+                r#"
+    /// Signals of `StaticMessage`, held by value
+    pub struct StaticSignals {{"#
+            )
+        )?;
+        for signal in &self.signals {
+            let (sig_snake, sig_type) = (signal.get_type_snake(), signal.get_type_kamel());
+            code_output!(code, format!(r#"        pub {sig_snake}: {sig_type},"#))?;
+        }
+        code_output!(
+            code,
+            format!(
+                r#"    }}
+
+    /// {msg_type} message owning its signals, see `super::StaticPool`
+    pub struct StaticMessage {{
+        pub signals: StaticSignals,
+        status: CanBcmOpCode,
+        listeners: i32,
+        signal_listeners: [i32;{sig_count}],
+        stamp: u64,{counter_fields}
+    }}
+
+    impl StaticMessage {{
+        pub fn new() -> Self {{
+            StaticMessage {{
+                status: CanBcmOpCode::Unknown,
+                listeners: 0,
+                signal_listeners: [0;{sig_count}],
+                stamp: 0,{counter_init}
+                signals: StaticSignals {{"#
+            )
+        )?;
+        for signal in &self.signals {
+            let (sig_snake, sig_type) = (signal.get_type_snake(), signal.get_type_kamel());
+            code_output!(code, format!(r#"                    {sig_snake}: {sig_type}::init(),"#))?;
+        }
+        code_output!(
+            code,
+            r#"                },
+            }
+        }
+
+        pub fn get_stamp(&self) -> u64 {
+            self.stamp
+        }
+
+        pub fn get_status(&self) -> CanBcmOpCode {
+            self.status
+        }
+
+        /// Sum of the signal callback return codes on the last frame
+        pub fn get_listeners(&self) -> i32 {
+            self.listeners
+        }"#
+        )?;
+        // presence-only messages have no frame length check
+        if code.canfd && !self.signals.is_empty() {
+            code_output!(
+                code,
+                r#"
+        /// Payload bytes of the message, see `DbcMessage::SIZE`
+        pub const SIZE: usize = DbcMessage::SIZE;"#
+            )?;
+        }
+        if !self.signals.is_empty() {
+            code_output!(
+                code,
+                format!(
+                    r#"
+        /// Return code of each signal callback on the last frame, see `DbcMessage::get_signal_listeners()`
+        pub fn get_signal_listeners(&self) -> &[i32;{sig_count}] {{
+            &self.signal_listeners
+        }}"#
+                )
+            )?;
+        }
+        if counter.is_some() {
+            code_output!(
+                code,
+                r#"
+        /// See `DbcMessage::counter_status()`
+        pub fn counter_status(&self) -> super::CounterStatus {
+            self.counter_status
+        }
+
+        /// See `DbcMessage::counter_rejected()`
+        pub fn counter_rejected(&self) -> u64 {
+            self.counter_rejected
+        }"#
+            )?;
+        }
+        if checksum.is_some() {
+            code_output!(
+                code,
+                r#"
+        /// See `DbcMessage::checksum_rejected()`
+        pub fn checksum_rejected(&self) -> u64 {
+            self.checksum_rejected
+        }"#
+            )?;
+        }
+
+        code_output!(
+            code,
+            r#"
+        pub fn reset(&mut self) -> Result<(), CanError> {"#
+        )?;
+        emit_message_reset(code, self, SigAccess::Inline)?;
+        code_output!(
+            code,
+            r#"
+            Ok(())
+        }
+
+        /// Decode `frame` like `DbcMessage::update()`, without message callback
+        pub fn update(&mut self, frame: &CanMsgData) -> Result<(), CanError> {"#
+        )?;
+        if self.signals.is_empty() {
+            code_output!(
+                code,
+                r#"            self.stamp= frame.stamp;
+            self.status= frame.opcode;"#
+            )?;
+        } else {
+            emit_message_update(code, self, SigAccess::Inline)?;
+        }
+        code_output!(
+            code,
+            r#"
+            Ok(())
+        }"#
+        )?;
+
+        if !self.signals.is_empty() {
+            code_output!(
+                code,
+                r#"
+        /// Copy the stamp, status and every signal value, see `DbcMessage::snapshot()`
+        pub fn snapshot(&self) -> Result<MessageSnapshot, CanError> {
+            Ok(MessageSnapshot {
+                stamp: self.stamp,
+                status: self.status,
+                values: SignalValues {"#
+            )?;
+            for sig in self.signals.iter().filter(|sig| !sig.is_byte_array()) {
+                let sig_snake = sig.get_type_snake();
+                code_output!(
+                    code,
+                    format!(
+                        r#"                    {sig_snake}: self.signals.{sig_snake}.get_value().cast()?,"#
+                    )
+                )?;
+            }
+            code_output!(
+                code,
+                r#"                },
+            })
+        }"#
+            )?;
+        }
+        if let Some(mux_idx) = find_mux_idx(self)?.filter(|_| has_multiplexed_signals(self)) {
+            let mux_sig = &self.signals[mux_idx];
+            let selector = mux_selector_expr(
+                code,
+                self,
+                mux_sig,
+                &format!("self.signals.{}", mux_sig.get_type_snake()),
+            );
+            code_output!(
+                code,
+                format!(
+                    r#"
+        /// See `DbcMessage::get_active_group()`
+        pub fn get_active_group(&self) -> Option<MuxGroup> {{
+            MuxGroup::from_selector({selector})
+        }}"#
+                )
+            )?;
+        }
+        code_output!(code, "    }")
+    }
+
+    fn gen_doc_example(&self, code: &DbcCodeGen) -> io::Result<()> {
+        let Some(examples) = &code.examples else {
+            return Ok(());
+        };
+        let uid = examples.uid;
+        let include = &examples.include;
+        let msg_mod = self.get_type_kamel();
+        let id = self.id.raw();
+        // FD frames are padded to the length of their DLC
+        let size = match canfd_dlc(self.size).filter(|_| code.canfd) {
+            Some(dlc) => CANFD_LENGTHS[usize::from(dlc)],
+            None => self.size,
+        };
+        let prelude = if code.sockcan {
+            "sockcan::prelude".to_owned()
+        } else {
+            format!("{uid}::sockcan::prelude")
+        };
+        let intro = if self.signals.is_empty() {
+            "Decode a frame of this presence-only message:"
+        } else {
+            "Encode every signal into a frame, decode it and check the values read back:"
+        };
+        code_output!(
+            code,
+            format!(
+                r#"///
+/// # Examples
+///
+/// {intro}
+///
+/// ```
+/// # include!({include});
+/// # fn main() -> Result<(), {prelude}::CanError> {{
+/// use {prelude}::*;
+/// use {uid}::{msg_mod};
+///
+/// let msg = {msg_mod}::DbcMessage::new();"#
+            )
+        )?;
+
+        if !self.signals.is_empty() {
+            code_output!(
+                code,
+                format!(
+                    r#"/// let signals = msg.borrow().get_signals().to_vec();
+/// let mut data = [0u8; {size}];"#
+                )
+            )?;
+        }
+        // the frame holds the first multiplexed page, the checksum is computed last
+        let page = self
+            .signals
+            .iter()
+            .filter_map(|sig| match sig.multiplexer_indicator {
+                MultiplexIndicator::MultiplexedSignal(page) => Some(page),
+                _ => None,
+            })
+            .min();
+        let checksum = code.checksum_for(self).map(|(sig, _)| sig.name.as_str());
+        // signals sharing bits in the frame overwrite each other, nothing to assert
+        let bits: Vec<Vec<u64>> = self
+            .signals
+            .iter()
+            .map(|sig| match sig.multiplexer_indicator {
+                MultiplexIndicator::MultiplexedSignal(mux_val) if Some(mux_val) != page => {
+                    Vec::new()
+                },
+                _ => frame_bits(sig),
+            })
+            .collect();
+        let overlaps = |idx: usize| {
+            bits.iter().enumerate().any(|(other, others)| {
+                other != idx && others.iter().any(|bit| bits[idx].contains(bit))
+            })
+        };
+        // read back: None for another page, Some(None) without a known value
+        let mut expected = Vec::with_capacity(self.signals.len());
+        for (idx, sig) in self.signals.iter().enumerate() {
+            let sig_type = sig.get_type_kamel();
+            let raw = match sig.multiplexer_indicator {
+                MultiplexIndicator::MultiplexedSignal(mux_val) if Some(mux_val) != page => {
+                    code_output!(
+                        code,
+                        format!(r#"/// // {sig_type}: page {mux_val}, not in this frame"#)
+                    )?;
+                    expected.push(None);
+                    continue;
+                },
+                MultiplexIndicator::Multiplexor => page.map(|page| {
+                    let shift = 128 - sig.size;
+                    match sig.value_type {
+                        ValueType::Signed => (i128::from(page) << shift) >> shift,
+                        ValueType::Unsigned => i128::from(page),
+                    }
+                }),
+                _ => None,
+            };
+            if checksum == Some(sig.name.as_str()) {
+                expected.push(Some(None));
+                continue;
+            }
+            let data_type = code.data_type(self, sig);
+            let round_trip = example_round_trip(code, self, sig, raw);
+            let value = match &round_trip {
+                Some((value, _)) => Some(value.clone()),
+                None => example_value(sig, &data_type, code.range_check_for(self, sig)),
+            };
+            match value {
+                Some(value) => code_output!(
+                    code,
+                    format!(
+                        r#"/// signals[{idx}].borrow_mut().set_value({value}, &mut data)?; // {sig_type}"#
+                    )
+                )?,
+                None if sig.is_byte_array() => code_output!(
+                    code,
+                    format!(
+                        r#"/// // {sig_type}: byte array, written with {msg_mod}::{sig_type}::set_bytes()"#
+                    )
+                )?,
+                None => code_output!(
+                    code,
+                    format!(
+                        r#"/// // {sig_type}: empty DBC range, no value passes the range check"#
+                    )
+                )?,
+            }
+            expected
+                .push(Some(round_trip.map(|(_, expected)| expected).filter(|_| !overlaps(idx))));
+        }
+        if let Some((idx, sig)) = self
+            .signals
+            .iter()
+            .enumerate()
+            .find(|(_, sig)| checksum == Some(sig.name.as_str()))
+        {
+            let data_type = code.data_type(self, sig);
+            if int_type_range(&data_type).is_some() {
+                let variant = data_type.to_upper_camel_case();
+                let sig_type = sig.get_type_kamel();
+                let value = match data_type.as_str() {
+                    "u64" => format!("{msg_mod}::DbcMessage::checksum(&data)"),
+                    _ => format!("{msg_mod}::DbcMessage::checksum(&data) as {data_type}"),
+                };
+                code_output!(
+                    code,
+                    format!(
+                        r#"/// signals[{idx}].borrow_mut().set_value(CanDbcType::{variant}({value}), &mut data)?; // {sig_type}"#
+                    )
+                )?;
+            }
+        }
+
+        let data = if self.signals.is_empty() {
+            format!("vec![0; {size}]")
+        } else {
+            "data.to_vec()".to_owned()
+        };
+        code_output!(
+            code,
+            format!(
+                r#"/// msg.borrow_mut().update(&CanMsgData {{
+///     canid: {id},
+///     stamp: 1,
+///     opcode: CanBcmOpCode::RxChanged,
+///     len: {size},
+///     data: {data},
+/// }})?;"#
+            )
+        )?;
+
+        if self.signals.is_empty() {
+            code_output!(code, r#"/// assert_eq!(msg.borrow().get_stamp(), 1);"#)?;
+        }
+        for ((idx, sig), expected) in self.signals.iter().enumerate().zip(expected) {
+            let Some(expected) = expected else { continue };
+            let sig_snake = sig.get_type_snake();
+            if sig.is_byte_array() {
+                code_output!(
+                    code,
+                    format!(
+                        r#"/// let {sig_snake} = signals[{idx}].borrow().get_value(); // first 8 bytes, see get_bytes()"#
+                    )
+                )?;
+            } else {
+                let data_type = code.data_type(self, sig);
+                code_output!(
+                    code,
+                    format!(
+                        r#"/// let {sig_snake}: {data_type} = signals[{idx}].borrow().get_value().cast()?;"#
+                    )
+                )?;
+                if let Some(expected) = expected {
+                    code_output!(code, format!(r#"/// assert_eq!({sig_snake}, {expected});"#))?;
+                }
+            }
+        }
+        code_output!(
+            code,
+            r#"/// # Ok(())
+/// # }
+/// ```"#
+        )
+    }
+}
+
+/// One `@test raw=<int> phys=<number>` annotation of a signal comment.
+struct ReferenceValue {
+    raw: u64,
+    phys: f64,
+    /// Annotation text, for error messages.
+    text: String,
+}
+
+/// Reference values of a signal comment. Each `@test` is followed by `raw=` (decimal or `0x`
+/// hexadecimal bits) and `phys=` (number, `true`/`false` for 1-bit signals), in any order.
+fn reference_values(comment: &str) -> io::Result<Vec<ReferenceValue>> {
+    let mut references = Vec::new();
+    for annotation in comment.split("@test").skip(1) {
+        if !annotation.starts_with(char::is_whitespace) {
+            continue;
+        }
+        let (mut raw, mut phys) = (None, None);
+        let mut words = Vec::new();
+        for word in annotation.split_whitespace() {
+            let Some((key, value)) = word.split_once('=') else { break };
+            let invalid = || Error::other(format!("@test invalid {key}:{value}"));
+            match key {
+                "raw" => {
+                    let parsed = match value.strip_prefix("0x").or_else(|| value.strip_prefix("0X"))
+                    {
+                        Some(hex) => u64::from_str_radix(hex, 16),
+                        None => value.parse(),
+                    };
+                    raw = Some(parsed.map_err(|_| invalid())?);
+                },
+                "phys" => {
+                    phys = Some(match value {
+                        "true" => 1.0,
+                        "false" => 0.0,
+                        _ => value
+                            .parse::<f64>()
+                            .ok()
+                            .filter(|phys| phys.is_finite())
+                            .ok_or_else(invalid)?,
+                    });
+                },
+                _ => return Err(Error::other(format!("@test unknown key:{key}"))),
+            }
+            words.push(word);
+        }
+        let text = words.join(" ");
+        let (Some(raw), Some(phys)) = (raw, phys) else {
+            return Err(Error::other(format!("@test {text} needs raw= and phys=")));
+        };
+        references.push(ReferenceValue { raw, phys, text });
+    }
+    Ok(references)
+}
+
+/// Assertions of the generated reference test of `sig` for one annotation: decoding `raw`
+/// gives `phys` and encoding `phys` gives back `raw` (within one raw unit for `f64` signals,
+/// which are truncated when scaled).
+fn reference_check(
+    sig: &Signal,
+    data_type: &str,
+    reference: &ReferenceValue,
+) -> io::Result<String> {
+    if sig.is_byte_array() {
+        return Err(Error::other("signals wider than 64 bits have no raw value"));
+    }
+    let raw = reference.raw;
+    if raw > all_ones(sig.size) {
+        return Err(Error::other(format!("raw:{raw:#x} does not fit {} bits", sig.size)));
+    }
+    let sig_type = sig.get_type_kamel();
+    let phys = reference.phys;
+    let check = match data_type {
+        "bool" => {
+            if phys != 0.0 && phys != 1.0 {
+                return Err(Error::other("phys of a 1-bit signal is true/false (1/0)"));
+            }
+            let value = phys == 1.0;
+            let not = if value { "" } else { "!" };
+            format!(
+                r#"            assert!({not}{sig_type}::physical_from_raw({raw:#x}));
+            assert_eq!({sig_type}::raw_from_physical({value}), {raw:#x});"#
+            )
+        },
+        "f64" => format!(
+            r#"            let value = {sig_type}::physical_from_raw({raw:#x});
+            assert!((value - {phys:?}_f64).abs() <= 1e-6 * (1.0 + {phys:?}_f64.abs()), "raw={raw:#x}: {{value}} != {phys:?}");
+            assert!({sig_type}::raw_from_physical({phys:?}_f64).abs_diff({raw:#x}) <= 1, "phys={phys:?}: raw != {raw:#x}");"#
+        ),
+        _ => {
+            let (tmin, tmax) = int_type_range(data_type)
+                .ok_or_else(|| Error::other(format!("no reference test for {data_type}")))?;
+            if phys.fract() != 0.0 || (phys as i128) < tmin || (phys as i128) > tmax {
+                return Err(Error::other(format!("phys:{phys} is not a {data_type}")));
+            }
+            let phys = phys as i128;
+            format!(
+                r#"            assert_eq!({sig_type}::physical_from_raw({raw:#x}), {phys});
+            assert_eq!({sig_type}::raw_from_physical({phys}), {raw:#x});"#
+            )
+        },
+    };
+    Ok(check)
+}
+
+/// `CanDbcType` literal of a value `set_value()` accepts for `sig` of `data_type` (its DBC minimum
+/// when range checked), `None` for byte arrays and empty ranges.
+fn example_value(sig: &Signal, data_type: &str, range_check: bool) -> Option<String> {
+    match data_type {
+        _ if sig.is_byte_array() => None,
+        "bool" => Some("CanDbcType::Bool(false)".to_owned()),
+        "f64" if !range_check => Some("CanDbcType::F64(0.0)".to_owned()),
+        "f64" => (sig.min <= sig.max).then(|| format!("CanDbcType::F64({:?})", sig.min)),
+        _ => {
+            let (tmin, tmax) = int_type_range(data_type)?;
+            let variant = data_type.to_upper_camel_case();
+            if !range_check {
+                return Some(format!("CanDbcType::{variant}(0)"));
+            }
+            let value = (sig.min.ceil() as i128).clamp(tmin, tmax);
+            (value as f64 <= sig.max).then(|| format!("CanDbcType::{variant}({value})"))
+        },
+    }
+}
+
+/// Frame bits of `sig`, numbered `byte * 8 + bit` with bit 0 the least significant.
+fn frame_bits(sig: &Signal) -> Vec<u64> {
+    let mut bit = sig.start_bit;
+    let mut bits = Vec::with_capacity(sig.size as usize);
+    for _ in 0..sig.size {
+        bits.push(bit);
+        bit = match sig.byte_order {
+            ByteOrder::LittleEndian => bit + 1,
+            // Motorola: down to the byte LSB, then to the MSB of the next byte
+            ByteOrder::BigEndian if bit.is_multiple_of(8) => bit + 15,
+            ByteOrder::BigEndian => bit - 1,
+        };
+    }
+    bits
+}
+
+/// Value of `sig` the doc examples write and read back with `assert_eq!`: `CanDbcType` literal
+/// and expected value. The raw value is `raw` (a mux page) or three quarters up the DBC range,
+/// a `VAL_` entry when the signal is checked against its table, and never the SNA; `None` when
+/// no such value survives the scaling both ways exactly.
+fn example_round_trip(
+    code: &DbcCodeGen,
+    msg: &Message,
+    sig: &Signal,
+    raw: Option<i128>,
+) -> Option<(String, String)> {
+    let data_type = code.data_type(msg, sig);
+    let range_check = code.range_check_for(msg, sig);
+    let in_range = |phys: f64| !range_check || (sig.min <= phys && phys <= sig.max);
+    if sig.is_byte_array() || (range_check && sig.min > sig.max) {
+        return None;
+    }
+    if data_type == "bool" {
+        let value = raw.map_or_else(|| in_range(1.0), |raw| raw != 0);
+        return in_range(f64::from(u8::from(value)))
+            .then(|| (format!("CanDbcType::Bool({value})"), value.to_string()));
+    }
+
+    let key = (msg.id.raw(), sig.name.clone());
+    let float = code.float_size(msg, sig).is_some();
+    let signed = sig.value_type == ValueType::Signed;
+    let (mut lo, mut hi) = if float {
+        // integral raw floats, exact in f32
+        (-(1_i128 << 24), 1_i128 << 24)
+    } else if signed {
+        (-(1_i128 << (sig.size - 1)), (1_i128 << (sig.size - 1)) - 1)
+    } else {
+        (0, (1_i128 << sig.size) - 1)
+    };
+    if range_check {
+        let (a, b) = ((sig.min - sig.offset) / sig.factor, (sig.max - sig.offset) / sig.factor);
+        lo = lo.max(a.min(b).ceil() as i128);
+        hi = hi.min(a.max(b).floor() as i128);
+    }
+    // three quarters up the range: not zero for ranges centered on it
+    let mid = lo + (hi - lo) * 3 / 4;
+    let mut candidates = match (raw, code.domains.get(&key)) {
+        (Some(raw), _) => vec![raw],
+        (None, Some(domain)) if !float => {
+            let shift = 128 - sig.size;
+            let mut values: Vec<i128> =
+                domain
+                    .iter()
+                    .map(|&bits| {
+                        if signed {
+                            (i128::from(bits) << shift) >> shift
+                        } else {
+                            i128::from(bits)
+                        }
+                    })
+                    .collect();
+            values.sort_by_key(|value| (value - mid).abs());
+            values
+        },
+        _ => (0..4).flat_map(|step| [mid + step, mid - step - 1]).chain([lo, hi]).collect(),
+    };
+    let sna = code.sna.get(&key).filter(|_| !float);
+    candidates.retain(|&raw| {
+        let bits = (raw as u64) & all_ones(sig.size);
+        let not_available = match sna {
+            Some(SignalSna::Raw(sna)) => bits == *sna,
+            Some(SignalSna::J1939 { error, not_available }) => {
+                (error.0..=error.1).contains(&bits)
+                    || (not_available.0..=not_available.1).contains(&bits)
+            },
+            None => false,
+        };
+        let phys = raw as f64 * sig.factor + sig.offset;
+        (lo..=hi).contains(&raw)
+            && !not_available
+            && in_range(phys)
+            && ((phys - sig.offset) / sig.factor).trunc() == raw as f64
+    });
+    let raw = *candidates.first()?;
+    let phys = raw as f64 * sig.factor + sig.offset;
+    if data_type == "f64" {
+        return Some((format!("CanDbcType::F64({phys:?})"), format!("{phys:?}")));
+    }
+    let (tmin, tmax) = int_type_range(&data_type)?;
+    let value = phys as i128;
+    let variant = data_type.to_upper_camel_case();
+    (tmin..=tmax)
+        .contains(&value)
+        .then(|| (format!("CanDbcType::{variant}({value})"), value.to_string()))
+}
+
+/// `SignalValues` field literal of `sig` for the generated benchmarks: `phys`, or the middle of
+/// the DBC range, `None` when range checked and out of the range.
+fn bench_value(
+    sig: &Signal,
+    data_type: &str,
+    range_check: bool,
+    phys: Option<f64>,
+) -> Option<String> {
+    let value = phys.unwrap_or(if sig.min <= sig.max { (sig.min + sig.max) / 2.0 } else { 0.0 });
+    let in_range = |value: f64| !range_check || (sig.min <= value && value <= sig.max);
+    match data_type {
+        "bool" => Some((value >= 0.5).to_string()),
+        "f64" => in_range(value).then(|| format!("{value:?}")),
+        _ => {
+            let (tmin, tmax) = int_type_range(data_type)?;
+            let value = (value.round() as i128).clamp(tmin, tmax);
+            in_range(value as f64).then(|| value.to_string())
+        },
+    }
+}
+
+/// `SignalValues` literal of the representative frame of `msg`: the first multiplexed page, other
+/// signals at the middle of their DBC range. `None` when a value fails its range check.
+fn bench_values(code: &DbcCodeGen, msg: &Message, uid: &str) -> Option<String> {
+    let page = msg
+        .signals
+        .iter()
+        .filter_map(|sig| match sig.multiplexer_indicator {
+            MultiplexIndicator::MultiplexedSignal(page) => Some(page),
+            _ => None,
+        })
+        .min();
+    let mut fields = String::new();
+    let mut partial = false;
+    for sig in msg.signals.iter().filter(|sig| !sig.is_byte_array()) {
+        let phys = match sig.multiplexer_indicator {
+            MultiplexIndicator::MultiplexedSignal(mux_val) if Some(mux_val) != page => {
+                // inactive page, not encoded
+                partial = true;
+                continue;
+            },
+            MultiplexIndicator::Multiplexor => {
+                page.map(|page| page as f64 * sig.factor + sig.offset)
+            },
+            _ => None,
+        };
+        let data_type = code.data_type(msg, sig);
+        let value = bench_value(sig, &data_type, code.range_check_for(msg, sig), phys)?;
+        let _ = write!(fields, "\n        {}: {value},", sig.get_type_snake());
+    }
+    let rest = if partial { "\n        ..Default::default()" } else { "" };
+    Some(format!("{uid}::{}::SignalValues {{{fields}{rest}\n    }}", msg.get_type_kamel()))
+}
+
+/// Tests of `DbcParser::emit_crate()`: the representative frame of each message (see
+/// `bench_values()`) decodes and encodes back to the same bytes.
+fn gen_crate_tests(code: &DbcCodeGen, crate_ident: &str) -> String {
+    let alloc = if code.no_std { "extern crate alloc;\n" } else { "" };
+    let mut tests = format!(
+        r#"// Round trip tests of the {crate_ident} messages, generated by dbcparser: do not edit.
+// Frames hold the first multiplexed page and every other signal at the middle of its range.
+{alloc}"#
+    );
+    for msg in &code.dbcfd.messages {
+        if msg.signals.iter().all(Signal::is_byte_array) {
+            continue;
+        }
+        let Some(values) = bench_values(code, msg, crate_ident) else {
+            continue;
+        };
+        let msg_type = msg.get_type_kamel();
+        let size = match canfd_dlc(msg.size).filter(|_| code.canfd) {
+            Some(dlc) => CANFD_LENGTHS[usize::from(dlc)],
+            None => msg.size,
+        };
+        let _ = write!(
+            tests,
+            r#"
+#[test]
+fn {msg_snake}_round_trip() {{
+    let values = {values};
+    let mut data = [0u8; {size}];
+    {crate_ident}::{msg_type}::encode(&values, &mut data).expect("{msg_type} values");
+    let decoded = {crate_ident}::{msg_type}::decode(&data).expect("{msg_type} frame");
+    let mut again = [0u8; {size}];
+    {crate_ident}::{msg_type}::encode(&decoded, &mut again).expect("{msg_type} decoded values");
+    assert_eq!(data, again);
+}}
+"#,
+            msg_snake = msg_type.to_snake_case()
+        );
+    }
+    tests
+}
+
+/// `include!` argument of the generated file: absolute paths as is, relative ones from the
+/// manifest directory of the crate compiling the doctests or benchmarks.
+fn include_expr(include: &Path) -> String {
+    let path = include.display().to_string();
+    if include.is_absolute() {
+        format!("{path:?}")
+    } else {
+        format!("concat!(env!(\"CARGO_MANIFEST_DIR\"), {:?})", format!("/{path}"))
+    }
+}
+
+/// Criterion benchmarks of `DbcParser::emit_benches()`: `decode()` and `encode()` of each message
+/// with signals, then `CanMsgPool::update()` (and `StaticPool::update()`) of the same frames.
+fn gen_benches(code: &DbcCodeGen, uid: &str, include: &Path) -> String {
+    let prelude = if code.sockcan {
+        "sockcan::prelude".to_owned()
+    } else {
+        format!("{uid}::sockcan::prelude")
+    };
+    // no_std code refers to the alloc crate, benchmarks run with std
+    let alloc = if code.no_std { "extern crate alloc;\n" } else { "" };
+    let mut benches = format!(
+        r#"// Criterion benchmarks of the {uid} codecs, generated by dbcparser: do not edit.
+// Declare them in Cargo.toml as a `[[bench]]` with `harness = false`, then `cargo bench`.
+// Frames hold the first multiplexed page and every other signal at the middle of its range.
+
+{alloc}use criterion::{{criterion_group, criterion_main, Criterion}};
+use std::hint::black_box;
+use {prelude}::*;
+
+include!({include});
+"#,
+        include = include_expr(include)
+    );
+
+    // (message, type, snake name, frame length, encodable) of the benchmarked messages
+    let mut messages = Vec::new();
+    for msg in &code.dbcfd.messages {
+        if msg.signals.iter().all(Signal::is_byte_array) {
+            continue;
+        }
+        let msg_type = msg.get_type_kamel();
+        let msg_snake = msg_type.to_snake_case();
+        // FD frames are padded to the length of their DLC
+        let size = match canfd_dlc(msg.size).filter(|_| code.canfd) {
+            Some(dlc) => CANFD_LENGTHS[usize::from(dlc)],
+            None => msg.size,
+        };
+        let values = bench_values(code, msg, uid);
+        let _ = match &values {
+            Some(values) => write!(
+                benches,
+                r#"
+fn {msg_snake}_values() -> {uid}::{msg_type}::SignalValues {{
+    {values}
+}}
+
+fn {msg_snake}_frame() -> [u8; {size}] {{
+    let mut data = [0u8; {size}];
+    {uid}::{msg_type}::encode(&{msg_snake}_values(), &mut data).expect("{msg_type} values");
+    data
+}}
+"#
+            ),
+            None => write!(
+                benches,
+                r#"
+/// Some {msg_type} signal has no value passing its range check, decode zeros
+fn {msg_snake}_frame() -> [u8; {size}] {{
+    [0u8; {size}]
+}}
+"#
+            ),
+        };
+        messages.push((msg, msg_type, msg_snake, size, values.is_some()));
+    }
+
+    benches.push_str(
+        r#"
+fn decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode");"#,
+    );
+    for (_, msg_type, msg_snake, ..) in &messages {
+        let _ = write!(
+            benches,
+            r#"
+    let data = {msg_snake}_frame();
+    group.bench_function("{msg_type}", |b| b.iter(|| {uid}::{msg_type}::decode(black_box(&data))));"#
+        );
+    }
+    benches.push_str(
+        r#"
+    group.finish();
+}
+
+fn encode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("encode");"#,
+    );
+    for (_, msg_type, msg_snake, size, _) in messages.iter().filter(|(.., encodable)| *encodable) {
+        let _ = write!(
+            benches,
+            r#"
+    let values = {msg_snake}_values();
+    group.bench_function("{msg_type}", |b| {{
+        let mut data = [0u8; {size}];
+        b.iter(|| {uid}::{msg_type}::encode(black_box(&values), &mut data))
+    }});"#
+        );
+    }
+
+    let mut groups = vec!["decode", "encode", "pool_update"];
+    let mut pools = vec![("pool_update", "let pool = ", "CanMsgPool::new(\"bench\")", ".is_ok()")];
+    if code.static_pool {
+        groups.push("static_pool_update");
+        pools.push((
+            "static_pool_update",
+            "let mut pool = ",
+            "StaticPool::new()",
+            ".map(|msg| msg.is_some())",
+        ));
+    }
+    for (group, binding, pool, result) in pools {
+        let _ = write!(
+            benches,
+            r#"
+    group.finish();
+}}
+
+fn {group}(c: &mut Criterion) {{
+    let mut group = c.benchmark_group("{group}");
+    {binding}{uid}::{pool};"#
+        );
+        for (msg, msg_type, msg_snake, size, _) in &messages {
+            let _ = write!(
+                benches,
+                r#"
+    let frame = CanMsgData {{
+        canid: {id},
+        stamp: 1,
+        opcode: CanBcmOpCode::RxChanged,
+        len: {size},
+        data: {msg_snake}_frame().to_vec(),
+    }};
+    group.bench_function("{msg_type}", |b| b.iter(|| pool.update(black_box(&frame)){result}));"#,
+                id = msg.id.raw()
+            );
+        }
+    }
+    let _ = write!(
+        benches,
+        r#"
+    group.finish();
+}}
+
+criterion_group!(benches, {});
+criterion_main!(benches);
+"#,
+        groups.join(", ")
+    );
+    benches
+}
+
+pub trait Text2Str<T> {
+    /// Write a line with indentation.
+    ///
+    /// # Errors
+    /// Propagates any I/O error from the underlying writer.
+    fn write(&self, indent: &str, text: T) -> io::Result<()>;
+}
+
+impl Text2Str<&str> for DbcCodeGen {
+    fn write(&self, indent: &str, text: &str) -> io::Result<()> {
+        let nl = "\n";
+        if let Some(capture) = &self.capture {
+            let mut capture = capture.borrow_mut();
+            capture.extend_from_slice(indent.as_bytes());
+            capture.extend_from_slice(text.as_bytes());
+            capture.extend_from_slice(nl.as_bytes());
+        } else if let Some(outfd) = &self.outfd {
+            let mut outfd = outfd.try_clone()?;
+            outfd.write_all(indent.as_bytes())?;
+            outfd.write_all(text.as_bytes())?;
+            outfd.write_all(nl.as_bytes())?;
+        } else {
+            let mut outfd = io::stdout();
+            outfd.write_all(indent.as_bytes())?;
+            outfd.write_all(text.as_bytes())?;
+            outfd.write_all(nl.as_bytes())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Text2Str<String> for DbcCodeGen {
+    fn write(&self, indent: &str, text: String) -> io::Result<()> {
+        Self::write(self, indent, text.as_str())
+    }
+}
+
+impl DbcCodeGen {
+    fn output<T>(&self, indent: &str, text: T) -> io::Result<()>
+    where
+        DbcCodeGen: Text2Str<T>,
+    {
+        Self::write(self, indent, text)
+    }
+
+    /// Crate of the `core` items (cells, fmt, Any): `std`, or `core` with `no_std`.
+    fn core_crate(&self) -> &'static str {
+        if self.no_std {
+            "core"
+        } else {
+            "std"
+        }
+    }
+
+    /// Crate of the allocating items (Rc, collections): `std`, or `alloc` with `no_std`.
+    fn alloc_crate(&self) -> &'static str {
+        if self.no_std {
+            "alloc"
+        } else {
+            "std"
+        }
+    }
+
+    /// Imports of the `alloc` items the std prelude provides, for each module with `no_std`.
+    fn alloc_prelude(&self) -> &'static str {
+        if self.no_std {
+            "\n    #[allow(unused_imports)]\n    use alloc::{borrow::ToOwned, boxed::Box, format, string::{String, ToString}, vec, vec::Vec};"
+        } else {
+            ""
+        }
+    }
+
+    /// Statement reporting an unexpected internal error, nothing with `no_std`.
+    fn trace(&self, text: &str) -> String {
+        if self.no_std {
+            String::new()
+        } else {
+            format!("println!(\"{text}\");")
+        }
+    }
+
+    /// Path of the sockcan prelude from a module `depth` levels below the uid module.
+    fn sockcan_prelude(&self, depth: usize) -> String {
+        match (self.sockcan, depth) {
+            (true, _) => "sockcan::prelude::*".to_owned(),
+            (false, 0) => "self::sockcan::prelude::*".to_owned(),
+            (false, depth) => format!("{}sockcan::prelude::*", "super::".repeat(depth)),
+        }
+    }
+
+    fn signal_flags(&self, msg: &Message, sig: &Signal) -> SignalFlags {
+        self.overrides
+            .get(&(msg.id.raw(), sig.name.clone()))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn float_size(&self, msg: &Message, sig: &Signal) -> Option<u64> {
+        signal_float_size(&self.dbcfd, msg, sig)
+    }
+
+    /// Physical type of `sig`, `f64` for float signals whatever their scaling.
+    fn data_type(&self, msg: &Message, sig: &Signal) -> String {
+        match self.float_size(msg, sig) {
+            Some(_) => "f64".to_owned(),
+            None => sig.get_data_type(),
+        }
+    }
+
+    /// Range check of `sig`, with its override if any.
+    fn range_check_for(&self, msg: &Message, sig: &Signal) -> bool {
+        self.signal_flags(msg, sig).range_check.unwrap_or(self.range_check)
+    }
+
+    /// E2E checksum signal of `msg` and its checksum, if any.
+    fn checksum_for<'a>(&'a self, msg: &'a Message) -> Option<(&'a Signal, &'a Checksum)> {
+        let checksum = self.checksums.get(&msg.id.raw())?;
+        let sig = msg.signals.iter().find(|sig| sig.name == checksum.signal)?;
+        Some((sig, checksum))
+    }
+
+    /// Rolling counter of `msg`, if any.
+    fn counter_for<'a>(&self, msg: &'a Message) -> io::Result<Option<&'a Signal>> {
+        let mut counters = msg.signals.iter().filter(|sig| self.signal_flags(msg, sig).counter);
+        let Some(counter) = counters.next() else { return Ok(None) };
+        if let Some(other) = counters.next() {
+            return Err(Error::other(format!(
+                "message:{} has several counters: {} and {}",
+                msg.get_type_kamel(),
+                counter.name,
+                other.name
+            )));
+        }
+        if counter.multiplexer_indicator != MultiplexIndicator::Plain
+            || !(2..=64).contains(&counter.size)
+            || counter.has_scaling()
+        {
+            return Err(Error::other(format!(
+                "message:{} counter:{} must be a plain 2 to 64 bits signal without factor/offset",
+                msg.get_type_kamel(),
+                counter.name
+            )));
+        }
+        Ok(Some(counter))
+    }
+
+    /// True when `sig` is flagged as text.
+    fn text_for(&self, msg: &Message, sig: &Signal) -> bool {
+        self.signal_flags(msg, sig).text
+    }
+
+    /// Serde support of `sig`, with its override if any.
+    fn serde_json_for(&self, msg: &Message, sig: &Signal) -> bool {
+        self.signal_flags(msg, sig).serde_json.unwrap_or(self.serde_json)
+    }
+
+    /// `#[cfg]` line gating a serde-only item behind the serde feature, followed by `indent`
+    /// for the item; empty when serde is not feature gated.
+    fn serde_cfg(&self, indent: &str) -> String {
+        match &self.serde_feature {
+            Some(feature) => format!("#[cfg(feature = \"{feature}\")]\n{indent}"),
+            None => String::new(),
+        }
+    }
+
+    /// Serde attribute `attr`, applied only with the serde feature when gated.
+    fn serde_attr(&self, attr: &str) -> String {
+        match &self.serde_feature {
+            Some(feature) => format!("#[cfg_attr(feature = \"{feature}\", {attr})]"),
+            None => format!("#[{attr}]"),
+        }
+    }
+
+    /// Serde `traits` of a `#[derive(..)]` list when `enabled`: the suffix of the list, or when
+    /// feature gated, a `#[cfg_attr]` line following the derive.
+    fn serde_derives(&self, enabled: bool, traits: &str, indent: &str) -> (String, String) {
+        match &self.serde_feature {
+            _ if !enabled => (String::new(), String::new()),
+            Some(_) => (
+                String::new(),
+                format!("\n{indent}{}", self.serde_attr(&format!("derive({traits})"))),
+            ),
+            None => (format!(", {traits}"), String::new()),
+        }
+    }
+
+    /// True when any signal of `msg` (of the database when None) derives serde traits.
+    fn uses_serde(&self, msg: Option<&Message>) -> bool {
+        let serde_msg = |msg: &Message| msg.signals.iter().any(|sig| self.serde_json_for(msg, sig));
+        match msg {
+            _ if self.serde_json => true,
+            Some(msg) => serde_msg(msg),
+            None => self.dbcfd.messages.iter().any(serde_msg),
+        }
+    }
+}
+
+pub const DEFAULT_HEADER: &str = r#"
+// -----------------------------------------------------------------------
+//              <- DBC file Rust mapping ->
+// -----------------------------------------------------------------------
+//  Do not edit this file it will be regenerated automatically by cargo.
+//  Check:
+//   - build.rs at project root for dynamically mapping
+//   - example/demo/dbc-log/??? for static values
+//  Reference: iot.bzh/Redpesk canbus-rs code generator
+// -----------------------------------------------------------------------
+
+// Tell rustfmt (stable) to skip formatting this whole file
+#[rustfmt::skip]
+
+#[allow(
+    warnings,
+    clippy::all,
+    clippy::pedantic,
+    clippy::nursery,
+    clippy::redundant_field_names,
+    clippy::similar_names
+)]
+"#;
+
+impl DbcParser {
+    #[must_use]
+    pub fn new(uid: &'static str) -> Self {
+        DbcParser {
+            uid,
+            range_check: true,
+            serde_json: true,
+            serde_feature: None,
+            infile: None,
+            outfile: None,
+            header: None,
+            whitelist: None,
+            blacklist: None,
+            nodes: Vec::new(),
+            plugin_abi: false,
+            node_id: 0,
+            drop_signals: Vec::new(),
+            rename_map: RenameMap::default(),
+            dbc_excerpts: false,
+            sna: Vec::new(),
+            check_values: Vec::new(),
+            j1939: false,
+            sockcan: true,
+            no_std: false,
+            static_pool: false,
+            canfd: false,
+            crate_root: false,
+            module_doc: None,
+            overrides: Vec::new(),
+            text_signals: Vec::new(),
+            counters: Vec::new(),
+            checksums: Vec::new(),
+            crc_algorithms: Vec::new(),
+            signal_structs: Vec::new(),
+            message_structs: Vec::new(),
+            json_ir: None,
+            json_schema: None,
+            markdown: None,
+            emit_tests: None,
+            emit_benches: None,
+            emit_crate: None,
+            copyright: None,
+            clock: None,
+            timestamp: true,
+        }
+    }
+
+    pub fn dbcfile<P: AsRef<Path>>(&mut self, dbcfile: P) -> &mut Self {
+        self.infile = Some(dbcfile.as_ref().to_path_buf());
+        self
+    }
+
+    pub fn outfile<P: AsRef<Path>>(&mut self, outfile: P) -> &mut Self {
+        self.outfile = Some(outfile.as_ref().to_path_buf());
+        self
+    }
+
+    /// Banner written before the generated code. `{dbc_path}`, `{dbc_sha256}`, `{git_rev}`
+    /// (revision of the repository holding the DBC) and `{date}` are expanded.
+    pub fn header(&mut self, header: &'static str) -> &mut Self {
+        self.header = Some(header);
+        self
+    }
+
+    pub fn whitelist(&mut self, canids: Vec<u32>) -> &mut Self {
+        self.whitelist = Some(canids);
+        self
+    }
+
+    pub fn blacklist(&mut self, canids: Vec<u32>) -> &mut Self {
+        self.blacklist = Some(canids);
+        self
+    }
+
+    /// Only generate the messages `node` transmits (`BO_` or `BO_TX_BU_`) or receives at least
+    /// one signal of. Repeated calls keep the messages of every given node.
+    pub fn node(&mut self, node: &str) -> &mut Self {
+        self.nodes.push(node.to_owned());
+        self
+    }
+
+    pub fn range_check(&mut self, flag: bool) -> &mut Self {
+        self.range_check = flag;
+        self
+    }
+
+    pub fn serde_json(&mut self, flag: bool) -> &mut Self {
+        self.serde_json = flag;
+        self
+    }
+
+    /// Compile the generated serde code (imports, derives, `Serialize` impls and `to_json()`)
+    /// only with the `feature` cargo feature of the crate including it, which then enables its
+    /// `serde` and `serde_json` dependencies.
+    pub fn serde_feature(&mut self, feature: &str) -> &mut Self {
+        self.serde_feature = Some(feature.to_owned());
+        self
+    }
+
+    /// Append a fixed C ABI (`canforge_plugin_*`) wrapping the generated pool, so the output
+    /// can be built as a cdylib and loaded by a generic binder.
+    pub fn plugin_abi(&mut self, flag: bool) -> &mut Self {
+        self.plugin_abi = flag;
+        self
+    }
+
+    /// CANopen node id used to resolve `$NODEID` when the input is an EDS/DCF file.
+    pub fn node_id(&mut self, node_id: u8) -> &mut Self {
+        self.node_id = node_id;
+        self
+    }
+
+    /// Omit signals matching any pattern from the generated code while keeping their message.
+    /// Patterns are `Signal` or `Message.Signal` with DBC names, `*` matches any sequence.
+    pub fn drop_signals(&mut self, patterns: Vec<String>) -> &mut Self {
+        self.drop_signals = patterns;
+        self
+    }
+
+    /// Replace DBC message/signal names before generated identifiers are derived from them.
+    /// Whitelist, blacklist and drop patterns still use the original DBC names.
+    pub fn rename_map(&mut self, renames: RenameMap) -> &mut Self {
+        self.rename_map = renames;
+        self
+    }
+
+    /// Quote the source `BO_`/`SG_` lines in each message doc comment. For EDS/LDF input the
+    /// quoted lines are those of the converted DBC.
+    pub fn dbc_excerpts(&mut self, flag: bool) -> &mut Self {
+        self.dbc_excerpts = flag;
+        self
+    }
+
+    /// Signals reporting "not available" instead of a value when their raw value matches.
+    /// Entries are `Signal[=raw]` or `Message.Signal[=raw]` with DBC names, `*` matches any
+    /// sequence, raw defaults to all ones. `GenSigSNA` signal attributes are always applied.
+    pub fn sna(&mut self, patterns: Vec<String>) -> &mut Self {
+        self.sna = patterns;
+        self
+    }
+
+    /// Signals flagging raw values missing from their `VAL_` table, to catch a DBC and a
+    /// sender that disagree: the value is still decoded but the status is `Error` and
+    /// `is_out_of_domain()` true. Entries are `Signal` or `Message.Signal` with DBC names, `*`
+    /// matches any sequence; signals without a table are ignored.
+    pub fn check_values(&mut self, patterns: Vec<String>) -> &mut Self {
+        self.check_values = patterns;
+        self
+    }
+
+    /// Apply the SAE J1939-71 conventions to unsigned signals without an explicit SNA: the
+    /// 0xFE../0xFF.. raw ranges (per parameter size) report an error indicator or "not
+    /// available" instead of a physical value. Every extended message is also a parameter
+    /// group with `PGN`/`PRIORITY`/`SOURCE_ADDRESS`, without a `VFrameFormat` attribute.
+    pub fn j1939(&mut self, flag: bool) -> &mut Self {
+        self.j1939 = flag;
+        self
+    }
+
+    /// When false, the generated code no longer imports the sockcan crate: a local `sockcan`
+    /// module defines the few frame, error and status types and traits it uses, so the output
+    /// builds on hosts without SocketCAN, e.g. to decode logs offline. Default true.
+    pub fn sockcan(&mut self, flag: bool) -> &mut Self {
+        self.sockcan = flag;
+        self
+    }
+
+    /// Generate code free of `std` for bare-metal targets: items come from `core` and `alloc`,
+    /// the local sockcan module replaces the sockcan crate, `verify_dbc()` and the callback
+    /// error traces are left out. The including crate declares `#![no_std]` and
+    /// `extern crate alloc;`. Not compatible with `plugin_abi`. Default false.
+    pub fn no_std(&mut self, flag: bool) -> &mut Self {
+        self.no_std = flag;
+        self
+    }
+
+    /// Also generate a `StaticPool` holding every message by value: each message module gets a
+    /// `StaticMessage` owning its signal structs, and `StaticPool::update()` dispatches a frame
+    /// with a `match` on its id. Decoding then neither allocates nor borrows a `RefCell`; the
+    /// shared `CanMsgPool` stays available for callbacks and subscriptions. Default false.
+    pub fn static_pool(&mut self, flag: bool) -> &mut Self {
+        self.static_pool = flag;
+        self
+    }
+
+    /// CAN FD mode: messages up to 64 bytes, `update()` and `set_values()` check the frame
+    /// length against the message size instead of assuming 8 bytes, and each message exposes
+    /// its `SIZE` and the FD `DLC` carrying it. Default false.
+    pub fn canfd(&mut self, flag: bool) -> &mut Self {
+        self.canfd = flag;
+        self
+    }
+
+    /// Emit the items at the top level of the file instead of in the `uid` module, so the output
+    /// is a complete crate root, e.g. the `lib.rs` of a vehicle specific crate. The module
+    /// attributes become crate attributes, `#![no_std]` included with `no_std`, and the lints
+    /// the default header allows are allowed on the crate. Headers holding attributes, doc
+    /// examples and benchmarks need the `uid` module and are refused. Default false.
+    pub fn crate_root(&mut self, flag: bool) -> &mut Self {
+        self.crate_root = flag;
+        self
+    }
+
+    /// Documentation of the generated module (of the crate with `crate_root`), written as `//!`
+    /// lines. With `crate_root` and no doc, the crate is described by its DBC file name.
+    pub fn module_doc(&mut self, doc: &str) -> &mut Self {
+        self.module_doc = Some(doc.to_owned());
+        self
+    }
+
+    /// Per-message or per-signal exceptions to `range_check` and `serde_json`, e.g. no range
+    /// check on a signal known to be out of spec. The last matching override wins.
+    pub fn overrides(&mut self, overrides: Vec<SignalOverride>) -> &mut Self {
+        self.overrides = overrides;
+        self
+    }
+
+    /// Signals holding ASCII text (VIN, firmware version), "Signal" or "Message.Signal" with DBC
+    /// names, `*` matches any sequence. They get `get_text()`/`set_text()`, characters in frame
+    /// byte order. Matching signals need a whole number of bytes and no factor/offset.
+    pub fn text_signals(&mut self, patterns: Vec<String>) -> &mut Self {
+        self.text_signals = patterns;
+        self
+    }
+
+    /// Rolling counter signals, "Signal" or "Message.Signal" with DBC names, `*` matches any
+    /// sequence. The `update()` of their message ignores frames whose counter is repeated or
+    /// went backward, see the generated `CounterStatus`. At most one counter per message.
+    pub fn counters(&mut self, patterns: Vec<String>) -> &mut Self {
+        self.counters = patterns;
+        self
+    }
+
+    /// E2E checksum signals, "Signal[=ALGORITHM]" or "Message.Signal[=ALGORITHM]" with DBC
+    /// names, `*` matches any sequence; the algorithm defaults to the one of the signal size.
+    /// Signals with a checksum role in their `GenSigFuncType`/`GenSigEVName` attribute are
+    /// always checksums. The `set_values()` of their message writes the next rolling counter
+    /// and the checksum, its `update()` ignores frames whose checksum does not match.
+    pub fn checksums(&mut self, patterns: Vec<String>) -> &mut Self {
+        self.checksums = patterns;
+        self
+    }
+
+    /// CRC algorithms added to the built-in ones (`e2e::builtin_crc_algorithms()`), replacing
+    /// those with the same name.
+    pub fn crc_algorithms(&mut self, algorithms: Vec<CrcAlgorithm>) -> &mut Self {
+        self.crc_algorithms = algorithms;
+        self
+    }
+
+    /// Signals grouped across messages into user-named structs. Each gets a pool getter,
+    /// `get_<struct>()`, returning all members at once when every member was received and is
+    /// at most `max_age_ms` older than the last frame of the pool.
+    pub fn signal_structs(&mut self, structs: Vec<SignalStruct>) -> &mut Self {
+        self.signal_structs = structs;
+        self
+    }
+
+    /// Application structs converted from/to messages, keeping domain types decoupled from the
+    /// generated ones: `From<&DbcMessage>` and `From<&SignalValues>` build the struct,
+    /// `From<&Struct> for SignalValues` feeds the stateless `encode()`.
+    pub fn message_structs(&mut self, structs: Vec<MessageStruct>) -> &mut Self {
+        self.message_structs = structs;
+        self
+    }
+
+    /// Also write the parsed database as JSON to `path`, see [`crate::export::dbc_to_json`].
+    pub fn json_ir<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
+        self.json_ir = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Also write a JSON Schema of the exported message JSON to `path`, see
+    /// [`crate::export::dbc_to_json_schema`].
+    pub fn json_schema<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
+        self.json_schema = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Also write a Markdown reference of the database to `path`, see
+    /// [`crate::export::dbc_to_markdown`].
+    pub fn markdown<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
+        self.markdown = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Add an `# Examples` section to each message doc comment, encoding every signal into a
+    /// frame, decoding it and asserting the values read back. Each example is a doctest including
+    /// the generated file from `include`, so `cargo test` checks it when the code belongs to a
+    /// library crate; a relative `include` is taken from the crate manifest directory, e.g.
+    /// `src/dbc.rs`, an absolute one is kept, e.g. under `OUT_DIR`.
+    pub fn emit_tests<P: AsRef<Path>>(&mut self, include: P) -> &mut Self {
+        self.emit_tests = Some(include.as_ref().to_path_buf());
+        self
+    }
+
+    /// Also write criterion benchmarks to `path`: `decode()` and `encode()` of each message with
+    /// a representative frame, then `CanMsgPool::update()` (and `StaticPool::update()`) of the
+    /// same frames, to measure the per-frame cost of a DBC and compare generation options. The
+    /// benchmarks include the generated file from `include`, relative to the crate manifest
+    /// directory or absolute like for [`DbcParser::emit_tests`].
+    pub fn emit_benches<P: AsRef<Path>, Q: AsRef<Path>>(
+        &mut self,
+        path: P,
+        include: Q,
+    ) -> &mut Self {
+        self.emit_benches = Some((path.as_ref().to_path_buf(), include.as_ref().to_path_buf()));
+        self
+    }
+
+    /// Write a complete cargo package in `dir`, named after it: `Cargo.toml` versioned after the
+    /// DBC `VERSION` line with pinned dependencies (see [`crate::package`]), `src/lib.rs` holding
+    /// the code and `tests/messages.rs` checking that each message frame decodes and encodes
+    /// back to the same bytes. Implies `crate_root`, `outfile` defaults to `src/lib.rs`.
+    pub fn emit_crate<P: AsRef<Path>>(&mut self, dir: P) -> &mut Self {
+        let dir = dir.as_ref();
+        if self.outfile.is_none() {
+            self.outfile = Some(dir.join("src").join("lib.rs"));
+        }
+        self.emit_crate = Some(dir.to_path_buf());
+        self.crate_root = true;
+        self
+    }
+
+    /// Replace the IoT.bzh/Apache-2.0 copyright block of the generated banner.
+    pub fn copyright(&mut self, copyright: Copyright) -> &mut Self {
+        self.copyright = Some(copyright);
+        self
+    }
+
+    /// Time source of the banner generation time and of the `{date}` header placeholder.
+    /// Without one, `SOURCE_DATE_EPOCH` pins the time when set, the system clock gives it
+    /// otherwise.
+    pub fn clock(&mut self, clock: Box<dyn Clock>) -> &mut Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Write the generation time in the banner. Default true; false makes the output only
+    /// depend on the DBC and the options, for reproducible builds.
+    pub fn timestamp(&mut self, flag: bool) -> &mut Self {
+        self.timestamp = flag;
+        self
+    }
+
+    fn check_list(canid: MessageId, list: &[u32]) -> bool {
+        list.binary_search(&canid.raw()).is_ok()
+    }
+
+    /// The code is written to a temporary file next to `outfile` and renamed into place once
+    /// complete, so a failed or interrupted generation never leaves a truncated output. The
+    /// JSON IR and Markdown outputs, when set, come from the same parse.
+    ///
+    /// # Errors
+    /// Propagates any I/O error: reading the DBC, parsing, writing output, and time formatting.
+    pub fn generate(&mut self) -> io::Result<()> {
+        let result = self.emit(false, true);
+        if let Some(outfile) = &self.outfile {
+            let partial = partial_path(outfile);
+            match &result {
+                Ok(_) => fs::rename(&partial, outfile)?,
+                Err(_) => {
+                    let _ = fs::remove_file(&partial);
+                },
+            }
+        }
+        result.map(|_| ())
+    }
+
+    /// Generate the code in memory, `outfile` is left untouched. The JSON IR and Markdown
+    /// outputs, when set, are still written.
+    ///
+    /// # Errors
+    /// Propagates any I/O error: reading the DBC, parsing, writing outputs, and time formatting.
+    pub fn generate_string(&mut self) -> io::Result<String> {
+        let code = self.emit(true, true)?.unwrap_or_default();
+        String::from_utf8(code).map_err(Error::other)
+    }
+
+    /// Compare `existing` generated code with what the current DBC and options produce.
+    /// Returns `None` when it is up to date, a summary of the first difference otherwise.
+    /// The "code generated from" line, holding the input path and generation time, is ignored.
+    ///
+    /// # Errors
+    /// Propagates any I/O error: reading the DBC, parsing, and time formatting.
+    pub fn check_generated(&mut self, existing: &str) -> io::Result<Option<String>> {
+        let generated = self.emit(true, false)?.unwrap_or_default();
+        let generated = String::from_utf8(generated).map_err(Error::other)?;
+        Ok(generated_drift(existing, &generated))
+    }
+
+    /// Generate the code into `outfile` (its temporary file) or memory when `capture`, then the
+    /// JSON IR and Markdown outputs when `artifacts`.
+    #[allow(clippy::too_many_lines)]
+    fn emit(&mut self, capture: bool, artifacts: bool) -> io::Result<Option<Vec<u8>>> {
+        let Some(infile) = &self.infile else {
+            return Err(Error::other("setting dbcpath is mandatory"));
+        };
+        let _keep_names = KeepNames::set(self.rename_map.keep_names);
+        // the plugin is loaded by a std host, and its glue allocates through std
+        if self.plugin_abi && self.no_std {
+            return Err(Error::other("plugin ABI is not available with no_std"));
+        }
+        if let Some(feature) = &self.serde_feature {
+            let valid = |char: char| char.is_ascii_alphanumeric() || "-_+.".contains(char);
+            if feature.is_empty() || !feature.chars().all(valid) {
+                return Err(Error::other(format!(
+                    "serde feature:{feature:?} not a cargo feature name"
+                )));
+            }
+        }
+        let package = match &self.emit_crate {
+            Some(_) if !self.crate_root => {
+                return Err(Error::other("emit crate needs crate_root"));
+            },
+            Some(dir) => Some(crate::package::crate_name(dir)?),
+            None => None,
+        };
+        if self.crate_root {
+            // a crate root starts with its inner attributes, and no module is left to include
+            let attributes = self.header.is_some_and(|header| {
+                header.lines().any(|line| line.trim_start().starts_with("#["))
+            });
+            if attributes {
+                return Err(Error::other("header attributes need the uid module, not crate_root"));
+            }
+            if self.emit_tests.is_some() || self.emit_benches.is_some() {
+                return Err(Error::other(
+                    "doc examples and benchmarks include the uid module, not crate_root",
+                ));
+            }
+        }
+
+        // open and parse dbc input file
+        let mut buffer = fs::read_to_string(infile)?;
+        let dbc_sha256 = sha256_hex(buffer.as_bytes());
+        let dbc_version = crate::package::dbc_version(&buffer);
+        if crate::eds::is_eds_file(infile) {
+            buffer = crate::eds::eds_to_dbc(&buffer, self.node_id)?;
+        } else if crate::ldf::is_ldf_file(infile) {
+            buffer = crate::ldf::ldf_to_dbc(&buffer)?;
+        }
+        buffer = resolve_value_tables(&buffer)?;
+
+        let attributes = parse_attributes(&buffer);
+        let signal_attributes = parse_signal_attribute_values(&buffer);
+        let message_attributes = parse_message_attribute_values(&buffer);
+        let excerpts = if self.dbc_excerpts { dbc_excerpts(&buffer) } else { HashMap::new() };
+        let mut dbcfd = match Dbc::try_from(buffer.as_str()) {
+            Err(error) => return Err(Error::other(error.to_string())),
+            Ok(dbcfd) => dbcfd,
+        };
+
+        // sort message by canid
+        dbcfd.messages.sort_by_key(|msg| msg.id.raw());
+        check_renames(&dbcfd, &self.rename_map)?;
+
+        if let Some(mut list) = self.whitelist.clone() {
+            if list.is_empty() {
+                // empty whitelist means "keep everything"
+                dbcfd.messages.retain(|_| true);
+            } else {
+                list.sort_unstable();
+                dbcfd.messages.retain(|msg| DbcParser::check_list(msg.id, &list));
+            }
+        }
+
+        if let Some(mut list) = self.blacklist.clone() {
+            list.sort_unstable();
+            dbcfd.messages.retain(|msg| !DbcParser::check_list(msg.id, &list));
+        }
+
+        if !self.nodes.is_empty() {
+            retain_nodes(&mut dbcfd, &self.nodes)?;
+        }
+
+        // sort message by canid
+        dbcfd.messages.sort_by_key(|msg| msg.id.raw());
+
+        if !self.drop_signals.is_empty() {
+            drop_signals(&mut dbcfd, &self.drop_signals)?;
+        }
+
+        let mut sna = signal_sna(&dbcfd, &signal_attributes, &self.sna, self.j1939)?;
+        let mut domains = signal_domains(&dbcfd, &self.check_values);
+        let mut start_values = signal_start_values(&dbcfd, &attributes, &signal_attributes)?;
+        let cycle_times = message_cycle_times(&dbcfd, &attributes, &message_attributes)?;
+        let j1939_messages = j1939_messages(&dbcfd, &attributes, &message_attributes, self.j1939);
+        let mut spns = signal_spns(&dbcfd, &signal_attributes)?;
+        let message_attribute_values =
+            message_attribute_literals(&dbcfd, &attributes, &message_attributes)?;
+        let mut signal_attribute_values =
+            signal_attribute_literals(&dbcfd, &attributes, &signal_attributes)?;
+        let mut overrides =
+            signal_overrides(&dbcfd, &self.overrides, &self.text_signals, &self.counters);
+        let mut algorithms = builtin_crc_algorithms();
+        for custom in &self.crc_algorithms {
+            algorithms.retain(|algo| !algo.name.eq_ignore_ascii_case(&custom.name));
+            algorithms.push(custom.clone());
+        }
+        let mut checksums = e2e_signals(
+            &dbcfd,
+            (&attributes, &signal_attributes, &message_attributes),
+            &self.checksums,
+            &algorithms,
+            &mut overrides,
+        )?;
+        let mut signal_structs = resolve_signal_structs(&dbcfd, &self.signal_structs)?;
+        if !self.rename_map.is_empty() {
+            let renamed = rename(&mut dbcfd, &self.rename_map)?;
+            sna = renamed_keys(sna, &renamed);
+            domains = renamed_keys(domains, &renamed);
+            start_values = renamed_keys(start_values, &renamed);
+            spns = renamed_keys(spns, &renamed);
+            signal_attribute_values = renamed_keys(signal_attribute_values, &renamed);
+            overrides = renamed_keys(overrides, &renamed);
+            for (id, checksum) in &mut checksums {
+                if let Some((_, _, new)) = renamed
+                    .iter()
+                    .find(|(msg_id, old, _)| msg_id.raw() == *id && *old == checksum.signal)
+                {
+                    checksum.signal.clone_from(new);
+                }
+            }
+            for (_, id, name) in signal_structs.iter_mut().flat_map(|entry| &mut entry.fields) {
+                if let Some((_, _, new)) =
+                    renamed.iter().find(|(msg_id, old, _)| msg_id.raw() == *id && old == name)
+                {
+                    name.clone_from(new);
+                }
+            }
+        }
+
+        let message_structs = resolve_message_structs(&dbcfd, &self.message_structs)?;
+
+        if let Some(dir) = self.emit_crate.as_ref().filter(|_| artifacts) {
+            fs::create_dir_all(dir.join("src"))?;
+            fs::create_dir_all(dir.join("tests"))?;
+        }
+        let outfd = match &self.outfile {
+            Some(outfile) if !capture => {
+                let outfd = File::create(partial_path(outfile))?;
+                Some(outfd)
+            },
+            _ => None,
+        };
+
+        // open/create output file
+        let code = DbcCodeGen {
+            dbcfd,
+            outfd,
+            capture: capture.then(RefCell::default),
+            range_check: self.range_check,
+            serde_json: self.serde_json,
+            serde_feature: self.serde_feature.clone(),
+            excerpts,
+            sna,
+            domains,
+            start_values,
+            cycle_times,
+            j1939_messages,
+            checksums,
+            spns,
+            attributes,
+            message_attributes: message_attribute_values,
+            signal_attributes: signal_attribute_values,
+            overrides,
+            signal_structs,
+            message_structs,
+            sockcan: self.sockcan && !self.no_std,
+            no_std: self.no_std,
+            static_pool: self.static_pool,
+            canfd: self.canfd,
+            examples: self
+                .emit_tests
+                .as_ref()
+                .map(|include| DocExamples { uid: self.uid, include: include_expr(include) }),
+        };
+
+        let epoch = match self.clock {
+            Some(_) => None,
+            None => source_date_epoch()?.map(|secs| FixedClock(secs.saturating_mul(1_000_000))),
+        };
+        let clock = match (&self.clock, &epoch) {
+            (Some(clock), _) => GenClock { clock: clock.as_ref(), local: true },
+            (None, Some(epoch)) => GenClock { clock: epoch, local: false },
+            (None, None) => GenClock { clock: &SystemClock, local: true },
+        };
+        if let Some(header) = self.header {
+            code_output!(code, expand_header(header, infile, &dbc_sha256, &clock)?)?;
+        }
+
+        // change Rust default to stick as much as possible on can names
+        let gen_time =
+            if self.timestamp { format!(" ({})", clock.format("%c")?) } else { String::new() };
+
+        let uid = self.uid;
+        let module = if self.crate_root { String::new() } else { format!("\nmod {uid} {{") };
+        let doc: String = match &self.module_doc {
+            Some(doc) => {
+                doc.lines().map(|line| format!("\n//! {line}").trim_end().to_owned()).collect()
+            },
+            None if self.crate_root => {
+                let name = infile.file_name().unwrap_or_default().to_string_lossy();
+                format!("\n//! CAN messages and signals of {name}, generated by dbcparser.")
+            },
+            None => String::new(),
+        };
+        // the uid module attributes become crate attributes, with the header allowed lints
+        let (no_std, lints) = match (self.crate_root, code.no_std) {
+            (false, _) => ("", ""),
+            (true, no_std) => (
+                if no_std { "\n#![no_std]" } else { "" },
+                "\n#![allow(warnings, clippy::all, clippy::pedantic, clippy::nursery)]",
+            ),
+        };
+        let infile = infile.display();
+        let copyright = self
+            .copyright
+            .as_ref()
+            .map_or_else(|| DEFAULT_COPYRIGHT.to_owned(), Copyright::banner);
+        code_output!(
+            code,
+            format!(
+                r#"
+// --------------------------------------------------------------
+//       WARNING: Manual modification will be destroyed
+// --------------------------------------------------------------
+// - code generated from {infile}{gen_time}
+// - update only with [dbc-parser|build.rs::DbcParser]
+// - source code: https://github.com/redpesk-common/canforge-rs
+// Generated file — DO NOT EDIT.
+// Update only with [dbc-parser|build.rs::DbcParser]
+// Source: https://github.com/redpesk-common/canforge-rs
+//
+{copyright}
+// -------------------------------------------------------------{module}{doc}{no_std}
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(dead_code)]{lints}"#
+            )
+        )?;
+
+        if self.crate_root && code.no_std {
+            code_output!(code, "extern crate alloc;")?;
+        }
+        if code.uses_serde(None) {
+            code_output!(code, format!("{}extern crate serde;", code.serde_cfg("")))?;
+        }
+        code_output!(
+            code,
+            format!(
+                r#"
+extern crate bitvec;
+use {};
+use {}::cell::{{Cell,RefCell,RefMut}};
+use {alloc}::collections::BTreeMap;
+use {alloc}::rc::{{Rc}};{}
+"#,
+                code.sockcan_prelude(0),
+                code.core_crate(),
+                code.alloc_prelude().replace("\n    ", "\n"),
+                alloc = code.alloc_crate(),
             )
         )?;
 
-        if code.serde_json {
-            code_output!(code, r#"    #[derive(Serialize, Deserialize)]"#)?;
-        }
-        code_output!(code, format!(r#"    pub struct {type_kamel} {{"#))?;
+        if !code.sockcan {
+            gen_sockcan_shim(&code)?;
+        }
+        gen_errors(&code)?;
+        gen_dbc_checksum(&code, &dbc_sha256)?;
+        if code.serde_json {
+            gen_serde_state(&code)?;
+        }
+        gen_pool_diff(&code)?;
+        if code.overrides.values().any(|flags| flags.counter) {
+            gen_counter_status(&code)?;
+        }
+        if !code.checksums.is_empty() {
+            gen_crc_algorithms(&code)?;
+        }
+        if code.canfd {
+            gen_canfd(&code)?;
+        }
+
+        if !code.attributes.is_empty() {
+            gen_attributes(&code, &code.attributes)?;
+        }
+
+        // output messages/signals
+        for message in &code.dbcfd.messages {
+            message.gen_code_message(&code)?;
+        }
+
+        gen_nodes(&code)?;
+
+        // enumeration with all signal type
+        code_output!(code, "enum DbcMessages {")?;
+        for message in &code.dbcfd.messages {
+            let msg_type = message.get_type_kamel();
+            code_output!(code, format!(r#"    {msg_type},"#))?;
+        }
+        // extract canid from messages vector
+        let canids: Vec<u32> = code.dbcfd.messages.iter().map(|msg| msg.id.raw()).collect();
+
+        let msg_count = code.dbcfd.messages.len();
+
+        code_output!(
+            code,
+            format!(
+                r#"
+}}
+
+pub struct CanMsgPool {{
+    uid: &'static str,
+    pool: [Rc<RefCell<Box<dyn CanDbcMessage>>>;{msg_count}],
+    filters: RefCell<Vec<CanIdFilter>>,
+    stamp: Rc<Cell<u64>>,
+    unknown: RefCell<UnknownIds>,
+}}
+
+impl CanMsgPool {{
+    pub fn new(uid: &'static str) -> Self {{
+        CanMsgPool {{
+            uid: uid,
+            filters: RefCell::new(Vec::new()),
+            stamp: Rc::new(Cell::new(0)),
+            unknown: RefCell::new(UnknownIds::default()),
+            pool: ["#
+            )
+        )?;
+
+        for idx in 0..code.dbcfd.messages.len() {
+            let msg_type = code.dbcfd.messages[idx].get_type_kamel();
+            code_output!(code, format!(r#"                {msg_type}::DbcMessage::new(),"#))?;
+        }
+        code_output!(
+            code,
+            format!(
+                r#"
+            ]
+        }}
+    }}
+}}
+
+impl CanDbcPool for CanMsgPool {{
+    fn get_messages(&self) -> &[Rc<RefCell<Box<dyn CanDbcMessage>>>] {{
+        &self.pool
+    }}
+
+    fn get_ids(&self) -> &[u32] {{
+        &{canids:?}
+    }}
+
+    fn get_mut(&self, canid: u32) -> Result<RefMut<'_, Box<dyn CanDbcMessage>>, CanError> {{
+        let search= self.pool.binary_search_by(|msg| msg.borrow().get_id().cmp(&canid));
+        match search {{
+            Ok(idx) => {{
+                match self.pool[idx].try_borrow_mut() {{
+                    Err(_code) => Err(CanError::new("message-get_mut", "internal msg pool error")),
+                    Ok(mut_ref) => Ok(mut_ref),
+                }}
+            }},
+            Err(_) => Err(CanError::new("fail-canid-search", format!("canid:{{}} not found",canid))),
+        }}
+    }}
+
+    fn update(&self, data: &CanMsgData) -> Result<RefMut<'_, Box<dyn CanDbcMessage>>, CanError> {{
+        // ids outside the pool may reach a message through a subscribe_ids() filter
+        let canid = self.resolve(data.canid).unwrap_or(data.canid);
+        if self.get_ids().binary_search(&canid).is_err() {{
+            self.unknown_frame(data);
+        }}
+        let mut msg= match self.get_mut(canid) {{
+            Err(error) => return Err(error),
+            Ok(msg_ref) => msg_ref,
+        }};
+        // frame time of the CallbackFlow::Pause of subscribe_signals_flow() callbacks
+        self.stamp.set(data.stamp);
+        msg.update(data)?;
+        Ok(msg)
+    }}
+ }}"#
+            )
+        )?;
+
+        gen_pool_signal_callbacks(&code)?;
+        gen_pool_lookup(&code)?;
+        gen_pool_id_filters(&code)?;
+        gen_pool_unknown_ids(&code)?;
+        if !code.signal_structs.is_empty() {
+            gen_signal_structs(&code)?;
+        }
+        if code.static_pool {
+            gen_static_pool(&code)?;
+        }
+        if code.sockcan {
+            gen_pool_subscribe(&code)?;
+        }
+        if !self.crate_root {
+            code_output!(code, "} // end dbc generated parser")?;
+        }
+
+        if self.plugin_abi {
+            let module = if self.crate_root { "crate".to_owned() } else { format!("super::{uid}") };
+            gen_plugin_abi(&code, uid, &module)?;
+        }
+
+        if artifacts {
+            if let Some(path) = &self.json_ir {
+                write_generated(path, &crate::export::dbc_to_json(&code.dbcfd, uid))?;
+            }
+            if let Some(path) = &self.json_schema {
+                write_generated(path, &crate::export::dbc_to_json_schema(&code.dbcfd, uid))?;
+            }
+            if let Some(path) = &self.markdown {
+                write_generated(path, &crate::export::dbc_to_markdown(&code.dbcfd, uid))?;
+            }
+            if let Some((path, include)) = &self.emit_benches {
+                write_generated(path, &gen_benches(&code, uid, include))?;
+            }
+            if let (Some(dir), Some(name)) = (&self.emit_crate, package) {
+                let package = CargoPackage {
+                    description: format!(
+                        "CAN database of {}, generated by dbcparser",
+                        self.infile
+                            .as_deref()
+                            .and_then(Path::file_name)
+                            .unwrap_or_default()
+                            .to_string_lossy()
+                    ),
+                    version: dbc_version
+                        .unwrap_or_else(|| crate::package::DEFAULT_CRATE_VERSION.to_owned()),
+                    license: self.copyright.as_ref().and_then(|copyright| copyright.spdx.clone()),
+                    sockcan: code.sockcan,
+                    no_std: code.no_std,
+                    serde_feature: code.serde_feature.clone(),
+                    cdylib: self.plugin_abi,
+                    name,
+                };
+                write_generated(dir.join("Cargo.toml"), &package.manifest())?;
+                let tests = gen_crate_tests(&code, &package.name.replace('-', "_"));
+                write_generated(dir.join("tests").join("messages.rs"), &tests)?;
+            }
+        }
+
+        Ok(code.capture.map(RefCell::into_inner))
+    }
+}
+
+/// Temporary file `generate()` writes before renaming it to `outfile`, in the same directory
+/// so the rename stays atomic.
+fn partial_path(outfile: &Path) -> PathBuf {
+    let mut name = OsString::from(".");
+    name.push(outfile.file_name().unwrap_or_default());
+    name.push(format!(".{}.partial", std::process::id()));
+    outfile.with_file_name(name)
+}
+
+/// Write code generated in memory to `outfile` through a temporary file, like `generate()`,
+/// e.g. the concatenated output of several `generate_string()` calls.
+///
+/// # Errors
+/// Propagates any I/O error writing or renaming the file.
+pub fn write_generated<P: AsRef<Path>>(outfile: P, code: &str) -> io::Result<()> {
+    let outfile = outfile.as_ref();
+    let partial = partial_path(outfile);
+    if let Err(error) = fs::write(&partial, code) {
+        let _ = fs::remove_file(&partial);
+        return Err(error);
+    }
+    fs::rename(&partial, outfile)
+}
+
+/// Summary of the lines changed between `existing` and `generated` code, `None` when they
+/// only differ by their generation stamps ("code generated from" lines).
+#[must_use]
+pub fn generated_drift(existing: &str, generated: &str) -> Option<String> {
+    const STAMP: &str = "// - code generated from ";
+    const SHOWN: usize = 10;
+    let existing: Vec<&str> = existing.lines().filter(|l| !l.starts_with(STAMP)).collect();
+    let generated: Vec<&str> = generated.lines().filter(|l| !l.starts_with(STAMP)).collect();
+    if existing == generated {
+        return None;
+    }
+
+    // a single hunk between the common head and tail is enough to point at the change
+    let head = existing.iter().zip(&generated).take_while(|(a, b)| a == b).count();
+    let tail = existing[head..]
+        .iter()
+        .rev()
+        .zip(generated[head..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let removed = &existing[head..existing.len() - tail];
+    let added = &generated[head..generated.len() - tail];
+
+    let mut summary = format!(
+        "generated code is stale: {} line(s) removed, {} line(s) added from line {}\n",
+        removed.len(),
+        added.len(),
+        head + 1
+    );
+    for line in removed.iter().take(SHOWN) {
+        let _ = writeln!(summary, "- {line}");
+    }
+    for line in added.iter().take(SHOWN) {
+        let _ = writeln!(summary, "+ {line}");
+    }
+    if removed.len().max(added.len()) > SHOWN {
+        summary.push_str("...\n");
+    }
+    Some(summary)
+}
+
+/// Local stand-in for the sockcan types and traits the generated code relies on, so the
+/// output builds without the sockcan crate (offline decoding on hosts without SocketCAN).
+fn gen_sockcan_shim(code: &DbcCodeGen) -> io::Result<()> {
+    let (serde, serde_line) =
+        code.serde_derives(code.serde_json, "serde::Serialize, serde::Deserialize", "    ");
+    let (core, alloc) = (code.core_crate(), code.alloc_crate());
+    let alloc_prelude = code.alloc_prelude();
+    code_output!(
+        code,
+        format!(
+            r#"
+/// Minimal sockcan replacement, generated without the sockcan crate for offline decoding
+pub mod sockcan {{
+pub mod prelude {{
+    use {core}::any::Any;
+    use {core}::cell::{{RefCell, RefMut}};
+    use {core}::fmt;
+    use {alloc}::rc::Rc;{alloc_prelude}
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct CanError {{
+        uid: String,
+        info: String,
+    }}
+
+    impl CanError {{
+        pub fn new<T: ToString>(uid: &str, info: T) -> Self {{
+            CanError {{ uid: uid.to_owned(), info: info.to_string() }}
+        }}
 
-        if code.serde_json {
-            code_output!(code, r#"        #[serde(skip)]"#)?;
-        }
-        code_output!(
-            code,
-            format!(
-                r#"
-        callback: Option<RefCell<Box<dyn CanSigCtrl>>>,
-        status: CanDataStatus,
-        name: &'static str,
-        stamp: u64,
-        value: Option<{data_type}>,
-    }}
-"#
-            )
-        )?;
+        pub fn get_uid(&self) -> &str {{
+            &self.uid
+        }}
 
-        self.gen_signal_enum(code, msg)?;
+        pub fn get_info(&self) -> &str {{
+            &self.info
+        }}
+    }}
 
-        // start signal implementation
-        code_output!(
-            code,
-            format!(
-                r#"
-    impl {type_kamel}  {{
-        pub fn new() -> Rc<RefCell<Box<dyn CanDbcSignal>>> {{
-            Rc::new(RefCell::new(Box::new({type_kamel} {{
-                status: CanDataStatus::Unset,
-                name:"{type_kamel}",
-                value: None,
-                stamp: 0,
-                callback: None,
-            }})))
+    impl fmt::Display for CanError {{
+        fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {{
+            write!(format, "{{}}: {{}}", self.uid, self.info)
         }}
+    }}
 
-        fn reset_value(&mut self) {{
-            self.value= None;
-            }}"#
-            )
-        )?;
+    impl {core}::error::Error for CanError {{}}
 
-        if let Some(variants) = code.dbcfd.value_descriptions_for_signal(msg.id, self.name.as_str())
-        {
-            code_output!(
-                code,
-                format!(r#"        pub fn get_as_def (&self) -> Dbc{type_kamel} {{"#)
-            )?;
+    /// Origin of the last message update, subset of the BCM opcodes
+    #[derive(Debug, Clone, Copy, PartialEq, Eq{serde})]{serde_line}
+    pub enum CanBcmOpCode {{
+        Unknown,
+        RxChanged,
+        RxTimeout,
+    }}
 
-            // float is not compatible with match
-            if data_type == "f64" {
-                code_output!(
-                    code,
-                    format!(r#"                Dbc{type_kamel}::_Other(self.get_typed_value())"#)
-                )?;
-            } else {
-                let mut count = 0;
-                code_output!(code, r#"            match self.get_typed_value() {"#)?;
-                for variant in variants {
-                    count += 1;
+    #[derive(Debug, Clone, Copy, PartialEq, Eq{serde})]{serde_line}
+    pub enum CanDataStatus {{
+        Unset,
+        Updated,
+        Unchanged,
+        Timeout,
+        Error,
+    }}
 
-                    let data_value = variant_typed_literal(self, variant.id, &data_type);
-                    let variant_type_kamel = variant.get_type_kamel();
-                    code_output!(
-                        code,
-                        format!(
-                            r#"                {data_value} => Dbc{type_kamel}::{variant_type_kamel},"#
-                        )
-                    )?;
-                }
+    /// One received frame, `stamp` in microseconds
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct CanMsgData {{
+        pub canid: u32,
+        pub stamp: u64,
+        pub opcode: CanBcmOpCode,
+        pub len: u8,
+        pub data: Vec<u8>,
+    }}
 
-                // Help in buggy DBC file support
-                if count != 2 || self.size != 1 {
-                    code_output!(
-                        code,
-                        format!(
-                            r#"                _ => Dbc{type_kamel}::_Other(self.get_typed_value()),"#
-                        )
-                    )?;
-                }
-                code_output!(code, r#"            }"#)?;
-            }
-            code_output!(
-                code,
-                format!(
-                    r#"
+    #[derive(Debug, Clone, Copy, PartialEq{serde})]{serde_line}
+    pub enum CanDbcType {{
+        Bool(bool),
+        U8(u8),
+        U16(u16),
+        U32(u32),
+        U64(u64),
+        I8(i8),
+        I16(i16),
+        I32(i32),
+        I64(i64),
+        F64(f64),
+    }}
+
+    /// Conversion of a CanDbcType to a signal native type
+    pub trait CanDbcCast: Sized {{
+        fn from_dbc(value: CanDbcType) -> Result<Self, CanError>;
+    }}
+
+    macro_rules! can_dbc_cast {{
+        ($($native:ty),*) => {{$(
+            impl CanDbcCast for $native {{
+                fn from_dbc(value: CanDbcType) -> Result<Self, CanError> {{
+                    Ok(match value {{
+                        CanDbcType::Bool(value) => u8::from(value) as $native,
+                        CanDbcType::U8(value) => value as $native,
+                        CanDbcType::U16(value) => value as $native,
+                        CanDbcType::U32(value) => value as $native,
+                        CanDbcType::U64(value) => value as $native,
+                        CanDbcType::I8(value) => value as $native,
+                        CanDbcType::I16(value) => value as $native,
+                        CanDbcType::I32(value) => value as $native,
+                        CanDbcType::I64(value) => value as $native,
+                        CanDbcType::F64(value) => value as $native,
+                    }})
+                }}
+            }}
+        )*}};
+    }}
+    can_dbc_cast!(u8, u16, u32, u64, i8, i16, i32, i64, f64);
+
+    impl CanDbcCast for bool {{
+        fn from_dbc(value: CanDbcType) -> Result<Self, CanError> {{
+            match value {{
+                CanDbcType::Bool(value) => Ok(value),
+                other => Ok(f64::from_dbc(other)? != 0.0),
+            }}
         }}
-        pub fn set_raw_value(&mut self, value: {data_usize}, data: &mut[u8]) {{"#
-                )
-            )?;
-            match self.byte_order {
-                ByteOrder::LittleEndian => {
-                    let (start_bit, end_bit) = self.le_start_end_bit(msg)?;
-                    code_output!(
-                        code,
-                        format!(
-                            r#"            data.view_bits_mut::<Lsb0>()[{start_bit}..{end_bit}].store_le(value);"#
-                        )
-                    )?;
-                },
-                ByteOrder::BigEndian => {
-                    let (start_bit, end_bit) = self.be_start_end_bit(msg)?;
-                    code_output!(
-                        code,
-                        format!(
-                            r#"            data.view_bits_mut::<Msb0>()[{start_bit}..{end_bit}].store_be(value);"#
-                        )
-                    )?;
-                },
-            }
-            code_output!(
-                code,
-                format!(
-                    r#"
+    }}
+
+    impl CanDbcType {{
+        pub fn cast<T: CanDbcCast>(self) -> Result<T, CanError> {{
+            T::from_dbc(self)
         }}
-        pub fn set_as_def (&mut self, signal_def: Dbc{type_kamel}, data: &mut[u8])-> Result<(),CanError> {{
-            match signal_def {{"#
-                )
-            )?;
-            for variant in variants {
-                let variant_type_kamel = variant.get_type_kamel();
-                let data_value = variant.id;
-                code_output!(
-                    code,
-                    format!(
-                        r#"                Dbc{type_kamel}::{variant_type_kamel} => Ok(self.set_raw_value({data_value}, data)),"#
-                    )
-                )?;
-            }
-            code_output!(
-                code,
-                format!(
-                    r#"                Dbc{type_kamel}::_Other(x) => self.set_typed_value(x,data)"#
-                )
-            )?;
-            code_output!(
-                code,
-                r#"            }
-        }"#
-            )?;
-        }
+    }}
 
-        code_output!(
-            code,
-            format!(
-                r#"
-        fn get_typed_value(&self) -> {data_type} {{
-            self.value.unwrap_or_default()
+    pub trait CanSigCtrl {{
+        fn sig_notification(&self, sig: &dyn CanDbcSignal) -> i32;
+    }}
+
+    pub trait CanMsgCtrl {{
+        fn msg_notification(&self, msg: &dyn CanDbcMessage);
+    }}
+
+    pub trait CanDbcSignal {{
+        fn get_name(&self) -> &'static str;
+        fn get_stamp(&self) -> u64;
+        fn get_status(&self) -> CanDataStatus;
+        fn as_any(&mut self) -> &mut dyn Any;
+        fn update(&mut self, frame: &CanMsgData) -> i32;
+        fn set_value(&mut self, value: CanDbcType, data: &mut [u8]) -> Result<(), CanError>;
+        fn get_value(&self) -> CanDbcType;
+        fn to_json(&self) -> String {{
+            String::new()
         }}
+        /// Physical unit, empty when unknown
+        fn get_unit(&self) -> &'static str {{
+            ""
+        }}
+        /// Minimum physical value, 0 when unknown
+        fn get_min(&self) -> f64 {{
+            0.0
+        }}
+        /// Maximum physical value, 0 when unknown
+        fn get_max(&self) -> f64 {{
+            0.0
+        }}
+        /// Scaling factor, physical = raw * factor + offset
+        fn get_factor(&self) -> f64 {{
+            1.0
+        }}
+        /// Scaling offset
+        fn get_offset(&self) -> f64 {{
+            0.0
+        }}
+        /// Size in bits, 0 when unknown
+        fn get_bit_length(&self) -> u32 {{
+            0
+        }}
+        /// DBC start bit, 0 when unknown
+        fn get_start_bit(&self) -> u32 {{
+            0
+        }}
+        fn reset(&mut self);
+        fn set_callback(&mut self, callback: Box<dyn CanSigCtrl>);
+    }}
 
-        fn set_typed_value(&mut self, value:{data_type}, data:&mut [u8]) -> Result<(),CanError> {{"#
-            )
-        )?;
+    pub trait CanDbcMessage {{
+        fn reset(&mut self) -> Result<(), CanError>;
+        fn update(&mut self, frame: &CanMsgData) -> Result<(), CanError>;
+        fn get_signals(&self) -> &[Rc<RefCell<Box<dyn CanDbcSignal>>>];
+        fn get_listeners(&self) -> i32;
+        fn set_callback(&mut self, callback: Box<dyn CanMsgCtrl>);
+        fn get_name(&self) -> &'static str;
+        fn get_status(&self) -> CanBcmOpCode;
+        fn get_stamp(&self) -> u64;
+        fn get_id(&self) -> u32;
+        fn as_any(&mut self) -> &mut dyn Any;
+    }}
 
-        if self.size == 1 {
-            code_output!(code, r#"            let value: u8 = value as u8;"#)?;
-        } else {
-            let bits = self.size;
-            code_output!(
-                code,
-                format!(
-                    r#"
-            //  Mask to the signal bit-length (prevents leaking upper bits).
-            let __mask: u64 = if {bits} == 64 {{ u64::MAX }} else {{ (1u64 << {bits}) - 1 }};"#
-                )
-            )?;
+    pub trait CanDbcPool {{
+        fn get_messages(&self) -> &[Rc<RefCell<Box<dyn CanDbcMessage>>>];
+        fn get_ids(&self) -> &[u32];
+        fn get_mut(&self, canid: u32) -> Result<RefMut<'_, Box<dyn CanDbcMessage>>, CanError>;
+        fn update(&self, data: &CanMsgData) -> Result<RefMut<'_, Box<dyn CanDbcMessage>>, CanError>;
+    }}
+}}
+}}"#
+        )
+    )
+}
 
-            if code.range_check {
-                let min_expr = bound_expr(min, &data_type, true);
-                let max_expr = bound_expr(max, &data_type, false);
-                code_output!(
-                    code,
-                    format!(
-                        r#"
-            // Range-check clamped to the target type limits (avoids overflowing literals).
-            if value < {min_expr} || {max_expr} < value {{
-                return Err(CanError::new("invalid-signal-value",format!("value={{}} not in [{min}..{max}]",value)));
-            }}"#
-                    )
-                )?;
-            }
+fn gen_errors(code: &DbcCodeGen) -> io::Result<()> {
+    code_output!(
+        code,
+        format!(
+            r"
+/// Machine-readable codes of the CanError uids raised by this module
+pub mod errors {{
+    use {};
+    use {core}::error::Error;
+    use {core}::fmt;{}
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum CanErrorCode {{",
+            code.sockcan_prelude(1),
+            code.alloc_prelude(),
+            core = code.core_crate(),
+        )
+    )?;
+    for error in CanErrorCode::ALL {
+        code_output!(code, format!("        {error:?},"))?;
+    }
+    code_output!(
+        code,
+        r"    }
+
+    impl CanErrorCode {
+        /// CanError uid carrying this code
+        pub const fn uid(self) -> &'static str {
+            match self {"
+    )?;
+    for error in CanErrorCode::ALL {
+        code_output!(
+            code,
+            format!(r#"                CanErrorCode::{error:?} => "{}","#, error.uid())
+        )?;
+    }
+    code_output!(
+        code,
+        r"            }
+        }
 
-            if self.has_scaling() {
-                let factor = self.factor;
-                let offset = self.offset;
-                code_output!(
-                    code,
-                    format!(
-                        r#"
-            let factor = {factor}_f64;
-            let offset = {offset}_f64;
-            let __raw_f = (value - offset) / factor;"#
-                    )
-                )?;
+        /// Code of a CanError uid, Other when it is not raised by generated code
+        pub fn from_uid(uid: &str) -> Self {
+            match uid {"
+    )?;
+    for error in CanErrorCode::ALL.iter().filter(|error| **error != CanErrorCode::Other) {
+        code_output!(
+            code,
+            format!(r#"                "{}" => CanErrorCode::{error:?},"#, error.uid())
+        )?;
+    }
+    code_output!(
+        code,
+        r#"                _ => CanErrorCode::Other,
+            }
+        }
+    }
 
-                if self.value_type == ValueType::Signed {
-                    code_output!(
-                        code,
-                        format!(
-                            r#"
-            //  Encode signed value as two's complement on {bits} bits.
-            let __raw_i64 = __raw_f as i64;
-            let value: {data_usize} = (((__raw_i64 as u64) & __mask) as {data_usize});"#
-                        )
-                    )?;
-                } else {
-                    code_output!(
-                        code,
-                        format!(
-                            r#"
-            let value: {data_usize} = (((__raw_f as u64) & __mask) as {data_usize});"#
-                        )
-                    )?;
-                }
-            } else {
-                if self.value_type == ValueType::Signed {
-                    code_output!(
-                        code,
-                        format!(
-                            r#"
-            //  Encode signed integer as two's complement on {bits} bits.
-            let value: {data_usize} = ((((value as i64) as u64) & __mask) as {data_usize});"#
-                        )
-                    )?;
-                } else {
-                    code_output!(
-                        code,
-                        format!(
-                            r#"
-            let value: {data_usize} = (((value as u64) & __mask) as {data_usize});"#
-                        )
-                    )?;
-                }
+    impl fmt::Display for CanErrorCode {
+        fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {
+            format.write_str(self.uid())
+        }
+    }
+
+    /// CanError with its code, implements the Error trait so `?` works with anyhow/thiserror
+    #[derive(Debug, Clone)]
+    pub struct DbcError {
+        pub code: CanErrorCode,
+        pub uid: String,
+        pub info: String,
+    }
+
+    impl From<CanError> for DbcError {
+        fn from(error: CanError) -> Self {
+            DbcError {
+                code: CanErrorCode::from_uid(error.get_uid()),
+                uid: error.get_uid().to_owned(),
+                info: error.get_info().to_owned(),
             }
         }
+    }
 
-        match self.byte_order {
-            ByteOrder::LittleEndian => {
-                let (start_bit, end_bit) = self.le_start_end_bit(msg)?;
-                code_output!(
-                    code,
-                    format!(
-                        r#"            data.view_bits_mut::<Lsb0>()[{start_bit}..{end_bit}].store_le(value);"#
-                    )
-                )?;
-            },
-            ByteOrder::BigEndian => {
-                let (start_bit, end_bit) = self.be_start_end_bit(msg)?;
-                code_output!(
-                    code,
-                    format!(
-                        r#"            data.view_bits_mut::<Msb0>()[{start_bit}..{end_bit}].store_be(value);"#
-                    )
-                )?;
-            },
+    impl fmt::Display for DbcError {
+        fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(format, "{}: {}", self.uid, self.info)
         }
+    }
 
-        let msg_type = msg.get_type_kamel();
-        let sig_type = self.get_type_kamel();
+    impl Error for DbcError {}
 
-        code_output!(
-            code,
-            format!(
-                r#"
-            Ok(())
-        }}
+    /// Code of a sockcan CanError
+    pub trait CanErrorCodeExt {
+        fn code(&self) -> CanErrorCode;
+    }
 
-    }} // {msg_type}::{sig_type} impl end
+    impl CanErrorCodeExt for CanError {
+        fn code(&self) -> CanErrorCode {
+            CanErrorCode::from_uid(self.get_uid())
+        }
+    }
+}
 "#
-            )
-        )?;
+    )?;
+    Ok(())
+}
 
+/// `DBC_SHA256` of the input file and `verify_dbc()`, with a self-contained SHA-256 so the
+/// generated code needs no extra crate.
+fn gen_dbc_checksum(code: &DbcCodeGen, dbc_sha256: &str) -> io::Result<()> {
+    code_output!(
+        code,
+        format!(
+            r#"
+/// SHA-256 of the DBC file this module was generated from
+pub const DBC_SHA256: &str = "{dbc_sha256}";"#
+        )
+    )?;
+    // reading the file needs std
+    if !code.no_std {
+        code_output!(
+            code,
+            r#"
+/// Check that the DBC file at `path` is the one this module was generated from
+pub fn verify_dbc<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<()> {
+    let path = path.as_ref();
+    let digest = dbc_sha256(&std::fs::read(path)?);
+    if digest == DBC_SHA256 {
         Ok(())
+    } else {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("{}: sha256 {} does not match generated code {}", path.display(), digest, DBC_SHA256),
+        ))
+    }
+}"#
+        )?;
+    }
+    code_output!(
+        code,
+        r#"
+fn dbc_sha256(data: &[u8]) -> String {
+    const K: [u32; 64] = [
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+        0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+        0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+        0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+        0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+        0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+        0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+        0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+    ];
+    let mut hash: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+    ];
+    let mut msg = data.to_vec();
+    msg.push(0x80);
+    while msg.len() % 64 != 56 {
+        msg.push(0);
+    }
+    msg.extend_from_slice(&(data.len() as u64).wrapping_mul(8).to_be_bytes());
+    for chunk in msg.chunks(64) {
+        let mut w = [0u32; 64];
+        for idx in 0..16 {
+            w[idx] = u32::from_be_bytes([chunk[4 * idx], chunk[4 * idx + 1], chunk[4 * idx + 2], chunk[4 * idx + 3]]);
+        }
+        for idx in 16..64 {
+            let s0 = w[idx - 15].rotate_right(7) ^ w[idx - 15].rotate_right(18) ^ (w[idx - 15] >> 3);
+            let s1 = w[idx - 2].rotate_right(17) ^ w[idx - 2].rotate_right(19) ^ (w[idx - 2] >> 10);
+            w[idx] = w[idx - 16].wrapping_add(s0).wrapping_add(w[idx - 7]).wrapping_add(s1);
+        }
+        let mut v = hash;
+        for idx in 0..64 {
+            let s1 = v[4].rotate_right(6) ^ v[4].rotate_right(11) ^ v[4].rotate_right(25);
+            let ch = (v[4] & v[5]) ^ (!v[4] & v[6]);
+            let t1 = v[7].wrapping_add(s1).wrapping_add(ch).wrapping_add(K[idx]).wrapping_add(w[idx]);
+            let s0 = v[0].rotate_right(2) ^ v[0].rotate_right(13) ^ v[0].rotate_right(22);
+            let maj = (v[0] & v[1]) ^ (v[0] & v[2]) ^ (v[1] & v[2]);
+            let t2 = s0.wrapping_add(maj);
+            v = [t1.wrapping_add(t2), v[0], v[1], v[2], v[3].wrapping_add(t1), v[4], v[5], v[6]];
+        }
+        for (word, value) in hash.iter_mut().zip(v) {
+            *word = word.wrapping_add(value);
+        }
     }
+    hash.iter().map(|word| format!("{:08x}", word)).collect()
+}"#
+    )
+}
 
-    fn gen_can_std_frame(&self, _code: &DbcCodeGen, _msg: &Message) -> io::Result<()> {
-        Ok(())
+/// Flag of 29-bit ids in DBC message ids, also `CAN_EFF_FLAG` in Linux `can_id`.
+const CAN_EFF_FLAG: u32 = 0x8000_0000;
+
+/// CAN id of `id` without the extended flag, and whether it is a 29-bit one.
+fn bus_id(id: MessageId) -> (u32, bool) {
+    let raw = id.raw();
+    (raw & !CAN_EFF_FLAG, raw & CAN_EFF_FLAG != 0)
+}
+
+/// Unit, range, scaling and layout constants of a signal, from its SG_ line.
+fn signal_meta_api(sig: &Signal) -> String {
+    format!(
+        r#"
+        /// Physical unit (SG_ unit), empty when the DBC gives none
+        pub const UNIT: &'static str = {:?};
+        /// Minimum physical value (SG_ range), 0 and 0 for an unspecified range
+        pub const MIN: f64 = {:?};
+        /// Maximum physical value (SG_ range)
+        pub const MAX: f64 = {:?};
+        /// Scaling factor, physical = raw * FACTOR + OFFSET
+        pub const FACTOR: f64 = {:?};
+        /// Scaling offset
+        pub const OFFSET: f64 = {:?};
+        /// Signal size in bits
+        pub const BIT_LENGTH: u32 = {};
+        /// SG_ start bit: least significant bit of little endian signals, most significant
+        /// bit of big endian ones, in the DBC bit numbering
+        pub const START_BIT: u32 = {};
+        /// The constants above together, see `DbcMessage::signal_meta()`
+        pub const META: super::SignalMeta = super::SignalMeta {{
+            unit: Self::UNIT,
+            min: Self::MIN,
+            max: Self::MAX,
+            factor: Self::FACTOR,
+            offset: Self::OFFSET,
+            bit_length: Self::BIT_LENGTH,
+            start_bit: Self::START_BIT,
+        }};"#,
+        sig.unit, sig.min, sig.max, sig.factor, sig.offset, sig.size, sig.start_bit
+    )
+}
+
+/// `CanDbcSignal` metadata accessors returning the `signal_meta_api()` constants, only known
+/// to the local sockcan stand-in trait.
+fn signal_meta_impl(code: &DbcCodeGen) -> &'static str {
+    if code.sockcan {
+        return "";
     }
+    r#"
+        fn get_unit(&self) -> &'static str {
+            Self::UNIT
+        }
 
-    fn gen_can_any_frame(&self, code: &DbcCodeGen, msg: &Message) -> io::Result<()> {
-        match self.multiplexer_indicator {
-            MultiplexIndicator::Plain => self.gen_can_std_frame(code, msg)?,
-            MultiplexIndicator::Multiplexor
-            | MultiplexIndicator::MultiplexedSignal(_)
-            | MultiplexIndicator::MultiplexorAndMultiplexedSignal(_) => {
-                // (optional) any shared handling for multiplexed cases
-            },
+        fn get_min(&self) -> f64 {
+            Self::MIN
         }
-        let sig_type = self.get_type_kamel();
 
-        code_output!(
-            code,
+        fn get_max(&self) -> f64 {
+            Self::MAX
+        }
+
+        fn get_factor(&self) -> f64 {
+            Self::FACTOR
+        }
+
+        fn get_offset(&self) -> f64 {
+            Self::OFFSET
+        }
+
+        fn get_bit_length(&self) -> u32 {
+            Self::BIT_LENGTH
+        }
+
+        fn get_start_bit(&self) -> u32 {
+            Self::START_BIT
+        }
+"#
+}
+
+/// `Display` (one log line: name, id, status, stamp and signal values) and `Debug` of a
+/// message `DbcMessage`, signals borrowed elsewhere shown as `?`/`None`.
+fn gen_message_fmt(code: &DbcCodeGen, msg: &Message) -> io::Result<()> {
+    let msg_type = msg.get_type_kamel();
+    let mut display = String::new();
+    let mut debug = String::new();
+    for (idx, sig) in msg.signals.iter().enumerate() {
+        let (sig_type, sig_snake) = (sig.get_type_kamel(), sig.get_type_snake());
+        // byte arrays only print through their concrete type, other signals through their value
+        let value = if sig.is_byte_array() {
             format!(
-                r#"
-    impl fmt::Display for {sig_type} {{
+                "self.signals[{idx}].try_borrow_mut().ok().and_then(|mut signal| signal.as_any().downcast_ref::<{sig_type}>().map({sig_type}::to_hex))"
+            )
+        } else {
+            format!(
+                "self.signals[{idx}].try_borrow().ok().and_then(|signal| signal.get_value().cast::<{}>().ok())",
+                code.data_type(msg, sig)
+            )
+        };
+        let _ = write!(
+            display,
+            r#"
+            match {value} {{
+                Some(value) => write!(fmt, " {sig_type}:{{value}}")?,
+                None => write!(fmt, " {sig_type}:?")?,
+            }}"#
+        );
+        let _ = write!(debug, "\n                .field(\"{sig_snake}\", &{value})");
+    }
+    code_output!(
+        code,
+        format!(
+            r#"
+    /// `{msg_type}(0x..) <opcode> stamp:<us> Signal:value ...`, for logs
+    impl fmt::Display for DbcMessage {{
         fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {{
-            let text=format!("{sig_type}:{{}}", self.get_typed_value());
-            fmt.pad(&text)
+            write!(fmt, "{msg_type}({{:#x}}) {{:?}} stamp:{{}}", self.id, self.status, self.stamp)?;{display}
+            Ok(())
         }}
     }}
 
-    impl fmt::Debug for {sig_type} {{
+    impl fmt::Debug for DbcMessage {{
         fn fmt(&self, format: &mut fmt::Formatter<'_>) -> fmt::Result {{
-            format.debug_struct("{sig_type}")
-                .field("val", &self.get_typed_value())
-                .field("stamp", &self.get_stamp())
-                .field("status", &self.get_status())
+            format.debug_struct("{msg_type}")
+                .field("id", &self.id)
+                .field("status", &self.status)
+                .field("stamp", &self.stamp){debug}
                 .finish()
         }}
     }}
 "#
-            )
-        )?;
-
-        Ok(())
-    }
+        )
+    )
+}
 
-    fn gen_code_signal(&self, code: &DbcCodeGen, msg: &Message) -> io::Result<()> {
-        self.gen_signal_impl(code, msg)?;
-        self.gen_can_any_frame(code, msg)?;
-        self.gen_signal_trait(code, msg)?;
-        Ok(())
+/// `ATTRIBUTES` and `get_attribute()` of a message, in its `impl DbcMessage`.
+fn gen_message_attribute_api(code: &DbcCodeGen, msg: &Message) -> io::Result<()> {
+    let values = code.message_attributes.get(&msg.id.raw()).map_or(&[][..], Vec::as_slice);
+    match attribute_api(code, AttributeScope::Message, values).strip_prefix('\n') {
+        Some(api) => code_output!(code, api),
+        None => Ok(()),
     }
 }
 
-impl MsgCodeGen<&DbcCodeGen> for Message {
-    fn gen_can_dbc_impl(&self, code: &DbcCodeGen) -> io::Result<()> {
-        let sig_count = self.signals.len();
-        let msg_id = self.id.raw();
-        let msg_name = self.get_type_kamel();
+/// Generated id and cycle time constants, `is_extended()` and `get_cycle_time_ms()` of a
+/// message, in its `impl DbcMessage`.
+fn gen_message_id_api(code: &DbcCodeGen, msg: &Message) -> io::Result<()> {
+    let (can_id, extended) = bus_id(msg.id);
+    let cycle_time = code.cycle_times.get(&msg.id.raw()).copied().unwrap_or_default();
+    code_output!(
+        code,
+        format!(
+            r#"
+        /// CAN id without the extended flag, 11 or 29 bits
+        pub const CAN_ID: u32 = {can_id:#x};
+        /// 29-bit id: `get_id()` and the BCM filter carry CAN_EFF_FLAG (bit 31), like Linux can_id
+        pub const EXTENDED: bool = {extended};
 
-        code_output!(
-            code,
-            format!(
-                r#"
-    pub struct DbcMessage {{
-        callback: Option<RefCell<Box<dyn CanMsgCtrl>>>,
-        signals: [Rc<RefCell<Box<dyn CanDbcSignal>>>;{sig_count}],
-        name: &'static str,
-        status: CanBcmOpCode,
-        listeners: i32,
-        stamp: u64,
-        id: u32,
-    }}
+        pub fn is_extended(&self) -> bool {{
+            Self::EXTENDED
+        }}
 
-    impl DbcMessage {{
-        pub fn new() -> Rc<RefCell<Box <dyn CanDbcMessage>>> {{
-            Rc::new(RefCell::new(Box::new (DbcMessage {{
-                id: {msg_id},
-                name: "{msg_name}",
-                status: CanBcmOpCode::Unknown,
-                listeners: 0,
-                stamp: 0,
-                callback: None,
-                signals: ["#
-            )
-        )?;
+        /// Transmission period from the GenMsgCycleTime attribute (or its default), 0 for a
+        /// message sent on event or without attribute
+        pub const CYCLE_TIME_MS: u32 = {cycle_time};
 
-        for signal in &self.signals {
-            let type_id = signal.get_type_kamel();
-            code_output!(code, format!(r#"                    {type_id}::new(),"#))?;
-        }
-        code_output!(
-            code,
+        /// `CYCLE_TIME_MS`, base of the BCM rate and watchdog timers of the message
+        pub fn get_cycle_time_ms(&self) -> u32 {{
+            Self::CYCLE_TIME_MS
+        }}"#
+        )
+    )?;
+    if !code.j1939_messages.contains(&msg.id.raw()) {
+        return Ok(());
+    }
+    let pf = (can_id >> 16) & 0xff;
+    // PDU1 (PF < 240): PS is a destination address, not part of the PGN
+    let pgn = if pf < 0xf0 { (can_id >> 8) & 0x3_ff00 } else { (can_id >> 8) & 0x3_ffff };
+    let priority = (can_id >> 26) & 0x7;
+    let source = can_id & 0xff;
+    code_output!(
+        code,
+        format!(
             r#"
-                ],
-            })))
-        }
-"#
-        )?;
+        /// J1939 parameter group number of the DBC id, PDU1 destination address cleared
+        pub const PGN: u32 = {pgn:#x};
+        /// J1939 priority of the DBC id, 0 (highest) to 7
+        pub const PRIORITY: u8 = {priority};
+        /// J1939 source address of the DBC id
+        pub const SOURCE_ADDRESS: u8 = {source:#x};
+
+        pub fn get_pgn(&self) -> u32 {{
+            Self::PGN
+        }}
 
-        // build message signal:type list
-        let args: Vec<String> = self
-            .signals
-            .iter()
-            .map(|signal| format!("{}: {}", signal.get_type_snake(), signal.get_data_type()))
-            .collect();
-        let args_str = args.join(", ");
+        pub fn get_priority(&self) -> u8 {{
+            Self::PRIORITY
+        }}
 
-        code_output!(
-            code,
-            format!(
-                r#"
-        pub fn set_values(&mut self, {args_str}, frame: &mut[u8]) -> Result<&mut Self, CanError> {{
-"#
-            )
-        )?;
+        pub fn get_source_address(&self) -> u8 {{
+            Self::SOURCE_ADDRESS
+        }}"#
+        )
+    )
+}
 
-        // Mux validation (generator-time):
-        let mux_idx = find_mux_idx(self)?;
-        if has_multiplexed_signals(self) && mux_idx.is_none() {
-            return Err(Error::other(format!(
-                "message:{} has multiplexed signals but no multiplexor",
-                self.get_type_kamel()
-            )));
-        }
+/// Payload lengths of the 16 CAN FD DLC codes.
+const CANFD_LENGTHS: [u64; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 12, 16, 20, 24, 32, 48, 64];
 
-        if let Some(mux_idx) = mux_idx {
-            let mux_sig = &self.signals[mux_idx];
-            validate_mux(self, mux_sig)?;
+/// Smallest CAN FD DLC carrying `size` bytes, `None` above 64.
+fn canfd_dlc(size: u64) -> Option<u8> {
+    CANFD_LENGTHS
+        .iter()
+        .position(|len| *len >= size)
+        .and_then(|dlc| u8::try_from(dlc).ok())
+}
 
-            let mux_arg = mux_sig.get_type_snake();
-            let mux_bits = mux_sig.size;
+fn gen_canfd(code: &DbcCodeGen) -> io::Result<()> {
+    code_output!(
+        code,
+        format!(
+            r#"
+/// Payload length of each CAN FD DLC
+pub const CANFD_LENGTHS: [usize; 16] = {CANFD_LENGTHS:?};
 
-            // Compute multiplexor RAW value (DBC selectors are defined on raw values).
-            let mux_raw_expr = if mux_sig.size == 1 {
-                format!(
-                    r#"
-            if {mux_arg} {{ 1 }} else {{ 0 }}"#
-                )
-            } else if mux_sig.value_type == ValueType::Signed {
-                format!(
-                    r#"{{
-            let __mask: u64 = if {mux_bits} == 64 {{ u64::MAX }} else {{ (1u64 << {mux_bits}) - 1 }};
-            ((({mux_arg} as i64) as u64) & __mask)
-    }}"#
-                )
-            } else {
-                format!(
-                    r#"{{
-            let __mask: u64 = if {mux_bits} == 64 {{ u64::MAX }} else {{ (1u64 << {mux_bits}) - 1 }};
-            (({mux_arg} as u64) & __mask)
-    }}"#
-                )
-            };
+/// Smallest CAN FD DLC carrying `len` bytes, None above 64
+pub fn canfd_dlc(len: usize) -> Option<u8> {{
+    CANFD_LENGTHS.iter().position(|fd_len| *fd_len >= len).map(|dlc| dlc as u8)
+}}
 
-            code_output!(
-                code,
-                format!(
-                    r#"
-            let __mux_raw_value: u64 = {mux_raw_expr};"#
-                )
-            )?;
+/// Payload length of a CAN FD `dlc`, 64 above 15
+pub fn canfd_len(dlc: u8) -> usize {{
+    CANFD_LENGTHS[usize::from(dlc.min(15))]
+}}"#
+        )
+    )
+}
 
-            // 1) Always pack the multiplexor itself first.
-            emit_signal_mut_action(
-                code,
-                "            ",
-                mux_idx,
-                mux_sig,
-                SigMutAction::SetValue { frame: "frame" },
-                "signal-set-values-fail",
-            )?;
+fn gen_counter_status(code: &DbcCodeGen) -> io::Result<()> {
+    let (derive_serde, serde_line) =
+        code.serde_derives(code.serde_json, "serde::Serialize, serde::Deserialize", "");
+    code_output!(
+        code,
+        format!(
+            r#"
+/// Freshness of the last frame of a message with a rolling counter
+#[derive(Debug, Clone, Copy, PartialEq, Eq{derive_serde})]{serde_line}
+pub enum CounterStatus {{
+    /// No frame received since the last reset
+    Unknown,
+    /// Counter moved forward, possibly skipping lost frames
+    Fresh,
+    /// Same counter as the last fresh frame: repeated or replayed frame, ignored
+    Replayed,
+    /// Counter went backward: out of order frame, ignored
+    Stale,
+}}"#
+        )
+    )
+}
 
-            // 2) Pack other signals (plain always, multiplexed only if mux matches).
-            for idx in 0..self.signals.len() {
-                if idx == mux_idx {
-                    continue;
-                }
+fn gen_crc_algorithms(code: &DbcCodeGen) -> io::Result<()> {
+    code_output!(
+        code,
+        r#"
+/// CRC parameters of an E2E checksum, Rocksoft model with input and output reflected together
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrcAlgorithm {
+    pub name: &'static str,
+    /// 8 to 64 bits
+    pub width: u32,
+    pub poly: u64,
+    pub init: u64,
+    pub xorout: u64,
+    pub reflect: bool,
+}
 
-                match self.signals[idx].multiplexer_indicator {
-                    MultiplexIndicator::Plain
-                    | MultiplexIndicator::Multiplexor
-                    | MultiplexIndicator::MultiplexorAndMultiplexedSignal(_) => {
-                        emit_signal_mut_action(
-                            code,
-                            "            ",
-                            idx,
-                            &self.signals[idx],
-                            SigMutAction::SetValue { frame: "frame" },
-                            "signal-set-values-fail",
-                        )?;
-                    },
-                    MultiplexIndicator::MultiplexedSignal(mux_val) => {
-                        code_output!(
-                            code,
-                            format!(
-                                r#"
-            if __mux_raw_value == {mux_val} {{
-                "#
-                            )
-                        )?;
-                        emit_signal_mut_action(
-                            code,
-                            "                ",
-                            idx,
-                            &self.signals[idx],
-                            SigMutAction::SetValue { frame: "frame" },
-                            "signal-set-values-fail",
-                        )?;
-                        code_output!(code, r#"            }"#)?;
-                    },
+impl CrcAlgorithm {
+    /// CRC of the concatenated `chunks`
+    pub fn compute(&self, chunks: &[&[u8]]) -> u64 {
+        let mask = u64::MAX >> (64 - self.width);
+        let top = 1u64 << (self.width - 1);
+        // reflected algorithms shift right through the reversed polynomial and init
+        let reflected = |value: u64| value.reverse_bits() >> (64 - self.width);
+        let poly = if self.reflect { reflected(self.poly) } else { self.poly };
+        let mut crc = if self.reflect { reflected(self.init) } else { self.init };
+        for byte in chunks.iter().flat_map(|chunk| chunk.iter()) {
+            if self.reflect {
+                crc ^= *byte as u64;
+                for _ in 0..8 {
+                    crc = if crc & 1 == 0 { crc >> 1 } else { (crc >> 1) ^ poly };
+                }
+            } else {
+                crc ^= (*byte as u64) << (self.width - 8);
+                for _ in 0..8 {
+                    crc = if crc & top == 0 { crc << 1 } else { (crc << 1) ^ poly } & mask;
                 }
-            }
-        } else {
-            // Non-multiplexed message: pack all signals.
-            for idx in 0..self.signals.len() {
-                emit_signal_mut_action(
-                    code,
-                    "            ",
-                    idx,
-                    &self.signals[idx],
-                    SigMutAction::SetValue { frame: "frame" },
-                    "signal-set-values-fail",
-                )?;
             }
         }
-
+        (crc ^ self.xorout) & mask
+    }
+}"#
+    )?;
+    let mut algorithms: Vec<&CrcAlgorithm> =
+        code.checksums.values().map(|checksum| &checksum.algorithm).collect();
+    algorithms.sort_by(|a, b| a.name.cmp(&b.name));
+    algorithms.dedup_by(|a, b| a.name == b.name);
+    for algo in algorithms {
         code_output!(
             code,
-            r#"
-            Ok(self)
-        }
-    }
-"#
+            format!(
+                r#"
+/// {name} E2E checksum
+pub const {const_name}: CrcAlgorithm = CrcAlgorithm {{ name: "{name}", width: {width}, poly: {poly:#x}, init: {init:#x}, xorout: {xorout:#x}, reflect: {reflect} }};"#,
+                name = algo.name,
+                const_name = algo.name.to_shouty_snake_case(),
+                width = algo.width,
+                poly = algo.poly,
+                init = algo.init,
+                xorout = algo.xorout,
+                reflect = algo.reflect,
+            )
         )?;
-
-        Ok(())
     }
+    Ok(())
+}
 
-    fn gen_can_dbc_message(&self, code: &DbcCodeGen) -> io::Result<()> {
-        // build message signal:type list
-        code_output!(
-            code,
+/// Serialize impls working on `dyn CanDbcMessage`, shared by every message and the pool.
+/// Callbacks and listener counts are left out, signals are keyed by name.
+fn gen_serde_state(code: &DbcCodeGen) -> io::Result<()> {
+    code_output!(
+        code,
+        format!(
             r#"
-    impl CanDbcMessage for DbcMessage {
-        fn reset(&mut self) -> Result<(), CanError> {
-            self.status=CanBcmOpCode::Unknown;
-            self.stamp=0;"#
-        )?;
+/// Serializable view of any message: id, name, status, stamp and signals by name
+{cfg}pub struct MessageState<'a>(pub &'a dyn CanDbcMessage);
+
+{cfg}impl serde::Serialize for MessageState<'_> {{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+        use serde::ser::{{Error, SerializeMap, SerializeStruct}};
+        struct Signals<'a>(&'a [Rc<RefCell<Box<dyn CanDbcSignal>>>]);
+        impl serde::Serialize for Signals<'_> {{
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+                let mut map = serializer.serialize_map(Some(self.0.len()))?;
+                for signal in self.0 {{
+                    let signal = signal.try_borrow().map_err(S::Error::custom)?;
+                    let value: serde_json::Value =
+                        serde_json::from_str(&signal.to_json()).map_err(S::Error::custom)?;
+                    map.serialize_entry(signal.get_name(), &value)?;
+                }}
+                map.end()
+            }}
+        }}
+        let mut state = serializer.serialize_struct("DbcMessage", 5)?;
+        state.serialize_field("id", &self.0.get_id())?;
+        state.serialize_field("name", self.0.get_name())?;
+        state.serialize_field("status", &self.0.get_status())?;
+        state.serialize_field("stamp", &self.0.get_stamp())?;
+        state.serialize_field("signals", &Signals(self.0.get_signals()))?;
+        state.end()
+    }}
+}}
 
-        for idx in 0..self.signals.len() {
-            emit_signal_mut_action(
-                code,
-                "            ",
-                idx,
-                &self.signals[idx],
-                SigMutAction::Reset,
-                "signal-reset-fail",
-            )?;
-        }
-        code_output!(
-            code,
+{cfg}impl serde::Serialize for CanMsgPool {{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+        use serde::ser::{{Error, SerializeSeq, SerializeStruct}};
+        struct Messages<'a>(&'a [Rc<RefCell<Box<dyn CanDbcMessage>>>]);
+        impl serde::Serialize for Messages<'_> {{
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+                let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+                for msg in self.0 {{
+                    let msg = msg.try_borrow().map_err(S::Error::custom)?;
+                    seq.serialize_element(&MessageState(msg.as_ref()))?;
+                }}
+                seq.end()
+            }}
+        }}
+        let mut state = serializer.serialize_struct("CanMsgPool", 2)?;
+        state.serialize_field("uid", self.uid)?;
+        state.serialize_field("messages", &Messages(&self.pool))?;
+        state.end()
+    }}
+}}"#,
+            cfg = code.serde_cfg("")
+        )
+    )
+}
+
+/// `CanMsgPool::subscribe_signals()`, one callback wired on every signal matching a pattern.
+fn gen_pool_signal_callbacks(code: &DbcCodeGen) -> io::Result<()> {
+    let uid = CanErrorCode::MessageGetMut.uid();
+    code_output!(
+        code,
+        format!(
             r#"
-        Ok(())
-    }
+/// `subscribe_signals()` callback, shared by every matching signal
+struct SignalCallback(Rc<dyn Fn(&dyn CanDbcSignal) -> i32>);
 
-        fn update(&mut self, frame: &CanMsgData) -> Result<(), CanError> {
-            self.stamp= frame.stamp;
-            self.status= frame.opcode;
-            self.listeners= 0;"#
-        )?;
+impl CanSigCtrl for SignalCallback {{
+    fn sig_notification(&self, signal: &dyn CanDbcSignal) -> i32 {{
+        (self.0)(signal)
+    }}
+}}
 
-        // Mux validation (generator-time):
-        let mux_idx = find_mux_idx(self)?;
-        if has_multiplexed_signals(self) && mux_idx.is_none() {
-            return Err(Error::other(format!(
-                "message:{} has multiplexed signals but no multiplexor",
-                self.get_type_kamel()
-            )));
-        }
+/// What a `subscribe_signals_flow()` callback asks the dispatch to do next
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallbackFlow {{
+    /// Keep notifying
+    Continue,
+    /// Stop notifying this subscription, for every signal it wired
+    Unsubscribe,
+    /// Skip the notifications of this subscription for this many milliseconds of frame time
+    Pause(u64),
+}}
 
-        if let Some(mux_idx) = mux_idx {
-            let mux_sig = &self.signals[mux_idx];
-            validate_mux(self, mux_sig)?;
+/// One `subscribe_signals_flow()` subscription, shared by every matching signal
+struct FlowSubscription {{
+    callback: Box<dyn Fn(&dyn CanDbcSignal) -> CallbackFlow>,
+    /// stamp of the frame the pool is dispatching
+    stamp: Rc<Cell<u64>>,
+    unsubscribed: Cell<bool>,
+    resume_at: Cell<u64>,
+}}
 
-            // Read multiplexor RAW value from frame bits.
-            let mux_read_fn = match mux_sig.byte_order {
-                ByteOrder::LittleEndian => {
-                    let (start_bit, end_bit) = mux_sig.le_start_end_bit(self)?;
-                    format!(
-                        "frame.data.view_bits::<Lsb0>()[{start}..{end}].load_le::<{typ}>()",
-                        typ = mux_sig.get_data_usize(),
-                        start = start_bit,
-                        end = end_bit,
-                    )
-                },
-                ByteOrder::BigEndian => {
-                    let (start_bit, end_bit) = mux_sig.be_start_end_bit(self)?;
-                    format!(
-                        "frame.data.view_bits::<Msb0>()[{start}..{end}].load_be::<{typ}>()",
-                        typ = mux_sig.get_data_usize(),
-                        start = start_bit,
-                        end = end_bit,
-                    )
-                },
-            };
+struct FlowCallback(Rc<FlowSubscription>);
 
-            if mux_sig.value_type == ValueType::Signed {
-                let data_usize = mux_sig.get_data_usize();
-                let data_isize = mux_sig.get_data_isize();
-                let bits = mux_sig.size;
-                code_output!(
-                    code,
-                    format!(
-                        r#"
-            let __mux_raw_value: u64 = {{
-            let value = {mux_read_fn};
-            // Sign-extend mux raw value from its bit-width (mux selectors are raw values).
-            let shift = {data_usize}::BITS - {bits}u32;
-            let signed: {data_isize} = ((value << shift) as {data_isize}) >> shift;
-            (signed as i64) as u64
-    }};"#
-                    )
-                )?;
-            } else {
-                code_output!(
-                    code,
-                    format!(
-                        r#"
-            let __mux_raw_value: u64 = ({mux_read_fn}) as u64;"#
-                    )
-                )?;
-            }
+impl CanSigCtrl for FlowCallback {{
+    fn sig_notification(&self, signal: &dyn CanDbcSignal) -> i32 {{
+        let flow = &self.0;
+        let now = flow.stamp.get();
+        if flow.unsubscribed.get() || now < flow.resume_at.get() {{
+            return 0;
+        }}
+        match (flow.callback)(signal) {{
+            CallbackFlow::Continue => {{}},
+            CallbackFlow::Unsubscribe => flow.unsubscribed.set(true),
+            CallbackFlow::Pause(ms) => flow.resume_at.set(now.saturating_add(ms.saturating_mul(1000))),
+        }}
+        0
+    }}
+}}
 
-            // Always update the multiplexor itself first.
-            emit_signal_mut_action(
-                code,
-                "            ",
-                mux_idx,
-                &self.signals[mux_idx],
-                SigMutAction::Update { frame: "frame", listeners: "self.listeners" },
-                "signal-update-fail",
-            )?;
+/// Shell-like match where `*` stands for any sequence of characters
+fn glob_match(pattern: &str, text: &str) -> bool {{
+    match pattern.split_once('*') {{
+        None => pattern == text,
+        Some((head, tail)) => {{
+            text.starts_with(head)
+                && (head.len()..=text.len())
+                    .filter(|idx| text.is_char_boundary(*idx))
+                    .any(|idx| glob_match(tail, &text[idx..]))
+        }},
+    }}
+}}
 
-            // Update/reset other signals based on mux value.
-            for idx in 0..self.signals.len() {
-                if idx == mux_idx {
-                    continue;
-                }
+impl CanMsgPool {{
+    /// Set `callback` on every signal matching `pattern`, "Signal" or "Message.Signal" where `*`
+    /// matches any sequence (e.g. "Battery*", "Bms*.*Temp"), replacing their previous callback.
+    /// Returns the number of signals wired.
+    pub fn subscribe_signals<F>(&self, pattern: &str, callback: F) -> Result<usize, CanError>
+    where
+        F: Fn(&dyn CanDbcSignal) -> i32 + 'static,
+    {{
+        let callback: Rc<dyn Fn(&dyn CanDbcSignal) -> i32> = Rc::new(callback);
+        self.wire_signals(pattern, || Box::new(SignalCallback(callback.clone())))
+    }}
 
-                match self.signals[idx].multiplexer_indicator {
-                    MultiplexIndicator::MultiplexedSignal(mux_val) => {
-                        code_output!(
-                            code,
-                            format!(
-                                r#"
-            if __mux_raw_value == {mux_val} {{
-                "#
-                            )
-                        )?;
-                        emit_signal_mut_action(
-                            code,
-                            "                ",
-                            idx,
-                            &self.signals[idx],
-                            SigMutAction::Update { frame: "frame", listeners: "self.listeners" },
-                            "signal-update-fail",
-                        )?;
-                        code_output!(
-                            code,
-                            r#"
-            } else {"#
-                        )?;
-                        emit_signal_mut_action(
-                            code,
-                            "                ",
-                            idx,
-                            &self.signals[idx],
-                            SigMutAction::Reset,
-                            "signal-update-fail",
-                        )?;
-                        code_output!(code, r#"            }"#)?;
-                    },
+    /// Like `subscribe_signals()`, the `callback` result steering the subscription: `Continue`,
+    /// `Unsubscribe` for good, or `Pause(ms)` skipping notifications until a frame stamped `ms`
+    /// later. Pauses follow the stamps of the frames given to `update()` of this pool.
+    pub fn subscribe_signals_flow<F>(&self, pattern: &str, callback: F) -> Result<usize, CanError>
+    where
+        F: Fn(&dyn CanDbcSignal) -> CallbackFlow + 'static,
+    {{
+        let flow = Rc::new(FlowSubscription {{
+            callback: Box::new(callback),
+            stamp: Rc::clone(&self.stamp),
+            unsubscribed: Cell::new(false),
+            resume_at: Cell::new(0),
+        }});
+        self.wire_signals(pattern, || Box::new(FlowCallback(Rc::clone(&flow))))
+    }}
 
-                    MultiplexIndicator::Plain
-                    | MultiplexIndicator::Multiplexor
-                    | MultiplexIndicator::MultiplexorAndMultiplexedSignal(_) => {
-                        emit_signal_mut_action(
-                            code,
-                            "            ",
-                            idx,
-                            &self.signals[idx],
-                            SigMutAction::Update { frame: "frame", listeners: "self.listeners" },
-                            "signal-update-fail",
-                        )?;
-                    },
-                }
-            }
+    /// Set a callback from `make` on every signal matching `pattern`
+    fn wire_signals(&self, pattern: &str, make: impl Fn() -> Box<dyn CanSigCtrl>) -> Result<usize, CanError> {{
+        let (msg_pattern, sig_pattern) = pattern.split_once('.').unwrap_or(("*", pattern));
+        let mut count = 0;
+        for msg in &self.pool {{
+            let msg = match msg.try_borrow() {{
+                Ok(msg) => msg,
+                Err(_) => return Err(CanError::new("{uid}", "Internal error message borrowed")),
+            }};
+            if !glob_match(msg_pattern, msg.get_name()) {{
+                continue;
+            }}
+            for signal in msg.get_signals() {{
+                let mut signal = match signal.try_borrow_mut() {{
+                    Ok(signal) => signal,
+                    Err(_) => return Err(CanError::new("{uid}", format!("Internal error {{}} borrowed", msg.get_name()))),
+                }};
+                if glob_match(sig_pattern, signal.get_name()) {{
+                    signal.set_callback(make());
+                    count += 1;
+                }}
+            }}
+        }}
+        Ok(count)
+    }}
+}}"#
+        )
+    )
+}
+
+/// `CanMsgPool::get_by_name()`, `get_signal()` and `signal_meta()`, messages and signals
+/// found by name or id through generated matches.
+fn gen_pool_lookup(code: &DbcCodeGen) -> io::Result<()> {
+    code_output!(
+        code,
+        r"
+/// SG_ unit, range, scaling and layout of a signal, see `CanMsgPool::signal_meta()`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SignalMeta {
+    /// Physical unit, empty when the DBC gives none
+    pub unit: &'static str,
+    /// Minimum physical value, 0 and 0 for an unspecified range
+    pub min: f64,
+    /// Maximum physical value
+    pub max: f64,
+    /// Scaling factor, physical = raw * factor + offset
+    pub factor: f64,
+    /// Scaling offset
+    pub offset: f64,
+    /// Signal size in bits
+    pub bit_length: u32,
+    /// SG_ start bit, in the DBC bit numbering
+    pub start_bit: u32,
+}
+
+impl CanMsgPool {
+    /// Message called `name`, its `get_name()` or DBC name, as `get_mut()` finds it by id:
+    /// configurations name messages whose ids differ between vehicle variants.
+    pub fn get_by_name(&self, name: &str) -> Result<RefMut<'_, Box<dyn CanDbcMessage>>, CanError> {
+        let idx = match name {"
+    )?;
+    for (idx, message) in code.dbcfd.messages.iter().enumerate() {
+        let msg_type = message.get_type_kamel();
+        let names = if msg_type == message.name {
+            format!("{msg_type:?}")
         } else {
-            // Non-multiplexed message
-            for idx in 0..self.signals.len() {
-                emit_signal_mut_action(
-                    code,
-                    "            ",
-                    idx,
-                    &self.signals[idx],
-                    SigMutAction::Update { frame: "frame", listeners: "self.listeners" },
-                    "signal-update-fail",
-                )?;
-            }
+            format!("{msg_type:?} | {:?}", message.name)
+        };
+        code_output!(code, format!("            {names} => {idx},"))?;
+    }
+    code_output!(
+        code,
+        format!(
+            r#"            _ => return Err(CanError::new("{}", format!("message:{{}} not found", name))),
+        }};
+        match self.pool[idx].try_borrow_mut() {{
+            Err(_code) => Err(CanError::new("{}", "internal msg pool error")),
+            Ok(mut_ref) => Ok(mut_ref),
+        }}
+    }}"#,
+            CanErrorCode::UnknownCanId.uid(),
+            CanErrorCode::MessageGetMut.uid()
+        )
+    )?;
+    code_output!(
+        code,
+        r"
+    /// Signal called `name` (its `get_name()` or DBC name) of the message `canid`, see
+    /// `DbcMessage::signal_index()`. None when the message or signal is unknown, or while the
+    /// message is being updated.
+    pub fn get_signal(&self, canid: u32, name: &str) -> Option<Rc<RefCell<Box<dyn CanDbcSignal>>>> {
+        let (msg, sig) = match canid {"
+    )?;
+    for (idx, message) in code.dbcfd.messages.iter().enumerate() {
+        if !message.signals.is_empty() {
+            let (canid, msg_type) = (message.id.raw(), message.get_type_kamel());
+            code_output!(
+                code,
+                format!(
+                    "            {canid} => ({idx}, {msg_type}::DbcMessage::signal_index(name)?),"
+                )
+            )?;
+        }
+    }
+    code_output!(
+        code,
+        r"            _ => return None,
+        };
+        let msg = self.pool[msg].try_borrow().ok()?;
+        msg.get_signals().get(sig).cloned()
+    }
+
+    /// Unit, range, scaling and layout of the signal called `name` (its `get_name()` or DBC
+    /// name) of the message `canid`, see `DbcMessage::signal_meta()`. Unlike `get_signal()`,
+    /// it borrows nothing and works while the message is being updated.
+    pub fn signal_meta(canid: u32, name: &str) -> Option<SignalMeta> {
+        match canid {"
+    )?;
+    for message in &code.dbcfd.messages {
+        if !message.signals.is_empty() {
+            let (canid, msg_type) = (message.id.raw(), message.get_type_kamel());
+            code_output!(
+                code,
+                format!("            {canid} => {msg_type}::DbcMessage::signal_meta(name),")
+            )?;
         }
-        let msg_type = self.get_type_kamel();
-        code_output!(
-            code,
-            format!(
-                r#"
-            match &self.callback {{
-                None => {{}},
-                Some(callback) => {{
-                    match callback.try_borrow() {{
-                        Err(_) => println!("fail to get message callback reference"),
-                        Ok(cb_ref) => cb_ref.msg_notification(self),
-                    }}
-                }}
-            }}
-            Ok(())
-        }}
+    }
+    code_output!(
+        code,
+        r"            _ => None,
+        }
+    }
+}"
+    )
+}
 
-        fn get_signals(&self) -> &[Rc<RefCell<Box<dyn CanDbcSignal>>>] {{
-            &self.signals
-        }}
+/// `CanIdFilter` and `CanMsgPool::subscribe_ids()`/`resolve()`, routing frame ids outside the
+/// DBC (e.g. J1939 source addresses) to pool messages.
+fn gen_pool_id_filters(code: &DbcCodeGen) -> io::Result<()> {
+    let uid = CanErrorCode::UnknownCanId.uid();
+    code_output!(
+        code,
+        format!(
+            r#"
+/// Frame ids routed to a pool message whose DBC id differs, e.g. J1939 source addresses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CanIdFilter {{
+    /// Ids equal to `value` on the `mask` bits, each routed to the message with the same
+    /// masked id: `{{ value: 0x18FEF100, mask: 0x03FFFF00 }}` ignores priority and source address
+    Mask {{ value: u32, mask: u32 }},
+    /// Ids in `first..=last`, routed to the only message of the range
+    Range {{ first: u32, last: u32 }},
+}}
 
-        fn get_listeners(&self) -> i32 {{
-            self.listeners
+impl CanIdFilter {{
+    /// True when the filter accepts `canid`
+    pub fn matches(&self, canid: u32) -> bool {{
+        match *self {{
+            CanIdFilter::Mask {{ value, mask }} => canid & mask == value & mask,
+            CanIdFilter::Range {{ first, last }} => (first..=last).contains(&canid),
         }}
+    }}
 
-        fn set_callback(&mut self, callback: Box<dyn CanMsgCtrl>)  {{
-            self.callback= Some(RefCell::new(callback));
+    /// Id among `ids` a frame `canid` accepted by the filter is routed to
+    fn route(&self, canid: u32, ids: &[u32]) -> Option<u32> {{
+        if !self.matches(canid) {{
+            return None;
         }}
-
-        fn get_name(&self) -> &'static str {{
-            self.name
+        match *self {{
+            CanIdFilter::Mask {{ mask, .. }} => ids.iter().copied().find(|id| id & mask == canid & mask),
+            CanIdFilter::Range {{ .. }} => ids.iter().copied().find(|id| self.matches(*id)),
         }}
+    }}
+}}
 
-        fn get_status(&self) -> CanBcmOpCode {{
-            self.status
+impl CanMsgPool {{
+    /// Route frame ids accepted by `filter` to the pool messages it covers, returns how many
+    /// messages it covers. A filter covering no message, or a range covering several, is rejected.
+    pub fn subscribe_ids(&self, filter: CanIdFilter) -> Result<usize, CanError> {{
+        let count = self.get_ids().iter().filter(|id| filter.matches(**id)).count();
+        match (filter, count) {{
+            (_, 0) => return Err(CanError::new("{uid}", format!("no message matches {{:?}}", filter))),
+            (CanIdFilter::Range {{ .. }}, 2..) => {{
+                return Err(CanError::new("{uid}", format!("{{}} messages in {{:?}}", count, filter)))
+            }},
+            _ => {{}},
         }}
-
-        fn get_stamp(&self) -> u64 {{
-            self.stamp
+        match self.filters.try_borrow_mut() {{
+            Ok(mut filters) => filters.push(filter),
+            Err(_) => return Err(CanError::new("{uid}", "internal filter list error")),
         }}
+        Ok(count)
+    }}
 
-        fn get_id(&self) -> u32 {{
-            self.id
+    /// Pool message id a frame `canid` updates: itself when the pool has it, the extended
+    /// message when a 29-bit `canid` comes without CAN_EFF_FLAG, else the message routed by the
+    /// first `subscribe_ids()` filter accepting it
+    pub fn resolve(&self, canid: u32) -> Option<u32> {{
+        if self.get_ids().binary_search(&canid).is_ok() {{
+            return Some(canid);
         }}
-
-        fn as_any(&mut self) -> &mut dyn Any {{
-            self
+        let extended = canid | {CAN_EFF_FLAG:#x};
+        if canid > 0x7ff && self.get_ids().binary_search(&extended).is_ok() {{
+            return Some(extended);
         }}
+        let filters = self.filters.try_borrow().ok()?;
+        filters.iter().find_map(|filter| filter.route(canid, self.get_ids()))
+    }}
+}}"#
+        )
+    )
+}
 
-    }} // end {msg_type} impl for CanDbcMessage"#
-            )
-        )?;
+/// Pool field of a message in `StaticPool`.
+fn static_pool_field(msg: &Message) -> String {
+    let field = msg.get_type_kamel().to_snake_case();
+    if KEYWORDS.contains(&field.as_str()) {
+        format!("x_{field}")
+    } else {
+        field
+    }
+}
 
-        Ok(())
+/// `StaticPool` of `DbcParser::static_pool()`: every `StaticMessage` by value and the `match`
+/// dispatching frames to them.
+fn gen_static_pool(code: &DbcCodeGen) -> io::Result<()> {
+    code_output!(
+        code,
+        r#"
+/// Every message held by value: frames are decoded without allocation, RefCell borrow nor
+/// dynamic dispatch. Message and signal callbacks are not called, see `CanMsgPool` for them.
+pub struct StaticPool {"#
+    )?;
+    for msg in &code.dbcfd.messages {
+        let (field, msg_type) = (static_pool_field(msg), msg.get_type_kamel());
+        code_output!(code, format!(r#"    pub {field}: {msg_type}::StaticMessage,"#))?;
     }
+    code_output!(
+        code,
+        r#"}
 
-    fn gen_code_message(&self, code: &DbcCodeGen) -> io::Result<()> {
-        // message header
-        let name = &self.name;
-        let id = self.id.raw();
-        let size = self.size;
+/// Message of the `StaticPool` a frame was decoded into
+pub enum StaticMessageMut<'a> {"#
+    )?;
+    for msg in &code.dbcfd.messages {
+        let msg_type = msg.get_type_kamel();
+        code_output!(code, format!(r#"    {msg_type}(&'a mut {msg_type}::StaticMessage),"#))?;
+    }
+    code_output!(
+        code,
+        r#"}
+
+impl StaticPool {
+    pub fn new() -> Self {
+        StaticPool {"#
+    )?;
+    for msg in &code.dbcfd.messages {
+        let (field, msg_type) = (static_pool_field(msg), msg.get_type_kamel());
+        code_output!(code, format!(r#"            {field}: {msg_type}::StaticMessage::new(),"#))?;
+    }
+    code_output!(
+        code,
+        r#"        }
+    }
+
+    /// Reset every message and signal
+    pub fn reset(&mut self) -> Result<(), CanError> {"#
+    )?;
+    for msg in &code.dbcfd.messages {
+        code_output!(code, format!(r#"        self.{}.reset()?;"#, static_pool_field(msg)))?;
+    }
+    code_output!(
+        code,
+        r#"        Ok(())
+    }
+
+    /// Decode `data` into the message with its id, `None` when the pool has none. Like
+    /// `CanMsgPool::update()`, 29-bit ids match with or without CAN_EFF_FLAG; `subscribe_ids()`
+    /// filters do not apply.
+    pub fn update(&mut self, data: &CanMsgData) -> Result<Option<StaticMessageMut<'_>>, CanError> {
+        match data.canid {"#
+    )?;
+    for msg in &code.dbcfd.messages {
+        let (field, msg_type) = (static_pool_field(msg), msg.get_type_kamel());
+        let (can_id, extended) = bus_id(msg.id);
+        // same rule as CanMsgPool::resolve(), 11-bit ids never reach an extended message
+        let pattern = if extended && can_id > 0x7ff {
+            format!("{:#x} | {can_id:#x}", msg.id.raw())
+        } else {
+            format!("{:#x}", msg.id.raw())
+        };
         code_output!(
             code,
             format!(
-                r#"
-/// {name} Message
-/// - ID: {id} (0x{id:x})
-/// - Size: {size} bytes"#
+                r#"            {pattern} => {{
+                self.{field}.update(data)?;
+                Ok(Some(StaticMessageMut::{msg_type}(&mut self.{field})))
+            }},"#
             )
         )?;
-
-        if let Transmitter::NodeName(transmitter) = &self.transmitter {
-            code_output!(code, format!(r"/// - Transmitter: {transmitter}"))?;
+    }
+    code_output!(
+        code,
+        r#"            _ => Ok(None),
         }
+    }
+}"#
+    )
+}
 
-        if let Some(comment) = code.dbcfd.message_comment(self.id) {
-            code_output!(code, "///")?;
-            for line in comment.trim().lines() {
-                code_output!(code, format!(r"/// {line}"))?;
-            }
+/// Signal structs of `DbcParser::signal_structs()`: one struct per entry and its pool getter.
+fn gen_signal_structs(code: &DbcCodeGen) -> io::Result<()> {
+    let uid = CanErrorCode::SignalGroupGetFail.uid();
+    code_output!(
+        code,
+        format!(
+            r#"
+impl CanMsgPool {{
+    /// Value and message stamp of a signal struct member, None unless its last frame set it
+    /// and came at most `max_age_ms` before the last frame of the pool (0 for no limit)
+    fn fresh_value(&self, msg: usize, signal: usize, max_age_ms: u64) -> Result<Option<(CanDbcType, u64)>, CanError> {{
+        let msg = match self.pool[msg].try_borrow() {{
+            Ok(msg) => msg,
+            Err(_) => return Err(CanError::new("{uid}", "Internal error message borrowed")),
+        }};
+        let signal = match msg.get_signals()[signal].try_borrow() {{
+            Ok(signal) => signal,
+            Err(_) => return Err(CanError::new("{uid}", format!("Internal error {{}} borrowed", msg.get_name()))),
+        }};
+        if !matches!(signal.get_status(), CanDataStatus::Updated | CanDataStatus::Unchanged) {{
+            return Ok(None);
+        }}
+        let stamp = msg.get_stamp();
+        if max_age_ms > 0 && self.stamp.get().saturating_sub(stamp) > max_age_ms * 1000 {{
+            return Ok(None);
+        }}
+        Ok(Some((signal.get_value(), stamp)))
+    }}
+}}"#
+        )
+    )?;
+
+    for entry in &code.signal_structs {
+        // (field, message index in the pool, message, signal index, signal)
+        let mut members = Vec::new();
+        for (field, id, name) in &entry.fields {
+            let (msg_idx, msg) = code
+                .dbcfd
+                .messages
+                .iter()
+                .enumerate()
+                .find(|(_, msg)| msg.id.raw() == *id)
+                .ok_or_else(|| {
+                    Error::other(format!("signal struct:{} canid:{id} not generated", entry.name))
+                })?;
+            let (sig_idx, sig) =
+                msg.signals.iter().enumerate().find(|(_, sig)| sig.name == *name).ok_or_else(
+                    || {
+                        Error::other(format!(
+                            "signal struct:{} signal:{name} not generated",
+                            entry.name
+                        ))
+                    },
+                )?;
+            members.push((field, msg_idx, msg, sig_idx, sig));
         }
 
-        // per message module/name-space
-        let msg_mod = self.get_type_kamel();
-
+        let name = &entry.name;
+        let getter = name.to_snake_case();
+        let listing: Vec<String> = members
+            .iter()
+            .map(|(field, _, msg, _, sig)| format!("{field} = {}.{}", msg.name, sig.name))
+            .collect();
         code_output!(
             code,
             format!(
                 r#"
-pub mod {msg_mod} {{ /// Message name space
-    use sockcan::prelude::*;
-    use bitvec::prelude::*;
-    use std::any::Any;
-    use std::cell::{{RefCell}};
-    use std::rc::Rc;
-
-    use std::fmt;
-"#
+/// {name} signal struct, read with `CanMsgPool::get_{getter}()`: {listing}
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct {name} {{"#,
+                listing = listing.join(", "),
             )
         )?;
-
-        if code.serde_json {
-            code_output!(code, r#"    use serde::{Deserialize, Serialize};"#)?;
-        }
-
-        // enumeration with all signal type
-        code_output!(code, r#"    pub enum DbcSignal {"#)?;
-        for signal in &self.signals {
-            let type_id = signal.get_type_kamel();
-            code_output!(code, format!(r#"        {type_id},"#))?;
+        for (field, _, msg, _, sig) in &members {
+            code_output!(code, format!("    pub {field}: {},", code.data_type(msg, sig)))?;
         }
+        let signals: Vec<String> = members
+            .iter()
+            .map(|(_, _, msg, _, sig)| format!("({}, {:?})", msg.id.raw(), sig.get_type_kamel()))
+            .collect();
         code_output!(
             code,
-            r#"
-    }
-"#
+            format!(
+                r#"    /// Message stamp of the newest member
+    pub stamp: u64,
+}}
+
+impl {name} {{
+    /// (canid, signal) of each field, in field order
+    pub const SIGNALS: [(u32, &'static str); {count}] = [{signals}];
+    /// Oldest member accepted, relative to the last frame of the pool (0 for no limit)
+    pub const MAX_AGE_MS: u64 = {max_age_ms};
+}}
+
+impl CanMsgPool {{
+    /// Every {name} member from the last decoded frames at once, None until each member was
+    /// received and none is older than {name}::MAX_AGE_MS
+    pub fn get_{getter}(&self) -> Result<Option<{name}>, CanError> {{"#,
+                count = members.len(),
+                signals = signals.join(", "),
+                max_age_ms = entry.max_age_ms,
+            )
         )?;
-        // signals structures and implementation
-        for signal in &self.signals {
-            signal.gen_code_signal(code, self)?;
+        for (field, msg_idx, _, sig_idx, _) in &members {
+            code_output!(
+                code,
+                format!(
+                    "        let Some(({field}, {field}_stamp)) = self.fresh_value({msg_idx}, {sig_idx}, {name}::MAX_AGE_MS)? else {{ return Ok(None) }};"
+                )
+            )?;
         }
-
-        self.gen_can_dbc_impl(code)?;
-        self.gen_can_dbc_message(code)?;
-        let msg_type = self.get_type_kamel();
+        code_output!(code, format!("        Ok(Some({name} {{"))?;
+        for (field, _, _, _, _) in &members {
+            code_output!(code, format!("            {field}: {field}.cast()?,"))?;
+        }
+        let stamp = members
+            .iter()
+            .map(|(field, _, _, _, _)| format!("{field}_stamp"))
+            .reduce(|newest, stamp| format!("{newest}.max({stamp})"))
+            .unwrap_or_default();
         code_output!(
             code,
             format!(
-                r#"
-}} // end {msg_type} message
-"#
+                r#"            stamp: {stamp},
+        }}))
+    }}
+}}"#
             )
         )?;
-        Ok(())
-    }
-}
-
-pub trait Text2Str<T> {
-    /// Write a line with indentation.
-    ///
-    /// # Errors
-    /// Propagates any I/O error from the underlying writer.
-    fn write(&self, indent: &str, text: T) -> io::Result<()>;
-}
-
-impl Text2Str<&str> for DbcCodeGen {
-    fn write(&self, indent: &str, text: &str) -> io::Result<()> {
-        let nl = "\n";
-        if let Some(outfd) = &self.outfd {
-            let mut outfd = outfd.try_clone()?;
-            outfd.write_all(indent.as_bytes())?;
-            outfd.write_all(text.as_bytes())?;
-            outfd.write_all(nl.as_bytes())?;
-        } else {
-            let mut outfd = io::stdout();
-            outfd.write_all(indent.as_bytes())?;
-            outfd.write_all(text.as_bytes())?;
-            outfd.write_all(nl.as_bytes())?;
-        }
-
-        Ok(())
-    }
-}
-
-impl Text2Str<String> for DbcCodeGen {
-    fn write(&self, indent: &str, text: String) -> io::Result<()> {
-        Self::write(self, indent, text.as_str())
     }
+    Ok(())
 }
 
-impl DbcCodeGen {
-    fn output<T>(&self, indent: &str, text: T) -> io::Result<()>
-    where
-        DbcCodeGen: Text2Str<T>,
-    {
-        Self::write(self, indent, text)
-    }
+/// `UnknownIdPolicy` and the unknown id statistics of `CanMsgPool`, for frames neither in the
+/// DBC nor routed by a filter.
+fn gen_pool_unknown_ids(code: &DbcCodeGen) -> io::Result<()> {
+    code_output!(
+        code,
+        r#"
+/// What the pool does with frames whose id is neither in the DBC nor routed by a filter
+#[derive(Clone, Default)]
+pub enum UnknownIdPolicy {
+    /// `update()` and `update_frame()` fail with a fail-canid-search error
+    #[default]
+    Reject,
+    /// `update_frame()` returns `Ok(None)`
+    Ignore,
+    /// As `Ignore`, and the frame is counted in `unknown_frames()`
+    Count,
+    /// As `Count`, and the frame is handed to the raw handler
+    Forward(Rc<dyn Fn(&CanMsgData)>),
 }
 
-pub const DEFAULT_HEADER: &str = r#"
-// -----------------------------------------------------------------------
-//              <- DBC file Rust mapping ->
-// -----------------------------------------------------------------------
-//  Do not edit this file it will be regenerated automatically by cargo.
-//  Check:
-//   - build.rs at project root for dynamically mapping
-//   - example/demo/dbc-log/??? for static values
-//  Reference: iot.bzh/Redpesk canbus-rs code generator
-// -----------------------------------------------------------------------
-
-// Tell rustfmt (stable) to skip formatting this whole file
-#[rustfmt::skip]
-
-#[allow(
-    warnings,
-    clippy::all,
-    clippy::pedantic,
-    clippy::nursery,
-    clippy::redundant_field_names,
-    clippy::similar_names
-)]
-"#;
-
-impl DbcParser {
-    #[must_use]
-    pub fn new(uid: &'static str) -> Self {
-        DbcParser {
-            uid,
-            range_check: true,
-            serde_json: true,
-            infile: None,
-            outfile: None,
-            header: None,
-            whitelist: None,
-            blacklist: None,
-        }
-    }
-
-    pub fn dbcfile(&mut self, dbcfile: &str) -> &mut Self {
-        self.infile = Some(dbcfile.to_owned());
-        self
-    }
-
-    pub fn outfile(&mut self, outfile: &str) -> &mut Self {
-        self.outfile = Some(outfile.to_owned());
-        self
-    }
-
-    pub fn header(&mut self, header: &'static str) -> &mut Self {
-        self.header = Some(header);
-        self
-    }
+/// Frames received for one unknown id
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownFrame {
+    pub canid: u32,
+    pub count: u64,
+    /// Stamp and length of the last frame
+    pub stamp: u64,
+    pub len: u8,
+}
 
-    pub fn whitelist(&mut self, canids: Vec<u32>) -> &mut Self {
-        self.whitelist = Some(canids);
-        self
+/// Unknown id policy and statistics of a pool
+#[derive(Default)]
+struct UnknownIds {
+    policy: UnknownIdPolicy,
+    frames: BTreeMap<u32, UnknownFrame>,
+}
+
+impl CanMsgPool {
+    /// Policy applied to frames with an unknown id, `Reject` by default
+    pub fn set_unknown_policy(&self, policy: UnknownIdPolicy) {
+        if let Ok(mut unknown) = self.unknown.try_borrow_mut() {
+            unknown.policy = policy;
+        }
     }
 
-    pub fn blacklist(&mut self, canids: Vec<u32>) -> &mut Self {
-        self.blacklist = Some(canids);
-        self
+    /// Fallback receiving every frame whose id is unknown to the pool (raw bytes and stamp),
+    /// e.g. to forward or log it from a gateway: the `Forward` policy with `handler`
+    pub fn set_raw_handler<F>(&self, handler: F)
+    where
+        F: Fn(&CanMsgData) + 'static,
+    {
+        self.set_unknown_policy(UnknownIdPolicy::Forward(Rc::new(handler)));
     }
 
-    pub fn range_check(&mut self, flag: bool) -> &mut Self {
-        self.range_check = flag;
-        self
+    /// Like `update()`, but a frame with an unknown id returns `Ok(None)` unless the policy
+    /// is `Reject`, so mixed traffic needs no pre-filtering
+    pub fn update_frame(&self, data: &CanMsgData) -> Result<Option<RefMut<'_, Box<dyn CanDbcMessage>>>, CanError> {
+        let canid = self.resolve(data.canid).unwrap_or(data.canid);
+        let reject = self.unknown.try_borrow().map_or(true, |unknown| matches!(unknown.policy, UnknownIdPolicy::Reject));
+        if !reject && self.get_ids().binary_search(&canid).is_err() {
+            self.unknown_frame(data);
+            return Ok(None);
+        }
+        self.update(data).map(Some)
     }
 
-    pub fn serde_json(&mut self, flag: bool) -> &mut Self {
-        self.serde_json = flag;
-        self
+    /// Unknown ids counted by the `Count` and `Forward` policies, ordered by id
+    pub fn unknown_frames(&self) -> Vec<UnknownFrame> {
+        match self.unknown.try_borrow() {
+            Ok(unknown) => unknown.frames.values().copied().collect(),
+            Err(_) => Vec::new(),
+        }
     }
 
-    fn check_list(canid: MessageId, list: &[u32]) -> bool {
-        list.binary_search(&canid.raw()).is_ok()
+    /// Forget the unknown ids counted so far
+    pub fn clear_unknown_frames(&self) {
+        if let Ok(mut unknown) = self.unknown.try_borrow_mut() {
+            unknown.frames.clear();
+        }
     }
 
-    /// # Errors
-    /// Propagates any I/O error: reading the DBC, parsing, writing output, and time formatting.
-    #[allow(clippy::too_many_lines)]
-    pub fn generate(&mut self) -> io::Result<()> {
-        let Some(infile) = &self.infile else {
-            return Err(Error::other("setting dbcpath is mandatory"));
+    fn unknown_frame(&self, data: &CanMsgData) {
+        let handler = match self.unknown.try_borrow_mut() {
+            Err(_) => return,
+            Ok(mut unknown) => {
+                let handler = match &unknown.policy {
+                    UnknownIdPolicy::Reject | UnknownIdPolicy::Ignore => return,
+                    UnknownIdPolicy::Count => None,
+                    UnknownIdPolicy::Forward(handler) => Some(Rc::clone(handler)),
+                };
+                let frame = unknown.frames.entry(data.canid).or_insert(UnknownFrame { canid: data.canid, count: 0, stamp: 0, len: 0 });
+                frame.count += 1;
+                frame.stamp = data.stamp;
+                frame.len = data.len;
+                handler
+            },
         };
+        // called without the borrow, the handler may use the pool
+        if let Some(handler) = handler {
+            handler(data);
+        }
+    }
+}"#
+    )
+}
 
-        // open and parse dbc input file
-        let buffer = fs::read_to_string(infile.as_str())?;
+/// BCM subscription of a whole pool, so consumers do not hand-roll RxSetup loops.
+fn gen_pool_subscribe(code: &DbcCodeGen) -> io::Result<()> {
+    let uid = CanErrorCode::UnknownCanId.uid();
+    code_output!(
+        code,
+        format!(
+            r#"
+/// BCM receive filters registered by `CanMsgPool::subscribe()`, or their new timers
+/// for `CanMsgPool::set_timers()`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SubscribeOpts {{
+    /// At most one RxChanged notification per message every `rate` ms, 0 for every change
+    pub rate: u64,
+    /// RxTimeout notification when a message is not received for `watchdog` ms, 0 to disable
+    pub watchdog: u64,
+    /// CAN ids to subscribe, every message of the pool when None
+    pub only: Option<Vec<u32>>,
+}}
 
-        let mut dbcfd = match Dbc::try_from(buffer.as_str()) {
-            Err(error) => return Err(Error::other(error.to_string())),
-            Ok(dbcfd) => dbcfd,
-        };
+impl CanMsgPool {{
+    /// Register one BCM RxSetup filter per message on `sock` (a BCM socket), nothing is
+    /// registered when `only` lists a CAN id missing from the pool
+    pub fn subscribe(&self, sock: &SockCanHandle, opts: SubscribeOpts) -> Result<(), CanError> {{
+        let mut flags = CanBcmFlag::RX_FILTER_ID;
+        if opts.rate > 0 || opts.watchdog > 0 {{
+            flags = flags | CanBcmFlag::SET_TIMER | CanBcmFlag::START_TIMER;
+        }}
+        self.rx_setup(sock, &opts, flags)
+    }}
 
-        // sort message by canid
-        dbcfd.messages.sort_by(|a, b| a.id.raw().cmp(&b.id.raw()));
+    /// Change the `rate` and `watchdog` of the filters `subscribe()` registered on `sock`,
+    /// without reopening it, e.g. to switch between slow polling and high-rate diagnostics:
+    /// 0 stops the throttling or the watchdog. `only` restricts the change to some messages,
+    /// an id not subscribed yet gets a filter.
+    pub fn set_timers(&self, sock: &SockCanHandle, opts: SubscribeOpts) -> Result<(), CanError> {{
+        let flags = CanBcmFlag::RX_FILTER_ID | CanBcmFlag::SET_TIMER | CanBcmFlag::START_TIMER;
+        self.rx_setup(sock, &opts, flags)
+    }}
 
-        if let Some(mut list) = self.whitelist.clone() {
-            if list.is_empty() {
-                // empty whitelist means "keep everything"
-                dbcfd.messages.retain(|_| true);
-            } else {
-                list.sort_unstable();
-                dbcfd.messages.retain(|msg| DbcParser::check_list(msg.id, &list));
-            }
-        }
+    /// One BCM RxSetup per `opts` message, none when an id is missing from the pool
+    fn rx_setup(&self, sock: &SockCanHandle, opts: &SubscribeOpts, flags: CanBcmFlag) -> Result<(), CanError> {{
+        let canids = match &opts.only {{
+            Some(only) => only.as_slice(),
+            None => self.get_ids(),
+        }};
+        if let Some(canid) = canids.iter().find(|canid| !self.get_ids().contains(canid)) {{
+            return Err(CanError::new("{uid}", format!("canid:{{}} not found", canid)));
+        }}
+        // extended DBC ids already carry CAN_EFF_FLAG (bit 31), as the BCM expects
+        for canid in canids {{
+            SockBcmCmd::new(CanBcmOpCode::RxSetup, flags, *canid)
+                .set_timers(opts.rate, opts.watchdog)
+                .apply(sock)?;
+        }}
+        Ok(())
+    }}
+}}"#
+        )
+    )
+}
 
-        if let Some(mut list) = self.blacklist.clone() {
-            list.sort_unstable();
-            dbcfd.messages.retain(|msg| !DbcParser::check_list(msg.id, &list));
-        }
+/// `PoolSnapshot` and the `CanDbcPoolDiff` extension trait, implemented for every
+/// `CanDbcPool` since the trait itself belongs to sockcan.
+fn gen_pool_diff(code: &DbcCodeGen) -> io::Result<()> {
+    let uid = CanErrorCode::MessageSnapshotFail.uid();
+    code_output!(
+        code,
+        format!(
+            r#"
+/// Value of one signal in a `PoolSnapshot`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SignalState {{
+    pub canid: u32,
+    pub message: &'static str,
+    pub signal: &'static str,
+    pub value: CanDbcType,
+}}
 
-        // sort message by canid
-        dbcfd.messages.sort_by(|a, b| a.id.raw().cmp(&b.id.raw()));
+/// Signal values of a whole pool at a point in time, see `CanDbcPoolDiff::snapshot()`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PoolSnapshot {{
+    pub signals: Vec<SignalState>,
+}}
 
-        let outfd = match &self.outfile {
-            Some(outfile) => {
-                let outfd = File::create(outfile.as_str())?;
-                Some(outfd)
-            },
-            None => None,
-        };
+/// Signal whose value changed since a `PoolSnapshot`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SignalChange {{
+    pub canid: u32,
+    pub message: &'static str,
+    pub signal: &'static str,
+    pub before: CanDbcType,
+    pub after: CanDbcType,
+}}
 
-        // open/create output file
-        let code =
-            DbcCodeGen { dbcfd, outfd, range_check: self.range_check, serde_json: self.serde_json };
+/// Snapshot every signal value of a pool and list what changed since, e.g. to only publish changes
+pub trait CanDbcPoolDiff {{
+    fn snapshot(&self) -> Result<PoolSnapshot, CanError>;
+    fn diff(&self, snapshot: &PoolSnapshot) -> Result<Vec<SignalChange>, CanError>;
+}}
 
-        if let Some(header) = self.header {
-            code_output!(code, header)?;
-        }
+impl<T: CanDbcPool + ?Sized> CanDbcPoolDiff for T {{
+    fn snapshot(&self) -> Result<PoolSnapshot, CanError> {{
+        let mut signals = Vec::new();
+        for msg in self.get_messages() {{
+            let msg = match msg.try_borrow() {{
+                Ok(msg) => msg,
+                Err(_) => return Err(CanError::new("{uid}", "Internal error message borrowed")),
+            }};
+            for signal in msg.get_signals() {{
+                let signal = match signal.try_borrow() {{
+                    Ok(signal) => signal,
+                    Err(_) => return Err(CanError::new("{uid}", format!("Internal error {{}} borrowed", msg.get_name()))),
+                }};
+                signals.push(SignalState {{
+                    canid: msg.get_id(),
+                    message: msg.get_name(),
+                    signal: signal.get_name(),
+                    value: signal.get_value(),
+                }});
+            }}
+        }}
+        Ok(PoolSnapshot {{ signals }})
+    }}
 
-        // change Rust default to stick as much as possible on can names
-        let gen_time = get_time("%c")?;
+    fn diff(&self, snapshot: &PoolSnapshot) -> Result<Vec<SignalChange>, CanError> {{
+        let current = self.snapshot()?;
+        if current.signals.len() != snapshot.signals.len() {{
+            return Err(CanError::new("{uid}", "snapshot taken from another pool"));
+        }}
+        let mut changes = Vec::new();
+        for (before, after) in snapshot.signals.iter().zip(&current.signals) {{
+            if before.canid != after.canid || before.signal != after.signal {{
+                return Err(CanError::new("{uid}", "snapshot taken from another pool"));
+            }}
+            if before.value != after.value {{
+                changes.push(SignalChange {{
+                    canid: after.canid,
+                    message: after.message,
+                    signal: after.signal,
+                    before: before.value,
+                    after: after.value,
+                }});
+            }}
+        }}
+        Ok(changes)
+    }}
+}}"#
+        )
+    )
+}
 
-        let uid = self.uid;
+fn gen_nodes(code: &DbcCodeGen) -> io::Result<()> {
+    let nodes: Vec<&String> = code.dbcfd.nodes.iter().flat_map(|node| node.0.iter()).collect();
+    if nodes.is_empty() {
+        return Ok(());
+    }
+    code_output!(
+        code,
+        r#"
+/// Network nodes (BU_) with the messages they transmit and the signals they consume
+pub mod nodes {"#
+    )?;
+    for node in nodes {
+        let transmits: Vec<u32> = code
+            .dbcfd
+            .messages
+            .iter()
+            .filter(|msg| message_transmitters(&code.dbcfd, msg).contains(node))
+            .map(|msg| msg.id.raw())
+            .collect();
+        let consumes: Vec<String> = code
+            .dbcfd
+            .messages
+            .iter()
+            .flat_map(|msg| {
+                msg.signals
+                    .iter()
+                    .filter(|sig| signal_receivers(sig).contains(&node.as_str()))
+                    .map(move |sig| format!("({}, {:?})", msg.id.raw(), sig.get_type_kamel()))
+            })
+            .collect();
         code_output!(
             code,
             format!(
-                r#"
-// --------------------------------------------------------------
-//       WARNING: Manual modification will be destroyed
-// --------------------------------------------------------------
-// - code generated from {infile} ({gen_time})
-// - update only with [dbc-parser|build.rs::DbcParser]
-// - source code: https://github.com/redpesk-common/canforge-rs
-// Generated file — DO NOT EDIT.
-// Update only with [dbc-parser|build.rs::DbcParser]
-// Source: https://github.com/redpesk-common/canforge-rs
-//
-// Copyright (C) 2023 IoT.bzh Company
-// Author: Fulup Ar Foll <fulup@iot.bzh>
-//
-// Licensed under the Apache License, Version 2.0 (the "License");
-// you may not use this file except in compliance with the License.
-// You may obtain a copy of the License at
-//
-//   http://www.apache.org/licenses/LICENSE-2.0
-//
-// Unless required by applicable law or agreed to in writing, software
-// distributed under the License is distributed on an "AS IS" BASIS,
-// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
-// See the License for the specific language governing permissions and
-// limitations under the License.
-// -------------------------------------------------------------
-mod {uid} {{
-#![allow(non_upper_case_globals)]
-#![allow(non_camel_case_types)]
-#![allow(non_snake_case)]
-#![allow(dead_code)]"#
+                r#"    pub mod {node_mod} {{
+        pub const NAME: &str = "{node}";
+        pub const TRANSMITS: [u32; {count}] = {transmits:?};
+        /// (canid, signal) consumed by this node
+        pub const CONSUMES: [(u32, &str); {consume_count}] = [{consumes}];
+    }}"#,
+                node_mod = node.to_upper_camel_case(),
+                count = transmits.len(),
+                consume_count = consumes.len(),
+                consumes = consumes.join(", "),
             )
         )?;
+    }
+    code_output!(code, "} // end nodes\n")
+}
 
-        if code.serde_json {
-            code_output!(code, "extern crate serde;")?;
-        }
-        code_output!(
-            code,
-            r#"
-extern crate bitvec;
-use sockcan::prelude::*;
-use std::cell::{RefCell,RefMut};
-use std::rc::{Rc};
-"#
-        )?;
+fn gen_attributes(code: &DbcCodeGen, attributes: &[DbcAttribute]) -> io::Result<()> {
+    code_output!(
+        code,
+        r#"
+/// DBC attribute definitions (BA_DEF_) with their constraints and defaults (BA_DEF_DEF_)
+pub mod attributes {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum AttributeScope {
+        Network,
+        Node,
+        Message,
+        Signal,
+        EnvVar,
+    }
 
-        // output messages/signals
-        for message in &code.dbcfd.messages {
-            message.gen_code_message(&code)?;
-        }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum AttributeKind {
+        Int,
+        Hex,
+        Float,
+        String,
+        Enum,
+    }
 
-        // enumeration with all signal type
-        code_output!(code, "enum DbcMessages {")?;
-        for message in &code.dbcfd.messages {
-            let msg_type = message.get_type_kamel();
-            code_output!(code, format!(r#"    {msg_type},"#))?;
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum AttributeValue {
+        Int(i64),
+        Float(f64),
+        Str(&'static str),
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct AttributeDef {
+        pub name: &'static str,
+        pub scope: AttributeScope,
+        pub kind: AttributeKind,
+        range: Option<(f64, f64)>,
+        choices: &'static [&'static str],
+        default: Option<AttributeValue>,
+    }
+
+    impl AttributeDef {
+        /// Allowed [min, max] for INT, HEX and FLOAT attributes
+        pub const fn range(&self) -> Option<(f64, f64)> {
+            self.range
         }
-        // extract canid from messages vector
-        let canids: Vec<u32> = code.dbcfd.messages.iter().map(|msg| msg.id.raw()).collect();
 
-        let msg_count = code.dbcfd.messages.len();
+        /// Allowed labels for ENUM attributes
+        pub const fn choices(&self) -> &'static [&'static str] {
+            self.choices
+        }
 
+        pub const fn default(&self) -> Option<AttributeValue> {
+            self.default
+        }
+    }
+"#
+    )?;
+
+    for attr in attributes {
+        let kind = match attr.kind {
+            AttributeKind::Int { .. } => "Int",
+            AttributeKind::Hex { .. } => "Hex",
+            AttributeKind::Float { .. } => "Float",
+            AttributeKind::String => "String",
+            AttributeKind::Enum(_) => "Enum",
+        };
+        let range = match attr.range() {
+            Some((min, max)) => format!("Some(({min:?}_f64, {max:?}_f64))"),
+            None => "None".to_owned(),
+        };
+        let choices = match &attr.kind {
+            AttributeKind::Enum(choices) => format!("{choices:?}"),
+            _ => "[]".to_owned(),
+        };
+        let default = match &attr.default {
+            Some(value) => format!("Some({})", attribute_value_literal(value)),
+            None => "None".to_owned(),
+        };
         code_output!(
             code,
             format!(
-                r#"
+                r#"    pub const {const_name}: AttributeDef = AttributeDef {{
+        name: "{name}",
+        scope: AttributeScope::{scope:?},
+        kind: AttributeKind::{kind},
+        range: {range},
+        choices: &{choices},
+        default: {default},
+    }};
+"#,
+                const_name = attr.name.to_shouty_snake_case(),
+                name = attr.name,
+                scope = attr.scope,
+            )
+        )?;
+    }
+
+    let all: Vec<String> = attributes.iter().map(|attr| attr.name.to_shouty_snake_case()).collect();
+    code_output!(
+        code,
+        format!(
+            r#"    pub const ALL: [AttributeDef; {count}] = [{all}];
+}} // end attributes
+"#,
+            count = all.len(),
+            all = all.join(", ")
+        )
+    )
+}
+
+/// Plugin ABI revision, bumped on any incompatible change of the `canforge_plugin_*` symbols.
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+/// `module` is the path of the generated items from the plugin module: `super::{uid}`, or
+/// `crate` with `crate_root`.
+fn gen_plugin_abi(code: &DbcCodeGen, uid: &str, module: &str) -> io::Result<()> {
+    // the plugin module sits next to the uid module, not below it
+    let prelude = if code.sockcan {
+        code.sockcan_prelude(1)
+    } else {
+        format!("{module}::sockcan::prelude::*")
+    };
+    code_output!(
+        code,
+        format!(
+            r#"
+// --------------------------------------------------------------
+// binder plugin ABI v{PLUGIN_ABI_VERSION}: build this file in a cdylib, the host resolves
+// canforge_plugin_* with dlsym() and never sees Rust types.
+// --------------------------------------------------------------
+mod canforge_plugin {{
+#![allow(dead_code)]
+use {module}::CanMsgPool;
+use {prelude};
+use std::ffi::{{c_char, c_void, CStr}};
+
+#[no_mangle]
+pub extern "C" fn canforge_plugin_abi_version() -> u32 {{
+    {PLUGIN_ABI_VERSION}
 }}
 
-pub struct CanMsgPool {{
-    uid: &'static str,
-    pool: [Rc<RefCell<Box<dyn CanDbcMessage>>>;{msg_count}],
+#[no_mangle]
+pub extern "C" fn canforge_plugin_uid() -> *const c_char {{
+    b"{uid}\0".as_ptr() as *const c_char
 }}
 
-impl CanMsgPool {{
-    pub fn new(uid: &'static str) -> Self {{
-        CanMsgPool {{
-            uid: uid,
-            pool: ["#
-            )
-        )?;
+/// Create a message pool, release it with canforge_plugin_release().
+#[no_mangle]
+pub extern "C" fn canforge_plugin_init() -> *mut c_void {{
+    Box::into_raw(Box::new(CanMsgPool::new("{uid}"))) as *mut c_void
+}}
 
-        for idx in 0..code.dbcfd.messages.len() {
-            let msg_type = code.dbcfd.messages[idx].get_type_kamel();
-            code_output!(code, format!(r#"                {msg_type}::DbcMessage::new(),"#))?;
-        }
-        code_output!(
-            code,
-            format!(
-                r#"
-            ]
-        }}
+#[no_mangle]
+pub unsafe extern "C" fn canforge_plugin_release(pool: *mut c_void) {{
+    if !pool.is_null() {{
+        drop(Box::from_raw(pool as *mut CanMsgPool));
     }}
 }}
 
-impl CanDbcPool for CanMsgPool {{
-    fn get_messages(&self) -> &[Rc<RefCell<Box<dyn CanDbcMessage>>>] {{
-        &self.pool
+/// Update the pool with one received frame, returns the signal listener count or -1 (also for
+/// a payload over the 64 bytes of CAN FD). `data` may be NULL when `len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn canforge_plugin_decode(pool: *mut c_void, canid: u32, stamp: u64, data: *const u8, len: usize) -> i32 {{
+    let Some(pool) = (pool as *const CanMsgPool).as_ref() else {{ return -1 }};
+    if (data.is_null() && len > 0) || len > 64 {{
+        return -1;
     }}
-
-    fn get_ids(&self) -> &[u32] {{
-        &{canids:?}
+    let frame = CanMsgData {{
+        canid,
+        stamp,
+        opcode: CanBcmOpCode::RxChanged,
+        len: len as u8,
+        data: if len == 0 {{ Vec::new() }} else {{ std::slice::from_raw_parts(data, len).to_vec() }},
+    }};
+    match pool.update(&frame) {{
+        Ok(msg) => msg.get_listeners(),
+        Err(_) => -1,
     }}
+}}
 
-    fn get_mut(&self, canid: u32) -> Result<RefMut<'_, Box<dyn CanDbcMessage>>, CanError> {{
-        let search= self.pool.binary_search_by(|msg| msg.borrow().get_id().cmp(&canid));
-        match search {{
-            Ok(idx) => {{
-                match self.pool[idx].try_borrow_mut() {{
-                    Err(_code) => Err(CanError::new("message-get_mut", "internal msg pool error")),
-                    Ok(mut_ref) => Ok(mut_ref),
-                }}
-            }},
-            Err(_) => Err(CanError::new("fail-canid-search", format!("canid:{{}} not found",canid))),
-        }}
+/// Read the last value of a signal (generated name) as double, returns 0 or -1.
+#[no_mangle]
+pub unsafe extern "C" fn canforge_plugin_query(pool: *mut c_void, canid: u32, signal: *const c_char, value: *mut f64) -> i32 {{
+    let Some(pool) = (pool as *const CanMsgPool).as_ref() else {{ return -1 }};
+    if signal.is_null() || value.is_null() {{
+        return -1;
     }}
-
-    fn update(&self, data: &CanMsgData) -> Result<RefMut<'_, Box<dyn CanDbcMessage>>, CanError> {{
-        let mut msg= match self.get_mut(data.canid) {{
-            Err(error) => return Err(error),
-            Ok(msg_ref) => msg_ref,
-        }};
-        msg.update(data)?;
-        Ok(msg)
+    let Ok(name) = CStr::from_ptr(signal).to_str() else {{ return -1 }};
+    let Ok(msg) = pool.get_mut(canid) else {{ return -1 }};
+    for sig in msg.get_signals() {{
+        let sig = sig.borrow();
+        if sig.get_name() == name {{
+            *value = match sig.get_value() {{
+                CanDbcType::Bool(v) => f64::from(u8::from(v)),
+                CanDbcType::U8(v) => f64::from(v),
+                CanDbcType::U16(v) => f64::from(v),
+                CanDbcType::U32(v) => f64::from(v),
+                CanDbcType::U64(v) => v as f64,
+                CanDbcType::I8(v) => f64::from(v),
+                CanDbcType::I16(v) => f64::from(v),
+                CanDbcType::I32(v) => f64::from(v),
+                CanDbcType::I64(v) => v as f64,
+                CanDbcType::F64(v) => v,
+            }};
+            return 0;
+        }}
     }}
- }}
-}} // end dbc generated parser"#
-            )
-        )?;
-
-        Ok(())
-    }
+    -1
+}}
+}} // end binder plugin ABI"#
+        )
+    )
 }
@@ -0,0 +1,91 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Golden file checks, for "the generated code matches the checked-in reference" tests.
+//!
+//! Generated files carry their input path and generation time in the banner ("code generated
+//! from" line), and checkouts may turn line endings into CRLF. Comparisons here ignore both and
+//! nothing else, so any other change of the generated code fails the test with a summary of the
+//! changed lines. Set `CANFORGE_BLESS=1` to rewrite the references instead, then review the diff.
+//!
+//! ```no_run
+//! use dbcparser::prelude::*;
+//!
+//! let code = DbcParser::new("Model3").dbcfile("dbc/model3.dbc").generate_string().unwrap();
+//! dbcparser::golden::assert_golden("src/model3.rs", &code);
+//! ```
+
+use crate::gencode::generated_drift;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Environment variable rewriting the references in [`assert_golden`] when set to `1`.
+pub const BLESS_VAR: &str = "CANFORGE_BLESS";
+
+/// Start of the banner line holding the input path and generation time.
+pub const STAMP_PREFIX: &str = "// - code generated from ";
+
+/// `content` without its generation stamp lines, lines joined with `\n` whatever their
+/// original ending.
+#[must_use]
+pub fn filter_content(content: &str) -> String {
+    content
+        .lines()
+        .filter(|line| !line.trim_start().starts_with(STAMP_PREFIX))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// [`filter_content`] of the file at `path`.
+///
+/// # Errors
+/// Returns an error when the file cannot be read.
+pub fn read_filtered<P: AsRef<Path>>(path: P) -> io::Result<String> {
+    fs::read_to_string(path).map(|content| filter_content(&content))
+}
+
+/// Compare `generated` code with its `reference`, `None` when they only differ by generation
+/// stamps and line endings, a summary of the changed lines otherwise.
+#[must_use]
+pub fn compare(reference: &str, generated: &str) -> Option<String> {
+    generated_drift(&filter_content(reference), &filter_content(generated))
+}
+
+/// Check `generated` code against the reference file at `path`, or rewrite the reference when
+/// [`BLESS_VAR`] is `1`.
+///
+/// # Panics
+/// Panics when the reference cannot be read (or written when blessing) or differs from
+/// `generated`.
+pub fn assert_golden<P: AsRef<Path>>(path: P, generated: &str) {
+    let path = path.as_ref();
+    if env::var(BLESS_VAR).is_ok_and(|bless| bless == "1") {
+        fs::write(path, generated)
+            .unwrap_or_else(|error| panic!("failed to bless {}: {error}", path.display()));
+        return;
+    }
+    let reference = fs::read_to_string(path)
+        .unwrap_or_else(|error| panic!("failed to read {}: {error}", path.display()));
+    if let Some(summary) = compare(&reference, generated) {
+        panic!(
+            "{} differs from the generated code, {BLESS_VAR}=1 rewrites it\n{summary}",
+            path.display()
+        );
+    }
+}
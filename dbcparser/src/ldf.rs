@@ -0,0 +1,348 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Reference:
+ *   LIN Specification Package 2.2A, section 9 (LIN description file)
+ */
+
+//! LIN description file (LDF) import.
+//!
+//! Unconditional frames are turned into DBC `BO_` definitions with their signals, physical
+//! encodings become factor/offset/unit and logical encodings `VAL_` tables, so LIN sub-buses
+//! go through the same parser and code generator as CAN. Schedule tables, diagnostic and
+//! event-triggered frames are ignored.
+
+use crate::eds::range;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::io::{self, Error};
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Text(String),
+    Punct(char),
+    /// Inline `{a, b}` value, e.g. a byte array signal init value.
+    List(Vec<String>),
+}
+
+fn tokenize(text: &str) -> io::Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {},
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            },
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut last = ' ';
+                for c in chars.by_ref() {
+                    if last == '*' && c == '/' {
+                        break;
+                    }
+                    last = c;
+                }
+            },
+            '"' => {
+                let mut text = String::new();
+                for c in chars.by_ref() {
+                    if c == '"' {
+                        break;
+                    }
+                    text.push(c);
+                }
+                tokens.push(Token::Text(text));
+            },
+            // a brace following a comma or a colon opens a value list, not a block
+            '{' if matches!(tokens.last(), Some(Token::Punct(',' | ':' | '='))) => {
+                let mut list = String::new();
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        break;
+                    }
+                    list.push(c);
+                }
+                tokens.push(Token::List(list.split(',').map(|v| v.trim().to_owned()).collect()));
+            },
+            '{' | '}' | ';' | ',' | ':' | '=' => tokens.push(Token::Punct(c)),
+            _ => {
+                let mut word = String::from(c);
+                while let Some(&next) = chars.peek() {
+                    if next.is_whitespace() || "{};,:=\"".contains(next) {
+                        break;
+                    }
+                    word.push(next);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            },
+        }
+    }
+    if tokens.is_empty() {
+        return Err(Error::other("ldf: empty file"));
+    }
+    Ok(tokens)
+}
+
+/// One LDF statement: `head;` or `head { body }`.
+#[derive(Debug, Default)]
+struct Item {
+    head: Vec<Token>,
+    body: Option<Vec<Item>>,
+}
+
+impl Item {
+    fn word(&self, idx: usize) -> Option<&str> {
+        match self.head.get(idx) {
+            Some(Token::Word(word)) => Some(word),
+            _ => None,
+        }
+    }
+
+    /// Head values separated by commas, after the optional `name:` prefix.
+    fn values(&self) -> Vec<&Token> {
+        let start = self.head.iter().position(|t| *t == Token::Punct(':')).map_or(0, |p| p + 1);
+        self.head[start..].iter().filter(|t| **t != Token::Punct(',')).collect()
+    }
+}
+
+fn parse_items(tokens: &[Token], pos: &mut usize) -> io::Result<Vec<Item>> {
+    let mut items = Vec::new();
+    let mut item = Item::default();
+    while let Some(token) = tokens.get(*pos) {
+        *pos += 1;
+        match token {
+            Token::Punct(';') => items.push(std::mem::take(&mut item)),
+            Token::Punct('{') => {
+                item.body = Some(parse_items(tokens, pos)?);
+                items.push(std::mem::take(&mut item));
+            },
+            Token::Punct('}') => {
+                if !item.head.is_empty() {
+                    items.push(item);
+                }
+                return Ok(items);
+            },
+            token => item.head.push(token.clone()),
+        }
+    }
+    if !item.head.is_empty() {
+        return Err(Error::other("ldf: missing ';' at end of file"));
+    }
+    Ok(items)
+}
+
+fn number(token: Option<&&Token>) -> Option<f64> {
+    let Some(Token::Word(word)) = token else {
+        return None;
+    };
+    if let Some(hex) = word.strip_prefix("0x").or_else(|| word.strip_prefix("0X")) {
+        return u64::from_str_radix(hex, 16).ok().map(|v| v as f64);
+    }
+    word.parse().ok()
+}
+
+struct LdfSignal {
+    size: u32,
+    subscribers: Vec<String>,
+}
+
+#[derive(Default)]
+struct Encoding {
+    physical: Option<(f64, f64, f64, f64, String)>,
+    logical: Vec<(u64, String)>,
+}
+
+/// Convert LDF text into DBC text.
+///
+/// # Errors
+/// Returns an error when the LDF is malformed or a frame references an unknown signal.
+#[allow(clippy::too_many_lines, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn ldf_to_dbc(text: &str) -> io::Result<String> {
+    let tokens = tokenize(text)?;
+    let items = parse_items(&tokens, &mut 0)?;
+    let section = |name: &str| {
+        items
+            .iter()
+            .find(|item| item.word(0) == Some(name))
+            .and_then(|item| item.body.as_deref())
+            .unwrap_or_default()
+    };
+
+    let mut nodes = Vec::new();
+    for item in section("Nodes") {
+        match item.word(0) {
+            // Master: name, time base, jitter;
+            Some("Master") => {
+                if let Some(Token::Word(name)) = item.values().first() {
+                    nodes.push(name.clone());
+                }
+            },
+            Some("Slaves") => nodes.extend(item.values().iter().filter_map(|t| match t {
+                Token::Word(name) => Some(name.clone()),
+                _ => None,
+            })),
+            _ => {},
+        }
+    }
+
+    // name: size, init value, publisher, subscribers...;
+    let mut signals = HashMap::new();
+    for item in section("Signals") {
+        let Some(name) = item.word(0) else { continue };
+        let values = item.values();
+        let size = number(values.first())
+            .ok_or_else(|| Error::other(format!("ldf signal:{name} invalid size")))?
+            as u32;
+        // the frame publisher is the DBC transmitter, only keep subscribers
+        let subscribers = values
+            .iter()
+            .skip(3)
+            .filter_map(|t| match t {
+                Token::Word(node) => Some(node.clone()),
+                _ => None,
+            })
+            .collect();
+        signals.insert(name.to_owned(), LdfSignal { size, subscribers });
+    }
+
+    let mut encodings: HashMap<&str, Encoding> = HashMap::new();
+    for item in section("Signal_encoding_types") {
+        let Some(name) = item.word(0) else { continue };
+        let encoding = encodings.entry(name).or_default();
+        for value in item.body.as_deref().unwrap_or_default() {
+            let fields = value.values();
+            match value.word(0) {
+                Some("logical_value") => {
+                    let Some(raw) = number(fields.get(1)) else { continue };
+                    let text = match fields.get(2) {
+                        Some(Token::Text(text)) => text.clone(),
+                        _ => format!("{raw}"),
+                    };
+                    encoding.logical.push((raw as u64, text));
+                },
+                // first physical range wins, DBC has a single factor/offset per signal
+                Some("physical_value") if encoding.physical.is_none() => {
+                    let (Some(min), Some(max)) = (number(fields.get(1)), number(fields.get(2)))
+                    else {
+                        continue;
+                    };
+                    let factor = number(fields.get(3)).unwrap_or(1.0);
+                    let offset = number(fields.get(4)).unwrap_or(0.0);
+                    let unit = match fields.get(5) {
+                        Some(Token::Text(unit)) => unit.clone(),
+                        _ => String::new(),
+                    };
+                    encoding.physical = Some((min, max, factor, offset, unit));
+                },
+                _ => {},
+            }
+        }
+    }
+
+    let mut representation: HashMap<String, &Encoding> = HashMap::new();
+    for item in section("Signal_representation") {
+        let Some(encoding) = item.word(0).and_then(|name| encodings.get(name)) else {
+            continue;
+        };
+        for value in item.values() {
+            if let Token::Word(signal) = value {
+                representation.insert(signal.clone(), encoding);
+            }
+        }
+    }
+
+    let mut dbc = String::new();
+    let mut tables = String::new();
+    let _ = writeln!(dbc, "VERSION \"\"\n\nNS_ :\n\nBS_:\n\nBU_: {}\n", nodes.join(" "));
+
+    // name: id, publisher, length { signal, offset; ... }
+    for frame in section("Frames") {
+        let Some(name) = frame.word(0) else { continue };
+        let values = frame.values();
+        let id = number(values.first())
+            .ok_or_else(|| Error::other(format!("ldf frame:{name} invalid id")))?
+            as u32;
+        let publisher = match values.get(1) {
+            Some(Token::Word(node)) => node.as_str(),
+            _ => "Vector__XXX",
+        };
+        let length = number(values.get(2)).unwrap_or(8.0) as u32;
+        let _ = writeln!(dbc, "BO_ {id} {name}: {length} {publisher}");
+
+        for entry in frame.body.as_deref().unwrap_or_default() {
+            let Some(sig_name) = entry.word(0) else { continue };
+            let offset = number(entry.values().get(1)).unwrap_or(0.0) as u32;
+            let sig = signals.get(sig_name).ok_or_else(|| {
+                Error::other(format!("ldf frame:{name} signal:{sig_name} not declared"))
+            })?;
+            if sig.size == 0 || sig.size > 64 || offset + sig.size > length * 8 {
+                return Err(Error::other(format!(
+                    "ldf frame:{name} signal:{sig_name} does not fit in {length} bytes"
+                )));
+            }
+            let receivers = if sig.subscribers.is_empty() {
+                "Vector__XXX".to_owned()
+            } else {
+                sig.subscribers.join(",")
+            };
+            let encoding = representation.get(sig_name);
+            let (min, max, factor, phys_offset, unit) =
+                match encoding.and_then(|encoding| encoding.physical.as_ref()) {
+                    Some((min, max, factor, offset, unit)) => (
+                        *offset + *min * *factor,
+                        *offset + *max * *factor,
+                        *factor,
+                        *offset,
+                        unit.as_str(),
+                    ),
+                    None => {
+                        let (min, max) = range(sig.size, false);
+                        (min, max, 1.0, 0.0, "")
+                    },
+                };
+            // LIN transmits LSB first, i.e. DBC little-endian (@1)
+            let _ = writeln!(
+                dbc,
+                " SG_ {sig_name} : {offset}|{}@1+ ({factor},{phys_offset}) [{min}|{max}] \"{unit}\" {receivers}",
+                sig.size
+            );
+            if let Some(encoding) = encoding.filter(|encoding| !encoding.logical.is_empty()) {
+                let _ = write!(tables, "VAL_ {id} {sig_name}");
+                for (raw, text) in &encoding.logical {
+                    let _ = write!(tables, " {raw} \"{text}\"");
+                }
+                tables.push_str(" ;\n");
+            }
+        }
+        dbc.push('\n');
+    }
+    dbc.push_str(&tables);
+    Ok(dbc)
+}
+
+/// True when `path` looks like a LIN description file.
+#[must_use]
+pub fn is_ldf_file<P: AsRef<Path>>(path: P) -> bool {
+    path.as_ref().extension().is_some_and(|ext| ext.eq_ignore_ascii_case("ldf"))
+}
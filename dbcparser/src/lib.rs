@@ -11,6 +11,43 @@ pub mod gencode;
 
 pub use crate::gencode::*;
 
+// BA_DEF_ attribute definitions
+pub mod attributes;
+
+// codes of the CanError uids raised by generated code
+pub mod errors;
+
+// time source, injectable for tests and replays
+pub mod clock;
+
+// end-to-end protection: counter/checksum signals and CRC algorithms
+pub mod e2e;
+
+// CANopen EDS/DCF and LIN LDF import
+pub mod eds;
+pub mod ldf;
+
+// JSON IR and Markdown documentation written next to the generated code
+pub mod export;
+
+// reference comparison of generated files, for downstream golden tests
+pub mod golden;
+
+// cargo package around generated code
+pub mod package;
+
+// semantic version bump between two revisions of a database
+pub mod compat;
+
 pub mod prelude {
+    pub use crate::attributes::*;
+    pub use crate::clock::*;
+    pub use crate::compat::*;
+    pub use crate::e2e::*;
+    pub use crate::eds::*;
+    pub use crate::errors::*;
+    pub use crate::export::*;
     pub use crate::gencode::*;
+    pub use crate::ldf::*;
+    pub use crate::package::*;
 }
@@ -0,0 +1,137 @@
+/*
+ * Copyright (C) 2015-2026 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Cargo package around generated code, written by `DbcParser::emit_crate()`.
+//!
+//! The package is named after its directory and versioned after the DBC `VERSION` line, so
+//! a database release becomes a crate release. Dependencies are pinned to the versions the
+//! generated code is tested with.
+
+use crate::compat::parse_version;
+use std::fmt::Write;
+use std::io::{self, Error};
+use std::path::Path;
+
+/// `bitvec` version of generated packages.
+pub const BITVEC_VERSION: &str = "1.1.1";
+/// `serde` version of generated packages.
+pub const SERDE_VERSION: &str = "1.0.229";
+/// `serde_json` version of generated packages.
+pub const SERDE_JSON_VERSION: &str = "1.0.154";
+/// Repository of the `sockcan` crate, not published on crates.io.
+pub const SOCKCAN_GIT: &str = "https://github.com/redpesk-labs/canbus-rs";
+/// `sockcan` revision of generated packages.
+pub const SOCKCAN_REV: &str = "aa147a9d529fb484b4d885e2279d3d2dcac2e51f";
+
+/// Version of packages whose DBC has no semantic `VERSION`.
+pub const DEFAULT_CRATE_VERSION: &str = "0.1.0";
+
+/// Cargo package description, see [`CargoPackage::manifest`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CargoPackage {
+    pub name: String,
+    pub version: String,
+    pub description: String,
+    /// SPDX license expression, none when unset.
+    pub license: Option<String>,
+    /// Depend on the sockcan crate, the code holds its own stand-in otherwise.
+    pub sockcan: bool,
+    /// Dependencies without their default features, for `no_std` code.
+    pub no_std: bool,
+    /// Feature enabling the then optional serde dependencies.
+    pub serde_feature: Option<String>,
+    /// Also build a cdylib, for the binder plugin ABI.
+    pub cdylib: bool,
+}
+
+impl CargoPackage {
+    /// `Cargo.toml` of the package.
+    #[must_use]
+    pub fn manifest(&self) -> String {
+        let mut manifest = format!(
+            "# Generated by dbcparser: do not edit.\n\
+             [package]\n\
+             name = {:?}\n\
+             version = {:?}\n\
+             edition = \"2021\"\n\
+             description = {:?}\n",
+            self.name, self.version, self.description
+        );
+        if let Some(license) = &self.license {
+            let _ = writeln!(manifest, "license = {license:?}");
+        }
+        if self.cdylib {
+            manifest.push_str("\n[lib]\ncrate-type = [\"rlib\", \"cdylib\"]\n");
+        }
+
+        let optional = if self.serde_feature.is_some() { ", optional = true" } else { "" };
+        let (bitvec, serde, serde_json) = if self.no_std {
+            (
+                format!("{{ version = \"{BITVEC_VERSION}\", default-features = false, features = [\"alloc\"] }}"),
+                format!("{{ version = \"{SERDE_VERSION}\", default-features = false, features = [\"derive\", \"alloc\"]{optional} }}"),
+                format!("{{ version = \"{SERDE_JSON_VERSION}\", default-features = false, features = [\"alloc\"]{optional} }}"),
+            )
+        } else {
+            (
+                format!("\"{BITVEC_VERSION}\""),
+                format!("{{ version = \"{SERDE_VERSION}\", features = [\"derive\"]{optional} }}"),
+                format!("{{ version = \"{SERDE_JSON_VERSION}\"{optional} }}"),
+            )
+        };
+        let _ = write!(
+            manifest,
+            "\n[dependencies]\nbitvec = {bitvec}\nserde = {serde}\nserde_json = {serde_json}\n"
+        );
+        if self.sockcan {
+            let _ =
+                writeln!(manifest, "sockcan = {{ git = {SOCKCAN_GIT:?}, rev = {SOCKCAN_REV:?} }}");
+        }
+        if let Some(feature) = &self.serde_feature {
+            let _ =
+                write!(manifest, "\n[features]\n{feature} = [\"dep:serde\", \"dep:serde_json\"]\n");
+        }
+        manifest
+    }
+}
+
+/// Package name of the directory `dir`, e.g. `my-car-dbc`.
+///
+/// # Errors
+/// Returns an error when the directory name is not a crate name: ASCII letters, digits, `-`
+/// and `_`, starting with a letter.
+pub fn crate_name(dir: &Path) -> io::Result<String> {
+    let name = dir.file_name().unwrap_or_default().to_string_lossy();
+    let valid = name.starts_with(|char: char| char.is_ascii_alphabetic())
+        && name
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || char == '-' || char == '_');
+    if !valid {
+        return Err(Error::other(format!(
+            "crate directory:{} not named after a crate",
+            dir.display()
+        )));
+    }
+    Ok(name.into_owned())
+}
+
+/// `major.minor.patch` of the DBC `VERSION` line, `None` when missing or not semantic.
+#[must_use]
+pub fn dbc_version(dbc: &str) -> Option<String> {
+    let line = dbc.lines().map(str::trim).find(|line| line.starts_with("VERSION"))?;
+    let version = line.strip_prefix("VERSION")?.trim().trim_matches('"');
+    parse_version(version).map(|_| version.to_owned())
+}